@@ -0,0 +1,83 @@
+//! Deadline for individual Kubernetes API calls
+//!
+//! A hung API call in an `ensure_*` function would otherwise stall the
+//! reconcile, and the controller worker running it, indefinitely. Calls wrapped
+//! in [`with_timeout`] fail with the retriable [`Error::Timeout`] once the
+//! deadline set by `--api-call-timeout-seconds` passes, so the node is requeued.
+
+use std::future::Future;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Duration;
+
+use crate::error::{Error, Result};
+
+/// Default deadline for a single API call, in seconds
+pub const DEFAULT_API_CALL_TIMEOUT_SECONDS: u64 = 30;
+
+static API_CALL_TIMEOUT_SECONDS: AtomicU64 = AtomicU64::new(DEFAULT_API_CALL_TIMEOUT_SECONDS);
+
+/// Set the deadline applied to every wrapped API call
+pub fn set_api_call_timeout(timeout: Duration) {
+    API_CALL_TIMEOUT_SECONDS.store(timeout.as_secs().max(1), Ordering::Relaxed);
+}
+
+/// Deadline applied to every wrapped API call
+pub fn api_call_timeout() -> Duration {
+    Duration::from_secs(API_CALL_TIMEOUT_SECONDS.load(Ordering::Relaxed))
+}
+
+/// Await `call`, failing with [`Error::Timeout`] after [`api_call_timeout`]
+///
+/// `operation` names the call in the error, e.g. `"apply Deployment stellar/horizon"`.
+pub async fn with_timeout<T>(
+    operation: &str,
+    call: impl Future<Output = kube::Result<T>>,
+) -> Result<T> {
+    with_deadline(api_call_timeout(), operation, call).await
+}
+
+async fn with_deadline<T>(
+    deadline: Duration,
+    operation: &str,
+    call: impl Future<Output = kube::Result<T>>,
+) -> Result<T> {
+    match tokio::time::timeout(deadline, call).await {
+        Ok(result) => result.map_err(Error::KubeError),
+        Err(_) => Err(Error::Timeout(format!(
+            "{operation} did not complete within {}s",
+            deadline.as_secs()
+        ))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_slow_call_times_out_as_retriable() {
+        let slow = async {
+            tokio::time::sleep(Duration::from_secs(60)).await;
+            Ok(())
+        };
+        let err = with_deadline(
+            Duration::from_millis(10),
+            "apply Deployment stellar/horizon",
+            slow,
+        )
+        .await
+        .unwrap_err();
+
+        assert!(matches!(err, Error::Timeout(_)));
+        assert!(err.is_retriable());
+        assert!(err.to_string().contains("apply Deployment stellar/horizon"));
+    }
+
+    #[tokio::test]
+    async fn test_fast_call_passes_through() {
+        let value = with_deadline(Duration::from_secs(1), "get Job", async { Ok(7) })
+            .await
+            .unwrap();
+        assert_eq!(value, 7);
+    }
+}
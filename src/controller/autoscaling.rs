@@ -0,0 +1,1248 @@
+//! Capacity-aware autoscaling helpers
+//!
+//! Heterogeneous node pools (e.g. a mix of large and small instance types)
+//! do not provide equal capacity per replica. `AutoscalingConfig` carries a
+//! map of relative weights keyed by a node label value; this module computes
+//! the effective capacity of the currently running replicas, sizes the HPA's
+//! scale-up step from it, and steers new replicas onto the heaviest pools.
+//!
+//! It also tracks the post-deploy cooldown: when a new version rolls out, the
+//! Deployment is stamped with its deploy time and the HPA is frozen at
+//! `maxReplicas` until `postDeployCooldownSeconds` have passed.
+//!
+//! Finally, it closes the ingest lag feedback loop: when the metric named by
+//! `ingestLagMetric` reports Stellar Core more than `maxIngestLagSeconds`
+//! behind, the HPA's minReplicas is dropped to 1 until the lag recovers.
+//!
+//! `cronOverrides` replace the HPA's replica bounds for a fixed window after
+//! each cron match (e.g. a known weekly traffic peak). The override is applied
+//! on every reconcile while the window is open, so the configured bounds come
+//! back with the first reconcile after it closes.
+//!
+//! `targetActiveConnections` adds an `External` HPA metric on active
+//! connections, so Horizon scales out before it nears `CONNECTION_LIMIT`.
+//!
+//! With `clusterCapacityAware`, maxReplicas is capped at the replicas the
+//! schedulable nodes' free memory can still fit, so the HPA does not create
+//! pods that would stay Pending while a node pool scales out.
+
+use std::collections::BTreeMap;
+
+use chrono::{DateTime, Datelike, Timelike, Utc, Weekday};
+use chrono_tz::Tz;
+use k8s_openapi::api::apps::v1::Deployment;
+use k8s_openapi::api::autoscaling::v2::{
+    CrossVersionObjectReference, ExternalMetricSource, HPAScalingPolicy, MetricIdentifier,
+    MetricSpec, MetricTarget, ObjectMetricSource,
+};
+use k8s_openapi::api::core::v1::{
+    Node, NodeAffinity, NodeSelectorRequirement, NodeSelectorTerm, Pod, PreferredSchedulingTerm,
+};
+use k8s_openapi::apimachinery::pkg::api::resource::Quantity;
+use k8s_openapi::apimachinery::pkg::apis::meta::v1::LabelSelector;
+use kube::{
+    api::{Api, ListParams, Patch, PatchParams},
+    client::Client,
+    ResourceExt,
+};
+use serde_json::{json, Value};
+use tracing::{debug, instrument};
+
+use crate::crd::{
+    ActiveScalingOverride, AutoscalingConfig, CustomResourceMetricSpec, StellarNode, TargetType,
+};
+use crate::error::{Error, Result};
+
+use super::conditions;
+use super::pvc_resize::quantity_bytes;
+
+/// Period used for the weighted scale-up policies
+const WEIGHTED_POLICY_PERIOD_SECONDS: i32 = 60;
+
+/// Deployment annotation recording when the current version was rolled out
+pub const LAST_DEPLOY_TIME_ANNOTATION: &str = "stellar.org/last-deploy-time";
+
+/// Field manager for the deploy-time annotation
+///
+/// Kept separate from the main server-side apply manager so re-applying the
+/// Deployment does not strip the annotation.
+const DEPLOY_TIME_FIELD_MANAGER: &str = "stellar-operator-deploy-time";
+
+/// Build the capacity-aware scale-up policy for the configured instance weights
+///
+/// Each period may add roughly the capacity of one replica on the heaviest
+/// pool. Once `status.effectiveCapacityUnits` has been measured this is a
+/// `Percent` policy: a percentage of the current replicas adds the same
+/// percentage of the current capacity, so `100 * maxWeight / units` percent
+/// adds `maxWeight` units whatever mix of pools the replicas run on. Before
+/// the first measurement it falls back to a `Pods` step of `maxWeight`.
+pub fn weighted_scale_up_policies(
+    cfg: &AutoscalingConfig,
+    effective_capacity_units: Option<u32>,
+) -> Vec<HPAScalingPolicy> {
+    let Some(max_weight) = cfg.instance_weight_annotations.values().max() else {
+        return Vec::new();
+    };
+    let max_weight = (*max_weight).max(1);
+
+    let policy = match effective_capacity_units.filter(|units| *units > 0) {
+        Some(units) => HPAScalingPolicy {
+            type_: "Percent".to_string(),
+            value: (100 * max_weight).div_ceil(units) as i32,
+            period_seconds: WEIGHTED_POLICY_PERIOD_SECONDS,
+        },
+        None => HPAScalingPolicy {
+            type_: "Pods".to_string(),
+            value: max_weight as i32,
+            period_seconds: WEIGHTED_POLICY_PERIOD_SECONDS,
+        },
+    };
+    vec![policy]
+}
+
+/// Preferred node affinity steering new replicas onto the heaviest pools
+///
+/// The HPA only decides how many replicas to run; which nodes they land on is
+/// up to the scheduler. One preferred term per weighted pool, scaled so the
+/// heaviest pool gets the maximum scheduling weight of 100. Pools weighted 0
+/// get no preference.
+pub fn weighted_node_affinity(cfg: &AutoscalingConfig) -> Option<NodeAffinity> {
+    let max_weight = *cfg.instance_weight_annotations.values().max()?;
+    if max_weight == 0 {
+        return None;
+    }
+
+    let terms = cfg
+        .instance_weight_annotations
+        .iter()
+        .filter(|(_, weight)| **weight > 0)
+        .map(|(value, weight)| PreferredSchedulingTerm {
+            weight: (weight * 100 / max_weight).max(1) as i32,
+            preference: NodeSelectorTerm {
+                match_expressions: Some(vec![NodeSelectorRequirement {
+                    key: cfg.instance_weight_label.clone(),
+                    operator: "In".to_string(),
+                    values: Some(vec![value.clone()]),
+                }]),
+                ..Default::default()
+            },
+        })
+        .collect();
+
+    Some(NodeAffinity {
+        preferred_during_scheduling_ignored_during_execution: Some(terms),
+        ..Default::default()
+    })
+}
+
+/// Convert a custom resource metric into an HPA metric
+///
+/// Metrics with a `namespaceSelector` become `External` metrics selected by
+/// those labels; all others become `Object` metrics describing a single
+/// resource in the node's namespace (named after the node unless `resource`
+/// carries an explicit `/<name>`).
+pub fn custom_resource_metric_spec(
+    node: &StellarNode,
+    metric: &CustomResourceMetricSpec,
+) -> MetricSpec {
+    let target = metric_target(metric);
+
+    match &metric.namespace_selector {
+        Some(selector) => MetricSpec {
+            type_: "External".to_string(),
+            external: Some(ExternalMetricSource {
+                metric: MetricIdentifier {
+                    name: metric.name.clone(),
+                    selector: Some(LabelSelector {
+                        match_labels: Some(selector.clone()),
+                        ..Default::default()
+                    }),
+                },
+                target,
+            }),
+            ..Default::default()
+        },
+        None => {
+            let (kind, object_name) = metric.kind_and_name();
+            MetricSpec {
+                type_: "Object".to_string(),
+                object: Some(ObjectMetricSource {
+                    described_object: CrossVersionObjectReference {
+                        api_version: Some(metric.group.clone()),
+                        kind: kind.to_string(),
+                        name: object_name
+                            .map(str::to_string)
+                            .unwrap_or_else(|| node.name_any()),
+                    },
+                    metric: MetricIdentifier {
+                        name: metric.name.clone(),
+                        selector: None,
+                    },
+                    target,
+                }),
+                ..Default::default()
+            }
+        }
+    }
+}
+
+/// Build the `External` active-connections metric for the HPA
+///
+/// Returns `None` unless both `targetActiveConnections` and
+/// `maxConnectionsPerReplica` are set. The target is an average per replica,
+/// so the HPA adds replicas as total connections grow.
+pub fn active_connections_metric_spec(cfg: &AutoscalingConfig) -> Option<MetricSpec> {
+    let target = cfg.target_active_connections?;
+    cfg.max_connections_per_replica?;
+
+    Some(MetricSpec {
+        type_: "External".to_string(),
+        external: Some(ExternalMetricSource {
+            metric: MetricIdentifier {
+                name: cfg.active_connections_metric().to_string(),
+                selector: None,
+            },
+            target: MetricTarget {
+                type_: TargetType::AverageValue.to_string(),
+                average_value: Some(Quantity(target.to_string())),
+                ..Default::default()
+            },
+        }),
+        ..Default::default()
+    })
+}
+
+fn metric_target(metric: &CustomResourceMetricSpec) -> MetricTarget {
+    let value = metric.target_value.trim();
+    match metric.target_type {
+        TargetType::Value => MetricTarget {
+            type_: TargetType::Value.to_string(),
+            value: Some(Quantity(value.to_string())),
+            ..Default::default()
+        },
+        TargetType::AverageValue => MetricTarget {
+            type_: TargetType::AverageValue.to_string(),
+            average_value: Some(Quantity(value.to_string())),
+            ..Default::default()
+        },
+        TargetType::Utilization => MetricTarget {
+            type_: TargetType::Utilization.to_string(),
+            average_utilization: value.parse().ok(),
+            ..Default::default()
+        },
+    }
+}
+
+/// Sum the weights of the nodes hosting each replica
+///
+/// `node_label_values` holds one entry per replica: the value of the
+/// configured instance label on the node it runs on, if any. Replicas on
+/// unlabelled or unweighted nodes count as one unit.
+pub fn effective_capacity_units(
+    cfg: &AutoscalingConfig,
+    node_label_values: &[Option<String>],
+) -> u32 {
+    node_label_values
+        .iter()
+        .map(|value| {
+            value
+                .as_ref()
+                .and_then(|v| cfg.instance_weight_annotations.get(v))
+                .copied()
+                .unwrap_or(1)
+        })
+        .sum()
+}
+
+/// Compute the effective capacity of the running replicas of a StellarNode
+///
+/// Returns `None` when autoscaling is not configured or no instance weights
+/// are set.
+#[instrument(skip(client, node), fields(name = %node.name_any()))]
+pub async fn compute_effective_capacity(
+    client: &Client,
+    node: &StellarNode,
+) -> Result<Option<u32>> {
+    let cfg = match node.spec.autoscaling.as_ref() {
+        Some(cfg) if !cfg.instance_weight_annotations.is_empty() => cfg,
+        _ => return Ok(None),
+    };
+
+    let namespace = node.namespace().unwrap_or_else(|| "default".to_string());
+    let pods: Api<Pod> = Api::namespaced(client.clone(), &namespace);
+    let nodes: Api<Node> = Api::all(client.clone());
+
+    let lp =
+        ListParams::default().labels(&format!("app.kubernetes.io/instance={}", node.name_any()));
+    let pod_list = pods.list(&lp).await.map_err(Error::KubeError)?;
+
+    let mut label_cache: BTreeMap<String, Option<String>> = BTreeMap::new();
+    let mut label_values = Vec::new();
+
+    for pod in pod_list.items {
+        let Some(node_name) = pod.spec.as_ref().and_then(|s| s.node_name.clone()) else {
+            // Unscheduled pods do not contribute capacity yet
+            continue;
+        };
+
+        if !label_cache.contains_key(&node_name) {
+            let value = match nodes.get_opt(&node_name).await.map_err(Error::KubeError)? {
+                Some(k8s_node) => k8s_node.labels().get(&cfg.instance_weight_label).cloned(),
+                None => None,
+            };
+            label_cache.insert(node_name.clone(), value);
+        }
+
+        label_values.push(label_cache.get(&node_name).cloned().flatten());
+    }
+
+    let units = effective_capacity_units(cfg, &label_values);
+    debug!(
+        "Effective capacity for {}/{}: {} units across {} replicas",
+        namespace,
+        node.name_any(),
+        units,
+        label_values.len()
+    );
+
+    Ok(Some(units))
+}
+
+/// Patch `status.effectiveCapacityUnits` on the StellarNode
+pub async fn update_effective_capacity_status(
+    client: &Client,
+    node: &StellarNode,
+    units: u32,
+) -> Result<()> {
+    let namespace = node.namespace().unwrap_or_else(|| "default".to_string());
+    let api: Api<StellarNode> = Api::namespaced(client.clone(), &namespace);
+
+    let patch = json!({
+        "status": {
+            "effectiveCapacityUnits": units
+        }
+    });
+
+    api.patch_status(
+        &node.name_any(),
+        &PatchParams::apply("stellar-operator"),
+        &Patch::Merge(&patch),
+    )
+    .await
+    .map_err(Error::KubeError)?;
+
+    Ok(())
+}
+
+/// Mirror the HPA replica bounds into `status.hpaMinReplicas` / `status.hpaMaxReplicas`
+///
+/// Passing `None` clears both fields.
+pub async fn update_hpa_status(
+    client: &Client,
+    node: &StellarNode,
+    bounds: Option<(i32, i32)>,
+) -> Result<()> {
+    let namespace = node.namespace().unwrap_or_else(|| "default".to_string());
+    let api: Api<StellarNode> = Api::namespaced(client.clone(), &namespace);
+
+    let (min, max) = match bounds {
+        Some((min, max)) => (Some(min), Some(max)),
+        None => (None, None),
+    };
+    let patch = json!({
+        "status": {
+            "hpaMinReplicas": min,
+            "hpaMaxReplicas": max
+        }
+    });
+
+    api.patch_status(
+        &node.name_any(),
+        &PatchParams::apply("stellar-operator"),
+        &Patch::Merge(&patch),
+    )
+    .await
+    .map_err(Error::KubeError)?;
+
+    Ok(())
+}
+
+/// Whether the existing Deployment runs a different image than the spec asks for
+///
+/// A missing Deployment is a first install, not a version change.
+pub async fn new_version_pending(client: &Client, node: &StellarNode) -> Result<bool> {
+    let namespace = node.namespace().unwrap_or_else(|| "default".to_string());
+    let api: Api<Deployment> = Api::namespaced(client.clone(), &namespace);
+    let Some(deployment) = api
+        .get_opt(&node.name_any())
+        .await
+        .map_err(Error::KubeError)?
+    else {
+        return Ok(false);
+    };
+
+    let current = deployment
+        .spec
+        .as_ref()
+        .and_then(|s| s.template.spec.as_ref())
+        .and_then(|ts| ts.containers.first())
+        .and_then(|c| c.image.as_deref());
+    Ok(current.is_some_and(|image| image != node.spec.container_image()))
+}
+
+/// Stamp the node's Deployment with the current time as its last deploy time
+pub async fn record_deploy_time(client: &Client, node: &StellarNode) -> Result<()> {
+    let namespace = node.namespace().unwrap_or_else(|| "default".to_string());
+    let api: Api<Deployment> = Api::namespaced(client.clone(), &namespace);
+
+    let patch = json!({
+        "metadata": {
+            "annotations": {
+                LAST_DEPLOY_TIME_ANNOTATION: Utc::now().to_rfc3339()
+            }
+        }
+    });
+
+    api.patch(
+        &node.name_any(),
+        &PatchParams {
+            field_manager: Some(DEPLOY_TIME_FIELD_MANAGER.to_string()),
+            ..Default::default()
+        },
+        &Patch::Merge(&patch),
+    )
+    .await
+    .map_err(Error::KubeError)?;
+
+    Ok(())
+}
+
+/// Whether `deployment` was rolled out less than `cooldown_secs` before `now`
+pub fn post_deploy_cooldown_active(
+    deployment: &Deployment,
+    cooldown_secs: u32,
+    now: DateTime<Utc>,
+) -> bool {
+    if cooldown_secs == 0 {
+        return false;
+    }
+    deployment
+        .annotations()
+        .get(LAST_DEPLOY_TIME_ANNOTATION)
+        .and_then(|t| DateTime::parse_from_rfc3339(t).ok())
+        .is_some_and(|deployed| {
+            now.signed_duration_since(deployed.with_timezone(&Utc))
+                .num_seconds()
+                < i64::from(cooldown_secs)
+        })
+}
+
+/// Whether the node's HPA should currently be frozen after a deploy
+pub async fn in_post_deploy_cooldown(client: &Client, node: &StellarNode) -> Result<bool> {
+    let Some(cooldown) = node
+        .spec
+        .autoscaling
+        .as_ref()
+        .map(|a| a.post_deploy_cooldown())
+    else {
+        return Ok(false);
+    };
+
+    let namespace = node.namespace().unwrap_or_else(|| "default".to_string());
+    let api: Api<Deployment> = Api::namespaced(client.clone(), &namespace);
+    let active = api
+        .get_opt(&node.name_any())
+        .await
+        .map_err(Error::KubeError)?
+        .is_some_and(|d| post_deploy_cooldown_active(&d, cooldown, Utc::now()));
+
+    Ok(active)
+}
+
+/// Parse a custom metrics API quantity such as "12", "1500m" or "2k"
+pub fn parse_metric_quantity(quantity: &str) -> Option<f64> {
+    let quantity = quantity.trim();
+    let (number, scale) = match quantity.char_indices().last()? {
+        (i, 'm') => (&quantity[..i], 1e-3),
+        (i, 'k') => (&quantity[..i], 1e3),
+        (i, 'M') => (&quantity[..i], 1e6),
+        (i, 'G') => (&quantity[..i], 1e9),
+        _ => (quantity, 1.0),
+    };
+    number.parse::<f64>().ok().map(|n| n * scale)
+}
+
+/// Highest value in a custom metrics API `MetricValueList`
+///
+/// Returns `None` for an empty list or one with no parseable values.
+pub fn max_metric_value(list: &Value) -> Option<f64> {
+    list.get("items")?
+        .as_array()?
+        .iter()
+        .filter_map(|item| item.get("value").and_then(Value::as_str))
+        .filter_map(parse_metric_quantity)
+        .reduce(f64::max)
+}
+
+/// Whether `lag_seconds` exceeds the configured ingest lag threshold
+pub fn ingest_lag_exceeded(cfg: &AutoscalingConfig, lag_seconds: f64) -> bool {
+    cfg.ingest_lag_threshold()
+        .is_some_and(|(_, max)| lag_seconds > f64::from(max))
+}
+
+/// Read the node's ingest lag from the custom metrics API
+///
+/// Returns `Ok(None)` when no feedback loop is configured or the metric has
+/// no samples for the node's pods.
+#[instrument(skip(client, node), fields(name = %node.name_any()))]
+pub async fn measure_ingest_lag(client: &Client, node: &StellarNode) -> Result<Option<f64>> {
+    let Some((metric, _)) = node
+        .spec
+        .autoscaling
+        .as_ref()
+        .and_then(|a| a.ingest_lag_threshold())
+    else {
+        return Ok(None);
+    };
+
+    let namespace = node.namespace().unwrap_or_else(|| "default".to_string());
+    let lp =
+        ListParams::default().labels(&format!("app.kubernetes.io/instance={}", node.name_any()));
+    let request = kube::core::Request::new(format!(
+        "/apis/custom.metrics.k8s.io/v1beta2/namespaces/{namespace}/pods/*/{metric}"
+    ))
+    .list(&lp)
+    .map_err(|e| Error::KubeError(kube::Error::BuildRequest(e)))?;
+    let list: Value = client.request(request).await.map_err(Error::KubeError)?;
+    let lag = max_metric_value(&list);
+    debug!(
+        "Ingest lag for {}/{} ({}): {:?}s",
+        namespace,
+        node.name_any(),
+        metric,
+        lag
+    );
+    Ok(lag)
+}
+
+/// Record the `IngestLagThrottling` condition
+///
+/// `lag_seconds` is `None` when the metric could not be read; throttling is
+/// then lifted so a missing metric cannot pin the HPA at one replica. Returns
+/// whether throttling just started according to the live status.
+pub async fn update_ingest_lag_status(
+    client: &Client,
+    node: &StellarNode,
+    lag_seconds: Option<f64>,
+    throttling: bool,
+) -> Result<bool> {
+    let max = node
+        .spec
+        .autoscaling
+        .as_ref()
+        .and_then(|a| a.max_ingest_lag_seconds)
+        .unwrap_or_default();
+    let (status, reason, message) = match lag_seconds {
+        Some(lag) if throttling => (
+            conditions::CONDITION_STATUS_TRUE,
+            "IngestLagHigh",
+            format!("Stellar Core is {lag}s behind (max {max}s); HPA minReplicas lowered to 1"),
+        ),
+        Some(lag) => (
+            conditions::CONDITION_STATUS_FALSE,
+            "IngestLagRecovered",
+            format!("Stellar Core is {lag}s behind (max {max}s)"),
+        ),
+        None => (
+            conditions::CONDITION_STATUS_UNKNOWN,
+            "MetricUnavailable",
+            "Ingest lag metric has no samples for this node".to_string(),
+        ),
+    };
+
+    conditions::update_conditions(client, node, json!({}), |node_conditions| {
+        let was_throttling = conditions::is_condition_true(
+            node_conditions,
+            conditions::CONDITION_TYPE_INGEST_LAG_THROTTLING,
+        );
+        conditions::set_condition(
+            node_conditions,
+            conditions::CONDITION_TYPE_INGEST_LAG_THROTTLING,
+            status,
+            reason,
+            &message,
+        );
+        throttling && !was_throttling
+    })
+    .await
+}
+
+/// Whether pods can be scheduled onto `node`: not cordoned and Ready
+pub fn node_schedulable(node: &Node) -> bool {
+    let cordoned = node
+        .spec
+        .as_ref()
+        .and_then(|s| s.unschedulable)
+        .unwrap_or(false);
+    let ready = node
+        .status
+        .as_ref()
+        .and_then(|s| s.conditions.as_ref())
+        .is_some_and(|conditions| {
+            conditions
+                .iter()
+                .any(|c| c.type_ == "Ready" && c.status == "True")
+        });
+    !cordoned && ready
+}
+
+/// Allocatable memory of `node` in bytes
+fn allocatable_memory_bytes(node: &Node) -> f64 {
+    node.status
+        .as_ref()
+        .and_then(|s| s.allocatable.as_ref())
+        .and_then(|a| a.get("memory"))
+        .and_then(|q| quantity_bytes(&q.0))
+        .unwrap_or(0.0)
+}
+
+/// Memory requested by all containers of `pod`, in bytes
+fn requested_memory_bytes(pod: &Pod) -> f64 {
+    pod.spec
+        .iter()
+        .flat_map(|s| s.containers.iter())
+        .filter_map(|c| c.resources.as_ref()?.requests.as_ref()?.get("memory"))
+        .filter_map(|q| quantity_bytes(&q.0))
+        .sum()
+}
+
+/// Replicas the cluster can hold: the ones running plus what free memory fits
+///
+/// Returns `None` when the per-replica request is not positive.
+pub fn capacity_limited_max_replicas(
+    free_memory_bytes: f64,
+    replica_memory_bytes: f64,
+    running_replicas: i32,
+) -> Option<i32> {
+    if replica_memory_bytes <= 0.0 {
+        return None;
+    }
+    let fit = (free_memory_bytes.max(0.0) / replica_memory_bytes).floor();
+    Some(running_replicas.saturating_add(fit.min(f64::from(i32::MAX)) as i32))
+}
+
+/// Compute the capacity cap on the HPA's maxReplicas
+///
+/// Returns `None` unless `clusterCapacityAware` is set. Free memory is the
+/// allocatable memory of schedulable nodes minus the requests of the pods
+/// running on them. This lists every Node and Pod, so the reconciler only
+/// calls it when the HPA is created or the spec changes.
+#[instrument(skip(client, node), fields(name = %node.name_any()))]
+pub async fn compute_capacity_limit(client: &Client, node: &StellarNode) -> Result<Option<i32>> {
+    if !node
+        .spec
+        .autoscaling
+        .as_ref()
+        .is_some_and(|a| a.cluster_capacity_aware)
+    {
+        return Ok(None);
+    }
+    let Some(replica_memory) = quantity_bytes(&node.spec.resources.requests.memory) else {
+        return Ok(None);
+    };
+
+    let nodes: Api<Node> = Api::all(client.clone());
+    let node_list = nodes
+        .list(&ListParams::default())
+        .await
+        .map_err(Error::KubeError)?;
+    let schedulable: BTreeMap<String, f64> = node_list
+        .items
+        .iter()
+        .filter(|n| node_schedulable(n))
+        .map(|n| (n.name_any(), allocatable_memory_bytes(n)))
+        .collect();
+
+    let pods: Api<Pod> = Api::all(client.clone());
+    let pod_list = pods
+        .list(&ListParams::default().fields("status.phase!=Succeeded,status.phase!=Failed"))
+        .await
+        .map_err(Error::KubeError)?;
+
+    let namespace = node.namespace().unwrap_or_else(|| "default".to_string());
+    let mut requested = 0.0;
+    let mut running_replicas = 0;
+    for pod in &pod_list.items {
+        let on_schedulable = pod
+            .spec
+            .as_ref()
+            .and_then(|s| s.node_name.as_ref())
+            .is_some_and(|n| schedulable.contains_key(n));
+        if !on_schedulable {
+            continue;
+        }
+        requested += requested_memory_bytes(pod);
+        if pod.namespace().as_deref() == Some(namespace.as_str())
+            && pod.labels().get("app.kubernetes.io/instance") == Some(&node.name_any())
+        {
+            running_replicas += 1;
+        }
+    }
+
+    let free = schedulable.values().sum::<f64>() - requested;
+    let limit = capacity_limited_max_replicas(free, replica_memory, running_replicas);
+    debug!(
+        "Cluster capacity for {}/{}: {:.0} bytes free across {} nodes, maxReplicas cap {:?}",
+        namespace,
+        node.name_any(),
+        free,
+        schedulable.len(),
+        limit
+    );
+    Ok(limit)
+}
+
+/// Record `status.clusterCapacityLimitedMaxReplicas`; `None` clears it
+pub async fn update_capacity_limit_status(
+    client: &Client,
+    node: &StellarNode,
+    limit: Option<i32>,
+) -> Result<()> {
+    let namespace = node.namespace().unwrap_or_else(|| "default".to_string());
+    let api: Api<StellarNode> = Api::namespaced(client.clone(), &namespace);
+    let patch = json!({ "status": { "clusterCapacityLimitedMaxReplicas": limit } });
+
+    api.patch_status(
+        &node.name_any(),
+        &PatchParams::apply("stellar-operator"),
+        &Patch::Merge(&patch),
+    )
+    .await
+    .map_err(Error::KubeError)?;
+    Ok(())
+}
+
+/// The cron override whose window is open at `now`, if any
+///
+/// Overrides are checked in spec order; the first open window wins.
+pub fn active_scaling_override(
+    node: &StellarNode,
+    now: DateTime<Utc>,
+) -> Option<ActiveScalingOverride> {
+    node.spec
+        .autoscaling
+        .as_ref()?
+        .cron_overrides
+        .iter()
+        .find_map(|o| {
+            let ends_at = o.window_end(now)?;
+            Some(ActiveScalingOverride {
+                cron_expression: o.cron_expression.clone(),
+                min_replicas: o.min_replicas,
+                max_replicas: o.max_replicas,
+                ends_at: ends_at.to_rfc3339(),
+            })
+        })
+}
+
+/// Record `status.activeScalingOverride`; `None` clears it
+pub async fn update_scaling_override_status(
+    client: &Client,
+    node: &StellarNode,
+    active: Option<&ActiveScalingOverride>,
+) -> Result<()> {
+    let namespace = node.namespace().unwrap_or_else(|| "default".to_string());
+    let api: Api<StellarNode> = Api::namespaced(client.clone(), &namespace);
+    let patch = json!({ "status": { "activeScalingOverride": active } });
+
+    api.patch_status(
+        &node.name_any(),
+        &PatchParams::apply("stellar-operator"),
+        &Patch::Merge(&patch),
+    )
+    .await
+    .map_err(Error::KubeError)?;
+    Ok(())
+}
+
+/// Local hour on Friday when the weekend window opens
+const WEEKEND_START_HOUR: u32 = 18;
+
+/// Local hour on Monday when the weekend window closes
+const WEEKEND_END_HOUR: u32 = 9;
+
+/// Whether `now` falls between Friday 18:00 and Monday 09:00 in `time_zone`
+pub fn in_weekend_window(now: DateTime<Utc>, time_zone: Tz) -> bool {
+    let local = now.with_timezone(&time_zone);
+    match local.weekday() {
+        Weekday::Fri => local.hour() >= WEEKEND_START_HOUR,
+        Weekday::Sat | Weekday::Sun => true,
+        Weekday::Mon => local.hour() < WEEKEND_END_HOUR,
+        _ => false,
+    }
+}
+
+/// HPA minReplicas while `spec.autoscaling.disableOnWeekend` applies at `now`
+///
+/// `Some(0)` means the node is suspended for the weekend.
+pub fn weekend_min_replicas(node: &StellarNode, now: DateTime<Utc>) -> Option<u32> {
+    let cfg = node
+        .spec
+        .autoscaling
+        .as_ref()
+        .filter(|a| a.enabled && a.disable_on_weekend)?;
+    let time_zone = cfg.weekend_time_zone().ok()?;
+    in_weekend_window(now, time_zone).then(|| cfg.weekend_min_replicas.unwrap_or_default())
+}
+
+/// Whether the node is suspended for the weekend
+///
+/// Uses `status.weekendSuspensionActive`, so it can be evaluated while building
+/// the workload. Unlike `spec.suspended` this is a scheduled suspension, not
+/// maintenance.
+pub fn weekend_suspended(node: &StellarNode) -> bool {
+    node.status
+        .as_ref()
+        .is_some_and(|s| s.weekend_suspension_active)
+}
+
+/// Record `status.weekendSuspensionActive`
+pub async fn update_weekend_suspension_status(
+    client: &Client,
+    node: &StellarNode,
+    active: bool,
+) -> Result<()> {
+    let namespace = node.namespace().unwrap_or_else(|| "default".to_string());
+    let api: Api<StellarNode> = Api::namespaced(client.clone(), &namespace);
+    let patch = json!({ "status": { "weekendSuspensionActive": active } });
+
+    api.patch_status(
+        &node.name_any(),
+        &PatchParams::apply("stellar-operator"),
+        &Patch::Merge(&patch),
+    )
+    .await
+    .map_err(Error::KubeError)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::crd::CronScalingOverride;
+
+    fn config(weights: &[(&str, u32)]) -> AutoscalingConfig {
+        AutoscalingConfig {
+            min_replicas: 1,
+            max_replicas: 10,
+            target_cpu_utilization_percentage: Some(70),
+            custom_metrics: vec![],
+            behavior: None,
+            disable_on_weekend: false,
+            weekend_min_replicas: None,
+            weekend_timezone: None,
+            cluster_capacity_aware: false,
+            active_connections_metric: None,
+            max_connections_per_replica: None,
+            target_active_connections: None,
+            cron_overrides: vec![],
+            post_deploy_cooldown_seconds: None,
+            ingest_lag_metric: None,
+            max_ingest_lag_seconds: None,
+            enabled: true,
+            instance_weight_annotations: weights.iter().map(|(k, v)| (k.to_string(), *v)).collect(),
+            instance_weight_label: "node.kubernetes.io/instance-type".to_string(),
+            custom_resource_metrics: vec![],
+        }
+    }
+
+    fn test_node() -> StellarNode {
+        let spec = serde_json::from_value(serde_json::json!({
+            "nodeType": "Horizon",
+            "network": "Testnet",
+            "version": "v2.31.0",
+            "horizonConfig": { "databaseSecretRef": "horizon-db" }
+        }))
+        .unwrap();
+        StellarNode::new("horizon-1", spec)
+    }
+
+    fn resource_metric(
+        resource: &str,
+        selector: Option<&[(&str, &str)]>,
+        target_type: TargetType,
+        target_value: &str,
+    ) -> CustomResourceMetricSpec {
+        CustomResourceMetricSpec {
+            group: "queues.example.com/v1".to_string(),
+            resource: resource.to_string(),
+            name: "queue_depth".to_string(),
+            namespace_selector: selector.map(|labels| {
+                labels
+                    .iter()
+                    .map(|(k, v)| (k.to_string(), v.to_string()))
+                    .collect()
+            }),
+            target_type,
+            target_value: target_value.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_custom_resource_metric_object() {
+        let node = test_node();
+        let metric = resource_metric("IngestQueue", None, TargetType::AverageValue, "500m");
+        let spec = custom_resource_metric_spec(&node, &metric);
+
+        assert_eq!(spec.type_, "Object");
+        let object = spec.object.unwrap();
+        assert_eq!(object.described_object.kind, "IngestQueue");
+        assert_eq!(object.described_object.name, "horizon-1");
+        assert_eq!(
+            object.described_object.api_version.as_deref(),
+            Some("queues.example.com/v1")
+        );
+        assert_eq!(object.target.type_, "AverageValue");
+        assert_eq!(
+            object.target.average_value,
+            Some(Quantity("500m".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_custom_resource_metric_object_explicit_name() {
+        let node = test_node();
+        let metric = resource_metric("IngestQueue/shared", None, TargetType::Value, "10");
+        let object = custom_resource_metric_spec(&node, &metric).object.unwrap();
+
+        assert_eq!(object.described_object.name, "shared");
+        assert_eq!(object.target.value, Some(Quantity("10".to_string())));
+    }
+
+    #[test]
+    fn test_custom_resource_metric_external() {
+        let node = test_node();
+        let metric = resource_metric(
+            "IngestQueue",
+            Some(&[("queue", "ingest")]),
+            TargetType::Utilization,
+            "80",
+        );
+        let spec = custom_resource_metric_spec(&node, &metric);
+
+        assert_eq!(spec.type_, "External");
+        let external = spec.external.unwrap();
+        assert_eq!(external.metric.name, "queue_depth");
+        assert_eq!(
+            external.metric.selector.unwrap().match_labels.unwrap()["queue"],
+            "ingest"
+        );
+        assert_eq!(external.target.average_utilization, Some(80));
+    }
+
+    #[test]
+    fn test_active_connections_metric() {
+        let mut cfg = config(&[]);
+        assert!(active_connections_metric_spec(&cfg).is_none());
+
+        cfg.target_active_connections = Some(800);
+        assert!(
+            active_connections_metric_spec(&cfg).is_none(),
+            "needs maxConnectionsPerReplica too"
+        );
+
+        cfg.max_connections_per_replica = Some(1000);
+        let spec = active_connections_metric_spec(&cfg).unwrap();
+        assert_eq!(spec.type_, "External");
+        let external = spec.external.unwrap();
+        assert_eq!(external.metric.name, "nginx_active_connections");
+        assert_eq!(external.target.type_, "AverageValue");
+        assert_eq!(
+            external.target.average_value,
+            Some(Quantity("800".to_string()))
+        );
+
+        cfg.active_connections_metric = Some("horizon_active_connections".to_string());
+        let external = active_connections_metric_spec(&cfg)
+            .unwrap()
+            .external
+            .unwrap();
+        assert_eq!(external.metric.name, "horizon_active_connections");
+    }
+
+    #[test]
+    fn test_capacity_limited_max_replicas() {
+        const GI: f64 = 1024.0 * 1024.0 * 1024.0;
+
+        assert_eq!(
+            capacity_limited_max_replicas(10.5 * GI, 2.0 * GI, 3),
+            Some(8)
+        );
+        assert_eq!(capacity_limited_max_replicas(-GI, 2.0 * GI, 3), Some(3));
+        assert_eq!(capacity_limited_max_replicas(10.0 * GI, 0.0, 3), None);
+    }
+
+    #[test]
+    fn test_cordoned_or_unready_nodes_are_not_schedulable() {
+        use k8s_openapi::api::core::v1::{NodeCondition, NodeSpec, NodeStatus};
+
+        let node = |unschedulable: bool, ready: &str| Node {
+            spec: Some(NodeSpec {
+                unschedulable: Some(unschedulable),
+                ..Default::default()
+            }),
+            status: Some(NodeStatus {
+                conditions: Some(vec![NodeCondition {
+                    type_: "Ready".to_string(),
+                    status: ready.to_string(),
+                    ..Default::default()
+                }]),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+
+        assert!(node_schedulable(&node(false, "True")));
+        assert!(!node_schedulable(&node(true, "True")));
+        assert!(!node_schedulable(&node(false, "False")));
+        assert!(!node_schedulable(&Node::default()));
+    }
+
+    #[test]
+    fn test_weighted_policy_adds_heaviest_pool_capacity() {
+        let cfg = config(&[("large", 4), ("small", 1)]);
+
+        // 2 large + 2 small replicas = 10 units; 40% of 4 replicas is ~1.6
+        // replicas of 2.5 units each, i.e. one large replica's worth
+        let policies = weighted_scale_up_policies(&cfg, Some(10));
+        assert_eq!(policies.len(), 1);
+        assert_eq!(policies[0].type_, "Percent");
+        assert_eq!(policies[0].value, 40);
+
+        // All small replicas: each adds less, so the step is larger
+        let policies = weighted_scale_up_policies(&cfg, Some(4));
+        assert_eq!(policies[0].value, 100);
+    }
+
+    #[test]
+    fn test_weighted_policy_falls_back_to_pods_before_measurement() {
+        let cfg = config(&[("large", 4), ("small", 1)]);
+
+        for units in [None, Some(0)] {
+            let policies = weighted_scale_up_policies(&cfg, units);
+            assert_eq!(policies[0].type_, "Pods");
+            assert_eq!(policies[0].value, 4);
+        }
+        assert!(weighted_scale_up_policies(&config(&[]), Some(10)).is_empty());
+    }
+
+    #[test]
+    fn test_weighted_policies_zero_weight_is_clamped() {
+        let cfg = config(&[("tiny", 0)]);
+        let policies = weighted_scale_up_policies(&cfg, None);
+
+        assert_eq!(policies[0].value, 1);
+    }
+
+    #[test]
+    fn test_node_affinity_prefers_heaviest_pool() {
+        let cfg = config(&[("large", 4), ("small", 1), ("spare", 0)]);
+        let affinity = weighted_node_affinity(&cfg).unwrap();
+        let terms = affinity
+            .preferred_during_scheduling_ignored_during_execution
+            .unwrap();
+
+        let weight_of = |pool: &str| {
+            terms
+                .iter()
+                .find(|t| {
+                    t.preference.match_expressions.as_ref().unwrap()[0].values
+                        == Some(vec![pool.to_string()])
+                })
+                .map(|t| t.weight)
+        };
+        assert_eq!(weight_of("large"), Some(100));
+        assert_eq!(weight_of("small"), Some(25));
+        assert_eq!(weight_of("spare"), None);
+        assert!(weighted_node_affinity(&config(&[("tiny", 0)])).is_none());
+    }
+
+    #[test]
+    fn test_effective_capacity_sums_weights() {
+        let cfg = config(&[("large", 4), ("small", 1)]);
+        let values = vec![
+            Some("large".to_string()),
+            Some("large".to_string()),
+            Some("small".to_string()),
+        ];
+
+        assert_eq!(effective_capacity_units(&cfg, &values), 9);
+    }
+
+    #[test]
+    fn test_effective_capacity_unknown_nodes_count_as_one() {
+        let cfg = config(&[("large", 4)]);
+        let values = vec![Some("medium".to_string()), None];
+
+        assert_eq!(effective_capacity_units(&cfg, &values), 2);
+    }
+
+    fn deployed_at(time: Option<DateTime<Utc>>) -> Deployment {
+        let mut deployment = Deployment::default();
+        if let Some(time) = time {
+            deployment.metadata.annotations = Some(BTreeMap::from([(
+                LAST_DEPLOY_TIME_ANNOTATION.to_string(),
+                time.to_rfc3339(),
+            )]));
+        }
+        deployment
+    }
+
+    #[test]
+    fn test_post_deploy_cooldown_window() {
+        let now = Utc::now();
+        let recent = deployed_at(Some(now - chrono::Duration::seconds(30)));
+        let old = deployed_at(Some(now - chrono::Duration::seconds(300)));
+
+        assert!(post_deploy_cooldown_active(&recent, 120, now));
+        assert!(!post_deploy_cooldown_active(&old, 120, now));
+        // Disabled cooldown and never-deployed workloads are not frozen
+        assert!(!post_deploy_cooldown_active(&recent, 0, now));
+        assert!(!post_deploy_cooldown_active(&deployed_at(None), 120, now));
+    }
+
+    #[test]
+    fn test_post_deploy_cooldown_defaults_to_120s() {
+        assert_eq!(config(&[]).post_deploy_cooldown(), 120);
+    }
+
+    #[test]
+    fn test_parse_metric_quantity() {
+        assert_eq!(parse_metric_quantity("12"), Some(12.0));
+        assert_eq!(parse_metric_quantity("1500m"), Some(1.5));
+        assert_eq!(parse_metric_quantity("2k"), Some(2000.0));
+        assert_eq!(parse_metric_quantity("abc"), None);
+        assert_eq!(parse_metric_quantity(""), None);
+    }
+
+    #[test]
+    fn test_max_metric_value() {
+        let list = json!({
+            "kind": "MetricValueList",
+            "items": [
+                { "describedObject": { "name": "horizon-1-a" }, "value": "4" },
+                { "describedObject": { "name": "horizon-1-b" }, "value": "95500m" },
+                { "describedObject": { "name": "horizon-1-c" }, "value": "bogus" }
+            ]
+        });
+        assert_eq!(max_metric_value(&list), Some(95.5));
+        assert_eq!(max_metric_value(&json!({ "items": [] })), None);
+    }
+
+    #[test]
+    fn test_ingest_lag_exceeded() {
+        let mut cfg = config(&[]);
+        assert!(!ingest_lag_exceeded(&cfg, 1000.0));
+
+        cfg.ingest_lag_metric = Some("stellar_core_ingest_lag_seconds".to_string());
+        cfg.max_ingest_lag_seconds = Some(60);
+        assert!(!ingest_lag_exceeded(&cfg, 60.0));
+        assert!(ingest_lag_exceeded(&cfg, 60.5));
+    }
+
+    fn cron_override(expression: &str, timezone: &str) -> CronScalingOverride {
+        CronScalingOverride {
+            cron_expression: expression.to_string(),
+            min_replicas: 6,
+            max_replicas: 20,
+            duration_minutes: 120,
+            timezone: timezone.to_string(),
+        }
+    }
+
+    fn at(time: &str) -> DateTime<Utc> {
+        time.parse().unwrap()
+    }
+
+    #[test]
+    fn test_cron_override_window() {
+        let o = cron_override("0 14 * * *", "UTC");
+        assert_eq!(o.window_end(at("2024-01-03T13:59:00Z")), None);
+        assert_eq!(
+            o.window_end(at("2024-01-03T15:00:00Z")),
+            Some(at("2024-01-03T16:00:00Z"))
+        );
+        assert_eq!(o.window_end(at("2024-01-03T16:30:00Z")), None);
+
+        // 14:00 in New York is 19:00 UTC in January
+        let o = cron_override("0 14 * * *", "America/New_York");
+        assert_eq!(o.window_end(at("2024-01-03T15:00:00Z")), None);
+        assert_eq!(
+            o.window_end(at("2024-01-03T19:30:00Z")),
+            Some(at("2024-01-03T21:00:00Z"))
+        );
+    }
+
+    #[test]
+    fn test_active_scaling_override() {
+        let mut node = test_node();
+        let mut cfg = config(&[]);
+        cfg.cron_overrides = vec![cron_override("0 14 * * 3", "UTC")];
+        node.spec.autoscaling = Some(cfg);
+
+        // 2024-01-03 is a Wednesday
+        let active = active_scaling_override(&node, at("2024-01-03T15:00:00Z")).unwrap();
+        assert_eq!(active.min_replicas, 6);
+        assert_eq!(active.max_replicas, 20);
+        assert_eq!(active.ends_at, at("2024-01-03T16:00:00Z").to_rfc3339());
+
+        assert_eq!(
+            active_scaling_override(&node, at("2024-01-04T15:00:00Z")),
+            None
+        );
+    }
+
+    #[test]
+    fn test_weekend_window() {
+        // 2024-01-05 is a Friday, 2024-01-08 a Monday
+        assert!(!in_weekend_window(at("2024-01-05T17:59:00Z"), Tz::UTC));
+        assert!(in_weekend_window(at("2024-01-05T18:00:00Z"), Tz::UTC));
+        assert!(in_weekend_window(at("2024-01-07T12:00:00Z"), Tz::UTC));
+        assert!(in_weekend_window(at("2024-01-08T08:59:00Z"), Tz::UTC));
+        assert!(!in_weekend_window(at("2024-01-08T09:00:00Z"), Tz::UTC));
+
+        // Friday 18:00 in New York is 23:00 UTC in January
+        let new_york: Tz = "America/New_York".parse().unwrap();
+        assert!(!in_weekend_window(at("2024-01-05T22:00:00Z"), new_york));
+        assert!(in_weekend_window(at("2024-01-05T23:00:00Z"), new_york));
+    }
+
+    #[test]
+    fn test_weekend_min_replicas() {
+        let mut node = test_node();
+        let mut cfg = config(&[]);
+        node.spec.autoscaling = Some(cfg.clone());
+        assert_eq!(
+            weekend_min_replicas(&node, at("2024-01-06T12:00:00Z")),
+            None
+        );
+
+        cfg.disable_on_weekend = true;
+        node.spec.autoscaling = Some(cfg.clone());
+        assert_eq!(
+            weekend_min_replicas(&node, at("2024-01-06T12:00:00Z")),
+            Some(0)
+        );
+        assert_eq!(
+            weekend_min_replicas(&node, at("2024-01-09T12:00:00Z")),
+            None
+        );
+
+        cfg.weekend_min_replicas = Some(2);
+        node.spec.autoscaling = Some(cfg);
+        assert_eq!(
+            weekend_min_replicas(&node, at("2024-01-06T12:00:00Z")),
+            Some(2)
+        );
+    }
+}
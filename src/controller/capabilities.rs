@@ -0,0 +1,83 @@
+//! Optional cluster capability detection
+//!
+//! Some features depend on cluster add-ons the operator does not install. They
+//! are probed once at startup so those features can report that the add-on is
+//! missing instead of failing on every request. Currently this covers
+//! metrics-server (`metrics.k8s.io`), which backs the node usage endpoint and
+//! CPU-based HPA targets.
+
+use k8s_openapi::apimachinery::pkg::apis::meta::v1::APIGroupList;
+use kube::client::Client;
+use tracing::{info, warn};
+
+/// API group served by metrics-server
+pub const METRICS_API_GROUP: &str = "metrics.k8s.io";
+
+/// Optional add-ons found in the cluster
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ClusterCapabilities {
+    /// metrics-server is registered (`metrics.k8s.io` is served)
+    pub metrics_server: bool,
+}
+
+/// Whether `groups` includes the API group `name`
+pub fn has_api_group(groups: &APIGroupList, name: &str) -> bool {
+    groups.groups.iter().any(|g| g.name == name)
+}
+
+/// Probe the cluster for optional add-ons
+///
+/// A failed discovery request is logged and every add-on is treated as absent.
+pub async fn detect_capabilities(client: &Client) -> ClusterCapabilities {
+    let groups = match client.list_api_groups().await {
+        Ok(groups) => groups,
+        Err(e) => {
+            warn!("API discovery failed; assuming no optional add-ons: {}", e);
+            return ClusterCapabilities::default();
+        }
+    };
+
+    let capabilities = ClusterCapabilities {
+        metrics_server: has_api_group(&groups, METRICS_API_GROUP),
+    };
+    if capabilities.metrics_server {
+        info!("metrics-server detected ({} is served)", METRICS_API_GROUP);
+    } else {
+        warn!(
+            "metrics-server not found ({} is not served); usage reporting is unavailable and CPU-based HPA targets will not scale",
+            METRICS_API_GROUP
+        );
+    }
+    capabilities
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use k8s_openapi::apimachinery::pkg::apis::meta::v1::APIGroup;
+
+    fn groups(names: &[&str]) -> APIGroupList {
+        APIGroupList {
+            groups: names
+                .iter()
+                .map(|name| APIGroup {
+                    name: name.to_string(),
+                    ..Default::default()
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn test_has_api_group() {
+        assert!(has_api_group(
+            &groups(&["apps", METRICS_API_GROUP]),
+            METRICS_API_GROUP
+        ));
+        assert!(!has_api_group(
+            &groups(&["apps", "custom.metrics.k8s.io"]),
+            METRICS_API_GROUP
+        ));
+        assert!(!has_api_group(&groups(&[]), METRICS_API_GROUP));
+    }
+}
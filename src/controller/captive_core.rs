@@ -189,7 +189,10 @@ impl CaptiveCoreConfigBuilder {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::crd::{CaptiveCoreConfig, NodeType, SorobanConfig, StellarNetwork, StellarNodeSpec};
+    use crate::crd::{
+        CaptiveCoreConfig, ConfigTemplateMode, NodeType, SorobanConfig, StellarNetwork,
+        StellarNodeSpec,
+    };
 
     /// Helper to create a test StellarNode with Soroban config
     fn create_test_node(captive_config: CaptiveCoreConfig) -> StellarNode {
@@ -213,12 +216,15 @@ mod tests {
                         cpu: "2".to_string(),
                         memory: "4Gi".to_string(),
                     },
+                    claims: vec![],
                 },
                 storage: crate::crd::StorageConfig {
                     storage_class: "standard".to_string(),
                     size: "100Gi".to_string(),
                     retention_policy: Default::default(),
                     annotations: None,
+                    pvc_labels: None,
+                    resize_grace_period_seconds: None,
                 },
                 validator_config: None,
                 horizon_config: None,
@@ -229,12 +235,28 @@ mod tests {
                     captive_core_structured_config: Some(captive_config),
                     enable_preflight: true,
                     max_events_per_request: 10000,
+                    contract_code_cache_size: None,
+                    contract_code_cache_ttl_seconds: None,
+                    contract_instance_cache_size: None,
+                    debug_rpc_max_body_log_bytes: None,
+                    debug_rpc: false,
+                    persistence_backend: Default::default(),
+                    database_secret_ref: None,
+                    run_migration_at_startup: false,
+                    archival_storage: None,
+                    tx_concurrency: None,
+                    rpc_endpoint: None,
+                    friendly_rpc_url: None,
+                    tx_queue_size: None,
+                    fee_config: None,
+                    stellar_core_ref: None,
                 }),
                 replicas: 2,
                 min_available: None,
                 max_unavailable: None,
                 suspended: false,
                 alerting: false,
+                prometheus_rules: None,
                 database: None,
                 // Added this field to resolve the E0063 error
                 managed_database: None,
@@ -249,6 +271,30 @@ mod tests {
                 dr_config: None,
                 topology_spread_constraints: None,
                 cve_handling: None,
+                image_override: None,
+                image_version_constraint: None,
+                extra_volume_mounts: vec![],
+                service: None,
+                hooks: None,
+                vault: None,
+                lifecycle: None,
+                container_security_context: None,
+                dev_mode: false,
+                allow_mainnet_parameter_override: false,
+                allow_debug_on_mainnet: false,
+                grafana_dashboard: None,
+                precheck_hook: None,
+                auto_detect_node_selector: false,
+                termination_message_policy: None,
+                termination_message_path: None,
+                emit_oom_event_on_kill: true,
+                mesh: None,
+                config_template_mode: ConfigTemplateMode::Static,
+                depends_on: vec![],
+                min_ready_replicas: None,
+                monitoring: None,
+                requeue_jitter_percent: None,
+                failure_policy: None,
                 resource_meta: None,
             },
             status: None,
@@ -0,0 +1,172 @@
+//! Catchup-before-validate gate for new validators
+//!
+//! With `spec.validatorConfig.catchupBeforeValidate`, a validator starts in
+//! `status.validatorMode: CatchingUp`: its config sets `NODE_IS_VALIDATOR=false`
+//! and the pod template carries [`CATCHUP_ANNOTATION`]. Each reconcile reads the
+//! Stellar Core `/info` endpoint; once it reports `Synced!` the mode moves to
+//! `Validating`, which regenerates the config and drops the annotation so the
+//! pods restart as a validator. The gate never closes again for that node.
+
+use kube::{
+    api::{Api, Patch, PatchParams},
+    client::Client,
+    ResourceExt,
+};
+use serde::Deserialize;
+use serde_json::json;
+use tracing::debug;
+
+use crate::crd::{NodeType, StellarNode, ValidatorMode};
+use crate::error::{Error, Result};
+
+/// Pod template annotation marking a validator that is still catching up
+///
+/// Dropping it once synced changes the pod template, which rolls the pods
+/// onto the regenerated, validating config.
+pub const CATCHUP_ANNOTATION: &str = "stellar.org/catching-up";
+
+/// `info.state` reported by Stellar Core once it has caught up
+const SYNCED_STATE: &str = "Synced!";
+
+/// Response of Stellar Core's `/info`
+#[derive(Debug, Default, Deserialize)]
+pub struct InfoResponse {
+    #[serde(default)]
+    pub info: CoreInfo,
+}
+
+/// The parts of `/info` the operator reads
+#[derive(Debug, Default, Deserialize)]
+pub struct CoreInfo {
+    #[serde(default)]
+    pub state: String,
+    /// Build string, e.g. "stellar-core 21.0.0 (a1b2c3d4)"
+    #[serde(default)]
+    pub build: String,
+}
+
+/// Whether the node is a validator using the gate
+///
+/// Warm standbys are non-validating anyway and are promoted explicitly.
+pub fn gate_enabled(node: &StellarNode) -> bool {
+    node.spec.node_type == NodeType::Validator
+        && node
+            .spec
+            .validator_config
+            .as_ref()
+            .is_some_and(|c| c.catchup_before_validate && !c.standby)
+}
+
+/// Whether the node must still run non-validating
+pub fn catching_up(node: &StellarNode) -> bool {
+    gate_enabled(node)
+        && node.status.as_ref().and_then(|s| s.validator_mode) != Some(ValidatorMode::Validating)
+}
+
+/// Whether Stellar Core reports it has caught up with the network
+pub async fn core_synced(
+    http_client: &reqwest::Client,
+    node: &StellarNode,
+    use_tls: bool,
+) -> Result<bool> {
+    let scheme = if use_tls { "https" } else { "http" };
+    let namespace = node.namespace().unwrap_or_else(|| "default".to_string());
+    let url = format!(
+        "{scheme}://{}.{namespace}.svc.cluster.local:11626/info",
+        node.name_any()
+    );
+
+    let response: InfoResponse = http_client
+        .get(&url)
+        .send()
+        .await?
+        .error_for_status()?
+        .json()
+        .await?;
+    debug!(
+        "Stellar Core state of {}/{}: {}",
+        namespace,
+        node.name_any(),
+        response.info.state
+    );
+    Ok(response.info.state == SYNCED_STATE)
+}
+
+/// Record `status.validatorMode`, if it changed
+pub async fn update_validator_mode(
+    client: &Client,
+    node: &StellarNode,
+    mode: ValidatorMode,
+) -> Result<()> {
+    if node.status.as_ref().and_then(|s| s.validator_mode) == Some(mode) {
+        return Ok(());
+    }
+
+    let namespace = node.namespace().unwrap_or_else(|| "default".to_string());
+    let api: Api<StellarNode> = Api::namespaced(client.clone(), &namespace);
+    let patch = json!({ "status": { "validatorMode": mode } });
+    api.patch_status(
+        &node.name_any(),
+        &PatchParams::apply("stellar-operator"),
+        &Patch::Merge(&patch),
+    )
+    .await
+    .map_err(Error::KubeError)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::crd::{StellarNodeSpec, StellarNodeStatus};
+
+    fn validator(catchup_before_validate: bool, standby: bool) -> StellarNode {
+        let spec: StellarNodeSpec = serde_json::from_value(json!({
+            "nodeType": "Validator",
+            "network": "Testnet",
+            "version": "v21.0.0",
+            "validatorConfig": {
+                "seedSecretRef": "validator-seed",
+                "catchupBeforeValidate": catchup_before_validate,
+                "standby": standby,
+            },
+        }))
+        .unwrap();
+        StellarNode::new("validator", spec)
+    }
+
+    #[test]
+    fn test_parses_info_state() {
+        let response: InfoResponse = serde_json::from_value(json!({
+            "info": {
+                "build": "stellar-core 21.0.0",
+                "ledger": { "num": 51234, "age": 3 },
+                "state": "Catching up",
+            },
+        }))
+        .unwrap();
+        assert_eq!(response.info.state, "Catching up");
+        assert_ne!(response.info.state, SYNCED_STATE);
+    }
+
+    #[test]
+    fn test_fresh_validator_is_catching_up() {
+        let mut node = validator(true, false);
+        assert!(catching_up(&node));
+
+        node.status = Some(StellarNodeStatus {
+            validator_mode: Some(ValidatorMode::CatchingUp),
+            ..Default::default()
+        });
+        assert!(catching_up(&node));
+
+        node.status.as_mut().unwrap().validator_mode = Some(ValidatorMode::Validating);
+        assert!(!catching_up(&node));
+    }
+
+    #[test]
+    fn test_gate_off_or_standby_is_not_gated() {
+        assert!(!catching_up(&validator(false, false)));
+        assert!(!catching_up(&validator(true, true)));
+    }
+}
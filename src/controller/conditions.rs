@@ -1,24 +1,61 @@
 //! Condition management helpers following Kubernetes API conventions
 
 use chrono::Utc;
+use kube::{
+    api::{Api, Patch, PatchParams},
+    client::Client,
+    ResourceExt,
+};
+use serde_json::{json, Value};
 
-use crate::crd::Condition;
+use crate::crd::{Condition, StellarNode};
+use crate::error::{Error, Result};
 
 /// Standard condition types following Kubernetes conventions
 pub const CONDITION_TYPE_READY: &str = "Ready";
 pub const CONDITION_TYPE_PROGRESSING: &str = "Progressing";
 pub const CONDITION_TYPE_DEGRADED: &str = "Degraded";
 pub const CONDITION_TYPE_AVAILABLE: &str = "Available";
+pub const CONDITION_TYPE_PAUSED: &str = "Paused";
+pub const CONDITION_TYPE_RECONCILIATION_PAUSED: &str = "ReconciliationPaused";
+pub const CONDITION_TYPE_MIGRATION_FAILED: &str = "MigrationFailed";
+pub const CONDITION_TYPE_PVC_RESIZE_PENDING: &str = "PVCResizePending";
+pub const CONDITION_TYPE_PVC_RESIZE_COMPLETE: &str = "PVCResizeComplete";
+pub const CONDITION_TYPE_STORAGE_PRESSURE: &str = "StoragePressure";
+pub const CONDITION_TYPE_INGEST_LAG_THROTTLING: &str = "IngestLagThrottling";
+pub const CONDITION_TYPE_PROTOCOL_VERSION_OUTDATED: &str = "ProtocolVersionOutdated";
+pub const CONDITION_TYPE_WAITING_FOR_DEPENDENCY: &str = "WaitingForDependency";
+pub const CONDITION_TYPE_DEBUG_RPC_ENABLED: &str = "DebugRPCEnabled";
+pub const CONDITION_TYPE_WAITING_FOR_DATABASE: &str = "WaitingForDatabase";
+pub const CONDITION_TYPE_QUORUM_SLICE_ALERT: &str = "QuorumSliceAlert";
+pub const CONDITION_TYPE_RECONCILE_STOPPED: &str = "ReconcileStopped";
+pub const CONDITION_TYPE_IMAGE_NOT_FOUND: &str = "ImageNotFound";
+pub const CONDITION_TYPE_CORE_VERSION_INCOMPATIBLE: &str = "CoreVersionIncompatible";
 
 /// Standard condition statuses
 pub const CONDITION_STATUS_TRUE: &str = "True";
 pub const CONDITION_STATUS_FALSE: &str = "False";
 pub const CONDITION_STATUS_UNKNOWN: &str = "Unknown";
 
+/// Default for the most conditions kept in `status.conditions`
+///
+/// Every condition type has a single entry, so this is only reached if many
+/// types accumulate; it keeps the status well below etcd's object size limit.
+/// Overridden with `--max-conditions`.
+pub const DEFAULT_MAX_CONDITIONS: usize = 32;
+
+/// Condition types never dropped by [`cap_conditions`]
+const CORE_CONDITION_TYPES: [&str; 3] = [
+    CONDITION_TYPE_READY,
+    CONDITION_TYPE_PROGRESSING,
+    CONDITION_TYPE_DEGRADED,
+];
+
 /// Update or add a condition to the conditions list
 ///
 /// If a condition with the same type exists and has different status/reason/message,
 /// it will be updated with a new transition time. Otherwise, it will be added.
+/// Duplicate entries of the same type are dropped, so each type appears once.
 pub fn set_condition(
     conditions: &mut Vec<Condition>,
     type_: &str,
@@ -39,6 +76,9 @@ pub fn set_condition(
         if should_update_time {
             existing.last_transition_time = now;
         }
+
+        let mut seen = false;
+        conditions.retain(|c| c.type_ != type_ || !std::mem::replace(&mut seen, true));
     } else {
         // Add new condition
         conditions.push(Condition {
@@ -69,6 +109,79 @@ pub fn remove_condition(conditions: &mut Vec<Condition>, type_: &str) {
     conditions.retain(|c| c.type_ != type_);
 }
 
+/// Keep one entry per condition type and at most `max` entries
+///
+/// The first entry of each type is kept. Beyond the cap, the conditions with the
+/// oldest transition time are dropped, except Ready, Progressing and Degraded,
+/// so fewer than three entries are never enforced.
+pub fn cap_conditions(conditions: &mut Vec<Condition>, max: usize) {
+    let mut seen = std::collections::HashSet::new();
+    conditions.retain(|c| seen.insert(c.type_.clone()));
+
+    while conditions.len() > max {
+        // RFC 3339 timestamps in UTC sort chronologically as strings
+        let Some(oldest) = conditions
+            .iter()
+            .enumerate()
+            .filter(|(_, c)| !CORE_CONDITION_TYPES.contains(&c.type_.as_str()))
+            .min_by(|(_, a), (_, b)| a.last_transition_time.cmp(&b.last_transition_time))
+            .map(|(i, _)| i)
+        else {
+            break;
+        };
+        conditions.remove(oldest);
+    }
+}
+
+/// Conditions of the live StellarNode
+///
+/// The object being reconciled is a watch-cache snapshot and misses status
+/// written earlier in the same pass. Falls back to the snapshot's conditions
+/// if the node no longer exists.
+pub async fn live_conditions(client: &Client, node: &StellarNode) -> Result<Vec<Condition>> {
+    let namespace = node.namespace().unwrap_or_else(|| "default".to_string());
+    let api: Api<StellarNode> = Api::namespaced(client.clone(), &namespace);
+    match api.get_status(&node.name_any()).await {
+        Ok(live) => Ok(live.status.map(|s| s.conditions).unwrap_or_default()),
+        Err(kube::Error::Api(e)) if e.code == 404 => Ok(node
+            .status
+            .as_ref()
+            .map(|s| s.conditions.clone())
+            .unwrap_or_default()),
+        Err(e) => Err(Error::KubeError(e)),
+    }
+}
+
+/// Apply `update` to the live conditions and patch them with the `status` fields
+///
+/// A merge patch replaces the whole `conditions` array, so building it from the
+/// reconcile snapshot would drop conditions set earlier in the same pass. Every
+/// writer goes through here and only changes the types it owns. `update` may
+/// return a value derived from the live conditions, e.g. whether a condition
+/// was already set before this write.
+pub async fn update_conditions<T>(
+    client: &Client,
+    node: &StellarNode,
+    mut status: Value,
+    update: impl FnOnce(&mut Vec<Condition>) -> T,
+) -> Result<T> {
+    let mut conditions = live_conditions(client, node).await?;
+    let result = update(&mut conditions);
+    status["conditions"] = json!(conditions);
+
+    let namespace = node.namespace().unwrap_or_else(|| "default".to_string());
+    let api: Api<StellarNode> = Api::namespaced(client.clone(), &namespace);
+    api.patch_status(
+        &node.name_any(),
+        &PatchParams::apply("stellar-operator"),
+        &Patch::Merge(&json!({ "status": status })),
+    )
+    .await
+    .map_err(Error::KubeError)?;
+
+    Ok(result)
+}
+
 /// Create a Ready=True condition
 pub fn ready_condition(reason: &str, message: &str) -> Condition {
     Condition {
@@ -186,6 +299,106 @@ mod tests {
         assert_ne!(conditions[0].last_transition_time, old_time); // Time should change when status changes
     }
 
+    #[test]
+    fn test_repeated_transitions_keep_one_entry_per_type() {
+        let mut conditions = Vec::new();
+        for i in 0..100 {
+            let status = if i % 2 == 0 {
+                CONDITION_STATUS_TRUE
+            } else {
+                CONDITION_STATUS_FALSE
+            };
+            set_condition(&mut conditions, CONDITION_TYPE_READY, status, "Flap", "");
+            set_condition(
+                &mut conditions,
+                CONDITION_TYPE_STORAGE_PRESSURE,
+                status,
+                "Flap",
+                "",
+            );
+        }
+        assert_eq!(conditions.len(), 2);
+    }
+
+    #[test]
+    fn test_set_condition_collapses_duplicates() {
+        let mut conditions = vec![
+            ready_condition("Healthy", "All good"),
+            degraded_condition("Lagging", "Behind"),
+            ready_condition("Healthy", "Duplicate"),
+        ];
+        set_condition(
+            &mut conditions,
+            CONDITION_TYPE_READY,
+            CONDITION_STATUS_FALSE,
+            "NotHealthy",
+            "Node not ready",
+        );
+
+        assert_eq!(conditions.len(), 2);
+        assert_eq!(conditions[0].status, CONDITION_STATUS_FALSE);
+    }
+
+    fn custom_conditions(count: usize) -> Vec<Condition> {
+        (0..count)
+            .map(|i| Condition {
+                type_: format!("Custom{i}"),
+                status: CONDITION_STATUS_TRUE.to_string(),
+                last_transition_time: format!("2024-01-01T00:{:02}:{:02}Z", i / 60, i % 60),
+                reason: "Test".to_string(),
+                message: String::new(),
+                observed_generation: None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_cap_conditions_bounds_length_and_keeps_core_types() {
+        let mut conditions = vec![
+            ready_condition("Healthy", "All good"),
+            ready_condition("Healthy", "Duplicate"),
+        ];
+        conditions.extend(custom_conditions(DEFAULT_MAX_CONDITIONS + 10));
+        conditions[0].last_transition_time = "2000-01-01T00:00:00Z".to_string();
+
+        cap_conditions(&mut conditions, DEFAULT_MAX_CONDITIONS);
+
+        assert_eq!(conditions.len(), DEFAULT_MAX_CONDITIONS);
+        assert!(find_condition(&conditions, CONDITION_TYPE_READY).is_some());
+        assert!(find_condition(&conditions, "Custom0").is_none());
+        assert!(find_condition(
+            &conditions,
+            &format!("Custom{}", DEFAULT_MAX_CONDITIONS + 9)
+        )
+        .is_some());
+    }
+
+    #[test]
+    fn test_cap_conditions_configured_boundary() {
+        // Exactly at the limit: nothing is dropped
+        let mut conditions = custom_conditions(5);
+        cap_conditions(&mut conditions, 5);
+        assert_eq!(conditions.len(), 5);
+
+        // One over: only the oldest goes
+        let mut conditions = custom_conditions(6);
+        cap_conditions(&mut conditions, 5);
+        assert_eq!(conditions.len(), 5);
+        assert!(find_condition(&conditions, "Custom0").is_none());
+        assert!(find_condition(&conditions, "Custom1").is_some());
+    }
+
+    #[test]
+    fn test_cap_conditions_never_drops_core_types() {
+        let mut conditions = vec![ready_condition("Healthy", "All good")];
+        conditions.extend(custom_conditions(3));
+
+        cap_conditions(&mut conditions, 0);
+
+        assert_eq!(conditions.len(), 1);
+        assert!(find_condition(&conditions, CONDITION_TYPE_READY).is_some());
+    }
+
     #[test]
     fn test_is_condition_true() {
         let conditions = vec![ready_condition("Healthy", "All good")];
@@ -205,4 +418,63 @@ mod tests {
         assert!(find_condition(&conditions, CONDITION_TYPE_PROGRESSING).is_some());
         assert!(find_condition(&conditions, CONDITION_TYPE_DEGRADED).is_none());
     }
+
+    #[tokio::test]
+    async fn test_update_conditions_merges_into_live_conditions() {
+        let server = crate::controller::fake_api::FakeApiServer::start().await;
+        let client = server.client();
+        let spec = serde_json::from_value(json!({
+            "nodeType": "Validator",
+            "network": "Testnet",
+            "version": "v21.0.0",
+            "validatorConfig": { "seedSecretRef": "validator-seed" },
+        }))
+        .unwrap();
+        let mut node = StellarNode::new("validator", spec);
+        node.metadata.namespace = Some("default".to_string());
+        let path = "/apis/stellar.org/v1alpha1/namespaces/default/stellarnodes/validator";
+        server.insert(path, &node);
+
+        // Written earlier in the same pass; `node` is a snapshot that predates it
+        update_conditions(&client, &node, json!({}), |c| {
+            set_condition(
+                c,
+                CONDITION_TYPE_PVC_RESIZE_PENDING,
+                CONDITION_STATUS_TRUE,
+                "ResizeInProgress",
+                "",
+            )
+        })
+        .await
+        .unwrap();
+
+        let was_set = update_conditions(
+            &client,
+            &node,
+            json!({ "lastMigratedVersion": "v21.0.0" }),
+            |c| {
+                let was_set = is_condition_true(c, CONDITION_TYPE_PVC_RESIZE_PENDING);
+                set_condition(
+                    c,
+                    CONDITION_TYPE_PAUSED,
+                    CONDITION_STATUS_TRUE,
+                    "Paused",
+                    "",
+                );
+                was_set
+            },
+        )
+        .await
+        .unwrap();
+        assert!(was_set);
+
+        let live: StellarNode = server.get(path);
+        let status = live.status.unwrap();
+        assert_eq!(status.last_migrated_version.as_deref(), Some("v21.0.0"));
+        assert!(is_condition_true(&status.conditions, CONDITION_TYPE_PAUSED));
+        assert!(is_condition_true(
+            &status.conditions,
+            CONDITION_TYPE_PVC_RESIZE_PENDING
+        ));
+    }
 }
@@ -0,0 +1,207 @@
+//! Templated ConfigMap values
+//!
+//! With `spec.configTemplateMode: GoTemplate`, every value of the node's
+//! ConfigMap is rendered as a Go template before it is applied, so values can
+//! be derived from the node itself, e.g.
+//! `http://{{ .node.name }}-core.{{ .node.namespace }}:11626`. The context
+//! exposes `.node.name`, `.node.namespace` and the whole spec as `.node.spec`
+//! (camelCase field names, as written in the manifest).
+
+use std::collections::HashMap;
+use std::sync::mpsc;
+use std::time::Duration;
+
+use gtmpl::Value;
+use k8s_openapi::api::core::v1::ConfigMap;
+use kube::ResourceExt;
+
+use crate::crd::{ConfigTemplateMode, StellarNode, StellarNodeSpec};
+use crate::error::{Error, Result};
+
+/// How long one value may take to render
+const RENDER_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// Convert a JSON value into a template value
+fn template_value(value: serde_json::Value) -> Value {
+    match value {
+        serde_json::Value::Null => Value::Nil,
+        serde_json::Value::Bool(b) => Value::Bool(b),
+        serde_json::Value::Number(n) => match (n.as_i64(), n.as_u64(), n.as_f64()) {
+            (Some(i), _, _) => Value::from(i),
+            (_, Some(u), _) => Value::from(u),
+            (_, _, Some(f)) => Value::from(f),
+            _ => Value::Nil,
+        },
+        serde_json::Value::String(s) => Value::String(s),
+        serde_json::Value::Array(items) => {
+            Value::Array(items.into_iter().map(template_value).collect())
+        }
+        serde_json::Value::Object(fields) => Value::Object(
+            fields
+                .into_iter()
+                .map(|(k, v)| (k, template_value(v)))
+                .collect(),
+        ),
+    }
+}
+
+/// Template context: `.node.name`, `.node.namespace` and `.node.spec`
+pub fn template_context(node: &StellarNode) -> Result<Value> {
+    let spec = serde_json::to_value(&node.spec)?;
+    let node_value = HashMap::from([
+        ("name".to_string(), Value::String(node.name_any())),
+        (
+            "namespace".to_string(),
+            Value::String(node.namespace().unwrap_or_else(|| "default".to_string())),
+        ),
+        ("spec".to_string(), template_value(spec)),
+    ]);
+    Ok(Value::Object(HashMap::from([(
+        "node".to_string(),
+        Value::Object(node_value),
+    )])))
+}
+
+/// Check that every `{{` is closed by a `}}` before the next action opens
+///
+/// gtmpl never returns on an unterminated action, so this has to be rejected
+/// before the value reaches it.
+fn check_delimiters(value: &str) -> std::result::Result<(), String> {
+    let mut rest = value;
+    while let Some(open) = rest.find("{{") {
+        let action = &rest[open + 2..];
+        let close = action.find("}}").ok_or_else(|| {
+            format!(
+                "unclosed action at byte {}",
+                value.len() - rest.len() + open
+            )
+        })?;
+        if let Some(nested) = action[..close].find("{{") {
+            return Err(format!(
+                "unclosed action at byte {}",
+                value.len() - action.len() + nested - 2
+            ));
+        }
+        rest = &action[close + 2..];
+    }
+    Ok(())
+}
+
+/// Render one value; values without `{{` are returned unchanged
+pub fn render_value(key: &str, value: &str, context: &Value) -> Result<String> {
+    if !value.contains("{{") {
+        return Ok(value.to_string());
+    }
+    check_delimiters(value)
+        .map_err(|e| Error::TemplateSyntaxError(format!("ConfigMap key {key}: {e}")))?;
+
+    // gtmpl can still spin on malformed input the delimiter check lets
+    // through; render on a separate thread so a stuck template only leaks
+    // that thread instead of wedging the reconcile
+    let (tx, rx) = mpsc::channel();
+    let (template, context) = (value.to_string(), context.clone());
+    std::thread::spawn(move || {
+        let _ = tx.send(gtmpl::template(&template, context));
+    });
+    match rx.recv_timeout(RENDER_TIMEOUT) {
+        Ok(rendered) => {
+            rendered.map_err(|e| Error::TemplateSyntaxError(format!("ConfigMap key {key}: {e}")))
+        }
+        Err(_) => Err(Error::TemplateSyntaxError(format!(
+            "ConfigMap key {key}: rendering did not finish within {}s",
+            RENDER_TIMEOUT.as_secs()
+        ))),
+    }
+}
+
+/// Render the ConfigMap values according to `spec.configTemplateMode`
+pub fn render_config_map(node: &StellarNode, cm: &mut ConfigMap) -> Result<()> {
+    match node.spec.config_template_mode {
+        ConfigTemplateMode::Static => Ok(()),
+        ConfigTemplateMode::GoTemplate => {
+            let context = template_context(node)?;
+            for (key, value) in cm.data.iter_mut().flatten() {
+                *value = render_value(key, value, &context)?;
+            }
+            Ok(())
+        }
+    }
+}
+
+/// Render the ConfigMap `spec` would produce, discarding the result
+///
+/// Used by spec validation so a broken template is reported as
+/// `SpecValidationFailed` before any resource is applied.
+pub fn check_spec_templates(spec: &StellarNodeSpec) -> Result<()> {
+    if spec.config_template_mode == ConfigTemplateMode::Static {
+        return Ok(());
+    }
+    let mut node = StellarNode::new("validation", spec.clone());
+    node.metadata.namespace = Some("default".to_string());
+    let mut cm = super::resources::build_config_map(&node, None, false);
+    render_config_map(&node, &mut cm)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::crd::StellarNodeSpec;
+    use serde_json::json;
+
+    fn horizon(mode: &str) -> StellarNode {
+        let spec: StellarNodeSpec = serde_json::from_value(json!({
+            "nodeType": "Horizon",
+            "network": "Testnet",
+            "version": "v2.31.0",
+            "replicas": 2,
+            "configTemplateMode": mode,
+            "horizonConfig": { "databaseSecretRef": "horizon-db" },
+        }))
+        .unwrap();
+        let mut node = StellarNode::new("horizon-1", spec);
+        node.metadata.namespace = Some("stellar".to_string());
+        node
+    }
+
+    fn config_map(value: &str) -> ConfigMap {
+        ConfigMap {
+            data: Some([("STELLAR_CORE_URL".to_string(), value.to_string())].into()),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_go_template_renders_node_fields() {
+        let node = horizon("GoTemplate");
+        let mut cm = config_map(
+            "http://{{ .node.name }}-core.{{ .node.namespace }}:11626?r={{ .node.spec.replicas }}",
+        );
+        render_config_map(&node, &mut cm).unwrap();
+        assert_eq!(
+            cm.data.unwrap()["STELLAR_CORE_URL"],
+            "http://horizon-1-core.stellar:11626?r=2"
+        );
+    }
+
+    #[test]
+    fn test_static_mode_leaves_values_untouched() {
+        let mut cm = config_map("{{ .node.name }}");
+        render_config_map(&horizon("Static"), &mut cm).unwrap();
+        assert_eq!(cm.data.unwrap()["STELLAR_CORE_URL"], "{{ .node.name }}");
+    }
+
+    #[test]
+    fn test_template_syntax_error() {
+        let mut cm = config_map("{{ .node.name ");
+        let err = render_config_map(&horizon("GoTemplate"), &mut cm).unwrap_err();
+        assert!(matches!(err, Error::TemplateSyntaxError(_)));
+        assert!(err.to_string().contains("STELLAR_CORE_URL"));
+    }
+
+    #[test]
+    fn test_nested_unclosed_action_is_rejected() {
+        let mut cm = config_map("{{ .node.name {{ .node.namespace }}");
+        let err = render_config_map(&horizon("GoTemplate"), &mut cm).unwrap_err();
+        assert!(matches!(err, Error::TemplateSyntaxError(_)));
+    }
+}
@@ -0,0 +1,233 @@
+//! Stellar Core version compatibility check for Horizon
+//!
+//! A Horizon release may require a minimum Stellar Core version; ingesting
+//! from an older Core can corrupt its database. With
+//! `spec.horizonConfig.minStellarCoreVersion` set, each reconcile reads the
+//! `build` field of the Core `/info` endpoint and records it in
+//! `status.stellarCoreVersion`. While it is older than the minimum, Horizon is
+//! scaled to 0 and the `CoreVersionIncompatible` condition is set; both are
+//! lifted once Core is upgraded.
+
+use std::time::Duration;
+
+use kube::{client::Client, ResourceExt};
+use serde_json::json;
+use tracing::debug;
+
+use crate::crd::{parse_image_version, NodeType, StellarNode};
+use crate::error::Result;
+
+use super::catchup_gate::InfoResponse;
+use super::conditions;
+
+/// Timeout for the Stellar Core `/info` request
+const CORE_INFO_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Minimum Stellar Core version required by a Horizon node, if configured
+pub fn min_stellar_core_version(node: &StellarNode) -> Option<&str> {
+    if node.spec.node_type != NodeType::Horizon {
+        return None;
+    }
+    node.spec
+        .horizon_config
+        .as_ref()
+        .filter(|c| c.captive_core.is_none())
+        .and_then(|c| c.min_stellar_core_version.as_deref())
+}
+
+/// Extract the version from a Stellar Core `build` string
+///
+/// Accepts forms such as "stellar-core 21.0.0 (a1b2c3d)" or "v21.0.0-12-gabcdef".
+/// The suffix is dropped: for `git describe` builds it counts commits past the
+/// release, which semver would otherwise order before it.
+pub fn parse_core_build(build: &str) -> Option<semver::Version> {
+    let mut version = build
+        .split_whitespace()
+        .filter(|token| {
+            token
+                .trim_start_matches('v')
+                .starts_with(|c: char| c.is_ascii_digit())
+        })
+        .find_map(parse_image_version)?;
+    version.pre = semver::Prerelease::EMPTY;
+    Some(version)
+}
+
+/// Whether `build` is older than `minimum`
+///
+/// Unparseable versions are never reported as incompatible, so an unusual
+/// build string does not take Horizon down.
+pub fn core_older_than(build: &str, minimum: &str) -> bool {
+    match (parse_core_build(build), parse_image_version(minimum)) {
+        (Some(build), Some(minimum)) => build < minimum,
+        _ => false,
+    }
+}
+
+/// Whether the Core version last recorded in `status.stellarCoreVersion` is
+/// older than the node requires
+///
+/// Evaluated while building the workload and deciding whether to manage the HPA.
+pub fn core_version_incompatible(node: &StellarNode) -> bool {
+    let recorded = node
+        .status
+        .as_ref()
+        .and_then(|s| s.stellar_core_version.as_deref());
+    match (recorded, min_stellar_core_version(node)) {
+        (Some(build), Some(minimum)) => core_older_than(build, minimum),
+        _ => false,
+    }
+}
+
+/// Fetch the `build` string reported by the Stellar Core Horizon ingests from
+///
+/// Returns `Ok(None)` when the check is not enabled for the node.
+pub async fn fetch_core_build(
+    http_client: &reqwest::Client,
+    node: &StellarNode,
+) -> Result<Option<String>> {
+    if min_stellar_core_version(node).is_none() {
+        return Ok(None);
+    }
+    let namespace = node.namespace().unwrap_or_else(|| "default".to_string());
+    let Some(core_url) = node
+        .spec
+        .resolved_stellar_core_url(&namespace)
+        .filter(|u| !u.trim().is_empty())
+    else {
+        return Ok(None);
+    };
+    let url = format!("{}/info", core_url.trim_end_matches('/'));
+
+    let response: InfoResponse = http_client
+        .get(&url)
+        .timeout(CORE_INFO_TIMEOUT)
+        .send()
+        .await?
+        .error_for_status()?
+        .json()
+        .await?;
+    debug!("Stellar Core build from {}: {}", url, response.info.build);
+    Ok(Some(response.info.build))
+}
+
+/// Record `status.stellarCoreVersion` and the `CoreVersionIncompatible` condition
+///
+/// Returns whether the live status reported the Core version as incompatible
+/// before this update, so callers can tell transitions apart.
+pub async fn update_core_version_status(
+    client: &Client,
+    node: &StellarNode,
+    build: &str,
+) -> Result<bool> {
+    let minimum = min_stellar_core_version(node).unwrap_or_default();
+
+    let status = json!({ "stellarCoreVersion": build });
+    conditions::update_conditions(client, node, status, |node_conditions| {
+        let was_incompatible = conditions::is_condition_true(
+            node_conditions,
+            conditions::CONDITION_TYPE_CORE_VERSION_INCOMPATIBLE,
+        );
+        if core_older_than(build, minimum) {
+            conditions::set_condition(
+                node_conditions,
+                conditions::CONDITION_TYPE_CORE_VERSION_INCOMPATIBLE,
+                conditions::CONDITION_STATUS_TRUE,
+                "CoreTooOld",
+                &format!(
+                    "Stellar Core reports '{build}' but Horizon requires at least {minimum}; scaled to 0 until Core is upgraded"
+                ),
+            );
+        } else {
+            conditions::set_condition(
+                node_conditions,
+                conditions::CONDITION_TYPE_CORE_VERSION_INCOMPATIBLE,
+                conditions::CONDITION_STATUS_FALSE,
+                "CoreVersionSupported",
+                &format!("Stellar Core reports '{build}'; Horizon requires at least {minimum}"),
+            );
+        }
+        was_incompatible
+    })
+    .await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::crd::{StellarNodeSpec, StellarNodeStatus};
+
+    fn horizon(minimum: Option<&str>, recorded: Option<&str>) -> StellarNode {
+        let spec: StellarNodeSpec = serde_json::from_value(json!({
+            "nodeType": "Horizon",
+            "network": "Testnet",
+            "version": "v2.30.0",
+            "horizonConfig": {
+                "databaseSecretRef": "horizon-db",
+                "stellarCoreUrl": "http://core:11626",
+                "minStellarCoreVersion": minimum,
+            },
+        }))
+        .unwrap();
+        let mut node = StellarNode::new("horizon", spec);
+        node.status = Some(StellarNodeStatus {
+            stellar_core_version: recorded.map(str::to_string),
+            ..Default::default()
+        });
+        node
+    }
+
+    #[test]
+    fn test_parse_core_build() {
+        assert_eq!(
+            parse_core_build("stellar-core 21.0.0 (a1b2c3d4)"),
+            Some(semver::Version::new(21, 0, 0))
+        );
+        assert_eq!(
+            parse_core_build("v20.4.1"),
+            Some(semver::Version::new(20, 4, 1))
+        );
+        assert_eq!(
+            parse_core_build("stellar-core v21.0.0-12-gabcdef (abcdef)"),
+            Some(semver::Version::new(21, 0, 0))
+        );
+        assert_eq!(parse_core_build("stellar-core dev"), None);
+    }
+
+    #[test]
+    fn test_core_older_than_minimum() {
+        assert!(core_older_than("stellar-core 20.4.0", "21.0.0"));
+        assert!(!core_older_than("stellar-core 21.0.0", "21.0.0"));
+        assert!(!core_older_than("stellar-core 22.1.0", "v21"));
+        assert!(!core_older_than("stellar-core dev", "21.0.0"));
+    }
+
+    #[test]
+    fn test_core_version_incompatible_uses_recorded_status() {
+        assert!(core_version_incompatible(&horizon(
+            Some("21.0.0"),
+            Some("stellar-core 20.0.0")
+        )));
+        assert!(!core_version_incompatible(&horizon(
+            Some("21.0.0"),
+            Some("stellar-core 21.1.0")
+        )));
+        assert!(!core_version_incompatible(&horizon(Some("21.0.0"), None)));
+        assert!(!core_version_incompatible(&horizon(
+            None,
+            Some("stellar-core 20.0.0")
+        )));
+    }
+
+    #[test]
+    fn test_parses_info_build() {
+        let response: InfoResponse = serde_json::from_value(json!({
+            "info": {
+                "build": "stellar-core 21.0.0 (a1b2c3d4)",
+                "state": "Synced!",
+            },
+        }))
+        .unwrap();
+        assert_eq!(response.info.build, "stellar-core 21.0.0 (a1b2c3d4)");
+    }
+}
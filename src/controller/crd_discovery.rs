@@ -0,0 +1,100 @@
+//! Cached discovery of optional third-party CRDs
+//!
+//! Prometheus Operator and cert-manager resources are only managed when their
+//! CRDs are served. Asking the API server on every reconcile of every node is
+//! wasteful, so each answer is reused for [`CRD_DISCOVERY_TTL`]; a CRD installed
+//! later is picked up once the entry expires. Failed lookups are not cached.
+
+use std::collections::BTreeMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use kube::client::Client;
+
+use crate::controller::api_timeout::with_timeout;
+use crate::error::{Error, Result};
+
+/// How long a discovery result is reused before the API server is asked again
+pub const CRD_DISCOVERY_TTL: Duration = Duration::from_secs(300);
+
+/// (group/version, kind) -> (checked at, served)
+static CACHE: Mutex<BTreeMap<(String, String), (Instant, bool)>> = Mutex::new(BTreeMap::new());
+
+/// Whether `kind` is served under `group_version` (e.g. "monitoring.coreos.com/v1")
+pub async fn kind_served(client: &Client, group_version: &str, kind: &str) -> Result<bool> {
+    let key = (group_version.to_string(), kind.to_string());
+    if let Some((checked_at, served)) = CACHE.lock().unwrap().get(&key) {
+        if checked_at.elapsed() < CRD_DISCOVERY_TTL {
+            return Ok(*served);
+        }
+    }
+
+    let served = match with_timeout(
+        &format!("discover {group_version}"),
+        client.list_api_group_resources(group_version),
+    )
+    .await
+    {
+        Ok(list) => list.resources.iter().any(|r| r.kind == kind),
+        Err(Error::KubeError(kube::Error::Api(e))) if e.code == 404 => false,
+        Err(e) => return Err(e),
+    };
+
+    CACHE.lock().unwrap().insert(key, (Instant::now(), served));
+    Ok(served)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::controller::fake_api::FakeApiServer;
+    use serde_json::json;
+
+    fn resource_list(kinds: &[&str]) -> serde_json::Value {
+        json!({
+            "kind": "APIResourceList",
+            "apiVersion": "v1",
+            "groupVersion": "discovery-test.example.com/v1",
+            "resources": kinds.iter().map(|kind| json!({
+                "name": format!("{}s", kind.to_lowercase()),
+                "singularName": kind.to_lowercase(),
+                "namespaced": true,
+                "kind": kind,
+                "verbs": ["get", "list"],
+            })).collect::<Vec<_>>(),
+        })
+    }
+
+    #[tokio::test]
+    async fn test_result_is_reused_within_ttl() {
+        let server = FakeApiServer::start().await;
+        let client = server.client();
+        let path = "/apis/discovery-test.example.com/v1";
+
+        server.insert(path, resource_list(&["Widget"]));
+        assert!(
+            kind_served(&client, "discovery-test.example.com/v1", "Widget")
+                .await
+                .unwrap()
+        );
+
+        // Removing the CRD is not noticed until the entry expires
+        server.insert(path, resource_list(&[]));
+        assert!(
+            kind_served(&client, "discovery-test.example.com/v1", "Widget")
+                .await
+                .unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_missing_group_is_not_served() {
+        let server = FakeApiServer::start().await;
+
+        assert!(
+            !kind_served(&server.client(), "missing-test.example.com/v1", "Widget")
+                .await
+                .unwrap()
+        );
+    }
+}
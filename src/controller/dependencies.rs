@@ -0,0 +1,200 @@
+//! Ordering between StellarNodes
+//!
+//! `spec.dependsOn` lists StellarNodes that must be Ready (healthy and synced)
+//! before this node's workload is created, e.g. the validator a Horizon node
+//! ingests from. Until then the reconciler sets the `WaitingForDependency`
+//! condition and requeues; the condition is removed once every dependency is
+//! Ready.
+
+use kube::{api::Api, client::Client, ResourceExt};
+use serde_json::json;
+use tracing::{debug, instrument};
+
+use crate::crd::{StellarNode, StellarNodeRef};
+use crate::error::{Error, Result};
+
+use super::conditions;
+
+/// Whether a dependency is Ready: healthy, synced and with all replicas up
+#[allow(deprecated)]
+pub fn dependency_ready(dependency: &StellarNode) -> bool {
+    dependency
+        .status
+        .as_ref()
+        .is_some_and(|s| s.phase == "Ready" || s.is_ready())
+}
+
+/// Why `dependency` blocks `node`, or `None` when it is satisfied
+///
+/// `found` is the referenced StellarNode, if it exists.
+pub fn unmet_reason(
+    node: &StellarNode,
+    dependency: &StellarNodeRef,
+    found: Option<&StellarNode>,
+) -> Option<String> {
+    let namespace = node.namespace().unwrap_or_else(|| "default".to_string());
+    let dep_namespace = dependency.namespace.as_deref().unwrap_or(&namespace);
+    let key = format!("{}/{}", dep_namespace, dependency.name);
+
+    if dep_namespace == namespace && dependency.name == node.name_any() {
+        return Some(format!("{key} (the node itself)"));
+    }
+    match found {
+        None => Some(format!("{key} (not found)")),
+        Some(dep) if !dependency_ready(dep) => {
+            #[allow(deprecated)]
+            let phase = dep.status.as_ref().map(|s| s.phase.as_str());
+            Some(format!("{key} (phase {})", phase.unwrap_or("Pending")))
+        }
+        Some(_) => None,
+    }
+}
+
+/// Dependencies of `node` that are not Ready yet, with the reason for each
+#[instrument(skip(client, node), fields(name = %node.name_any()))]
+pub async fn unmet_dependencies(client: &Client, node: &StellarNode) -> Result<Vec<String>> {
+    let namespace = node.namespace().unwrap_or_else(|| "default".to_string());
+    let mut unmet = Vec::new();
+    for dependency in &node.spec.depends_on {
+        let dep_namespace = dependency.namespace.as_deref().unwrap_or(&namespace);
+        let api: Api<StellarNode> = Api::namespaced(client.clone(), dep_namespace);
+        let found = api
+            .get_opt(&dependency.name)
+            .await
+            .map_err(Error::KubeError)?;
+        unmet.extend(unmet_reason(node, dependency, found.as_ref()));
+    }
+    debug!(
+        "Unmet dependencies of {}/{}: {:?}",
+        namespace,
+        node.name_any(),
+        unmet
+    );
+    Ok(unmet)
+}
+
+/// Set `WaitingForDependency` while `unmet` is non-empty and remove it after
+///
+/// Returns whether the node started waiting with this call, judged from the
+/// live conditions so the event is emitted once per wait.
+pub async fn update_dependency_condition(
+    client: &Client,
+    node: &StellarNode,
+    unmet: &[String],
+) -> Result<bool> {
+    let snapshot = node
+        .status
+        .as_ref()
+        .map(|s| s.conditions.as_slice())
+        .unwrap_or_default();
+    let existing =
+        conditions::find_condition(snapshot, conditions::CONDITION_TYPE_WAITING_FOR_DEPENDENCY);
+    let message = format!("Waiting for {}", unmet.join(", "));
+    let unchanged = if unmet.is_empty() {
+        existing.is_none()
+    } else {
+        existing.is_some_and(|c| c.message == message)
+    };
+    if unchanged {
+        return Ok(false);
+    }
+
+    conditions::update_conditions(client, node, json!({}), |node_conditions| {
+        let was_waiting = conditions::find_condition(
+            node_conditions,
+            conditions::CONDITION_TYPE_WAITING_FOR_DEPENDENCY,
+        )
+        .is_some();
+        if unmet.is_empty() {
+            conditions::remove_condition(
+                node_conditions,
+                conditions::CONDITION_TYPE_WAITING_FOR_DEPENDENCY,
+            );
+        } else {
+            conditions::set_condition(
+                node_conditions,
+                conditions::CONDITION_TYPE_WAITING_FOR_DEPENDENCY,
+                conditions::CONDITION_STATUS_TRUE,
+                "DependencyNotReady",
+                &message,
+            );
+        }
+        !was_waiting && !unmet.is_empty()
+    })
+    .await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::crd::{StellarNodeSpec, StellarNodeStatus};
+    use serde_json::json;
+
+    fn stellar_node(name: &str, spec: serde_json::Value) -> StellarNode {
+        let spec: StellarNodeSpec = serde_json::from_value(spec).unwrap();
+        let mut node = StellarNode::new(name, spec);
+        node.metadata.namespace = Some("stellar".to_string());
+        node
+    }
+
+    fn horizon() -> StellarNode {
+        stellar_node(
+            "horizon",
+            json!({
+                "nodeType": "Horizon",
+                "network": "Testnet",
+                "version": "v2.31.0",
+                "horizonConfig": { "databaseSecretRef": "horizon-db" },
+                "dependsOn": [{ "name": "core" }],
+            }),
+        )
+    }
+
+    fn core(phase: &str) -> StellarNode {
+        let mut core = stellar_node(
+            "core",
+            json!({
+                "nodeType": "Validator",
+                "network": "Testnet",
+                "version": "v21.0.0",
+                "validatorConfig": { "seedSecretRef": "core-seed" },
+            }),
+        );
+        #[allow(deprecated)]
+        let status = StellarNodeStatus::with_phase(phase);
+        core.status = Some(status);
+        core
+    }
+
+    #[test]
+    fn test_horizon_waits_for_core() {
+        let horizon = horizon();
+        let dependency = &horizon.spec.depends_on[0];
+
+        assert_eq!(
+            unmet_reason(&horizon, dependency, None).as_deref(),
+            Some("stellar/core (not found)")
+        );
+        assert_eq!(
+            unmet_reason(&horizon, dependency, Some(&core("Syncing"))).as_deref(),
+            Some("stellar/core (phase Syncing)")
+        );
+        assert_eq!(
+            unmet_reason(&horizon, dependency, Some(&core("Ready"))),
+            None
+        );
+    }
+
+    #[test]
+    fn test_self_dependency_never_satisfied() {
+        let horizon = horizon();
+        let own = StellarNodeRef {
+            name: "horizon".to_string(),
+            namespace: Some("stellar".to_string()),
+        };
+        assert_eq!(
+            unmet_reason(&horizon, &own, Some(&horizon)).as_deref(),
+            Some("stellar/horizon (the node itself)")
+        );
+    }
+}
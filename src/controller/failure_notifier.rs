@@ -0,0 +1,186 @@
+//! Webhook notification for repeatedly failing reconciles
+//!
+//! The controller counts consecutive reconcile failures per node. When a node
+//! reaches `--failure-notification-threshold` failures in a row, the error is
+//! posted once to `--failure-webhook-url`. The counter resets on the next
+//! successful reconcile, and a node is notified at most once per
+//! `--failure-notification-interval-seconds`, so a node flapping around the
+//! threshold does not flood the receiver.
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use kube::ResourceExt;
+use serde_json::json;
+use tracing::{info, warn};
+
+use crate::crd::StellarNode;
+use crate::error::Error;
+
+/// Consecutive failures before a node is reported
+pub const DEFAULT_FAILURE_NOTIFICATION_THRESHOLD: u32 = 5;
+
+/// Minimum time between two notifications for the same node, in seconds
+pub const DEFAULT_FAILURE_NOTIFICATION_INTERVAL_SECONDS: u64 = 3600;
+
+/// Timeout for the webhook request
+const WEBHOOK_TIMEOUT: Duration = Duration::from_secs(10);
+
+#[derive(Debug, Default)]
+struct NodeFailures {
+    consecutive: u32,
+    last_notified: Option<Instant>,
+}
+
+/// Per-node consecutive failure counter with webhook notification
+#[derive(Debug)]
+pub struct FailureNotifier {
+    webhook_url: Option<String>,
+    threshold: u32,
+    min_interval: Duration,
+    http_client: reqwest::Client,
+    nodes: Mutex<HashMap<String, NodeFailures>>,
+}
+
+impl FailureNotifier {
+    /// Notify `webhook_url` after `threshold` consecutive failures of a node
+    pub fn new(webhook_url: Option<String>, threshold: u32, min_interval: Duration) -> Self {
+        let http_client = reqwest::Client::builder()
+            .timeout(WEBHOOK_TIMEOUT)
+            .build()
+            .unwrap_or_default();
+        Self {
+            webhook_url,
+            threshold: threshold.max(1),
+            min_interval,
+            http_client,
+            nodes: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Count failures without sending notifications
+    pub fn disabled() -> Self {
+        Self::new(
+            None,
+            DEFAULT_FAILURE_NOTIFICATION_THRESHOLD,
+            Duration::from_secs(DEFAULT_FAILURE_NOTIFICATION_INTERVAL_SECONDS),
+        )
+    }
+
+    /// Consecutive failures recorded for `key` (`namespace/name`)
+    pub fn consecutive_failures(&self, key: &str) -> u32 {
+        let nodes = self.nodes.lock().unwrap_or_else(|e| e.into_inner());
+        nodes.get(key).map(|n| n.consecutive).unwrap_or_default()
+    }
+
+    /// Reset the failure count after a successful reconcile
+    pub fn record_success(&self, key: &str) {
+        let mut nodes = self.nodes.lock().unwrap_or_else(|e| e.into_inner());
+        if let Some(node) = nodes.get_mut(key) {
+            node.consecutive = 0;
+        }
+    }
+
+    /// Count a failure; returns the failure count to notify, if this failure crosses the threshold
+    fn record_failure_at(&self, key: &str, now: Instant) -> Option<u32> {
+        let mut nodes = self.nodes.lock().unwrap_or_else(|e| e.into_inner());
+        let node = nodes.entry(key.to_string()).or_default();
+        node.consecutive = node.consecutive.saturating_add(1);
+
+        let rate_limited = node
+            .last_notified
+            .is_some_and(|at| now.duration_since(at) < self.min_interval);
+        if node.consecutive != self.threshold || rate_limited {
+            return None;
+        }
+        node.last_notified = Some(now);
+        Some(node.consecutive)
+    }
+
+    /// Count a failed reconcile of `node` and post to the webhook on a threshold crossing
+    pub fn record_failure(&self, node: &StellarNode, error: &Error) {
+        let namespace = node.namespace().unwrap_or_else(|| "default".to_string());
+        let name = node.name_any();
+        let Some(failures) = self.record_failure_at(&format!("{namespace}/{name}"), Instant::now())
+        else {
+            return;
+        };
+        let Some(url) = self.webhook_url.clone() else {
+            return;
+        };
+
+        let payload = json!({
+            "node": name,
+            "namespace": namespace,
+            "consecutiveFailures": failures,
+            "error": error.to_string(),
+        });
+        let client = self.http_client.clone();
+        tokio::spawn(async move {
+            match client
+                .post(&url)
+                .json(&payload)
+                .send()
+                .await
+                .and_then(|r| r.error_for_status())
+            {
+                Ok(_) => info!(
+                    "Notified {} of {} consecutive reconcile failures of {}/{}",
+                    url, failures, namespace, name
+                ),
+                Err(e) => warn!(
+                    "Failed to send reconcile failure notification for {}/{}: {}",
+                    namespace, name, e
+                ),
+            }
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_notifies_once_per_threshold_crossing() {
+        let notifier = FailureNotifier::new(None, 3, Duration::ZERO);
+        let now = Instant::now();
+        let notified: Vec<bool> = (0..6)
+            .map(|_| {
+                notifier
+                    .record_failure_at("stellar/validator", now)
+                    .is_some()
+            })
+            .collect();
+        assert_eq!(notified, [false, false, true, false, false, false]);
+        assert_eq!(notifier.consecutive_failures("stellar/validator"), 6);
+
+        notifier.record_success("stellar/validator");
+        assert_eq!(notifier.consecutive_failures("stellar/validator"), 0);
+        let notified: Vec<bool> = (0..3)
+            .map(|_| {
+                notifier
+                    .record_failure_at("stellar/validator", now)
+                    .is_some()
+            })
+            .collect();
+        assert_eq!(notified, [false, false, true]);
+    }
+
+    #[test]
+    fn test_rate_limited_per_node() {
+        let notifier = FailureNotifier::new(None, 1, Duration::from_secs(600));
+        let start = Instant::now();
+        assert!(notifier.record_failure_at("stellar/a", start).is_some());
+
+        notifier.record_success("stellar/a");
+        let soon = start + Duration::from_secs(60);
+        assert!(notifier.record_failure_at("stellar/a", soon).is_none());
+        assert!(notifier.record_failure_at("stellar/b", soon).is_some());
+
+        notifier.record_success("stellar/a");
+        let later = start + Duration::from_secs(601);
+        assert!(notifier.record_failure_at("stellar/a", later).is_some());
+    }
+}
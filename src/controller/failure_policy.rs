@@ -0,0 +1,265 @@
+//! Retry behavior for nodes whose reconciles keep failing
+//!
+//! `error_policy` picks the retry interval from `spec.nodeFailurePolicy` and
+//! the consecutive failures counted in memory by the failure notifier. The
+//! count is not written on every failure: a status patch would trigger another
+//! reconcile right away and defeat the backoff. With `alarmAndStop`, reaching
+//! `maxFailures` records the count in `status.consecutiveFailureCount` and sets
+//! `ReconcileStopped=True` for the current generation, and the node is left
+//! alone until its spec changes. The next successful reconcile resets the count
+//! and clears the condition.
+
+use std::time::Duration;
+
+use kube::client::Client;
+use serde_json::json;
+
+use crate::crd::{NodeFailurePolicy, StellarNode};
+use crate::error::Result;
+
+use super::conditions;
+
+/// Retry interval without a policy, for retriable errors
+const DEFAULT_RETRIABLE_RETRY: Duration = Duration::from_secs(15);
+
+/// Retry interval without a policy, for other errors
+const DEFAULT_RETRY: Duration = Duration::from_secs(60);
+
+/// Condition reason once `alarmAndStop.maxFailures` is reached
+pub const REASON_MAX_FAILURES_REACHED: &str = "MaxFailuresReached";
+
+/// Consecutive failures recorded in the node's status when it was stopped
+pub fn recorded_failures(node: &StellarNode) -> u32 {
+    node.status
+        .as_ref()
+        .map(|s| s.consecutive_failure_count)
+        .unwrap_or_default()
+}
+
+/// When to retry after the `failures`-th consecutive failure, or `None` to stop
+pub fn retry_after(
+    policy: Option<&NodeFailurePolicy>,
+    failures: u32,
+    retriable: bool,
+) -> Option<Duration> {
+    match policy {
+        None if retriable => Some(DEFAULT_RETRIABLE_RETRY),
+        None => Some(DEFAULT_RETRY),
+        Some(NodeFailurePolicy::Requeue(seconds)) => Some(Duration::from_secs(*seconds)),
+        Some(NodeFailurePolicy::ExponentialBackoff { initial, max }) => {
+            let doublings = failures.saturating_sub(1).min(63);
+            let seconds = initial.saturating_mul(1u64 << doublings).min(*max);
+            Some(Duration::from_secs(seconds))
+        }
+        Some(NodeFailurePolicy::AlarmAndStop { max_failures }) => {
+            let retry = if retriable {
+                DEFAULT_RETRIABLE_RETRY
+            } else {
+                DEFAULT_RETRY
+            };
+            (failures < *max_failures).then_some(retry)
+        }
+    }
+}
+
+/// Whether reconciling was stopped for the node's current generation
+pub fn reconcile_stopped(node: &StellarNode) -> bool {
+    node.status.as_ref().is_some_and(|status| {
+        conditions::find_condition(
+            &status.conditions,
+            conditions::CONDITION_TYPE_RECONCILE_STOPPED,
+        )
+        .is_some_and(|c| {
+            c.status == conditions::CONDITION_STATUS_TRUE
+                && c.observed_generation == node.metadata.generation
+        })
+    })
+}
+
+/// Record that reconciling stopped after `failures` consecutive failures
+///
+/// Sets `ReconcileStopped` for the current generation; called once, when the
+/// `alarmAndStop` threshold is reached.
+pub async fn record_stopped(
+    client: &Client,
+    node: &StellarNode,
+    failures: u32,
+    error: &str,
+) -> Result<()> {
+    let status = json!({ "consecutiveFailureCount": failures });
+    conditions::update_conditions(client, node, status, |node_conditions| {
+        conditions::set_condition(
+            node_conditions,
+            conditions::CONDITION_TYPE_RECONCILE_STOPPED,
+            conditions::CONDITION_STATUS_TRUE,
+            REASON_MAX_FAILURES_REACHED,
+            &format!(
+                "Stopped after {failures} consecutive failures; update the spec to retry. Last error: {error}"
+            ),
+        );
+        if let Some(condition) = node_conditions
+            .iter_mut()
+            .find(|c| c.type_ == conditions::CONDITION_TYPE_RECONCILE_STOPPED)
+        {
+            condition.observed_generation = node.metadata.generation;
+        }
+    })
+    .await
+}
+
+/// Reset the failure count and clear `ReconcileStopped` after a successful reconcile
+///
+/// Does nothing unless the node's status recorded a stop.
+pub async fn record_success(client: &Client, node: &StellarNode) -> Result<()> {
+    let stopped = node.status.as_ref().is_some_and(|s| {
+        conditions::find_condition(&s.conditions, conditions::CONDITION_TYPE_RECONCILE_STOPPED)
+            .is_some()
+    });
+    if recorded_failures(node) == 0 && !stopped {
+        return Ok(());
+    }
+
+    let status = json!({ "consecutiveFailureCount": 0 });
+    conditions::update_conditions(client, node, status, |node_conditions| {
+        conditions::remove_condition(
+            node_conditions,
+            conditions::CONDITION_TYPE_RECONCILE_STOPPED,
+        );
+    })
+    .await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::controller::fake_api::FakeApiServer;
+    use crate::crd::{Condition, StellarNodeSpec, StellarNodeStatus};
+
+    #[test]
+    fn test_default_retry_depends_on_error_kind() {
+        assert_eq!(retry_after(None, 10, true), Some(Duration::from_secs(15)));
+        assert_eq!(retry_after(None, 10, false), Some(Duration::from_secs(60)));
+    }
+
+    #[test]
+    fn test_fixed_requeue() {
+        let policy = NodeFailurePolicy::Requeue(30);
+        assert_eq!(
+            retry_after(Some(&policy), 1, false),
+            Some(Duration::from_secs(30))
+        );
+        assert_eq!(
+            retry_after(Some(&policy), 50, true),
+            Some(Duration::from_secs(30))
+        );
+    }
+
+    #[test]
+    fn test_exponential_backoff_is_capped() {
+        let policy = NodeFailurePolicy::ExponentialBackoff {
+            initial: 10,
+            max: 300,
+        };
+        let seconds: Vec<u64> = (1..=7)
+            .map(|n| retry_after(Some(&policy), n, true).unwrap().as_secs())
+            .collect();
+        assert_eq!(seconds, vec![10, 20, 40, 80, 160, 300, 300]);
+        assert_eq!(
+            retry_after(Some(&policy), u32::MAX, true),
+            Some(Duration::from_secs(300))
+        );
+    }
+
+    #[test]
+    fn test_alarm_and_stop_after_max_failures() {
+        let policy = NodeFailurePolicy::AlarmAndStop { max_failures: 3 };
+        assert!(retry_after(Some(&policy), 2, true).is_some());
+        assert_eq!(retry_after(Some(&policy), 3, true), None);
+        assert_eq!(retry_after(Some(&policy), 4, false), None);
+    }
+
+    #[test]
+    fn test_policy_deserializes_from_camel_case() {
+        let policy: NodeFailurePolicy =
+            serde_json::from_value(json!({ "alarmAndStop": { "maxFailures": 5 } })).unwrap();
+        assert_eq!(policy, NodeFailurePolicy::AlarmAndStop { max_failures: 5 });
+        let policy: NodeFailurePolicy = serde_json::from_value(json!({ "requeue": 45 })).unwrap();
+        assert_eq!(policy, NodeFailurePolicy::Requeue(45));
+    }
+
+    #[test]
+    fn test_reconcile_stopped_only_for_same_generation() {
+        let spec: StellarNodeSpec = serde_json::from_value(json!({
+            "nodeType": "Validator",
+            "network": "Testnet",
+            "version": "v21.0.0",
+        }))
+        .unwrap();
+        let mut node = StellarNode::new("validator", spec);
+        node.metadata.generation = Some(4);
+        node.status = Some(StellarNodeStatus {
+            conditions: vec![Condition {
+                type_: conditions::CONDITION_TYPE_RECONCILE_STOPPED.to_string(),
+                status: conditions::CONDITION_STATUS_TRUE.to_string(),
+                last_transition_time: String::new(),
+                reason: REASON_MAX_FAILURES_REACHED.to_string(),
+                message: String::new(),
+                observed_generation: Some(4),
+            }],
+            ..Default::default()
+        });
+        assert!(reconcile_stopped(&node));
+
+        node.metadata.generation = Some(5);
+        assert!(!reconcile_stopped(&node));
+    }
+
+    #[tokio::test]
+    async fn test_success_clears_stop_from_live_conditions() {
+        let server = FakeApiServer::start().await;
+        let client = server.client();
+        let spec: StellarNodeSpec = serde_json::from_value(json!({
+            "nodeType": "Validator",
+            "network": "Testnet",
+            "version": "v21.0.0",
+        }))
+        .unwrap();
+        let mut node = StellarNode::new("validator", spec);
+        node.metadata.namespace = Some("default".to_string());
+        node.metadata.generation = Some(2);
+        let path = "/apis/stellar.org/v1alpha1/namespaces/default/stellarnodes/validator";
+        server.insert(path, &node);
+
+        // Stopped, then another condition is written; the success below sees
+        // the stale snapshot taken when the node was stopped
+        record_stopped(&client, &node, 3, "boom").await.unwrap();
+        let stopped: StellarNode = server.get(path);
+        assert!(reconcile_stopped(&stopped));
+        assert_eq!(recorded_failures(&stopped), 3);
+        conditions::update_conditions(&client, &node, json!({}), |c| {
+            conditions::set_condition(
+                c,
+                conditions::CONDITION_TYPE_STORAGE_PRESSURE,
+                conditions::CONDITION_STATUS_TRUE,
+                "VolumeNearlyFull",
+                "",
+            )
+        })
+        .await
+        .unwrap();
+
+        record_success(&client, &stopped).await.unwrap();
+        let live: StellarNode = server.get(path);
+        let status = live.status.unwrap();
+        assert_eq!(status.consecutive_failure_count, 0);
+        assert!(conditions::find_condition(
+            &status.conditions,
+            conditions::CONDITION_TYPE_RECONCILE_STOPPED
+        )
+        .is_none());
+        assert!(conditions::is_condition_true(
+            &status.conditions,
+            conditions::CONDITION_TYPE_STORAGE_PRESSURE
+        ));
+    }
+}
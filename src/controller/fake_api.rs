@@ -0,0 +1,170 @@
+//! In-memory Kubernetes API server for tests that span several API calls
+//!
+//! Serves GET, PATCH (JSON merge patch) and POST on a loopback port. Objects
+//! are keyed by their API path; `/status` requests act on the same object, as
+//! the subresource does. POSTed objects are recorded so tests can inspect the
+//! events a reconcile emitted.
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+use kube::Client;
+use serde_json::{json, Value};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+#[derive(Default)]
+struct State {
+    objects: HashMap<String, Value>,
+    created: Vec<Value>,
+}
+
+pub struct FakeApiServer {
+    state: Arc<Mutex<State>>,
+    url: String,
+}
+
+impl FakeApiServer {
+    pub async fn start() -> Self {
+        // rustls has both ring and aws-lc-rs enabled, so kube cannot pick one
+        let _ = rustls::crypto::ring::default_provider().install_default();
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let state = Arc::new(Mutex::new(State::default()));
+        let shared = state.clone();
+        tokio::spawn(async move {
+            while let Ok((mut stream, _)) = listener.accept().await {
+                let state = shared.clone();
+                tokio::spawn(async move {
+                    let Some((method, path, body)) = read_request(&mut stream).await else {
+                        return;
+                    };
+                    let (code, body) = handle(&mut state.lock().unwrap(), &method, &path, body);
+                    let body = body.to_string();
+                    let response = format!(
+                        "HTTP/1.1 {code} X\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                        body.len()
+                    );
+                    let _ = stream.write_all(response.as_bytes()).await;
+                });
+            }
+        });
+        Self { state, url }
+    }
+
+    pub fn client(&self) -> Client {
+        Client::try_from(kube::Config::new(self.url.parse().unwrap())).unwrap()
+    }
+
+    /// Store `object` at `path`, e.g. `/api/v1/namespaces/default/pods/web`
+    pub fn insert(&self, path: &str, object: impl serde::Serialize) {
+        let object = serde_json::to_value(object).unwrap();
+        self.state
+            .lock()
+            .unwrap()
+            .objects
+            .insert(path.to_string(), object);
+    }
+
+    pub fn get<T: serde::de::DeserializeOwned>(&self, path: &str) -> T {
+        serde_json::from_value(self.state.lock().unwrap().objects[path].clone()).unwrap()
+    }
+
+    /// Objects created with POST, in order
+    pub fn created(&self) -> Vec<Value> {
+        self.state.lock().unwrap().created.clone()
+    }
+}
+
+async fn read_request(stream: &mut tokio::net::TcpStream) -> Option<(String, String, Value)> {
+    let mut buffer = Vec::new();
+    let mut chunk = [0u8; 4096];
+    let header_end = loop {
+        if let Some(end) = buffer.windows(4).position(|w| w == b"\r\n\r\n") {
+            break end + 4;
+        }
+        let n = stream.read(&mut chunk).await.ok()?;
+        if n == 0 {
+            return None;
+        }
+        buffer.extend_from_slice(&chunk[..n]);
+    };
+
+    let head = String::from_utf8_lossy(&buffer[..header_end]).to_string();
+    let mut request_line = head.lines().next()?.split_whitespace();
+    let method = request_line.next()?.to_string();
+    let path = request_line.next()?.split('?').next()?.to_string();
+    let length = head
+        .lines()
+        .find_map(|line| {
+            let (name, value) = line.split_once(':')?;
+            name.eq_ignore_ascii_case("content-length")
+                .then(|| value.trim().parse::<usize>().ok())?
+        })
+        .unwrap_or(0);
+
+    while buffer.len() < header_end + length {
+        let n = stream.read(&mut chunk).await.ok()?;
+        if n == 0 {
+            break;
+        }
+        buffer.extend_from_slice(&chunk[..n]);
+    }
+    let body = serde_json::from_slice(&buffer[header_end..]).unwrap_or(Value::Null);
+    Some((method, path, body))
+}
+
+fn handle(state: &mut State, method: &str, path: &str, body: Value) -> (u16, Value) {
+    let key = path.strip_suffix("/status").unwrap_or(path);
+    match method {
+        "GET" => match state.objects.get(key) {
+            Some(object) => (200, object.clone()),
+            None => not_found(key),
+        },
+        "PATCH" => match state.objects.get_mut(key) {
+            Some(object) => {
+                merge(object, &body);
+                (200, object.clone())
+            }
+            None => not_found(key),
+        },
+        "POST" => {
+            state.created.push(body.clone());
+            (201, body)
+        }
+        _ => (405, json!({})),
+    }
+}
+
+fn not_found(key: &str) -> (u16, Value) {
+    (
+        404,
+        json!({
+            "kind": "Status",
+            "apiVersion": "v1",
+            "metadata": {},
+            "status": "Failure",
+            "message": format!("{key} not found"),
+            "reason": "NotFound",
+            "code": 404,
+        }),
+    )
+}
+
+/// RFC 7386 JSON merge patch
+fn merge(target: &mut Value, patch: &Value) {
+    let Value::Object(patch) = patch else {
+        *target = patch.clone();
+        return;
+    };
+    if !target.is_object() {
+        *target = json!({});
+    }
+    let target = target.as_object_mut().unwrap();
+    for (key, value) in patch {
+        if value.is_null() {
+            target.remove(key);
+        } else {
+            merge(target.entry(key.clone()).or_insert(Value::Null), value);
+        }
+    }
+}
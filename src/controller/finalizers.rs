@@ -5,6 +5,7 @@
 //! 2. Persistent Volumes/Claims are deleted based on retention policy
 //! 3. External resources (cloud storage, DNS) are properly removed
 
+use k8s_openapi::api::core::v1::Namespace;
 use kube::{
     api::{Api, Patch, PatchParams},
     Client, ResourceExt,
@@ -13,7 +14,7 @@ use serde_json::json;
 use tracing::info;
 
 use crate::crd::StellarNode;
-use crate::error::Result;
+use crate::error::{Error, Result};
 
 /// Finalizer name used to protect StellarNode resources
 ///
@@ -94,6 +95,30 @@ pub fn is_being_deleted(node: &StellarNode) -> bool {
     node.metadata.deletion_timestamp.is_some()
 }
 
+/// Check if a namespace is being deleted
+///
+/// Once a namespace is terminating the API server rejects new objects in it,
+/// and its garbage collector removes everything the node owns there.
+pub fn is_namespace_terminating(namespace: &Namespace) -> bool {
+    namespace.metadata.deletion_timestamp.is_some()
+        || namespace
+            .status
+            .as_ref()
+            .and_then(|s| s.phase.as_deref())
+            .is_some_and(|phase| phase == "Terminating")
+}
+
+/// Look up whether `namespace` is terminating
+///
+/// A namespace that no longer exists is treated as terminating.
+pub async fn namespace_terminating(client: &Client, namespace: &str) -> Result<bool> {
+    let api: Api<Namespace> = Api::all(client.clone());
+    match api.get_opt(namespace).await.map_err(Error::KubeError)? {
+        Some(ns) => Ok(is_namespace_terminating(&ns)),
+        None => Ok(true),
+    }
+}
+
 /// Check if the node has our finalizer
 #[allow(dead_code)]
 pub fn has_finalizer(node: &StellarNode) -> bool {
@@ -110,4 +135,29 @@ mod tests {
     fn test_finalizer_name() {
         assert_eq!(STELLAR_NODE_FINALIZER, "stellarnode.stellar.org/finalizer");
     }
+
+    #[test]
+    fn test_namespace_terminating() {
+        use k8s_openapi::api::core::v1::NamespaceStatus;
+        use k8s_openapi::apimachinery::pkg::apis::meta::v1::Time;
+
+        let mut ns = Namespace::default();
+        assert!(!is_namespace_terminating(&ns));
+
+        ns.status = Some(NamespaceStatus {
+            phase: Some("Active".to_string()),
+            ..Default::default()
+        });
+        assert!(!is_namespace_terminating(&ns));
+
+        ns.metadata.deletion_timestamp = Some(Time(chrono::Utc::now()));
+        assert!(is_namespace_terminating(&ns));
+
+        ns.metadata.deletion_timestamp = None;
+        ns.status = Some(NamespaceStatus {
+            phase: Some("Terminating".to_string()),
+            ..Default::default()
+        });
+        assert!(is_namespace_terminating(&ns));
+    }
 }
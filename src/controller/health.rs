@@ -133,11 +133,55 @@ impl HealthCheckResult {
     }
 }
 
+/// Timeout for a single health check request
+const HEALTH_CHECK_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// How long pooled connections to node endpoints are kept between reconciles
+const HEALTH_CHECK_POOL_IDLE_TIMEOUT: Duration = Duration::from_secs(90);
+
+/// Build the HTTP client shared by all node health checks
+///
+/// The client is built once and reused, so connections to node endpoints are
+/// pooled across reconciles. With mTLS enabled it presents the operator
+/// certificate and trusts the operator CA.
+pub fn build_health_http_client(
+    mtls_config: Option<&crate::MtlsConfig>,
+) -> Result<reqwest::Client> {
+    let mut builder = reqwest::Client::builder()
+        .timeout(HEALTH_CHECK_TIMEOUT)
+        .connect_timeout(HEALTH_CHECK_TIMEOUT)
+        .pool_idle_timeout(HEALTH_CHECK_POOL_IDLE_TIMEOUT);
+
+    if let Some(config) = mtls_config {
+        let mut identity_pem = config.cert_pem.clone();
+        identity_pem.extend_from_slice(&config.key_pem);
+
+        let identity = reqwest::Identity::from_pem(&identity_pem)
+            .map_err(|e| Error::ConfigError(format!("Failed to create identity: {e}")))?;
+
+        let ca_cert = reqwest::Certificate::from_pem(&config.ca_pem)
+            .map_err(|e| Error::ConfigError(format!("Failed to parse CA cert: {e}")))?;
+
+        builder = builder
+            .identity(identity)
+            .add_root_certificate(ca_cert)
+            .danger_accept_invalid_hostnames(true);
+    }
+
+    builder
+        .build()
+        .map_err(|e| Error::ConfigError(format!("Failed to create HTTP client: {e}")))
+}
+
 /// Check the health of a StellarNode
+///
+/// `http_client` is the shared client from [`build_health_http_client`];
+/// `use_tls` selects `https` for the node endpoint.
 pub async fn check_node_health(
     client: &Client,
     node: &StellarNode,
-    mtls_config: Option<&crate::MtlsConfig>,
+    http_client: &reqwest::Client,
+    use_tls: bool,
 ) -> Result<HealthCheckResult> {
     let namespace = node.namespace().unwrap_or_else(|| "default".to_string());
     let name = node.name_any();
@@ -194,8 +238,8 @@ pub async fn check_node_health(
 
     // Perform health check based on node type
     match node.spec.node_type {
-        NodeType::Horizon => check_horizon_health(pod_ip, mtls_config).await,
-        NodeType::SorobanRpc => check_soroban_health(pod_ip, mtls_config).await,
+        NodeType::Horizon => check_horizon_health(http_client, pod_ip, use_tls).await,
+        NodeType::SorobanRpc => check_soroban_health(http_client, pod_ip, use_tls).await,
         NodeType::Validator => {
             // Validators don't have a standard health endpoint
             // We consider them healthy if the pod is running
@@ -218,42 +262,16 @@ fn is_pod_ready(pod: &Pod) -> bool {
 
 /// Check Horizon node health
 async fn check_horizon_health(
+    http_client: &reqwest::Client,
     pod_ip: &str,
-    mtls_config: Option<&crate::MtlsConfig>,
+    use_tls: bool,
 ) -> Result<HealthCheckResult> {
-    let scheme = if mtls_config.is_some() {
-        "https"
-    } else {
-        "http"
-    };
+    let scheme = if use_tls { "https" } else { "http" };
     let url = format!("{scheme}://{pod_ip}:8000/health");
 
     debug!("Querying Horizon health endpoint: {}", url);
 
-    // Create HTTP client with mTLS if enabled
-    let mut builder = reqwest::Client::builder().timeout(Duration::from_secs(5));
-
-    if let Some(config) = mtls_config {
-        let mut identity_pem = config.cert_pem.clone();
-        identity_pem.extend_from_slice(&config.key_pem);
-
-        let identity = reqwest::Identity::from_pem(&identity_pem)
-            .map_err(|e| Error::ConfigError(format!("Failed to create identity: {e}")))?;
-
-        let ca_cert = reqwest::Certificate::from_pem(&config.ca_pem)
-            .map_err(|e| Error::ConfigError(format!("Failed to parse CA cert: {e}")))?;
-
-        builder = builder
-            .identity(identity)
-            .add_root_certificate(ca_cert)
-            .danger_accept_invalid_hostnames(true);
-    }
-
-    let client = builder
-        .build()
-        .map_err(|e| Error::ConfigError(format!("Failed to create HTTP client: {e}")))?;
-
-    match client.get(&url).send().await {
+    match http_client.get(&url).send().await {
         Ok(response) => {
             if !response.status().is_success() {
                 warn!(
@@ -318,41 +336,16 @@ async fn check_horizon_health(
 
 /// Check Soroban RPC node health
 async fn check_soroban_health(
+    http_client: &reqwest::Client,
     pod_ip: &str,
-    mtls_config: Option<&crate::MtlsConfig>,
+    use_tls: bool,
 ) -> Result<HealthCheckResult> {
-    let scheme = if mtls_config.is_some() {
-        "https"
-    } else {
-        "http"
-    };
+    let scheme = if use_tls { "https" } else { "http" };
     let url = format!("{scheme}://{pod_ip}:8000/health");
 
     debug!("Querying Soroban RPC health endpoint: {}", url);
 
-    let mut builder = reqwest::Client::builder().timeout(Duration::from_secs(5));
-
-    if let Some(config) = mtls_config {
-        let mut identity_pem = config.cert_pem.clone();
-        identity_pem.extend_from_slice(&config.key_pem);
-
-        let identity = reqwest::Identity::from_pem(&identity_pem)
-            .map_err(|e| Error::ConfigError(format!("Failed to create identity: {e}")))?;
-
-        let ca_cert = reqwest::Certificate::from_pem(&config.ca_pem)
-            .map_err(|e| Error::ConfigError(format!("Failed to parse CA cert: {e}")))?;
-
-        builder = builder
-            .identity(identity)
-            .add_root_certificate(ca_cert)
-            .danger_accept_invalid_hostnames(true);
-    }
-
-    let client = builder
-        .build()
-        .map_err(|e| Error::ConfigError(format!("Failed to create HTTP client: {e}")))?;
-
-    match client.get(&url).send().await {
+    match http_client.get(&url).send().await {
         Ok(response) => {
             if !response.status().is_success() {
                 warn!(
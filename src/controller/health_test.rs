@@ -35,4 +35,19 @@ mod tests {
         assert!(!result.synced);
         assert_eq!(result.ledger_sequence, None);
     }
+
+    #[test]
+    fn test_build_health_http_client() {
+        assert!(build_health_http_client(None).is_ok());
+    }
+
+    #[test]
+    fn test_build_health_http_client_rejects_invalid_mtls_identity() {
+        let config = crate::MtlsConfig {
+            cert_pem: b"not a certificate".to_vec(),
+            key_pem: b"not a key".to_vec(),
+            ca_pem: b"not a ca".to_vec(),
+        };
+        assert!(build_health_http_client(Some(&config)).is_err());
+    }
 }
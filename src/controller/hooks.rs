@@ -0,0 +1,226 @@
+//! Pre/post apply reconcile hooks
+//!
+//! When `spec.hooks` is set, the reconciler POSTs a small JSON payload to the
+//! configured URLs before and after applying resources. This lets users plug
+//! the operator into external automation (chat notifications, CMDB updates)
+//! without the operator knowing about any specific integration.
+//!
+//! Hooks are best-effort: failures, non-2xx responses and timeouts are logged
+//! and reconciliation continues.
+
+use std::time::Duration;
+
+use kube::ResourceExt;
+use serde::Serialize;
+use tracing::{debug, warn};
+
+use crate::crd::StellarNode;
+
+/// Point in the reconcile loop at which a hook fires
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum HookEvent {
+    PreApply,
+    PostApply,
+}
+
+/// JSON body sent to hook endpoints
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HookPayload {
+    pub event: HookEvent,
+    pub namespace: String,
+    pub name: String,
+    pub node_type: String,
+    pub network: String,
+    pub version: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub generation: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub phase: Option<String>,
+    pub timestamp: String,
+}
+
+impl HookPayload {
+    /// Build the payload describing `node` at `event`
+    pub fn new(node: &StellarNode, event: HookEvent, phase: Option<&str>) -> Self {
+        Self {
+            event,
+            namespace: node.namespace().unwrap_or_else(|| "default".to_string()),
+            name: node.name_any(),
+            node_type: node.spec.node_type.to_string(),
+            network: node.spec.network.passphrase().to_string(),
+            version: node.spec.version.clone(),
+            generation: node.metadata.generation,
+            phase: phase.map(str::to_string),
+            timestamp: chrono::Utc::now().to_rfc3339(),
+        }
+    }
+}
+
+/// URL configured for `event`, if any
+fn hook_url(node: &StellarNode, event: HookEvent) -> Option<&str> {
+    let hooks = node.spec.hooks.as_ref()?;
+    match event {
+        HookEvent::PreApply => hooks.pre_apply_url.as_deref(),
+        HookEvent::PostApply => hooks.post_apply_url.as_deref(),
+    }
+}
+
+/// Fire the hook for `event` if configured
+///
+/// Returns whether the endpoint acknowledged the call with a 2xx response.
+/// Errors are logged and never propagated.
+pub async fn run_hook(node: &StellarNode, event: HookEvent, phase: Option<&str>) -> bool {
+    let Some(url) = hook_url(node, event) else {
+        return false;
+    };
+    let timeout_secs = node
+        .spec
+        .hooks
+        .as_ref()
+        .map(|h| h.timeout_seconds)
+        .unwrap_or(5);
+    let payload = HookPayload::new(node, event, phase);
+
+    let client = match reqwest::Client::builder()
+        .timeout(Duration::from_secs(u64::from(timeout_secs)))
+        .build()
+    {
+        Ok(client) => client,
+        Err(e) => {
+            warn!("Failed to create HTTP client for {:?} hook: {}", event, e);
+            return false;
+        }
+    };
+
+    match client.post(url).json(&payload).send().await {
+        Ok(response) if response.status().is_success() => {
+            debug!(
+                "{:?} hook for {}/{} acknowledged by {}",
+                event, payload.namespace, payload.name, url
+            );
+            true
+        }
+        Ok(response) => {
+            warn!(
+                "{:?} hook for {}/{} returned status {} from {}",
+                event,
+                payload.namespace,
+                payload.name,
+                response.status(),
+                url
+            );
+            false
+        }
+        Err(e) => {
+            warn!(
+                "{:?} hook for {}/{} failed: {}",
+                event, payload.namespace, payload.name, e
+            );
+            false
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::crd::{ReconcileHooksConfig, StellarNodeSpec};
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::TcpListener;
+
+    fn node_with_hooks(hooks: ReconcileHooksConfig) -> StellarNode {
+        let spec: StellarNodeSpec = serde_json::from_value(serde_json::json!({
+            "nodeType": "SorobanRpc",
+            "network": "Testnet",
+            "version": "v21.0.0",
+            "sorobanConfig": { "stellarCoreUrl": "http://core:11626" }
+        }))
+        .unwrap();
+        let mut node = StellarNode::new("soroban-1", spec);
+        node.metadata.namespace = Some("stellar".to_string());
+        node.metadata.generation = Some(3);
+        node.spec.hooks = Some(hooks);
+        node
+    }
+
+    /// Accept one HTTP request, reply 200 and return its body
+    async fn capture_one_request(listener: TcpListener) -> String {
+        let (mut socket, _) = listener.accept().await.unwrap();
+        let mut buf = Vec::new();
+        let mut chunk = [0u8; 1024];
+        loop {
+            let n = socket.read(&mut chunk).await.unwrap();
+            buf.extend_from_slice(&chunk[..n]);
+            let text = String::from_utf8_lossy(&buf);
+            if let Some((head, body)) = text.split_once("\r\n\r\n") {
+                let len = head
+                    .lines()
+                    .find_map(|l| {
+                        let (k, v) = l.split_once(':')?;
+                        k.eq_ignore_ascii_case("content-length")
+                            .then(|| v.trim().parse::<usize>().ok())?
+                    })
+                    .unwrap_or(0);
+                if body.len() >= len {
+                    socket
+                        .write_all(b"HTTP/1.1 200 OK\r\ncontent-length: 0\r\n\r\n")
+                        .await
+                        .unwrap();
+                    return body.to_string();
+                }
+            }
+            if n == 0 {
+                return String::new();
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_post_apply_hook_sends_payload() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let server = tokio::spawn(capture_one_request(listener));
+
+        let node = node_with_hooks(ReconcileHooksConfig {
+            pre_apply_url: None,
+            post_apply_url: Some(format!("http://{addr}/hook")),
+            timeout_seconds: 5,
+        });
+        assert!(run_hook(&node, HookEvent::PostApply, Some("Ready")).await);
+
+        let body: serde_json::Value = serde_json::from_str(&server.await.unwrap()).unwrap();
+        assert_eq!(body["event"], "postApply");
+        assert_eq!(body["namespace"], "stellar");
+        assert_eq!(body["name"], "soroban-1");
+        assert_eq!(body["nodeType"], "SorobanRpc");
+        assert_eq!(body["version"], "v21.0.0");
+        assert_eq!(body["generation"], 3);
+        assert_eq!(body["phase"], "Ready");
+    }
+
+    #[tokio::test]
+    async fn test_unconfigured_hook_is_skipped() {
+        let node = node_with_hooks(ReconcileHooksConfig {
+            pre_apply_url: None,
+            post_apply_url: Some("http://127.0.0.1:1/hook".to_string()),
+            timeout_seconds: 1,
+        });
+        assert!(!run_hook(&node, HookEvent::PreApply, None).await);
+    }
+
+    #[tokio::test]
+    async fn test_unreachable_hook_is_non_fatal() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        drop(listener);
+
+        let node = node_with_hooks(ReconcileHooksConfig {
+            pre_apply_url: Some(format!("http://{addr}/hook")),
+            post_apply_url: None,
+            timeout_seconds: 1,
+        });
+        assert!(!run_hook(&node, HookEvent::PreApply, None).await);
+    }
+}
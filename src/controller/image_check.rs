@@ -0,0 +1,564 @@
+//! Pre-flight check that a node's container image exists
+//!
+//! With `--verify-images`, the reconciler asks the image's registry for its
+//! manifest (a `HEAD` on the Docker Registry v2 API) before creating or
+//! updating the workload. A 404 sets `ImageNotFound=True` and leaves the
+//! workload untouched, instead of the pods failing later with
+//! ImagePullBackOff. Credentials come from the imagePullSecrets of the
+//! namespace's `default` ServiceAccount, which the pods pull with. A registry
+//! that cannot be reached or refuses the credentials does not block the
+//! reconcile.
+
+use std::collections::{BTreeMap, HashSet};
+use std::sync::Mutex;
+use std::time::Duration;
+
+use k8s_openapi::api::core::v1::{Secret, ServiceAccount};
+use kube::{api::Api, client::Client, ResourceExt};
+use reqwest::header::{ACCEPT, WWW_AUTHENTICATE};
+use reqwest::StatusCode;
+use serde::Deserialize;
+use serde_json::json;
+use tracing::debug;
+
+use crate::crd::{Condition, StellarNode};
+use crate::error::{Error, Result};
+
+use super::conditions;
+
+/// Condition reason when the registry has no manifest for the image
+pub const REASON_MANIFEST_NOT_FOUND: &str = "ManifestNotFound";
+
+/// Timeout for each registry request
+const REGISTRY_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Registry the Docker CLI resolves unqualified image names against
+const DOCKER_HUB: &str = "docker.io";
+
+/// API host of Docker Hub
+const DOCKER_HUB_API: &str = "registry-1.docker.io";
+
+/// Manifest media types accepted from the registry
+const MANIFEST_MEDIA_TYPES: &str = "application/vnd.oci.image.index.v1+json, \
+    application/vnd.docker.distribution.manifest.list.v2+json, \
+    application/vnd.oci.image.manifest.v1+json, \
+    application/vnd.docker.distribution.manifest.v2+json";
+
+/// An image reference split into registry, repository and tag or digest
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ImageReference {
+    pub registry: String,
+    pub repository: String,
+    pub reference: String,
+}
+
+impl ImageReference {
+    /// Parse an image the way the container runtime resolves it
+    ///
+    /// A first path component containing `.` or `:`, or `localhost`, is a
+    /// registry; otherwise the image is on Docker Hub, where single-component
+    /// names live under `library/`. The tag defaults to `latest`.
+    pub fn parse(image: &str) -> Self {
+        let (name, reference) = match image.split_once('@') {
+            Some((name, digest)) => (name, Some(digest.to_string())),
+            None => (image, None),
+        };
+        let (name, reference) = match reference {
+            Some(digest) => (
+                name.rsplit_once(':')
+                    .filter(|(_, tag)| !tag.contains('/'))
+                    .map_or(name, |(name, _)| name),
+                digest,
+            ),
+            None => match name.rsplit_once(':').filter(|(_, tag)| !tag.contains('/')) {
+                Some((name, tag)) => (name, tag.to_string()),
+                None => (name, "latest".to_string()),
+            },
+        };
+
+        let (registry, repository) = match name.split_once('/') {
+            Some((first, rest))
+                if first.contains('.') || first.contains(':') || first == "localhost" =>
+            {
+                (first.to_string(), rest.to_string())
+            }
+            _ => (DOCKER_HUB.to_string(), name.to_string()),
+        };
+        let repository = if registry == DOCKER_HUB && !repository.contains('/') {
+            format!("library/{repository}")
+        } else {
+            repository
+        };
+
+        Self {
+            registry,
+            repository,
+            reference,
+        }
+    }
+
+    /// Registry v2 API URL of the image manifest
+    ///
+    /// Like the container runtimes, registries on the loopback address are
+    /// reached over plain HTTP.
+    pub fn manifest_url(&self) -> String {
+        let host = if self.registry == DOCKER_HUB {
+            DOCKER_HUB_API
+        } else {
+            &self.registry
+        };
+        let hostname = host.split(':').next().unwrap_or(host);
+        let scheme = if matches!(hostname, "localhost" | "127.0.0.1") {
+            "http"
+        } else {
+            "https"
+        };
+        format!(
+            "{scheme}://{host}/v2/{}/manifests/{}",
+            self.repository, self.reference
+        )
+    }
+}
+
+/// Outcome of an image existence check
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ImageCheck {
+    /// The registry has a manifest for the image
+    Found,
+    /// The registry answered that the image does not exist
+    NotFound,
+    /// The registry could not be reached or gave no clear answer
+    Unverified(String),
+}
+
+/// Credentials for one registry from a `.dockerconfigjson` pull secret
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Eq)]
+pub struct RegistryAuth {
+    #[serde(default)]
+    pub username: Option<String>,
+    #[serde(default)]
+    pub password: Option<String>,
+    /// Base64 of `username:password`
+    #[serde(default)]
+    pub auth: Option<String>,
+}
+
+impl RegistryAuth {
+    fn apply(&self, request: reqwest::RequestBuilder) -> reqwest::RequestBuilder {
+        match (&self.username, &self.auth) {
+            (Some(username), _) => request.basic_auth(username, self.password.as_ref()),
+            (None, Some(auth)) => request.header("Authorization", format!("Basic {auth}")),
+            (None, None) => request,
+        }
+    }
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct DockerConfig {
+    #[serde(default)]
+    auths: BTreeMap<String, RegistryAuth>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct TokenResponse {
+    #[serde(default)]
+    token: Option<String>,
+    #[serde(default)]
+    access_token: Option<String>,
+}
+
+/// Credentials for `registry` in a `.dockerconfigjson` document
+///
+/// Entries may be bare hosts or URLs such as `https://index.docker.io/v1/`.
+pub fn auth_for_registry(docker_config: &[u8], registry: &str) -> Option<RegistryAuth> {
+    let config: DockerConfig = serde_json::from_slice(docker_config).ok()?;
+    let host_of = |key: &str| {
+        let key = key
+            .trim_start_matches("https://")
+            .trim_start_matches("http://");
+        key.split('/').next().unwrap_or(key).to_string()
+    };
+    let matches = |host: &str| {
+        host == registry
+            || (registry == DOCKER_HUB
+                && matches!(host, "index.docker.io" | "registry-1.docker.io"))
+    };
+    config
+        .auths
+        .into_iter()
+        .find(|(key, _)| matches(&host_of(key)))
+        .map(|(_, auth)| auth)
+}
+
+/// Parameters of a `WWW-Authenticate: Bearer ...` challenge
+pub fn parse_bearer_challenge(header: &str) -> Option<BTreeMap<String, String>> {
+    let params = header.strip_prefix("Bearer ")?;
+    let mut result = BTreeMap::new();
+    let mut rest = params.trim();
+    while !rest.is_empty() {
+        let (key, after) = rest.split_once('=')?;
+        let (value, after) = match after.strip_prefix('"') {
+            Some(quoted) => {
+                let (value, after) = quoted.split_once('"')?;
+                (value, after)
+            }
+            None => after.split_once(',').unwrap_or((after, "")),
+        };
+        result.insert(key.trim().to_string(), value.to_string());
+        rest = after.trim_start_matches([',', ' ']);
+    }
+    Some(result)
+}
+
+/// Registry client that remembers images it has already found
+#[derive(Debug)]
+pub struct ImageVerifier {
+    http_client: reqwest::Client,
+    found: Mutex<HashSet<String>>,
+}
+
+impl Default for ImageVerifier {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ImageVerifier {
+    pub fn new() -> Self {
+        let http_client = reqwest::Client::builder()
+            .timeout(REGISTRY_TIMEOUT)
+            .build()
+            .unwrap_or_default();
+        Self {
+            http_client,
+            found: Mutex::new(HashSet::new()),
+        }
+    }
+
+    /// Check that the node's container image exists in its registry
+    ///
+    /// Images found once are not checked again, and their pull secrets are
+    /// not read.
+    pub async fn check(&self, client: &Client, node: &StellarNode) -> ImageCheck {
+        let image = node.spec.container_image();
+        if self.is_found(&image) {
+            return ImageCheck::Found;
+        }
+        let registry = ImageReference::parse(&image).registry;
+        let namespace = node.namespace().unwrap_or_else(|| "default".to_string());
+        let auth = match pull_secret_auth(client, &namespace, &registry).await {
+            Ok(auth) => auth,
+            Err(e) => {
+                debug!("Failed to read image pull secrets in {}: {}", namespace, e);
+                None
+            }
+        };
+        self.check_image(&image, auth.as_ref()).await
+    }
+
+    /// Check that `image` exists, authenticating with `auth` if given
+    pub async fn check_image(&self, image: &str, auth: Option<&RegistryAuth>) -> ImageCheck {
+        if self.is_found(image) {
+            return ImageCheck::Found;
+        }
+
+        let reference = ImageReference::parse(image);
+        let check = match self.head_manifest(&reference, auth).await {
+            Ok(StatusCode::NOT_FOUND) => ImageCheck::NotFound,
+            Ok(status) if status.is_success() => ImageCheck::Found,
+            Ok(status) => ImageCheck::Unverified(format!("registry returned {status}")),
+            Err(e) => ImageCheck::Unverified(e.to_string()),
+        };
+        if check == ImageCheck::Found {
+            self.found
+                .lock()
+                .unwrap_or_else(|e| e.into_inner())
+                .insert(image.to_string());
+        }
+        check
+    }
+
+    /// Whether `image` was already found in its registry
+    fn is_found(&self, image: &str) -> bool {
+        self.found
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .contains(image)
+    }
+
+    /// `HEAD` the manifest, answering a bearer token challenge once
+    async fn head_manifest(
+        &self,
+        reference: &ImageReference,
+        auth: Option<&RegistryAuth>,
+    ) -> std::result::Result<StatusCode, reqwest::Error> {
+        let url = reference.manifest_url();
+        let request = self
+            .http_client
+            .head(&url)
+            .header(ACCEPT, MANIFEST_MEDIA_TYPES);
+        let response = match auth {
+            Some(auth) => auth.apply(request),
+            None => request,
+        }
+        .send()
+        .await?;
+        if response.status() != StatusCode::UNAUTHORIZED {
+            return Ok(response.status());
+        }
+
+        let Some(challenge) = response
+            .headers()
+            .get(WWW_AUTHENTICATE)
+            .and_then(|h| h.to_str().ok())
+            .and_then(parse_bearer_challenge)
+        else {
+            return Ok(response.status());
+        };
+        let Some(realm) = challenge.get("realm") else {
+            return Ok(response.status());
+        };
+        let query: Vec<(&str, &str)> = ["service", "scope"]
+            .into_iter()
+            .filter_map(|key| challenge.get(key).map(|v| (key, v.as_str())))
+            .collect();
+        let request = self.http_client.get(realm).query(&query);
+        let token: TokenResponse = match auth {
+            Some(auth) => auth.apply(request),
+            None => request,
+        }
+        .send()
+        .await?
+        .error_for_status()?
+        .json()
+        .await?;
+        let Some(token) = token.token.or(token.access_token) else {
+            return Ok(StatusCode::UNAUTHORIZED);
+        };
+
+        let response = self
+            .http_client
+            .head(&url)
+            .header(ACCEPT, MANIFEST_MEDIA_TYPES)
+            .bearer_auth(token)
+            .send()
+            .await?;
+        Ok(response.status())
+    }
+}
+
+/// Credentials for `registry` from the default ServiceAccount's imagePullSecrets
+async fn pull_secret_auth(
+    client: &Client,
+    namespace: &str,
+    registry: &str,
+) -> Result<Option<RegistryAuth>> {
+    let service_accounts: Api<ServiceAccount> = Api::namespaced(client.clone(), namespace);
+    let Some(service_account) = service_accounts
+        .get_opt("default")
+        .await
+        .map_err(Error::KubeError)?
+    else {
+        return Ok(None);
+    };
+
+    let secrets: Api<Secret> = Api::namespaced(client.clone(), namespace);
+    for reference in service_account.image_pull_secrets.unwrap_or_default() {
+        let Some(name) = reference.name else {
+            continue;
+        };
+        let Some(secret) = secrets.get_opt(&name).await.map_err(Error::KubeError)? else {
+            continue;
+        };
+        let auth = secret
+            .data
+            .as_ref()
+            .and_then(|d| d.get(".dockerconfigjson"))
+            .and_then(|config| auth_for_registry(&config.0, registry));
+        if auth.is_some() {
+            return Ok(auth);
+        }
+    }
+    Ok(None)
+}
+
+/// Apply `missing` to `ImageNotFound` in `node_conditions`
+///
+/// Returns `None` when the conditions already reflect it, otherwise whether
+/// the condition was newly set.
+fn set_image_not_found(
+    node_conditions: &mut Vec<Condition>,
+    missing: Option<&str>,
+) -> Option<bool> {
+    let existing =
+        conditions::find_condition(node_conditions, conditions::CONDITION_TYPE_IMAGE_NOT_FOUND);
+    let was_missing = existing.is_some_and(|c| c.status == conditions::CONDITION_STATUS_TRUE);
+
+    match missing {
+        Some(image) => {
+            let message = format!(
+                "Image {image} was not found in its registry; fix spec.version or spec.imageOverride"
+            );
+            if existing.is_some_and(|c| was_missing && c.message == message) {
+                return None;
+            }
+            conditions::set_condition(
+                node_conditions,
+                conditions::CONDITION_TYPE_IMAGE_NOT_FOUND,
+                conditions::CONDITION_STATUS_TRUE,
+                REASON_MANIFEST_NOT_FOUND,
+                &message,
+            );
+        }
+        None if existing.is_none() => return None,
+        None => conditions::remove_condition(
+            node_conditions,
+            conditions::CONDITION_TYPE_IMAGE_NOT_FOUND,
+        ),
+    }
+    Some(missing.is_some() && !was_missing)
+}
+
+/// Set `ImageNotFound=True` for a missing `image`, or clear it once found
+///
+/// Returns `true` when the condition was newly set in the live status.
+pub async fn update_image_condition(
+    client: &Client,
+    node: &StellarNode,
+    missing: Option<&str>,
+) -> Result<bool> {
+    let mut snapshot = node
+        .status
+        .as_ref()
+        .map(|s| s.conditions.clone())
+        .unwrap_or_default();
+    if set_image_not_found(&mut snapshot, missing).is_none() {
+        return Ok(false);
+    }
+
+    conditions::update_conditions(client, node, json!({}), |node_conditions| {
+        set_image_not_found(node_conditions, missing).unwrap_or(false)
+    })
+    .await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_docker_hub_images() {
+        let image = ImageReference::parse("stellar/stellar-core:21.0.0");
+        assert_eq!(image.registry, "docker.io");
+        assert_eq!(image.repository, "stellar/stellar-core");
+        assert_eq!(image.reference, "21.0.0");
+        assert_eq!(
+            image.manifest_url(),
+            "https://registry-1.docker.io/v2/stellar/stellar-core/manifests/21.0.0"
+        );
+
+        let image = ImageReference::parse("nginx");
+        assert_eq!(image.repository, "library/nginx");
+        assert_eq!(image.reference, "latest");
+    }
+
+    #[test]
+    fn test_parse_private_registry_and_digest() {
+        let image = ImageReference::parse("registry.example.com:5000/team/horizon:v2.31.0");
+        assert_eq!(image.registry, "registry.example.com:5000");
+        assert_eq!(image.repository, "team/horizon");
+        assert_eq!(image.reference, "v2.31.0");
+
+        let image = ImageReference::parse("ghcr.io/stellar/horizon@sha256:abc123");
+        assert_eq!(image.registry, "ghcr.io");
+        assert_eq!(image.repository, "stellar/horizon");
+        assert_eq!(image.reference, "sha256:abc123");
+        assert_eq!(
+            image.manifest_url(),
+            "https://ghcr.io/v2/stellar/horizon/manifests/sha256:abc123"
+        );
+    }
+
+    /// Serve `status` to every request on a loopback port
+    async fn fake_registry(status: &'static str) -> String {
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            while let Ok((mut stream, _)) = listener.accept().await {
+                let mut request = [0u8; 4096];
+                let _ = stream.read(&mut request).await;
+                let response =
+                    format!("HTTP/1.1 {status}\r\nContent-Length: 0\r\nConnection: close\r\n\r\n");
+                let _ = stream.write_all(response.as_bytes()).await;
+            }
+        });
+        address.to_string()
+    }
+
+    #[tokio::test]
+    async fn test_nonexistent_tag_is_not_found() {
+        let registry = fake_registry("404 Not Found").await;
+        let verifier = ImageVerifier::new();
+        assert_eq!(
+            verifier
+                .check_image(&format!("{registry}/stellar/horizon:v9.9.9-typo"), None)
+                .await,
+            ImageCheck::NotFound
+        );
+    }
+
+    #[tokio::test]
+    async fn test_existing_image_is_found() {
+        let registry = fake_registry("200 OK").await;
+        let verifier = ImageVerifier::new();
+        let image = format!("{registry}/stellar/horizon:v2.31.0");
+        assert_eq!(verifier.check_image(&image, None).await, ImageCheck::Found);
+        assert!(verifier.found.lock().unwrap().contains(&image));
+    }
+
+    #[tokio::test]
+    async fn test_unreachable_registry_is_unverified() {
+        let registry = fake_registry("500 Internal Server Error").await;
+        let verifier = ImageVerifier::new();
+        assert!(matches!(
+            verifier
+                .check_image(&format!("{registry}/stellar/horizon:v2.31.0"), None)
+                .await,
+            ImageCheck::Unverified(_)
+        ));
+    }
+
+    #[test]
+    fn test_parse_bearer_challenge() {
+        let challenge = parse_bearer_challenge(
+            r#"Bearer realm="https://auth.docker.io/token",service="registry.docker.io",scope="repository:stellar/horizon:pull,push""#,
+        )
+        .unwrap();
+        assert_eq!(challenge["realm"], "https://auth.docker.io/token");
+        assert_eq!(challenge["service"], "registry.docker.io");
+        assert_eq!(challenge["scope"], "repository:stellar/horizon:pull,push");
+        assert_eq!(parse_bearer_challenge(r#"Basic realm="x""#), None);
+    }
+
+    #[test]
+    fn test_auth_for_registry() {
+        let config = json!({
+            "auths": {
+                "https://index.docker.io/v1/": { "auth": "dXNlcjpwYXNz" },
+                "registry.example.com": { "username": "ci", "password": "secret" },
+            }
+        })
+        .to_string();
+        assert_eq!(
+            auth_for_registry(config.as_bytes(), "docker.io").and_then(|a| a.auth),
+            Some("dXNlcjpwYXNz".to_string())
+        );
+        assert_eq!(
+            auth_for_registry(config.as_bytes(), "registry.example.com").and_then(|a| a.username),
+            Some("ci".to_string())
+        );
+        assert_eq!(auth_for_registry(config.as_bytes(), "ghcr.io"), None);
+    }
+}
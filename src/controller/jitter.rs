@@ -0,0 +1,147 @@
+//! Requeue jitter
+//!
+//! Nodes created together (e.g. from a single Helm release) would otherwise be
+//! requeued at the same instant forever, hitting the API server and the Stellar
+//! endpoints in bursts. By default each node gets a stable offset of up to
+//! ±`--requeue-jitter-percent` of the requeue interval, derived from its
+//! namespace and name. A node setting `spec.requeueJitterPercent` instead adds a
+//! fresh random delay of up to that percentage on every requeue.
+
+use std::time::Duration;
+
+use rand::Rng;
+
+use crate::crd::MAX_REQUEUE_JITTER_PERCENT;
+
+/// Resolution of the per-node offset within the ±percent range
+const JITTER_STEPS: u64 = 2001;
+
+/// FNV-1a, used instead of `DefaultHasher` so offsets stay stable across builds
+fn fnv1a(key: &str) -> u64 {
+    const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+    const PRIME: u64 = 0x0000_0100_0000_01b3;
+
+    key.bytes().fold(OFFSET_BASIS, |hash, byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(PRIME)
+    })
+}
+
+/// Apply a stable per-node jitter of up to ±`percent` to `base`
+///
+/// The same `key` always yields the same interval, so a node keeps a steady
+/// cadence while different nodes are spread across the jitter window.
+/// `percent` is clamped to [`MAX_REQUEUE_JITTER_PERCENT`].
+pub fn jittered_requeue(base: Duration, key: &str, percent: u8) -> Duration {
+    let percent = percent.min(MAX_REQUEUE_JITTER_PERCENT);
+    if percent == 0 || base.is_zero() {
+        return base;
+    }
+
+    let base_ms = base.as_millis() as i128;
+    let window_ms = base_ms * i128::from(percent) / 100;
+    // Position within [-1000, 1000] per mille of the window
+    let position = (fnv1a(key) % JITTER_STEPS) as i128 - (JITTER_STEPS as i128 / 2);
+    let offset_ms = window_ms * position / (JITTER_STEPS as i128 / 2);
+
+    Duration::from_millis((base_ms + offset_ms).max(0) as u64)
+}
+
+/// Add a random delay of up to `percent` of `base`, drawn on every call
+///
+/// `percent` is clamped to [`MAX_REQUEUE_JITTER_PERCENT`].
+pub fn random_requeue(base: Duration, percent: u64) -> Duration {
+    let percent = percent.min(u64::from(MAX_REQUEUE_JITTER_PERCENT));
+    let window_ms = base.as_millis() as u64 * percent / 100;
+    if window_ms == 0 {
+        return base;
+    }
+    base + Duration::from_millis(rand::rng().random_range(0..window_ms))
+}
+
+/// Requeue interval for a node: random jitter when `spec_percent` is set,
+/// otherwise the stable per-node jitter at the operator-wide percentage
+pub fn requeue_interval(
+    base: Duration,
+    key: &str,
+    spec_percent: Option<u64>,
+    operator_percent: u8,
+) -> Duration {
+    match spec_percent {
+        Some(percent) => random_requeue(base, percent),
+        None => jittered_requeue(base, key, operator_percent),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_jitter_is_deterministic() {
+        let base = Duration::from_secs(60);
+        assert_eq!(
+            jittered_requeue(base, "stellar/validator-1", 10),
+            jittered_requeue(base, "stellar/validator-1", 10)
+        );
+    }
+
+    #[test]
+    fn test_jitter_stays_within_bounds() {
+        let base = Duration::from_secs(60);
+        for i in 0..500 {
+            let d = jittered_requeue(base, &format!("ns/node-{i}"), 10);
+            assert!(d >= Duration::from_secs(54) && d <= Duration::from_secs(66));
+        }
+    }
+
+    #[test]
+    fn test_jitter_spreads_nodes() {
+        let base = Duration::from_secs(60);
+        let intervals: std::collections::BTreeSet<_> = (0..100)
+            .map(|i| jittered_requeue(base, &format!("ns/node-{i}"), 10))
+            .collect();
+        assert!(intervals.len() > 50);
+        assert!(intervals.iter().any(|d| *d < base));
+        assert!(intervals.iter().any(|d| *d > base));
+    }
+
+    #[test]
+    fn test_zero_percent_disables_jitter() {
+        let base = Duration::from_secs(60);
+        assert_eq!(jittered_requeue(base, "ns/node", 0), base);
+    }
+
+    #[test]
+    fn test_percent_is_clamped() {
+        let base = Duration::from_secs(100);
+        for i in 0..200 {
+            let d = jittered_requeue(base, &format!("ns/node-{i}"), 255);
+            assert!(d >= Duration::from_secs(50) && d <= Duration::from_secs(150));
+        }
+    }
+
+    #[test]
+    fn test_spec_percent_adds_random_positive_jitter() {
+        let base = Duration::from_secs(60);
+        let secs: Vec<f64> = (0..100)
+            .map(|_| requeue_interval(base, "ns/node", Some(10), 0).as_secs_f64())
+            .collect();
+        assert!(secs.iter().all(|s| (60.0..66.0).contains(s)));
+
+        // Uniform over a 6s window: standard deviation 6 / sqrt(12) ~ 1.73s
+        let mean = secs.iter().sum::<f64>() / secs.len() as f64;
+        let std_dev =
+            (secs.iter().map(|s| (s - mean).powi(2)).sum::<f64>() / secs.len() as f64).sqrt();
+        assert!((1.0..2.5).contains(&std_dev), "std dev {std_dev}");
+    }
+
+    #[test]
+    fn test_operator_percent_is_used_without_spec_percent() {
+        let base = Duration::from_secs(60);
+        assert_eq!(
+            requeue_interval(base, "ns/node", None, 10),
+            jittered_requeue(base, "ns/node", 10)
+        );
+        assert_eq!(requeue_interval(base, "ns/node", Some(0), 10), base);
+    }
+}
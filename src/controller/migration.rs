@@ -0,0 +1,247 @@
+//! Pre-upgrade database migrations for Horizon
+//!
+//! Before a Horizon workload is patched to a new version, the operator runs the
+//! database migration as a one-shot Job using the new image. The workload is
+//! only upgraded once the Job succeeds; if it fails, the previous version keeps
+//! running and a `MigrationFailed` condition is set with a summary of the Job's
+//! logs.
+//!
+//! An explicit rollback can be requested with the
+//! `stellar.org/migration-rollback-count` annotation, which runs
+//! `horizon db migrate down <count>` and removes the annotation on success.
+
+use k8s_openapi::api::batch::v1::Job;
+use k8s_openapi::api::core::v1::Pod;
+use kube::{
+    api::{Api, ListParams, LogParams, Patch, PatchParams},
+    client::Client,
+    ResourceExt,
+};
+use serde_json::json;
+use tracing::{info, warn};
+
+use crate::crd::{NodeType, StellarNode};
+use crate::error::{Error, Result};
+
+use super::resources;
+
+/// Annotation requesting `horizon db migrate down <count>`
+pub const MIGRATION_ROLLBACK_ANNOTATION: &str = "stellar.org/migration-rollback-count";
+
+/// Upgrade script, also initializes an empty database
+const MIGRATE_UP_SCRIPT: &str = "horizon db upgrade || horizon db init";
+
+/// Maximum number of log lines included in the failure summary
+const LOG_SUMMARY_LINES: i64 = 20;
+
+/// Outcome of the pre-upgrade migration step
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum MigrationOutcome {
+    /// No migration needed for the current version
+    NotRequired,
+    /// The migration Job is still running
+    InProgress,
+    /// The migration for `version` completed successfully
+    Succeeded { version: String },
+    /// The migration Job failed; the workload must not be upgraded
+    Failed { job_name: String, summary: String },
+}
+
+/// State of a Job derived from its status counters
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum JobState {
+    Running,
+    Succeeded,
+    Failed,
+}
+
+/// Derive the state of a Job from its status
+pub fn job_state(job: &Job) -> JobState {
+    let status = job.status.as_ref();
+    if status.and_then(|s| s.succeeded).unwrap_or(0) > 0 {
+        JobState::Succeeded
+    } else if status.and_then(|s| s.failed).unwrap_or(0) > 0 {
+        JobState::Failed
+    } else {
+        JobState::Running
+    }
+}
+
+/// Whether the node needs a migration for its current version
+pub fn migration_required(node: &StellarNode) -> bool {
+    if node.spec.node_type != NodeType::Horizon {
+        return false;
+    }
+    let auto_migration = node
+        .spec
+        .horizon_config
+        .as_ref()
+        .is_some_and(|h| h.auto_migration);
+    let last_migrated = node
+        .status
+        .as_ref()
+        .and_then(|s| s.last_migrated_version.as_deref());
+
+    auto_migration && last_migrated != Some(node.spec.version.as_str())
+}
+
+/// Run (or observe) the pre-upgrade migration Job for the current version
+pub async fn reconcile_migration(client: &Client, node: &StellarNode) -> Result<MigrationOutcome> {
+    if !migration_required(node) {
+        return Ok(MigrationOutcome::NotRequired);
+    }
+
+    let version = node.spec.version.clone();
+    let job_name = resources::migration_job_name(node, &version);
+    let job = resources::ensure_migration_job(client, node, &job_name, MIGRATE_UP_SCRIPT).await?;
+
+    match job_state(&job) {
+        JobState::Running => Ok(MigrationOutcome::InProgress),
+        JobState::Succeeded => {
+            info!(
+                "Migration Job {} succeeded for {}/{}",
+                job_name,
+                node.namespace().unwrap_or_default(),
+                node.name_any()
+            );
+            resources::delete_migration_job(client, node, &job_name).await?;
+            Ok(MigrationOutcome::Succeeded { version })
+        }
+        JobState::Failed => {
+            let summary = job_logs_summary(client, node, &job_name).await;
+            Ok(MigrationOutcome::Failed { job_name, summary })
+        }
+    }
+}
+
+/// Handle an explicit rollback request, returning true while a rollback is pending
+pub async fn reconcile_rollback(client: &Client, node: &StellarNode) -> Result<bool> {
+    let Some(raw) = node.annotations().get(MIGRATION_ROLLBACK_ANNOTATION) else {
+        return Ok(false);
+    };
+    let count: u32 = match raw.trim().parse() {
+        Ok(count) if count > 0 => count,
+        _ => {
+            warn!(
+                "Ignoring invalid {} annotation value '{}' on {}",
+                MIGRATION_ROLLBACK_ANNOTATION,
+                raw,
+                node.name_any()
+            );
+            return Ok(false);
+        }
+    };
+
+    let job_name = resources::migration_job_name(node, &format!("down-{count}"));
+    let script = format!("horizon db migrate down {count}");
+    let job = resources::ensure_migration_job(client, node, &job_name, &script).await?;
+
+    match job_state(&job) {
+        JobState::Running => Ok(true),
+        JobState::Succeeded => {
+            info!("Rolled back {} migration(s) for {}", count, node.name_any());
+            resources::delete_migration_job(client, node, &job_name).await?;
+
+            let namespace = node.namespace().unwrap_or_else(|| "default".to_string());
+            let api: Api<StellarNode> = Api::namespaced(client.clone(), &namespace);
+            let patch = json!({
+                "metadata": { "annotations": { MIGRATION_ROLLBACK_ANNOTATION: null } }
+            });
+            api.patch(
+                &node.name_any(),
+                &PatchParams::apply("stellar-operator"),
+                &Patch::Merge(&patch),
+            )
+            .await
+            .map_err(Error::KubeError)?;
+            Ok(false)
+        }
+        JobState::Failed => {
+            let summary = job_logs_summary(client, node, &job_name).await;
+            Err(Error::ConfigError(format!(
+                "Migration rollback Job {job_name} failed: {summary}"
+            )))
+        }
+    }
+}
+
+/// Collect the tail of the migration Job's pod logs for status and events
+async fn job_logs_summary(client: &Client, node: &StellarNode, job_name: &str) -> String {
+    let namespace = node.namespace().unwrap_or_else(|| "default".to_string());
+    let pods: Api<Pod> = Api::namespaced(client.clone(), &namespace);
+    let lp = ListParams::default().labels(&format!("job-name={job_name}"));
+
+    let pod_name = match pods.list(&lp).await {
+        Ok(list) => list.items.into_iter().next().map(|p| p.name_any()),
+        Err(e) => {
+            warn!("Failed to list pods for Job {}: {}", job_name, e);
+            None
+        }
+    };
+    let Some(pod_name) = pod_name else {
+        return format!("Job {job_name} failed (no pod logs available)");
+    };
+
+    let params = LogParams {
+        tail_lines: Some(LOG_SUMMARY_LINES),
+        ..Default::default()
+    };
+    match pods.logs(&pod_name, &params).await {
+        Ok(logs) => summarize_logs(&logs),
+        Err(e) => format!("Job {job_name} failed (could not read logs: {e})"),
+    }
+}
+
+/// Collapse log output into a single bounded line
+pub fn summarize_logs(logs: &str) -> String {
+    const MAX_LEN: usize = 1024;
+    let joined = logs
+        .lines()
+        .map(str::trim)
+        .filter(|l| !l.is_empty())
+        .collect::<Vec<_>>()
+        .join(" | ");
+    if joined.len() <= MAX_LEN {
+        return joined;
+    }
+    let mut cut = joined.len() - MAX_LEN;
+    while !joined.is_char_boundary(cut) {
+        cut += 1;
+    }
+    format!("...{}", &joined[cut..])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use k8s_openapi::api::batch::v1::JobStatus;
+
+    fn job_with(succeeded: Option<i32>, failed: Option<i32>) -> Job {
+        Job {
+            status: Some(JobStatus {
+                succeeded,
+                failed,
+                ..Default::default()
+            }),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_job_state() {
+        assert_eq!(job_state(&Job::default()), JobState::Running);
+        assert_eq!(job_state(&job_with(Some(1), None)), JobState::Succeeded);
+        assert_eq!(job_state(&job_with(None, Some(1))), JobState::Failed);
+    }
+
+    #[test]
+    fn test_summarize_logs_keeps_tail() {
+        let logs = "line one\n\nline two\n";
+        assert_eq!(summarize_logs(logs), "line one | line two");
+
+        let long = "x".repeat(2000);
+        let summary = summarize_logs(&long);
+        assert!(summary.starts_with("..."));
+        assert_eq!(summary.len(), 1027);
+    }
+}
@@ -4,36 +4,87 @@
 
 pub mod resource_meta;
 
+mod api_timeout;
 mod archive_health;
+mod autoscaling;
+mod capabilities;
 pub mod captive_core;
+mod catchup_gate;
 pub mod conditions;
+mod config_template;
+mod core_version;
+mod crd_discovery;
 pub mod cross_cluster;
 pub mod cve;
 mod cve_reconciler;
 #[cfg(test)]
 mod cve_test;
+mod database_readiness;
+mod dependencies;
 pub mod dr;
+mod failure_notifier;
+mod failure_policy;
+#[cfg(test)]
+mod fake_api;
 mod finalizers;
 mod health;
 #[cfg(test)]
 mod health_test;
+mod hooks;
+mod image_check;
+mod jitter;
 #[cfg(feature = "metrics")]
 pub mod metrics;
+mod migration;
 pub mod mtls;
+mod node_selector;
+mod oom;
+pub mod operator_control;
 pub mod peer_discovery;
+mod permission_check;
+mod precheck;
+mod protocol_version;
+mod pvc_resize;
+mod quorum_builder;
+mod quorum_slice;
+mod reconcile_concurrency;
+mod reconcile_pause;
+mod reconcile_trigger;
 mod reconciler;
 mod remediation;
 mod resources;
+mod stellar_toml;
+mod storage_usage;
+mod validator_key;
 mod vsl;
 
+pub use api_timeout::{set_api_call_timeout, DEFAULT_API_CALL_TIMEOUT_SECONDS};
 pub use archive_health::{calculate_backoff, check_history_archive_health, ArchiveHealthResult};
+pub use capabilities::{detect_capabilities, ClusterCapabilities};
+pub use catchup_gate::CATCHUP_ANNOTATION;
+pub use config_template::check_spec_templates;
 pub use cross_cluster::{check_peer_latency, ensure_cross_cluster_services, PeerLatencyStatus};
 pub use cve_reconciler::reconcile_cve_patches;
+pub use failure_notifier::{
+    FailureNotifier, DEFAULT_FAILURE_NOTIFICATION_INTERVAL_SECONDS,
+    DEFAULT_FAILURE_NOTIFICATION_THRESHOLD,
+};
 pub use finalizers::STELLAR_NODE_FINALIZER;
-pub use health::{check_node_health, HealthCheckResult};
+pub use health::{build_health_http_client, check_node_health, HealthCheckResult};
+pub use image_check::ImageVerifier;
 pub use peer_discovery::{
     get_peers_from_config_map, trigger_peer_config_reload, PeerDiscoveryConfig,
     PeerDiscoveryManager, PeerInfo,
 };
+pub use reconcile_concurrency::ReconcileLimiter;
+pub use reconcile_pause::{set_reconciliation_paused, PAUSE_RECONCILIATION_ANNOTATION};
 pub use reconciler::{run_controller, ControllerState};
 pub use remediation::{can_remediate, check_stale_node, RemediationLevel, StaleCheckResult};
+pub use resources::{
+    build_config_map, build_deployment, build_grafana_dashboard, build_history_cache_pvc,
+    build_history_gc_cron_job, build_network_policy, build_prometheus_rule, build_pvc,
+    build_service, build_service_monitor, build_stellar_toml_certificate,
+    build_stellar_toml_config_map, build_stellar_toml_deployment, build_stellar_toml_service,
+    grafana_dashboard_json, GRAFANA_DASHBOARD_LABEL, GRAFANA_FOLDER_ANNOTATION,
+    HISTORY_CACHE_MOUNT_PATH, QUORUM_GROUP_LABEL, STANDBY_ANNOTATION, TOPOLOGY_MODE_ANNOTATION,
+};
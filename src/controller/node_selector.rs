@@ -0,0 +1,126 @@
+//! Automatic node selector detection
+//!
+//! With `spec.autoDetectNodeSelector`, the operator looks for Kubernetes nodes
+//! labelled `stellar.org/node-type=validator` (Validators) or
+//! `stellar.org/node-type=rpc` (Horizon and Soroban RPC) and pins the pods to
+//! them. The detected selector is cached in `status.detectedNodeSelector`, so
+//! nodes are listed only until a match is found. Without matching nodes the
+//! pods are scheduled normally.
+
+use std::collections::BTreeMap;
+
+use k8s_openapi::api::core::v1::Node;
+use kube::{
+    api::{Api, ListParams, Patch, PatchParams},
+    client::Client,
+    ResourceExt,
+};
+use serde_json::json;
+use tracing::{info, warn};
+
+use crate::crd::{NodeType, StellarNode};
+use crate::error::{Error, Result};
+
+/// Label marking the Kubernetes nodes reserved for a Stellar workload type
+pub const NODE_TYPE_LABEL: &str = "stellar.org/node-type";
+
+/// `stellar.org/node-type` value expected for `node_type`
+pub fn node_type_label_value(node_type: &NodeType) -> &'static str {
+    match node_type {
+        NodeType::Validator => "validator",
+        NodeType::Horizon | NodeType::SorobanRpc => "rpc",
+    }
+}
+
+/// Node selector pinning `node_type` to its labelled nodes
+pub fn node_selector_for(node_type: &NodeType) -> BTreeMap<String, String> {
+    BTreeMap::from([(
+        NODE_TYPE_LABEL.to_string(),
+        node_type_label_value(node_type).to_string(),
+    )])
+}
+
+/// Detect the node selector for `node`, if any Kubernetes node carries the label
+///
+/// Returns `Ok(None)` when auto-detection is disabled, a selector is already
+/// cached in status, or no labelled nodes exist.
+pub async fn detect_node_selector(
+    client: &Client,
+    node: &StellarNode,
+) -> Result<Option<BTreeMap<String, String>>> {
+    if !node.spec.auto_detect_node_selector {
+        return Ok(None);
+    }
+    let cached = node
+        .status
+        .as_ref()
+        .and_then(|s| s.detected_node_selector.as_ref());
+    if cached.is_some() {
+        return Ok(None);
+    }
+
+    let selector = node_selector_for(&node.spec.node_type);
+    let label = format!(
+        "{NODE_TYPE_LABEL}={}",
+        node_type_label_value(&node.spec.node_type)
+    );
+    let nodes: Api<Node> = Api::all(client.clone());
+    let matching = nodes
+        .list(&ListParams::default().labels(&label).limit(1))
+        .await
+        .map_err(Error::KubeError)?;
+
+    if matching.items.is_empty() {
+        warn!(
+            "No nodes labelled {} found for {}/{}; scheduling without a node selector",
+            label,
+            node.namespace().unwrap_or_default(),
+            node.name_any()
+        );
+        return Ok(None);
+    }
+
+    info!(
+        "Detected node selector {} for {}/{}",
+        label,
+        node.namespace().unwrap_or_default(),
+        node.name_any()
+    );
+    Ok(Some(selector))
+}
+
+/// Cache the detected selector in `status.detectedNodeSelector`
+pub async fn update_detected_node_selector_status(
+    client: &Client,
+    node: &StellarNode,
+    selector: &BTreeMap<String, String>,
+) -> Result<()> {
+    let namespace = node.namespace().unwrap_or_else(|| "default".to_string());
+    let api: Api<StellarNode> = Api::namespaced(client.clone(), &namespace);
+    let patch = json!({ "status": { "detectedNodeSelector": selector } });
+
+    api.patch_status(
+        &node.name_any(),
+        &PatchParams::apply("stellar-operator"),
+        &Patch::Merge(&patch),
+    )
+    .await
+    .map_err(Error::KubeError)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_node_type_label_values() {
+        assert_eq!(node_type_label_value(&NodeType::Validator), "validator");
+        assert_eq!(node_type_label_value(&NodeType::Horizon), "rpc");
+        assert_eq!(node_type_label_value(&NodeType::SorobanRpc), "rpc");
+        assert_eq!(
+            node_selector_for(&NodeType::Validator).get(NODE_TYPE_LABEL),
+            Some(&"validator".to_string())
+        );
+    }
+}
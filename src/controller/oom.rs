@@ -0,0 +1,218 @@
+//! OOMKilled detection
+//!
+//! A container killed for exceeding its memory limit is restarted within
+//! seconds, so the kill is easy to miss. With `spec.emitOomEventOnKill` (the
+//! default), the operator looks for containers whose
+//! `lastState.terminated.reason` is `OOMKilled` and reports each kill once as a
+//! Warning `OOMKilled` event. The newest reported kill is kept in
+//! `status.lastOomKilledAt`.
+
+use chrono::{DateTime, Utc};
+use k8s_openapi::api::core::v1::Pod;
+use kube::{
+    api::{Api, ListParams, Patch, PatchParams},
+    client::Client,
+    ResourceExt,
+};
+use serde_json::json;
+use tracing::debug;
+
+use crate::crd::StellarNode;
+use crate::error::{Error, Result};
+
+/// Termination reason the kubelet reports for memory limit kills
+pub const OOM_KILLED_REASON: &str = "OOMKilled";
+
+/// A container found OOM killed
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct OomKill {
+    pub pod: String,
+    pub container: String,
+    /// Memory limit of the container, as set in the pod spec
+    pub memory_limit: Option<String>,
+    pub finished_at: DateTime<Utc>,
+}
+
+impl OomKill {
+    /// Event message for the kill
+    pub fn message(&self) -> String {
+        format!(
+            "Container {} in pod {} was OOM killed (memory limit: {})",
+            self.container,
+            self.pod,
+            self.memory_limit.as_deref().unwrap_or("none")
+        )
+    }
+}
+
+/// Containers of `pod` whose last termination was an OOM kill
+pub fn pod_oom_kills(pod: &Pod) -> Vec<OomKill> {
+    let Some(statuses) = pod
+        .status
+        .as_ref()
+        .and_then(|s| s.container_statuses.as_ref())
+    else {
+        return Vec::new();
+    };
+
+    statuses
+        .iter()
+        .filter_map(|status| {
+            let terminated = status.last_state.as_ref()?.terminated.as_ref()?;
+            if terminated.reason.as_deref() != Some(OOM_KILLED_REASON) {
+                return None;
+            }
+            Some(OomKill {
+                pod: pod.name_any(),
+                container: status.name.clone(),
+                memory_limit: memory_limit(pod, &status.name),
+                finished_at: terminated.finished_at.as_ref()?.0,
+            })
+        })
+        .collect()
+}
+
+fn memory_limit(pod: &Pod, container: &str) -> Option<String> {
+    pod.spec
+        .as_ref()?
+        .containers
+        .iter()
+        .find(|c| c.name == container)?
+        .resources
+        .as_ref()?
+        .limits
+        .as_ref()?
+        .get("memory")
+        .map(|q| q.0.clone())
+}
+
+/// OOM kills across `pods` that finished after `since`, oldest first
+pub fn new_oom_kills(pods: &[Pod], since: Option<DateTime<Utc>>) -> Vec<OomKill> {
+    let mut kills: Vec<OomKill> = pods
+        .iter()
+        .flat_map(pod_oom_kills)
+        .filter(|kill| since.is_none_or(|since| kill.finished_at > since))
+        .collect();
+    kills.sort_by_key(|kill| kill.finished_at);
+    kills
+}
+
+/// Find OOM kills of the node's containers not yet reported
+pub async fn detect_oom_kills(client: &Client, node: &StellarNode) -> Result<Vec<OomKill>> {
+    let namespace = node.namespace().unwrap_or_else(|| "default".to_string());
+    let pods: Api<Pod> = Api::namespaced(client.clone(), &namespace);
+
+    let lp =
+        ListParams::default().labels(&format!("app.kubernetes.io/instance={}", node.name_any()));
+    let pod_list = pods.list(&lp).await.map_err(Error::KubeError)?;
+
+    let since = node
+        .status
+        .as_ref()
+        .and_then(|s| s.last_oom_killed_at.as_deref())
+        .and_then(|t| DateTime::parse_from_rfc3339(t).ok())
+        .map(|t| t.with_timezone(&Utc));
+    let kills = new_oom_kills(&pod_list.items, since);
+    debug!(
+        "Found {} new OOM kills for {}/{}",
+        kills.len(),
+        namespace,
+        node.name_any()
+    );
+    Ok(kills)
+}
+
+/// Record the newest reported kill in `status.lastOomKilledAt`
+pub async fn update_oom_killed_status(
+    client: &Client,
+    node: &StellarNode,
+    killed_at: DateTime<Utc>,
+) -> Result<()> {
+    let namespace = node.namespace().unwrap_or_else(|| "default".to_string());
+    let api: Api<StellarNode> = Api::namespaced(client.clone(), &namespace);
+    let patch = json!({ "status": { "lastOomKilledAt": killed_at.to_rfc3339() } });
+
+    api.patch_status(
+        &node.name_any(),
+        &PatchParams::apply("stellar-operator"),
+        &Patch::Merge(&patch),
+    )
+    .await
+    .map_err(Error::KubeError)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use k8s_openapi::api::core::v1::{
+        Container, ContainerState, ContainerStateTerminated, ContainerStatus, PodSpec, PodStatus,
+        ResourceRequirements,
+    };
+    use k8s_openapi::apimachinery::pkg::api::resource::Quantity;
+    use k8s_openapi::apimachinery::pkg::apis::meta::v1::{ObjectMeta, Time};
+    use std::collections::BTreeMap;
+
+    fn pod(reason: &str, finished_at: &str) -> Pod {
+        Pod {
+            metadata: ObjectMeta {
+                name: Some("validator-0".to_string()),
+                ..Default::default()
+            },
+            spec: Some(PodSpec {
+                containers: vec![Container {
+                    name: "stellar-node".to_string(),
+                    resources: Some(ResourceRequirements {
+                        limits: Some(BTreeMap::from([(
+                            "memory".to_string(),
+                            Quantity("8Gi".to_string()),
+                        )])),
+                        ..Default::default()
+                    }),
+                    ..Default::default()
+                }],
+                ..Default::default()
+            }),
+            status: Some(PodStatus {
+                container_statuses: Some(vec![ContainerStatus {
+                    name: "stellar-node".to_string(),
+                    last_state: Some(ContainerState {
+                        terminated: Some(ContainerStateTerminated {
+                            reason: Some(reason.to_string()),
+                            exit_code: 137,
+                            finished_at: Some(Time(finished_at.parse().unwrap())),
+                            ..Default::default()
+                        }),
+                        ..Default::default()
+                    }),
+                    ..Default::default()
+                }]),
+                ..Default::default()
+            }),
+        }
+    }
+
+    #[test]
+    fn test_pod_oom_kills_reports_memory_limit() {
+        let kills = pod_oom_kills(&pod(OOM_KILLED_REASON, "2024-01-01T00:00:00Z"));
+        assert_eq!(kills.len(), 1);
+        assert_eq!(kills[0].container, "stellar-node");
+        assert_eq!(kills[0].memory_limit.as_deref(), Some("8Gi"));
+        assert!(kills[0].message().contains("memory limit: 8Gi"));
+
+        assert!(pod_oom_kills(&pod("Error", "2024-01-01T00:00:00Z")).is_empty());
+        assert!(pod_oom_kills(&Pod::default()).is_empty());
+    }
+
+    #[test]
+    fn test_new_oom_kills_skips_reported() {
+        let pods = [pod(OOM_KILLED_REASON, "2024-01-01T00:00:00Z")];
+        assert_eq!(new_oom_kills(&pods, None).len(), 1);
+
+        let reported = "2024-01-01T00:00:00Z".parse().ok();
+        assert!(new_oom_kills(&pods, reported).is_empty());
+
+        let earlier = "2023-12-31T23:00:00Z".parse().ok();
+        assert_eq!(new_oom_kills(&pods, earlier).len(), 1);
+    }
+}
@@ -0,0 +1,213 @@
+//! Operator-wide control switches
+//!
+//! A well-known ConfigMap (`stellar-operator-control`) in the operator namespace
+//! acts as a fleet-wide "big red button". Setting `paused: "true"` stops all
+//! mutating reconciliation until the key is removed or set to anything else.
+//!
+//! The same ConfigMap can list namespaces under `observeOnlyNamespaces`
+//! (comma separated). StellarNodes in those namespaces still get status and
+//! metrics, but the operator never creates, updates or deletes their resources.
+//! This supports handing resources over gradually from another management tool.
+
+use std::collections::BTreeSet;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use futures::channel::mpsc;
+use futures::{SinkExt, StreamExt};
+use k8s_openapi::api::core::v1::ConfigMap;
+use kube::{
+    api::Api,
+    client::Client,
+    runtime::{watcher, WatchStreamExt},
+};
+use tracing::{info, warn};
+
+use crate::error::{Error, Result};
+
+/// Name of the operator control ConfigMap
+pub const OPERATOR_CONTROL_CONFIGMAP: &str = "stellar-operator-control";
+
+/// Key in the control ConfigMap that pauses all reconciliation
+pub const PAUSED_KEY: &str = "paused";
+
+/// Key in the control ConfigMap listing namespaces that are observed but never mutated
+pub const OBSERVE_ONLY_NAMESPACES_KEY: &str = "observeOnlyNamespaces";
+
+/// Tracks the last observed pause state so transitions are logged once
+static FLEET_PAUSED: AtomicBool = AtomicBool::new(false);
+
+/// Whether the control ConfigMap marks the fleet as paused
+pub fn is_paused_config_map(cm: &ConfigMap) -> bool {
+    cm.data
+        .as_ref()
+        .and_then(|data| data.get(PAUSED_KEY))
+        .map(|value| value.trim().eq_ignore_ascii_case("true"))
+        .unwrap_or(false)
+}
+
+/// Namespaces the control ConfigMap marks as observe-only
+pub fn observe_only_namespaces(cm: &ConfigMap) -> BTreeSet<String> {
+    cm.data
+        .as_ref()
+        .and_then(|data| data.get(OBSERVE_ONLY_NAMESPACES_KEY))
+        .map(|value| {
+            value
+                .split(',')
+                .map(str::trim)
+                .filter(|ns| !ns.is_empty())
+                .map(str::to_string)
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Operator-wide switches read from the control ConfigMap
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct OperatorControl {
+    /// All mutating reconciliation is halted
+    pub paused: bool,
+    /// Namespaces whose StellarNodes are observed but never mutated
+    pub observe_only_namespaces: BTreeSet<String>,
+}
+
+impl OperatorControl {
+    /// Parse the control ConfigMap
+    pub fn from_config_map(cm: &ConfigMap) -> Self {
+        Self {
+            paused: is_paused_config_map(cm),
+            observe_only_namespaces: observe_only_namespaces(cm),
+        }
+    }
+
+    /// Whether StellarNodes in `namespace` must not have their resources mutated
+    pub fn is_observe_only(&self, namespace: &str) -> bool {
+        self.observe_only_namespaces.contains(namespace)
+    }
+}
+
+/// Load the current operator control switches
+///
+/// A missing ConfigMap means not paused and no observe-only namespaces.
+pub async fn load_operator_control(
+    client: &Client,
+    operator_namespace: &str,
+) -> Result<OperatorControl> {
+    let api: Api<ConfigMap> = Api::namespaced(client.clone(), operator_namespace);
+    let control = api
+        .get_opt(OPERATOR_CONTROL_CONFIGMAP)
+        .await
+        .map_err(Error::KubeError)?
+        .map(|cm| OperatorControl::from_config_map(&cm))
+        .unwrap_or_default();
+
+    record_pause_state(control.paused);
+    Ok(control)
+}
+
+/// Log pause/resume transitions exactly once
+fn record_pause_state(paused: bool) {
+    let previous = FLEET_PAUSED.swap(paused, Ordering::SeqCst);
+    if paused && !previous {
+        warn!(
+            "Fleet-wide pause enabled via ConfigMap {}; all reconciliation is halted",
+            OPERATOR_CONTROL_CONFIGMAP
+        );
+    } else if !paused && previous {
+        info!(
+            "Fleet-wide pause lifted via ConfigMap {}; resuming reconciliation",
+            OPERATOR_CONTROL_CONFIGMAP
+        );
+    }
+}
+
+/// Watch the control ConfigMap and emit a signal whenever the pause state flips
+///
+/// Used to trigger a reconcile of every StellarNode so that paused nodes pick up
+/// the `Paused` condition promptly and resume as soon as the pause is lifted.
+pub fn pause_transitions(client: Client, operator_namespace: String) -> mpsc::Receiver<()> {
+    let (mut tx, rx) = mpsc::channel(1);
+
+    tokio::spawn(async move {
+        let api: Api<ConfigMap> = Api::namespaced(client, &operator_namespace);
+        let config = watcher::Config::default()
+            .fields(&format!("metadata.name={OPERATOR_CONTROL_CONFIGMAP}"));
+        let mut last = false;
+
+        let mut events = watcher(api, config).default_backoff().boxed();
+        while let Some(event) = events.next().await {
+            let paused = match event {
+                Ok(watcher::Event::Apply(cm)) | Ok(watcher::Event::InitApply(cm)) => {
+                    is_paused_config_map(&cm)
+                }
+                Ok(watcher::Event::Delete(_)) => false,
+                Ok(_) => continue,
+                Err(e) => {
+                    warn!("Error watching {}: {}", OPERATOR_CONTROL_CONFIGMAP, e);
+                    continue;
+                }
+            };
+
+            if paused != last {
+                last = paused;
+                if tx.send(()).await.is_err() {
+                    break;
+                }
+            }
+        }
+    });
+
+    rx
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::BTreeMap;
+
+    fn control_map(value: Option<&str>) -> ConfigMap {
+        ConfigMap {
+            data: value.map(|v| BTreeMap::from([(PAUSED_KEY.to_string(), v.to_string())])),
+            ..Default::default()
+        }
+    }
+
+    fn observe_only_map(value: &str) -> ConfigMap {
+        ConfigMap {
+            data: Some(BTreeMap::from([(
+                OBSERVE_ONLY_NAMESPACES_KEY.to_string(),
+                value.to_string(),
+            )])),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_paused_true() {
+        assert!(is_paused_config_map(&control_map(Some("true"))));
+        assert!(is_paused_config_map(&control_map(Some(" True "))));
+    }
+
+    #[test]
+    fn test_not_paused() {
+        assert!(!is_paused_config_map(&control_map(Some("false"))));
+        assert!(!is_paused_config_map(&control_map(Some("yes"))));
+        assert!(!is_paused_config_map(&control_map(None)));
+    }
+
+    #[test]
+    fn test_observe_only_namespaces() {
+        let control = OperatorControl::from_config_map(&observe_only_map(" legacy, migrating ,,"));
+        assert!(control.is_observe_only("legacy"));
+        assert!(control.is_observe_only("migrating"));
+        assert!(!control.is_observe_only("stellar"));
+        assert!(!control.paused);
+    }
+
+    #[test]
+    fn test_no_observe_only_namespaces_by_default() {
+        let control = OperatorControl::from_config_map(&control_map(Some("true")));
+        assert!(control.paused);
+        assert!(control.observe_only_namespaces.is_empty());
+        assert!(!OperatorControl::default().is_observe_only("default"));
+    }
+}
@@ -0,0 +1,247 @@
+//! Startup RBAC permission check
+//!
+//! An operator installed with namespace-only RBAC (or a trimmed ClusterRole)
+//! otherwise starts normally and fails on the first resource operation it is
+//! not allowed to perform. [`check_permissions`] asks the API server for the
+//! operator's own rules with a `SelfSubjectRulesReview`, logs a table of what
+//! is missing and refuses to start when a required permission is absent.
+//! Permissions only used by optional features are reported but not enforced.
+
+use k8s_openapi::api::authorization::v1::{
+    ResourceRule, SelfSubjectRulesReview, SelfSubjectRulesReviewSpec,
+};
+use kube::{
+    api::{Api, PostParams},
+    client::Client,
+};
+use tracing::{error, info, warn};
+
+use crate::error::{Error, Result};
+
+/// A permission the operator uses
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Permission {
+    /// API group ("" for the core group)
+    pub group: &'static str,
+    pub resource: &'static str,
+    pub verb: &'static str,
+    /// Startup fails without it; optional permissions only back optional features
+    pub required: bool,
+}
+
+const fn required(group: &'static str, resource: &'static str, verb: &'static str) -> Permission {
+    Permission {
+        group,
+        resource,
+        verb,
+        required: true,
+    }
+}
+
+const fn optional(group: &'static str, resource: &'static str, verb: &'static str) -> Permission {
+    Permission {
+        group,
+        resource,
+        verb,
+        required: false,
+    }
+}
+
+/// Permissions checked at startup
+pub const OPERATOR_PERMISSIONS: &[Permission] = &[
+    required("stellar.org", "stellarnodes", "get"),
+    required("stellar.org", "stellarnodes", "list"),
+    required("stellar.org", "stellarnodes", "watch"),
+    required("stellar.org", "stellarnodes", "patch"),
+    required("stellar.org", "stellarnodes/status", "patch"),
+    required("apps", "deployments", "list"),
+    required("apps", "deployments", "watch"),
+    required("apps", "deployments", "patch"),
+    required("apps", "deployments", "delete"),
+    required("apps", "statefulsets", "list"),
+    required("apps", "statefulsets", "watch"),
+    required("apps", "statefulsets", "patch"),
+    required("apps", "statefulsets", "delete"),
+    required("", "services", "list"),
+    required("", "services", "watch"),
+    required("", "services", "patch"),
+    required("", "services", "delete"),
+    required("", "configmaps", "list"),
+    required("", "configmaps", "watch"),
+    required("", "configmaps", "get"),
+    required("", "configmaps", "patch"),
+    required("", "configmaps", "delete"),
+    required("", "persistentvolumeclaims", "list"),
+    required("", "persistentvolumeclaims", "watch"),
+    required("", "persistentvolumeclaims", "patch"),
+    required("", "persistentvolumeclaims", "delete"),
+    required("", "secrets", "get"),
+    required("", "pods", "list"),
+    required("", "events", "create"),
+    required("policy", "poddisruptionbudgets", "list"),
+    required("policy", "poddisruptionbudgets", "watch"),
+    required("policy", "poddisruptionbudgets", "patch"),
+    optional("autoscaling", "horizontalpodautoscalers", "patch"),
+    optional("autoscaling", "horizontalpodautoscalers", "delete"),
+    optional("networking.k8s.io", "ingresses", "patch"),
+    optional("networking.k8s.io", "networkpolicies", "patch"),
+    optional("batch", "jobs", "create"),
+    optional("batch", "jobs", "delete"),
+    optional("", "nodes", "list"),
+];
+
+fn matches(values: Option<&Vec<String>>, wanted: &str) -> bool {
+    values.is_some_and(|values| values.iter().any(|v| v == "*" || v == wanted))
+}
+
+/// Whether `rule` grants `permission` on every object of the resource
+///
+/// Rules limited to specific `resourceNames` do not count.
+pub fn rule_allows(rule: &ResourceRule, permission: &Permission) -> bool {
+    rule.resource_names
+        .as_deref()
+        .unwrap_or_default()
+        .is_empty()
+        && matches(rule.api_groups.as_ref(), permission.group)
+        && matches(rule.resources.as_ref(), permission.resource)
+        && rule.verbs.iter().any(|v| v == "*" || v == permission.verb)
+}
+
+/// Permissions in `wanted` not granted by any of `rules`
+pub fn missing_permissions(rules: &[ResourceRule], wanted: &[Permission]) -> Vec<Permission> {
+    wanted
+        .iter()
+        .filter(|p| !rules.iter().any(|rule| rule_allows(rule, p)))
+        .copied()
+        .collect()
+}
+
+/// Render `missing` as an aligned table for the startup log
+pub fn permission_table(missing: &[Permission]) -> String {
+    let mut table = format!(
+        "{:<20} {:<26} {:<8} {}\n",
+        "API GROUP", "RESOURCE", "VERB", "REQUIRED"
+    );
+    for p in missing {
+        let group = if p.group.is_empty() { "core" } else { p.group };
+        table.push_str(&format!(
+            "{:<20} {:<26} {:<8} {}\n",
+            group,
+            p.resource,
+            p.verb,
+            if p.required { "yes" } else { "no" }
+        ));
+    }
+    table
+}
+
+/// Verify the operator's RBAC rules in `namespace` before reconciling
+///
+/// Returns [`Error::ConfigError`] listing every missing required permission.
+/// If the API server reports the rule list as incomplete (e.g. a webhook
+/// authorizer is in use), missing permissions are only logged.
+pub async fn check_permissions(client: &Client, namespace: &str) -> Result<()> {
+    let api: Api<SelfSubjectRulesReview> = Api::all(client.clone());
+    let review = SelfSubjectRulesReview {
+        spec: SelfSubjectRulesReviewSpec {
+            namespace: Some(namespace.to_string()),
+        },
+        ..Default::default()
+    };
+    let review = api
+        .create(&PostParams::default(), &review)
+        .await
+        .map_err(Error::KubeError)?;
+    let Some(status) = review.status else {
+        warn!("SelfSubjectRulesReview returned no status; skipping permission check");
+        return Ok(());
+    };
+
+    let missing = missing_permissions(&status.resource_rules, OPERATOR_PERMISSIONS);
+    if missing.is_empty() {
+        info!("Operator has all required permissions in {}", namespace);
+        return Ok(());
+    }
+
+    let table = permission_table(&missing);
+    let required: Vec<String> = missing
+        .iter()
+        .filter(|p| p.required)
+        .map(|p| format!("{} {}", p.verb, p.resource))
+        .collect();
+    if required.is_empty() || status.incomplete {
+        warn!(
+            "Operator is missing permissions in {} (rule list incomplete: {}):\n{}",
+            namespace, status.incomplete, table
+        );
+        return Ok(());
+    }
+
+    error!(
+        "Operator is missing required permissions in {}:\n{}",
+        namespace, table
+    );
+    Err(Error::ConfigError(format!(
+        "Missing required RBAC permissions: {}",
+        required.join(", ")
+    )))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rule(groups: &[&str], resources: &[&str], verbs: &[&str]) -> ResourceRule {
+        let strings = |v: &[&str]| Some(v.iter().map(|s| s.to_string()).collect());
+        ResourceRule {
+            api_groups: strings(groups),
+            resources: strings(resources),
+            verbs: verbs.iter().map(|s| s.to_string()).collect(),
+            resource_names: None,
+        }
+    }
+
+    #[test]
+    fn test_rule_allows_wildcards_and_names() {
+        let patch_deployments = required("apps", "deployments", "patch");
+        assert!(rule_allows(
+            &rule(&["apps"], &["deployments"], &["get", "patch"]),
+            &patch_deployments
+        ));
+        assert!(rule_allows(
+            &rule(&["*"], &["*"], &["*"]),
+            &patch_deployments
+        ));
+        assert!(!rule_allows(
+            &rule(&["apps"], &["deployments"], &["get"]),
+            &patch_deployments
+        ));
+        assert!(!rule_allows(
+            &rule(&[""], &["deployments"], &["patch"]),
+            &patch_deployments
+        ));
+
+        let mut named = rule(&["apps"], &["deployments"], &["patch"]);
+        named.resource_names = Some(vec!["horizon".to_string()]);
+        assert!(!rule_allows(&named, &patch_deployments));
+    }
+
+    #[test]
+    fn test_missing_permissions() {
+        let rules = vec![rule(&["apps"], &["deployments"], &["*"])];
+        let wanted = [
+            required("apps", "deployments", "patch"),
+            required("", "services", "patch"),
+            optional("batch", "jobs", "create"),
+        ];
+        let missing = missing_permissions(&rules, &wanted);
+        assert_eq!(missing, vec![wanted[1], wanted[2]]);
+
+        let table = permission_table(&missing);
+        assert!(table.contains("core"));
+        assert_eq!(table.lines().count(), 3);
+        assert!(
+            missing_permissions(&[rule(&["*"], &["*"], &["*"])], OPERATOR_PERMISSIONS).is_empty()
+        );
+    }
+}
@@ -0,0 +1,342 @@
+//! Pre-upgrade precheck hook
+//!
+//! When a spec change would restart the node's pods (a new image or a changed
+//! pod template), the operator first runs `spec.precheckHook` as a one-shot Job
+//! and waits for it. A failing check either holds the rollout
+//! (`failurePolicy: Abort`) or is reported as a Warning event
+//! (`failurePolicy: Warn`). The outcome is recorded in
+//! `status.lastPrecheckResult` so the check runs once per spec generation.
+
+use std::time::Duration;
+
+use futures::TryStreamExt;
+use k8s_openapi::api::apps::v1::{Deployment, StatefulSet};
+use k8s_openapi::api::batch::v1::Job;
+use k8s_openapi::api::core::v1::{Pod, PodTemplateSpec};
+use kube::{
+    api::{Api, ListParams, Patch, PatchParams},
+    client::Client,
+    runtime::watcher,
+    ResourceExt,
+};
+use serde_json::json;
+use tracing::{info, warn};
+
+use crate::crd::{FailurePolicy, NodeType, PrecheckHookConfig, PrecheckResult, StellarNode};
+use crate::error::{Error, Result};
+
+use super::migration::{job_state, JobState};
+use super::resources;
+
+/// Outcome of the precheck step
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum PrecheckOutcome {
+    /// No hook configured, the change does not restart pods, or the check
+    /// for this generation was already handled
+    NotRequired,
+    /// The hook passed for the current generation
+    Passed,
+    /// The hook failed; the rollout must be held
+    Aborted(PrecheckResult),
+    /// The hook failed but the policy allows the rollout to continue
+    Warned(PrecheckResult),
+    /// An earlier failed check for this generation still holds the rollout
+    Held,
+}
+
+/// Whether moving from `current` to `desired` restarts the pods
+///
+/// Only fields the operator sets and the API server does not default are
+/// compared: container images and pod template annotations. Annotations set
+/// by kubectl (e.g. `kubectl rollout restart`) are not owned by the operator
+/// and are ignored.
+pub fn restart_required(current: &PodTemplateSpec, desired: &PodTemplateSpec) -> bool {
+    let images = |template: &PodTemplateSpec| -> Vec<Option<String>> {
+        template
+            .spec
+            .as_ref()
+            .map(|s| s.containers.iter().map(|c| c.image.clone()).collect())
+            .unwrap_or_default()
+    };
+    let annotations = |template: &PodTemplateSpec| {
+        template
+            .metadata
+            .as_ref()
+            .and_then(|m| m.annotations.clone())
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|(key, _)| !key.starts_with("kubectl.kubernetes.io/"))
+            .collect::<Vec<_>>()
+    };
+
+    images(current) != images(desired) || annotations(current) != annotations(desired)
+}
+
+/// Pod template of the running workload, if it exists
+async fn current_pod_template(
+    client: &Client,
+    node: &StellarNode,
+) -> Result<Option<PodTemplateSpec>> {
+    let namespace = node.namespace().unwrap_or_else(|| "default".to_string());
+    let name = node.name_any();
+    let template = match node.spec.node_type {
+        NodeType::Validator => {
+            let api: Api<StatefulSet> = Api::namespaced(client.clone(), &namespace);
+            api.get_opt(&name)
+                .await?
+                .and_then(|s| s.spec)
+                .map(|s| s.template)
+        }
+        NodeType::Horizon | NodeType::SorobanRpc => {
+            let api: Api<Deployment> = Api::namespaced(client.clone(), &namespace);
+            api.get_opt(&name)
+                .await?
+                .and_then(|d| d.spec)
+                .map(|s| s.template)
+        }
+    };
+    Ok(template)
+}
+
+/// Run the precheck hook if the pending change would restart the pods
+///
+/// The outcome for a generation is kept in status, so a failed check is not
+/// re-run until the spec changes again.
+pub async fn reconcile_precheck(
+    client: &Client,
+    node: &StellarNode,
+    enable_mtls: bool,
+) -> Result<PrecheckOutcome> {
+    let Some(hook) = node.spec.precheck_hook.as_ref() else {
+        return Ok(PrecheckOutcome::NotRequired);
+    };
+    let generation = node.metadata.generation.unwrap_or(0);
+
+    if let Some(last) = node
+        .status
+        .as_ref()
+        .and_then(|s| s.last_precheck_result.as_ref())
+        .filter(|r| r.generation == generation)
+    {
+        let held = !last.passed && hook.failure_policy == FailurePolicy::Abort;
+        return Ok(if held {
+            PrecheckOutcome::Held
+        } else {
+            PrecheckOutcome::NotRequired
+        });
+    }
+
+    let Some(current) = current_pod_template(client, node).await? else {
+        return Ok(PrecheckOutcome::NotRequired);
+    };
+    if !restart_required(
+        &current,
+        &resources::desired_pod_template(node, enable_mtls),
+    ) {
+        return Ok(PrecheckOutcome::NotRequired);
+    }
+
+    let result = run_precheck_job(client, node, hook, generation).await?;
+    update_precheck_status(client, node, &result).await?;
+    Ok(outcome(hook, result))
+}
+
+fn outcome(hook: &PrecheckHookConfig, result: PrecheckResult) -> PrecheckOutcome {
+    match (result.passed, hook.failure_policy) {
+        (true, _) => PrecheckOutcome::Passed,
+        (false, FailurePolicy::Abort) => PrecheckOutcome::Aborted(result),
+        (false, FailurePolicy::Warn) => PrecheckOutcome::Warned(result),
+    }
+}
+
+/// Create the hook Job, wait for it to finish and clean it up
+async fn run_precheck_job(
+    client: &Client,
+    node: &StellarNode,
+    hook: &PrecheckHookConfig,
+    generation: i64,
+) -> Result<PrecheckResult> {
+    let namespace = node.namespace().unwrap_or_else(|| "default".to_string());
+    let api: Api<Job> = Api::namespaced(client.clone(), &namespace);
+    let job_name = resources::precheck_job_name(node, generation);
+
+    resources::ensure_precheck_job(client, node, hook, &job_name).await?;
+    info!(
+        "Waiting up to {}s for precheck Job {}/{}",
+        hook.timeout_seconds, namespace, job_name
+    );
+
+    let wait = async {
+        let mut stream = Box::pin(watcher::watch_object(api, &job_name));
+        while let Some(job) = stream
+            .try_next()
+            .await
+            .map_err(|e| Error::ConfigError(format!("Failed to watch Job {job_name}: {e}")))?
+        {
+            if let Some(state) = job.as_ref().map(job_state) {
+                if state != JobState::Running {
+                    return Ok(state);
+                }
+            }
+        }
+        Ok::<_, Error>(JobState::Running)
+    };
+    let state = match tokio::time::timeout(
+        Duration::from_secs(u64::from(hook.timeout_seconds)),
+        wait,
+    )
+    .await
+    {
+        Ok(state) => state?,
+        Err(_) => JobState::Running,
+    };
+
+    let exit_code = precheck_exit_code(client, node, &job_name).await;
+    resources::delete_precheck_job(client, node, &job_name).await?;
+
+    let message = match state {
+        JobState::Succeeded => format!("Precheck Job {job_name} succeeded"),
+        JobState::Failed => match exit_code {
+            Some(code) => format!("Precheck Job {job_name} failed with exit code {code}"),
+            None => format!("Precheck Job {job_name} failed"),
+        },
+        JobState::Running => format!(
+            "Precheck Job {job_name} did not finish within {}s",
+            hook.timeout_seconds
+        ),
+    };
+    Ok(PrecheckResult {
+        generation,
+        exit_code,
+        passed: state == JobState::Succeeded,
+        message: Some(message),
+    })
+}
+
+/// Exit code of the hook container, read from the Job's pod
+async fn precheck_exit_code(client: &Client, node: &StellarNode, job_name: &str) -> Option<i32> {
+    let namespace = node.namespace().unwrap_or_else(|| "default".to_string());
+    let pods: Api<Pod> = Api::namespaced(client.clone(), &namespace);
+    let lp = ListParams::default().labels(&format!("job-name={job_name}"));
+
+    match pods.list(&lp).await {
+        Ok(list) => list.items.iter().find_map(pod_exit_code),
+        Err(e) => {
+            warn!("Failed to list pods for Job {}: {}", job_name, e);
+            None
+        }
+    }
+}
+
+/// Exit code of a pod's terminated `precheck` container
+pub fn pod_exit_code(pod: &Pod) -> Option<i32> {
+    pod.status
+        .as_ref()?
+        .container_statuses
+        .as_ref()?
+        .iter()
+        .find(|c| c.name == "precheck")?
+        .state
+        .as_ref()?
+        .terminated
+        .as_ref()
+        .map(|t| t.exit_code)
+}
+
+/// Record `status.lastPrecheckResult`
+async fn update_precheck_status(
+    client: &Client,
+    node: &StellarNode,
+    result: &PrecheckResult,
+) -> Result<()> {
+    let namespace = node.namespace().unwrap_or_else(|| "default".to_string());
+    let api: Api<StellarNode> = Api::namespaced(client.clone(), &namespace);
+    let patch = json!({ "status": { "lastPrecheckResult": result } });
+
+    api.patch_status(
+        &node.name_any(),
+        &PatchParams::apply("stellar-operator"),
+        &Patch::Merge(&patch),
+    )
+    .await
+    .map_err(Error::KubeError)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use k8s_openapi::api::core::v1::{
+        Container, ContainerState, ContainerStateTerminated, ContainerStatus, PodSpec, PodStatus,
+    };
+    use k8s_openapi::apimachinery::pkg::apis::meta::v1::ObjectMeta;
+    use std::collections::BTreeMap;
+
+    fn template(image: &str, annotations: &[(&str, &str)]) -> PodTemplateSpec {
+        PodTemplateSpec {
+            metadata: Some(ObjectMeta {
+                annotations: (!annotations.is_empty()).then(|| {
+                    annotations
+                        .iter()
+                        .map(|(k, v)| (k.to_string(), v.to_string()))
+                        .collect::<BTreeMap<_, _>>()
+                }),
+                ..Default::default()
+            }),
+            spec: Some(PodSpec {
+                containers: vec![Container {
+                    name: "stellar-node".to_string(),
+                    image: Some(image.to_string()),
+                    ..Default::default()
+                }],
+                ..Default::default()
+            }),
+        }
+    }
+
+    #[test]
+    fn test_restart_required_on_image_or_annotation_change() {
+        let current = template("stellar/stellar-core:21", &[]);
+        assert!(!restart_required(&current, &current.clone()));
+        assert!(restart_required(
+            &current,
+            &template("stellar/stellar-core:22", &[])
+        ));
+        assert!(restart_required(
+            &current,
+            &template(
+                "stellar/stellar-core:21",
+                &[("stellar.org/standby", "true")]
+            )
+        ));
+
+        let restarted = template(
+            "stellar/stellar-core:21",
+            &[("kubectl.kubernetes.io/restartedAt", "2024-01-01T00:00:00Z")],
+        );
+        assert!(!restart_required(&restarted, &current));
+    }
+
+    #[test]
+    fn test_pod_exit_code() {
+        let pod = Pod {
+            status: Some(PodStatus {
+                container_statuses: Some(vec![ContainerStatus {
+                    name: "precheck".to_string(),
+                    state: Some(ContainerState {
+                        terminated: Some(ContainerStateTerminated {
+                            exit_code: 3,
+                            ..Default::default()
+                        }),
+                        ..Default::default()
+                    }),
+                    ..Default::default()
+                }]),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        assert_eq!(pod_exit_code(&pod), Some(3));
+        assert_eq!(pod_exit_code(&Pod::default()), None);
+    }
+}
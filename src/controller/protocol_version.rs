@@ -0,0 +1,182 @@
+//! Network protocol version enforcement for validators
+//!
+//! A validator whose image does not support the network's current protocol
+//! version falls out of consensus and can be ejected from quorum sets. With
+//! `spec.validatorConfig.minimumProtocolVersion` set, the operator reads the
+//! network's `current_protocol_version` from Horizon and, once it is higher than
+//! the configured version, scales the validator to 0 and sets the
+//! `ProtocolVersionOutdated` condition until the spec is updated.
+
+use std::time::Duration;
+
+use kube::client::Client;
+use serde::Deserialize;
+use serde_json::json;
+use tracing::debug;
+
+use crate::crd::{NodeType, StellarNode};
+use crate::error::Result;
+
+use super::conditions;
+
+/// Timeout for the Horizon root request
+const PROTOCOL_VERSION_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Subset of the Horizon root resource
+#[derive(Debug, Deserialize)]
+struct HorizonRoot {
+    current_protocol_version: u32,
+}
+
+/// Configured minimum protocol version, if enforcement is enabled
+///
+/// This is the network protocol the node's image was deployed for; the network
+/// moving past it means the image has to be updated.
+pub fn minimum_protocol_version(node: &StellarNode) -> Option<u32> {
+    if node.spec.node_type != NodeType::Validator {
+        return None;
+    }
+    node.spec
+        .validator_config
+        .as_ref()
+        .and_then(|c| c.minimum_protocol_version)
+}
+
+/// Whether the network has moved past the node's minimum protocol version
+///
+/// Uses the protocol version last recorded in `status.networkProtocolVersion`,
+/// so it can be evaluated while building the workload.
+pub fn protocol_version_outdated(node: &StellarNode) -> bool {
+    let current = node
+        .status
+        .as_ref()
+        .and_then(|s| s.network_protocol_version);
+    match (minimum_protocol_version(node), current) {
+        (Some(minimum), Some(current)) => current > minimum,
+        _ => false,
+    }
+}
+
+/// Horizon URL the network protocol version is read from
+fn protocol_version_url(node: &StellarNode) -> Option<String> {
+    node.spec
+        .validator_config
+        .as_ref()
+        .and_then(|c| c.protocol_version_url.clone())
+        .or_else(|| node.spec.network.public_horizon_url().map(str::to_string))
+}
+
+/// Fetch the network's current protocol version
+///
+/// Returns `Ok(None)` when enforcement is not enabled for the node.
+pub async fn fetch_network_protocol_version(
+    http_client: &reqwest::Client,
+    node: &StellarNode,
+) -> Result<Option<u32>> {
+    if minimum_protocol_version(node).is_none() {
+        return Ok(None);
+    }
+    let Some(url) = protocol_version_url(node) else {
+        return Ok(None);
+    };
+
+    let root: HorizonRoot = http_client
+        .get(&url)
+        .timeout(PROTOCOL_VERSION_TIMEOUT)
+        .send()
+        .await?
+        .error_for_status()?
+        .json()
+        .await?;
+
+    debug!(
+        "Network protocol version from {}: {}",
+        url, root.current_protocol_version
+    );
+    Ok(Some(root.current_protocol_version))
+}
+
+/// Record `status.networkProtocolVersion` and the `ProtocolVersionOutdated` condition
+///
+/// Returns whether the node just became outdated according to the live status.
+pub async fn update_protocol_version_status(
+    client: &Client,
+    node: &StellarNode,
+    network_version: u32,
+) -> Result<bool> {
+    let minimum = minimum_protocol_version(node).unwrap_or_default();
+    let outdated = network_version > minimum;
+
+    let status = json!({ "networkProtocolVersion": network_version });
+    conditions::update_conditions(client, node, status, |node_conditions| {
+        let was_outdated = conditions::is_condition_true(
+            node_conditions,
+            conditions::CONDITION_TYPE_PROTOCOL_VERSION_OUTDATED,
+        );
+        if outdated {
+            conditions::set_condition(
+                node_conditions,
+                conditions::CONDITION_TYPE_PROTOCOL_VERSION_OUTDATED,
+                conditions::CONDITION_STATUS_TRUE,
+                "NetworkUpgraded",
+                &format!(
+                    "Network is on protocol {network_version}, past the node's minimumProtocolVersion {minimum}; scaled to 0 until the image and minimumProtocolVersion are updated"
+                ),
+            );
+        } else {
+            conditions::set_condition(
+                node_conditions,
+                conditions::CONDITION_TYPE_PROTOCOL_VERSION_OUTDATED,
+                conditions::CONDITION_STATUS_FALSE,
+                "ProtocolSupported",
+                &format!(
+                    "Network is on protocol {network_version}; the node's minimumProtocolVersion is {minimum}"
+                ),
+            );
+        }
+        outdated && !was_outdated
+    })
+    .await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::crd::{StellarNodeSpec, StellarNodeStatus};
+
+    fn validator(minimum: Option<u32>, network_version: Option<u32>) -> StellarNode {
+        let spec: StellarNodeSpec = serde_json::from_value(json!({
+            "nodeType": "Validator",
+            "network": "Testnet",
+            "version": "v21.0.0",
+            "validatorConfig": {
+                "seedSecretRef": "validator-seed",
+                "minimumProtocolVersion": minimum,
+            },
+        }))
+        .unwrap();
+        let mut node = StellarNode::new("validator", spec);
+        node.status = Some(StellarNodeStatus {
+            network_protocol_version: network_version,
+            ..Default::default()
+        });
+        node
+    }
+
+    #[test]
+    fn test_protocol_version_outdated() {
+        assert!(!protocol_version_outdated(&validator(Some(21), Some(21))));
+        assert!(protocol_version_outdated(&validator(Some(21), Some(22))));
+        assert!(!protocol_version_outdated(&validator(None, Some(22))));
+        assert!(!protocol_version_outdated(&validator(Some(21), None)));
+    }
+
+    #[test]
+    fn test_protocol_version_url_defaults_to_public_horizon() {
+        let node = validator(Some(21), None);
+        assert_eq!(
+            protocol_version_url(&node).as_deref(),
+            Some("https://horizon-testnet.stellar.org")
+        );
+    }
+}
@@ -0,0 +1,384 @@
+//! Online PersistentVolumeClaim expansion
+//!
+//! When `spec.storage.size` grows, the operator patches the PVC's storage
+//! request and tracks the expansion through the `PVCResizePending` and
+//! `PVCResizeComplete` conditions. Some CSI drivers only finish the file system
+//! resize after the pod restarts; the PVC then reports
+//! `FileSystemResizePending` until that happens. The reconciler polls until the
+//! condition clears, or until `storage.resizeGracePeriodSeconds` expires.
+
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use k8s_openapi::api::core::v1::PersistentVolumeClaim;
+use kube::{
+    api::{Api, Patch, PatchParams},
+    client::Client,
+    ResourceExt,
+};
+use serde_json::json;
+use tracing::{info, warn};
+
+use crate::crd::StellarNode;
+use crate::error::{Error, Result};
+
+use super::conditions;
+use super::resources;
+
+/// How often a pending resize is re-checked
+pub const RESIZE_POLL_INTERVAL: Duration = Duration::from_secs(10);
+
+/// Reason recorded on `PVCResizePending` once the grace period has expired
+const REASON_TIMED_OUT: &str = "ResizeTimedOut";
+
+/// Result of one resize reconciliation pass
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum PvcResizeOutcome {
+    /// No resize requested or in flight
+    Idle,
+    /// The PVC storage request was just increased
+    Started { from: String, to: String },
+    /// The expansion is still in progress
+    Pending,
+    /// The expansion finished
+    Completed,
+    /// The grace period expired before the expansion finished
+    TimedOut,
+}
+
+impl PvcResizeOutcome {
+    /// Requeue interval needed to keep polling, if any
+    pub fn poll_interval(&self) -> Option<Duration> {
+        match self {
+            PvcResizeOutcome::Started { .. } | PvcResizeOutcome::Pending => {
+                Some(RESIZE_POLL_INTERVAL)
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Convert a Kubernetes storage quantity (e.g. "100Gi", "1.5T") to bytes
+pub fn quantity_bytes(quantity: &str) -> Option<f64> {
+    const SUFFIXES: [(&str, f64); 12] = [
+        ("Ki", 1024.0),
+        ("Mi", 1024.0 * 1024.0),
+        ("Gi", 1024.0 * 1024.0 * 1024.0),
+        ("Ti", 1024.0 * 1024.0 * 1024.0 * 1024.0),
+        ("Pi", 1024.0 * 1024.0 * 1024.0 * 1024.0 * 1024.0),
+        ("Ei", 1024.0 * 1024.0 * 1024.0 * 1024.0 * 1024.0 * 1024.0),
+        ("k", 1e3),
+        ("M", 1e6),
+        ("G", 1e9),
+        ("T", 1e12),
+        ("P", 1e15),
+        ("E", 1e18),
+    ];
+
+    let quantity = quantity.trim();
+    let (number, multiplier) = SUFFIXES
+        .iter()
+        .find_map(|(suffix, mult)| quantity.strip_suffix(suffix).map(|n| (n, *mult)))
+        .unwrap_or((quantity, 1.0));
+    number.parse::<f64>().ok().map(|n| n * multiplier)
+}
+
+/// Whether the PVC still reports an unfinished expansion
+pub fn filesystem_resize_pending(pvc: &PersistentVolumeClaim) -> bool {
+    pvc.status
+        .as_ref()
+        .and_then(|s| s.conditions.as_ref())
+        .is_some_and(|conds| {
+            conds.iter().any(|c| {
+                (c.type_ == "FileSystemResizePending" || c.type_ == "Resizing")
+                    && c.status == "True"
+            })
+        })
+}
+
+/// Whether the PVC's reported capacity has reached its request
+fn capacity_reached(pvc: &PersistentVolumeClaim, requested: &str) -> bool {
+    let capacity = pvc
+        .status
+        .as_ref()
+        .and_then(|s| s.capacity.as_ref())
+        .and_then(|c| c.get("storage"))
+        .and_then(|q| quantity_bytes(&q.0));
+    match (capacity, quantity_bytes(requested)) {
+        (Some(capacity), Some(requested)) => capacity >= requested,
+        _ => false,
+    }
+}
+
+/// Whether a pending resize started at `started` has outlived the grace period
+///
+/// A grace period of 0 (or unset) never times out.
+pub fn grace_period_expired(started: DateTime<Utc>, grace_secs: u32, now: DateTime<Utc>) -> bool {
+    grace_secs > 0 && now.signed_duration_since(started).num_seconds() >= i64::from(grace_secs)
+}
+
+/// Expand the data PVC if the spec asks for more storage and track progress
+pub async fn reconcile_pvc_resize(client: &Client, node: &StellarNode) -> Result<PvcResizeOutcome> {
+    let namespace = node.namespace().unwrap_or_else(|| "default".to_string());
+    let api: Api<PersistentVolumeClaim> = Api::namespaced(client.clone(), &namespace);
+    let name = resources::pvc_name(node);
+
+    let Some(pvc) = api.get_opt(&name).await.map_err(Error::KubeError)? else {
+        return Ok(PvcResizeOutcome::Idle);
+    };
+
+    let desired = resources::effective_storage_size(node);
+    let current = pvc
+        .spec
+        .as_ref()
+        .and_then(|s| s.resources.as_ref())
+        .and_then(|r| r.requests.as_ref())
+        .and_then(|r| r.get("storage"))
+        .map(|q| q.0.clone())
+        .unwrap_or_default();
+
+    let grows = match (quantity_bytes(&current), quantity_bytes(&desired)) {
+        (Some(current), Some(desired)) => desired > current,
+        _ => false,
+    };
+
+    if grows {
+        info!(
+            "Expanding PVC {}/{} from {} to {}",
+            namespace, name, current, desired
+        );
+        let patch = json!({ "spec": { "resources": { "requests": { "storage": desired } } } });
+        api.patch(
+            &name,
+            &PatchParams::apply("stellar-operator"),
+            &Patch::Merge(&patch),
+        )
+        .await
+        .map_err(Error::KubeError)?;
+
+        conditions::update_conditions(client, node, json!({}), |node_conditions| {
+            conditions::set_condition(
+                node_conditions,
+                conditions::CONDITION_TYPE_PVC_RESIZE_PENDING,
+                conditions::CONDITION_STATUS_TRUE,
+                "ResizeRequested",
+                &format!("Expanding PVC {name} from {current} to {desired}"),
+            );
+            conditions::remove_condition(
+                node_conditions,
+                conditions::CONDITION_TYPE_PVC_RESIZE_COMPLETE,
+            );
+        })
+        .await?;
+        return Ok(PvcResizeOutcome::Started {
+            from: current,
+            to: desired,
+        });
+    }
+
+    // Read back from the API server: the snapshot may predate the write that
+    // started the resize
+    let node_conditions = conditions::live_conditions(client, node).await?;
+    let Some(pending) = conditions::find_condition(
+        &node_conditions,
+        conditions::CONDITION_TYPE_PVC_RESIZE_PENDING,
+    )
+    .filter(|c| c.status == conditions::CONDITION_STATUS_TRUE)
+    .cloned() else {
+        return Ok(PvcResizeOutcome::Idle);
+    };
+
+    if !filesystem_resize_pending(&pvc) && capacity_reached(&pvc, &current) {
+        info!(
+            "PVC {}/{} expansion to {} complete",
+            namespace, name, current
+        );
+        conditions::update_conditions(client, node, json!({}), |node_conditions| {
+            conditions::set_condition(
+                node_conditions,
+                conditions::CONDITION_TYPE_PVC_RESIZE_PENDING,
+                conditions::CONDITION_STATUS_FALSE,
+                "ResizeComplete",
+                &format!("PVC {name} expanded to {current}"),
+            );
+            conditions::set_condition(
+                node_conditions,
+                conditions::CONDITION_TYPE_PVC_RESIZE_COMPLETE,
+                conditions::CONDITION_STATUS_TRUE,
+                "ResizeComplete",
+                &format!("PVC {name} expanded to {current}"),
+            );
+        })
+        .await?;
+        return Ok(PvcResizeOutcome::Completed);
+    }
+
+    if pending.reason == REASON_TIMED_OUT {
+        // Already reported; keep the condition pending without further polling
+        return Ok(PvcResizeOutcome::Idle);
+    }
+
+    let grace = node.spec.storage.resize_grace_period_seconds.unwrap_or(0);
+    let started = DateTime::parse_from_rfc3339(&pending.last_transition_time)
+        .map(|t| t.with_timezone(&Utc))
+        .unwrap_or_else(|_| Utc::now());
+    if grace_period_expired(started, grace, Utc::now()) {
+        warn!(
+            "PVC {}/{} expansion did not finish within {}s",
+            namespace, name, grace
+        );
+        conditions::update_conditions(client, node, json!({}), |node_conditions| {
+            conditions::set_condition(
+                node_conditions,
+                conditions::CONDITION_TYPE_PVC_RESIZE_PENDING,
+                conditions::CONDITION_STATUS_TRUE,
+                REASON_TIMED_OUT,
+                &format!(
+                    "PVC {name} expansion to {current} did not finish within {grace}s; the pod may need a restart"
+                ),
+            );
+        })
+        .await?;
+        return Ok(PvcResizeOutcome::TimedOut);
+    }
+
+    Ok(PvcResizeOutcome::Pending)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::controller::conditions::DEFAULT_MAX_CONDITIONS;
+    use crate::controller::fake_api::FakeApiServer;
+    use crate::controller::reconciler::update_status;
+    use k8s_openapi::api::core::v1::{PersistentVolumeClaimCondition, PersistentVolumeClaimStatus};
+
+    #[test]
+    fn test_quantity_bytes() {
+        assert_eq!(
+            quantity_bytes("100Gi"),
+            Some(100.0 * 1024.0 * 1024.0 * 1024.0)
+        );
+        assert_eq!(quantity_bytes("1.5T"), Some(1.5e12));
+        assert_eq!(quantity_bytes("2048"), Some(2048.0));
+        assert_eq!(quantity_bytes("abc"), None);
+        assert!(quantity_bytes("200Gi") > quantity_bytes("100Gi"));
+    }
+
+    #[test]
+    fn test_filesystem_resize_pending() {
+        let pvc = |type_: &str, status: &str| PersistentVolumeClaim {
+            status: Some(PersistentVolumeClaimStatus {
+                conditions: Some(vec![PersistentVolumeClaimCondition {
+                    type_: type_.to_string(),
+                    status: status.to_string(),
+                    ..Default::default()
+                }]),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+
+        assert!(filesystem_resize_pending(&pvc(
+            "FileSystemResizePending",
+            "True"
+        )));
+        assert!(!filesystem_resize_pending(&pvc(
+            "FileSystemResizePending",
+            "False"
+        )));
+        assert!(!filesystem_resize_pending(&PersistentVolumeClaim::default()));
+    }
+
+    #[test]
+    fn test_grace_period_expired() {
+        let started = Utc::now() - chrono::Duration::seconds(120);
+        assert!(grace_period_expired(started, 60, Utc::now()));
+        assert!(!grace_period_expired(started, 600, Utc::now()));
+        // Zero never times out
+        assert!(!grace_period_expired(started, 0, Utc::now()));
+    }
+
+    #[test]
+    fn test_only_in_flight_outcomes_poll() {
+        assert!(PvcResizeOutcome::Pending.poll_interval().is_some());
+        assert!(PvcResizeOutcome::Completed.poll_interval().is_none());
+        assert!(PvcResizeOutcome::TimedOut.poll_interval().is_none());
+    }
+
+    fn data_pvc(name: &str, requested: &str, capacity: &str) -> serde_json::Value {
+        json!({
+            "apiVersion": "v1",
+            "kind": "PersistentVolumeClaim",
+            "metadata": { "name": name, "namespace": "default" },
+            "spec": { "resources": { "requests": { "storage": requested } } },
+            "status": { "capacity": { "storage": capacity } },
+        })
+    }
+
+    #[tokio::test]
+    async fn test_pending_resize_survives_final_status_write() {
+        let server = FakeApiServer::start().await;
+        let client = server.client();
+        let spec = serde_json::from_value(json!({
+            "nodeType": "Validator",
+            "network": "Testnet",
+            "version": "v21.0.0",
+            "validatorConfig": { "seedSecretRef": "validator-seed" },
+            "storage": { "storageClass": "standard", "size": "200Gi" },
+        }))
+        .unwrap();
+        let mut node = StellarNode::new("validator", spec);
+        node.metadata.namespace = Some("default".to_string());
+        node.metadata.generation = Some(1);
+        let node_path = "/apis/stellar.org/v1alpha1/namespaces/default/stellarnodes/validator";
+        let pvc = resources::pvc_name(&node);
+        let pvc_path = format!("/api/v1/namespaces/default/persistentvolumeclaims/{pvc}");
+        server.insert(node_path, &node);
+        server.insert(&pvc_path, data_pvc(&pvc, "100Gi", "100Gi"));
+
+        // First pass: the resize starts, and the pass ends with the phase
+        // status write built from the same snapshot
+        assert!(matches!(
+            reconcile_pvc_resize(&client, &node).await.unwrap(),
+            PvcResizeOutcome::Started { .. }
+        ));
+        update_status(
+            &client,
+            &node,
+            "Ready",
+            None,
+            1,
+            true,
+            DEFAULT_MAX_CONDITIONS,
+        )
+        .await
+        .unwrap();
+        let node: StellarNode = server.get(node_path);
+        let status = node.status.clone().unwrap();
+        #[allow(deprecated)]
+        let phase = status.phase.clone();
+        assert_eq!(phase, "Ready");
+        assert!(conditions::is_condition_true(
+            &status.conditions,
+            conditions::CONDITION_TYPE_PVC_RESIZE_PENDING
+        ));
+
+        // Second pass, after the volume finished expanding
+        server.insert(&pvc_path, data_pvc(&pvc, "200Gi", "200Gi"));
+        assert_eq!(
+            reconcile_pvc_resize(&client, &node).await.unwrap(),
+            PvcResizeOutcome::Completed
+        );
+        let node: StellarNode = server.get(node_path);
+        let status = node.status.unwrap();
+        assert!(conditions::is_condition_true(
+            &status.conditions,
+            conditions::CONDITION_TYPE_PVC_RESIZE_COMPLETE
+        ));
+        assert!(conditions::is_condition_true(
+            &status.conditions,
+            conditions::CONDITION_TYPE_READY
+        ));
+    }
+}
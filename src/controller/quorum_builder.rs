@@ -0,0 +1,103 @@
+//! Quorum set generation for validators
+//!
+//! Builds the stellar-core `[QUORUM_SET]` TOML section from
+//! `spec.validatorConfig.quorumValidators`, so users do not have to hand-write
+//! (or copy-paste) quorum set TOML.
+
+use crate::crd::QuorumValidatorSpec;
+
+/// Default `THRESHOLD_PERCENT` for generated quorum sets
+pub const DEFAULT_QUORUM_THRESHOLD_PERCENT: u32 = 67;
+
+/// Render the `[QUORUM_SET]` section for `validators`
+///
+/// Validators are listed heaviest first (ties keep their spec order); a weight
+/// of 0 leaves the validator out. `threshold` is clamped to 1..=100.
+pub fn generate_quorum_set_toml(validators: &[QuorumValidatorSpec], threshold: u32) -> String {
+    let mut trusted: Vec<&QuorumValidatorSpec> = validators
+        .iter()
+        .filter(|v| v.weight.unwrap_or(1) > 0)
+        .collect();
+    trusted.sort_by_key(|v| std::cmp::Reverse(v.weight.unwrap_or(1)));
+
+    let mut toml = String::from("[QUORUM_SET]\n");
+    toml.push_str(&format!("THRESHOLD_PERCENT={}\n", threshold.clamp(1, 100)));
+    toml.push_str("VALIDATORS=[\n");
+    for validator in trusted {
+        toml.push_str(&format!(
+            "  \"{} {}\", # {}\n",
+            validator.public_key.trim(),
+            escape(validator.name.trim()),
+            escape(validator.home_domain.trim())
+        ));
+    }
+    toml.push_str("]\n");
+    toml
+}
+
+/// Keep user-provided aliases from breaking out of the TOML string or comment
+fn escape(value: &str) -> String {
+    value
+        .chars()
+        .filter(|c| !c.is_control())
+        .map(|c| if c == '"' || c == '\\' { '_' } else { c })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SDF_1: &str = "GCGB2S2KGYARPVIA37HYZXVRM2YZUEXA6S33ZU5BUDC6THSB62LZSTYH";
+    const SDF_2: &str = "GCM6QMP3DLRPTAZW2UZPCPX2LF3SXWXKPMP3GKFZBDSF3QZGRM3ERXW6";
+    const LOBSTR: &str = "GD5QWEVV4GZZTQP46BRXV5CUMMMLP4JTGFD7FWYJJWRL54CELY6JGQ63";
+
+    fn validator(public_key: &str, name: &str, weight: Option<u32>) -> QuorumValidatorSpec {
+        QuorumValidatorSpec {
+            public_key: public_key.to_string(),
+            name: name.to_string(),
+            home_domain: "stellar.org".to_string(),
+            weight,
+        }
+    }
+
+    #[test]
+    fn test_generates_quorum_set_section() {
+        let toml = generate_quorum_set_toml(
+            &[
+                validator(SDF_1, "sdf_1", None),
+                validator(SDF_2, "sdf_2", None),
+            ],
+            DEFAULT_QUORUM_THRESHOLD_PERCENT,
+        );
+
+        assert_eq!(
+            toml,
+            format!(
+                "[QUORUM_SET]\nTHRESHOLD_PERCENT=67\nVALIDATORS=[\n  \"{SDF_1} sdf_1\", # stellar.org\n  \"{SDF_2} sdf_2\", # stellar.org\n]\n"
+            )
+        );
+    }
+
+    #[test]
+    fn test_weight_orders_and_excludes() {
+        let toml = generate_quorum_set_toml(
+            &[
+                validator(SDF_1, "sdf_1", Some(1)),
+                validator(SDF_2, "sdf_2", Some(0)),
+                validator(LOBSTR, "lobstr", Some(3)),
+            ],
+            50,
+        );
+
+        assert!(!toml.contains(SDF_2));
+        assert!(toml.find(LOBSTR).unwrap() < toml.find(SDF_1).unwrap());
+    }
+
+    #[test]
+    fn test_threshold_clamped_and_names_escaped() {
+        let toml = generate_quorum_set_toml(&[validator(SDF_1, "bad\"name", None)], 250);
+        assert!(toml.contains("THRESHOLD_PERCENT=100\n"));
+        assert!(toml.contains("bad_name"));
+    }
+}
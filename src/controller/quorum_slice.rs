@@ -0,0 +1,340 @@
+//! Quorum slice watcher for validators
+//!
+//! With `spec.validatorConfig.quorumSliceWatcher` enabled, each reconcile reads
+//! the validator's Stellar Core `/quorum` endpoint and checks the validators
+//! listed in `alertOnMissingValidators`. One that is missing or disagreeing
+//! (e.g. offline, or on a Stellar Core version that forked off) starts a grace
+//! period recorded in `status.quorumSliceDegradedSince`; once it lasts longer
+//! than `checkIntervalSeconds`, `QuorumSliceAlert=True` is set and a Warning
+//! event is emitted.
+
+use chrono::{DateTime, Utc};
+use kube::{client::Client, ResourceExt};
+use serde::Deserialize;
+use serde_json::json;
+use tracing::debug;
+
+use crate::crd::{NodeType, QuorumSliceWatcherConfig, StellarNode};
+use crate::error::Result;
+
+use super::conditions;
+
+/// Condition reason once a listed validator has been unhealthy past the interval
+pub const REASON_VALIDATORS_UNHEALTHY: &str = "QuorumValidatorsUnhealthy";
+
+/// Condition reason while a listed validator is unhealthy within the interval
+pub const REASON_GRACE_PERIOD: &str = "GracePeriod";
+
+/// Condition reason while all listed validators agree
+pub const REASON_QUORUM_SLICE_HEALTHY: &str = "QuorumSliceHealthy";
+
+/// Response of Stellar Core's `/quorum?fullkeys=true`
+#[derive(Debug, Default, Deserialize)]
+pub struct QuorumResponse {
+    #[serde(default)]
+    pub qset: QuorumSetInfo,
+}
+
+/// Compact summary of the local quorum set for the last externalized ledger
+///
+/// Stellar Core reports `agree` as a count and the other validators by public
+/// key; empty lists come back as `null`.
+#[derive(Debug, Default, Deserialize)]
+pub struct QuorumSetInfo {
+    #[serde(default)]
+    pub agree: u32,
+    #[serde(default)]
+    pub disagree: Option<Vec<String>>,
+    #[serde(default)]
+    pub missing: Option<Vec<String>>,
+}
+
+/// The node's watcher config, if it is an enabled validator watcher
+pub fn watcher(node: &StellarNode) -> Option<&QuorumSliceWatcherConfig> {
+    if node.spec.node_type != NodeType::Validator {
+        return None;
+    }
+    node.spec
+        .validator_config
+        .as_ref()?
+        .quorum_slice_watcher
+        .as_ref()
+        .filter(|w| w.enabled)
+}
+
+/// Listed validators that are missing or disagreeing, as `<key> (<state>)`
+pub fn unhealthy_validators(
+    watcher: &QuorumSliceWatcherConfig,
+    qset: &QuorumSetInfo,
+) -> Vec<String> {
+    let listed = |list: &Option<Vec<String>>, key: &str| {
+        list.as_ref().is_some_and(|l| l.iter().any(|k| k == key))
+    };
+    watcher
+        .alert_on_missing_validators
+        .iter()
+        .filter_map(|key| {
+            if listed(&qset.missing, key) {
+                Some(format!("{key} (missing)"))
+            } else if listed(&qset.disagree, key) {
+                Some(format!("{key} (disagree)"))
+            } else {
+                None
+            }
+        })
+        .collect()
+}
+
+/// Read the quorum summary from the validator's Stellar Core HTTP port
+pub async fn fetch_quorum(
+    http_client: &reqwest::Client,
+    node: &StellarNode,
+    use_tls: bool,
+) -> Result<QuorumSetInfo> {
+    let scheme = if use_tls { "https" } else { "http" };
+    let namespace = node.namespace().unwrap_or_else(|| "default".to_string());
+    let url = format!(
+        "{scheme}://{}.{namespace}.svc.cluster.local:11626/quorum?fullkeys=true",
+        node.name_any()
+    );
+
+    let response: QuorumResponse = http_client
+        .get(&url)
+        .send()
+        .await?
+        .error_for_status()?
+        .json()
+        .await?;
+    debug!(
+        "Quorum of {}/{}: agree={}, disagree={:?}, missing={:?}",
+        namespace,
+        node.name_any(),
+        response.qset.agree,
+        response.qset.disagree,
+        response.qset.missing
+    );
+    Ok(response.qset)
+}
+
+/// Start of the current degraded period, or `None` once all listed validators agree
+pub fn degraded_since(
+    unhealthy: &[String],
+    previous: Option<&str>,
+    now: DateTime<Utc>,
+) -> Option<String> {
+    if unhealthy.is_empty() {
+        return None;
+    }
+    Some(
+        previous
+            .filter(|since| DateTime::parse_from_rfc3339(since).is_ok())
+            .map(str::to_string)
+            .unwrap_or_else(|| now.to_rfc3339()),
+    )
+}
+
+/// Whether a degraded period that began at `since` has outlasted the interval
+pub fn alert_due(since: &str, check_interval_seconds: u32, now: DateTime<Utc>) -> bool {
+    DateTime::parse_from_rfc3339(since).is_ok_and(|since| {
+        (now - since.with_timezone(&Utc)).num_seconds() > i64::from(check_interval_seconds)
+    })
+}
+
+/// Record `status.quorumSliceDegradedSince` and the `QuorumSliceAlert` condition
+///
+/// Returns `true` when the alert fired with this call.
+pub async fn update_quorum_slice_status(
+    client: &Client,
+    node: &StellarNode,
+    watcher: &QuorumSliceWatcherConfig,
+    qset: &QuorumSetInfo,
+) -> Result<bool> {
+    let now = Utc::now();
+    let unhealthy = unhealthy_validators(watcher, qset);
+    let previous = node
+        .status
+        .as_ref()
+        .and_then(|s| s.quorum_slice_degraded_since.clone());
+    let since = degraded_since(&unhealthy, previous.as_deref(), now);
+    let alert = since
+        .as_deref()
+        .is_some_and(|since| alert_due(since, watcher.check_interval_seconds, now));
+
+    let node_conditions = conditions::live_conditions(client, node).await?;
+    let was_alerting = conditions::is_condition_true(
+        &node_conditions,
+        conditions::CONDITION_TYPE_QUORUM_SLICE_ALERT,
+    );
+    let (status, reason, message) = if unhealthy.is_empty() {
+        (
+            conditions::CONDITION_STATUS_FALSE,
+            REASON_QUORUM_SLICE_HEALTHY,
+            format!(
+                "All {} watched validators agree",
+                watcher.alert_on_missing_validators.len()
+            ),
+        )
+    } else if alert {
+        (
+            conditions::CONDITION_STATUS_TRUE,
+            REASON_VALIDATORS_UNHEALTHY,
+            format!(
+                "Unhealthy for more than {}s: {}",
+                watcher.check_interval_seconds,
+                unhealthy.join(", ")
+            ),
+        )
+    } else {
+        (
+            conditions::CONDITION_STATUS_FALSE,
+            REASON_GRACE_PERIOD,
+            format!(
+                "Unhealthy, alerting after {}s: {}",
+                watcher.check_interval_seconds,
+                unhealthy.join(", ")
+            ),
+        )
+    };
+    let unchanged = since == previous
+        && conditions::find_condition(
+            &node_conditions,
+            conditions::CONDITION_TYPE_QUORUM_SLICE_ALERT,
+        )
+        .is_some_and(|c| c.status == status && c.reason == reason && c.message == message);
+    if unchanged {
+        return Ok(false);
+    }
+    let patch = json!({ "quorumSliceDegradedSince": since });
+    conditions::update_conditions(client, node, patch, |node_conditions| {
+        conditions::set_condition(
+            node_conditions,
+            conditions::CONDITION_TYPE_QUORUM_SLICE_ALERT,
+            status,
+            reason,
+            &message,
+        );
+    })
+    .await?;
+
+    Ok(alert && !was_alerting)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::controller::conditions::DEFAULT_MAX_CONDITIONS;
+    use crate::controller::fake_api::FakeApiServer;
+    use crate::controller::reconciler::update_status;
+    use crate::crd::StellarNodeStatus;
+
+    const SDF_1: &str = "GCGB2S2KGYARPVIA37HYZXVRM2YZUEXA6S33ZU5BUDC6THSB62LZSTYH";
+    const SDF_2: &str = "GCM6QMP3DLRPTAZW2UZPCPX2LF3SXWXKPMP3GKFZBDSF3QZGRM6T5CKF";
+
+    fn watcher_for(keys: &[&str]) -> QuorumSliceWatcherConfig {
+        QuorumSliceWatcherConfig {
+            enabled: true,
+            check_interval_seconds: 60,
+            alert_on_missing_validators: keys.iter().map(|k| k.to_string()).collect(),
+        }
+    }
+
+    #[test]
+    fn test_parses_compact_quorum_response() {
+        let response: QuorumResponse = serde_json::from_value(json!({
+            "node": "GAAA",
+            "qset": {
+                "agree": 4,
+                "delayed": null,
+                "disagree": [SDF_2],
+                "fail_at": 2,
+                "ledger": 51234,
+                "missing": null,
+                "phase": "EXTERNALIZE",
+                "validated": true,
+            },
+        }))
+        .unwrap();
+        assert_eq!(response.qset.agree, 4);
+        assert_eq!(
+            unhealthy_validators(&watcher_for(&[SDF_1, SDF_2]), &response.qset),
+            vec![format!("{SDF_2} (disagree)")]
+        );
+    }
+
+    #[test]
+    fn test_missing_validator_is_unhealthy() {
+        let qset = QuorumSetInfo {
+            agree: 2,
+            missing: Some(vec![SDF_1.to_string()]),
+            ..Default::default()
+        };
+        assert_eq!(
+            unhealthy_validators(&watcher_for(&[SDF_1]), &qset),
+            vec![format!("{SDF_1} (missing)")]
+        );
+        assert!(unhealthy_validators(&watcher_for(&[SDF_2]), &qset).is_empty());
+    }
+
+    #[test]
+    fn test_alert_fires_after_check_interval() {
+        let now = Utc::now();
+        let unhealthy = vec![format!("{SDF_1} (missing)")];
+
+        let started = degraded_since(&unhealthy, None, now).unwrap();
+        assert!(!alert_due(&started, 60, now));
+
+        let earlier = (now - chrono::Duration::seconds(61)).to_rfc3339();
+        let since = degraded_since(&unhealthy, Some(&earlier), now).unwrap();
+        assert_eq!(since, earlier);
+        assert!(alert_due(&since, 60, now));
+
+        assert_eq!(degraded_since(&[], Some(&earlier), now), None);
+    }
+
+    #[tokio::test]
+    async fn test_alert_fires_once_across_passes() {
+        let server = FakeApiServer::start().await;
+        let client = server.client();
+        let spec = serde_json::from_value(json!({
+            "nodeType": "Validator",
+            "network": "Testnet",
+            "version": "v21.0.0",
+            "validatorConfig": { "seedSecretRef": "validator-seed" },
+        }))
+        .unwrap();
+        let mut node = StellarNode::new("validator", spec);
+        node.metadata.namespace = Some("default".to_string());
+        node.status = Some(StellarNodeStatus {
+            quorum_slice_degraded_since: Some("2024-01-01T00:00:00Z".to_string()),
+            ..Default::default()
+        });
+        let path = "/apis/stellar.org/v1alpha1/namespaces/default/stellarnodes/validator";
+        server.insert(path, &node);
+
+        let watcher = watcher_for(&[SDF_1]);
+        let qset = QuorumSetInfo {
+            missing: Some(vec![SDF_1.to_string()]),
+            ..Default::default()
+        };
+        assert!(update_quorum_slice_status(&client, &node, &watcher, &qset)
+            .await
+            .unwrap());
+        update_status(
+            &client,
+            &node,
+            "Ready",
+            None,
+            1,
+            true,
+            DEFAULT_MAX_CONDITIONS,
+        )
+        .await
+        .unwrap();
+        let refreshed: StellarNode = server.get(path);
+        assert!(
+            !update_quorum_slice_status(&client, &refreshed, &watcher, &qset)
+                .await
+                .unwrap()
+        );
+    }
+}
@@ -0,0 +1,114 @@
+//! Per-node-type reconcile concurrency
+//!
+//! Validators (a single slow StatefulSet) and Horizon / Soroban RPC (fast
+//! Deployments) reconcile at very different costs. The controller itself runs
+//! reconciles with unbounded concurrency; each reconcile then takes a permit
+//! from the pool of its node type, limited by `--validator-reconcile-concurrency`,
+//! `--horizon-reconcile-concurrency` and `--soroban-reconcile-concurrency`.
+//! A burst of Horizon changes therefore only queues behind other Horizon
+//! reconciles and never holds up validators. A limit of 0 means unlimited.
+
+use std::sync::Arc;
+
+use tokio::sync::{OwnedSemaphorePermit, Semaphore};
+
+use crate::crd::NodeType;
+
+/// Concurrent reconciles per node type
+#[derive(Debug)]
+pub struct ReconcileLimiter {
+    validator: Option<Arc<Semaphore>>,
+    horizon: Option<Arc<Semaphore>>,
+    soroban: Option<Arc<Semaphore>>,
+}
+
+impl ReconcileLimiter {
+    /// Allow up to the given concurrent reconciles per node type (0 = unlimited)
+    pub fn new(validator: usize, horizon: usize, soroban: usize) -> Self {
+        let pool = |limit: usize| (limit > 0).then(|| Arc::new(Semaphore::new(limit)));
+        Self {
+            validator: pool(validator),
+            horizon: pool(horizon),
+            soroban: pool(soroban),
+        }
+    }
+
+    /// No limits for any node type
+    pub fn unlimited() -> Self {
+        Self::new(0, 0, 0)
+    }
+
+    fn pool(&self, node_type: &NodeType) -> Option<&Arc<Semaphore>> {
+        match node_type {
+            NodeType::Validator => self.validator.as_ref(),
+            NodeType::Horizon => self.horizon.as_ref(),
+            NodeType::SorobanRpc => self.soroban.as_ref(),
+        }
+    }
+
+    /// Wait for a reconcile slot of `node_type`
+    ///
+    /// Hold the returned permit for the whole reconcile; `None` means the node
+    /// type is unlimited.
+    pub async fn acquire(&self, node_type: &NodeType) -> Option<OwnedSemaphorePermit> {
+        let pool = self.pool(node_type)?.clone();
+        // The semaphores are never closed
+        pool.acquire_owned().await.ok()
+    }
+
+    /// Reconciles of `node_type` that could start right now, if limited
+    pub fn available(&self, node_type: &NodeType) -> Option<usize> {
+        self.pool(node_type).map(|pool| pool.available_permits())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[tokio::test]
+    async fn test_horizon_churn_does_not_starve_validators() {
+        let limiter = Arc::new(ReconcileLimiter::new(1, 2, 0));
+
+        // Saturate Horizon and queue a burst of further Horizon reconciles
+        let held = [
+            limiter.acquire(&NodeType::Horizon).await,
+            limiter.acquire(&NodeType::Horizon).await,
+        ];
+        assert!(held.iter().all(Option::is_some));
+        let queued: Vec<_> = (0..50)
+            .map(|_| {
+                let limiter = limiter.clone();
+                tokio::spawn(async move { limiter.acquire(&NodeType::Horizon).await })
+            })
+            .collect();
+        tokio::task::yield_now().await;
+        assert_eq!(limiter.available(&NodeType::Horizon), Some(0));
+
+        let validator = tokio::time::timeout(
+            Duration::from_secs(1),
+            limiter.acquire(&NodeType::Validator),
+        )
+        .await
+        .expect("validator reconcile must not wait behind Horizon");
+        assert!(validator.is_some());
+        assert_eq!(limiter.available(&NodeType::Validator), Some(0));
+
+        for task in queued {
+            task.abort();
+        }
+    }
+
+    #[tokio::test]
+    async fn test_zero_means_unlimited() {
+        let limiter = ReconcileLimiter::new(1, 0, 0);
+        assert!(limiter.acquire(&NodeType::SorobanRpc).await.is_none());
+        assert_eq!(limiter.available(&NodeType::Horizon), None);
+
+        let permit = limiter.acquire(&NodeType::Validator).await;
+        assert_eq!(limiter.available(&NodeType::Validator), Some(0));
+        drop(permit);
+        assert_eq!(limiter.available(&NodeType::Validator), Some(1));
+    }
+}
@@ -0,0 +1,160 @@
+//! Per-node reconciliation pause
+//!
+//! Annotating a StellarNode with `stellar.org/pause-reconciliation: "true"`
+//! stops the operator from applying anything to it, so manual changes made
+//! while debugging are not overwritten. The reconciler only records the
+//! `ReconciliationPaused` condition and requeues until the annotation is
+//! removed. The REST API toggles the annotation via
+//! `POST /api/v1/nodes/:namespace/:name/pause` and `.../resume`.
+
+use k8s_openapi::api::core::v1::Event;
+use kube::{
+    api::{Api, Patch, PatchParams, PostParams},
+    client::Client,
+    Resource, ResourceExt,
+};
+use serde_json::json;
+use tracing::info;
+
+use crate::crd::StellarNode;
+use crate::error::{Error, Result};
+
+use super::conditions;
+
+/// Annotation that pauses reconciliation of a single node when set to "true"
+pub const PAUSE_RECONCILIATION_ANNOTATION: &str = "stellar.org/pause-reconciliation";
+
+/// Whether `node` carries `stellar.org/pause-reconciliation: "true"`
+pub fn is_reconciliation_paused(node: &StellarNode) -> bool {
+    node.annotations()
+        .get(PAUSE_RECONCILIATION_ANNOTATION)
+        .is_some_and(|v| v.eq_ignore_ascii_case("true"))
+}
+
+/// Set or clear the `ReconciliationPaused` condition; no-op when unchanged
+pub async fn update_reconciliation_paused_condition(
+    client: &Client,
+    node: &StellarNode,
+    paused: bool,
+) -> Result<()> {
+    let current = node.status.as_ref().and_then(|s| {
+        conditions::find_condition(
+            &s.conditions,
+            conditions::CONDITION_TYPE_RECONCILIATION_PAUSED,
+        )
+    });
+    let unchanged = match current {
+        Some(condition) => paused && condition.status == conditions::CONDITION_STATUS_TRUE,
+        None => !paused,
+    };
+    if unchanged {
+        return Ok(());
+    }
+
+    conditions::update_conditions(client, node, json!({}), |node_conditions| {
+        if paused {
+            conditions::set_condition(
+                node_conditions,
+                conditions::CONDITION_TYPE_RECONCILIATION_PAUSED,
+                conditions::CONDITION_STATUS_TRUE,
+                "PauseAnnotation",
+                &format!(
+                    "Reconciliation paused via the {PAUSE_RECONCILIATION_ANNOTATION} annotation"
+                ),
+            );
+        } else {
+            conditions::remove_condition(
+                node_conditions,
+                conditions::CONDITION_TYPE_RECONCILIATION_PAUSED,
+            );
+        }
+    })
+    .await
+}
+
+/// Add or remove the pause annotation on `namespace/name` and record an Event
+///
+/// Emits `ReconciliationPaused` or `ReconciliationResumed` and returns the
+/// updated node.
+pub async fn set_reconciliation_paused(
+    client: &Client,
+    namespace: &str,
+    name: &str,
+    paused: bool,
+) -> Result<StellarNode> {
+    let api: Api<StellarNode> = Api::namespaced(client.clone(), namespace);
+    let value = if paused { json!("true") } else { json!(null) };
+    let patch = json!({
+        "metadata": {
+            "annotations": { PAUSE_RECONCILIATION_ANNOTATION: value }
+        }
+    });
+    let node = api
+        .patch(name, &PatchParams::default(), &Patch::Merge(&patch))
+        .await
+        .map_err(Error::KubeError)?;
+
+    let (reason, message) = if paused {
+        (
+            "ReconciliationPaused",
+            "Reconciliation paused; the operator will not apply changes to this node",
+        )
+    } else {
+        ("ReconciliationResumed", "Reconciliation resumed")
+    };
+    let time = k8s_openapi::apimachinery::pkg::apis::meta::v1::Time(chrono::Utc::now());
+    let event = Event {
+        metadata: kube::api::ObjectMeta {
+            generate_name: Some(format!("{name}-event-")),
+            ..Default::default()
+        },
+        type_: Some("Normal".to_string()),
+        reason: Some(reason.to_string()),
+        message: Some(message.to_string()),
+        involved_object: node.object_ref(&()),
+        first_timestamp: Some(time.clone()),
+        last_timestamp: Some(time),
+        count: Some(1),
+        ..Default::default()
+    };
+    let events: Api<Event> = Api::namespaced(client.clone(), namespace);
+    events
+        .create(&PostParams::default(), &event)
+        .await
+        .map_err(Error::KubeError)?;
+
+    info!("{} {}/{}", reason, namespace, name);
+    Ok(node)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::crd::StellarNodeSpec;
+
+    fn node(annotation: Option<&str>) -> StellarNode {
+        let spec: StellarNodeSpec = serde_json::from_value(json!({
+            "nodeType": "Horizon",
+            "network": "Testnet",
+            "version": "v2.31.0",
+            "horizonConfig": { "databaseSecretRef": "horizon-db" },
+        }))
+        .unwrap();
+        let mut node = StellarNode::new("horizon", spec);
+        if let Some(value) = annotation {
+            node.annotations_mut().insert(
+                PAUSE_RECONCILIATION_ANNOTATION.to_string(),
+                value.to_string(),
+            );
+        }
+        node
+    }
+
+    #[test]
+    fn test_is_reconciliation_paused() {
+        assert!(is_reconciliation_paused(&node(Some("true"))));
+        assert!(is_reconciliation_paused(&node(Some("True"))));
+        assert!(!is_reconciliation_paused(&node(Some("false"))));
+        assert!(!is_reconciliation_paused(&node(None)));
+    }
+}
@@ -0,0 +1,112 @@
+//! Generation context for reconcile traces
+//!
+//! kube-runtime wraps every reconcile in a `reconciling object` span carrying
+//! `object.reason` ("object updated", "requeue requested", "related object
+//! updated", ...), so exported traces already say which watch fired. The
+//! reconciler spans add `generation`, `observed_generation` and `trigger`,
+//! which tell a spec change apart from a status-only write or a resync of an
+//! unchanged spec, i.e. a reconcile that should find nothing to do.
+
+use std::fmt;
+
+use crate::crd::StellarNode;
+
+/// What a reconcile of a StellarNode is reacting to, as far as its object shows
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ReconcileTrigger {
+    /// Never reconciled: no `status.observedGeneration` yet
+    Create,
+    /// `metadata.generation` is ahead of `status.observedGeneration`
+    SpecChange,
+    /// `metadata.deletionTimestamp` is set
+    Deletion,
+    /// Spec already reconciled: a requeue, an owned resource change or a
+    /// status/metadata-only edit (see the parent span's `object.reason`)
+    Resync,
+}
+
+impl ReconcileTrigger {
+    /// Classify the reconcile of `node`
+    pub fn of(node: &StellarNode) -> Self {
+        if node.metadata.deletion_timestamp.is_some() {
+            return Self::Deletion;
+        }
+        match (node.metadata.generation, observed_generation(node)) {
+            (_, None) => Self::Create,
+            (Some(generation), Some(observed)) if generation > observed => Self::SpecChange,
+            _ => Self::Resync,
+        }
+    }
+
+    /// Span attribute value
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Create => "create",
+            Self::SpecChange => "spec_change",
+            Self::Deletion => "deletion",
+            Self::Resync => "resync",
+        }
+    }
+}
+
+impl fmt::Display for ReconcileTrigger {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// `status.observedGeneration` of `node`, if it has been reconciled before
+pub fn observed_generation(node: &StellarNode) -> Option<i64> {
+    node.status.as_ref()?.observed_generation
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::crd::{StellarNodeSpec, StellarNodeStatus};
+    use k8s_openapi::apimachinery::pkg::apis::meta::v1::Time;
+    use serde_json::json;
+
+    fn node(generation: i64, observed: Option<i64>) -> StellarNode {
+        let spec: StellarNodeSpec = serde_json::from_value(json!({
+            "nodeType": "Horizon",
+            "network": "Testnet",
+            "version": "v2.31.0",
+            "horizonConfig": { "databaseSecretRef": "horizon-db" },
+        }))
+        .unwrap();
+        let mut node = StellarNode::new("horizon", spec);
+        node.metadata.generation = Some(generation);
+        #[allow(deprecated)]
+        let ready = StellarNodeStatus::with_phase("Ready");
+        node.status = observed.map(|observed| StellarNodeStatus {
+            observed_generation: Some(observed),
+            ..ready
+        });
+        node
+    }
+
+    #[test]
+    fn test_trigger_from_generations() {
+        assert_eq!(
+            ReconcileTrigger::of(&node(1, None)),
+            ReconcileTrigger::Create
+        );
+        assert_eq!(
+            ReconcileTrigger::of(&node(3, Some(2))),
+            ReconcileTrigger::SpecChange
+        );
+        assert_eq!(
+            ReconcileTrigger::of(&node(3, Some(3))),
+            ReconcileTrigger::Resync
+        );
+    }
+
+    #[test]
+    fn test_deletion_wins() {
+        let mut node = node(3, Some(2));
+        node.metadata.deletion_timestamp = Some(Time(chrono::Utc::now()));
+        assert_eq!(ReconcileTrigger::of(&node), ReconcileTrigger::Deletion);
+        assert_eq!(ReconcileTrigger::Deletion.to_string(), "deletion");
+    }
+}
@@ -19,6 +19,7 @@
 //! 6. Update StellarNode status with current state
 //! 7. Schedule requeue for periodic health checks
 
+use std::sync::atomic::AtomicBool;
 use std::sync::Arc;
 use std::time::Duration;
 
@@ -26,13 +27,14 @@ use k8s_openapi::api::policy::v1::PodDisruptionBudget;
 
 use futures::StreamExt;
 use k8s_openapi::api::apps::v1::{Deployment, StatefulSet};
-use k8s_openapi::api::core::v1::{Event, PersistentVolumeClaim, Service};
+use k8s_openapi::api::core::v1::{ConfigMap, Event, PersistentVolumeClaim, Service};
 use kube::{
     api::{Api, Patch, PatchParams, PostParams},
     client::Client,
     runtime::{
         controller::{Action, Controller},
         finalizer::{finalizer, Event as FinalizerEvent},
+        reflector::{ObjectRef, Store},
         watcher::Config,
     },
     Resource, ResourceExt,
@@ -40,23 +42,48 @@ use kube::{
 use tracing::{debug, error, info, instrument, warn};
 
 use crate::crd::{
-    DisasterRecoveryStatus, NodeType, RolloutStrategy, SpecValidationError, StellarNode,
-    StellarNodeStatus,
+    Condition, DisasterRecoveryStatus, NodeType, RolloutStrategy, SpecValidationError, StellarNode,
+    StellarNodeStatus, ValidatorMode,
 };
 use crate::error::{Error, Result};
 
 use super::archive_health::{calculate_backoff, check_history_archive_health, ArchiveHealthResult};
+use super::autoscaling;
+use super::capabilities::ClusterCapabilities;
+use super::catchup_gate;
 use super::conditions;
+use super::core_version;
 use super::cve_reconciler;
+use super::database_readiness;
+use super::dependencies;
 use super::dr;
-use super::finalizers::STELLAR_NODE_FINALIZER;
+use super::failure_notifier::FailureNotifier;
+use super::failure_policy;
+use super::finalizers::{self, STELLAR_NODE_FINALIZER};
 use super::health;
+use super::hooks;
+use super::image_check::{self, ImageCheck, ImageVerifier};
+use super::jitter;
 #[cfg(feature = "metrics")]
 use super::metrics;
+use super::migration;
 use super::mtls;
+use super::node_selector;
+use super::oom;
+use super::operator_control;
 use super::peer_discovery;
+use super::permission_check;
+use super::precheck;
+use super::protocol_version;
+use super::pvc_resize;
+use super::quorum_slice;
+use super::reconcile_concurrency::ReconcileLimiter;
+use super::reconcile_pause;
+use super::reconcile_trigger::{self, ReconcileTrigger};
 use super::remediation;
 use super::resources;
+use super::storage_usage;
+use super::validator_key;
 use super::vsl;
 
 // Constants
@@ -74,6 +101,24 @@ pub struct ControllerState {
     pub operator_namespace: String,
     pub mtls_config: Option<crate::MtlsConfig>,
     pub dry_run: bool,
+    /// Maximum per-node jitter applied to requeue intervals, in percent
+    pub requeue_jitter_percent: u8,
+    /// Identity of this operator instance (pod name / leader election holder ID)
+    pub instance_id: String,
+    /// Whether this instance currently holds leadership
+    pub is_leader: AtomicBool,
+    /// HTTP client shared by all node health checks (see [`health::build_health_http_client`])
+    pub http_client: reqwest::Client,
+    /// Optional cluster add-ons found at startup (see [`crate::controller::detect_capabilities`])
+    pub capabilities: ClusterCapabilities,
+    /// Consecutive reconcile failures per node, reported to the failure webhook
+    pub failure_notifier: FailureNotifier,
+    /// Per-node-type reconcile concurrency limits (see [`ReconcileLimiter`])
+    pub reconcile_limiter: ReconcileLimiter,
+    /// Registry check of container images before rollout; `None` skips it
+    pub image_verifier: Option<ImageVerifier>,
+    /// Most entries kept in `status.conditions` (see [`conditions::cap_conditions`])
+    pub max_conditions: usize,
 }
 
 /// Main entry point to start the controller
@@ -96,19 +141,33 @@ pub struct ControllerState {
 /// # Examples
 ///
 /// ```rust,no_run
+/// use std::sync::atomic::AtomicBool;
 /// use std::sync::Arc;
-/// use stellar_k8s::controller::{ControllerState, run_controller};
+/// use stellar_k8s::controller::{
+///     build_health_http_client, detect_capabilities, run_controller, ControllerState,
+///     FailureNotifier, ReconcileLimiter,
+/// };
 /// use kube::Client;
 ///
 /// #[tokio::main]
 /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
 ///     let client = Client::try_default().await?;
+///     let capabilities = detect_capabilities(&client).await;
 ///     let state = Arc::new(ControllerState {
 ///         client,
 ///         enable_mtls: false,
 ///         mtls_config: None,
 ///         operator_namespace: "stellar-operator".to_string(),
 ///         dry_run: false,
+///         requeue_jitter_percent: 10,
+///         instance_id: "stellar-operator-0".to_string(),
+///         is_leader: AtomicBool::new(true),
+///         http_client: build_health_http_client(None)?,
+///         capabilities,
+///         failure_notifier: FailureNotifier::disabled(),
+///         reconcile_limiter: ReconcileLimiter::unlimited(),
+///         image_verifier: None,
+///         max_conditions: 32,
 ///     });
 ///     run_controller(state).await?;
 ///     Ok(())
@@ -134,13 +193,29 @@ pub async fn run_controller(state: Arc<ControllerState>) -> Result<()> {
         }
     }
 
-    Controller::new(stellar_nodes, Config::default())
+    // Fail fast on missing RBAC instead of on the first resource operation
+    permission_check::check_permissions(&client, &state.operator_namespace).await?;
+
+    let controller = Controller::new(stellar_nodes, Config::default());
+    let nodes = controller.store();
+    controller
         // Watch owned resources for changes
         .owns::<Deployment>(Api::all(client.clone()), Config::default())
         .owns::<StatefulSet>(Api::all(client.clone()), Config::default())
         .owns::<Service>(Api::all(client.clone()), Config::default())
         .owns::<PersistentVolumeClaim>(Api::all(client.clone()), Config::default())
         .owns::<PodDisruptionBudget>(Api::all(client.clone()), Config::default())
+        // Re-reconcile Horizon nodes when their offer filter rules change
+        .watches(
+            Api::<ConfigMap>::all(client.clone()),
+            Config::default(),
+            move |config_map| filter_rules_watchers(&nodes, &config_map),
+        )
+        // Re-reconcile everything when the fleet-wide pause is toggled
+        .reconcile_all_on(operator_control::pause_transitions(
+            client.clone(),
+            state.operator_namespace.clone(),
+        ))
         .shutdown_on_signal()
         .run(reconcile, error_policy, state)
         .for_each(|res| async move {
@@ -154,6 +229,24 @@ pub async fn run_controller(state: Arc<ControllerState>) -> Result<()> {
     Ok(())
 }
 
+/// StellarNodes whose `offersFiltering` rules are read from `config_map`
+fn filter_rules_watchers(
+    nodes: &Store<StellarNode>,
+    config_map: &ConfigMap,
+) -> Vec<ObjectRef<StellarNode>> {
+    let namespace = config_map.namespace();
+    let name = config_map.name_any();
+    nodes
+        .state()
+        .into_iter()
+        .filter(|node| {
+            node.namespace() == namespace
+                && node.spec.horizon_filter_rules_config_map() == Some(name.as_str())
+        })
+        .map(|node| ObjectRef::from_obj(node.as_ref()))
+        .collect()
+}
+
 /// Helper to emit a Kubernetes Event
 async fn emit_event(
     client: &Client,
@@ -200,6 +293,18 @@ fn format_spec_validation_errors(errors: &[SpecValidationError]) -> String {
     msg.trim_end().to_string()
 }
 
+/// Format non-fatal spec validation warnings into a user-friendly message
+fn format_spec_validation_warnings(warnings: &[SpecValidationError]) -> String {
+    let mut msg = String::from("Spec has the following warnings:\n");
+    for w in warnings {
+        msg.push_str(&format!(
+            "- Field `{}`: {}\n  Suggestion: {}\n",
+            w.field, w.message, w.how_to_fix
+        ));
+    }
+    msg.trim_end().to_string()
+}
+
 /// Emit a single grouped Kubernetes Event for all spec validation errors
 async fn emit_spec_validation_event(
     client: &Client,
@@ -228,29 +333,34 @@ impl std::fmt::Display for ActionType {
 }
 
 /// Helper to perform an action or emit a "WouldPatch" event in dry-run mode
-async fn apply_or_emit<Fut>(
-    ctx: &ControllerState,
-    node: &StellarNode,
+///
+/// The step is boxed so its state lives on the heap instead of inside the
+/// `apply_stellar_node` future, which would otherwise outgrow a worker stack.
+fn apply_or_emit<'a, Fut>(
+    ctx: &'a ControllerState,
+    node: &'a StellarNode,
     action: ActionType,
-    resource_info: &str,
+    resource_info: &'a str,
     fut: Fut,
-) -> Result<()>
+) -> futures::future::BoxFuture<'a, Result<()>>
 where
-    Fut: std::future::Future<Output = Result<()>>,
+    Fut: std::future::Future<Output = Result<()>> + Send + 'a,
 {
-    if ctx.dry_run {
-        let reason = match action {
-            ActionType::Create => "WouldCreate",
-            ActionType::Update => "WouldUpdate",
-            ActionType::Delete => "WouldDelete",
-        };
-        let message = format!("Dry Run: Would {action} {resource_info}");
-        info!("{}", message);
-        emit_event(&ctx.client, node, "Normal", reason, &message).await?;
-        Ok(())
-    } else {
-        fut.await
-    }
+    Box::pin(async move {
+        if ctx.dry_run {
+            let reason = match action {
+                ActionType::Create => "WouldCreate",
+                ActionType::Update => "WouldUpdate",
+                ActionType::Delete => "WouldDelete",
+            };
+            let message = format!("Dry Run: Would {action} {resource_info}");
+            info!("{}", message);
+            emit_event(&ctx.client, node, "Normal", reason, &message).await?;
+            Ok(())
+        } else {
+            fut.await
+        }
+    })
 }
 
 /// The main reconciliation function
@@ -259,12 +369,24 @@ where
 /// - A StellarNode is created, updated, or deleted
 /// - An owned resource (Deployment, Service, PVC) changes
 /// - The requeue timer expires
-#[instrument(skip(ctx), fields(name = %obj.name_any(), namespace = obj.namespace()))]
+///
+/// The span records the generation context and [`ReconcileTrigger`]; the
+/// watch event itself is in kube-runtime's parent span as `object.reason`.
+#[instrument(skip(ctx), fields(
+    name = %obj.name_any(),
+    namespace = obj.namespace(),
+    generation = obj.metadata.generation,
+    observed_generation = reconcile_trigger::observed_generation(&obj),
+    trigger = %ReconcileTrigger::of(&obj),
+))]
 async fn reconcile(obj: Arc<StellarNode>, ctx: Arc<ControllerState>) -> Result<Action> {
     let client = ctx.client.clone();
     let namespace = obj.namespace().unwrap_or_else(|| "default".to_string());
     let api: Api<StellarNode> = Api::namespaced(client.clone(), &namespace);
 
+    // Held until the reconcile returns, so busy node types only queue behind themselves
+    let _permit = ctx.reconcile_limiter.acquire(&obj.spec.node_type).await;
+
     info!(
         "Reconciling StellarNode {}/{} (type: {:?})",
         namespace,
@@ -272,19 +394,103 @@ async fn reconcile(obj: Arc<StellarNode>, ctx: Arc<ControllerState>) -> Result<A
         obj.spec.node_type
     );
 
-    // Use kube-rs built-in finalizer helper for clean lifecycle management
-    finalizer(&api, STELLAR_NODE_FINALIZER, obj, |event| async {
-        match event {
-            FinalizerEvent::Apply(node) => apply_stellar_node(&client, &node, &ctx).await,
-            FinalizerEvent::Cleanup(node) => cleanup_stellar_node(&client, &node, &ctx).await,
+    let control = operator_control::load_operator_control(&client, &ctx.operator_namespace).await?;
+
+    // Fleet-wide pause: halt all mutating reconciliation during incidents
+    if control.paused {
+        debug!(
+            "Fleet-wide pause active; skipping reconcile of {}/{}",
+            namespace,
+            obj.name_any()
+        );
+        if !ctx.dry_run {
+            update_paused_condition(&client, &obj, true).await?;
         }
-    })
-    .await
-    .map_err(Error::from)
+        return Ok(Action::await_change());
+    }
+    if !ctx.dry_run {
+        update_paused_condition(&client, &obj, false).await?;
+    }
+
+    // Per-node pause: leave manual changes in place while someone debugs the node
+    let paused = reconcile_pause::is_reconciliation_paused(&obj);
+    if !ctx.dry_run {
+        reconcile_pause::update_reconciliation_paused_condition(&client, &obj, paused).await?;
+    }
+    if paused {
+        debug!(
+            "Reconciliation of {}/{} paused by annotation",
+            namespace,
+            obj.name_any()
+        );
+        return Ok(Action::requeue(Duration::from_secs(60)));
+    }
+
+    // Observe-only namespaces are managed by another tool: track status and metrics
+    // but never touch their resources or metadata, not even on deletion
+    let observe_only = control.is_observe_only(&namespace);
+
+    // Stopped by spec.nodeFailurePolicy: wait for a spec change, but still allow deletion
+    if obj.metadata.deletion_timestamp.is_none() && failure_policy::reconcile_stopped(&obj) {
+        debug!(
+            "Reconciliation of {}/{} stopped after repeated failures",
+            namespace,
+            obj.name_any()
+        );
+        return Ok(Action::await_change());
+    }
+
+    let key = format!("{}/{}", namespace, obj.name_any());
+    let reconciled = obj.clone();
+
+    // Observe-only nodes bypass the finalizer helper: it would add (or remove) our
+    // finalizer, and a finalizer we add blocks deletion by the owning tool
+    let result = if observe_only {
+        if reconciled.metadata.deletion_timestamp.is_some() {
+            if reconciled
+                .finalizers()
+                .iter()
+                .any(|f| f == STELLAR_NODE_FINALIZER)
+            {
+                warn!(
+                    "{} is being deleted in observe-only namespace {} but still carries {}; remove it by hand or lift observe-only mode",
+                    reconciled.name_any(),
+                    namespace,
+                    STELLAR_NODE_FINALIZER
+                );
+            }
+            Ok(Action::await_change())
+        } else {
+            observe_stellar_node(&client, &reconciled, &ctx).await
+        }
+    } else {
+        // Use kube-rs built-in finalizer helper for clean lifecycle management
+        finalizer(&api, STELLAR_NODE_FINALIZER, obj, |event| async {
+            match event {
+                FinalizerEvent::Apply(node) => apply_stellar_node(&client, &node, &ctx).await,
+                FinalizerEvent::Cleanup(node) => cleanup_stellar_node(&client, &node, &ctx).await,
+            }
+        })
+        .await
+        .map_err(Error::from)
+    };
+
+    if result.is_ok() {
+        ctx.failure_notifier.record_success(&key);
+        if let Err(e) = failure_policy::record_success(&client, &reconciled).await {
+            warn!("Failed to reset the failure count of {}: {}", key, e);
+        }
+    }
+    result
 }
 
 /// Apply/create/update the StellarNode resources
-#[instrument(skip(client, node, ctx), fields(name = %node.name_any(), namespace = node.namespace()))]
+#[instrument(skip(client, node, ctx), fields(
+    name = %node.name_any(),
+    namespace = node.namespace(),
+    generation = node.metadata.generation,
+    observed_generation = reconcile_trigger::observed_generation(node),
+))]
 async fn apply_stellar_node(
     client: &Client,
     node: &StellarNode,
@@ -292,6 +498,16 @@ async fn apply_stellar_node(
 ) -> Result<Action> {
     let namespace = node.namespace().unwrap_or_else(|| "default".to_string());
     let name = node.name_any();
+    // Spread steady-state requeues so nodes created together do not reconcile in lockstep
+    let jitter_key = format!("{namespace}/{name}");
+    let requeue = |base: Duration| {
+        Action::requeue(jitter::requeue_interval(
+            base,
+            &jitter_key,
+            node.spec.requeue_jitter_percent,
+            ctx.requeue_jitter_percent,
+        ))
+    };
 
     info!("Applying StellarNode: {}/{}", namespace, name);
 
@@ -300,10 +516,290 @@ async fn apply_stellar_node(
         let message = format_spec_validation_errors(&errors);
         warn!("Validation failed for {}/{}: {}", namespace, name, message);
         emit_spec_validation_event(client, node, &errors).await?;
-        update_status(client, node, "Failed", Some(&message), 0, true).await?;
+        update_status(
+            client,
+            node,
+            "Failed",
+            Some(&message),
+            0,
+            true,
+            ctx.max_conditions,
+        )
+        .await?;
         return Err(Error::ValidationError(message));
     }
 
+    let warnings = node.spec.validation_warnings();
+    if !warnings.is_empty() {
+        let message = format_spec_validation_warnings(&warnings);
+        warn!(
+            "Validation warnings for {}/{}: {}",
+            namespace, name, message
+        );
+        emit_event(client, node, "Warning", "SpecValidationWarning", &message).await?;
+    }
+
+    // Owned resources carry an OwnerReference built from metadata.uid. Without a UID
+    // the reference would be empty and garbage collection would silently never run,
+    // so wait for the API server to populate it instead of creating orphans.
+    if node.metadata.uid.as_deref().unwrap_or_default().is_empty() {
+        warn!(
+            "StellarNode {}/{} has no metadata.uid; refusing to create owned resources with an empty owner reference, requeuing",
+            namespace, name
+        );
+        return Ok(Action::requeue(Duration::from_secs(5)));
+    }
+
+    // Hooks notify external automation; they never block reconciliation
+    if !ctx.dry_run {
+        hooks::run_hook(node, hooks::HookEvent::PreApply, None).await;
+    }
+
+    // Pin pods to labelled nodes; the detected selector is cached in status and
+    // applied to this reconcile's workload right away. The updated copies of
+    // the node below are boxed to keep them out of this future's state.
+    let detected;
+    let node = match Box::pin(node_selector::detect_node_selector(client, node)).await {
+        Ok(Some(selector)) if !ctx.dry_run => {
+            node_selector::update_detected_node_selector_status(client, node, &selector).await?;
+            let mut with_selector = node.clone();
+            with_selector
+                .status
+                .get_or_insert_with(Default::default)
+                .detected_node_selector = Some(selector);
+            detected = Box::new(with_selector);
+            &*detected
+        }
+        Ok(_) => node,
+        Err(e) => {
+            warn!(
+                "Failed to detect node selector for {}/{}: {}",
+                namespace, name, e
+            );
+            node
+        }
+    };
+
+    // Flag JSON-RPC payload logging, which exposes users' transactions
+    let debug_rpc = node.spec.soroban_debug_rpc();
+    let debug_rpc_flagged = node.status.as_ref().is_some_and(|s| {
+        conditions::is_condition_true(&s.conditions, conditions::CONDITION_TYPE_DEBUG_RPC_ENABLED)
+    });
+    if debug_rpc != debug_rpc_flagged {
+        let mut newly_flagged = false;
+        apply_or_emit(ctx, node, ActionType::Update, "Status (Debug RPC)", async {
+            newly_flagged = update_debug_rpc_condition(client, node, debug_rpc).await?;
+            Ok(())
+        })
+        .await?;
+        if newly_flagged {
+            emit_event(
+                client,
+                node,
+                "Warning",
+                "DebugRPCEnabled",
+                "Soroban RPC is logging full JSON-RPC request and response payloads",
+            )
+            .await?;
+        }
+    }
+
+    // 0. Hold the node until every spec.dependsOn node is Ready
+    if !node.spec.depends_on.is_empty() {
+        let unmet = Box::pin(dependencies::unmet_dependencies(client, node)).await?;
+        if !ctx.dry_run && dependencies::update_dependency_condition(client, node, &unmet).await? {
+            emit_event(
+                client,
+                node,
+                "Normal",
+                "WaitingForDependency",
+                &format!("Waiting for {}", unmet.join(", ")),
+            )
+            .await?;
+        }
+        if !unmet.is_empty() {
+            info!(
+                "{}/{} is waiting for dependencies: {}",
+                namespace,
+                name,
+                unmet.join(", ")
+            );
+            return Ok(requeue(Duration::from_secs(30)));
+        }
+    }
+
+    // 0a. Hold Horizon until its database Secret holds a valid connection string
+    if database_readiness::database_secret(node).is_some() {
+        let problem = Box::pin(database_readiness::unmet_database(client, node)).await?;
+        if !ctx.dry_run {
+            match database_readiness::update_database_condition(client, node, problem.as_deref())
+                .await?
+            {
+                database_readiness::DatabaseWait::Unchanged => {}
+                database_readiness::DatabaseWait::Started => {
+                    emit_event(
+                        client,
+                        node,
+                        "Normal",
+                        "WaitingForDatabase",
+                        problem.as_deref().unwrap_or_default(),
+                    )
+                    .await?;
+                }
+                database_readiness::DatabaseWait::TimedOut => {
+                    emit_event(
+                        client,
+                        node,
+                        "Warning",
+                        database_readiness::REASON_DATABASE_READY_TIMEOUT,
+                        &format!(
+                            "Database still not ready after databaseReadyTimeoutSeconds: {}",
+                            problem.as_deref().unwrap_or_default()
+                        ),
+                    )
+                    .await?;
+                }
+            }
+        }
+        if let Some(problem) = problem {
+            info!(
+                "{}/{} is waiting for its database: {}",
+                namespace, name, problem
+            );
+            return Ok(requeue(Duration::from_secs(30)));
+        }
+    }
+
+    // 0b. Keep a gated validator non-validating until Stellar Core is synced
+    let catchup_checked;
+    let node = if catchup_gate::catching_up(node) && !ctx.dry_run {
+        let use_tls = ctx.mtls_config.is_some();
+        let mode = match Box::pin(catchup_gate::core_synced(&ctx.http_client, node, use_tls)).await
+        {
+            Ok(true) => ValidatorMode::Validating,
+            Ok(false) => ValidatorMode::CatchingUp,
+            Err(e) => {
+                debug!(
+                    "Stellar Core of {}/{} not reachable for the catchup gate: {}",
+                    namespace, name, e
+                );
+                ValidatorMode::CatchingUp
+            }
+        };
+        catchup_gate::update_validator_mode(client, node, mode).await?;
+        if mode == ValidatorMode::Validating {
+            emit_event(
+                client,
+                node,
+                "Normal",
+                "CatchupComplete",
+                "Stellar Core is synced; restarting the node in validating mode",
+            )
+            .await?;
+        }
+        let mut checked = node.clone();
+        checked
+            .status
+            .get_or_insert_with(Default::default)
+            .validator_mode = Some(mode);
+        catchup_checked = Box::new(checked);
+        &*catchup_checked
+    } else {
+        node
+    };
+
+    // 0c. Scale test environments down over the weekend; a weekend floor of 0
+    // suspends the node until Monday morning without touching its spec
+    let weekend_min_replicas = autoscaling::weekend_min_replicas(node, chrono::Utc::now());
+    let weekend_suspension = weekend_min_replicas == Some(0);
+    let recorded_suspension = node
+        .status
+        .as_ref()
+        .is_some_and(|s| s.weekend_suspension_active);
+    if weekend_suspension != recorded_suspension {
+        apply_or_emit(
+            ctx,
+            node,
+            ActionType::Update,
+            "Status (Weekend Suspension)",
+            async {
+                autoscaling::update_weekend_suspension_status(client, node, weekend_suspension)
+                    .await?;
+                Ok(())
+            },
+        )
+        .await?;
+        if !ctx.dry_run {
+            let (reason, message) = if weekend_suspension {
+                ("WeekendSuspension", "Suspending the node for the weekend")
+            } else {
+                (
+                    "WeekendSuspensionEnded",
+                    "Weekend over; restoring autoscaling",
+                )
+            };
+            emit_event(client, node, "Normal", reason, message).await?;
+        }
+    }
+    let weekend_checked;
+    let node = if weekend_suspension != recorded_suspension {
+        let mut checked = node.clone();
+        checked
+            .status
+            .get_or_insert_with(Default::default)
+            .weekend_suspension_active = weekend_suspension;
+        weekend_checked = Box::new(checked);
+        &*weekend_checked
+    } else {
+        node
+    };
+
+    // A scheduled suspension is not maintenance: keep the resources in place at
+    // 0 replicas and report it as its own phase until the window closes
+    if weekend_suspension && !node.spec.suspended {
+        apply_or_emit(
+            ctx,
+            node,
+            ActionType::Update,
+            "Weekend suspension resources",
+            async {
+                resources::ensure_pvc(client, node).await?;
+                resources::ensure_config_map(client, node, None, ctx.enable_mtls).await?;
+                match node.spec.node_type {
+                    NodeType::Validator => {
+                        resources::ensure_statefulset(client, node, ctx.enable_mtls).await?;
+                    }
+                    NodeType::Horizon | NodeType::SorobanRpc => {
+                        resources::ensure_deployment(client, node, ctx.enable_mtls).await?;
+                    }
+                }
+                resources::ensure_service(client, node, ctx.enable_mtls).await?;
+                Ok(())
+            },
+        )
+        .await?;
+        apply_or_emit(
+            ctx,
+            node,
+            ActionType::Update,
+            "Status (WeekendSuspended)",
+            async {
+                update_status(
+                    client,
+                    node,
+                    "WeekendSuspended",
+                    Some("Suspended for the weekend by spec.autoscaling.disableOnWeekend; resumes Monday 09:00 in weekendTimezone"),
+                    0,
+                    true,
+                    ctx.max_conditions,
+                )
+                .await
+            },
+        )
+        .await?;
+        return Ok(requeue(Duration::from_secs(60)));
+    }
+
     // 1. Core infrastructure (PVC and ConfigMap) always managed by operator
     apply_or_emit(ctx, node, ActionType::Update, "PVC and ConfigMap", async {
         resources::ensure_pvc(client, node).await?;
@@ -312,6 +808,47 @@ async fn apply_stellar_node(
     })
     .await?;
 
+    // Online PVC expansion when storage.size grows
+    let resize_poll = if ctx.dry_run {
+        None
+    } else {
+        let outcome = Box::pin(pvc_resize::reconcile_pvc_resize(client, node)).await?;
+        match &outcome {
+            pvc_resize::PvcResizeOutcome::Started { from, to } => {
+                emit_event(
+                    client,
+                    node,
+                    "Normal",
+                    "PVCResizeStarted",
+                    &format!("Expanding data volume from {from} to {to}"),
+                )
+                .await?;
+            }
+            pvc_resize::PvcResizeOutcome::Completed => {
+                emit_event(
+                    client,
+                    node,
+                    "Normal",
+                    "PVCResizeComplete",
+                    "Data volume expansion completed",
+                )
+                .await?;
+            }
+            pvc_resize::PvcResizeOutcome::TimedOut => {
+                emit_event(
+                    client,
+                    node,
+                    "Warning",
+                    "PVCResizeTimeout",
+                    "Data volume expansion did not finish within storage.resizeGracePeriodSeconds; the pod may need a restart",
+                )
+                .await?;
+            }
+            pvc_resize::PvcResizeOutcome::Idle | pvc_resize::PvcResizeOutcome::Pending => {}
+        }
+        outcome.poll_interval()
+    };
+
     // 1a. Managed Database (CloudNativePG)
     apply_or_emit(ctx, node, ActionType::Update, "Managed Database", async {
         resources::ensure_cnpg_cluster(client, node).await?;
@@ -359,6 +896,7 @@ async fn apply_stellar_node(
                     Some("Manual maintenance mode active; workload management paused"),
                     0,
                     true,
+                    ctx.max_conditions,
                 )
                 .await?;
                 update_suspended_status(client, node).await?;
@@ -367,7 +905,7 @@ async fn apply_stellar_node(
         )
         .await?;
 
-        return Ok(Action::requeue(Duration::from_secs(60)));
+        return Ok(requeue(Duration::from_secs(60)));
     }
 
     // 3. Normal Mode: Handle suspension
@@ -381,38 +919,96 @@ async fn apply_stellar_node(
             Some("Node is suspended"),
             0,
             true,
+            ctx.max_conditions,
         )
         .await?;
         // Still create resources but with 0 replicas
     }
 
-    // Handle Horizon database migrations
-    if node.spec.node_type == NodeType::Horizon {
-        if let Some(horizon_config) = &node.spec.horizon_config {
-            if horizon_config.auto_migration {
-                let current_version = &node.spec.version;
-                let last_migrated = node
-                    .status
-                    .as_ref()
-                    .and_then(|s| s.last_migrated_version.as_ref());
+    // Handle Horizon database migrations as a pre-upgrade Job. The workload is only
+    // patched to the new version once the migration has succeeded, so a failing
+    // migration leaves the previous version running.
+    if node.spec.node_type == NodeType::Horizon && ctx.dry_run {
+        if migration::migration_required(node) {
+            apply_or_emit(
+                ctx,
+                node,
+                ActionType::Create,
+                "Database migration Job",
+                async { Ok(()) },
+            )
+            .await?;
+        }
+    } else if node.spec.node_type == NodeType::Horizon {
+        if Box::pin(migration::reconcile_rollback(client, node)).await? {
+            info!(
+                "Database migration rollback in progress for {}/{}",
+                namespace, name
+            );
+            return Ok(Action::requeue(Duration::from_secs(10)));
+        }
 
-                if last_migrated.map(|v| v != current_version).unwrap_or(true) {
-                    info!(
-                        "Database migration required for Horizon {}/{} (version: {})",
-                        namespace, name, current_version
-                    );
+        match Box::pin(migration::reconcile_migration(client, node)).await? {
+            migration::MigrationOutcome::NotRequired => {}
+            migration::MigrationOutcome::InProgress => {
+                info!(
+                    "Database migration for {}/{} (version: {}) in progress; holding workload upgrade",
+                    namespace, name, node.spec.version
+                );
+                return Ok(Action::requeue(Duration::from_secs(10)));
+            }
+            migration::MigrationOutcome::Succeeded { version } => {
+                update_migration_status(client, node, Some(&version), None).await?;
+                emit_event(
+                    client,
+                    node,
+                    "Normal",
+                    "DatabaseMigrationSucceeded",
+                    &format!("Database migrated for version {version}"),
+                )
+                .await?;
+            }
+            migration::MigrationOutcome::Failed { job_name, summary } => {
+                let message = format!(
+                    "Migration Job {job_name} failed for version {}; keeping the previous version running. {summary}",
+                    node.spec.version
+                );
+                warn!("{}/{}: {}", namespace, name, message);
+                emit_event(client, node, "Warning", "DatabaseMigrationFailed", &message).await?;
+                update_migration_status(client, node, None, Some(&message)).await?;
+                return Ok(requeue(Duration::from_secs(60)));
+            }
+        }
+    }
 
-                    emit_event(
-                        client,
-                        node,
-                        "Normal",
-                        "DatabaseMigrationRequired",
-                        &format!(
-                            "Database migration will be performed via InitContainer for version {current_version}"
-                        ),
-                    )
-                    .await?;
-                }
+    // Run the precheck hook before a change that restarts pods is rolled out
+    if node.spec.precheck_hook.is_some() && !ctx.dry_run {
+        match Box::pin(precheck::reconcile_precheck(client, node, ctx.enable_mtls)).await? {
+            precheck::PrecheckOutcome::NotRequired | precheck::PrecheckOutcome::Passed => {}
+            precheck::PrecheckOutcome::Aborted(result) => {
+                let message = format!(
+                    "{}; holding the rollout of generation {}",
+                    result.message.unwrap_or_default(),
+                    result.generation
+                );
+                warn!("{}/{}: {}", namespace, name, message);
+                emit_event(client, node, "Warning", "PrecheckFailed", &message).await?;
+                return Ok(requeue(Duration::from_secs(60)));
+            }
+            precheck::PrecheckOutcome::Held => {
+                info!(
+                    "Precheck failed for {}/{} (generation {:?}); rollout held until the spec changes",
+                    namespace, name, node.metadata.generation
+                );
+                return Ok(requeue(Duration::from_secs(60)));
+            }
+            precheck::PrecheckOutcome::Warned(result) => {
+                let message = format!(
+                    "{}; continuing because failurePolicy is Warn",
+                    result.message.unwrap_or_default()
+                );
+                warn!("{}/{}: {}", namespace, name, message);
+                emit_event(client, node, "Warning", "PrecheckFailed", &message).await?;
             }
         }
     }
@@ -514,6 +1110,7 @@ async fn apply_stellar_node(
             Some("Creating resources"),
             0,
             true,
+            ctx.max_conditions,
         )
         .await?;
         Ok(())
@@ -571,9 +1168,10 @@ async fn apply_stellar_node(
             Some("Manual maintenance mode active; workload management paused"),
             0,
             true,
+            ctx.max_conditions,
         )
         .await?;
-        return Ok(Action::requeue(Duration::from_secs(60)));
+        return Ok(requeue(Duration::from_secs(60)));
     }
 
     if node.spec.suspended {
@@ -594,23 +1192,162 @@ async fn apply_stellar_node(
     })
     .await?;
 
-    // 5. Create/update the Deployment/StatefulSet based on node type
-    apply_or_emit(
-        ctx,
+    // 4a. Stamp the deploy time when a new version is about to roll out, so the
+    // HPA is frozen while it warms up (canaries keep the stable version running)
+    if node.spec.autoscaling_enabled()
+        && !matches!(node.spec.strategy, RolloutStrategy::Canary(_))
+        && autoscaling::new_version_pending(client, node).await?
+    {
+        apply_or_emit(
+            ctx,
+            node,
+            ActionType::Update,
+            "Deployment deploy time",
+            async { autoscaling::record_deploy_time(client, node).await },
+        )
+        .await?;
+    }
+
+    // 4b. Keep validators stopped once the network moves past their protocol version
+    let protocol_checked;
+    let node = match Box::pin(protocol_version::fetch_network_protocol_version(
+        &ctx.http_client,
         node,
-        ActionType::Update,
-        "Workload (Deployment/StatefulSet)",
-        async {
-            match node.spec.node_type {
-                NodeType::Validator => {
-                    resources::ensure_statefulset(client, node, ctx.enable_mtls).await?;
-                }
-                NodeType::Horizon | NodeType::SorobanRpc => {
-                    // Handle Canary Deployment
-                    if let RolloutStrategy::Canary(_) = &node.spec.strategy {
-                        // Determine if we are in a canary state
-                        let current_version = get_current_deployment_version(client, node).await?;
-                        if let Some(cv) = current_version {
+    ))
+    .await
+    {
+        Ok(Some(network_version)) if !ctx.dry_run => {
+            let became_outdated =
+                protocol_version::update_protocol_version_status(client, node, network_version)
+                    .await?;
+            let mut checked = node.clone();
+            checked
+                .status
+                .get_or_insert_with(Default::default)
+                .network_protocol_version = Some(network_version);
+            if became_outdated {
+                emit_event(
+                    client,
+                    node,
+                    "Warning",
+                    "ProtocolVersionOutdated",
+                    &format!(
+                        "Network upgraded to protocol {network_version}; scaling the validator to 0 until its image supports it"
+                    ),
+                )
+                .await?;
+            }
+            protocol_checked = Box::new(checked);
+            &*protocol_checked
+        }
+        Ok(_) => node,
+        Err(e) => {
+            warn!(
+                "Failed to read the network protocol version for {}/{}: {}",
+                namespace, name, e
+            );
+            node
+        }
+    };
+
+    // 4c. Keep Horizon stopped while its Stellar Core is older than it supports
+    let core_checked;
+    let node = match Box::pin(core_version::fetch_core_build(&ctx.http_client, node)).await {
+        Ok(Some(build)) if !ctx.dry_run => {
+            let was_incompatible =
+                core_version::update_core_version_status(client, node, &build).await?;
+            let mut checked = node.clone();
+            checked
+                .status
+                .get_or_insert_with(Default::default)
+                .stellar_core_version = Some(build.clone());
+            let incompatible = core_version::core_version_incompatible(&checked);
+            if incompatible && !was_incompatible {
+                emit_event(
+                    client,
+                    node,
+                    "Warning",
+                    "CoreVersionIncompatible",
+                    &format!(
+                        "Stellar Core reports '{build}', older than minStellarCoreVersion; scaling Horizon to 0"
+                    ),
+                )
+                .await?;
+            } else if was_incompatible && !incompatible {
+                emit_event(
+                    client,
+                    node,
+                    "Normal",
+                    "CoreVersionCompatible",
+                    &format!("Stellar Core reports '{build}'; resuming Horizon"),
+                )
+                .await?;
+            }
+            core_checked = Box::new(checked);
+            &*core_checked
+        }
+        Ok(_) => node,
+        Err(e) => {
+            warn!(
+                "Failed to read the Stellar Core version for {}/{}: {}",
+                namespace, name, e
+            );
+            node
+        }
+    };
+
+    // 4d. Pre-flight: do not roll out an image the registry does not have
+    if let Some(verifier) = &ctx.image_verifier {
+        let image = node.spec.container_image();
+        let check = Box::pin(verifier.check(client, node)).await;
+        if let ImageCheck::Unverified(reason) = &check {
+            debug!(
+                "Could not verify image {} of {}/{}: {}",
+                image, namespace, name, reason
+            );
+        }
+        let missing = check == ImageCheck::NotFound;
+        if !ctx.dry_run
+            && image_check::update_image_condition(client, node, missing.then_some(image.as_str()))
+                .await?
+        {
+            emit_event(
+                client,
+                node,
+                "Warning",
+                "ImageNotFound",
+                &format!(
+                    "Image {image} was not found in its registry; the workload was not updated"
+                ),
+            )
+            .await?;
+        }
+        if missing {
+            warn!(
+                "Image {} of {}/{} not found; skipping the workload update",
+                image, namespace, name
+            );
+            return Ok(requeue(Duration::from_secs(60)));
+        }
+    }
+
+    // 5. Create/update the Deployment/StatefulSet based on node type
+    apply_or_emit(
+        ctx,
+        node,
+        ActionType::Update,
+        "Workload (Deployment/StatefulSet)",
+        async {
+            match node.spec.node_type {
+                NodeType::Validator => {
+                    resources::ensure_statefulset(client, node, ctx.enable_mtls).await?;
+                }
+                NodeType::Horizon | NodeType::SorobanRpc => {
+                    // Handle Canary Deployment
+                    if let RolloutStrategy::Canary(_) = &node.spec.strategy {
+                        // Determine if we are in a canary state
+                        let current_version = get_current_deployment_version(client, node).await?;
+                        if let Some(cv) = current_version {
                             if cv != node.spec.version {
                                 // We have a version mismatch, ensure canary
                                 info!(
@@ -668,6 +1405,63 @@ async fn apply_stellar_node(
     )
     .await?;
 
+    // 5b. Remove optional resources whose configuration was dropped from the spec
+    if !node.spec.ingress_enabled() && resources::get_ingress(client, node).await?.is_some() {
+        info!(
+            "Ingress no longer configured for {}/{}; removing stale Ingress",
+            namespace, name
+        );
+        apply_or_emit(ctx, node, ActionType::Delete, "Ingress", async {
+            resources::delete_ingress(client, node).await
+        })
+        .await?;
+    }
+    if !node.spec.network_policy_enabled()
+        && resources::get_network_policy(client, node).await?.is_some()
+    {
+        info!(
+            "NetworkPolicy no longer configured for {}/{}; removing stale NetworkPolicy",
+            namespace, name
+        );
+        apply_or_emit(ctx, node, ActionType::Delete, "NetworkPolicy", async {
+            resources::delete_network_policy(client, node).await
+        })
+        .await?;
+    }
+
+    // 5c. stellar.toml publication for Validators, removed once no longer configured
+    if resources::stellar_toml_config(node).is_some() {
+        apply_or_emit(
+            ctx,
+            node,
+            ActionType::Update,
+            "stellar.toml server",
+            async { resources::ensure_stellar_toml(client, node).await },
+        )
+        .await?;
+    } else if resources::stellar_toml_exists(client, node).await? {
+        info!(
+            "stellar.toml no longer configured for {}/{}; removing stellar.toml server",
+            namespace, name
+        );
+        apply_or_emit(
+            ctx,
+            node,
+            ActionType::Delete,
+            "stellar.toml server",
+            async { resources::delete_stellar_toml(client, node).await },
+        )
+        .await?;
+    }
+
+    // 5d. History archive GC CronJob, removed when GC is disabled or runs as a sidecar
+    if node.spec.node_type == NodeType::Validator {
+        apply_or_emit(ctx, node, ActionType::Update, "history GC CronJob", async {
+            resources::ensure_history_gc_cron_job(client, node).await
+        })
+        .await?;
+    }
+
     // 5a. MetalLB / LoadBalancer
     apply_or_emit(
         ctx,
@@ -690,30 +1484,353 @@ async fn apply_stellar_node(
         ActionType::Update,
         "Monitoring and Scaling resources",
         async {
-            if node.spec.autoscaling.is_some() {
-                resources::ensure_service_monitor(client, node).await?;
-                resources::ensure_hpa(client, node).await?;
-            }
+            resources::ensure_service_monitor(client, node).await?;
             resources::ensure_pdb(client, node).await?;
             resources::ensure_alerting(client, node).await?;
+            resources::ensure_prometheus_rule(client, node).await?;
+            resources::ensure_grafana_dashboard(client, node).await?;
             resources::ensure_network_policy(client, node).await?;
             Ok(())
         },
     )
     .await?;
 
+    // 6a. Autoscaling: manage the HPA only while enabled, and remove a stale
+    // HPA left behind after autoscaling was disabled or removed. Horizon stopped
+    // for an incompatible Stellar Core has its HPA removed too, since the HPA's
+    // minReplicas would scale it back up
+    let existing_hpa = resources::get_hpa(client, node).await?;
+    if node.spec.autoscaling_enabled() && !core_version::core_version_incompatible(node) {
+        // Feedback loop: back off Horizon replicas while Stellar Core lags on ingestion
+        let mut ingest_lag_throttling = false;
+        if let Some(cfg) = node
+            .spec
+            .autoscaling
+            .as_ref()
+            .filter(|a| a.ingest_lag_threshold().is_some())
+        {
+            let lag = match autoscaling::measure_ingest_lag(client, node).await {
+                Ok(lag) => lag,
+                Err(e) => {
+                    warn!(
+                        "Failed to read ingest lag for {}/{}: {}",
+                        namespace, name, e
+                    );
+                    None
+                }
+            };
+            ingest_lag_throttling = lag.is_some_and(|l| autoscaling::ingest_lag_exceeded(cfg, l));
+            let mut throttling_started = false;
+            apply_or_emit(
+                ctx,
+                node,
+                ActionType::Update,
+                "Status (Ingest Lag)",
+                async {
+                    throttling_started = autoscaling::update_ingest_lag_status(
+                        client,
+                        node,
+                        lag,
+                        ingest_lag_throttling,
+                    )
+                    .await?;
+                    Ok(())
+                },
+            )
+            .await?;
+            if throttling_started {
+                emit_event(
+                    client,
+                    node,
+                    "Warning",
+                    "IngestLagThrottling",
+                    &format!(
+                        "Stellar Core ingest lag {:.0}s exceeds {}s; lowering HPA minReplicas to 1",
+                        lag.unwrap_or_default(),
+                        cfg.max_ingest_lag_seconds.unwrap_or_default()
+                    ),
+                )
+                .await?;
+            }
+        }
+
+        // Scheduled replica bounds from spec.autoscaling.cronOverrides
+        let scaling_override = autoscaling::active_scaling_override(node, chrono::Utc::now());
+        let recorded_override = node
+            .status
+            .as_ref()
+            .and_then(|s| s.active_scaling_override.as_ref());
+        if scaling_override.as_ref() != recorded_override {
+            apply_or_emit(
+                ctx,
+                node,
+                ActionType::Update,
+                "Status (Scaling Override)",
+                async {
+                    autoscaling::update_scaling_override_status(
+                        client,
+                        node,
+                        scaling_override.as_ref(),
+                    )
+                    .await?;
+                    Ok(())
+                },
+            )
+            .await?;
+        }
+
+        // Keep maxReplicas within what the cluster can schedule. Measuring lists
+        // every Node and Pod, so it only runs when the HPA is created or the
+        // spec changed; resyncs keep the recorded cap
+        let recorded_limit = node
+            .status
+            .as_ref()
+            .and_then(|s| s.cluster_capacity_limited_max_replicas);
+        let hpa_changing = existing_hpa.is_none()
+            || matches!(
+                ReconcileTrigger::of(node),
+                ReconcileTrigger::Create | ReconcileTrigger::SpecChange
+            );
+        let capacity_limit = if hpa_changing {
+            match autoscaling::compute_capacity_limit(client, node).await {
+                Ok(limit) => limit,
+                Err(e) => {
+                    warn!(
+                        "Failed to compute cluster capacity for {}/{}: {}",
+                        namespace, name, e
+                    );
+                    None
+                }
+            }
+        } else {
+            recorded_limit
+        };
+        if capacity_limit != recorded_limit {
+            apply_or_emit(
+                ctx,
+                node,
+                ActionType::Update,
+                "Status (Cluster Capacity)",
+                async {
+                    autoscaling::update_capacity_limit_status(client, node, capacity_limit).await?;
+                    Ok(())
+                },
+            )
+            .await?;
+        }
+
+        apply_or_emit(ctx, node, ActionType::Update, "HPA", async {
+            resources::ensure_hpa(
+                client,
+                node,
+                scaling_override.as_ref(),
+                weekend_min_replicas.map(|min| min as i32),
+                ingest_lag_throttling,
+                capacity_limit,
+            )
+            .await?;
+            Ok(())
+        })
+        .await?;
+        if let Some(hpa) = resources::get_hpa(client, node).await? {
+            let bounds = hpa
+                .spec
+                .map(|spec| (spec.min_replicas.unwrap_or(1), spec.max_replicas));
+            apply_or_emit(ctx, node, ActionType::Update, "Status (HPA)", async {
+                autoscaling::update_hpa_status(client, node, bounds).await?;
+                Ok(())
+            })
+            .await?;
+        }
+    } else if existing_hpa.is_some() {
+        info!(
+            "Autoscaling disabled for {}/{}; removing stale HPA",
+            namespace, name
+        );
+        apply_or_emit(ctx, node, ActionType::Delete, "HPA", async {
+            resources::delete_hpa(client, node).await?;
+            autoscaling::update_hpa_status(client, node, None).await?;
+            autoscaling::update_scaling_override_status(client, node, None).await?;
+            autoscaling::update_capacity_limit_status(client, node, None).await?;
+            Ok(())
+        })
+        .await?;
+    }
+
+    // 6b. Effective capacity across heterogeneous node pools
+    match autoscaling::compute_effective_capacity(client, node).await {
+        Ok(Some(units))
+            if node
+                .status
+                .as_ref()
+                .and_then(|status| status.effective_capacity_units)
+                != Some(units) =>
+        {
+            apply_or_emit(
+                ctx,
+                node,
+                ActionType::Update,
+                "Status (Effective Capacity)",
+                async {
+                    autoscaling::update_effective_capacity_status(client, node, units).await?;
+                    Ok(())
+                },
+            )
+            .await?;
+        }
+        Ok(_) => {}
+        Err(e) => {
+            warn!(
+                "Failed to compute effective capacity for {}/{}: {}",
+                namespace, name, e
+            );
+        }
+    }
+
+    // 6c. Data volume utilization from kubelet volume stats
+    match storage_usage::measure_storage_usage(client, node).await {
+        Ok(Some(used_percent)) => {
+            let mut pressure_raised = false;
+            apply_or_emit(
+                ctx,
+                node,
+                ActionType::Update,
+                "Status (Storage Usage)",
+                async {
+                    pressure_raised =
+                        storage_usage::update_storage_usage_status(client, node, used_percent)
+                            .await?;
+                    Ok(())
+                },
+            )
+            .await?;
+            if pressure_raised {
+                emit_event(
+                    client,
+                    node,
+                    "Warning",
+                    "StoragePressure",
+                    &format!("Data volume is {used_percent}% full; expand spec.storage.size before it fills up"),
+                )
+                .await?;
+            }
+        }
+        Ok(None) => {}
+        Err(e) => {
+            warn!(
+                "Failed to measure storage usage for {}/{}: {}",
+                namespace, name, e
+            );
+        }
+    }
+
+    // 6d. Report containers killed for exceeding their memory limit
+    if node.spec.emit_oom_event_on_kill {
+        match Box::pin(oom::detect_oom_kills(client, node)).await {
+            Ok(kills) => {
+                if !ctx.dry_run {
+                    for kill in &kills {
+                        emit_event(client, node, "Warning", "OOMKilled", &kill.message()).await?;
+                    }
+                }
+                if let Some(latest) = kills.last().map(|kill| kill.finished_at) {
+                    apply_or_emit(ctx, node, ActionType::Update, "Status (OOM Kill)", async {
+                        oom::update_oom_killed_status(client, node, latest).await?;
+                        Ok(())
+                    })
+                    .await?;
+                }
+            }
+            Err(e) => {
+                warn!(
+                    "Failed to check for OOM kills of {}/{}: {}",
+                    namespace, name, e
+                );
+            }
+        }
+    }
+
+    // 6e. Publish the validator public key for other validators' quorum sets
+    match validator_key::read_validator_public_key(client, node).await {
+        Ok(Some(public_key)) => {
+            let recorded = node.status.as_ref().and_then(|s| s.public_key.as_deref());
+            if recorded != Some(public_key.as_str()) {
+                apply_or_emit(
+                    ctx,
+                    node,
+                    ActionType::Update,
+                    "Status (Public Key)",
+                    async {
+                        validator_key::update_public_key_status(client, node, &public_key).await?;
+                        Ok(())
+                    },
+                )
+                .await?;
+            }
+        }
+        Ok(None) => {}
+        Err(e) => {
+            warn!(
+                "Failed to derive the validator public key for {}/{}: {}",
+                namespace, name, e
+            );
+        }
+    }
+
     // 7. Perform health check to determine if node is ready
-    let health_result = health::check_node_health(client, node, ctx.mtls_config.as_ref()).await?;
+    let health_result = Box::pin(health::check_node_health(
+        client,
+        node,
+        &ctx.http_client,
+        ctx.mtls_config.is_some(),
+    ))
+    .await?;
 
     debug!(
         "Health check result for {}/{}: healthy={}, synced={}, message={}",
         namespace, name, health_result.healthy, health_result.synced, health_result.message
     );
 
+    // 7a. Alert when watched validators drop out of the quorum slice
+    if let Some(watcher) = quorum_slice::watcher(node).filter(|_| health_result.healthy) {
+        match Box::pin(quorum_slice::fetch_quorum(
+            &ctx.http_client,
+            node,
+            ctx.mtls_config.is_some(),
+        ))
+        .await
+        {
+            Ok(qset) if !ctx.dry_run => {
+                if quorum_slice::update_quorum_slice_status(client, node, watcher, &qset).await? {
+                    let unhealthy = quorum_slice::unhealthy_validators(watcher, &qset);
+                    emit_event(
+                        client,
+                        node,
+                        "Warning",
+                        "QuorumSliceAlert",
+                        &format!(
+                            "Quorum validators missing or disagreeing for more than {}s: {}",
+                            watcher.check_interval_seconds,
+                            unhealthy.join(", ")
+                        ),
+                    )
+                    .await?;
+                }
+            }
+            Ok(_) => {}
+            Err(e) => {
+                warn!("Failed to read the quorum of {}/{}: {}", namespace, name, e);
+            }
+        }
+    }
+
     // 7b. CVE scanning and automated patching
     if let Some(cve_config) = &node.spec.cve_handling {
         apply_or_emit(ctx, node, ActionType::Update, "CVE Handling", async {
-            cve_reconciler::reconcile_cve_patches(client, node, cve_config).await?;
+            Box::pin(cve_reconciler::reconcile_cve_patches(
+                client, node, cve_config,
+            ))
+            .await?;
             Ok(())
         })
         .await?;
@@ -755,7 +1872,7 @@ async fn apply_stellar_node(
     }
 
     // 8. Disaster Recovery reconciliation
-    if let Some(dr_status) = dr::reconcile_dr(client, node).await? {
+    if let Some(dr_status) = Box::pin(dr::reconcile_dr(client, node)).await? {
         apply_or_emit(ctx, node, ActionType::Update, "Status (DR)", async {
             update_dr_status(client, node, dr_status).await?;
             Ok(())
@@ -794,7 +1911,7 @@ async fn apply_stellar_node(
                     },
                 )
                 .await?;
-                return Ok(Action::requeue(Duration::from_secs(30)));
+                return Ok(requeue(Duration::from_secs(30)));
             }
         } else {
             apply_or_emit(ctx, node, ActionType::Update, "Remediation State", async {
@@ -813,25 +1930,45 @@ async fn apply_stellar_node(
     }
 
     // 10. Final Status Update
+    // The node is not reported as Ready or Running until enough replicas are ready
+    let ready_replicas = get_ready_replicas(client, node).await.unwrap_or(0);
+    let min_ready_replicas = node.spec.min_ready_replicas();
+    let awaiting_replicas = !node.spec.suspended && ready_replicas < min_ready_replicas;
     let (phase, message) = if node.spec.suspended {
         ("Suspended", "Node is suspended".to_string())
     } else if !health_result.healthy {
         ("Creating", health_result.message.clone())
     } else if !health_result.synced {
         ("Syncing", health_result.message.clone())
+    } else if awaiting_replicas {
+        (
+            "Progressing",
+            format!("Waiting for ready replicas ({ready_replicas}/{min_ready_replicas})"),
+        )
     } else {
         ("Ready", "Node is healthy and synced".to_string())
     };
 
     apply_or_emit(ctx, node, ActionType::Update, "Status (Final)", async {
         update_status_with_health(client, node, phase, Some(&message), &health_result).await?;
-
-        let ready_replicas = get_ready_replicas(client, node).await.unwrap_or(0);
-        update_status(client, node, phase, Some(&message), ready_replicas, true).await?;
+        update_status(
+            client,
+            node,
+            phase,
+            Some(&message),
+            ready_replicas,
+            true,
+            ctx.max_conditions,
+        )
+        .await?;
         Ok(())
     })
     .await?;
 
+    if !ctx.dry_run {
+        hooks::run_hook(node, hooks::HookEvent::PostApply, Some(phase)).await;
+    }
+
     // 9. Update status with ready replica count
     let phase = if node.spec.suspended {
         "Suspended"
@@ -842,14 +1979,107 @@ async fn apply_stellar_node(
         .is_some()
     {
         "Canary"
+    } else if awaiting_replicas {
+        "Progressing"
     } else {
         "Running"
     };
 
     // 10. Update ledger sequence metric if available
+    record_ledger_metrics(node).await;
+
+    // Poll an in-flight PVC expansion more frequently than the steady-state interval
+    if let Some(poll) = resize_poll {
+        return Ok(Action::requeue(poll));
+    }
+
+    // 10. Update status to Running with ready replica count; poll faster while
+    // pods are still becoming ready
+    Ok(requeue(phase_requeue_interval(phase)))
+}
+
+/// Base requeue interval after a pass ending in `phase`
+///
+/// Healthy nodes are checked every minute, nodes still waiting for replicas
+/// every 5s, and canary or suspended nodes every 15s.
+fn phase_requeue_interval(phase: &str) -> Duration {
+    Duration::from_secs(match phase {
+        "Running" => 60,
+        "Progressing" => 5,
+        _ => 15,
+    })
+}
+
+/// Track status and metrics for a StellarNode in an observe-only namespace
+///
+/// Nothing is created, updated or deleted; only the StellarNode status is written.
+#[instrument(skip(client, node, ctx), fields(
+    name = %node.name_any(),
+    namespace = node.namespace(),
+    generation = node.metadata.generation,
+    observed_generation = reconcile_trigger::observed_generation(node),
+))]
+async fn observe_stellar_node(
+    client: &Client,
+    node: &StellarNode,
+    ctx: &ControllerState,
+) -> Result<Action> {
+    let namespace = node.namespace().unwrap_or_else(|| "default".to_string());
+    let name = node.name_any();
+    debug!(
+        "Observing StellarNode {}/{} (observe-only)",
+        namespace, name
+    );
+
+    let health_result =
+        health::check_node_health(client, node, &ctx.http_client, ctx.mtls_config.is_some())
+            .await?;
+    let (phase, message) = if node.spec.suspended {
+        ("Suspended", "Node is suspended".to_string())
+    } else if !health_result.healthy {
+        ("Creating", health_result.message.clone())
+    } else if !health_result.synced {
+        ("Syncing", health_result.message.clone())
+    } else {
+        ("Ready", "Node is healthy and synced".to_string())
+    };
+    let message = format!("Observe-only: {message}");
+
+    apply_or_emit(ctx, node, ActionType::Update, "Status (Observed)", async {
+        update_status_with_health(client, node, phase, Some(&message), &health_result).await?;
+
+        let ready_replicas = get_ready_replicas(client, node).await.unwrap_or(0);
+        update_status(
+            client,
+            node,
+            phase,
+            Some(&message),
+            ready_replicas,
+            true,
+            ctx.max_conditions,
+        )
+        .await?;
+        Ok(())
+    })
+    .await?;
+
+    record_ledger_metrics(node).await;
+
+    Ok(Action::requeue(jitter::requeue_interval(
+        Duration::from_secs(60),
+        &format!("{namespace}/{name}"),
+        node.spec.requeue_jitter_percent,
+        ctx.requeue_jitter_percent,
+    )))
+}
+
+/// Export ledger sequence and ingestion lag metrics from the last observed status
+async fn record_ledger_metrics(node: &StellarNode) {
     if let Some(ref status) = node.status {
         #[cfg(feature = "metrics")]
         if let Some(seq) = status.ledger_sequence {
+            let namespace = node.namespace().unwrap_or_else(|| "default".to_string());
+            let name = node.name_any();
             metrics::set_ledger_sequence(
                 &namespace,
                 &name,
@@ -873,17 +2103,15 @@ async fn apply_stellar_node(
             }
         }
     }
-
-    // 10. Update status to Running with ready replica count
-    Ok(Action::requeue(Duration::from_secs(if phase == "Ready" {
-        60
-    } else {
-        15
-    })))
 }
 
 /// Clean up resources when the StellarNode is deleted
-#[instrument(skip(client, node, ctx), fields(name = %node.name_any(), namespace = node.namespace()))]
+#[instrument(skip(client, node, ctx), fields(
+    name = %node.name_any(),
+    namespace = node.namespace(),
+    generation = node.metadata.generation,
+    observed_generation = reconcile_trigger::observed_generation(node),
+))]
 async fn cleanup_stellar_node(
     client: &Client,
     node: &StellarNode,
@@ -894,6 +2122,31 @@ async fn cleanup_stellar_node(
 
     info!("Cleaning up StellarNode: {}/{}", namespace, name);
 
+    // When the whole namespace is being deleted, its garbage collector removes
+    // everything we own there, and any write into it would be rejected and
+    // hold the finalizer forever. Only clean up what lives elsewhere.
+    let namespace_terminating = finalizers::namespace_terminating(client, &namespace)
+        .await
+        .unwrap_or_else(|e| {
+            warn!("Failed to check namespace {}: {:?}", namespace, e);
+            false
+        });
+    if namespace_terminating {
+        info!(
+            "Namespace {} is terminating; skipping in-namespace cleanup for {}",
+            namespace, name
+        );
+        apply_or_emit(ctx, node, ActionType::Delete, "Grafana dashboard", async {
+            if let Err(e) = resources::delete_grafana_dashboard(client, node).await {
+                warn!("Failed to delete Grafana dashboard: {:?}", e);
+            }
+            Ok(())
+        })
+        .await?;
+        info!("Cleanup complete for StellarNode: {}/{}", namespace, name);
+        return Ok(Action::await_change());
+    }
+
     // Delete resources in reverse order of creation
 
     // 0a. Delete Managed Database Resources
@@ -914,6 +2167,24 @@ async fn cleanup_stellar_node(
     })
     .await?;
 
+    // 0b. Delete PrometheusRule
+    apply_or_emit(ctx, node, ActionType::Delete, "PrometheusRule", async {
+        if let Err(e) = resources::delete_prometheus_rule(client, node).await {
+            warn!("Failed to delete PrometheusRule: {:?}", e);
+        }
+        Ok(())
+    })
+    .await?;
+
+    // 0c. Delete Grafana dashboards (they may live outside the node's namespace)
+    apply_or_emit(ctx, node, ActionType::Delete, "Grafana dashboard", async {
+        if let Err(e) = resources::delete_grafana_dashboard(client, node).await {
+            warn!("Failed to delete Grafana dashboard: {:?}", e);
+        }
+        Ok(())
+    })
+    .await?;
+
     // 1. Delete HPA (if autoscaling was configured)
     apply_or_emit(ctx, node, ActionType::Delete, "HPA", async {
         if let Err(e) = resources::delete_hpa(client, node).await {
@@ -923,7 +2194,7 @@ async fn cleanup_stellar_node(
     })
     .await?;
 
-    // 2. Delete ServiceMonitor (if autoscaling was configured)
+    // 2. Delete ServiceMonitor
     apply_or_emit(ctx, node, ActionType::Delete, "ServiceMonitor", async {
         if let Err(e) = resources::delete_service_monitor(client, node).await {
             warn!("Failed to delete ServiceMonitor: {:?}", e);
@@ -1002,6 +2273,22 @@ async fn cleanup_stellar_node(
         Ok(())
     })
     .await?;
+
+    // 3d. Delete stellar.toml server
+    apply_or_emit(
+        ctx,
+        node,
+        ActionType::Delete,
+        "stellar.toml server",
+        async {
+            if let Err(e) = resources::delete_stellar_toml(client, node).await {
+                warn!("Failed to delete stellar.toml server: {:?}", e);
+            }
+            Ok(())
+        },
+    )
+    .await?;
+
     // 4. Delete Service
     apply_or_emit(ctx, node, ActionType::Delete, "Service", async {
         if let Err(e) = resources::delete_service(client, node).await {
@@ -1145,86 +2432,161 @@ async fn get_current_deployment_version(
     }
 }
 
-/// Check health of canary pods
-#[allow(dead_code)]
-async fn check_canary_health(
+/// Check health of canary pods
+#[allow(dead_code)]
+async fn check_canary_health(
+    client: &Client,
+    node: &StellarNode,
+    http_client: &reqwest::Client,
+) -> Result<health::HealthCheckResult> {
+    let _namespace = node.namespace().unwrap_or_else(|| "default".to_string());
+    let name = format!("{}-canary", node.name_any());
+
+    // Create a temporary node with the canary name to use the existing health check logic
+    let mut canary_node = node.clone();
+    canary_node.metadata.name = Some(name);
+
+    health::check_node_health(client, &canary_node, http_client, false).await
+}
+
+/// Set or clear the `Paused` condition for the fleet-wide pause
+///
+/// Only patches the status when the condition actually needs to change.
+async fn update_paused_condition(client: &Client, node: &StellarNode, paused: bool) -> Result<()> {
+    let current = node
+        .status
+        .as_ref()
+        .and_then(|s| conditions::find_condition(&s.conditions, conditions::CONDITION_TYPE_PAUSED));
+    let unchanged = match current {
+        Some(condition) => paused && condition.status == conditions::CONDITION_STATUS_TRUE,
+        None => !paused,
+    };
+    if unchanged {
+        return Ok(());
+    }
+
+    conditions::update_conditions(client, node, serde_json::json!({}), |conditions| {
+        if paused {
+            conditions::set_condition(
+                conditions,
+                conditions::CONDITION_TYPE_PAUSED,
+                conditions::CONDITION_STATUS_TRUE,
+                "FleetPaused",
+                &format!(
+                    "Reconciliation paused operator-wide via ConfigMap {}",
+                    operator_control::OPERATOR_CONTROL_CONFIGMAP
+                ),
+            );
+        } else {
+            conditions::remove_condition(conditions, conditions::CONDITION_TYPE_PAUSED);
+        }
+    })
+    .await
+}
+
+/// Set or clear the `DebugRPCEnabled` warning condition
+///
+/// Returns whether the condition was just set, judged by the live status.
+async fn update_debug_rpc_condition(
+    client: &Client,
+    node: &StellarNode,
+    enabled: bool,
+) -> Result<bool> {
+    conditions::update_conditions(client, node, serde_json::json!({}), |conditions| {
+        let was_flagged =
+            conditions::is_condition_true(conditions, conditions::CONDITION_TYPE_DEBUG_RPC_ENABLED);
+        if enabled {
+            conditions::set_condition(
+                conditions,
+                conditions::CONDITION_TYPE_DEBUG_RPC_ENABLED,
+                conditions::CONDITION_STATUS_TRUE,
+                "DebugLoggingEnabled",
+                "sorobanConfig.debugRpc logs full JSON-RPC payloads, including users' transactions; disable it once debugging is done",
+            );
+        } else {
+            conditions::remove_condition(conditions, conditions::CONDITION_TYPE_DEBUG_RPC_ENABLED);
+        }
+        enabled && !was_flagged
+    })
+    .await
+}
+
+/// Record the migration result in status
+///
+/// `migrated_version` updates `lastMigratedVersion` and clears `MigrationFailed`;
+/// `failure` sets the `MigrationFailed` condition with the given message.
+async fn update_migration_status(
     client: &Client,
     node: &StellarNode,
-) -> Result<health::HealthCheckResult> {
-    let _namespace = node.namespace().unwrap_or_else(|| "default".to_string());
-    let name = format!("{}-canary", node.name_any());
-
-    // Create a temporary node with the canary name to use the existing health check logic
-    let mut canary_node = node.clone();
-    canary_node.metadata.name = Some(name);
+    migrated_version: Option<&str>,
+    failure: Option<&str>,
+) -> Result<()> {
+    let mut status = serde_json::json!({});
+    if let Some(version) = migrated_version {
+        status["lastMigratedVersion"] = serde_json::json!(version);
+    }
 
-    health::check_node_health(client, &canary_node, None).await
+    conditions::update_conditions(client, node, status, |conditions| match failure {
+        Some(message) => conditions::set_condition(
+            conditions,
+            conditions::CONDITION_TYPE_MIGRATION_FAILED,
+            conditions::CONDITION_STATUS_TRUE,
+            "MigrationJobFailed",
+            message,
+        ),
+        None => {
+            conditions::remove_condition(conditions, conditions::CONDITION_TYPE_MIGRATION_FAILED)
+        }
+    })
+    .await
 }
 
 /// Update status for suspended nodes
-#[allow(deprecated)]
 async fn update_suspended_status(client: &Client, node: &StellarNode) -> Result<()> {
-    let namespace = node.namespace().unwrap_or_else(|| "default".to_string());
-    let api: Api<StellarNode> = Api::namespaced(client.clone(), &namespace);
+    let status_patch = serde_json::json!({
+        "message": "Node suspended - scaled to 0 replicas",
+        "observedGeneration": node.metadata.generation,
+        "replicas": 0,
+        "readyReplicas": 0,
+    });
 
-    let mut conditions = node
-        .status
-        .as_ref()
-        .map(|s| s.conditions.clone())
-        .unwrap_or_default();
-
-    // Set conditions for suspended state
-    conditions::set_condition(
-        &mut conditions,
-        conditions::CONDITION_TYPE_READY,
-        conditions::CONDITION_STATUS_FALSE,
-        "NodeSuspended",
-        "Node is offline - replicas scaled to 0. Service remains active for peer discovery.",
-    );
-    conditions::remove_condition(&mut conditions, conditions::CONDITION_TYPE_PROGRESSING);
-    conditions::remove_condition(&mut conditions, conditions::CONDITION_TYPE_DEGRADED);
+    conditions::update_conditions(client, node, status_patch, |conditions| {
+        // Set conditions for suspended state
+        conditions::set_condition(
+            conditions,
+            conditions::CONDITION_TYPE_READY,
+            conditions::CONDITION_STATUS_FALSE,
+            "NodeSuspended",
+            "Node is offline - replicas scaled to 0. Service remains active for peer discovery.",
+        );
+        conditions::remove_condition(conditions, conditions::CONDITION_TYPE_PROGRESSING);
+        conditions::remove_condition(conditions, conditions::CONDITION_TYPE_DEGRADED);
 
-    // Set observed generation on conditions
-    if let Some(gen) = node.metadata.generation {
-        for condition in &mut conditions {
-            condition.observed_generation = Some(gen);
+        // Set observed generation on conditions
+        if let Some(gen) = node.metadata.generation {
+            for condition in conditions.iter_mut() {
+                condition.observed_generation = Some(gen);
+            }
         }
-    }
-
-    let status = StellarNodeStatus {
-        message: Some("Node suspended - scaled to 0 replicas".to_string()),
-        observed_generation: node.metadata.generation,
-        replicas: 0,
-        ready_replicas: 0,
-        ledger_sequence: None,
-        conditions,
-        ..Default::default()
-    };
-
-    let patch = serde_json::json!({ "status": status });
-    api.patch_status(
-        &node.name_any(),
-        &PatchParams::apply("stellar-operator"),
-        &Patch::Merge(&patch),
-    )
+    })
     .await
-    .map_err(Error::KubeError)?;
-
-    Ok(())
 }
 
 /// Update the status subresource of a StellarNode using Kubernetes conditions pattern
+///
+/// Conditions are merged into the live status, so those recorded earlier in
+/// the pass (PVC resize, storage pressure, ...) survive this final write.
 #[allow(deprecated)]
-async fn update_status(
+pub(super) async fn update_status(
     client: &Client,
     node: &StellarNode,
     phase: &str,
     message: Option<&str>,
     ready_replicas: i32,
     update_obs_gen: bool,
+    max_conditions: usize,
 ) -> Result<()> {
     let namespace = node.namespace().unwrap_or_else(|| "default".to_string());
-    let api: Api<StellarNode> = Api::namespaced(client.clone(), &namespace);
 
     let observed_generation = if update_obs_gen {
         node.metadata.generation
@@ -1234,32 +2596,59 @@ async fn update_status(
             .and_then(|status| status.observed_generation)
     };
 
-    // Build conditions based on phase
-    let mut conditions = node
-        .status
-        .as_ref()
-        .map(|s| s.conditions.clone())
-        .unwrap_or_default();
+    let mut status_patch = serde_json::json!({
+        "phase": phase,
+        "observedGeneration": observed_generation,
+        "replicas": if node.spec.suspended || autoscaling::weekend_suspended(node) {
+            0
+        } else {
+            node.spec.replicas
+        },
+        "readyReplicas": ready_replicas,
+    });
+
+    if let Some(msg) = message {
+        status_patch["message"] = serde_json::Value::String(msg.to_string());
+    }
+    if let Some(endpoint) = node.spec.advertised_endpoint(&node.name_any(), &namespace) {
+        status_patch["endpoint"] = serde_json::Value::String(endpoint);
+    }
 
-    // Map phase to conditions
+    conditions::update_conditions(client, node, status_patch, |conditions| {
+        set_phase_conditions(conditions, phase, message);
+
+        // Set observed generation on all conditions
+        if let Some(gen) = observed_generation {
+            for condition in conditions.iter_mut() {
+                condition.observed_generation = Some(gen);
+            }
+        }
+
+        conditions::cap_conditions(conditions, max_conditions);
+    })
+    .await
+}
+
+/// Map a phase to the Ready, Progressing and Degraded conditions
+fn set_phase_conditions(conditions: &mut Vec<Condition>, phase: &str, message: Option<&str>) {
     match phase {
         "Ready" => {
             conditions::set_condition(
-                &mut conditions,
+                conditions,
                 conditions::CONDITION_TYPE_READY,
                 conditions::CONDITION_STATUS_TRUE,
                 "AllSubresourcesHealthy",
                 message.unwrap_or("All sub-resources are healthy and operational"),
             );
             conditions::set_condition(
-                &mut conditions,
+                conditions,
                 conditions::CONDITION_TYPE_PROGRESSING,
                 conditions::CONDITION_STATUS_FALSE,
                 "ReconcileComplete",
                 "Reconciliation completed successfully",
             );
             conditions::set_condition(
-                &mut conditions,
+                conditions,
                 conditions::CONDITION_TYPE_DEGRADED,
                 conditions::CONDITION_STATUS_FALSE,
                 "NoIssues",
@@ -1268,106 +2657,123 @@ async fn update_status(
         }
         "Creating" | "Pending" => {
             conditions::set_condition(
-                &mut conditions,
+                conditions,
                 conditions::CONDITION_TYPE_READY,
                 conditions::CONDITION_STATUS_FALSE,
                 "Creating",
                 message.unwrap_or("Resources are being created"),
             );
             conditions::set_condition(
-                &mut conditions,
+                conditions,
                 conditions::CONDITION_TYPE_PROGRESSING,
                 conditions::CONDITION_STATUS_TRUE,
                 "Creating",
                 message.unwrap_or("Creating resources"),
             );
-            conditions::remove_condition(&mut conditions, conditions::CONDITION_TYPE_DEGRADED);
+            conditions::remove_condition(conditions, conditions::CONDITION_TYPE_DEGRADED);
         }
         "Syncing" => {
             conditions::set_condition(
-                &mut conditions,
+                conditions,
                 conditions::CONDITION_TYPE_READY,
                 conditions::CONDITION_STATUS_FALSE,
                 "Syncing",
                 message.unwrap_or("Node is syncing with the network"),
             );
             conditions::set_condition(
-                &mut conditions,
+                conditions,
                 conditions::CONDITION_TYPE_PROGRESSING,
                 conditions::CONDITION_STATUS_TRUE,
                 "Syncing",
                 message.unwrap_or("Syncing data"),
             );
-            conditions::remove_condition(&mut conditions, conditions::CONDITION_TYPE_DEGRADED);
+            conditions::remove_condition(conditions, conditions::CONDITION_TYPE_DEGRADED);
+        }
+        "Progressing" => {
+            conditions::set_condition(
+                conditions,
+                conditions::CONDITION_TYPE_READY,
+                conditions::CONDITION_STATUS_FALSE,
+                "WaitingForReadyReplicas",
+                message.unwrap_or("Waiting for ready replicas"),
+            );
+            conditions::set_condition(
+                conditions,
+                conditions::CONDITION_TYPE_PROGRESSING,
+                conditions::CONDITION_STATUS_TRUE,
+                "WaitingForReadyReplicas",
+                message.unwrap_or("Waiting for ready replicas"),
+            );
+            conditions::remove_condition(conditions, conditions::CONDITION_TYPE_DEGRADED);
         }
         "Running" => {
             conditions::set_condition(
-                &mut conditions,
+                conditions,
                 conditions::CONDITION_TYPE_READY,
                 conditions::CONDITION_STATUS_TRUE,
                 "ResourcesCreated",
                 message.unwrap_or("Resources created successfully"),
             );
             conditions::set_condition(
-                &mut conditions,
+                conditions,
                 conditions::CONDITION_TYPE_PROGRESSING,
                 conditions::CONDITION_STATUS_FALSE,
                 "Complete",
                 "Resource creation complete",
             );
-            conditions::remove_condition(&mut conditions, conditions::CONDITION_TYPE_DEGRADED);
+            conditions::remove_condition(conditions, conditions::CONDITION_TYPE_DEGRADED);
         }
         "Degraded" => {
             conditions::set_condition(
-                &mut conditions,
+                conditions,
                 conditions::CONDITION_TYPE_READY,
                 conditions::CONDITION_STATUS_FALSE,
                 "Degraded",
                 message.unwrap_or("Node is experiencing issues"),
             );
             conditions::set_condition(
-                &mut conditions,
+                conditions,
                 conditions::CONDITION_TYPE_DEGRADED,
                 conditions::CONDITION_STATUS_TRUE,
                 "IssuesDetected",
                 message.unwrap_or("Node is degraded"),
             );
-            conditions::remove_condition(&mut conditions, conditions::CONDITION_TYPE_PROGRESSING);
+            conditions::remove_condition(conditions, conditions::CONDITION_TYPE_PROGRESSING);
         }
         "Failed" => {
             conditions::set_condition(
-                &mut conditions,
+                conditions,
                 conditions::CONDITION_TYPE_READY,
                 conditions::CONDITION_STATUS_FALSE,
                 "Failed",
                 message.unwrap_or("Node operation failed"),
             );
             conditions::set_condition(
-                &mut conditions,
+                conditions,
                 conditions::CONDITION_TYPE_DEGRADED,
                 conditions::CONDITION_STATUS_TRUE,
                 "Failed",
                 message.unwrap_or("Operation failed"),
             );
-            conditions::remove_condition(&mut conditions, conditions::CONDITION_TYPE_PROGRESSING);
+            conditions::remove_condition(conditions, conditions::CONDITION_TYPE_PROGRESSING);
         }
         "Remediating" => {
             conditions::set_condition(
-                &mut conditions,
+                conditions,
                 conditions::CONDITION_TYPE_READY,
                 conditions::CONDITION_STATUS_FALSE,
                 "Remediating",
                 message.unwrap_or("Auto-remediation in progress"),
             );
             conditions::set_condition(
-                &mut conditions,
+                conditions,
                 conditions::CONDITION_TYPE_PROGRESSING,
                 conditions::CONDITION_STATUS_TRUE,
                 "Remediating",
                 message.unwrap_or("Remediation in progress"),
             );
             conditions::set_condition(
-                &mut conditions,
+                conditions,
                 conditions::CONDITION_TYPE_DEGRADED,
                 conditions::CONDITION_STATUS_TRUE,
                 "Remediating",
@@ -1376,29 +2782,40 @@ async fn update_status(
         }
         "Suspended" => {
             conditions::set_condition(
-                &mut conditions,
+                conditions,
                 conditions::CONDITION_TYPE_READY,
                 conditions::CONDITION_STATUS_FALSE,
                 "Suspended",
                 message.unwrap_or("Node is suspended"),
             );
-            conditions::remove_condition(&mut conditions, conditions::CONDITION_TYPE_PROGRESSING);
-            conditions::remove_condition(&mut conditions, conditions::CONDITION_TYPE_DEGRADED);
+            conditions::remove_condition(conditions, conditions::CONDITION_TYPE_PROGRESSING);
+            conditions::remove_condition(conditions, conditions::CONDITION_TYPE_DEGRADED);
+        }
+        "WeekendSuspended" => {
+            conditions::set_condition(
+                conditions,
+                conditions::CONDITION_TYPE_READY,
+                conditions::CONDITION_STATUS_FALSE,
+                "WeekendSuspension",
+                message.unwrap_or("Node is suspended for the weekend"),
+            );
+            conditions::remove_condition(conditions, conditions::CONDITION_TYPE_PROGRESSING);
+            conditions::remove_condition(conditions, conditions::CONDITION_TYPE_DEGRADED);
         }
         "Maintenance" => {
             conditions::set_condition(
-                &mut conditions,
+                conditions,
                 conditions::CONDITION_TYPE_READY,
                 conditions::CONDITION_STATUS_FALSE,
                 "Maintenance",
                 message.unwrap_or("Node is in maintenance mode"),
             );
-            conditions::remove_condition(&mut conditions, conditions::CONDITION_TYPE_PROGRESSING);
-            conditions::remove_condition(&mut conditions, conditions::CONDITION_TYPE_DEGRADED);
+            conditions::remove_condition(conditions, conditions::CONDITION_TYPE_PROGRESSING);
+            conditions::remove_condition(conditions, conditions::CONDITION_TYPE_DEGRADED);
         }
         _ => {
             conditions::set_condition(
-                &mut conditions,
+                conditions,
                 conditions::CONDITION_TYPE_READY,
                 conditions::CONDITION_STATUS_UNKNOWN,
                 "Unknown",
@@ -1406,36 +2823,6 @@ async fn update_status(
             );
         }
     }
-
-    // Set observed generation on all conditions
-    if let Some(gen) = observed_generation {
-        for condition in &mut conditions {
-            condition.observed_generation = Some(gen);
-        }
-    }
-
-    let mut status_patch = serde_json::json!({
-        "phase": phase,
-        "observedGeneration": observed_generation,
-        "replicas": if node.spec.suspended { 0 } else { node.spec.replicas },
-        "readyReplicas": ready_replicas,
-        "conditions": conditions,
-    });
-
-    if let Some(msg) = message {
-        status_patch["message"] = serde_json::Value::String(msg.to_string());
-    }
-
-    let patch = serde_json::json!({ "status": status_patch });
-    api.patch_status(
-        &node.name_any(),
-        &PatchParams::apply("stellar-operator"),
-        &Patch::Merge(&patch),
-    )
-    .await
-    .map_err(Error::KubeError)?;
-
-    Ok(())
 }
 
 /// Update the status with archive health check results
@@ -1444,15 +2831,6 @@ async fn update_archive_health_status(
     node: &StellarNode,
     result: &ArchiveHealthResult,
 ) -> Result<()> {
-    let namespace = node.namespace().unwrap_or_else(|| "default".to_string());
-    let api: Api<StellarNode> = Api::namespaced(client.clone(), &namespace);
-
-    let mut conditions = node
-        .status
-        .as_ref()
-        .map(|s| s.conditions.clone())
-        .unwrap_or_default();
-
     // Update ArchiveHealthCheck condition
     let archive_message = if result.any_healthy {
         result.summary()
@@ -1460,31 +2838,7 @@ async fn update_archive_health_status(
         format!("{}\n{}", result.summary(), result.error_details())
     };
 
-    conditions::set_condition(
-        &mut conditions,
-        "ArchiveHealthCheck",
-        if result.any_healthy {
-            conditions::CONDITION_STATUS_TRUE
-        } else {
-            conditions::CONDITION_STATUS_FALSE
-        },
-        if result.any_healthy {
-            "ArchiveHealthy"
-        } else {
-            "ArchiveUnreachable"
-        },
-        &archive_message,
-    );
-
-    // Set observed generation on conditions
-    if let Some(gen) = node.metadata.generation {
-        for condition in &mut conditions {
-            condition.observed_generation = Some(gen);
-        }
-    }
-
     let mut status_patch = serde_json::json!({
-        "conditions": conditions,
         "phase": if result.any_healthy { "Creating" } else { "WaitingForArchive" },
     });
 
@@ -1493,20 +2847,37 @@ async fn update_archive_health_status(
         status_patch["observedGeneration"] = serde_json::json!(node.metadata.generation);
     }
 
-    let patch = serde_json::json!({ "status": status_patch });
-    api.patch_status(
-        &node.name_any(),
-        &PatchParams::apply("stellar-operator"),
-        &Patch::Merge(&patch),
-    )
-    .await
-    .map_err(Error::KubeError)?;
+    conditions::update_conditions(client, node, status_patch, |conditions| {
+        conditions::set_condition(
+            conditions,
+            "ArchiveHealthCheck",
+            if result.any_healthy {
+                conditions::CONDITION_STATUS_TRUE
+            } else {
+                conditions::CONDITION_STATUS_FALSE
+            },
+            if result.any_healthy {
+                "ArchiveHealthy"
+            } else {
+                "ArchiveUnreachable"
+            },
+            &archive_message,
+        );
 
-    Ok(())
+        // Set observed generation on conditions
+        if let Some(gen) = node.metadata.generation {
+            for condition in conditions.iter_mut() {
+                condition.observed_generation = Some(gen);
+            }
+        }
+    })
+    .await
 }
 
 /// Update the status subresource with health check results
-#[allow(deprecated)]
+///
+/// Only the fields derived from the health check are patched; conditions are
+/// merged into the live status.
 async fn update_status_with_health(
     client: &Client,
     node: &StellarNode,
@@ -1514,109 +2885,85 @@ async fn update_status_with_health(
     message: Option<&str>,
     health: &health::HealthCheckResult,
 ) -> Result<()> {
-    let namespace = node.namespace().unwrap_or_else(|| "default".to_string());
-    let api: Api<StellarNode> = Api::namespaced(client.clone(), &namespace);
-
-    // Build conditions based on health check
-    let mut conditions = node
-        .status
-        .as_ref()
-        .map(|s| s.conditions.clone())
-        .unwrap_or_default();
-
-    // Ready condition based on health status
-    if health.synced {
-        conditions::set_condition(
-            &mut conditions,
-            conditions::CONDITION_TYPE_READY,
-            conditions::CONDITION_STATUS_TRUE,
-            "NodeSynced",
-            "Node is fully synced and operational",
-        );
-        conditions::set_condition(
-            &mut conditions,
-            conditions::CONDITION_TYPE_PROGRESSING,
-            conditions::CONDITION_STATUS_FALSE,
-            "SyncComplete",
-            "Node sync completed",
-        );
-        conditions::remove_condition(&mut conditions, conditions::CONDITION_TYPE_DEGRADED);
-    } else if health.healthy {
-        conditions::set_condition(
-            &mut conditions,
-            conditions::CONDITION_TYPE_READY,
-            conditions::CONDITION_STATUS_FALSE,
-            "NodeSyncing",
-            &health.message,
-        );
-        conditions::set_condition(
-            &mut conditions,
-            conditions::CONDITION_TYPE_PROGRESSING,
-            conditions::CONDITION_STATUS_TRUE,
-            "Syncing",
-            &health.message,
-        );
-        conditions::remove_condition(&mut conditions, conditions::CONDITION_TYPE_DEGRADED);
-    } else {
-        conditions::set_condition(
-            &mut conditions,
-            conditions::CONDITION_TYPE_READY,
-            conditions::CONDITION_STATUS_FALSE,
-            "NodeNotHealthy",
-            &health.message,
-        );
-        conditions::set_condition(
-            &mut conditions,
-            conditions::CONDITION_TYPE_DEGRADED,
-            conditions::CONDITION_STATUS_TRUE,
-            "HealthCheckFailed",
-            &health.message,
-        );
-        conditions::remove_condition(&mut conditions, conditions::CONDITION_TYPE_PROGRESSING);
-    }
-
-    // Set observed generation on all conditions
-    if let Some(gen) = node.metadata.generation {
-        for condition in &mut conditions {
-            condition.observed_generation = Some(gen);
-        }
-    }
-
-    let status = StellarNodeStatus {
-        message: message.map(String::from),
-        observed_generation: node.metadata.generation,
-        replicas: if node.spec.suspended {
-            0
-        } else {
-            node.spec.replicas
-        },
-        ready_replicas: if health.synced && !node.spec.suspended {
+    let mut status_patch = serde_json::json!({
+        "observedGeneration": node.metadata.generation,
+        "replicas": if node.spec.suspended { 0 } else { node.spec.replicas },
+        "readyReplicas": if health.synced && !node.spec.suspended {
             node.spec.replicas
         } else {
             0
         },
-        ledger_sequence: health.ledger_sequence,
-        last_migrated_version: if health.synced && node.spec.node_type == NodeType::Horizon {
-            Some(node.spec.version.clone())
+    });
+    if let Some(msg) = message {
+        status_patch["message"] = serde_json::json!(msg);
+    }
+    if let Some(ledger) = health.ledger_sequence {
+        status_patch["ledgerSequence"] = serde_json::json!(ledger);
+    }
+    if health.synced && node.spec.node_type == NodeType::Horizon {
+        status_patch["lastMigratedVersion"] = serde_json::json!(node.spec.version);
+    }
+
+    conditions::update_conditions(client, node, status_patch, |conditions| {
+        // Ready condition based on health status
+        if health.synced {
+            conditions::set_condition(
+                conditions,
+                conditions::CONDITION_TYPE_READY,
+                conditions::CONDITION_STATUS_TRUE,
+                "NodeSynced",
+                "Node is fully synced and operational",
+            );
+            conditions::set_condition(
+                conditions,
+                conditions::CONDITION_TYPE_PROGRESSING,
+                conditions::CONDITION_STATUS_FALSE,
+                "SyncComplete",
+                "Node sync completed",
+            );
+            conditions::remove_condition(conditions, conditions::CONDITION_TYPE_DEGRADED);
+        } else if health.healthy {
+            conditions::set_condition(
+                conditions,
+                conditions::CONDITION_TYPE_READY,
+                conditions::CONDITION_STATUS_FALSE,
+                "NodeSyncing",
+                &health.message,
+            );
+            conditions::set_condition(
+                conditions,
+                conditions::CONDITION_TYPE_PROGRESSING,
+                conditions::CONDITION_STATUS_TRUE,
+                "Syncing",
+                &health.message,
+            );
+            conditions::remove_condition(conditions, conditions::CONDITION_TYPE_DEGRADED);
         } else {
-            node.status
-                .as_ref()
-                .and_then(|s| s.last_migrated_version.clone())
-        },
-        conditions,
-        ..Default::default()
-    };
+            conditions::set_condition(
+                conditions,
+                conditions::CONDITION_TYPE_READY,
+                conditions::CONDITION_STATUS_FALSE,
+                "NodeNotHealthy",
+                &health.message,
+            );
+            conditions::set_condition(
+                conditions,
+                conditions::CONDITION_TYPE_DEGRADED,
+                conditions::CONDITION_STATUS_TRUE,
+                "HealthCheckFailed",
+                &health.message,
+            );
+            conditions::remove_condition(conditions, conditions::CONDITION_TYPE_PROGRESSING);
+        }
 
-    let patch = serde_json::json!({ "status": status });
-    api.patch_status(
-        &node.name_any(),
-        &PatchParams::apply("stellar-operator"),
-        &Patch::Merge(&patch),
-    )
+        // Set observed generation on all conditions
+        if let Some(gen) = node.metadata.generation {
+            for condition in conditions.iter_mut() {
+                condition.observed_generation = Some(gen);
+            }
+        }
+    })
     .await
-    .map_err(Error::KubeError)?;
-
-    Ok(())
 }
 
 /// Update the status subresource with canary information
@@ -1713,15 +3060,108 @@ async fn update_dr_status(
 }
 
 /// Error policy determines how to handle reconciliation errors
-fn error_policy(node: Arc<StellarNode>, error: &Error, _ctx: Arc<ControllerState>) -> Action {
+fn error_policy(node: Arc<StellarNode>, error: &Error, ctx: Arc<ControllerState>) -> Action {
     error!("Reconciliation error for {}: {:?}", node.name_any(), error);
+    ctx.failure_notifier.record_failure(&node, error);
+
+    // Counted in memory: writing the count to the status on every failure would
+    // trigger another reconcile right away and defeat the backoff
+    let key = format!(
+        "{}/{}",
+        node.namespace().unwrap_or_else(|| "default".to_string()),
+        node.name_any()
+    );
+    let failures = ctx.failure_notifier.consecutive_failures(&key);
+    let retry = failure_policy::retry_after(
+        node.spec.failure_policy.as_ref(),
+        failures,
+        error.is_retriable(),
+    );
 
-    // Use shorter retry for retriable errors
-    let retry_duration = if error.is_retriable() {
-        Duration::from_secs(15)
-    } else {
-        Duration::from_secs(60)
-    };
+    // The status is only written once, when alarmAndStop stops the node
+    if retry.is_none() && !ctx.dry_run && !failure_policy::reconcile_stopped(&node) {
+        let client = ctx.client.clone();
+        let message = error.to_string();
+        let key = key.clone();
+        tokio::spawn(async move {
+            if let Err(e) = failure_policy::record_stopped(&client, &node, failures, &message).await
+            {
+                warn!("Failed to record the reconcile stop of {}: {}", key, e);
+            }
+        });
+    }
+
+    match retry {
+        Some(retry_duration) => Action::requeue(retry_duration),
+        None => {
+            warn!(
+                "Stopped reconciling {} after {} consecutive failures",
+                key, failures
+            );
+            Action::await_change()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::controller::fake_api::FakeApiServer;
+    use serde_json::json;
 
-    Action::requeue(retry_duration)
+    fn state(client: Client) -> ControllerState {
+        ControllerState {
+            client,
+            enable_mtls: false,
+            operator_namespace: "stellar-operator".to_string(),
+            mtls_config: None,
+            dry_run: false,
+            requeue_jitter_percent: 0,
+            instance_id: "test".to_string(),
+            is_leader: AtomicBool::new(true),
+            http_client: reqwest::Client::new(),
+            capabilities: ClusterCapabilities::default(),
+            failure_notifier: FailureNotifier::disabled(),
+            reconcile_limiter: ReconcileLimiter::unlimited(),
+            image_verifier: None,
+            max_conditions: conditions::DEFAULT_MAX_CONDITIONS,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_node_without_uid_is_requeued_before_creating_resources() {
+        let server = FakeApiServer::start().await;
+        let client = server.client();
+        let spec = serde_json::from_value(json!({
+            "nodeType": "Validator",
+            "network": "Testnet",
+            "version": "v21.0.0",
+            "validatorConfig": { "seedSecretRef": "validator-seed" },
+        }))
+        .unwrap();
+        let mut node = StellarNode::new("validator", spec);
+        node.metadata.namespace = Some("default".to_string());
+        assert!(node.metadata.uid.is_none());
+
+        let action = apply_stellar_node(&client, &node, &state(client.clone()))
+            .await
+            .unwrap();
+
+        assert_eq!(action, Action::requeue(Duration::from_secs(5)));
+        assert!(server
+            .created()
+            .iter()
+            .all(|object| object["kind"] == "Event"));
+    }
+
+    #[test]
+    fn test_phase_requeue_interval() {
+        assert_eq!(phase_requeue_interval("Running"), Duration::from_secs(60));
+        assert_eq!(
+            phase_requeue_interval("Progressing"),
+            Duration::from_secs(5)
+        );
+        assert_eq!(phase_requeue_interval("Canary"), Duration::from_secs(15));
+        assert_eq!(phase_requeue_interval("Suspended"), Duration::from_secs(15));
+    }
 }
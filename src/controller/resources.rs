@@ -3,7 +3,15 @@
 //! This module creates and manages the underlying Kubernetes resources
 //! (Deployments, StatefulSets, Services, PVCs, ConfigMaps) for each StellarNode.
 
+use crate::controller::api_timeout::with_timeout;
+use crate::controller::autoscaling;
+use crate::controller::catchup_gate::{self, CATCHUP_ANNOTATION};
+use crate::controller::core_version;
+use crate::controller::crd_discovery;
+use crate::controller::protocol_version;
+use crate::controller::quorum_builder;
 use crate::controller::resource_meta::merge_resource_meta;
+use crate::controller::stellar_toml;
 
 use std::collections::BTreeMap;
 
@@ -13,10 +21,14 @@ use k8s_openapi::api::autoscaling::v2::{
     HorizontalPodAutoscalerBehavior, HorizontalPodAutoscalerSpec, MetricIdentifier, MetricSpec,
     MetricTarget, ObjectMetricSource,
 };
+use k8s_openapi::api::batch::v1::{CronJob, CronJobSpec, Job, JobSpec, JobTemplateSpec};
 use k8s_openapi::api::core::v1::{
-    ConfigMap, Container, ContainerPort, EnvVar, EnvVarSource, PersistentVolumeClaim,
-    PersistentVolumeClaimSpec, PodSpec, PodTemplateSpec, ResourceRequirements as K8sResources,
-    SecretKeySelector, Service, ServicePort, ServiceSpec, Volume, VolumeMount,
+    Affinity, Capabilities, ClaimSource, ClientIPConfig, ConfigMap, ConfigMapVolumeSource,
+    Container, ContainerPort, EnvVar, EnvVarSource, ExecAction, HTTPGetAction, KeyToPath,
+    Lifecycle, LifecycleHandler, PersistentVolumeClaim, PersistentVolumeClaimSpec, PodAffinityTerm,
+    PodAntiAffinity, PodResourceClaim, PodSpec, PodTemplateSpec, Probe, ResourceClaim,
+    ResourceRequirements as K8sResources, SecretKeySelector, SecretVolumeSource, SecurityContext,
+    Service, ServicePort, ServiceSpec, SessionAffinityConfig, Volume, VolumeMount,
     VolumeResourceRequirements,
 };
 use k8s_openapi::api::networking::v1::{
@@ -28,17 +40,24 @@ use k8s_openapi::api::policy::v1::{PodDisruptionBudget, PodDisruptionBudgetSpec}
 use k8s_openapi::apimachinery::pkg::api::resource::Quantity;
 use k8s_openapi::apimachinery::pkg::apis::meta::v1::{LabelSelector, ObjectMeta, OwnerReference};
 use k8s_openapi::apimachinery::pkg::util::intstr::IntOrString;
-use kube::api::{Api, DeleteParams, Patch, PatchParams, PostParams};
+use kube::api::{Api, DeleteParams, DynamicObject, ListParams, Patch, PatchParams, PostParams};
+use kube::discovery::ApiResource;
 use kube::{Client, Resource, ResourceExt};
-use tracing::{info, instrument, warn};
+use tracing::{debug, info, instrument, warn};
 
 use crate::crd::{
-    BackupConfiguration, BarmanObjectStore, BootstrapConfiguration, Cluster, ClusterSpec,
-    HistoryMode, HsmProvider, IngressConfig, InitDbConfiguration, KeySource, ManagedDatabaseConfig,
-    MonitoringConfiguration, NetworkPolicyConfig, NodeType, PgBouncerSpec, Pooler, PoolerCluster,
-    PoolerSpec, PostgresConfiguration, RolloutStrategy, S3Credentials,
-    SecretKeySelector as CnpgSecretKeySelector, StellarNode, StorageConfiguration,
-    WalBackupConfiguration,
+    ActiveScalingOverride, ArchivalBackend, BackupConfiguration, BarmanObjectStore,
+    BootstrapConfiguration, Cluster, ClusterSpec, ExternalTrafficPolicy, GcImplementation,
+    GrafanaDashboardConfig, HistoryArchivePublishConfig, HistoryCacheConfig,
+    HistoryCacheVolumeType, HistoryGcConfig, HistoryMode, HsmProvider, IngressConfig,
+    InitDbConfiguration, KeySource, LifecycleHookSpec, LinuxCapability, ManagedDatabaseConfig,
+    MonitoringConfiguration, NetworkPolicyConfig, NodeType, PersistenceBackend, PgBouncerSpec,
+    Pooler, PoolerCluster, PoolerSpec, PostgresConfiguration, PrecheckHookConfig, RolloutStrategy,
+    S3Credentials, SecretKeySelector as CnpgSecretKeySelector, ServiceType, SessionAffinity,
+    StellarBeatConfig, StellarNode, StellarTomlConfig, StorageConfiguration, VaultConfig,
+    WalBackupConfiguration, DEFAULT_BUCKETLIST_DB_INDEX_CUTOFF_MB,
+    DEFAULT_BUCKETLIST_DB_PAGE_SIZE_EXPONENT, DEFAULT_DEBUG_RPC_MAX_BODY_LOG_BYTES,
+    DEFAULT_ORDER_BOOK_CACHE_DEPTH, DEFAULT_TERMINATION_MESSAGE_PATH,
 };
 use crate::error::{Error, Result};
 
@@ -87,35 +106,44 @@ fn resource_name(node: &StellarNode, suffix: &str) -> String {
 // ============================================================================
 
 /// Ensure a PersistentVolumeClaim exists for the node
+///
+/// Also creates the history archive cache PVC when
+/// `validatorConfig.historyCache` uses one.
 #[instrument(skip(client, node), fields(name = %node.name_any(), namespace = node.namespace()))]
 pub async fn ensure_pvc(client: &Client, node: &StellarNode) -> Result<()> {
     let namespace = node.namespace().unwrap_or_else(|| "default".to_string());
     let api: Api<PersistentVolumeClaim> = Api::namespaced(client.clone(), &namespace);
-    let name = resource_name(node, "data");
 
-    let pvc = build_pvc(node);
-
-    match api.get(&name).await {
-        Ok(_existing) => {
-            // PVCs are mostly immutable, just ensure it exists
-            info!("PVC {} already exists", name);
-        }
-        Err(kube::Error::Api(e)) if e.code == 404 => {
-            info!("Creating PVC {}", name);
-            api.create(&PostParams::default(), &pvc).await?;
+    for pvc in std::iter::once(build_pvc(node)).chain(build_history_cache_pvc(node)) {
+        let name = pvc.name_any();
+        match with_timeout("get PersistentVolumeClaim", api.get(&name)).await {
+            Ok(_existing) => {
+                // PVCs are mostly immutable, just ensure it exists
+                info!("PVC {} already exists", name);
+            }
+            Err(Error::KubeError(kube::Error::Api(e))) if e.code == 404 => {
+                info!("Creating PVC {}", name);
+                with_timeout(
+                    "create PersistentVolumeClaim",
+                    api.create(&PostParams::default(), &pvc),
+                )
+                .await?;
+            }
+            Err(e) => return Err(e),
         }
-        Err(e) => return Err(Error::KubeError(e)),
     }
 
     Ok(())
 }
 
-fn build_pvc(node: &StellarNode) -> PersistentVolumeClaim {
-    let labels = standard_labels(node);
-    let name = resource_name(node, "data");
+/// Name of the node's data PersistentVolumeClaim
+pub fn pvc_name(node: &StellarNode) -> String {
+    resource_name(node, "data")
+}
 
-    let mut requests = BTreeMap::new();
-    let effective_storage_size = if node.spec.storage.size.is_empty() {
+/// Requested PVC size, defaulting by history mode when `storage.size` is empty
+pub fn effective_storage_size(node: &StellarNode) -> String {
+    if node.spec.storage.size.is_empty() {
         // Default based on history_mode override
         match node.spec.history_mode {
             HistoryMode::Full => "1500Gi".to_string(), // Approximate for full history
@@ -123,8 +151,22 @@ fn build_pvc(node: &StellarNode) -> PersistentVolumeClaim {
         }
     } else {
         node.spec.storage.size.clone()
-    };
-    requests.insert("storage".to_string(), Quantity(effective_storage_size));
+    }
+}
+
+/// Build the data PersistentVolumeClaim
+///
+/// `storage.pvcLabels` are added to the standard labels, which take precedence.
+pub fn build_pvc(node: &StellarNode) -> PersistentVolumeClaim {
+    let mut labels = node.spec.storage.pvc_labels.clone().unwrap_or_default();
+    labels.extend(standard_labels(node));
+    let name = pvc_name(node);
+
+    let mut requests = BTreeMap::new();
+    requests.insert(
+        "storage".to_string(),
+        Quantity(effective_storage_size(node)),
+    );
 
     // Merge custom annotations from storage config with existing annotations
     let annotations = node.spec.storage.annotations.clone().unwrap_or_default();
@@ -158,6 +200,55 @@ fn build_pvc(node: &StellarNode) -> PersistentVolumeClaim {
     }
 }
 
+/// The validator's history archive cache, if configured
+fn history_cache(node: &StellarNode) -> Option<&HistoryCacheConfig> {
+    if node.spec.node_type != NodeType::Validator {
+        return None;
+    }
+    node.spec.validator_config.as_ref()?.history_cache.as_ref()
+}
+
+/// Name of the node's history archive cache PersistentVolumeClaim
+pub fn history_cache_pvc_name(node: &StellarNode) -> String {
+    resource_name(node, "history-cache")
+}
+
+/// Build the history archive cache PersistentVolumeClaim, if the cache uses one
+///
+/// Owned by the StellarNode, so it is garbage collected with the node.
+pub fn build_history_cache_pvc(node: &StellarNode) -> Option<PersistentVolumeClaim> {
+    let cache = history_cache(node)
+        .filter(|c| c.volume_type == HistoryCacheVolumeType::PersistentVolumeClaim)?;
+
+    let mut requests = BTreeMap::new();
+    requests.insert("storage".to_string(), Quantity(cache.size.clone()));
+
+    Some(PersistentVolumeClaim {
+        metadata: ObjectMeta {
+            name: Some(history_cache_pvc_name(node)),
+            namespace: node.namespace(),
+            labels: Some(standard_labels(node)),
+            owner_references: Some(vec![owner_reference(node)]),
+            ..Default::default()
+        },
+        spec: Some(PersistentVolumeClaimSpec {
+            access_modes: Some(vec!["ReadWriteOnce".to_string()]),
+            storage_class_name: Some(
+                cache
+                    .storage_class
+                    .clone()
+                    .unwrap_or_else(|| node.spec.storage.storage_class.clone()),
+            ),
+            resources: Some(VolumeResourceRequirements {
+                requests: Some(requests),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }),
+        status: None,
+    })
+}
+
 /// Delete the PersistentVolumeClaim for a node
 #[instrument(skip(client, node), fields(name = %node.name_any(), namespace = node.namespace()))]
 pub async fn delete_pvc(client: &Client, node: &StellarNode) -> Result<()> {
@@ -165,12 +256,17 @@ pub async fn delete_pvc(client: &Client, node: &StellarNode) -> Result<()> {
     let api: Api<PersistentVolumeClaim> = Api::namespaced(client.clone(), &namespace);
     let name = resource_name(node, "data");
 
-    match api.delete(&name, &DeleteParams::default()).await {
+    match with_timeout(
+        "delete PersistentVolumeClaim",
+        api.delete(&name, &DeleteParams::default()),
+    )
+    .await
+    {
         Ok(_) => info!("Deleted PVC {}", name),
-        Err(kube::Error::Api(e)) if e.code == 404 => {
+        Err(Error::KubeError(kube::Error::Api(e))) if e.code == 404 => {
             warn!("PVC {} not found, already deleted", name);
         }
-        Err(e) => return Err(Error::KubeError(e)),
+        Err(e) => return Err(e),
     }
 
     Ok(())
@@ -192,20 +288,33 @@ pub async fn ensure_config_map(
     let api: Api<ConfigMap> = Api::namespaced(client.clone(), &namespace);
     let name = resource_name(node, "config");
 
-    let cm = build_config_map(node, quorum_override, enable_mtls);
+    let mut cm = build_config_map(node, quorum_override, enable_mtls);
+    super::config_template::render_config_map(node, &mut cm)?;
 
     let patch = Patch::Apply(&cm);
-    api.patch(
-        &name,
-        &PatchParams::apply("stellar-operator").force(),
-        &patch,
+    with_timeout(
+        "apply ConfigMap",
+        api.patch(
+            &name,
+            &PatchParams::apply("stellar-operator").force(),
+            &patch,
+        ),
     )
     .await?;
 
     Ok(())
 }
 
-fn build_config_map(
+/// Stellar Core URL for Horizon/Soroban, resolving `stellarCoreRef` to a cluster-internal FQDN
+fn resolved_core_url(node: &StellarNode, fallback: &str) -> String {
+    let namespace = node.namespace().unwrap_or_else(|| "default".to_string());
+    node.spec
+        .resolved_stellar_core_url(&namespace)
+        .unwrap_or_else(|| fallback.to_string())
+}
+
+/// Build the node's ConfigMap (network passphrase plus node-type-specific settings)
+pub fn build_config_map(
     node: &StellarNode,
     quorum_override: Option<String>,
     enable_mtls: bool,
@@ -231,7 +340,69 @@ fn build_config_map(
         NodeType::Validator => {
             let mut core_cfg = String::new();
             if let Some(config) = &node.spec.validator_config {
-                let quorum = quorum_override.or_else(|| config.quorum_set.clone());
+                // Top-level keys go before any section
+                if config.standby || catchup_gate::catching_up(node) {
+                    core_cfg.push_str("NODE_IS_VALIDATOR=false\n");
+                }
+                if let Some(bucket_list_db) = &config.bucket_list_db {
+                    core_cfg.push_str(&format!(
+                        "BUCKETLIST_DB_INDEX_PAGE_SIZE_EXPONENT={}\n",
+                        bucket_list_db
+                            .index_page_size_exponent
+                            .unwrap_or(DEFAULT_BUCKETLIST_DB_PAGE_SIZE_EXPONENT)
+                    ));
+                    core_cfg.push_str(&format!(
+                        "BUCKETLIST_DB_INDEX_CUTOFF={}\n",
+                        bucket_list_db
+                            .index_cutoff_mb
+                            .unwrap_or(DEFAULT_BUCKETLIST_DB_INDEX_CUTOFF_MB)
+                    ));
+                    core_cfg.push_str(&format!(
+                        "BUCKETLIST_DB_PERSIST_INDEX={}\n",
+                        bucket_list_db.persist_index.unwrap_or(true)
+                    ));
+                }
+                if node.spec.network_parameters_allowed() {
+                    if let Some(size) = config.max_tx_set_size {
+                        core_cfg.push_str(&format!("MAX_TRANSACTIONS_PER_LEDGER={size}\n"));
+                    }
+                    if let Some(fee) = config.base_fee {
+                        core_cfg.push_str(&format!("BASE_FEE={fee}\n"));
+                    }
+                }
+                if let Some(ms) = config.scp_ballot_timeout_ms {
+                    core_cfg.push_str(&format!("SCP_BALLOT_PREPARE_TIMER_MS={ms}\n"));
+                }
+                if let Some(ms) = config.scp_ledger_target_duration_ms {
+                    core_cfg.push_str(&format!("SCP_LEDGER_TARGET_DURATION_MS={ms}\n"));
+                }
+                if let Some(seconds) = config.scp_max_time_slip_seconds {
+                    core_cfg.push_str(&format!("MAXIMUM_LEDGER_CLOSETIME_DRIFT={seconds}\n"));
+                }
+                if let Some(workers) = config.catchup_workers {
+                    core_cfg.push_str(&format!("MAX_CONCURRENT_SUBPROCESSES={workers}\n"));
+                }
+                if let Some(ms) = config.flood_tx_period_ms {
+                    core_cfg.push_str(&format!("FLOOD_TX_PERIOD_MS={ms}\n"));
+                }
+                if let Some(count) = config.max_batch_write_count {
+                    core_cfg.push_str(&format!("MAX_BATCH_WRITE_COUNT={count}\n"));
+                }
+                if config.history_cache.is_some() {
+                    core_cfg.push_str(&format!("TMP_DIR_PATH=\"{HISTORY_CACHE_MOUNT_PATH}\"\n"));
+                }
+
+                // A fetched VSL wins, then the structured validator list, then raw TOML
+                let quorum = quorum_override.or_else(|| {
+                    if config.quorum_validators.is_empty() {
+                        config.quorum_set.clone()
+                    } else {
+                        Some(quorum_builder::generate_quorum_set_toml(
+                            &config.quorum_validators,
+                            quorum_builder::DEFAULT_QUORUM_THRESHOLD_PERCENT,
+                        ))
+                    }
+                });
                 if let Some(q) = quorum {
                     core_cfg.push_str(&q);
                 }
@@ -244,6 +415,32 @@ fn build_config_map(
                 core_cfg.push_str("TLS_KEY_FILE=\"/etc/stellar/tls/tls.key\"\n");
             }
 
+            // History archive publication
+            if let Some(publish) = node
+                .spec
+                .validator_config
+                .as_ref()
+                .and_then(|c| c.archive_publication_credentials.as_ref())
+            {
+                if let Some(bucket) = &publish.s3_bucket {
+                    core_cfg.push_str("\n[HISTORY.s3]\n");
+                    core_cfg.push_str(&format!(
+                        "get=\"curl -sf https://{bucket}.s3.amazonaws.com/{{0}} -o {{1}}\"\n"
+                    ));
+                    core_cfg.push_str(&format!("put=\"aws s3 cp {{0}} s3://{bucket}/{{1}}\"\n"));
+                }
+                if let Some(azure) = &publish.azure_blob {
+                    let url = azure.container_url();
+                    core_cfg.push_str("\n[HISTORY.azure]\n");
+                    core_cfg.push_str(&format!(
+                        "get=\"curl -sf '{url}/{{0}}?'$AZURE_STORAGE_SAS_TOKEN -o {{1}}\"\n"
+                    ));
+                    core_cfg.push_str(&format!(
+                        "put=\"curl -sf -X PUT -H 'x-ms-blob-type: BlockBlob' --data-binary @{{0}} '{url}/{{1}}?'$AZURE_STORAGE_SAS_TOKEN\"\n"
+                    ));
+                }
+            }
+
             // History Mode Configuration
             match node.spec.history_mode {
                 HistoryMode::Full => {
@@ -263,20 +460,149 @@ fn build_config_map(
         }
         NodeType::Horizon => {
             if let Some(config) = &node.spec.horizon_config {
+                if let Some(captive) = &config.captive_core {
+                    data.insert("captive-core.cfg".to_string(), captive.config.clone());
+                    data.insert(
+                        "CAPTIVE_CORE_CONFIG_PATH".to_string(),
+                        "/config/captive-core.cfg".to_string(),
+                    );
+                    data.insert(
+                        "STELLAR_CORE_BINARY_PATH".to_string(),
+                        captive.binary_path.clone(),
+                    );
+                } else {
+                    data.insert(
+                        "STELLAR_CORE_URL".to_string(),
+                        resolved_core_url(node, &config.stellar_core_url),
+                    );
+                }
+                data.insert("INGEST".to_string(), config.enable_ingest.to_string());
+
                 data.insert(
-                    "STELLAR_CORE_URL".to_string(),
-                    config.stellar_core_url.clone(),
+                    "ORDER_BOOK_DEPTH".to_string(),
+                    config
+                        .order_book_cache_depth
+                        .unwrap_or(DEFAULT_ORDER_BOOK_CACHE_DEPTH)
+                        .to_string(),
                 );
-                data.insert("INGEST".to_string(), config.enable_ingest.to_string());
+                data.insert(
+                    "ENABLE_ORDER_BOOK_STREAMING".to_string(),
+                    config.enable_order_book_streaming.to_string(),
+                );
+                if let Some(tolerance) = config.order_book_stale_tolerance_ledgers {
+                    data.insert(
+                        "ORDER_BOOK_STALE_TOLERANCE_LEDGERS".to_string(),
+                        tolerance.to_string(),
+                    );
+                }
+                if let Some(max) = config.sse_max_connections {
+                    data.insert("SSE_MAX_CONNECTIONS".to_string(), max.to_string());
+                }
+                if !config.cors_allowed_headers.is_empty() {
+                    data.insert(
+                        "CORS_ALLOWED_HEADERS".to_string(),
+                        config.cors_allowed_headers.join(","),
+                    );
+                }
+                if !config.cors_expose_headers.is_empty() {
+                    data.insert(
+                        "CORS_EXPOSE_HEADERS".to_string(),
+                        config.cors_expose_headers.join(","),
+                    );
+                }
+                if let Some(seconds) = config.cors_max_age_seconds {
+                    data.insert("CORS_MAX_AGE".to_string(), seconds.to_string());
+                }
+                if let Some(limit) = node
+                    .spec
+                    .autoscaling
+                    .as_ref()
+                    .and_then(|a| a.max_connections_per_replica)
+                {
+                    data.insert("CONNECTION_LIMIT".to_string(), limit.to_string());
+                }
+
+                if let Some(exp) = &config.experimental_ingestion {
+                    data.insert(
+                        "EXP_ENABLE_INGESTION_FILTERING".to_string(),
+                        exp.enable_filtering.to_string(),
+                    );
+                    data.insert(
+                        "EXP_INGEST_CLAIMABLE_BALANCES".to_string(),
+                        exp.enable_claimable_balances.to_string(),
+                    );
+                    data.insert(
+                        "EXP_INGEST_LIQUIDITY_POOLS".to_string(),
+                        exp.enable_liquidity_pools.to_string(),
+                    );
+                    data.insert(
+                        "EXP_INGEST_SOROBAN_EVENTS".to_string(),
+                        exp.enable_soroban_events.to_string(),
+                    );
+                    if let Some(rules) = &exp.filter_rules_file {
+                        data.insert("ingestion-filter-rules.json".to_string(), rules.clone());
+                        data.insert(
+                            "INGESTION_FILTER_RULES_FILE".to_string(),
+                            "/config/ingestion-filter-rules.json".to_string(),
+                        );
+                    }
+                }
             }
         }
         NodeType::SorobanRpc => {
             if let Some(config) = &node.spec.soroban_config {
                 data.insert(
                     "STELLAR_CORE_URL".to_string(),
-                    config.stellar_core_url.clone(),
+                    resolved_core_url(node, &config.stellar_core_url),
                 );
 
+                if let Some(fees) = &config.fee_config {
+                    for (_, env, value) in fees.entries() {
+                        data.insert(env.to_string(), value.to_string());
+                    }
+                }
+                if let Some(size) = config.tx_queue_size {
+                    data.insert("TX_QUEUE_SIZE".to_string(), size.to_string());
+                }
+                if let Some(concurrency) = config.tx_concurrency {
+                    data.insert("TX_CONCURRENCY".to_string(), concurrency.to_string());
+                }
+                if let Some(size) = config.contract_code_cache_size {
+                    data.insert("CONTRACT_CODE_CACHE_SIZE".to_string(), size.to_string());
+                }
+                if let Some(ttl) = config.contract_code_cache_ttl_seconds {
+                    data.insert("CONTRACT_CODE_CACHE_TTL".to_string(), ttl.to_string());
+                }
+                if let Some(size) = config.contract_instance_cache_size {
+                    data.insert("CONTRACT_INSTANCE_CACHE_SIZE".to_string(), size.to_string());
+                }
+                data.insert("RPC_ENDPOINT".to_string(), config.rpc_path().to_string());
+                if config.debug_rpc {
+                    data.insert("RPC_DEBUG_LOG".to_string(), "true".to_string());
+                    data.insert(
+                        "RPC_DEBUG_MAX_BODY_BYTES".to_string(),
+                        config
+                            .debug_rpc_max_body_log_bytes
+                            .unwrap_or(DEFAULT_DEBUG_RPC_MAX_BODY_LOG_BYTES)
+                            .to_string(),
+                    );
+                }
+                if let Some(archival) = &config.archival_storage {
+                    data.insert("ARCHIVAL_BACKEND".to_string(), archival.backend.to_string());
+                    if let Some(ref bucket) = archival.bucket_name {
+                        data.insert("ARCHIVAL_BUCKET".to_string(), bucket.clone());
+                    }
+                    if let Some(bytes) = archival.restoration_max_bytes_per_ledger {
+                        data.insert(
+                            "ARCHIVAL_RESTORATION_MAX_BYTES_PER_LEDGER".to_string(),
+                            bytes.to_string(),
+                        );
+                    }
+                    if let Some(size) = archival.eviction_scan_size {
+                        data.insert("ARCHIVAL_EVICTION_SCAN_SIZE".to_string(), size.to_string());
+                    }
+                }
+
                 // Try to generate TOML from structured config (preferred)
                 if config.captive_core_structured_config.is_some() {
                     match crate::controller::captive_core::CaptiveCoreConfigBuilder::from_node_config(node) {
@@ -335,12 +661,17 @@ pub async fn delete_config_map(client: &Client, node: &StellarNode) -> Result<()
     let api: Api<ConfigMap> = Api::namespaced(client.clone(), &namespace);
     let name = resource_name(node, "config");
 
-    match api.delete(&name, &DeleteParams::default()).await {
+    match with_timeout(
+        "delete ConfigMap",
+        api.delete(&name, &DeleteParams::default()),
+    )
+    .await
+    {
         Ok(_) => info!("Deleted ConfigMap {}", name),
-        Err(kube::Error::Api(e)) if e.code == 404 => {
+        Err(Error::KubeError(kube::Error::Api(e))) if e.code == 404 => {
             warn!("ConfigMap {} not found", name);
         }
-        Err(e) => return Err(Error::KubeError(e)),
+        Err(e) => return Err(e),
     }
 
     Ok(())
@@ -364,10 +695,13 @@ pub async fn ensure_deployment(
     let deployment = build_deployment(node, enable_mtls);
 
     let patch = Patch::Apply(&deployment);
-    api.patch(
-        &name,
-        &PatchParams::apply("stellar-operator").force(),
-        &patch,
+    with_timeout(
+        "apply Deployment",
+        api.patch(
+            &name,
+            &PatchParams::apply("stellar-operator").force(),
+            &patch,
+        ),
     )
     .await?;
 
@@ -418,21 +752,28 @@ pub async fn ensure_canary_deployment(
     }
 
     let patch = Patch::Apply(&deployment);
-    api.patch(
-        &name,
-        &PatchParams::apply("stellar-operator").force(),
-        &patch,
+    with_timeout(
+        "apply Deployment",
+        api.patch(
+            &name,
+            &PatchParams::apply("stellar-operator").force(),
+            &patch,
+        ),
     )
     .await?;
 
     Ok(())
 }
 
-fn build_deployment(node: &StellarNode, enable_mtls: bool) -> Deployment {
+pub fn build_deployment(node: &StellarNode, enable_mtls: bool) -> Deployment {
     let labels = standard_labels(node);
     let name = node.name_any();
 
-    let replicas = if node.spec.suspended {
+    // Horizon stays stopped while its Stellar Core is older than it supports
+    let replicas = if node.spec.suspended
+        || autoscaling::weekend_suspended(node)
+        || core_version::core_version_incompatible(node)
+    {
         0
     } else {
         node.spec.replicas
@@ -480,10 +821,13 @@ pub async fn ensure_statefulset(
     let statefulset = build_statefulset(node, enable_mtls);
 
     let patch = Patch::Apply(&statefulset);
-    api.patch(
-        &name,
-        &PatchParams::apply("stellar-operator").force(),
-        &patch,
+    with_timeout(
+        "apply StatefulSet",
+        api.patch(
+            &name,
+            &PatchParams::apply("stellar-operator").force(),
+            &patch,
+        ),
     )
     .await?;
 
@@ -494,7 +838,15 @@ fn build_statefulset(node: &StellarNode, enable_mtls: bool) -> StatefulSet {
     let labels = standard_labels(node);
     let name = node.name_any();
 
-    let replicas = if node.spec.suspended { 0 } else { 1 }; // Validators always have 1 replica
+    // Validators always have 1 replica; an outdated protocol keeps them stopped
+    let replicas = if node.spec.suspended
+        || autoscaling::weekend_suspended(node)
+        || protocol_version::protocol_version_outdated(node)
+    {
+        0
+    } else {
+        1
+    };
 
     let mut annotations = BTreeMap::new();
     if node.spec.suspended {
@@ -546,22 +898,32 @@ pub async fn delete_workload(client: &Client, node: &StellarNode) -> Result<()>
     match node.spec.node_type {
         NodeType::Validator => {
             let api: Api<StatefulSet> = Api::namespaced(client.clone(), &namespace);
-            match api.delete(&name, &DeleteParams::default()).await {
+            match with_timeout(
+                "delete StatefulSet",
+                api.delete(&name, &DeleteParams::default()),
+            )
+            .await
+            {
                 Ok(_) => info!("Deleted StatefulSet {}", name),
-                Err(kube::Error::Api(e)) if e.code == 404 => {
+                Err(Error::KubeError(kube::Error::Api(e))) if e.code == 404 => {
                     warn!("StatefulSet {} not found", name);
                 }
-                Err(e) => return Err(Error::KubeError(e)),
+                Err(e) => return Err(e),
             }
         }
         _ => {
             let api: Api<Deployment> = Api::namespaced(client.clone(), &namespace);
-            match api.delete(&name, &DeleteParams::default()).await {
+            match with_timeout(
+                "delete Deployment",
+                api.delete(&name, &DeleteParams::default()),
+            )
+            .await
+            {
                 Ok(_) => info!("Deleted Deployment {}", name),
-                Err(kube::Error::Api(e)) if e.code == 404 => {
+                Err(Error::KubeError(kube::Error::Api(e))) if e.code == 404 => {
                     warn!("Deployment {} not found", name);
                 }
-                Err(e) => return Err(Error::KubeError(e)),
+                Err(e) => return Err(e),
             }
         }
     }
@@ -584,10 +946,13 @@ pub async fn ensure_service(client: &Client, node: &StellarNode, enable_mtls: bo
     let service = build_service(node, enable_mtls);
 
     let patch = Patch::Apply(&service);
-    api.patch(
-        &name,
-        &PatchParams::apply("stellar-operator").force(),
-        &patch,
+    with_timeout(
+        "apply Service",
+        api.patch(
+            &name,
+            &PatchParams::apply("stellar-operator").force(),
+            &patch,
+        ),
     )
     .await?;
 
@@ -628,22 +993,78 @@ pub async fn ensure_canary_service(
     }
 
     let patch = Patch::Apply(&service);
-    api.patch(
-        &name,
-        &PatchParams::apply("stellar-operator").force(),
-        &patch,
+    with_timeout(
+        "apply Service",
+        api.patch(
+            &name,
+            &PatchParams::apply("stellar-operator").force(),
+            &patch,
+        ),
     )
     .await?;
 
     Ok(())
 }
 
-fn build_service(node: &StellarNode, enable_mtls: bool) -> Service {
+/// Annotation enabling topology-aware routing (EndpointSlice zone hints)
+pub const TOPOLOGY_MODE_ANNOTATION: &str = "service.kubernetes.io/topology-mode";
+
+/// Build the node's ClusterIP Service
+pub fn build_service(node: &StellarNode, enable_mtls: bool) -> Service {
     let labels = standard_labels(node);
     let name = node.name_any();
 
+    // Prefer same-zone endpoints for multi-replica node types
+    let topology_aware = node.spec.node_type != NodeType::Validator
+        && node.spec.service.as_ref().is_some_and(|s| s.topology_aware);
+    let annotations = topology_aware
+        .then(|| BTreeMap::from([(TOPOLOGY_MODE_ANNOTATION.to_string(), "Auto".to_string())]));
+
     let http_port_name = if enable_mtls { "https" } else { "http" }.to_string();
 
+    let service_type = node
+        .spec
+        .service
+        .as_ref()
+        .map(|s| s.service_type)
+        .unwrap_or_default();
+    let external_traffic_policy = node
+        .spec
+        .service
+        .as_ref()
+        .and_then(|s| s.external_traffic_policy.clone())
+        .filter(|_| service_type != ServiceType::ClusterIp);
+    let local_traffic = external_traffic_policy == Some(ExternalTrafficPolicy::Local);
+
+    // Local traffic already ties a client to the pods of the node it enters
+    // through; ClientIP affinity keeps it on a single pod
+    let session_affinity = if local_traffic {
+        SessionAffinity::ClientIp
+    } else {
+        node.spec
+            .service
+            .as_ref()
+            .map(|s| s.session_affinity.clone())
+            .unwrap_or_default()
+    };
+    let health_check_node_port = node
+        .spec
+        .service
+        .as_ref()
+        .and_then(|s| s.health_check_node_port)
+        .filter(|_| local_traffic && service_type == ServiceType::LoadBalancer);
+    let session_affinity_config = node
+        .spec
+        .service
+        .as_ref()
+        .and_then(|s| s.session_affinity_timeout_seconds)
+        .filter(|_| session_affinity == SessionAffinity::ClientIp)
+        .map(|timeout_seconds| SessionAffinityConfig {
+            client_ip: Some(ClientIPConfig {
+                timeout_seconds: Some(timeout_seconds),
+            }),
+        });
+
     let ports = match node.spec.node_type {
         NodeType::Validator => vec![
             ServicePort {
@@ -657,11 +1078,18 @@ fn build_service(node: &StellarNode, enable_mtls: bool) -> Service {
                 ..Default::default()
             },
         ],
-        NodeType::Horizon => vec![ServicePort {
-            name: Some(http_port_name),
-            port: 8000,
-            ..Default::default()
-        }],
+        NodeType::Horizon => match node.spec.horizon_tls() {
+            Some(tls) => vec![ServicePort {
+                name: Some("https".to_string()),
+                port: i32::from(tls.port),
+                ..Default::default()
+            }],
+            None => vec![ServicePort {
+                name: Some(http_port_name),
+                port: 8000,
+                ..Default::default()
+            }],
+        },
         NodeType::SorobanRpc => vec![ServicePort {
             name: Some(http_port_name),
             port: 8000,
@@ -675,6 +1103,7 @@ fn build_service(node: &StellarNode, enable_mtls: bool) -> Service {
                 name: Some(name),
                 namespace: node.namespace(),
                 labels: Some(labels.clone()),
+                annotations,
                 owner_references: Some(vec![owner_reference(node)]),
                 ..Default::default()
             },
@@ -683,6 +1112,11 @@ fn build_service(node: &StellarNode, enable_mtls: bool) -> Service {
         spec: Some(ServiceSpec {
             selector: Some(labels),
             ports: Some(ports),
+            session_affinity: Some(session_affinity.to_string()),
+            session_affinity_config,
+            type_: Some(service_type.to_string()),
+            external_traffic_policy: external_traffic_policy.map(|p| p.to_string()),
+            health_check_node_port,
             ..Default::default()
         }),
         status: None,
@@ -1138,12 +1572,17 @@ pub async fn delete_service(client: &Client, node: &StellarNode) -> Result<()> {
     let api: Api<Service> = Api::namespaced(client.clone(), &namespace);
     let name = node.name_any();
 
-    match api.delete(&name, &DeleteParams::default()).await {
+    match with_timeout(
+        "delete Service",
+        api.delete(&name, &DeleteParams::default()),
+    )
+    .await
+    {
         Ok(_) => info!("Deleted Service {}", name),
-        Err(kube::Error::Api(e)) if e.code == 404 => {
+        Err(Error::KubeError(kube::Error::Api(e))) if e.code == 404 => {
             warn!("Service {} not found", name);
         }
-        Err(e) => return Err(Error::KubeError(e)),
+        Err(e) => return Err(e),
     }
 
     Ok(())
@@ -1168,10 +1607,13 @@ pub async fn ensure_cnpg_cluster(client: &Client, node: &StellarNode) -> Result<
     let cluster = build_cnpg_cluster(node, managed_db);
 
     let patch = Patch::Apply(&cluster);
-    api.patch(
-        &name,
-        &PatchParams::apply("stellar-operator").force(),
-        &patch,
+    with_timeout(
+        "apply CNPG Cluster",
+        api.patch(
+            &name,
+            &PatchParams::apply("stellar-operator").force(),
+            &patch,
+        ),
     )
     .await?;
 
@@ -1276,10 +1718,13 @@ pub async fn ensure_cnpg_pooler(client: &Client, node: &StellarNode) -> Result<(
     let pooler = build_cnpg_pooler(node, pgbouncer);
 
     let patch = Patch::Apply(&pooler);
-    api.patch(
-        &name,
-        &PatchParams::apply("stellar-operator").force(),
-        &patch,
+    with_timeout(
+        "apply CNPG Pooler",
+        api.patch(
+            &name,
+            &PatchParams::apply("stellar-operator").force(),
+            &patch,
+        ),
     )
     .await?;
 
@@ -1347,16 +1792,20 @@ pub async fn delete_cnpg_resources(client: &Client, node: &StellarNode) -> Resul
     // Delete Pooler
     let pooler_api: Api<Pooler> = Api::namespaced(client.clone(), &namespace);
     let pooler_name = resource_name(node, "pooler");
-    let _ = pooler_api
-        .delete(&pooler_name, &DeleteParams::default())
-        .await;
+    let _ = with_timeout(
+        "delete Pooler",
+        pooler_api.delete(&pooler_name, &DeleteParams::default()),
+    )
+    .await;
 
     // Delete Cluster
     let cluster_api: Api<Cluster> = Api::namespaced(client.clone(), &namespace);
     let cluster_name = node.name_any();
-    let _ = cluster_api
-        .delete(&cluster_name, &DeleteParams::default())
-        .await;
+    let _ = with_timeout(
+        "delete Cluster",
+        cluster_api.delete(&cluster_name, &DeleteParams::default()),
+    )
+    .await;
 
     Ok(())
 }
@@ -1369,14 +1818,7 @@ pub async fn delete_cnpg_resources(client: &Client, node: &StellarNode) -> Resul
 pub async fn ensure_ingress(client: &Client, node: &StellarNode) -> Result<()> {
     // Ingress is only supported for Horizon and SorobanRpc
     let ingress_cfg = match &node.spec.ingress {
-        Some(cfg)
-            if matches!(
-                node.spec.node_type,
-                NodeType::Horizon | NodeType::SorobanRpc
-            ) =>
-        {
-            cfg
-        }
+        Some(cfg) if node.spec.ingress_enabled() => cfg,
         _ => return Ok(()),
     };
 
@@ -1386,10 +1828,13 @@ pub async fn ensure_ingress(client: &Client, node: &StellarNode) -> Result<()> {
 
     let ingress = build_ingress(node, ingress_cfg);
 
-    api.patch(
-        &name,
-        &PatchParams::apply("stellar-operator").force(),
-        &Patch::Apply(&ingress),
+    with_timeout(
+        "apply Ingress",
+        api.patch(
+            &name,
+            &PatchParams::apply("stellar-operator").force(),
+            &Patch::Apply(&ingress),
+        ),
     )
     .await?;
 
@@ -1445,17 +1890,24 @@ pub async fn ensure_ingress(client: &Client, node: &StellarNode) -> Result<()> {
                 }
             }
 
-            api.patch(
-                &canary_name,
-                &PatchParams::apply("stellar-operator").force(),
-                &Patch::Apply(&canary_ingress),
+            with_timeout(
+                "apply Ingress",
+                api.patch(
+                    &canary_name,
+                    &PatchParams::apply("stellar-operator").force(),
+                    &Patch::Apply(&canary_ingress),
+                ),
             )
             .await?;
             info!("Canary Ingress ensured for {}/{}", namespace, canary_name);
         } else {
             // Delete canary ingress if no longer active
             let canary_name = format!("{name}-canary");
-            let _ = api.delete(&canary_name, &DeleteParams::default()).await;
+            let _ = with_timeout(
+                "delete Ingress",
+                api.delete(&canary_name, &DeleteParams::default()),
+            )
+            .await;
         }
     }
 
@@ -1547,21 +1999,25 @@ fn build_ingress(node: &StellarNode, config: &IngressConfig) -> Ingress {
 }
 
 /// Delete the Ingress for a node
+///
+/// Also used to remove a stale Ingress after `spec.ingress` was removed, so it
+/// does not depend on the current spec.
 pub async fn delete_ingress(client: &Client, node: &StellarNode) -> Result<()> {
-    if node.spec.ingress.is_none() {
-        return Ok(());
-    }
-
     let namespace = node.namespace().unwrap_or_else(|| "default".to_string());
     let api: Api<Ingress> = Api::namespaced(client.clone(), &namespace);
     let name = resource_name(node, "ingress");
 
-    match api.delete(&name, &DeleteParams::default()).await {
+    match with_timeout(
+        "delete Ingress",
+        api.delete(&name, &DeleteParams::default()),
+    )
+    .await
+    {
         Ok(_) => info!("Deleted Ingress {}", name),
-        Err(kube::Error::Api(e)) if e.code == 404 => {
-            warn!("Ingress {} not found, already deleted", name);
+        Err(Error::KubeError(kube::Error::Api(e))) if e.code == 404 => {
+            debug!("Ingress {} not found, already deleted", name);
         }
-        Err(e) => return Err(Error::KubeError(e)),
+        Err(e) => return Err(e),
     }
 
     Ok(())
@@ -1571,6 +2027,34 @@ pub async fn delete_ingress(client: &Client, node: &StellarNode) -> Result<()> {
 // Pod Template Builder
 // ============================================================================
 
+/// Container entries for `spec.resources.claims`
+fn resource_claims(node: &StellarNode) -> Option<Vec<ResourceClaim>> {
+    let claims = &node.spec.resources.claims;
+    (!claims.is_empty()).then(|| {
+        claims
+            .iter()
+            .map(|name| ResourceClaim { name: name.clone() })
+            .collect()
+    })
+}
+
+/// Pod entries backing [`resource_claims`], each naming the existing ResourceClaim
+fn pod_resource_claims(node: &StellarNode) -> Option<Vec<PodResourceClaim>> {
+    let claims = &node.spec.resources.claims;
+    (!claims.is_empty()).then(|| {
+        claims
+            .iter()
+            .map(|name| PodResourceClaim {
+                name: name.clone(),
+                source: Some(ClaimSource {
+                    resource_claim_name: Some(name.clone()),
+                    ..Default::default()
+                }),
+            })
+            .collect()
+    })
+}
+
 fn build_pod_template(
     node: &StellarNode,
     labels: &BTreeMap<String, String>,
@@ -1599,12 +2083,18 @@ fn build_pod_template(
             },
         ]),
         topology_spread_constraints: node.spec.topology_spread_constraints.clone(),
+        node_selector: detected_node_selector(node),
+        resource_claims: pod_resource_claims(node),
         ..Default::default()
     };
 
-    // Add Horizon database migration init container
+    // Add Horizon database migration init container, after waiting for the database
     if let NodeType::Horizon = node.spec.node_type {
         if let Some(horizon_config) = &node.spec.horizon_config {
+            if let Some(timeout) = horizon_config.db_wait_timeout_seconds {
+                let init_containers = pod_spec.init_containers.get_or_insert_with(Vec::new);
+                init_containers.push(build_db_wait_container(node, timeout));
+            }
             if horizon_config.auto_migration {
                 let init_containers = pod_spec.init_containers.get_or_insert_with(Vec::new);
                 init_containers.push(build_horizon_migration_container(node));
@@ -1612,6 +2102,18 @@ fn build_pod_template(
         }
     }
 
+    // Add Soroban RPC database migration init container (PostgreSQL only)
+    if let NodeType::SorobanRpc = node.spec.node_type {
+        if let Some(soroban_config) = &node.spec.soroban_config {
+            if soroban_config.persistence_backend == PersistenceBackend::PostgreSQL
+                && soroban_config.run_migration_at_startup
+            {
+                let init_containers = pod_spec.init_containers.get_or_insert_with(Vec::new);
+                init_containers.push(build_soroban_migration_container(node));
+            }
+        }
+    }
+
     // Add KMS init container if needed (Validator nodes only)
     if let NodeType::Validator = node.spec.node_type {
         if let Some(validator_config) = &node.spec.validator_config {
@@ -1683,6 +2185,94 @@ fn build_pod_template(
         ..Default::default()
     });
 
+    // Add the GCS service account key for Soroban state archival
+    if let Some(secret) = gcs_archival_credentials(node) {
+        volumes.push(Volume {
+            name: "archival-credentials".to_string(),
+            secret: Some(SecretVolumeSource {
+                secret_name: Some(secret.to_string()),
+                ..Default::default()
+            }),
+            ..Default::default()
+        });
+    }
+
+    // Add Horizon's own TLS certificate (and client CA for mTLS)
+    if let Some(tls) = node.spec.horizon_tls() {
+        volumes.push(Volume {
+            name: "horizon-tls".to_string(),
+            secret: Some(SecretVolumeSource {
+                secret_name: Some(tls.cert_secret_ref.clone()),
+                ..Default::default()
+            }),
+            ..Default::default()
+        });
+        if let Some(ref ca) = tls.client_ca_secret_ref {
+            volumes.push(Volume {
+                name: "horizon-client-ca".to_string(),
+                secret: Some(SecretVolumeSource {
+                    secret_name: Some(ca.clone()),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            });
+        }
+    }
+
+    // Add the history archive cache
+    if let Some(cache) = history_cache(node) {
+        volumes.push(match cache.volume_type {
+            HistoryCacheVolumeType::EmptyDir => Volume {
+                name: "history-cache".to_string(),
+                empty_dir: Some(k8s_openapi::api::core::v1::EmptyDirVolumeSource {
+                    size_limit: Some(Quantity(cache.size.clone())),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            },
+            HistoryCacheVolumeType::PersistentVolumeClaim => Volume {
+                name: "history-cache".to_string(),
+                persistent_volume_claim: Some(
+                    k8s_openapi::api::core::v1::PersistentVolumeClaimVolumeSource {
+                        claim_name: history_cache_pvc_name(node),
+                        ..Default::default()
+                    },
+                ),
+                ..Default::default()
+            },
+        });
+    }
+
+    // Add the Horizon offer filter rules
+    if let Some(config_map) = node.spec.horizon_filter_rules_config_map() {
+        volumes.push(Volume {
+            name: "horizon-filter-rules".to_string(),
+            config_map: Some(ConfigMapVolumeSource {
+                name: Some(config_map.to_string()),
+                items: Some(vec![KeyToPath {
+                    key: HORIZON_FILTER_RULES_FILE.to_string(),
+                    path: HORIZON_FILTER_RULES_FILE.to_string(),
+                    ..Default::default()
+                }]),
+                ..Default::default()
+            }),
+            ..Default::default()
+        });
+    }
+
+    // Add the PostgreSQL TLS secrets; libpq rejects a private key readable by others
+    for file in pg_ssl_files(node) {
+        volumes.push(Volume {
+            name: file.volume.to_string(),
+            secret: Some(SecretVolumeSource {
+                secret_name: Some(file.secret.to_string()),
+                default_mode: (file.key == "tls.key").then_some(0o600),
+                ..Default::default()
+            }),
+            ..Default::default()
+        });
+    }
+
     // Add Cloud HSM sidecar and volumes
     if let NodeType::Validator = node.spec.node_type {
         if let Some(validator_config) = &node.spec.validator_config {
@@ -1721,10 +2311,52 @@ fn build_pod_template(
         }
     }
 
+    // Add StellarBeat uptime reporting sidecar
+    if let NodeType::Validator = node.spec.node_type {
+        if let Some(beat) = node
+            .spec
+            .validator_config
+            .as_ref()
+            .and_then(|vc| vc.stellar_beat_reporting.as_ref())
+        {
+            pod_spec.containers.push(build_stellar_beat_container(beat));
+        }
+    }
+
+    // Add history archive GC sidecar
+    if let Some((gc, publish)) = history_gc(node) {
+        if gc.gc_implementation == GcImplementation::Sidecar {
+            pod_spec
+                .containers
+                .push(build_history_gc_sidecar(gc, publish));
+        }
+    }
+
+    let mut labels = labels.clone();
+    if let Some(group) = quorum_group(node) {
+        labels.insert(QUORUM_GROUP_LABEL.to_string(), group.to_string());
+        pod_spec.affinity = Some(quorum_group_anti_affinity(group));
+    }
+
+    // Schedule autoscaled replicas onto the highest-capacity pools first
+    if let Some(node_affinity) = node
+        .spec
+        .autoscaling
+        .as_ref()
+        .filter(|cfg| cfg.enabled)
+        .and_then(autoscaling::weighted_node_affinity)
+    {
+        pod_spec
+            .affinity
+            .get_or_insert_with(Default::default)
+            .node_affinity = Some(node_affinity);
+    }
+
     PodTemplateSpec {
         metadata: Some(merge_resource_meta(
             ObjectMeta {
-                labels: Some(labels.clone()),
+                labels: Some(labels),
+                annotations: pod_annotations(node),
                 ..Default::default()
             },
             &node.spec.resource_meta,
@@ -1733,7 +2365,363 @@ fn build_pod_template(
     }
 }
 
-fn build_container(node: &StellarNode, enable_mtls: bool) -> Container {
+/// Pod template annotation marking a warm-standby validator
+///
+/// Dropping it on promotion changes the pod template, which rolls the pods
+/// onto the regenerated, validating config.
+pub const STANDBY_ANNOTATION: &str = "stellar.org/standby";
+
+/// Annotations for the pod template, if any
+/// Pod label carrying `spec.validatorConfig.quorumGroup`
+pub const QUORUM_GROUP_LABEL: &str = "stellar.org/quorum-group";
+
+/// Quorum group of a validator, if set
+fn quorum_group(node: &StellarNode) -> Option<&str> {
+    if node.spec.node_type != NodeType::Validator {
+        return None;
+    }
+    node.spec
+        .validator_config
+        .as_ref()
+        .and_then(|c| c.quorum_group.as_deref())
+}
+
+/// Required anti-affinity keeping validators of one quorum group on separate nodes
+///
+/// The selector spans all namespaces, since members of a quorum are often
+/// deployed into different namespaces.
+fn quorum_group_anti_affinity(group: &str) -> Affinity {
+    Affinity {
+        pod_anti_affinity: Some(PodAntiAffinity {
+            required_during_scheduling_ignored_during_execution: Some(vec![PodAffinityTerm {
+                label_selector: Some(LabelSelector {
+                    match_labels: Some(BTreeMap::from([(
+                        QUORUM_GROUP_LABEL.to_string(),
+                        group.to_string(),
+                    )])),
+                    ..Default::default()
+                }),
+                namespace_selector: Some(LabelSelector::default()),
+                topology_key: "kubernetes.io/hostname".to_string(),
+                ..Default::default()
+            }]),
+            ..Default::default()
+        }),
+        ..Default::default()
+    }
+}
+
+/// Node selector cached by `spec.autoDetectNodeSelector`, while it is enabled
+fn detected_node_selector(node: &StellarNode) -> Option<BTreeMap<String, String>> {
+    if !node.spec.auto_detect_node_selector {
+        return None;
+    }
+    node.status
+        .as_ref()
+        .and_then(|s| s.detected_node_selector.clone())
+}
+
+fn pod_annotations(node: &StellarNode) -> Option<BTreeMap<String, String>> {
+    let mut annotations = node.spec.vault.as_ref().map(vault_annotations);
+    let standby = node.spec.node_type == NodeType::Validator
+        && node
+            .spec
+            .validator_config
+            .as_ref()
+            .is_some_and(|c| c.standby);
+    if standby {
+        annotations
+            .get_or_insert_with(BTreeMap::new)
+            .insert(STANDBY_ANNOTATION.to_string(), "true".to_string());
+    }
+    if catchup_gate::catching_up(node) {
+        annotations
+            .get_or_insert_with(BTreeMap::new)
+            .insert(CATCHUP_ANNOTATION.to_string(), "true".to_string());
+    }
+    if let Some(mesh) = &node.spec.mesh {
+        let inject = mesh
+            .inject
+            .unwrap_or(node.spec.node_type != NodeType::Validator);
+        annotations.get_or_insert_with(BTreeMap::new).insert(
+            mesh.provider.injection_annotation().to_string(),
+            mesh.provider.injection_value(inject).to_string(),
+        );
+    }
+    annotations
+}
+
+/// Vault Agent Injector annotations for the pod template
+///
+/// User-supplied `vault.annotations` are applied last so they can override the
+/// generated ones.
+fn vault_annotations(vault: &VaultConfig) -> BTreeMap<String, String> {
+    const PREFIX: &str = "vault.hashicorp.com";
+
+    let mut annotations = BTreeMap::from([
+        (format!("{PREFIX}/agent-inject"), "true".to_string()),
+        (format!("{PREFIX}/role"), vault.role.clone()),
+    ]);
+    if vault.pre_populate_only {
+        annotations.insert(
+            format!("{PREFIX}/agent-pre-populate-only"),
+            "true".to_string(),
+        );
+    }
+    for secret in &vault.secrets {
+        annotations.insert(
+            format!("{PREFIX}/agent-inject-secret-{}", secret.name),
+            secret.path.clone(),
+        );
+        if let Some(template) = &secret.template {
+            annotations.insert(
+                format!("{PREFIX}/agent-inject-template-{}", secret.name),
+                template.clone(),
+            );
+        }
+    }
+    annotations.extend(vault.annotations.clone());
+    annotations
+}
+
+/// Build the StellarBeat reporting sidecar
+///
+/// The sidecar reads the current ledger from the local Stellar Core HTTP
+/// endpoint and posts it together with the validator's public key.
+fn build_stellar_beat_container(beat: &StellarBeatConfig) -> Container {
+    let env = |name: &str, value: String| EnvVar {
+        name: name.to_string(),
+        value: Some(value),
+        ..Default::default()
+    };
+
+    Container {
+        name: "stellar-beat-reporter".to_string(),
+        image: Some(beat.image.clone()),
+        env: Some(vec![
+            env("STELLAR_BEAT_API_URL", beat.api_url.clone()),
+            env("STELLAR_BEAT_PUBLIC_KEY", beat.public_key.clone()),
+            env("STELLAR_BEAT_HOME_DOMAIN", beat.home_domain.clone()),
+            env(
+                "STELLAR_BEAT_POLL_INTERVAL_SECS",
+                beat.poll_interval_secs.to_string(),
+            ),
+            env(
+                "STELLAR_CORE_INFO_URL",
+                "http://localhost:11626/info".to_string(),
+            ),
+        ]),
+        resources: Some(K8sResources {
+            requests: Some(BTreeMap::from([
+                ("cpu".to_string(), Quantity("10m".to_string())),
+                ("memory".to_string(), Quantity("32Mi".to_string())),
+            ])),
+            limits: Some(BTreeMap::from([
+                ("cpu".to_string(), Quantity("100m".to_string())),
+                ("memory".to_string(), Quantity("64Mi".to_string())),
+            ])),
+            claims: None,
+        }),
+        ..Default::default()
+    }
+}
+
+/// One `stellar-archivist gc` run, preceded by a dry run when requested
+const HISTORY_GC_SCRIPT: &str = r#"set -e
+if [ "$HISTORY_GC_DRY_RUN_FIRST" = "true" ]; then
+  stellar-archivist gc --dry-run --keep "$HISTORY_GC_RETENTION_COUNT" "$HISTORY_ARCHIVE_URL"
+fi
+stellar-archivist gc --keep "$HISTORY_GC_RETENTION_COUNT" "$HISTORY_ARCHIVE_URL"
+"#;
+
+/// Sidecar entrypoint: run `HISTORY_GC_RUN` on `HISTORY_GC_SCHEDULE` under supercronic
+const HISTORY_GC_SIDECAR_SCRIPT: &str = r#"echo "$HISTORY_GC_SCHEDULE /bin/sh -c \"\$HISTORY_GC_RUN\"" > /tmp/history-gc.crontab
+exec supercronic /tmp/history-gc.crontab
+"#;
+
+/// Enabled `historyGCPolicy` together with the archive it collects
+fn history_gc(node: &StellarNode) -> Option<(&HistoryGcConfig, &HistoryArchivePublishConfig)> {
+    if node.spec.node_type != NodeType::Validator {
+        return None;
+    }
+    let validator = node.spec.validator_config.as_ref()?;
+    let gc = validator.history_gc.as_ref().filter(|gc| gc.enabled)?;
+    Some((gc, validator.archive_publication_credentials.as_ref()?))
+}
+
+/// Build a container running history archive GC
+///
+/// Injects the archive URL from `archivePublicationCredentials`, plus the
+/// Azure SAS token when publishing to Azure Blob; S3 uses the pod's ambient
+/// credentials like the publish commands do.
+fn build_history_gc_container(
+    gc: &HistoryGcConfig,
+    publish: &HistoryArchivePublishConfig,
+    command: &str,
+    extra_env: Vec<EnvVar>,
+) -> Container {
+    let env = |name: &str, value: String| EnvVar {
+        name: name.to_string(),
+        value: Some(value),
+        ..Default::default()
+    };
+
+    let mut env_vars = vec![
+        env(
+            "HISTORY_ARCHIVE_URL",
+            publish.archive_url().unwrap_or_default(),
+        ),
+        env("HISTORY_GC_RETENTION_COUNT", gc.retention_count.to_string()),
+        env("HISTORY_GC_DRY_RUN_FIRST", gc.dry_run_first.to_string()),
+    ];
+    if let Some(azure) = &publish.azure_blob {
+        env_vars.push(env("AZURE_STORAGE_ACCOUNT", azure.account_name.clone()));
+        env_vars.push(EnvVar {
+            name: "AZURE_STORAGE_SAS_TOKEN".to_string(),
+            value: None,
+            value_from: Some(EnvVarSource {
+                secret_key_ref: Some(SecretKeySelector {
+                    name: Some(azure.sas_token_secret.clone()),
+                    key: "AZURE_STORAGE_SAS_TOKEN".to_string(),
+                    optional: Some(false),
+                }),
+                ..Default::default()
+            }),
+        });
+    }
+    env_vars.extend(extra_env);
+
+    Container {
+        name: "history-gc".to_string(),
+        image: Some(gc.image.clone()),
+        image_pull_policy: Some(default_image_pull_policy(&gc.image).to_string()),
+        command: Some(vec![
+            "/bin/sh".to_string(),
+            "-c".to_string(),
+            command.to_string(),
+        ]),
+        env: Some(env_vars),
+        resources: Some(K8sResources {
+            requests: Some(BTreeMap::from([
+                ("cpu".to_string(), Quantity("10m".to_string())),
+                ("memory".to_string(), Quantity("64Mi".to_string())),
+            ])),
+            limits: Some(BTreeMap::from([
+                ("cpu".to_string(), Quantity("500m".to_string())),
+                ("memory".to_string(), Quantity("256Mi".to_string())),
+            ])),
+            claims: None,
+        }),
+        ..Default::default()
+    }
+}
+
+/// Build the history archive GC sidecar
+///
+/// supercronic triggers the GC script on `gcSchedule` and passes the
+/// container environment on to each run.
+fn build_history_gc_sidecar(
+    gc: &HistoryGcConfig,
+    publish: &HistoryArchivePublishConfig,
+) -> Container {
+    let env = |name: &str, value: &str| EnvVar {
+        name: name.to_string(),
+        value: Some(value.to_string()),
+        ..Default::default()
+    };
+    build_history_gc_container(
+        gc,
+        publish,
+        HISTORY_GC_SIDECAR_SCRIPT,
+        vec![
+            env("HISTORY_GC_SCHEDULE", gc.gc_schedule.trim()),
+            env("HISTORY_GC_RUN", HISTORY_GC_SCRIPT),
+        ],
+    )
+}
+
+/// SQLite database of Soroban RPC on the data volume (`DATABASE_PATH`)
+const SOROBAN_SQLITE_DATABASE_PATH: &str = "/data/soroban.db";
+
+/// Where `horizonConfig.tls.certSecretRef` is mounted in the Horizon container
+const HORIZON_TLS_MOUNT_PATH: &str = "/etc/tls";
+
+/// Directory the GCS archival credentials secret is mounted into
+const ARCHIVAL_CREDENTIALS_MOUNT_PATH: &str = "/etc/stellar/archival";
+
+/// Where the history archive cache is mounted, used as Stellar Core's `TMP_DIR_PATH`
+pub const HISTORY_CACHE_MOUNT_PATH: &str = "/opt/stellar/history-cache";
+
+/// Secret holding the GCS service account key for Soroban state archival
+fn gcs_archival_credentials(node: &StellarNode) -> Option<&str> {
+    node.spec
+        .soroban_archival_storage()
+        .filter(|a| a.backend == ArchivalBackend::Gcs)?
+        .credentials_secret_ref
+        .as_deref()
+}
+
+/// Where `horizonConfig.tls.clientCaSecretRef` is mounted in the Horizon container
+const HORIZON_CLIENT_CA_MOUNT_PATH: &str = "/etc/tls-client-ca";
+
+/// Directory the offer filter rules ConfigMap is mounted into
+///
+/// Mounted without `subPath` so edits to the ConfigMap reach the running pod.
+const HORIZON_FILTER_RULES_DIR: &str = "/etc/horizon";
+
+/// ConfigMap key and file name of the offer filter rules
+const HORIZON_FILTER_RULES_FILE: &str = "filter-rules.json";
+
+/// A file of `horizonConfig.databaseSSL` mounted from a Secret
+struct PgSslFile<'a> {
+    volume: &'static str,
+    secret: &'a str,
+    key: &'static str,
+    mount_path: &'static str,
+    env_var: &'static str,
+}
+
+/// Files for `horizonConfig.databaseSSL`: the CA certificate from `VerifyCa` on,
+/// and the client certificate and key when both are set
+fn pg_ssl_files(node: &StellarNode) -> Vec<PgSslFile<'_>> {
+    let Some(ssl) = node.spec.horizon_database_ssl() else {
+        return Vec::new();
+    };
+    let mut files = Vec::new();
+    if let Some(ca) = ssl.ca_cert_secret_ref.as_deref() {
+        if ssl.mode.verifies_server() {
+            files.push(PgSslFile {
+                volume: "pg-ssl-ca",
+                secret: ca,
+                key: "ca.crt",
+                mount_path: "/etc/ssl/pg-ca.crt",
+                env_var: "PGSSLROOTCERT",
+            });
+        }
+    }
+    if let (Some(cert), Some(key)) = (
+        ssl.client_cert_secret_ref.as_deref(),
+        ssl.client_key_secret_ref.as_deref(),
+    ) {
+        files.push(PgSslFile {
+            volume: "pg-ssl-client-cert",
+            secret: cert,
+            key: "tls.crt",
+            mount_path: "/etc/ssl/pg-client.crt",
+            env_var: "PGSSLCERT",
+        });
+        files.push(PgSslFile {
+            volume: "pg-ssl-client-key",
+            secret: key,
+            key: "tls.key",
+            mount_path: "/etc/ssl/pg-client.key",
+            env_var: "PGSSLKEY",
+        });
+    }
+    files
+}
+
+fn build_container(node: &StellarNode, enable_mtls: bool) -> Container {
     let mut requests = BTreeMap::new();
     requests.insert(
         "cpu".to_string(),
@@ -1754,9 +2742,14 @@ fn build_container(node: &StellarNode, enable_mtls: bool) -> Container {
         Quantity(node.spec.resources.limits.memory.clone()),
     );
 
+    let horizon_tls = node.spec.horizon_tls();
     let (container_port, data_mount_path, db_env_var_name) = match node.spec.node_type {
         NodeType::Validator => (11625, "/opt/stellar/data", "DATABASE"),
-        NodeType::Horizon => (8000, "/data", "DATABASE_URL"),
+        NodeType::Horizon => (
+            horizon_tls.map_or(8000, |tls| i32::from(tls.port)),
+            "/data",
+            "DATABASE_URL",
+        ),
         NodeType::SorobanRpc => (8000, "/data", "DATABASE_URL"),
     };
 
@@ -1830,8 +2823,61 @@ fn build_container(node: &StellarNode, enable_mtls: bool) -> Container {
         });
     }
 
-    // Add TLS environment variables if mTLS is enabled
-    if enable_mtls {
+    // Soroban RPC event store: SQLite on the data volume or PostgreSQL from a Secret
+    if let NodeType::SorobanRpc = node.spec.node_type {
+        if let Some(soroban_config) = &node.spec.soroban_config {
+            match (
+                soroban_config.persistence_backend,
+                soroban_config.database_secret_ref.as_ref(),
+            ) {
+                (PersistenceBackend::SQLite, _) => env_vars.push(EnvVar {
+                    name: "DATABASE_PATH".to_string(),
+                    value: Some(SOROBAN_SQLITE_DATABASE_PATH.to_string()),
+                    ..Default::default()
+                }),
+                (PersistenceBackend::PostgreSQL, Some(secret)) => env_vars.push(EnvVar {
+                    name: "DATABASE_URL".to_string(),
+                    value: None,
+                    value_from: Some(EnvVarSource {
+                        secret_key_ref: Some(SecretKeySelector {
+                            name: Some(secret.clone()),
+                            key: "DATABASE_URL".to_string(),
+                            ..Default::default()
+                        }),
+                        ..Default::default()
+                    }),
+                }),
+                (PersistenceBackend::PostgreSQL, None) => {}
+            }
+        }
+    }
+
+    // Serve HTTPS from Horizon itself; takes the place of the operator mTLS files
+    if let Some(tls) = horizon_tls {
+        env_vars.push(EnvVar {
+            name: "PORT".to_string(),
+            value: Some(tls.port.to_string()),
+            ..Default::default()
+        });
+        env_vars.push(EnvVar {
+            name: "TLS_CERT_FILE".to_string(),
+            value: Some(format!("{HORIZON_TLS_MOUNT_PATH}/tls.crt")),
+            ..Default::default()
+        });
+        env_vars.push(EnvVar {
+            name: "TLS_KEY_FILE".to_string(),
+            value: Some(format!("{HORIZON_TLS_MOUNT_PATH}/tls.key")),
+            ..Default::default()
+        });
+        if tls.client_ca_secret_ref.is_some() {
+            env_vars.push(EnvVar {
+                name: "CA_CERT_FILE".to_string(),
+                value: Some(format!("{HORIZON_CLIENT_CA_MOUNT_PATH}/ca.crt")),
+                ..Default::default()
+            });
+        }
+    } else if enable_mtls {
+        // Add TLS environment variables if mTLS is enabled
         match node.spec.node_type {
             NodeType::Horizon | NodeType::SorobanRpc => {
                 env_vars.push(EnvVar {
@@ -1854,6 +2900,138 @@ fn build_container(node: &StellarNode, enable_mtls: bool) -> Container {
         }
     }
 
+    // TLS for Horizon's PostgreSQL connection
+    if let Some(ssl) = node.spec.horizon_database_ssl() {
+        env_vars.push(EnvVar {
+            name: "PGSSLMODE".to_string(),
+            value: Some(ssl.mode.as_libpq().to_string()),
+            ..Default::default()
+        });
+        for file in pg_ssl_files(node) {
+            env_vars.push(EnvVar {
+                name: file.env_var.to_string(),
+                value: Some(file.mount_path.to_string()),
+                ..Default::default()
+            });
+        }
+    }
+
+    // Cloud credentials for Soroban state archival
+    if let Some(archival) = node.spec.soroban_archival_storage() {
+        match (archival.backend, archival.credentials_secret_ref.as_ref()) {
+            (ArchivalBackend::S3, Some(secret)) => {
+                for key in ["AWS_ACCESS_KEY_ID", "AWS_SECRET_ACCESS_KEY"] {
+                    env_vars.push(EnvVar {
+                        name: key.to_string(),
+                        value: None,
+                        value_from: Some(EnvVarSource {
+                            secret_key_ref: Some(SecretKeySelector {
+                                name: Some(secret.clone()),
+                                key: key.to_string(),
+                                optional: Some(false),
+                            }),
+                            ..Default::default()
+                        }),
+                    });
+                }
+            }
+            (ArchivalBackend::Gcs, Some(_)) => {
+                env_vars.push(EnvVar {
+                    name: "GOOGLE_APPLICATION_CREDENTIALS".to_string(),
+                    value: Some(format!(
+                        "{ARCHIVAL_CREDENTIALS_MOUNT_PATH}/credentials.json"
+                    )),
+                    ..Default::default()
+                });
+            }
+            _ => {}
+        }
+    }
+
+    // Point the container at secrets rendered by the Vault agent
+    if let Some(vault) = &node.spec.vault {
+        for secret in &vault.secrets {
+            if let Some(env_var) = &secret.env_var {
+                env_vars.push(EnvVar {
+                    name: env_var.clone(),
+                    value: Some(secret.file_path()),
+                    ..Default::default()
+                });
+            }
+        }
+    }
+
+    // Horizon history/checkpoint tuning and CORS
+    if let Some(horizon_config) = &node.spec.horizon_config {
+        if let Some(frequency) = horizon_config.checkpoint_frequency {
+            env_vars.push(EnvVar {
+                name: "CHECKPOINT_FREQUENCY".to_string(),
+                value: Some(frequency.to_string()),
+                ..Default::default()
+            });
+        }
+        if let Some(count) = horizon_config.history_retention_count {
+            env_vars.push(EnvVar {
+                name: "HISTORY_RETENTION_COUNT".to_string(),
+                value: Some(count.to_string()),
+                ..Default::default()
+            });
+        }
+        // CORS for browser-based clients
+        if !horizon_config.cors_allowed_origins.is_empty() {
+            env_vars.push(EnvVar {
+                name: "CORS_ALLOWED_ORIGINS".to_string(),
+                value: Some(horizon_config.cors_allowed_origins.join(",")),
+                ..Default::default()
+            });
+        }
+
+        // DEX offer filtering
+        if node.spec.horizon_filter_rules_config_map().is_some() {
+            env_vars.push(EnvVar {
+                name: "FILTER_RULES_FILE".to_string(),
+                value: Some(format!(
+                    "{HORIZON_FILTER_RULES_DIR}/{HORIZON_FILTER_RULES_FILE}"
+                )),
+                ..Default::default()
+            });
+            if let Some(interval) = horizon_config
+                .offers_filtering
+                .as_ref()
+                .and_then(|f| f.update_interval_seconds)
+            {
+                env_vars.push(EnvVar {
+                    name: "FILTER_RULES_UPDATE_INTERVAL_SECONDS".to_string(),
+                    value: Some(interval.to_string()),
+                    ..Default::default()
+                });
+            }
+        }
+
+        // Compliance filtering of transactions
+        if let Some(filtering) = horizon_config
+            .transaction_filtering
+            .as_ref()
+            .filter(|f| f.enabled)
+        {
+            env_vars.push(EnvVar {
+                name: "TRANSACTION_FILTER_SERVICE_URL".to_string(),
+                value: Some(filtering.filter_service_url.clone()),
+                ..Default::default()
+            });
+            env_vars.push(EnvVar {
+                name: "TRANSACTION_FILTER_CACHE_TTL".to_string(),
+                value: Some(filtering.filter_cache_ttl_seconds.to_string()),
+                ..Default::default()
+            });
+            env_vars.push(EnvVar {
+                name: "FILTER_TRANSACTIONS_ON_INGEST".to_string(),
+                value: Some(filtering.filter_on_ingest.to_string()),
+                ..Default::default()
+            });
+        }
+    }
+
     // Add HSM environment variables and mounts
     let mut extra_volume_mounts = Vec::new();
     if let NodeType::Validator = node.spec.node_type {
@@ -1912,6 +3090,45 @@ fn build_container(node: &StellarNode, enable_mtls: bool) -> Container {
                     });
                 }
             }
+
+            // Azure Blob credentials for history archive publication
+            if let Some(azure) = validator_config
+                .archive_publication_credentials
+                .as_ref()
+                .and_then(|p| p.azure_blob.as_ref())
+            {
+                env_vars.push(EnvVar {
+                    name: "AZURE_STORAGE_ACCOUNT".to_string(),
+                    value: Some(azure.account_name.clone()),
+                    ..Default::default()
+                });
+                env_vars.push(EnvVar {
+                    name: "AZURE_STORAGE_SAS_TOKEN".to_string(),
+                    value: None,
+                    value_from: Some(EnvVarSource {
+                        secret_key_ref: Some(SecretKeySelector {
+                            name: Some(azure.sas_token_secret.clone()),
+                            key: "AZURE_STORAGE_SAS_TOKEN".to_string(),
+                            optional: Some(false),
+                        }),
+                        ..Default::default()
+                    }),
+                });
+            }
+
+            // StellarBeat identity for the metrics exporter
+            if let Some(beat) = &validator_config.stellar_beat_reporting {
+                env_vars.push(EnvVar {
+                    name: "STELLAR_BEAT_HOME_DOMAIN".to_string(),
+                    value: Some(beat.home_domain.clone()),
+                    ..Default::default()
+                });
+                env_vars.push(EnvVar {
+                    name: "STELLAR_BEAT_PUBLIC_KEY".to_string(),
+                    value: Some(beat.public_key.clone()),
+                    ..Default::default()
+                });
+            }
         }
     }
 
@@ -1951,12 +3168,86 @@ fn build_container(node: &StellarNode, enable_mtls: bool) -> Container {
         ..Default::default()
     });
 
+    // Mount the GCS archival credentials
+    if gcs_archival_credentials(node).is_some() {
+        volume_mounts.push(VolumeMount {
+            name: "archival-credentials".to_string(),
+            mount_path: ARCHIVAL_CREDENTIALS_MOUNT_PATH.to_string(),
+            read_only: Some(true),
+            ..Default::default()
+        });
+    }
+
+    // Mount Horizon's own TLS certificate and client CA
+    if let Some(tls) = horizon_tls {
+        volume_mounts.push(VolumeMount {
+            name: "horizon-tls".to_string(),
+            mount_path: HORIZON_TLS_MOUNT_PATH.to_string(),
+            read_only: Some(true),
+            ..Default::default()
+        });
+        if tls.client_ca_secret_ref.is_some() {
+            volume_mounts.push(VolumeMount {
+                name: "horizon-client-ca".to_string(),
+                mount_path: HORIZON_CLIENT_CA_MOUNT_PATH.to_string(),
+                read_only: Some(true),
+                ..Default::default()
+            });
+        }
+    }
+
+    // Mount the history archive cache where Stellar Core downloads into
+    if history_cache(node).is_some() {
+        volume_mounts.push(VolumeMount {
+            name: "history-cache".to_string(),
+            mount_path: HISTORY_CACHE_MOUNT_PATH.to_string(),
+            ..Default::default()
+        });
+    }
+
+    // Mount the Horizon offer filter rules
+    if node.spec.horizon_filter_rules_config_map().is_some() {
+        volume_mounts.push(VolumeMount {
+            name: "horizon-filter-rules".to_string(),
+            mount_path: HORIZON_FILTER_RULES_DIR.to_string(),
+            read_only: Some(true),
+            ..Default::default()
+        });
+    }
+
+    // Mount the PostgreSQL CA and client certificate files
+    for file in pg_ssl_files(node) {
+        volume_mounts.push(VolumeMount {
+            name: file.volume.to_string(),
+            mount_path: file.mount_path.to_string(),
+            sub_path: Some(file.key.to_string()),
+            read_only: Some(true),
+            ..Default::default()
+        });
+    }
+
     // Add extra mounts (HSM)
     volume_mounts.extend(extra_volume_mounts);
 
+    // Add user-requested mounts of operator-managed volumes (optionally via subPath)
+    volume_mounts.extend(node.spec.extra_volume_mounts.iter().map(|m| VolumeMount {
+        name: m.name.clone(),
+        mount_path: m.mount_path.clone(),
+        sub_path: m.sub_path.clone(),
+        read_only: Some(m.read_only),
+        ..Default::default()
+    }));
+
+    let image = node.spec.container_image();
+    let image_pull_policy = node
+        .spec
+        .image_pull_policy()
+        .unwrap_or_else(|| default_image_pull_policy(&image).to_string());
+
     Container {
         name: "stellar-node".to_string(),
-        image: Some(node.spec.container_image()),
+        image: Some(image),
+        image_pull_policy: Some(image_pull_policy),
         ports: Some(vec![ContainerPort {
             container_port,
             ..Default::default()
@@ -1965,15 +3256,113 @@ fn build_container(node: &StellarNode, enable_mtls: bool) -> Container {
         resources: Some(K8sResources {
             requests: Some(requests),
             limits: Some(limits),
-            claims: None,
+            claims: resource_claims(node),
         }),
         volume_mounts: Some(volume_mounts),
+        lifecycle: build_lifecycle(node),
+        liveness_probe: build_liveness_probe(node),
+        security_context: Some(build_security_context(node)),
+        termination_message_policy: node
+            .spec
+            .termination_message_policy
+            .map(|policy| policy.to_string()),
+        termination_message_path: node.spec.termination_message_path.clone().or_else(|| {
+            node.spec
+                .termination_message_policy
+                .map(|_| DEFAULT_TERMINATION_MESSAGE_PATH.to_string())
+        }),
+        ..Default::default()
+    }
+}
+
+/// Liveness probe for the main container
+///
+/// Only Soroban RPC gets one; it targets `rpcEndpoint` so a node serving a
+/// sub-path is probed where it actually listens.
+fn build_liveness_probe(node: &StellarNode) -> Option<Probe> {
+    if node.spec.node_type != NodeType::SorobanRpc {
+        return None;
+    }
+    let config = node.spec.soroban_config.as_ref()?;
+    Some(Probe {
+        http_get: Some(HTTPGetAction {
+            path: Some(config.rpc_path().to_string()),
+            port: IntOrString::Int(8000),
+            ..Default::default()
+        }),
+        initial_delay_seconds: Some(60),
+        period_seconds: Some(30),
+        failure_threshold: Some(5),
+        ..Default::default()
+    })
+}
+
+/// Pull policy for `image` when `imageOverride.pullPolicy` is not set
+///
+/// Digests and version-like tags are immutable, so a cached image is reused
+/// (`IfNotPresent`). Channel tags such as `latest` or `stable`, recognised by
+/// containing no digit, move over time and are always re-pulled (`Always`).
+fn default_image_pull_policy(image: &str) -> &'static str {
+    if image.contains('@') {
+        return "IfNotPresent";
+    }
+    let name = image.rsplit('/').next().unwrap_or(image);
+    let tag = name.split_once(':').map_or("latest", |(_, tag)| tag);
+    if tag.chars().any(|c| c.is_ascii_digit()) {
+        "IfNotPresent"
+    } else {
+        "Always"
+    }
+}
+
+/// Main container `securityContext`, dropping ALL capabilities unless configured
+fn build_security_context(node: &StellarNode) -> SecurityContext {
+    let names = |caps: &[LinuxCapability]| -> Option<Vec<String>> {
+        (!caps.is_empty()).then(|| caps.iter().map(|c| c.as_str().to_string()).collect())
+    };
+    let capabilities = match node.spec.container_security_context {
+        Some(ref cfg) => Capabilities {
+            add: names(&cfg.capabilities_add),
+            drop: names(&cfg.capabilities_drop),
+        },
+        None => Capabilities {
+            add: None,
+            drop: names(&[LinuxCapability::All]),
+        },
+    };
+    SecurityContext {
+        capabilities: Some(capabilities),
+        ..Default::default()
+    }
+}
+
+/// Container lifecycle from `spec.lifecycle`
+fn build_lifecycle(node: &StellarNode) -> Option<Lifecycle> {
+    let post_start = node.spec.lifecycle.as_ref()?.post_start.as_ref()?;
+    Some(Lifecycle {
+        post_start: Some(build_lifecycle_handler(post_start)),
+        ..Default::default()
+    })
+}
+
+fn build_lifecycle_handler(hook: &LifecycleHookSpec) -> LifecycleHandler {
+    LifecycleHandler {
+        exec: hook.exec.as_ref().map(|exec| ExecAction {
+            command: Some(exec.command.clone()),
+        }),
+        http_get: hook.http_get.as_ref().map(|http| HTTPGetAction {
+            path: Some(http.path.clone()),
+            port: IntOrString::Int(i32::from(http.port)),
+            host: http.host.clone(),
+            scheme: http.scheme.clone(),
+            ..Default::default()
+        }),
         ..Default::default()
     }
 }
 
 /// Build the migration container for Horizon
-fn build_horizon_migration_container(node: &StellarNode) -> Container {
+pub fn build_horizon_migration_container(node: &StellarNode) -> Container {
     let mut container = build_container(node, false);
     container.name = "horizon-db-migration".to_string();
     // Use a shell to try upgrade then init if needed, ensuring the DB is ready
@@ -1993,18 +3382,448 @@ fn build_horizon_migration_container(node: &StellarNode) -> Container {
     // Use slightly less resources for migration if desired, but reusing main ones is safer
     container
 }
+
+/// Image providing `pg_isready` for the database wait init container
+const DB_WAIT_IMAGE: &str = "postgres:16-alpine";
+
+/// Poll PostgreSQL with exponential backoff (1s doubling to 30s) until it
+/// accepts connections or `DB_WAIT_TIMEOUT_SECONDS` have passed
+const DB_WAIT_SCRIPT: &str = r#"deadline=$(( $(date +%s) + DB_WAIT_TIMEOUT_SECONDS ))
+delay=1
+until pg_isready -q -d "$DATABASE_URL"; do
+  if [ "$(date +%s)" -ge "$deadline" ]; then
+    echo "database not ready after ${DB_WAIT_TIMEOUT_SECONDS}s"
+    exit 1
+  fi
+  echo "database not ready, retrying in ${delay}s"
+  sleep "$delay"
+  delay=$(( delay * 2 ))
+  if [ "$delay" -gt 30 ]; then delay=30; fi
+done
+"#;
+
+/// Build the init container that holds Horizon until its database is reachable
+///
+/// Shares the main container's environment and mounts, so `DATABASE_URL` and
+/// the PostgreSQL TLS settings are the ones Horizon will use.
+fn build_db_wait_container(node: &StellarNode, timeout_seconds: u32) -> Container {
+    let main = build_container(node, false);
+    let mut env = main.env.unwrap_or_default();
+    env.push(EnvVar {
+        name: "DB_WAIT_TIMEOUT_SECONDS".to_string(),
+        value: Some(timeout_seconds.to_string()),
+        ..Default::default()
+    });
+
+    Container {
+        name: "wait-for-db".to_string(),
+        image: Some(DB_WAIT_IMAGE.to_string()),
+        command: Some(vec![
+            "/bin/sh".to_string(),
+            "-c".to_string(),
+            DB_WAIT_SCRIPT.to_string(),
+        ]),
+        env: Some(env),
+        volume_mounts: main.volume_mounts,
+        resources: Some(K8sResources {
+            requests: Some(BTreeMap::from([
+                ("cpu".to_string(), Quantity("10m".to_string())),
+                ("memory".to_string(), Quantity("16Mi".to_string())),
+            ])),
+            limits: Some(BTreeMap::from([
+                ("cpu".to_string(), Quantity("100m".to_string())),
+                ("memory".to_string(), Quantity("64Mi".to_string())),
+            ])),
+            claims: None,
+        }),
+        security_context: main.security_context,
+        ..Default::default()
+    }
+}
+
+/// Schema migration command of the Soroban RPC image
+const SOROBAN_MIGRATE_SCRIPT: &str = "soroban-rpc db migrate";
+
+/// Build the migration container for Soroban RPC with a PostgreSQL backend
+pub fn build_soroban_migration_container(node: &StellarNode) -> Container {
+    let mut container = build_container(node, false);
+    container.name = "soroban-db-migration".to_string();
+    container.command = Some(vec!["/bin/sh".to_string()]);
+    container.args = Some(vec!["-c".to_string(), SOROBAN_MIGRATE_SCRIPT.to_string()]);
+
+    // Migration doesn't need ports or probes
+    container.ports = None;
+    container.liveness_probe = None;
+    container.readiness_probe = None;
+    container.startup_probe = None;
+    container.lifecycle = None;
+    container
+}
+
+// ============================================================================
+// Database Migration Jobs
+// ============================================================================
+
+/// Name of a migration Job for this node, e.g. `<name>-migrate-v21-0-0`
+pub fn migration_job_name(node: &StellarNode, suffix: &str) -> String {
+    let sanitized: String = suffix
+        .to_lowercase()
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '-' })
+        .collect();
+    resource_name(node, &format!("migrate-{}", sanitized.trim_matches('-')))
+}
+
+/// Build a one-shot Job running a Horizon database migration command
+///
+/// The Job reuses the migration container (same image, env and database
+/// credentials as the workload) but only mounts the config volume, so it can
+/// run while the previous version still holds the data PVC.
+pub fn build_migration_job(node: &StellarNode, job_name: &str, script: &str) -> Job {
+    let mut labels = standard_labels(node);
+    labels.insert(
+        "app.kubernetes.io/component".to_string(),
+        "db-migration".to_string(),
+    );
+
+    let mut container = build_horizon_migration_container(node);
+    container.args = Some(vec!["-c".to_string(), script.to_string()]);
+    container.volume_mounts = container
+        .volume_mounts
+        .map(|mounts| mounts.into_iter().filter(|m| m.name == "config").collect());
+
+    Job {
+        metadata: merge_resource_meta(
+            ObjectMeta {
+                name: Some(job_name.to_string()),
+                namespace: node.namespace(),
+                labels: Some(labels.clone()),
+                owner_references: Some(vec![owner_reference(node)]),
+                ..Default::default()
+            },
+            &node.spec.resource_meta,
+        ),
+        spec: Some(JobSpec {
+            backoff_limit: Some(0),
+            template: PodTemplateSpec {
+                metadata: Some(ObjectMeta {
+                    labels: Some(labels),
+                    ..Default::default()
+                }),
+                spec: Some(PodSpec {
+                    restart_policy: Some("Never".to_string()),
+                    containers: vec![container],
+                    volumes: Some(vec![Volume {
+                        name: "config".to_string(),
+                        config_map: Some(k8s_openapi::api::core::v1::ConfigMapVolumeSource {
+                            name: Some(resource_name(node, "config")),
+                            ..Default::default()
+                        }),
+                        ..Default::default()
+                    }]),
+                    ..Default::default()
+                }),
+            },
+            ..Default::default()
+        }),
+        status: None,
+    }
+}
+
+/// Create the migration Job if it does not exist yet and return its current state
+///
+/// Jobs are immutable once created, so an existing Job is returned as-is.
+pub async fn ensure_migration_job(
+    client: &Client,
+    node: &StellarNode,
+    job_name: &str,
+    script: &str,
+) -> Result<Job> {
+    let namespace = node.namespace().unwrap_or_else(|| "default".to_string());
+    let api: Api<Job> = Api::namespaced(client.clone(), &namespace);
+
+    if let Some(job) = with_timeout("get Job", api.get_opt(job_name)).await? {
+        return Ok(job);
+    }
+
+    let job = build_migration_job(node, job_name, script);
+    let created = with_timeout("create Job", api.create(&PostParams::default(), &job)).await?;
+    info!("Created migration Job {}/{}", namespace, job_name);
+    Ok(created)
+}
+
+/// Delete a migration Job and its pods
+pub async fn delete_migration_job(
+    client: &Client,
+    node: &StellarNode,
+    job_name: &str,
+) -> Result<()> {
+    let namespace = node.namespace().unwrap_or_else(|| "default".to_string());
+    let api: Api<Job> = Api::namespaced(client.clone(), &namespace);
+
+    match with_timeout(
+        "delete Job",
+        api.delete(job_name, &DeleteParams::background()),
+    )
+    .await
+    {
+        Ok(_) => info!("Deleted migration Job {}/{}", namespace, job_name),
+        Err(Error::KubeError(kube::Error::Api(e))) if e.code == 404 => {}
+        Err(e) => return Err(e),
+    }
+    Ok(())
+}
+
+// ============================================================================
+// Precheck Hook Jobs
+// ============================================================================
+
+/// Pod template the workload would be patched to
+pub fn desired_pod_template(node: &StellarNode, enable_mtls: bool) -> PodTemplateSpec {
+    build_pod_template(node, &standard_labels(node), enable_mtls)
+}
+
+/// Name of the precheck Job for a spec generation, e.g. `<name>-precheck-7`
+pub fn precheck_job_name(node: &StellarNode, generation: i64) -> String {
+    resource_name(node, &format!("precheck-{generation}"))
+}
+
+/// Build a one-shot Job running `spec.precheckHook`
+///
+/// The Job is not retried and is killed by Kubernetes once the hook's
+/// timeout has passed, so a hanging check cannot outlive the operator's wait.
+pub fn build_precheck_job(node: &StellarNode, hook: &PrecheckHookConfig, job_name: &str) -> Job {
+    let mut labels = standard_labels(node);
+    labels.insert(
+        "app.kubernetes.io/component".to_string(),
+        "precheck".to_string(),
+    );
+
+    let container = Container {
+        name: "precheck".to_string(),
+        image: Some(hook.image.clone()),
+        image_pull_policy: Some(default_image_pull_policy(&hook.image).to_string()),
+        command: Some(hook.command.clone()),
+        env: Some(vec![
+            EnvVar {
+                name: "STELLAR_NODE_NAME".to_string(),
+                value: Some(node.name_any()),
+                ..Default::default()
+            },
+            EnvVar {
+                name: "STELLAR_TARGET_IMAGE".to_string(),
+                value: Some(node.spec.container_image()),
+                ..Default::default()
+            },
+        ]),
+        security_context: Some(build_security_context(node)),
+        ..Default::default()
+    };
+
+    Job {
+        metadata: merge_resource_meta(
+            ObjectMeta {
+                name: Some(job_name.to_string()),
+                namespace: node.namespace(),
+                labels: Some(labels.clone()),
+                owner_references: Some(vec![owner_reference(node)]),
+                ..Default::default()
+            },
+            &node.spec.resource_meta,
+        ),
+        spec: Some(JobSpec {
+            backoff_limit: Some(0),
+            active_deadline_seconds: Some(i64::from(hook.timeout_seconds)),
+            template: PodTemplateSpec {
+                metadata: Some(ObjectMeta {
+                    labels: Some(labels),
+                    ..Default::default()
+                }),
+                spec: Some(PodSpec {
+                    restart_policy: Some("Never".to_string()),
+                    containers: vec![container],
+                    ..Default::default()
+                }),
+            },
+            ..Default::default()
+        }),
+        status: None,
+    }
+}
+
+/// Create the precheck Job if it does not exist yet
+pub async fn ensure_precheck_job(
+    client: &Client,
+    node: &StellarNode,
+    hook: &PrecheckHookConfig,
+    job_name: &str,
+) -> Result<Job> {
+    let namespace = node.namespace().unwrap_or_else(|| "default".to_string());
+    let api: Api<Job> = Api::namespaced(client.clone(), &namespace);
+
+    if let Some(job) = with_timeout("get Job", api.get_opt(job_name)).await? {
+        return Ok(job);
+    }
+
+    let job = build_precheck_job(node, hook, job_name);
+    let created = with_timeout("create Job", api.create(&PostParams::default(), &job)).await?;
+    info!("Created precheck Job {}/{}", namespace, job_name);
+    Ok(created)
+}
+
+/// Delete a precheck Job and its pods
+pub async fn delete_precheck_job(
+    client: &Client,
+    node: &StellarNode,
+    job_name: &str,
+) -> Result<()> {
+    let namespace = node.namespace().unwrap_or_else(|| "default".to_string());
+    let api: Api<Job> = Api::namespaced(client.clone(), &namespace);
+
+    match with_timeout(
+        "delete Job",
+        api.delete(job_name, &DeleteParams::background()),
+    )
+    .await
+    {
+        Ok(_) => info!("Deleted precheck Job {}/{}", namespace, job_name),
+        Err(Error::KubeError(kube::Error::Api(e))) if e.code == 404 => {}
+        Err(e) => return Err(e),
+    }
+    Ok(())
+}
+
+// ============================================================================
+// History archive GC CronJob
+// ============================================================================
+
+/// Name of the history archive GC CronJob, `<name>-history-gc`
+pub fn history_gc_cron_job_name(node: &StellarNode) -> String {
+    resource_name(node, "history-gc")
+}
+
+/// Build the CronJob running history archive GC on `gcSchedule`
+///
+/// Returns `None` unless GC is enabled with `gcImplementation: CronJob`.
+/// Runs never overlap, so a slow GC is not raced by the next one.
+pub fn build_history_gc_cron_job(node: &StellarNode) -> Option<CronJob> {
+    let (gc, publish) = history_gc(node)?;
+    if gc.gc_implementation != GcImplementation::CronJob {
+        return None;
+    }
+
+    let mut labels = standard_labels(node);
+    labels.insert(
+        "app.kubernetes.io/component".to_string(),
+        "history-gc".to_string(),
+    );
+    let container = build_history_gc_container(gc, publish, HISTORY_GC_SCRIPT, vec![]);
+
+    Some(CronJob {
+        metadata: merge_resource_meta(
+            ObjectMeta {
+                name: Some(history_gc_cron_job_name(node)),
+                namespace: node.namespace(),
+                labels: Some(labels.clone()),
+                owner_references: Some(vec![owner_reference(node)]),
+                ..Default::default()
+            },
+            &node.spec.resource_meta,
+        ),
+        spec: Some(CronJobSpec {
+            schedule: gc.gc_schedule.trim().to_string(),
+            time_zone: Some("Etc/UTC".to_string()),
+            concurrency_policy: Some("Forbid".to_string()),
+            successful_jobs_history_limit: Some(3),
+            failed_jobs_history_limit: Some(3),
+            job_template: JobTemplateSpec {
+                metadata: Some(ObjectMeta {
+                    labels: Some(labels.clone()),
+                    ..Default::default()
+                }),
+                spec: Some(JobSpec {
+                    backoff_limit: Some(1),
+                    template: PodTemplateSpec {
+                        metadata: Some(ObjectMeta {
+                            labels: Some(labels),
+                            ..Default::default()
+                        }),
+                        spec: Some(PodSpec {
+                            restart_policy: Some("Never".to_string()),
+                            containers: vec![container],
+                            ..Default::default()
+                        }),
+                    },
+                    ..Default::default()
+                }),
+            },
+            ..Default::default()
+        }),
+        status: None,
+    })
+}
+
+/// Apply the history GC CronJob, or delete it when not configured
+pub async fn ensure_history_gc_cron_job(client: &Client, node: &StellarNode) -> Result<()> {
+    let Some(cron_job) = build_history_gc_cron_job(node) else {
+        return delete_history_gc_cron_job(client, node).await;
+    };
+
+    let namespace = node.namespace().unwrap_or_else(|| "default".to_string());
+    let api: Api<CronJob> = Api::namespaced(client.clone(), &namespace);
+    let name = history_gc_cron_job_name(node);
+
+    info!("Reconciling history GC CronJob {}/{}", namespace, name);
+    let params = PatchParams::apply("stellar-operator").force();
+    with_timeout(
+        "apply CronJob",
+        api.patch(&name, &params, &Patch::Apply(&cron_job)),
+    )
+    .await?;
+    Ok(())
+}
+
+/// Delete the history GC CronJob and its Jobs
+pub async fn delete_history_gc_cron_job(client: &Client, node: &StellarNode) -> Result<()> {
+    let namespace = node.namespace().unwrap_or_else(|| "default".to_string());
+    let api: Api<CronJob> = Api::namespaced(client.clone(), &namespace);
+    let name = history_gc_cron_job_name(node);
+
+    match with_timeout(
+        "delete CronJob",
+        api.delete(&name, &DeleteParams::background()),
+    )
+    .await
+    {
+        Ok(_) => info!("Deleted history GC CronJob {}/{}", namespace, name),
+        Err(Error::KubeError(kube::Error::Api(e))) if e.code == 404 => {}
+        Err(e) => return Err(e),
+    }
+    Ok(())
+}
+
 // ============================================================================
 // HorizontalPodAutoscaler
 // ============================================================================
 
 /// Ensure a HorizontalPodAutoscaler exists for RPC nodes with autoscaling enabled
-pub async fn ensure_hpa(client: &Client, node: &StellarNode) -> Result<()> {
-    // Only create HPA for Horizon and SorobanRpc nodes with autoscaling config
-    if !matches!(
-        node.spec.node_type,
-        NodeType::Horizon | NodeType::SorobanRpc
-    ) || node.spec.autoscaling.is_none()
-    {
+///
+/// `scaling_override` replaces the replica bounds while a cron override
+/// window is open (see `spec.autoscaling.cronOverrides`).
+/// `weekend_min_replicas` lowers minReplicas while the weekend window of
+/// `spec.autoscaling.disableOnWeekend` is open.
+/// `ingest_lag_throttling` drops minReplicas to 1 while Stellar Core is
+/// catching up on ingestion (see `spec.autoscaling.maxIngestLagSeconds`).
+pub async fn ensure_hpa(
+    client: &Client,
+    node: &StellarNode,
+    scaling_override: Option<&ActiveScalingOverride>,
+    weekend_min_replicas: Option<i32>,
+    ingest_lag_throttling: bool,
+    capacity_limit: Option<i32>,
+) -> Result<()> {
+    // Only create HPA for Horizon and SorobanRpc nodes with autoscaling enabled
+    if !node.spec.autoscaling_enabled() {
         return Ok(());
     }
 
@@ -2012,13 +3831,87 @@ pub async fn ensure_hpa(client: &Client, node: &StellarNode) -> Result<()> {
     let api: Api<HorizontalPodAutoscaler> = Api::namespaced(client.clone(), &namespace);
     let name = resource_name(node, "hpa");
 
-    let hpa = build_hpa(node)?;
+    let mut hpa = build_hpa(node)?;
+
+    // Scheduled bounds replace the configured ones for the override window;
+    // the first apply after the window closes restores them
+    if let Some(active) = scaling_override {
+        if let Some(spec) = hpa.spec.as_mut() {
+            info!(
+                "Cron scaling override '{}' active for {}/{} until {}; HPA bounds {}-{}",
+                active.cron_expression,
+                namespace,
+                name,
+                active.ends_at,
+                active.min_replicas,
+                active.max_replicas
+            );
+            spec.min_replicas = Some(active.min_replicas);
+            spec.max_replicas = active.max_replicas;
+        }
+    }
+
+    // Lower the floor over the weekend; a floor of 0 suspends the node instead,
+    // as the HPA itself cannot go below 1 replica
+    if let Some(min) = weekend_min_replicas.filter(|min| *min > 0) {
+        if let Some(spec) = hpa.spec.as_mut() {
+            info!(
+                "Weekend scaling active for {}/{}; lowering HPA minReplicas to {}",
+                namespace, name, min
+            );
+            spec.min_replicas = Some(min.min(spec.max_replicas));
+        }
+    }
+
+    // Do not scale beyond what the cluster's free memory can schedule
+    if let Some(limit) = capacity_limit {
+        if let Some(spec) = hpa.spec.as_mut() {
+            let capped = spec
+                .max_replicas
+                .min(limit)
+                .max(spec.min_replicas.unwrap_or(1));
+            if capped < spec.max_replicas {
+                info!(
+                    "Cluster capacity limits {}/{} to {} replicas (configured maxReplicas {})",
+                    namespace, name, capped, spec.max_replicas
+                );
+                spec.max_replicas = capped;
+            }
+        }
+    }
+
+    // Freeze scaling at the upper bound while a freshly deployed version warms up;
+    // the next apply after the cooldown restores the configured minReplicas
+    if autoscaling::in_post_deploy_cooldown(client, node).await? {
+        if let Some(spec) = hpa.spec.as_mut() {
+            info!(
+                "Post-deploy cooldown active for {}/{}; holding HPA at {} replicas",
+                namespace, name, spec.max_replicas
+            );
+            spec.min_replicas = Some(spec.max_replicas);
+        }
+    }
+
+    // Let the HPA scale in while Stellar Core catches up, so fewer replicas
+    // compete for its ingestion backend; this takes precedence over the freeze
+    if ingest_lag_throttling {
+        if let Some(spec) = hpa.spec.as_mut() {
+            info!(
+                "Ingest lag throttling active for {}/{}; lowering HPA minReplicas to 1",
+                namespace, name
+            );
+            spec.min_replicas = Some(1);
+        }
+    }
 
     let patch = Patch::Apply(&hpa);
-    api.patch(
-        &name,
-        &PatchParams::apply("stellar-operator").force(),
-        &patch,
+    with_timeout(
+        "apply HorizontalPodAutoscaler",
+        api.patch(
+            &name,
+            &PatchParams::apply("stellar-operator").force(),
+            &patch,
+        ),
     )
     .await?;
 
@@ -2031,11 +3924,14 @@ pub async fn ensure_hpa(client: &Client, node: &StellarNode) -> Result<()> {
 // ============================================================================
 
 /// Ensure alerting resources exist for the node if enabled
+///
+/// Removed once `prometheusRules` is set, which replaces the ConfigMap with a
+/// `PrometheusRule`.
 pub async fn ensure_alerting(client: &Client, node: &StellarNode) -> Result<()> {
     let namespace = node.namespace().unwrap_or_else(|| "default".to_string());
     let name = resource_name(node, "alerts");
 
-    if !node.spec.alerting {
+    if !node.spec.alerting_config_map_enabled() {
         return delete_alerting(client, node).await;
     }
 
@@ -2094,10 +3990,13 @@ pub async fn ensure_alerting(client: &Client, node: &StellarNode) -> Result<()>
 
     let api: Api<ConfigMap> = Api::namespaced(client.clone(), &namespace);
     let patch = Patch::Apply(&cm);
-    api.patch(
-        &name,
-        &PatchParams::apply("stellar-operator").force(),
-        &patch,
+    with_timeout(
+        "apply ConfigMap",
+        api.patch(
+            &name,
+            &PatchParams::apply("stellar-operator").force(),
+            &patch,
+        ),
     )
     .await?;
 
@@ -2110,6 +4009,404 @@ pub async fn ensure_alerting(client: &Client, node: &StellarNode) -> Result<()>
     Ok(())
 }
 
+/// API resource for Prometheus Operator `PrometheusRule` objects
+fn prometheus_rule_api_resource() -> ApiResource {
+    ApiResource {
+        group: "monitoring.coreos.com".to_string(),
+        version: "v1".to_string(),
+        api_version: "monitoring.coreos.com/v1".to_string(),
+        kind: "PrometheusRule".to_string(),
+        plural: "prometheusrules".to_string(),
+    }
+}
+
+/// Check whether the `PrometheusRule` CRD is served by the API server
+pub async fn prometheus_rule_crd_installed(client: &Client) -> Result<bool> {
+    crd_discovery::kind_served(client, "monitoring.coreos.com/v1", "PrometheusRule").await
+}
+
+/// Built-in alerts scoped to this node
+fn default_alert_rules(node: &StellarNode) -> Vec<serde_json::Value> {
+    let namespace = node.namespace().unwrap_or_else(|| "default".to_string());
+    let instance = node.name_any();
+    let selector = format!(r#"namespace="{namespace}",app_kubernetes_io_instance="{instance}""#);
+
+    let mut rules = vec![
+        serde_json::json!({
+            "alert": "StellarNodeDown",
+            "expr": format!("up{{{selector}}} == 0"),
+            "for": "5m",
+            "labels": { "severity": "critical" },
+            "annotations": {
+                "summary": format!("Stellar node {namespace}/{instance} is down"),
+                "description": format!("The Stellar node {namespace}/{instance} has been unreachable for more than 5 minutes."),
+            },
+        }),
+        serde_json::json!({
+            "alert": "StellarNodeIngestionLagHigh",
+            "expr": format!(r#"stellar_node_ingestion_lag{{namespace="{namespace}",name="{instance}"}} > 10"#),
+            "for": "10m",
+            "labels": { "severity": "warning" },
+            "annotations": {
+                "summary": format!("Stellar node {namespace}/{instance} is lagging behind the network"),
+                "description": format!("The Stellar node {namespace}/{instance} has been more than 10 ledgers behind for 10 minutes."),
+            },
+        }),
+    ];
+
+    match node.spec.node_type {
+        NodeType::Validator => {
+            rules.push(serde_json::json!({
+                "alert": "StellarNodeQuorumUnhealthy",
+                "expr": format!("stellar_core_quorum_transitive_intersection{{{selector}}} == 0"),
+                "for": "5m",
+                "labels": { "severity": "critical" },
+                "annotations": {
+                    "summary": format!("Stellar validator {namespace}/{instance} quorum is unhealthy"),
+                    "description": format!("The quorum set of validator {namespace}/{instance} has lost transitive intersection."),
+                },
+            }));
+            rules.push(serde_json::json!({
+                "alert": "StellarValidatorBehind",
+                "expr": format!("stellar_core_ledger_age_current_seconds{{{selector}}} > 30"),
+                "for": "5m",
+                "labels": { "severity": "critical" },
+                "annotations": {
+                    "summary": format!("Stellar validator {namespace}/{instance} is not closing ledgers"),
+                    "description": format!("The last ledger closed by validator {namespace}/{instance} is more than 30 seconds old."),
+                },
+            }));
+        }
+        NodeType::Horizon => {
+            rules.push(serde_json::json!({
+                "alert": "HorizonHighLatency",
+                "expr": format!(
+                    "sum(rate(horizon_http_requests_duration_seconds_sum{{{selector}}}[5m])) / sum(rate(horizon_http_requests_duration_seconds_count{{{selector}}}[5m])) > 1"
+                ),
+                "for": "10m",
+                "labels": { "severity": "warning" },
+                "annotations": {
+                    "summary": format!("Horizon {namespace}/{instance} is responding slowly"),
+                    "description": format!("Average Horizon request latency for {namespace}/{instance} has been above 1s for 10 minutes."),
+                },
+            }));
+        }
+        NodeType::SorobanRpc => {
+            rules.push(serde_json::json!({
+                "alert": "SorobanRpcDown",
+                "expr": format!("absent(up{{{selector}}} == 1)"),
+                "for": "2m",
+                "labels": { "severity": "critical" },
+                "annotations": {
+                    "summary": format!("Soroban RPC {namespace}/{instance} is down"),
+                    "description": format!("No healthy Soroban RPC replica of {namespace}/{instance} has been scraped for 2 minutes."),
+                },
+            }));
+        }
+    }
+
+    rules
+}
+
+/// Build a `PrometheusRule` for this node
+///
+/// Contains the built-in alerts (unless `prometheusRules.enableDefaultRules` is
+/// false) followed by any `prometheusRules.extraRules`.
+pub fn build_prometheus_rule(node: &StellarNode) -> DynamicObject {
+    let namespace = node.namespace().unwrap_or_else(|| "default".to_string());
+    let instance = node.name_any();
+    let config = node.spec.prometheus_rules.as_ref();
+
+    let mut rules = if config.map(|c| c.enable_default_rules).unwrap_or(true) {
+        default_alert_rules(node)
+    } else {
+        Vec::new()
+    };
+    if let Some(config) = config {
+        rules.extend(
+            config
+                .extra_rules
+                .iter()
+                .map(|rule| serde_json::to_value(rule).unwrap_or_default()),
+        );
+    }
+
+    let api_resource = prometheus_rule_api_resource();
+    let mut rule =
+        DynamicObject::new(&resource_name(node, "rules"), &api_resource).within(&namespace);
+    rule.metadata = merge_resource_meta(
+        ObjectMeta {
+            name: Some(resource_name(node, "rules")),
+            namespace: Some(namespace),
+            labels: Some(standard_labels(node)),
+            owner_references: Some(vec![owner_reference(node)]),
+            ..Default::default()
+        },
+        &node.spec.resource_meta,
+    );
+    rule.data = serde_json::json!({
+        "spec": {
+            "groups": [{
+                "name": format!("{instance}.rules"),
+                "rules": rules,
+            }]
+        }
+    });
+
+    rule
+}
+
+/// Ensure the per-node `PrometheusRule` exists when enabled
+///
+/// Skipped (with a warning) if the Prometheus Operator CRDs are missing.
+pub async fn ensure_prometheus_rule(client: &Client, node: &StellarNode) -> Result<()> {
+    if !node.spec.prometheus_rule_enabled() {
+        return delete_prometheus_rule(client, node).await;
+    }
+
+    if !prometheus_rule_crd_installed(client).await? {
+        warn!(
+            "PrometheusRule CRD not installed; skipping alert rules for {}/{}",
+            node.namespace().unwrap_or_default(),
+            node.name_any()
+        );
+        return Ok(());
+    }
+
+    let namespace = node.namespace().unwrap_or_else(|| "default".to_string());
+    let name = resource_name(node, "rules");
+    let rule = build_prometheus_rule(node);
+
+    let api: Api<DynamicObject> =
+        Api::namespaced_with(client.clone(), &namespace, &prometheus_rule_api_resource());
+    with_timeout(
+        "apply PrometheusRule",
+        api.patch(
+            &name,
+            &PatchParams::apply("stellar-operator").force(),
+            &Patch::Apply(&rule),
+        ),
+    )
+    .await?;
+
+    info!(
+        "PrometheusRule {} ensured for {}/{}",
+        name,
+        namespace,
+        node.name_any()
+    );
+    Ok(())
+}
+
+/// Delete the per-node `PrometheusRule`
+pub async fn delete_prometheus_rule(client: &Client, node: &StellarNode) -> Result<()> {
+    if !prometheus_rule_crd_installed(client).await? {
+        return Ok(());
+    }
+
+    let namespace = node.namespace().unwrap_or_else(|| "default".to_string());
+    let name = resource_name(node, "rules");
+
+    let api: Api<DynamicObject> =
+        Api::namespaced_with(client.clone(), &namespace, &prometheus_rule_api_resource());
+    match with_timeout(
+        "delete PrometheusRule",
+        api.delete(&name, &DeleteParams::default()),
+    )
+    .await
+    {
+        Ok(_) => info!("Deleted PrometheusRule {}", name),
+        Err(Error::KubeError(kube::Error::Api(e))) if e.code == 404 => {
+            // Already gone
+        }
+        Err(e) => return Err(e),
+    }
+
+    Ok(())
+}
+
+// ============================================================================
+// Grafana Dashboards
+// ============================================================================
+
+/// Label Grafana's dashboard sidecar selects ConfigMaps by
+pub const GRAFANA_DASHBOARD_LABEL: &str = "grafana_dashboard";
+
+/// Annotation Grafana's dashboard sidecar reads the target folder from
+pub const GRAFANA_FOLDER_ANNOTATION: &str = "grafana_folder";
+
+/// Label tying a dashboard ConfigMap (possibly in another namespace) to its node
+const DASHBOARD_FOR_LABEL: &str = "stellar.org/dashboard-for";
+
+const VALIDATOR_DASHBOARD: &str = include_str!("../../assets/dashboards/validator.json");
+const HORIZON_DASHBOARD: &str = include_str!("../../assets/dashboards/horizon.json");
+const SOROBAN_DASHBOARD: &str = include_str!("../../assets/dashboards/soroban.json");
+
+/// Name of the dashboard ConfigMap, unique across node namespaces
+pub fn grafana_dashboard_name(node: &StellarNode) -> String {
+    format!(
+        "stellar-{}-{}-dashboard",
+        node.namespace().unwrap_or_else(|| "default".to_string()),
+        node.name_any()
+    )
+}
+
+/// `<namespace>.<name>` value of the `stellar.org/dashboard-for` label
+fn dashboard_owner_label(node: &StellarNode) -> String {
+    format!(
+        "{}.{}",
+        node.namespace().unwrap_or_else(|| "default".to_string()),
+        node.name_any()
+    )
+}
+
+/// Dashboard JSON for the node's type, scoped to this node
+///
+/// Sets a per-node `uid` and title and fills in the hidden `namespace` and
+/// `instance` template constants the panel queries filter on.
+pub fn grafana_dashboard_json(node: &StellarNode) -> String {
+    let namespace = node.namespace().unwrap_or_else(|| "default".to_string());
+    let name = node.name_any();
+    let template = match node.spec.node_type {
+        NodeType::Validator => VALIDATOR_DASHBOARD,
+        NodeType::Horizon => HORIZON_DASHBOARD,
+        NodeType::SorobanRpc => SOROBAN_DASHBOARD,
+    };
+
+    let Ok(mut dashboard) = serde_json::from_str::<serde_json::Value>(template) else {
+        return template.to_string();
+    };
+    // Grafana limits dashboard uids to 40 characters
+    let uid: String = format!("stellar-{namespace}-{name}")
+        .chars()
+        .take(40)
+        .collect();
+    let title = format!(
+        "{} ({namespace}/{name})",
+        dashboard["title"].as_str().unwrap_or("Stellar")
+    );
+    dashboard["uid"] = serde_json::Value::String(uid);
+    dashboard["title"] = serde_json::Value::String(title);
+    if let Some(variables) = dashboard["templating"]["list"].as_array_mut() {
+        for variable in variables {
+            let value = match variable["name"].as_str() {
+                Some("namespace") => namespace.clone(),
+                Some("instance") => name.clone(),
+                _ => continue,
+            };
+            variable["query"] = serde_json::Value::String(value);
+        }
+    }
+    serde_json::to_string_pretty(&dashboard).unwrap_or_else(|_| template.to_string())
+}
+
+/// Build the dashboard ConfigMap picked up by Grafana's sidecar
+///
+/// The owner reference is only set when Grafana runs in the node's namespace;
+/// Kubernetes garbage collection does not follow cross-namespace owners.
+pub fn build_grafana_dashboard(node: &StellarNode, config: &GrafanaDashboardConfig) -> ConfigMap {
+    let mut labels = config.dashboard_labels.clone();
+    labels.extend(standard_labels(node));
+    labels.insert(GRAFANA_DASHBOARD_LABEL.to_string(), "1".to_string());
+    labels.insert(DASHBOARD_FOR_LABEL.to_string(), dashboard_owner_label(node));
+
+    let same_namespace = node.namespace().as_deref() == Some(config.namespace.as_str());
+
+    ConfigMap {
+        metadata: ObjectMeta {
+            name: Some(grafana_dashboard_name(node)),
+            namespace: Some(config.namespace.clone()),
+            labels: Some(labels),
+            annotations: Some(BTreeMap::from([(
+                GRAFANA_FOLDER_ANNOTATION.to_string(),
+                config.folder.clone(),
+            )])),
+            owner_references: same_namespace.then(|| vec![owner_reference(node)]),
+            ..Default::default()
+        },
+        data: Some(BTreeMap::from([(
+            format!("{}.json", grafana_dashboard_name(node)),
+            grafana_dashboard_json(node),
+        )])),
+        ..Default::default()
+    }
+}
+
+/// Ensure the Grafana dashboard ConfigMap exists when configured
+///
+/// Dashboards left in other namespaces (after `grafanaDashboard.namespace`
+/// changed or the config was removed) are deleted.
+pub async fn ensure_grafana_dashboard(client: &Client, node: &StellarNode) -> Result<()> {
+    let target_namespace = node
+        .spec
+        .grafana_dashboard
+        .as_ref()
+        .map(|c| c.namespace.as_str());
+    delete_grafana_dashboards_except(client, node, target_namespace).await?;
+
+    let Some(config) = node.spec.grafana_dashboard.as_ref() else {
+        return Ok(());
+    };
+
+    let name = grafana_dashboard_name(node);
+    let api: Api<ConfigMap> = Api::namespaced(client.clone(), &config.namespace);
+    with_timeout(
+        "apply ConfigMap",
+        api.patch(
+            &name,
+            &PatchParams::apply("stellar-operator").force(),
+            &Patch::Apply(&build_grafana_dashboard(node, config)),
+        ),
+    )
+    .await?;
+
+    info!(
+        "Grafana dashboard {}/{} ensured for {}",
+        config.namespace,
+        name,
+        node.name_any()
+    );
+    Ok(())
+}
+
+/// Delete this node's Grafana dashboard ConfigMaps in every namespace
+pub async fn delete_grafana_dashboard(client: &Client, node: &StellarNode) -> Result<()> {
+    delete_grafana_dashboards_except(client, node, None).await
+}
+
+async fn delete_grafana_dashboards_except(
+    client: &Client,
+    node: &StellarNode,
+    keep_namespace: Option<&str>,
+) -> Result<()> {
+    let all: Api<ConfigMap> = Api::all(client.clone());
+    let lp = ListParams::default().labels(&format!(
+        "{}={}",
+        DASHBOARD_FOR_LABEL,
+        dashboard_owner_label(node)
+    ));
+    for cm in with_timeout("list ConfigMaps", all.list(&lp)).await?.items {
+        let Some(namespace) = cm.namespace() else {
+            continue;
+        };
+        if Some(namespace.as_str()) == keep_namespace {
+            continue;
+        }
+        let api: Api<ConfigMap> = Api::namespaced(client.clone(), &namespace);
+        match with_timeout(
+            "delete ConfigMap",
+            api.delete(&cm.name_any(), &DeleteParams::default()),
+        )
+        .await
+        {
+            Ok(_) => info!("Deleted Grafana dashboard {}/{}", namespace, cm.name_any()),
+            Err(Error::KubeError(kube::Error::Api(e))) if e.code == 404 => {}
+            Err(e) => return Err(e),
+        }
+    }
+    Ok(())
+}
+
 fn build_hpa(node: &StellarNode) -> Result<HorizontalPodAutoscaler> {
     let autoscaling = node
         .spec
@@ -2117,6 +4414,14 @@ fn build_hpa(node: &StellarNode) -> Result<HorizontalPodAutoscaler> {
         .as_ref()
         .ok_or_else(|| Error::ValidationError("Autoscaling config not found".to_string()))?;
 
+    // Guard against specs that bypassed validation; an inverted HPA flaps erratically
+    if autoscaling.min_replicas < 1 || autoscaling.min_replicas > autoscaling.max_replicas {
+        return Err(Error::ValidationError(format!(
+            "Invalid autoscaling bounds: minReplicas={} maxReplicas={} (need 1 <= minReplicas <= maxReplicas)",
+            autoscaling.min_replicas, autoscaling.max_replicas
+        )));
+    }
+
     let namespace = node.namespace().unwrap_or_else(|| "default".to_string());
     let name = resource_name(node, "hpa");
     let deployment_name = node.name_any();
@@ -2166,7 +4471,20 @@ fn build_hpa(node: &StellarNode) -> Result<HorizontalPodAutoscaler> {
         // Add more custom metrics mapping here (e.g., request throughput)
     }
 
-    let behavior = autoscaling
+    // Add the active-connections metric
+    metrics.extend(super::autoscaling::active_connections_metric_spec(
+        autoscaling,
+    ));
+
+    // Add metrics exposed by custom API resources
+    metrics.extend(
+        autoscaling
+            .custom_resource_metrics
+            .iter()
+            .map(|metric| super::autoscaling::custom_resource_metric_spec(node, metric)),
+    );
+
+    let mut behavior = autoscaling
         .behavior
         .as_ref()
         .map(|b| HorizontalPodAutoscalerBehavior {
@@ -2200,6 +4518,25 @@ fn build_hpa(node: &StellarNode) -> Result<HorizontalPodAutoscaler> {
             }),
         });
 
+    // Add the capacity-aware scale-up step for heterogeneous node pools
+    let weighted_policies = autoscaling::weighted_scale_up_policies(
+        autoscaling,
+        node.status
+            .as_ref()
+            .and_then(|status| status.effective_capacity_units),
+    );
+    if !weighted_policies.is_empty() {
+        let behavior = behavior.get_or_insert_with(Default::default);
+        let scale_up = behavior.scale_up.get_or_insert_with(|| HPAScalingRules {
+            select_policy: Some("Max".to_string()),
+            ..Default::default()
+        });
+        scale_up
+            .policies
+            .get_or_insert_with(Vec::new)
+            .extend(weighted_policies);
+    }
+
     let hpa = HorizontalPodAutoscaler {
         metadata: merge_resource_meta(
             ObjectMeta {
@@ -2234,20 +4571,20 @@ fn build_hpa(node: &StellarNode) -> Result<HorizontalPodAutoscaler> {
 
 /// Delete the HPA when node is deleted
 pub async fn delete_hpa(client: &Client, node: &StellarNode) -> Result<()> {
-    // Only delete HPA if autoscaling was configured
-    if node.spec.autoscaling.is_none() {
-        return Ok(());
-    }
-
     let namespace = node.namespace().unwrap_or_else(|| "default".to_string());
     let api: Api<HorizontalPodAutoscaler> = Api::namespaced(client.clone(), &namespace);
     let name = resource_name(node, "hpa");
 
-    match api.delete(&name, &DeleteParams::default()).await {
+    match with_timeout(
+        "delete HorizontalPodAutoscaler",
+        api.delete(&name, &DeleteParams::default()),
+    )
+    .await
+    {
         Ok(_) => {
             info!("HPA deleted for {}/{}", namespace, name);
         }
-        Err(kube::Error::Api(api_err)) if api_err.code == 404 => {
+        Err(Error::KubeError(kube::Error::Api(api_err))) if api_err.code == 404 => {
             info!("HPA {}/{} not found (already deleted)", namespace, name);
         }
         Err(e) => {
@@ -2258,55 +4595,173 @@ pub async fn delete_hpa(client: &Client, node: &StellarNode) -> Result<()> {
     Ok(())
 }
 
+/// Fetch the managed HPA, if it exists
+/// Fetch the node's Ingress, if it exists
+pub async fn get_ingress(client: &Client, node: &StellarNode) -> Result<Option<Ingress>> {
+    let namespace = node.namespace().unwrap_or_else(|| "default".to_string());
+    let api: Api<Ingress> = Api::namespaced(client.clone(), &namespace);
+    with_timeout("get Ingress", api.get_opt(&resource_name(node, "ingress"))).await
+}
+
+/// Fetch the node's NetworkPolicy, if it exists
+pub async fn get_network_policy(
+    client: &Client,
+    node: &StellarNode,
+) -> Result<Option<NetworkPolicy>> {
+    let namespace = node.namespace().unwrap_or_else(|| "default".to_string());
+    let api: Api<NetworkPolicy> = Api::namespaced(client.clone(), &namespace);
+    with_timeout(
+        "get NetworkPolicy",
+        api.get_opt(&resource_name(node, "netpol")),
+    )
+    .await
+}
+
+pub async fn get_hpa(
+    client: &Client,
+    node: &StellarNode,
+) -> Result<Option<HorizontalPodAutoscaler>> {
+    let namespace = node.namespace().unwrap_or_else(|| "default".to_string());
+    let api: Api<HorizontalPodAutoscaler> = Api::namespaced(client.clone(), &namespace);
+    with_timeout(
+        "get HorizontalPodAutoscaler",
+        api.get_opt(&resource_name(node, "hpa")),
+    )
+    .await
+}
+
 // ============================================================================
 // ServiceMonitor (Prometheus Operator)
 // ============================================================================
 
-/// Ensure a ServiceMonitor exists for Prometheus scraping (Prometheus Operator)
-///
-/// ServiceMonitor is a custom resource from the Prometheus Operator.
-/// Users should manually create ServiceMonitor resources or use a tool like
-/// kustomize/helm to generate them. This function documents the capability.
-pub async fn ensure_service_monitor(_client: &Client, node: &StellarNode) -> Result<()> {
-    // Only log for Horizon and SorobanRpc nodes with autoscaling config
-    if !matches!(
-        node.spec.node_type,
-        NodeType::Horizon | NodeType::SorobanRpc
-    ) || node.spec.autoscaling.is_none()
-    {
-        return Ok(());
+/// API resource for Prometheus Operator `ServiceMonitor` objects
+fn service_monitor_api_resource() -> ApiResource {
+    ApiResource {
+        group: "monitoring.coreos.com".to_string(),
+        version: "v1".to_string(),
+        api_version: "monitoring.coreos.com/v1".to_string(),
+        kind: "ServiceMonitor".to_string(),
+        plural: "servicemonitors".to_string(),
     }
+}
+
+/// Check whether the `ServiceMonitor` CRD is served by the API server
+pub async fn service_monitor_crd_installed(client: &Client) -> Result<bool> {
+    crd_discovery::kind_served(client, "monitoring.coreos.com/v1", "ServiceMonitor").await
+}
 
+/// Build a `ServiceMonitor` scraping `/metrics` on the node's `http` port
+///
+/// `monitoring.scrapeInterval` / `scrapeTimeout` are set on the endpoint when
+/// given; otherwise Prometheus applies its global defaults.
+pub fn build_service_monitor(node: &StellarNode) -> DynamicObject {
     let namespace = node.namespace().unwrap_or_else(|| "default".to_string());
     let name = resource_name(node, "service-monitor");
+    let monitoring = node.spec.monitoring.as_ref();
 
-    info!(
-        "ServiceMonitor configuration available for {}/{}. Users should manually create the ServiceMonitor resource.",
-        namespace, name
+    let mut endpoint = serde_json::json!({
+        "port": "http",
+        "path": "/metrics",
+    });
+    if let Some(interval) = monitoring.and_then(|m| m.scrape_interval.as_deref()) {
+        endpoint["interval"] = interval.trim().into();
+    }
+    if let Some(timeout) = monitoring.and_then(|m| m.scrape_timeout.as_deref()) {
+        endpoint["scrapeTimeout"] = timeout.trim().into();
+    }
+
+    let mut monitor = DynamicObject::new(&name, &service_monitor_api_resource()).within(&namespace);
+    monitor.metadata = merge_resource_meta(
+        ObjectMeta {
+            name: Some(name),
+            namespace: Some(namespace.clone()),
+            labels: Some(standard_labels(node)),
+            owner_references: Some(vec![owner_reference(node)]),
+            ..Default::default()
+        },
+        &node.spec.resource_meta,
     );
+    monitor.data = serde_json::json!({
+        "spec": {
+            "selector": {
+                "matchLabels": {
+                    "app.kubernetes.io/name": "stellar-node",
+                    "app.kubernetes.io/instance": node.name_any(),
+                },
+            },
+            "namespaceSelector": { "matchNames": [namespace] },
+            "endpoints": [endpoint],
+        }
+    });
+
+    monitor
+}
+
+/// Ensure the per-node `ServiceMonitor` exists when `monitoring` is enabled
+///
+/// Skipped (with a warning) if the Prometheus Operator CRDs are missing.
+pub async fn ensure_service_monitor(client: &Client, node: &StellarNode) -> Result<()> {
+    if !node.spec.service_monitor_enabled() {
+        return delete_service_monitor(client, node).await;
+    }
+
+    if !service_monitor_crd_installed(client).await? {
+        warn!(
+            "ServiceMonitor CRD not installed; skipping scrape config for {}/{}",
+            node.namespace().unwrap_or_default(),
+            node.name_any()
+        );
+        return Ok(());
+    }
+
+    let namespace = node.namespace().unwrap_or_else(|| "default".to_string());
+    let name = resource_name(node, "service-monitor");
+    let monitor = build_service_monitor(node);
+
+    let api: Api<DynamicObject> =
+        Api::namespaced_with(client.clone(), &namespace, &service_monitor_api_resource());
+    with_timeout(
+        "apply ServiceMonitor",
+        api.patch(
+            &name,
+            &PatchParams::apply("stellar-operator").force(),
+            &Patch::Apply(&monitor),
+        ),
+    )
+    .await?;
 
     info!(
-        "ServiceMonitor should scrape metrics on port 'http' at path '/metrics' from service: {}",
+        "ServiceMonitor {} ensured for {}/{}",
+        name,
+        namespace,
         node.name_any()
     );
-
     Ok(())
 }
 
-/// Delete the ServiceMonitor when node is deleted
-pub async fn delete_service_monitor(_client: &Client, node: &StellarNode) -> Result<()> {
-    // Only delete ServiceMonitor if autoscaling was configured
-    if node.spec.autoscaling.is_none() {
+/// Delete the per-node `ServiceMonitor`
+pub async fn delete_service_monitor(client: &Client, node: &StellarNode) -> Result<()> {
+    if !service_monitor_crd_installed(client).await? {
         return Ok(());
     }
 
     let namespace = node.namespace().unwrap_or_else(|| "default".to_string());
     let name = resource_name(node, "service-monitor");
 
-    info!(
-        "Note: ServiceMonitor {}/{} must be manually deleted if it was created",
-        namespace, name
-    );
+    let api: Api<DynamicObject> =
+        Api::namespaced_with(client.clone(), &namespace, &service_monitor_api_resource());
+    match with_timeout(
+        "delete ServiceMonitor",
+        api.delete(&name, &DeleteParams::default()),
+    )
+    .await
+    {
+        Ok(_) => info!("Deleted ServiceMonitor {}", name),
+        Err(Error::KubeError(kube::Error::Api(e))) if e.code == 404 => {
+            // Already gone
+        }
+        Err(e) => return Err(e),
+    }
 
     Ok(())
 }
@@ -2317,12 +4772,17 @@ pub async fn delete_alerting(client: &Client, node: &StellarNode) -> Result<()>
     let name = resource_name(node, "alerts");
 
     let api: Api<ConfigMap> = Api::namespaced(client.clone(), &namespace);
-    match api.delete(&name, &DeleteParams::default()).await {
+    match with_timeout(
+        "delete ConfigMap",
+        api.delete(&name, &DeleteParams::default()),
+    )
+    .await
+    {
         Ok(_) => info!("Deleted alerting ConfigMap {}", name),
-        Err(kube::Error::Api(e)) if e.code == 404 => {
+        Err(Error::KubeError(kube::Error::Api(e))) if e.code == 404 => {
             // Already gone
         }
-        Err(e) => return Err(Error::KubeError(e)),
+        Err(e) => return Err(e),
     }
 
     Ok(())
@@ -2337,18 +4797,28 @@ pub async fn delete_canary_resources(client: &Client, node: &StellarNode) -> Res
     // 1. Delete Canary Ingress
     if node.spec.ingress.is_some() {
         let api: Api<Ingress> = Api::namespaced(client.clone(), &namespace);
-        let _ = api.delete(&canary_name, &DeleteParams::default()).await;
+        let _ = with_timeout(
+            "delete Ingress",
+            api.delete(&canary_name, &DeleteParams::default()),
+        )
+        .await;
     }
 
     // 2. Delete Canary Service
     let api_svc: Api<Service> = Api::namespaced(client.clone(), &namespace);
-    let _ = api_svc.delete(&canary_name, &DeleteParams::default()).await;
+    let _ = with_timeout(
+        "delete Service",
+        api_svc.delete(&canary_name, &DeleteParams::default()),
+    )
+    .await;
 
     // 3. Delete Canary Deployment
     let api_deploy: Api<Deployment> = Api::namespaced(client.clone(), &namespace);
-    let _ = api_deploy
-        .delete(&canary_name, &DeleteParams::default())
-        .await;
+    let _ = with_timeout(
+        "delete Deployment",
+        api_deploy.delete(&canary_name, &DeleteParams::default()),
+    )
+    .await;
 
     Ok(())
 }
@@ -2361,7 +4831,7 @@ pub async fn delete_canary_resources(client: &Client, node: &StellarNode) -> Res
 #[instrument(skip(client, node), fields(name = %node.name_any(), namespace = node.namespace()))]
 pub async fn ensure_network_policy(client: &Client, node: &StellarNode) -> Result<()> {
     let policy_cfg = match &node.spec.network_policy {
-        Some(cfg) if cfg.enabled => cfg,
+        Some(cfg) if node.spec.network_policy_enabled() => cfg,
         _ => return Ok(()),
     };
 
@@ -2371,10 +4841,13 @@ pub async fn ensure_network_policy(client: &Client, node: &StellarNode) -> Resul
 
     let network_policy = build_network_policy(node, policy_cfg);
 
-    api.patch(
-        &name,
-        &PatchParams::apply("stellar-operator").force(),
-        &Patch::Apply(&network_policy),
+    with_timeout(
+        "apply NetworkPolicy",
+        api.patch(
+            &name,
+            &PatchParams::apply("stellar-operator").force(),
+            &Patch::Apply(&network_policy),
+        ),
     )
     .await?;
 
@@ -2382,12 +4855,23 @@ pub async fn ensure_network_policy(client: &Client, node: &StellarNode) -> Resul
     Ok(())
 }
 
-fn build_network_policy(node: &StellarNode, config: &NetworkPolicyConfig) -> NetworkPolicy {
+pub fn build_network_policy(node: &StellarNode, config: &NetworkPolicyConfig) -> NetworkPolicy {
     let labels = standard_labels(node);
     let name = resource_name(node, "netpol");
 
     let mut ingress_rules: Vec<NetworkPolicyIngressRule> = Vec::new();
 
+    let allowed_namespaces: Vec<&String> = config
+        .allow_namespaces
+        .iter()
+        .chain(&config.allow_from_namespaces)
+        .collect();
+    let allowed_cidrs: Vec<&String> = config
+        .allow_cidrs
+        .iter()
+        .chain(&config.allow_from_external_cidr)
+        .collect();
+
     // Determine ports based on node type
     let app_ports = match node.spec.node_type {
         NodeType::Validator => vec![
@@ -2403,22 +4887,27 @@ fn build_network_policy(node: &StellarNode, config: &NetworkPolicyConfig) -> Net
             },
         ],
         NodeType::Horizon | NodeType::SorobanRpc => vec![NetworkPolicyPort {
-            port: Some(k8s_openapi::apimachinery::pkg::util::intstr::IntOrString::Int(8000)),
+            port: Some(
+                k8s_openapi::apimachinery::pkg::util::intstr::IntOrString::Int(
+                    node.spec
+                        .horizon_tls()
+                        .map_or(8000, |tls| i32::from(tls.port)),
+                ),
+            ),
             protocol: Some("TCP".to_string()),
             ..Default::default()
         }],
     };
 
     // Allow from specified namespaces
-    if !config.allow_namespaces.is_empty() {
-        let peers: Vec<NetworkPolicyPeer> = config
-            .allow_namespaces
+    if !allowed_namespaces.is_empty() {
+        let peers: Vec<NetworkPolicyPeer> = allowed_namespaces
             .iter()
             .map(|ns| NetworkPolicyPeer {
                 namespace_selector: Some(LabelSelector {
                     match_labels: Some(BTreeMap::from([(
                         "kubernetes.io/metadata.name".to_string(),
-                        ns.clone(),
+                        (*ns).clone(),
                     )])),
                     ..Default::default()
                 }),
@@ -2447,13 +4936,12 @@ fn build_network_policy(node: &StellarNode, config: &NetworkPolicyConfig) -> Net
     }
 
     // Allow from specified CIDRs
-    if !config.allow_cidrs.is_empty() {
-        let peers: Vec<NetworkPolicyPeer> = config
-            .allow_cidrs
+    if !allowed_cidrs.is_empty() {
+        let peers: Vec<NetworkPolicyPeer> = allowed_cidrs
             .iter()
             .map(|cidr| NetworkPolicyPeer {
                 ip_block: Some(IPBlock {
-                    cidr: cidr.clone(),
+                    cidr: (*cidr).clone(),
                     except: None,
                 }),
                 ..Default::default()
@@ -2466,6 +4954,20 @@ fn build_network_policy(node: &StellarNode, config: &NetworkPolicyConfig) -> Net
         });
     }
 
+    // Without explicit allow rules, only pods in the same namespace reach the API
+    if allowed_namespaces.is_empty()
+        && allowed_cidrs.is_empty()
+        && config.allow_pod_selector.is_none()
+    {
+        ingress_rules.push(NetworkPolicyIngressRule {
+            from: Some(vec![NetworkPolicyPeer {
+                pod_selector: Some(LabelSelector::default()),
+                ..Default::default()
+            }]),
+            ports: Some(app_ports.clone()),
+        });
+    }
+
     // Allow metrics scraping from monitoring namespace
     if config.allow_metrics_scrape {
         ingress_rules.push(NetworkPolicyIngressRule {
@@ -2531,7 +5033,8 @@ fn build_network_policy(node: &StellarNode, config: &NetworkPolicyConfig) -> Net
                 ..Default::default()
             },
             policy_types: Some(vec!["Ingress".to_string()]),
-            ingress: if ingress_rules.is_empty() {
+            // An empty ingress list selects no traffic, i.e. deny all
+            ingress: if config.deny_all_ingress || ingress_rules.is_empty() {
                 None
             } else {
                 Some(ingress_rules)
@@ -2548,12 +5051,17 @@ pub async fn delete_network_policy(client: &Client, node: &StellarNode) -> Resul
     let api: Api<NetworkPolicy> = Api::namespaced(client.clone(), &namespace);
     let name = resource_name(node, "netpol");
 
-    match api.delete(&name, &DeleteParams::default()).await {
+    match with_timeout(
+        "delete NetworkPolicy",
+        api.delete(&name, &DeleteParams::default()),
+    )
+    .await
+    {
         Ok(_) => info!("NetworkPolicy {} deleted", name),
-        Err(kube::Error::Api(e)) if e.code == 404 => {
+        Err(Error::KubeError(kube::Error::Api(e))) if e.code == 404 => {
             info!("NetworkPolicy {} not found, skipping delete", name);
         }
-        Err(e) => return Err(Error::KubeError(e)),
+        Err(e) => return Err(e),
     }
 
     Ok(())
@@ -2620,9 +5128,11 @@ pub async fn ensure_pdb(client: &Client, node: &StellarNode) -> Result<()> {
 
         info!("Reconciling PodDisruptionBudget {}/{}", namespace, name);
         let params = PatchParams::apply("stellar-operator").force();
-        api.patch(&name, &params, &Patch::Apply(&pdb))
-            .await
-            .map_err(Error::KubeError)?;
+        with_timeout(
+            "apply PodDisruptionBudget",
+            api.patch(&name, &params, &Patch::Apply(&pdb)),
+        )
+        .await?;
     }
 
     Ok(())
@@ -2635,12 +5145,350 @@ pub async fn delete_pdb(client: &Client, node: &StellarNode) -> Result<()> {
 
     let api: Api<PodDisruptionBudget> = Api::namespaced(client.clone(), &namespace);
 
-    match api.delete(&name, &DeleteParams::default()).await {
+    match with_timeout(
+        "delete PodDisruptionBudget",
+        api.delete(&name, &DeleteParams::default()),
+    )
+    .await
+    {
         Ok(_) => info!("Deleted PodDisruptionBudget {}/{}", namespace, name),
-        Err(kube::Error::Api(e)) if e.code == 404 => {
+        Err(Error::KubeError(kube::Error::Api(e))) if e.code == 404 => {
             // Resource doesn't exist, ignore
         }
-        Err(e) => return Err(Error::KubeError(e)),
+        Err(e) => return Err(e),
+    }
+
+    Ok(())
+}
+
+// ============================================================================
+// stellar.toml publication (Validators)
+// ============================================================================
+
+/// Name shared by the stellar.toml ConfigMap, Deployment, Service and Certificate
+pub fn stellar_toml_name(node: &StellarNode) -> String {
+    resource_name(node, "stellar-toml")
+}
+
+/// Labels for the stellar.toml server
+///
+/// Deliberately omits `app.kubernetes.io/instance` so the Nginx pods are not
+/// mistaken for the node's own pods by instance label selectors.
+fn stellar_toml_labels(node: &StellarNode) -> BTreeMap<String, String> {
+    BTreeMap::from([
+        (
+            "app.kubernetes.io/name".to_string(),
+            "stellar-toml".to_string(),
+        ),
+        (
+            "app.kubernetes.io/managed-by".to_string(),
+            "stellar-operator".to_string(),
+        ),
+        ("stellar.org/stellar-toml-for".to_string(), node.name_any()),
+    ])
+}
+
+fn stellar_toml_metadata(node: &StellarNode) -> ObjectMeta {
+    ObjectMeta {
+        name: Some(stellar_toml_name(node)),
+        namespace: node.namespace(),
+        labels: Some(stellar_toml_labels(node)),
+        owner_references: Some(vec![owner_reference(node)]),
+        ..Default::default()
+    }
+}
+
+/// Build the `<node>-stellar-toml` ConfigMap holding the file and Nginx config
+pub fn build_stellar_toml_config_map(node: &StellarNode, config: &StellarTomlConfig) -> ConfigMap {
+    ConfigMap {
+        metadata: stellar_toml_metadata(node),
+        data: Some(BTreeMap::from([
+            (
+                "stellar.toml".to_string(),
+                stellar_toml::generate_stellar_toml(config),
+            ),
+            ("default.conf".to_string(), stellar_toml::nginx_config()),
+        ])),
+        ..Default::default()
+    }
+}
+
+/// Build the Nginx Deployment serving `/.well-known/stellar.toml` on port 443
+pub fn build_stellar_toml_deployment(node: &StellarNode) -> Deployment {
+    let name = stellar_toml_name(node);
+    let labels = stellar_toml_labels(node);
+    let config_volume = |volume_name: &str, key: &str| Volume {
+        name: volume_name.to_string(),
+        config_map: Some(ConfigMapVolumeSource {
+            name: Some(name.clone()),
+            items: Some(vec![KeyToPath {
+                key: key.to_string(),
+                path: key.to_string(),
+                ..Default::default()
+            }]),
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+
+    Deployment {
+        metadata: stellar_toml_metadata(node),
+        spec: Some(DeploymentSpec {
+            replicas: Some(1),
+            selector: LabelSelector {
+                match_labels: Some(labels.clone()),
+                ..Default::default()
+            },
+            template: PodTemplateSpec {
+                metadata: Some(ObjectMeta {
+                    labels: Some(labels),
+                    ..Default::default()
+                }),
+                spec: Some(PodSpec {
+                    containers: vec![Container {
+                        name: "nginx".to_string(),
+                        image: Some(stellar_toml::STELLAR_TOML_NGINX_IMAGE.to_string()),
+                        ports: Some(vec![ContainerPort {
+                            name: Some("https".to_string()),
+                            container_port: 443,
+                            ..Default::default()
+                        }]),
+                        volume_mounts: Some(vec![
+                            VolumeMount {
+                                name: "nginx-config".to_string(),
+                                mount_path: "/etc/nginx/conf.d".to_string(),
+                                read_only: Some(true),
+                                ..Default::default()
+                            },
+                            VolumeMount {
+                                name: "stellar-toml".to_string(),
+                                mount_path: stellar_toml::STELLAR_TOML_MOUNT_PATH.to_string(),
+                                read_only: Some(true),
+                                ..Default::default()
+                            },
+                            VolumeMount {
+                                name: "tls".to_string(),
+                                mount_path: stellar_toml::STELLAR_TOML_TLS_MOUNT_PATH.to_string(),
+                                read_only: Some(true),
+                                ..Default::default()
+                            },
+                        ]),
+                        ..Default::default()
+                    }],
+                    volumes: Some(vec![
+                        config_volume("nginx-config", "default.conf"),
+                        config_volume("stellar-toml", "stellar.toml"),
+                        Volume {
+                            name: "tls".to_string(),
+                            secret: Some(SecretVolumeSource {
+                                secret_name: Some(format!("{name}-tls")),
+                                ..Default::default()
+                            }),
+                            ..Default::default()
+                        },
+                    ]),
+                    ..Default::default()
+                }),
+            },
+            ..Default::default()
+        }),
+        status: None,
+    }
+}
+
+/// Build the Service exposing the stellar.toml server on port 443
+pub fn build_stellar_toml_service(node: &StellarNode) -> Service {
+    Service {
+        metadata: stellar_toml_metadata(node),
+        spec: Some(ServiceSpec {
+            selector: Some(stellar_toml_labels(node)),
+            ports: Some(vec![ServicePort {
+                name: Some("https".to_string()),
+                port: 443,
+                target_port: Some(IntOrString::String("https".to_string())),
+                ..Default::default()
+            }]),
+            ..Default::default()
+        }),
+        status: None,
+    }
+}
+
+/// API resource for cert-manager `Certificate` objects
+fn certificate_api_resource() -> ApiResource {
+    ApiResource {
+        group: "cert-manager.io".to_string(),
+        version: "v1".to_string(),
+        api_version: "cert-manager.io/v1".to_string(),
+        kind: "Certificate".to_string(),
+        plural: "certificates".to_string(),
+    }
+}
+
+/// Check whether the cert-manager `Certificate` CRD is served by the API server
+async fn certificate_crd_installed(client: &Client) -> Result<bool> {
+    crd_discovery::kind_served(client, "cert-manager.io/v1", "Certificate").await
+}
+
+/// Build the cert-manager `Certificate` for the home domain
+///
+/// The resulting `<node>-stellar-toml-tls` Secret is mounted by the Nginx pod.
+pub fn build_stellar_toml_certificate(
+    node: &StellarNode,
+    config: &StellarTomlConfig,
+) -> DynamicObject {
+    let name = stellar_toml_name(node);
+    let namespace = node.namespace().unwrap_or_else(|| "default".to_string());
+    let issuer_ref = match (
+        &config.cert_manager_issuer,
+        &config.cert_manager_cluster_issuer,
+    ) {
+        (Some(issuer), _) => serde_json::json!({ "name": issuer, "kind": "Issuer" }),
+        (None, cluster_issuer) => {
+            serde_json::json!({ "name": cluster_issuer, "kind": "ClusterIssuer" })
+        }
+    };
+
+    let mut certificate = DynamicObject::new(&name, &certificate_api_resource()).within(&namespace);
+    certificate.metadata = stellar_toml_metadata(node);
+    certificate.data = serde_json::json!({
+        "spec": {
+            "secretName": format!("{name}-tls"),
+            "dnsNames": config.home_domain().into_iter().collect::<Vec<_>>(),
+            "issuerRef": issuer_ref,
+        }
+    });
+    certificate
+}
+
+/// `validatorConfig.stellarToml`, if this is a Validator that configures it
+pub fn stellar_toml_config(node: &StellarNode) -> Option<&StellarTomlConfig> {
+    node.spec
+        .validator_config
+        .as_ref()
+        .and_then(|v| v.stellar_toml.as_ref())
+        .filter(|_| node.spec.node_type == NodeType::Validator)
+}
+
+/// Whether the stellar.toml ConfigMap exists for this node
+pub async fn stellar_toml_exists(client: &Client, node: &StellarNode) -> Result<bool> {
+    let namespace = node.namespace().unwrap_or_else(|| "default".to_string());
+    let api: Api<ConfigMap> = Api::namespaced(client.clone(), &namespace);
+    Ok(
+        with_timeout("get ConfigMap", api.get_opt(&stellar_toml_name(node)))
+            .await?
+            .is_some(),
+    )
+}
+
+/// Ensure the stellar.toml ConfigMap, server and certificate exist
+pub async fn ensure_stellar_toml(client: &Client, node: &StellarNode) -> Result<()> {
+    let Some(config) = stellar_toml_config(node) else {
+        return Ok(());
+    };
+
+    let namespace = node.namespace().unwrap_or_else(|| "default".to_string());
+    let name = stellar_toml_name(node);
+    let params = PatchParams::apply("stellar-operator").force();
+
+    let cm_api: Api<ConfigMap> = Api::namespaced(client.clone(), &namespace);
+    with_timeout(
+        "apply ConfigMap",
+        cm_api.patch(
+            &name,
+            &params,
+            &Patch::Apply(&build_stellar_toml_config_map(node, config)),
+        ),
+    )
+    .await?;
+
+    if certificate_crd_installed(client).await? {
+        let cert_api: Api<DynamicObject> =
+            Api::namespaced_with(client.clone(), &namespace, &certificate_api_resource());
+        with_timeout(
+            "apply Certificate",
+            cert_api.patch(
+                &name,
+                &params,
+                &Patch::Apply(&build_stellar_toml_certificate(node, config)),
+            ),
+        )
+        .await?;
+    } else {
+        warn!(
+            "cert-manager Certificate CRD not installed; {}/{} needs a {}-tls Secret to serve stellar.toml",
+            namespace, name, name
+        );
+    }
+
+    let deploy_api: Api<Deployment> = Api::namespaced(client.clone(), &namespace);
+    with_timeout(
+        "apply Deployment",
+        deploy_api.patch(
+            &name,
+            &params,
+            &Patch::Apply(&build_stellar_toml_deployment(node)),
+        ),
+    )
+    .await?;
+
+    let svc_api: Api<Service> = Api::namespaced(client.clone(), &namespace);
+    with_timeout(
+        "apply Service",
+        svc_api.patch(
+            &name,
+            &params,
+            &Patch::Apply(&build_stellar_toml_service(node)),
+        ),
+    )
+    .await?;
+
+    info!("stellar.toml server {} ensured for {}", name, namespace);
+    Ok(())
+}
+
+/// Delete the stellar.toml ConfigMap, server and certificate
+pub async fn delete_stellar_toml(client: &Client, node: &StellarNode) -> Result<()> {
+    let namespace = node.namespace().unwrap_or_else(|| "default".to_string());
+    let name = stellar_toml_name(node);
+    let dp = DeleteParams::default();
+
+    let results = [
+        with_timeout(
+            "delete Service",
+            Api::<Service>::namespaced(client.clone(), &namespace).delete(&name, &dp),
+        )
+        .await
+        .map(|_| ()),
+        with_timeout(
+            "delete Deployment",
+            Api::<Deployment>::namespaced(client.clone(), &namespace).delete(&name, &dp),
+        )
+        .await
+        .map(|_| ()),
+        with_timeout(
+            "delete ConfigMap",
+            Api::<ConfigMap>::namespaced(client.clone(), &namespace).delete(&name, &dp),
+        )
+        .await
+        .map(|_| ()),
+    ];
+    for result in results {
+        match result {
+            Ok(()) => debug!("Deleted stellar.toml resource {}/{}", namespace, name),
+            Err(Error::KubeError(kube::Error::Api(e))) if e.code == 404 => {}
+            Err(e) => return Err(e),
+        }
+    }
+
+    if certificate_crd_installed(client).await? {
+        let api: Api<DynamicObject> =
+            Api::namespaced_with(client.clone(), &namespace, &certificate_api_resource());
+        match with_timeout("delete Certificate", api.delete(&name, &dp)).await {
+            Ok(_) => debug!("Deleted Certificate {}/{}", namespace, name),
+            Err(Error::KubeError(kube::Error::Api(e))) if e.code == 404 => {}
+            Err(e) => return Err(e),
+        }
     }
 
     Ok(())
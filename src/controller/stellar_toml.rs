@@ -0,0 +1,140 @@
+//! `stellar.toml` generation for validators
+//!
+//! Renders the SEP-1 `stellar.toml` from `spec.validatorConfig.stellarToml`
+//! and the Nginx configuration used to serve it at
+//! `https://<home domain>/.well-known/stellar.toml`.
+
+use crate::crd::StellarTomlConfig;
+
+/// Image serving the generated file
+pub const STELLAR_TOML_NGINX_IMAGE: &str = "nginx:1.27-alpine";
+
+/// Directory the `stellar.toml` ConfigMap key is mounted into
+pub const STELLAR_TOML_MOUNT_PATH: &str = "/usr/share/stellar-toml";
+
+/// Directory the TLS secret is mounted into
+pub const STELLAR_TOML_TLS_MOUNT_PATH: &str = "/etc/nginx/tls";
+
+/// SEP-1 version declared in the generated file
+const SEP1_VERSION: &str = "2.0.0";
+
+/// Render the `stellar.toml` for `config`
+pub fn generate_stellar_toml(config: &StellarTomlConfig) -> String {
+    let mut toml = format!("VERSION=\"{SEP1_VERSION}\"\n\n");
+
+    toml.push_str("[DOCUMENTATION]\n");
+    toml.push_str(&format!(
+        "ORG_NAME=\"{}\"\n",
+        escape(&config.organization_name)
+    ));
+    toml.push_str(&format!(
+        "ORG_URL=\"{}\"\n",
+        escape(&config.organization_url)
+    ));
+    if let Some(ref description) = config.documentation {
+        toml.push_str(&format!("ORG_DESCRIPTION=\"{}\"\n", escape(description)));
+    }
+
+    toml.push_str("\n[[VALIDATORS]]\n");
+    toml.push_str(&format!("ALIAS=\"{}\"\n", escape(&config.node_alias)));
+    toml.push_str(&format!(
+        "DISPLAY_NAME=\"{}\"\n",
+        escape(&config.organization_name)
+    ));
+    toml.push_str(&format!(
+        "PUBLIC_KEY=\"{}\"\n",
+        escape(config.public_key.trim())
+    ));
+    toml.push_str(&format!("HISTORY=\"{}\"\n", escape(&config.history_url)));
+    toml
+}
+
+/// Nginx server block serving `stellar.toml` over TLS with the SEP-1 CORS header
+pub fn nginx_config() -> String {
+    format!(
+        r#"server {{
+    listen 443 ssl;
+    ssl_certificate {tls}/tls.crt;
+    ssl_certificate_key {tls}/tls.key;
+
+    location = /.well-known/stellar.toml {{
+        alias {toml}/stellar.toml;
+        default_type text/plain;
+        add_header Access-Control-Allow-Origin "*";
+    }}
+
+    location / {{
+        return 404;
+    }}
+}}
+"#,
+        tls = STELLAR_TOML_TLS_MOUNT_PATH,
+        toml = STELLAR_TOML_MOUNT_PATH,
+    )
+}
+
+/// Escape a value for a TOML basic string
+fn escape(value: &str) -> String {
+    value
+        .chars()
+        .filter(|c| !c.is_control())
+        .flat_map(|c| match c {
+            '"' | '\\' => vec!['\\', c],
+            _ => vec![c],
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config() -> StellarTomlConfig {
+        StellarTomlConfig {
+            organization_name: "Example Org".to_string(),
+            organization_url: "https://example.org".to_string(),
+            node_alias: "example-1".to_string(),
+            public_key: "GCGB2S2KGYARPVIA37HYZXVRM2YZUEXA6S33ZU5BUDC6THSB62LZSTYH".to_string(),
+            history_url: "https://history.example.org".to_string(),
+            documentation: None,
+            cert_manager_issuer: None,
+            cert_manager_cluster_issuer: Some("letsencrypt-prod".to_string()),
+        }
+    }
+
+    #[test]
+    fn test_generates_sep1_sections() {
+        let toml = generate_stellar_toml(&config());
+        assert_eq!(
+            toml,
+            "VERSION=\"2.0.0\"\n\n\
+             [DOCUMENTATION]\n\
+             ORG_NAME=\"Example Org\"\n\
+             ORG_URL=\"https://example.org\"\n\n\
+             [[VALIDATORS]]\n\
+             ALIAS=\"example-1\"\n\
+             DISPLAY_NAME=\"Example Org\"\n\
+             PUBLIC_KEY=\"GCGB2S2KGYARPVIA37HYZXVRM2YZUEXA6S33ZU5BUDC6THSB62LZSTYH\"\n\
+             HISTORY=\"https://history.example.org\"\n"
+        );
+    }
+
+    #[test]
+    fn test_documentation_and_escaping() {
+        let mut cfg = config();
+        cfg.organization_name = "Quote \"Co\"".to_string();
+        cfg.documentation = Some("Runs validators\nworldwide".to_string());
+
+        let toml = generate_stellar_toml(&cfg);
+        assert!(toml.contains("ORG_NAME=\"Quote \\\"Co\\\"\"\n"));
+        assert!(toml.contains("ORG_DESCRIPTION=\"Runs validatorsworldwide\"\n"));
+    }
+
+    #[test]
+    fn test_nginx_serves_well_known_path() {
+        let conf = nginx_config();
+        assert!(conf.contains("listen 443 ssl;"));
+        assert!(conf.contains("location = /.well-known/stellar.toml"));
+        assert!(conf.contains("Access-Control-Allow-Origin"));
+    }
+}
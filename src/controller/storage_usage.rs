@@ -0,0 +1,256 @@
+//! Data volume utilization reporting
+//!
+//! Reads volume stats for the node's data PVC from the kubelet summary API
+//! (`/api/v1/nodes/<node>/proxy/stats/summary`) of the Kubernetes node running
+//! the pod, reports them as `status.storageUsedPercent`, and sets the
+//! `StoragePressure` condition once the volume is nearly full. A full disk is
+//! one of the most common causes of validator outages, so this surfaces the
+//! risk before stellar-core stops closing ledgers.
+
+use k8s_openapi::api::core::v1::Pod;
+use kube::{
+    api::{Api, ListParams},
+    client::Client,
+    ResourceExt,
+};
+use serde_json::{json, Value};
+use tracing::{debug, instrument};
+
+use crate::crd::StellarNode;
+use crate::error::{Error, Result};
+
+use super::conditions;
+use super::resources;
+
+/// Utilization at or above which `StoragePressure` is set
+pub const STORAGE_PRESSURE_THRESHOLD_PERCENT: f64 = 85.0;
+
+/// Whether `used_percent` is high enough to report storage pressure
+pub fn storage_pressure(used_percent: f64) -> bool {
+    used_percent >= STORAGE_PRESSURE_THRESHOLD_PERCENT
+}
+
+/// Utilization of PVC `namespace/pvc_name` from a kubelet stats summary
+///
+/// Returns `None` when the summary has no stats for the claim (e.g. the pod
+/// has not mounted it yet) or reports a zero capacity.
+pub fn pvc_used_percent(summary: &Value, namespace: &str, pvc_name: &str) -> Option<f64> {
+    let volume = summary
+        .get("pods")?
+        .as_array()?
+        .iter()
+        .filter_map(|pod| pod.get("volume").and_then(Value::as_array))
+        .flatten()
+        .find(|volume| {
+            volume.get("pvcRef").is_some_and(|r| {
+                r.get("name").and_then(Value::as_str) == Some(pvc_name)
+                    && r.get("namespace").and_then(Value::as_str) == Some(namespace)
+            })
+        })?;
+
+    let used = volume.get("usedBytes")?.as_f64()?;
+    let capacity = volume.get("capacityBytes")?.as_f64()?;
+    if capacity <= 0.0 {
+        return None;
+    }
+    Some(((used / capacity) * 1000.0).round() / 10.0)
+}
+
+/// Measure how full the node's data PVC is
+///
+/// Returns `Ok(None)` when no scheduled pod is found or the kubelet has no
+/// stats for the claim yet.
+#[instrument(skip(client, node), fields(name = %node.name_any()))]
+pub async fn measure_storage_usage(client: &Client, node: &StellarNode) -> Result<Option<f64>> {
+    let namespace = node.namespace().unwrap_or_else(|| "default".to_string());
+    let pods: Api<Pod> = Api::namespaced(client.clone(), &namespace);
+
+    let lp =
+        ListParams::default().labels(&format!("app.kubernetes.io/instance={}", node.name_any()));
+    let pod_list = pods.list(&lp).await.map_err(Error::KubeError)?;
+    let Some(node_name) = pod_list
+        .items
+        .iter()
+        .find_map(|pod| pod.spec.as_ref().and_then(|s| s.node_name.clone()))
+    else {
+        return Ok(None);
+    };
+
+    let request = kube::core::Request::new("/api/v1/nodes")
+        .get_subresource("proxy/stats/summary", &node_name)
+        .map_err(|e| Error::KubeError(kube::Error::BuildRequest(e)))?;
+    let summary: Value = client.request(request).await.map_err(Error::KubeError)?;
+
+    let used_percent = pvc_used_percent(&summary, &namespace, &resources::pvc_name(node));
+    debug!(
+        "Storage usage for {}/{}: {:?}%",
+        namespace,
+        node.name_any(),
+        used_percent
+    );
+    Ok(used_percent)
+}
+
+/// Record `status.storageUsedPercent` and the `StoragePressure` condition
+///
+/// Returns whether storage pressure was just raised, i.e. the live status did
+/// not already report it, so the warning event is emitted once per episode.
+pub async fn update_storage_usage_status(
+    client: &Client,
+    node: &StellarNode,
+    used_percent: f64,
+) -> Result<bool> {
+    let status = json!({ "storageUsedPercent": used_percent });
+    conditions::update_conditions(client, node, status, |node_conditions| {
+        let was_pressured = conditions::is_condition_true(
+            node_conditions,
+            conditions::CONDITION_TYPE_STORAGE_PRESSURE,
+        );
+        if storage_pressure(used_percent) {
+            conditions::set_condition(
+                node_conditions,
+                conditions::CONDITION_TYPE_STORAGE_PRESSURE,
+                conditions::CONDITION_STATUS_TRUE,
+                "VolumeNearlyFull",
+                &format!(
+                    "Data volume is {used_percent}% full (threshold {STORAGE_PRESSURE_THRESHOLD_PERCENT}%)"
+                ),
+            );
+        } else {
+            conditions::set_condition(
+                node_conditions,
+                conditions::CONDITION_TYPE_STORAGE_PRESSURE,
+                conditions::CONDITION_STATUS_FALSE,
+                "SufficientStorage",
+                &format!("Data volume is {used_percent}% full"),
+            );
+        }
+        !was_pressured && storage_pressure(used_percent)
+    })
+    .await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::controller::conditions::DEFAULT_MAX_CONDITIONS;
+    use crate::controller::fake_api::FakeApiServer;
+    use crate::controller::reconciler::update_status;
+
+    fn summary(used: u64, capacity: u64) -> Value {
+        json!({
+            "node": { "nodeName": "worker-1" },
+            "pods": [
+                {
+                    "podRef": { "name": "other", "namespace": "stellar" },
+                    "volume": [{ "name": "tmp", "usedBytes": 1, "capacityBytes": 2 }]
+                },
+                {
+                    "podRef": { "name": "validator-0", "namespace": "stellar" },
+                    "volume": [
+                        { "name": "config", "usedBytes": 10, "capacityBytes": 100 },
+                        {
+                            "name": "data",
+                            "usedBytes": used,
+                            "capacityBytes": capacity,
+                            "pvcRef": { "name": "validator-data", "namespace": "stellar" }
+                        }
+                    ]
+                }
+            ]
+        })
+    }
+
+    #[test]
+    fn test_pvc_used_percent_from_summary() {
+        let s = summary(93, 100);
+        assert_eq!(
+            pvc_used_percent(&s, "stellar", "validator-data"),
+            Some(93.0)
+        );
+
+        let s = summary(1, 3);
+        assert_eq!(
+            pvc_used_percent(&s, "stellar", "validator-data"),
+            Some(33.3)
+        );
+    }
+
+    #[test]
+    fn test_pvc_used_percent_missing_or_empty() {
+        let s = summary(0, 0);
+        assert_eq!(pvc_used_percent(&s, "stellar", "validator-data"), None);
+        assert_eq!(pvc_used_percent(&s, "other-ns", "validator-data"), None);
+        assert_eq!(
+            pvc_used_percent(&json!({}), "stellar", "validator-data"),
+            None
+        );
+    }
+
+    #[test]
+    fn test_storage_pressure_threshold() {
+        assert!(!storage_pressure(84.9));
+        assert!(storage_pressure(85.0));
+        assert!(storage_pressure(97.5));
+    }
+
+    #[tokio::test]
+    async fn test_pressure_is_raised_once_across_passes() {
+        let server = FakeApiServer::start().await;
+        let client = server.client();
+        let spec = serde_json::from_value(json!({
+            "nodeType": "Validator",
+            "network": "Testnet",
+            "version": "v21.0.0",
+            "validatorConfig": { "seedSecretRef": "validator-seed" },
+        }))
+        .unwrap();
+        let mut node = StellarNode::new("validator", spec);
+        node.metadata.namespace = Some("default".to_string());
+        let path = "/apis/stellar.org/v1alpha1/namespaces/default/stellarnodes/validator";
+        server.insert(path, &node);
+
+        // Each pass ends with the phase status write from its own snapshot
+        assert!(update_storage_usage_status(&client, &node, 90.0)
+            .await
+            .unwrap());
+        update_status(
+            &client,
+            &node,
+            "Ready",
+            None,
+            1,
+            true,
+            DEFAULT_MAX_CONDITIONS,
+        )
+        .await
+        .unwrap();
+        let refreshed: StellarNode = server.get(path);
+        assert!(!update_storage_usage_status(&client, &refreshed, 91.0)
+            .await
+            .unwrap());
+        update_status(
+            &client,
+            &refreshed,
+            "Ready",
+            None,
+            1,
+            true,
+            DEFAULT_MAX_CONDITIONS,
+        )
+        .await
+        .unwrap();
+        // Even a stale snapshot does not raise it again
+        assert!(!update_storage_usage_status(&client, &node, 92.0)
+            .await
+            .unwrap());
+
+        // A new episode after the volume was expanded
+        assert!(!update_storage_usage_status(&client, &node, 40.0)
+            .await
+            .unwrap());
+        assert!(update_storage_usage_status(&client, &node, 88.0)
+            .await
+            .unwrap());
+    }
+}
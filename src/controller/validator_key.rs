@@ -0,0 +1,138 @@
+//! Validator public key discovery
+//!
+//! Other validators need this node's public key to list it in their quorum
+//! sets. For validators whose seed comes from a Secret, the operator reads the
+//! seed, derives the ed25519 public key and publishes its `G...` strkey in
+//! `status.publicKey`. The seed itself never leaves this module. The key is
+//! derived again only when the spec changes, so the Secret is not read on
+//! every reconcile.
+
+use k8s_openapi::api::core::v1::Secret;
+use kube::{
+    api::{Api, Patch, PatchParams},
+    client::Client,
+    ResourceExt,
+};
+use serde_json::json;
+use stellar_strkey::ed25519::{PrivateKey, PublicKey};
+use tracing::debug;
+
+use crate::crd::{KeySource, NodeType, StellarNode};
+use crate::error::{Error, Result};
+
+use super::api_timeout::with_timeout;
+
+/// Key of the validator seed in `spec.validatorConfig.seedSecretRef`
+pub const SEED_SECRET_KEY: &str = "STELLAR_CORE_SEED";
+
+/// Derive the `G...` public key from an `S...` seed
+///
+/// The error never includes the seed.
+pub fn public_key_from_seed(seed: &str) -> Result<String> {
+    let seed = PrivateKey::from_string(seed.trim())
+        .map_err(|_| Error::ConfigError("validator seed is not a valid S... strkey".to_string()))?;
+    let signing_key = ed25519_dalek::SigningKey::from_bytes(&seed.0);
+    Ok(PublicKey(signing_key.verifying_key().to_bytes()).to_string())
+}
+
+/// Whether `status.publicKey` is current for the node's spec
+fn cached(node: &StellarNode) -> bool {
+    node.status.as_ref().is_some_and(|s| {
+        s.public_key.is_some() && s.observed_generation == node.metadata.generation
+    })
+}
+
+/// Read the validator seed and derive its public key
+///
+/// Returns `Ok(None)` for non-validators, KMS sourced seeds, and when
+/// the key in status is still current.
+pub async fn read_validator_public_key(
+    client: &Client,
+    node: &StellarNode,
+) -> Result<Option<String>> {
+    if node.spec.node_type != NodeType::Validator || cached(node) {
+        return Ok(None);
+    }
+    let Some(cfg) = node
+        .spec
+        .validator_config
+        .as_ref()
+        .filter(|c| c.key_source == KeySource::Secret)
+    else {
+        return Ok(None);
+    };
+
+    let namespace = node.namespace().unwrap_or_else(|| "default".to_string());
+    let api: Api<Secret> = Api::namespaced(client.clone(), &namespace);
+    let secret = with_timeout("get Secret", api.get(&cfg.seed_secret_ref)).await?;
+    let seed = secret
+        .data
+        .as_ref()
+        .and_then(|data| data.get(SEED_SECRET_KEY))
+        .ok_or_else(|| {
+            Error::ConfigError(format!(
+                "Secret {}/{} has no {SEED_SECRET_KEY} key",
+                namespace, cfg.seed_secret_ref
+            ))
+        })?;
+    let seed = std::str::from_utf8(&seed.0).map_err(|_| {
+        Error::ConfigError(format!(
+            "{SEED_SECRET_KEY} in Secret {}/{} is not valid UTF-8",
+            namespace, cfg.seed_secret_ref
+        ))
+    })?;
+
+    let public_key = public_key_from_seed(seed)?;
+    debug!(
+        "Validator public key for {}/{}: {}",
+        namespace,
+        node.name_any(),
+        public_key
+    );
+    Ok(Some(public_key))
+}
+
+/// Publish the derived key in `status.publicKey`
+pub async fn update_public_key_status(
+    client: &Client,
+    node: &StellarNode,
+    public_key: &str,
+) -> Result<()> {
+    let namespace = node.namespace().unwrap_or_else(|| "default".to_string());
+    let api: Api<StellarNode> = Api::namespaced(client.clone(), &namespace);
+    let patch = json!({ "status": { "publicKey": public_key } });
+
+    api.patch_status(
+        &node.name_any(),
+        &PatchParams::apply("stellar-operator"),
+        &Patch::Merge(&patch),
+    )
+    .await
+    .map_err(Error::KubeError)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Seed with raw bytes 0x00..0x1f
+    const SEED: &str = "SAAACAQDAQCQMBYIBEFAWDANBYHRAEISCMKBKFQXDAMRUGY4DUPB6NKI";
+    const PUBLIC_KEY: &str = "GAB2CB576PHBBPQ5ODORRZ2LYCMWPZGWGCN2KDK7DXOIMZASKUY3QZ6Q";
+
+    #[test]
+    fn test_public_key_from_seed() {
+        assert_eq!(public_key_from_seed(SEED).unwrap(), PUBLIC_KEY);
+        assert_eq!(
+            public_key_from_seed(&format!("{SEED}\n")).unwrap(),
+            PUBLIC_KEY
+        );
+    }
+
+    #[test]
+    fn test_invalid_seed_is_not_echoed() {
+        let err = public_key_from_seed(PUBLIC_KEY).unwrap_err();
+        assert!(!err.to_string().contains(PUBLIC_KEY));
+        assert!(public_key_from_seed("not-a-seed").is_err());
+    }
+}
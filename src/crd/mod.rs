@@ -5,12 +5,16 @@
 mod cnpg;
 mod stellar_node;
 mod types;
+mod versions;
 
 #[cfg(test)]
 mod tests;
 
 pub use cnpg::*;
+pub(crate) use stellar_node::parse_image_version;
 pub use stellar_node::{
-    BGPStatus, SpecValidationError, StellarNode, StellarNodeSpec, StellarNodeStatus,
+    ActiveScalingOverride, BGPStatus, PrecheckResult, SpecValidationError, StellarNode,
+    StellarNodeSpec, StellarNodeStatus, ValidatorMode,
 };
 pub use types::*;
+pub use versions::{stellar_node_crd, SERVED_VERSIONS, STORAGE_VERSION};
@@ -3,6 +3,9 @@
 //! The StellarNode CRD represents a managed Stellar infrastructure node.
 //! Supports Validator (Core), Horizon API, and Soroban RPC node types.
 
+use std::collections::BTreeMap;
+use std::time::Duration;
+
 use k8s_openapi::apimachinery::pkg::apis::meta::v1::ObjectMeta;
 use k8s_openapi::apimachinery::pkg::util::intstr::IntOrString;
 use kube::CustomResource;
@@ -10,11 +13,28 @@ use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 use super::types::{
-    AutoscalingConfig, Condition, CrossClusterConfig, DisasterRecoveryConfig,
-    DisasterRecoveryStatus, ExternalDatabaseConfig, GlobalDiscoveryConfig, HistoryMode,
-    HorizonConfig, IngressConfig, LoadBalancerConfig, ManagedDatabaseConfig, NetworkPolicyConfig,
-    NodeType, ResourceRequirements, RetentionPolicy, RolloutStrategy, SorobanConfig,
-    StellarNetwork, StorageConfig, ValidatorConfig,
+    AlertRule, ArchivalBackend, ArchivalStorageConfig, AutoscalingConfig, BucketListDbConfig,
+    Condition, ConfigTemplateMode, ContainerSecurityContextConfig, CronScalingOverride,
+    CrossClusterConfig, CustomResourceMetricSpec, DatabaseSslConfig, DisasterRecoveryConfig,
+    DisasterRecoveryStatus, ExternalDatabaseConfig, ExternalTrafficPolicy, ExtraVolumeMount,
+    GlobalDiscoveryConfig, GrafanaDashboardConfig, HistoryArchivePublishConfig, HistoryCacheConfig,
+    HistoryCacheVolumeType, HistoryGcConfig, HistoryMode, HorizonCaptiveCoreConfig, HorizonConfig,
+    HorizonTlsConfig, ImageOverrideConfig, IngressConfig, LifecycleConfig, LifecycleHookSpec,
+    LinuxCapability, LoadBalancerConfig, ManagedDatabaseConfig, MeshConfig, MonitoringConfig,
+    NetworkPolicyConfig, NodeFailurePolicy, NodeType, OffersFilteringConfig, PersistenceBackend,
+    PgSslMode, PrecheckHookConfig, PrometheusRulesConfig, QuorumSliceWatcherConfig,
+    QuorumValidatorSpec, ReconcileHooksConfig, ResourceRequirements, RetentionPolicy,
+    RolloutStrategy, ServiceConfig, ServiceType, SessionAffinity, SorobanConfig, StellarBeatConfig,
+    StellarNetwork, StellarNodeRef, StellarTomlConfig, StorageConfig, TargetType,
+    TerminationMessagePolicy, TransactionFilteringConfig, ValidatorConfig, VaultConfig,
+    DEFAULT_BUCKETLIST_DB_INDEX_CUTOFF_MB, DEFAULT_BUCKETLIST_DB_PAGE_SIZE_EXPONENT,
+    DEFAULT_DEBUG_RPC_MAX_BODY_LOG_BYTES, DEFAULT_ORDER_BOOK_CACHE_DEPTH,
+    MAX_BATCH_WRITE_COUNT_LIMIT, MAX_BUCKETLIST_DB_INDEX_CUTOFF_MB,
+    MAX_BUCKETLIST_DB_PAGE_SIZE_EXPONENT, MAX_CATCHUP_WORKERS, MAX_FLOOD_TX_PERIOD_MS,
+    MAX_ORDER_BOOK_CACHE_DEPTH, MAX_PRECHECK_TIMEOUT_SECONDS, MAX_REQUEUE_JITTER_PERCENT,
+    MAX_SESSION_AFFINITY_TIMEOUT_SECONDS, MIN_CONTRACT_CODE_CACHE_SIZE,
+    MIN_CONTRACT_CODE_CACHE_TTL_SECONDS, MIN_FLOOD_TX_PERIOD_MS, MIN_NETWORK_BASE_FEE,
+    MIN_RECOMMENDED_SCP_LEDGER_TARGET_DURATION_MS, MIN_SCP_BALLOT_TIMEOUT_MS,
 };
 
 /// Structured validation error for `StellarNodeSpec`
@@ -56,6 +76,8 @@ impl SpecValidationError {
 #[serde(rename_all = "camelCase")]
 pub struct StellarNodeSpec {
     pub node_type: NodeType,
+    /// Mainnet, Testnet, Futurenet, or `{"Custom": "<network passphrase>"}`
+    #[schemars(schema_with = "crate::crd::types::string_or_object_schema")]
     pub network: StellarNetwork,
     pub version: String,
 
@@ -80,6 +102,12 @@ pub struct StellarNodeSpec {
     #[serde(default = "default_replicas")]
     pub replicas: i32,
 
+    /// Ready replicas required before the node is reported as Running (default: 1)
+    ///
+    /// Capped at `replicas`; until reached the phase stays `Progressing`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_ready_replicas: Option<i32>,
+
     #[serde(skip_serializing_if = "Option::is_none")]
     #[schemars(with = "Option<serde_json::Value>")]
     pub min_available: Option<IntOrString>,
@@ -91,9 +119,28 @@ pub struct StellarNodeSpec {
     #[serde(default)]
     pub suspended: bool,
 
+    /// Generate a `<name>-alerts` ConfigMap with baseline Prometheus rules, for
+    /// Prometheus setups without the Prometheus Operator
+    ///
+    /// Superseded by `prometheusRules`: when that is set, the ConfigMap is
+    /// removed and only the `PrometheusRule` is generated.
     #[serde(default)]
     pub alerting: bool,
 
+    /// Generate a `PrometheusRule` (monitoring.coreos.com/v1) with built-in and
+    /// user-defined alerts for this node
+    ///
+    /// This is the alerting switch: `prometheusRules: {}` enables the built-in
+    /// alerts, and it takes precedence over `alerting`. Ignored when the
+    /// Prometheus Operator CRDs are not installed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub prometheus_rules: Option<PrometheusRulesConfig>,
+
+    /// Generate a Prometheus Operator `ServiceMonitor` scraping this node
+    /// Ignored when the Prometheus Operator CRDs are not installed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub monitoring: Option<MonitoringConfig>,
+
     #[serde(skip_serializing_if = "Option::is_none")]
     pub database: Option<ExternalDatabaseConfig>,
 
@@ -120,6 +167,7 @@ pub struct StellarNodeSpec {
 
     /// Rollout strategy for updates (RollingUpdate or Canary)
     #[serde(default)]
+    #[schemars(schema_with = "crate::crd::types::string_or_object_schema")]
     pub strategy: RolloutStrategy,
 
     #[serde(default)]
@@ -140,6 +188,102 @@ pub struct StellarNodeSpec {
     /// Enables scanning for vulnerabilities and automatic rollout of patched versions
     #[serde(skip_serializing_if = "Option::is_none")]
     pub cve_handling: Option<super::types::CVEHandlingConfig>,
+
+    /// Container image override (repository, tag, digest, pull policy)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub image_override: Option<ImageOverrideConfig>,
+
+    /// Semver range (e.g. ">=21.0.0, <23.0.0") that `version` and
+    /// `imageOverride.tag` must satisfy
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub image_version_constraint: Option<String>,
+
+    /// Additional mounts of operator-managed volumes, with optional subPath
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub extra_volume_mounts: Vec<ExtraVolumeMount>,
+
+    /// Service options such as topology-aware routing
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub service: Option<ServiceConfig>,
+
+    /// HTTP hooks called before and after resources are applied
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hooks: Option<ReconcileHooksConfig>,
+
+    /// Vault Agent Injector configuration for file-based secrets
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vault: Option<VaultConfig>,
+
+    /// Service mesh sidecar injection for the node's pods
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mesh: Option<MeshConfig>,
+
+    /// Container lifecycle hooks for the main container
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lifecycle: Option<LifecycleConfig>,
+
+    /// Linux capabilities for the main container (default: drop ALL)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub container_security_context: Option<ContainerSecurityContextConfig>,
+
+    /// Relax safety checks meant for production (e.g. privileged capabilities)
+    #[serde(default)]
+    pub dev_mode: bool,
+
+    /// Emit validator network parameter votes (`maxTxSetSize`, `baseFee`) on Mainnet
+    #[serde(default)]
+    pub allow_mainnet_parameter_override: bool,
+
+    /// Allow `sorobanConfig.debugRpc` on Mainnet, where it logs users' transactions
+    #[serde(default)]
+    pub allow_debug_on_mainnet: bool,
+
+    /// Per-node requeue jitter in percent (0-50)
+    ///
+    /// Each requeue waits the interval plus a random delay of up to this
+    /// percentage, so nodes sharing an interval do not hit the API server in
+    /// bursts. Unset, the operator's `--requeue-jitter-percent` (default: 10)
+    /// applies as a stable per-node offset.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub requeue_jitter_percent: Option<u64>,
+
+    /// Retry behavior while reconciles of this node keep failing (optional)
+    #[serde(rename = "nodeFailurePolicy", skip_serializing_if = "Option::is_none")]
+    pub failure_policy: Option<NodeFailurePolicy>,
+
+    /// Provision a Grafana dashboard for this node through the Grafana sidecar
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub grafana_dashboard: Option<GrafanaDashboardConfig>,
+
+    /// Pin pods to Kubernetes nodes labelled `stellar.org/node-type=validator`
+    /// (Validators) or `stellar.org/node-type=rpc` (Horizon, Soroban RPC), if any exist
+    #[serde(default)]
+    pub auto_detect_node_selector: bool,
+
+    /// Check run as a one-shot Job before changes that restart the node's pods
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub precheck_hook: Option<PrecheckHookConfig>,
+
+    /// How the kubelet fills in the main container's termination message
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub termination_message_policy: Option<TerminationMessagePolicy>,
+
+    /// File the termination message is read from (default: `/dev/termination-log`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub termination_message_path: Option<String>,
+
+    /// Emit a Warning `OOMKilled` event when a container exceeds its memory limit
+    #[serde(default = "default_emit_oom_event_on_kill")]
+    pub emit_oom_event_on_kill: bool,
+
+    /// How ConfigMap values are rendered (default: Static)
+    #[serde(default)]
+    pub config_template_mode: ConfigTemplateMode,
+
+    /// StellarNodes that must be Ready before this node's workload is created,
+    /// e.g. the validator a Horizon node ingests from
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub depends_on: Vec<StellarNodeRef>,
     #[schemars(skip)]
     pub resource_meta: Option<ObjectMeta>,
 }
@@ -148,6 +292,10 @@ fn default_replicas() -> i32 {
     1
 }
 
+fn default_emit_oom_event_on_kill() -> bool {
+    true
+}
+
 impl StellarNodeSpec {
     /// Validate the spec based on node type
     ///
@@ -182,6 +330,7 @@ impl StellarNodeSpec {
     /// # max_unavailable: None,
     /// # suspended: false,
     /// # alerting: false,
+    /// # prometheus_rules: None,
     /// # database: None,
     /// # managed_database: None,
     /// # autoscaling: None,
@@ -189,11 +338,36 @@ impl StellarNodeSpec {
     /// # load_balancer: None,
     /// # global_discovery: None,
     /// # cross_cluster: None,
+    /// # cve_handling: None,
     /// # strategy: Default::default(),
     /// # maintenance_mode: false,
     /// # network_policy: None,
     /// # dr_config: None,
     /// # topology_spread_constraints: None,
+    /// # image_override: None,
+    /// # image_version_constraint: None,
+    /// # extra_volume_mounts: vec![],
+    /// # service: None,
+    /// # hooks: None,
+    /// # vault: None,
+    /// # lifecycle: None,
+    /// # container_security_context: None,
+    /// # dev_mode: false,
+    /// # allow_mainnet_parameter_override: false,
+    /// # allow_debug_on_mainnet: false,
+    /// # grafana_dashboard: None,
+    /// # precheck_hook: None,
+    /// # auto_detect_node_selector: false,
+    /// # termination_message_policy: None,
+    /// # termination_message_path: None,
+    /// # emit_oom_event_on_kill: true,
+    /// # mesh: None,
+    /// # config_template_mode: Default::default(),
+    /// # depends_on: vec![],
+    /// # min_ready_replicas: None,
+    /// # monitoring: None,
+    /// # requeue_jitter_percent: None,
+    /// # failure_policy: None,
     /// # resource_meta: None,
     /// };
     /// match spec.validate() {
@@ -217,193 +391,2253 @@ impl StellarNodeSpec {
             ));
         }
 
-        // 2. PDB Conflict Check
-        if self.min_available.is_some() && self.max_unavailable.is_some() {
+        // 2. PDB Conflict Check
+        if self.min_available.is_some() && self.max_unavailable.is_some() {
+            errors.push(SpecValidationError::new(
+                "spec.minAvailable / spec.maxUnavailable",
+                "Cannot specify both minAvailable and maxUnavailable in PDB configuration",
+                "Set either spec.minAvailable or spec.maxUnavailable in the spec, but not both at the same time.",
+            ));
+        }
+
+        if self.min_ready_replicas.is_some_and(|n| n < 1) {
+            errors.push(SpecValidationError::new(
+                "spec.minReadyReplicas",
+                "minReadyReplicas must be at least 1",
+                "Set spec.minReadyReplicas to 1 or greater, or remove it to wait for a single ready replica.",
+            ));
+        }
+
+        // 3. Node Type Specific Logic
+        match self.node_type {
+            NodeType::Validator => {
+                // Validator config required
+                if self.validator_config.is_none() {
+                    errors.push(SpecValidationError::new(
+                        "spec.validatorConfig",
+                        "validatorConfig is required for Validator nodes",
+                        "Add a spec.validatorConfig section with the required validator settings when nodeType is Validator.",
+                    ));
+                } else if let Some(vc) = &self.validator_config {
+                    if vc.enable_history_archive && vc.history_archive_urls.is_empty() {
+                        errors.push(SpecValidationError::new(
+                            "spec.validatorConfig.historyArchiveUrls",
+                            "historyArchiveUrls must not be empty when enableHistoryArchive is true",
+                            "Provide at least one valid history archive URL in spec.validatorConfig.historyArchiveUrls when enableHistoryArchive is true.",
+                        ));
+                    }
+                }
+
+                // Exactly 1 replica required
+                if self.replicas != 1 {
+                    errors.push(SpecValidationError::new(
+                        "spec.replicas",
+                        "Validator nodes must have exactly 1 replica",
+                        "Set spec.replicas to 1 for Validator nodes.",
+                    ));
+                }
+                if self.min_available.is_some() || self.max_unavailable.is_some() {
+                    errors.push(SpecValidationError::new(
+                        "spec.minAvailable / spec.maxUnavailable",
+                        "PDB configuration is not supported for Validator nodes (replicas must be 1)",
+                        "Remove PodDisruptionBudget fields (minAvailable/maxUnavailable) for Validator nodes; they must always have exactly 1 replica.",
+                    ));
+                }
+                if self.autoscaling.is_some() {
+                    errors.push(SpecValidationError::new(
+                        "spec.autoscaling",
+                        "autoscaling is not supported for Validator nodes",
+                        "Remove spec.autoscaling when nodeType is Validator; autoscaling is only supported for Horizon and SorobanRpc.",
+                    ));
+                }
+
+                // Ingress not supported
+                if self.ingress.is_some() {
+                    errors.push(SpecValidationError::new(
+                        "spec.ingress",
+                        "ingress is not supported for Validator nodes",
+                        "Remove spec.ingress for Validator nodes; expose Validator nodes using peer discovery or other supported mechanisms.",
+                    ));
+                }
+                if self.service.as_ref().is_some_and(|s| s.topology_aware) {
+                    errors.push(SpecValidationError::new(
+                        "spec.service.topologyAware",
+                        "topology-aware routing is not supported for Validator nodes",
+                        "Remove spec.service.topologyAware for Validator nodes; it only applies to multi-replica Horizon and SorobanRpc nodes.",
+                    ));
+                }
+                if matches!(self.strategy, RolloutStrategy::Canary(_)) {
+                    errors.push(SpecValidationError::new(
+                        "spec.strategy",
+                        "Canary rollout is not supported for Validator nodes",
+                        "Use a non-canary rollout strategy (e.g., RollingUpdate) for Validator nodes.",
+                    ));
+                }
+
+                // History archive validation
+                if let Some(ref validator_config) = self.validator_config {
+                    if let Some(ref beat) = validator_config.stellar_beat_reporting {
+                        validate_stellar_beat(beat, &mut errors);
+                    }
+                    validate_quorum_validators(&validator_config.quorum_validators, &mut errors);
+                    if let Some(ref watcher) = validator_config.quorum_slice_watcher {
+                        validate_quorum_slice_watcher(watcher, &mut errors);
+                    }
+                    if let Some(ref cache) = validator_config.history_cache {
+                        validate_history_cache(cache, &mut errors);
+                    }
+                    if let Some(ref publish) = validator_config.archive_publication_credentials {
+                        validate_archive_publication(publish, &mut errors);
+                    }
+                    if let Some(ref gc) = validator_config.history_gc {
+                        validate_history_gc(
+                            gc,
+                            validator_config.archive_publication_credentials.as_ref(),
+                            &mut errors,
+                        );
+                    }
+                    if let Some(ref stellar_toml) = validator_config.stellar_toml {
+                        validate_stellar_toml(stellar_toml, &mut errors);
+                    }
+                    validate_network_parameters(validator_config, &mut errors);
+                    validate_scp_timing(validator_config, &mut errors);
+                    validate_flood_settings(validator_config, &mut errors);
+                    if let Some(workers) = validator_config.catchup_workers {
+                        validate_catchup_workers(workers, &mut errors);
+                    }
+                    if let Some(ref bucket_list_db) = validator_config.bucket_list_db {
+                        validate_bucket_list_db(bucket_list_db, &mut errors);
+                    }
+                    if let Some(ref group) = validator_config.quorum_group {
+                        validate_quorum_group(group, &mut errors);
+                    }
+                    validate_protocol_version(validator_config, &self.network, &mut errors);
+                    if validator_config.enable_history_archive
+                        && validator_config.history_archive_urls.is_empty()
+                    {
+                        errors.push(SpecValidationError::new(
+                            "spec.validatorConfig.historyArchiveUrls",
+                            "historyArchiveUrls must not be empty when enableHistoryArchive is true",
+                            "Provide at least one valid history archive URL in spec.validatorConfig.historyArchiveUrls when enableHistoryArchive is true.",
+                        ));
+                    }
+                }
+
+                // Canary strategy not supported
+                if matches!(self.strategy, RolloutStrategy::Canary(_)) {
+                    errors.push(SpecValidationError::new(
+                        "spec.strategy",
+                        "canary rollout strategy is not supported for Validator nodes",
+                        "Use RollingUpdate strategy for Validator nodes; canary is only supported for Horizon and SorobanRpc.",
+                    ));
+                }
+            }
+            NodeType::Horizon => {
+                // Horizon config required
+                if self.horizon_config.is_none() {
+                    errors.push(SpecValidationError::new(
+                        "spec.horizonConfig",
+                        "horizonConfig is required for Horizon nodes",
+                        "Add a spec.horizonConfig section with the required Horizon settings when nodeType is Horizon.",
+                    ));
+                }
+                if let Some(ref h) = self.horizon_config {
+                    match h.captive_core {
+                        Some(ref captive) => validate_horizon_captive_core(h, captive, &mut errors),
+                        None => validate_stellar_core_endpoint(
+                            "spec.horizonConfig",
+                            &h.stellar_core_url,
+                            h.stellar_core_ref.as_ref(),
+                            &mut errors,
+                        ),
+                    }
+                    validate_horizon_history(h, &mut errors);
+                    validate_horizon_order_book(h, &mut errors);
+                    validate_min_stellar_core_version(h, &mut errors);
+                    if let Some(ref tls) = h.tls {
+                        validate_horizon_tls(tls, &mut errors);
+                    }
+                    if let Some(ref ssl) = h.database_ssl {
+                        validate_database_ssl(ssl, &self.network, &mut errors);
+                    }
+                    validate_cors_allowed_origins(&h.cors_allowed_origins, &mut errors);
+                    validate_cors_headers(h, &mut errors);
+                    if let Some(ref filtering) = h.transaction_filtering {
+                        validate_transaction_filtering(filtering, &self.network, &mut errors);
+                    }
+                    if let Some(ref filtering) = h.offers_filtering {
+                        validate_offers_filtering(filtering, &mut errors);
+                    }
+                }
+                if let Some(exp) = self
+                    .horizon_config
+                    .as_ref()
+                    .and_then(|h| h.experimental_ingestion.as_ref())
+                {
+                    let rules_missing = exp
+                        .filter_rules_file
+                        .as_deref()
+                        .unwrap_or_default()
+                        .trim()
+                        .is_empty();
+                    if exp.enable_filtering && rules_missing {
+                        errors.push(SpecValidationError::new(
+                            "spec.horizonConfig.experimentalIngestion.filterRulesFile",
+                            "filterRulesFile is required when enableFiltering is true",
+                            "Provide the ingestion filter rules in spec.horizonConfig.experimentalIngestion.filterRulesFile or set enableFiltering to false.",
+                        ));
+                    }
+                }
+                if let Some(ref autoscaling) = self.autoscaling {
+                    validate_autoscaling(autoscaling, &mut errors);
+                }
+                if let Some(ingress) = &self.ingress {
+                    validate_ingress(ingress, &mut errors);
+                }
+            }
+            NodeType::SorobanRpc => {
+                // Soroban config required
+                if self.soroban_config.is_none() {
+                    errors.push(SpecValidationError::new(
+                        "spec.sorobanConfig",
+                        "sorobanConfig is required for SorobanRpc nodes",
+                        "Add a spec.sorobanConfig section with the required Soroban RPC settings when nodeType is SorobanRpc.",
+                    ));
+                }
+                if let Some(ref sc) = self.soroban_config {
+                    validate_stellar_core_endpoint(
+                        "spec.sorobanConfig",
+                        &sc.stellar_core_url,
+                        sc.stellar_core_ref.as_ref(),
+                        &mut errors,
+                    );
+                    if sc.fee_config.is_some()
+                        && !matches!(
+                            self.network,
+                            StellarNetwork::Testnet | StellarNetwork::Futurenet
+                        )
+                    {
+                        errors.push(SpecValidationError::new(
+                            "spec.sorobanConfig.feeConfig",
+                            "feeConfig is only allowed on Testnet and Futurenet",
+                            "Remove spec.sorobanConfig.feeConfig; custom fee schedules would make simulation suggest incorrect fees on this network.",
+                        ));
+                    }
+                    validate_soroban_tx_queue(sc, &mut errors);
+                    validate_soroban_contract_cache(sc, &mut errors);
+                    validate_soroban_rpc_endpoint(sc, &mut errors);
+                    validate_soroban_persistence(sc, &mut errors);
+                    validate_soroban_debug_rpc(
+                        sc,
+                        &self.network,
+                        self.allow_debug_on_mainnet,
+                        &mut errors,
+                    );
+                    if let Some(ref archival) = sc.archival_storage {
+                        validate_archival_storage(archival, &mut errors);
+                    }
+                }
+                if let Some(ref autoscaling) = self.autoscaling {
+                    validate_autoscaling(autoscaling, &mut errors);
+                }
+                if let Some(ingress) = &self.ingress {
+                    validate_ingress(ingress, &mut errors);
+                }
+            }
+        }
+
+        // Validate optional features if present
+        if let Some(ref lb) = self.load_balancer {
+            validate_load_balancer(lb, &mut errors);
+        }
+        if let Some(ref gd) = self.global_discovery {
+            validate_global_discovery(gd, &mut errors);
+        }
+        if let Some(ref cc) = self.cross_cluster {
+            validate_cross_cluster(cc, &mut errors);
+        }
+        validate_resource_claims(&self.resources.claims, &mut errors);
+        if !self.extra_volume_mounts.is_empty() {
+            validate_extra_volume_mounts(&self.extra_volume_mounts, &mut errors);
+        }
+        for (i, dependency) in self.depends_on.iter().enumerate() {
+            if dependency.name.trim().is_empty() {
+                errors.push(SpecValidationError::new(
+                    format!("spec.dependsOn[{i}].name"),
+                    "dependsOn[].name must not be empty",
+                    "Set name to the StellarNode this node waits for.",
+                ));
+            }
+        }
+        if let Some(ref autoscaling) = self.autoscaling {
+            validate_custom_resource_metrics(&autoscaling.custom_resource_metrics, &mut errors);
+        }
+        if let Some(ref image_override) = self.image_override {
+            validate_image_override(image_override, &mut errors);
+        }
+        if let Some(ref service) = self.service {
+            validate_session_affinity(service, &mut errors);
+            validate_external_traffic_policy(service, &mut errors);
+        }
+        if let Some(ref hooks) = self.hooks {
+            validate_hooks(hooks, &mut errors);
+        }
+        if let Some(ref vault) = self.vault {
+            validate_vault(vault, &mut errors);
+        }
+        if let Some(ref dashboard) = self.grafana_dashboard {
+            validate_grafana_dashboard(dashboard, &mut errors);
+        }
+        if let Some(ref hook) = self.precheck_hook {
+            validate_precheck_hook(hook, &mut errors);
+        }
+        if let Some(ref path) = self.termination_message_path {
+            if !path.starts_with('/') {
+                errors.push(SpecValidationError::new(
+                    "spec.terminationMessagePath",
+                    "terminationMessagePath must be an absolute path",
+                    "Set spec.terminationMessagePath to an absolute file path, e.g. /dev/termination-log.",
+                ));
+            }
+        }
+        if let Err(e) = crate::controller::check_spec_templates(self) {
+            errors.push(SpecValidationError::new(
+                "spec.configTemplateMode",
+                e.to_string(),
+                "Fix the Go template syntax in the config values, or set configTemplateMode to Static.",
+            ));
+        }
+        if let Some(ref security_context) = self.container_security_context {
+            validate_container_security_context(security_context, self.dev_mode, &mut errors);
+        }
+        if let Some(hook) = self.lifecycle.as_ref().and_then(|l| l.post_start.as_ref()) {
+            validate_lifecycle_hook("spec.lifecycle.postStart", hook, &mut errors);
+        }
+        if let Some(ref network_policy) = self.network_policy {
+            validate_network_policy(network_policy, &mut errors);
+        }
+        if let Some(ref rules) = self.prometheus_rules {
+            validate_alert_rules(&rules.extra_rules, &mut errors);
+        }
+        if let Some(ref monitoring) = self.monitoring {
+            validate_monitoring(monitoring, &mut errors);
+        }
+        if self
+            .requeue_jitter_percent
+            .is_some_and(|percent| percent > u64::from(MAX_REQUEUE_JITTER_PERCENT))
+        {
+            errors.push(SpecValidationError::new(
+                "spec.requeueJitterPercent",
+                format!("requeueJitterPercent must be between 0 and {MAX_REQUEUE_JITTER_PERCENT}"),
+                format!("Set spec.requeueJitterPercent to {MAX_REQUEUE_JITTER_PERCENT} or less, or remove it to use the operator default."),
+            ));
+        }
+        if let Some(ref policy) = self.failure_policy {
+            validate_node_failure_policy(policy, &mut errors);
+        }
+        if let Some(ref constraint) = self.image_version_constraint {
+            validate_image_version_constraint(
+                constraint,
+                &self.version,
+                self.image_override.as_ref(),
+                &mut errors,
+            );
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+
+    /// Non-fatal issues with the spec that should be surfaced to the user
+    ///
+    /// Unlike [`validate`](Self::validate), these never block reconciliation.
+    pub fn validation_warnings(&self) -> Vec<SpecValidationError> {
+        let mut warnings = Vec::new();
+
+        if let Some(fees) = self
+            .soroban_config
+            .as_ref()
+            .and_then(|sc| sc.fee_config.as_ref())
+        {
+            for (field, _, value) in fees.entries() {
+                if value == 0 {
+                    warnings.push(SpecValidationError::new(
+                        format!("spec.sorobanConfig.feeConfig.{field}"),
+                        format!("{field} is 0; simulation may suggest transactions with insufficient fees"),
+                        format!("Set spec.sorobanConfig.feeConfig.{field} to a non-zero fee, or remove it to use the network fee schedule."),
+                    ));
+                }
+            }
+        }
+
+        if let Some(vc) = self
+            .validator_config
+            .as_ref()
+            .filter(|_| self.node_type == NodeType::Validator)
+        {
+            if vc
+                .base_fee
+                .is_some_and(|fee| fee > 0 && fee < MIN_NETWORK_BASE_FEE)
+            {
+                warnings.push(SpecValidationError::new(
+                    "spec.validatorConfig.baseFee",
+                    format!("baseFee is below the Stellar network minimum of {MIN_NETWORK_BASE_FEE} stroops required on Mainnet"),
+                    format!("Set spec.validatorConfig.baseFee to {MIN_NETWORK_BASE_FEE} or more."),
+                ));
+            }
+            if vc
+                .scp_ledger_target_duration_ms
+                .is_some_and(|ms| ms > 0 && ms < MIN_RECOMMENDED_SCP_LEDGER_TARGET_DURATION_MS)
+            {
+                warnings.push(SpecValidationError::new(
+                    "spec.validatorConfig.scpLedgerTargetDurationMs",
+                    format!("scpLedgerTargetDurationMs below {MIN_RECOMMENDED_SCP_LEDGER_TARGET_DURATION_MS} risks missing consensus rounds on high-latency networks"),
+                    format!("Set spec.validatorConfig.scpLedgerTargetDurationMs to {MIN_RECOMMENDED_SCP_LEDGER_TARGET_DURATION_MS} or more."),
+                ));
+            }
+            let has_parameters = vc.max_tx_set_size.is_some() || vc.base_fee.is_some();
+            if has_parameters && !self.network_parameters_allowed() {
+                warnings.push(SpecValidationError::new(
+                    "spec.allowMainnetParameterOverride",
+                    "maxTxSetSize and baseFee are ignored on Mainnet",
+                    "Set spec.allowMainnetParameterOverride to true to vote for these parameters on Mainnet.",
+                ));
+            }
+        }
+
+        let streaming_without_sse = self.node_type == NodeType::Horizon
+            && self.horizon_config.as_ref().is_some_and(|h| {
+                h.enable_order_book_streaming && h.sse_max_connections.unwrap_or(0) == 0
+            });
+        let local_traffic = self
+            .service
+            .as_ref()
+            .is_some_and(|s| s.external_traffic_policy == Some(ExternalTrafficPolicy::Local));
+        if local_traffic {
+            warnings.push(SpecValidationError::new(
+                "spec.service.externalTrafficPolicy",
+                "externalTrafficPolicy Local only routes to nodes running a pod; load balancer backends on nodes without one are reported unhealthy",
+                "Run at least one pod on every node receiving traffic (e.g. more replicas with pod anti-affinity), or use Cluster if client source IPs are not needed.",
+            ));
+        }
+
+        if streaming_without_sse {
+            warnings.push(SpecValidationError::new(
+                "spec.horizonConfig.sseMaxConnections",
+                "enableOrderBookStreaming is true but sseMaxConnections is not set; streaming clients may be refused",
+                "Set spec.horizonConfig.sseMaxConnections to the number of concurrent streaming clients you expect.",
+            ));
+        }
+
+        if self.alerting && self.prometheus_rule_enabled() {
+            warnings.push(SpecValidationError::new(
+                "spec.alerting",
+                "alerting is ignored when prometheusRules is set; only the PrometheusRule is generated",
+                "Remove spec.alerting, or remove spec.prometheusRules to keep the alerting ConfigMap.",
+            ));
+        }
+
+        warnings
+    }
+
+    /// Whether a `PrometheusRule` should be generated for this node
+    pub fn prometheus_rule_enabled(&self) -> bool {
+        self.prometheus_rules.is_some()
+    }
+
+    /// Whether the legacy alerting ConfigMap should be generated for this node
+    ///
+    /// `prometheusRules` wins over `alerting` when both are set.
+    pub fn alerting_config_map_enabled(&self) -> bool {
+        self.alerting && !self.prometheus_rule_enabled()
+    }
+
+    /// Whether a `ServiceMonitor` should be generated for this node
+    pub fn service_monitor_enabled(&self) -> bool {
+        self.monitoring.as_ref().is_some_and(|m| m.enabled)
+    }
+
+    /// Resolve the container image for this node
+    ///
+    /// Priority: `imageOverride.digest` > `imageOverride.tag` > `version`.
+    pub fn container_image(&self) -> String {
+        let name = match self.node_type {
+            NodeType::Validator => "stellar-core",
+            _ => "horizon",
+        };
+        let image_override = self.image_override.as_ref();
+        let repository = image_override
+            .and_then(|o| o.repository.clone())
+            .unwrap_or_else(|| format!("stellar/{name}"));
+
+        if let Some(digest) = image_override.and_then(|o| o.digest.as_ref()) {
+            return format!("{repository}@{digest}");
+        }
+        let tag = image_override
+            .and_then(|o| o.tag.as_deref())
+            .unwrap_or(&self.version);
+        format!("{repository}:{tag}")
+    }
+
+    /// Image pull policy from `imageOverride.pullPolicy`, if set
+    pub fn image_pull_policy(&self) -> Option<String> {
+        self.image_override
+            .as_ref()
+            .and_then(|o| o.pull_policy.clone())
+    }
+
+    /// Whether validator network parameter votes may be written to the config
+    ///
+    /// Always allowed off Mainnet; on Mainnet only with
+    /// `allowMainnetParameterOverride`.
+    pub fn network_parameters_allowed(&self) -> bool {
+        self.network != StellarNetwork::Mainnet || self.allow_mainnet_parameter_override
+    }
+
+    /// URL clients should use to reach the node, reported in `status.endpoint`
+    ///
+    /// Only Soroban RPC advertises one: its `friendlyRpcUrl`, or else the
+    /// in-cluster Service URL with the configured RPC path.
+    pub fn advertised_endpoint(&self, name: &str, namespace: &str) -> Option<String> {
+        if self.node_type != NodeType::SorobanRpc {
+            return None;
+        }
+        let config = self.soroban_config.as_ref()?;
+        Some(config.friendly_rpc_url.clone().unwrap_or_else(|| {
+            let path = config.rpc_path().trim_start_matches('/');
+            format!("http://{name}.{namespace}.svc.cluster.local:8000/{path}")
+        }))
+    }
+
+    /// Container-level TLS settings, for Horizon nodes only
+    pub fn horizon_tls(&self) -> Option<&HorizonTlsConfig> {
+        match self.node_type {
+            NodeType::Horizon => self.horizon_config.as_ref()?.tls.as_ref(),
+            _ => None,
+        }
+    }
+
+    /// PostgreSQL TLS settings, for Horizon nodes only
+    pub fn horizon_database_ssl(&self) -> Option<&DatabaseSslConfig> {
+        match self.node_type {
+            NodeType::Horizon => self.horizon_config.as_ref()?.database_ssl.as_ref(),
+            _ => None,
+        }
+    }
+
+    /// ConfigMap with the DEX offer filter rules, for Horizon nodes with filtering enabled
+    pub fn horizon_filter_rules_config_map(&self) -> Option<&str> {
+        match self.node_type {
+            NodeType::Horizon => self
+                .horizon_config
+                .as_ref()?
+                .offers_filtering
+                .as_ref()
+                .filter(|f| f.enabled)?
+                .filter_rules_configmap_ref
+                .as_deref(),
+            _ => None,
+        }
+    }
+
+    /// Whether JSON-RPC payload logging is on, for Soroban RPC nodes only
+    pub fn soroban_debug_rpc(&self) -> bool {
+        self.node_type == NodeType::SorobanRpc
+            && self.soroban_config.as_ref().is_some_and(|c| c.debug_rpc)
+    }
+
+    /// Soroban state archival settings, for Soroban RPC nodes only
+    pub fn soroban_archival_storage(&self) -> Option<&ArchivalStorageConfig> {
+        match self.node_type {
+            NodeType::SorobanRpc => self.soroban_config.as_ref()?.archival_storage.as_ref(),
+            _ => None,
+        }
+    }
+
+    /// Resolve the Stellar Core URL for Horizon and Soroban RPC nodes
+    ///
+    /// A `stellarCoreRef` is resolved to the sibling's cluster-internal FQDN;
+    /// otherwise the raw `stellarCoreUrl` is used.
+    pub fn resolved_stellar_core_url(&self, namespace: &str) -> Option<String> {
+        let (url, core_ref) = match self.node_type {
+            NodeType::Horizon => self
+                .horizon_config
+                .as_ref()
+                .map(|c| (&c.stellar_core_url, &c.stellar_core_ref))?,
+            NodeType::SorobanRpc => self
+                .soroban_config
+                .as_ref()
+                .map(|c| (&c.stellar_core_url, &c.stellar_core_ref))?,
+            NodeType::Validator => return None,
+        };
+        Some(match core_ref {
+            Some(r) => r.core_http_url(namespace),
+            None => url.clone(),
+        })
+    }
+
+    /// Ready replicas required before the phase moves from Progressing to Running
+    pub fn min_ready_replicas(&self) -> i32 {
+        self.min_ready_replicas
+            .unwrap_or(1)
+            .min(self.replicas.max(0))
+    }
+
+    /// Whether an HPA should be managed for this node
+    pub fn autoscaling_enabled(&self) -> bool {
+        matches!(self.node_type, NodeType::Horizon | NodeType::SorobanRpc)
+            && self.autoscaling.as_ref().is_some_and(|a| a.enabled)
+    }
+
+    /// Whether an Ingress should be managed for this node
+    pub fn ingress_enabled(&self) -> bool {
+        matches!(self.node_type, NodeType::Horizon | NodeType::SorobanRpc) && self.ingress.is_some()
+    }
+
+    /// Whether a NetworkPolicy should be managed for this node
+    pub fn network_policy_enabled(&self) -> bool {
+        self.network_policy.as_ref().is_some_and(|p| p.enabled)
+    }
+
+    pub fn should_delete_pvc(&self) -> bool {
+        self.storage.retention_policy == RetentionPolicy::Delete
+    }
+}
+fn validate_stellar_beat(beat: &StellarBeatConfig, errors: &mut Vec<SpecValidationError>) {
+    if beat.home_domain.trim().is_empty() {
+        errors.push(SpecValidationError::new(
+            "spec.validatorConfig.stellarBeatReporting.homeDomain",
+            "stellarBeatReporting.homeDomain must not be empty",
+            "Set homeDomain to the domain hosting the validator's stellar.toml.",
+        ));
+    }
+    if !(beat.public_key.len() == 56 && beat.public_key.starts_with('G')) {
+        errors.push(SpecValidationError::new(
+            "spec.validatorConfig.stellarBeatReporting.publicKey",
+            "stellarBeatReporting.publicKey must be a 56-character public key starting with 'G'",
+            "Set publicKey to the validator's public key (G...).",
+        ));
+    }
+    if beat.poll_interval_secs == 0 {
+        errors.push(SpecValidationError::new(
+            "spec.validatorConfig.stellarBeatReporting.pollIntervalSecs",
+            "stellarBeatReporting.pollIntervalSecs must be greater than 0",
+            "Set pollIntervalSecs to a positive number of seconds (default: 300).",
+        ));
+    }
+}
+
+fn validate_network_parameters(cfg: &ValidatorConfig, errors: &mut Vec<SpecValidationError>) {
+    if cfg.max_tx_set_size == Some(0) {
+        errors.push(SpecValidationError::new(
+            "spec.validatorConfig.maxTxSetSize",
+            "maxTxSetSize must be greater than 0; an empty transaction set halts the network",
+            "Set spec.validatorConfig.maxTxSetSize to 1 or greater, or remove it to use the default of 500.",
+        ));
+    }
+    if cfg.base_fee == Some(0) {
+        errors.push(SpecValidationError::new(
+            "spec.validatorConfig.baseFee",
+            "baseFee must be greater than 0; free transactions leave the network open to spam",
+            format!(
+                "Set spec.validatorConfig.baseFee to at least {MIN_NETWORK_BASE_FEE} stroops, or remove it to use the default."
+            ),
+        ));
+    }
+}
+
+fn validate_scp_timing(cfg: &ValidatorConfig, errors: &mut Vec<SpecValidationError>) {
+    if cfg
+        .scp_ballot_timeout_ms
+        .is_some_and(|ms| ms < MIN_SCP_BALLOT_TIMEOUT_MS)
+    {
+        errors.push(SpecValidationError::new(
+            "spec.validatorConfig.scpBallotTimeoutMs",
+            format!(
+                "scpBallotTimeoutMs must be at least {MIN_SCP_BALLOT_TIMEOUT_MS}; shorter timers cause excessive SCP messages"
+            ),
+            "Set spec.validatorConfig.scpBallotTimeoutMs to 100 or more, or remove it to use the default of 1000.",
+        ));
+    }
+    if cfg.scp_ledger_target_duration_ms == Some(0) {
+        errors.push(SpecValidationError::new(
+            "spec.validatorConfig.scpLedgerTargetDurationMs",
+            "scpLedgerTargetDurationMs must be greater than 0",
+            "Set spec.validatorConfig.scpLedgerTargetDurationMs, or remove it to use the default of 5000.",
+        ));
+    }
+}
+
+fn validate_flood_settings(cfg: &ValidatorConfig, errors: &mut Vec<SpecValidationError>) {
+    if let Some(ms) = cfg.flood_tx_period_ms {
+        if !(MIN_FLOOD_TX_PERIOD_MS..=MAX_FLOOD_TX_PERIOD_MS).contains(&ms) {
+            errors.push(SpecValidationError::new(
+                "spec.validatorConfig.floodTxPeriodMs",
+                format!(
+                    "floodTxPeriodMs {ms} is out of range ({MIN_FLOOD_TX_PERIOD_MS}-{MAX_FLOOD_TX_PERIOD_MS})"
+                ),
+                format!(
+                    "Set spec.validatorConfig.floodTxPeriodMs between {MIN_FLOOD_TX_PERIOD_MS} and {MAX_FLOOD_TX_PERIOD_MS}, or remove it to use the default of 200."
+                ),
+            ));
+        }
+    }
+    if let Some(count) = cfg.max_batch_write_count {
+        if count == 0 || count > MAX_BATCH_WRITE_COUNT_LIMIT {
+            errors.push(SpecValidationError::new(
+                "spec.validatorConfig.maxBatchWriteCount",
+                format!(
+                    "maxBatchWriteCount {count} is out of range (1-{MAX_BATCH_WRITE_COUNT_LIMIT})"
+                ),
+                format!(
+                    "Set spec.validatorConfig.maxBatchWriteCount between 1 and {MAX_BATCH_WRITE_COUNT_LIMIT}, or remove it to use the default of 1024."
+                ),
+            ));
+        }
+    }
+}
+
+fn validate_catchup_workers(workers: u32, errors: &mut Vec<SpecValidationError>) {
+    if !(1..=MAX_CATCHUP_WORKERS).contains(&workers) {
+        errors.push(SpecValidationError::new(
+            "spec.validatorConfig.catchupWorkers",
+            format!("catchupWorkers {workers} is out of range (1-{MAX_CATCHUP_WORKERS})"),
+            format!(
+                "Set spec.validatorConfig.catchupWorkers between 1 and {MAX_CATCHUP_WORKERS}, or remove it to use the default of 16."
+            ),
+        ));
+    }
+}
+
+fn validate_bucket_list_db(cfg: &BucketListDbConfig, errors: &mut Vec<SpecValidationError>) {
+    if let Some(exponent) = cfg.index_page_size_exponent {
+        if exponent > MAX_BUCKETLIST_DB_PAGE_SIZE_EXPONENT {
+            errors.push(SpecValidationError::new(
+                "spec.validatorConfig.bucketListDb.indexPageSizeExponent",
+                format!(
+                    "indexPageSizeExponent {exponent} is out of range (0-{MAX_BUCKETLIST_DB_PAGE_SIZE_EXPONENT})"
+                ),
+                format!(
+                    "Set spec.validatorConfig.bucketListDb.indexPageSizeExponent between 0 and {MAX_BUCKETLIST_DB_PAGE_SIZE_EXPONENT}, or remove it to use the default of {DEFAULT_BUCKETLIST_DB_PAGE_SIZE_EXPONENT}."
+                ),
+            ));
+        }
+    }
+    if let Some(cutoff) = cfg.index_cutoff_mb {
+        if cutoff > MAX_BUCKETLIST_DB_INDEX_CUTOFF_MB {
+            errors.push(SpecValidationError::new(
+                "spec.validatorConfig.bucketListDb.indexCutoffMb",
+                format!(
+                    "indexCutoffMb {cutoff} is out of range (0-{MAX_BUCKETLIST_DB_INDEX_CUTOFF_MB})"
+                ),
+                format!(
+                    "Set spec.validatorConfig.bucketListDb.indexCutoffMb between 0 and {MAX_BUCKETLIST_DB_INDEX_CUTOFF_MB}, or remove it to use the default of {DEFAULT_BUCKETLIST_DB_INDEX_CUTOFF_MB}."
+                ),
+            ));
+        }
+    }
+}
+
+fn validate_protocol_version(
+    cfg: &ValidatorConfig,
+    network: &StellarNetwork,
+    errors: &mut Vec<SpecValidationError>,
+) {
+    match cfg.protocol_version_url {
+        Some(ref url) if !(url.starts_with("https://") || url.starts_with("http://")) => {
+            errors.push(SpecValidationError::new(
+                "spec.validatorConfig.protocolVersionUrl",
+                format!("protocolVersionUrl '{url}' must be an http(s) URL"),
+                "Set spec.validatorConfig.protocolVersionUrl to a Horizon URL, e.g. https://horizon.example.org.",
+            ));
+        }
+        None if cfg.minimum_protocol_version.is_some()
+            && network.public_horizon_url().is_none() =>
+        {
+            errors.push(SpecValidationError::new(
+                "spec.validatorConfig.protocolVersionUrl",
+                "protocolVersionUrl is required for minimumProtocolVersion on a custom network",
+                "Set spec.validatorConfig.protocolVersionUrl to a Horizon serving the custom network.",
+            ));
+        }
+        _ => {}
+    }
+}
+
+/// `quorumGroup` is used as a label value, so it must be a valid one
+fn validate_quorum_group(group: &str, errors: &mut Vec<SpecValidationError>) {
+    let valid = !group.is_empty()
+        && group.len() <= 63
+        && group
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.'))
+        && group.starts_with(|c: char| c.is_ascii_alphanumeric())
+        && group.ends_with(|c: char| c.is_ascii_alphanumeric());
+    if !valid {
+        errors.push(SpecValidationError::new(
+            "spec.validatorConfig.quorumGroup",
+            format!("quorumGroup '{group}' is not a valid Kubernetes label value"),
+            "Use at most 63 letters, digits, '-', '_' or '.', starting and ending with a letter or digit.",
+        ));
+    }
+}
+
+fn validate_node_failure_policy(policy: &NodeFailurePolicy, errors: &mut Vec<SpecValidationError>) {
+    const FIELD: &str = "spec.nodeFailurePolicy";
+
+    match *policy {
+        NodeFailurePolicy::Requeue(0) => {
+            errors.push(SpecValidationError::new(
+                format!("{FIELD}.requeue"),
+                "requeue must be greater than 0",
+                "Set the retry interval to a positive number of seconds.",
+            ));
+        }
+        NodeFailurePolicy::ExponentialBackoff { initial, max } if initial == 0 || max < initial => {
+            errors.push(SpecValidationError::new(
+                format!("{FIELD}.exponentialBackoff"),
+                "initial must be greater than 0 and max at least initial",
+                "Set exponentialBackoff.initial to a positive number of seconds and max to initial or more.",
+            ));
+        }
+        NodeFailurePolicy::AlarmAndStop { max_failures: 0 } => {
+            errors.push(SpecValidationError::new(
+                format!("{FIELD}.alarmAndStop.maxFailures"),
+                "maxFailures must be greater than 0",
+                "Set maxFailures to the number of consecutive failures to tolerate before stopping.",
+            ));
+        }
+        _ => {}
+    }
+}
+
+fn validate_resource_claims(claims: &[String], errors: &mut Vec<SpecValidationError>) {
+    let mut seen = std::collections::BTreeSet::new();
+    for (i, claim) in claims.iter().enumerate() {
+        let field = format!("spec.resources.claims[{i}]");
+        let valid = !claim.is_empty()
+            && claim.len() <= 63
+            && claim
+                .chars()
+                .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-')
+            && claim.starts_with(|c: char| c.is_ascii_alphanumeric())
+            && claim.ends_with(|c: char| c.is_ascii_alphanumeric());
+        if !valid {
+            errors.push(SpecValidationError::new(
+                field,
+                format!("'{claim}' is not a valid ResourceClaim name"),
+                "Use at most 63 lowercase letters, digits or '-', starting and ending with a letter or digit.",
+            ));
+        } else if !seen.insert(claim.as_str()) {
+            errors.push(SpecValidationError::new(
+                field,
+                format!("ResourceClaim {claim} is listed more than once"),
+                "List each ResourceClaim only once in spec.resources.claims.",
+            ));
+        }
+    }
+}
+
+fn validate_stellar_toml(cfg: &StellarTomlConfig, errors: &mut Vec<SpecValidationError>) {
+    let field = "spec.validatorConfig.stellarToml";
+    for (name, value) in [
+        ("organizationName", &cfg.organization_name),
+        ("nodeAlias", &cfg.node_alias),
+    ] {
+        if value.trim().is_empty() {
+            errors.push(SpecValidationError::new(
+                format!("{field}.{name}"),
+                format!("{name} must not be empty"),
+                format!("Set {field}.{name}."),
+            ));
+        }
+    }
+    if !(cfg.public_key.len() == 56 && cfg.public_key.starts_with('G')) {
+        errors.push(SpecValidationError::new(
+            format!("{field}.publicKey"),
+            "publicKey must be a 56-character public key starting with 'G'",
+            "Set publicKey to this validator's public key (G...).",
+        ));
+    }
+    if cfg.home_domain().is_none() || !cfg.organization_url.starts_with("https://") {
+        errors.push(SpecValidationError::new(
+            format!("{field}.organizationUrl"),
+            format!("organizationUrl '{}' is not an https URL", cfg.organization_url),
+            "Set organizationUrl to your home domain, e.g. https://example.org; stellar.toml is served for its host.",
+        ));
+    }
+    if !(cfg.history_url.starts_with("https://") || cfg.history_url.starts_with("http://")) {
+        errors.push(SpecValidationError::new(
+            format!("{field}.historyUrl"),
+            format!("historyUrl '{}' is not an http(s) URL", cfg.history_url),
+            "Set historyUrl to the public URL of this validator's history archive.",
+        ));
+    }
+    if cfg.cert_manager_issuer.is_some() == cfg.cert_manager_cluster_issuer.is_some() {
+        errors.push(SpecValidationError::new(
+            field,
+            "exactly one of certManagerIssuer or certManagerClusterIssuer must be set",
+            format!("Set {field}.certManagerIssuer or {field}.certManagerClusterIssuer to the cert-manager issuer for the home domain certificate."),
+        ));
+    }
+}
+
+fn validate_quorum_slice_watcher(
+    cfg: &QuorumSliceWatcherConfig,
+    errors: &mut Vec<SpecValidationError>,
+) {
+    let field = "spec.validatorConfig.quorumSliceWatcher";
+    if cfg.check_interval_seconds == 0 {
+        errors.push(SpecValidationError::new(
+            format!("{field}.checkIntervalSeconds"),
+            "checkIntervalSeconds must be greater than 0",
+            "Set checkIntervalSeconds to a positive number of seconds (default: 60).",
+        ));
+    }
+    if cfg.enabled && cfg.alert_on_missing_validators.is_empty() {
+        errors.push(SpecValidationError::new(
+            format!("{field}.alertOnMissingValidators"),
+            "alertOnMissingValidators must not be empty when the watcher is enabled",
+            "List the public keys of the quorum validators to alert on, or set enabled to false.",
+        ));
+    }
+    for (i, key) in cfg.alert_on_missing_validators.iter().enumerate() {
+        if !(key.len() == 56 && key.starts_with('G')) {
+            errors.push(SpecValidationError::new(
+                format!("{field}.alertOnMissingValidators[{i}]"),
+                "entries must be 56-character public keys starting with 'G'",
+                "List validators by public key (G...).",
+            ));
+        }
+    }
+}
+
+fn validate_history_cache(cfg: &HistoryCacheConfig, errors: &mut Vec<SpecValidationError>) {
+    let field = "spec.validatorConfig.historyCache";
+    let positive = cfg
+        .size
+        .chars()
+        .take_while(|c| c.is_ascii_digit() || matches!(c, '+' | '.'))
+        .any(|c| ('1'..='9').contains(&c));
+    if !is_valid_quantity(&cfg.size) || !positive {
+        errors.push(SpecValidationError::new(
+            format!("{field}.size"),
+            format!("size '{}' is not a positive Kubernetes quantity", cfg.size),
+            "Set size to a storage quantity such as \"20Gi\".",
+        ));
+    }
+    if cfg.volume_type == HistoryCacheVolumeType::EmptyDir && cfg.storage_class.is_some() {
+        errors.push(SpecValidationError::new(
+            format!("{field}.storageClass"),
+            "storageClass only applies to the PersistentVolumeClaim volume type",
+            "Remove storageClass or set volumeType to PersistentVolumeClaim.",
+        ));
+    }
+}
+
+fn validate_quorum_validators(
+    validators: &[QuorumValidatorSpec],
+    errors: &mut Vec<SpecValidationError>,
+) {
+    let mut seen = std::collections::BTreeSet::new();
+    for (i, validator) in validators.iter().enumerate() {
+        let field = format!("spec.validatorConfig.quorumValidators[{i}]");
+        if !(validator.public_key.len() == 56 && validator.public_key.starts_with('G')) {
+            errors.push(SpecValidationError::new(
+                format!("{field}.publicKey"),
+                "publicKey must be a 56-character public key starting with 'G'",
+                "Set publicKey to the validator's public key (G...).",
+            ));
+        } else if !seen.insert(validator.public_key.as_str()) {
+            errors.push(SpecValidationError::new(
+                format!("{field}.publicKey"),
+                format!(
+                    "validator {} is listed more than once",
+                    validator.public_key
+                ),
+                "List each validator only once in spec.validatorConfig.quorumValidators.",
+            ));
+        }
+        if validator.name.trim().is_empty() {
+            errors.push(SpecValidationError::new(
+                format!("{field}.name"),
+                "name must not be empty",
+                "Give each quorum validator a short alias, e.g. sdf_1.",
+            ));
+        }
+        if validator.home_domain.trim().is_empty() {
+            errors.push(SpecValidationError::new(
+                format!("{field}.homeDomain"),
+                "homeDomain must not be empty",
+                "Set homeDomain to the domain hosting the validator's stellar.toml.",
+            ));
+        }
+    }
+}
+
+fn validate_extra_volume_mounts(
+    mounts: &[ExtraVolumeMount],
+    errors: &mut Vec<SpecValidationError>,
+) {
+    let mut seen = std::collections::HashSet::new();
+    for mount in mounts {
+        if mount.name.trim().is_empty() {
+            errors.push(SpecValidationError::new(
+                "spec.extraVolumeMounts[].name",
+                "extraVolumeMounts[].name must not be empty",
+                "Set name to an operator-managed volume such as \"config\" or \"data\".",
+            ));
+        }
+        if !mount.mount_path.starts_with('/') {
+            errors.push(SpecValidationError::new(
+                "spec.extraVolumeMounts[].mountPath",
+                format!("mountPath '{}' must be an absolute path", mount.mount_path),
+                "Use an absolute path such as /etc/stellar/stellar-core.cfg.",
+            ));
+        } else if !seen.insert(mount.mount_path.as_str()) {
+            errors.push(SpecValidationError::new(
+                "spec.extraVolumeMounts[].mountPath",
+                format!("mountPath '{}' is used more than once", mount.mount_path),
+                "Give each extra volume mount a unique mountPath.",
+            ));
+        }
+        if let Some(ref sub_path) = mount.sub_path {
+            if sub_path.is_empty()
+                || sub_path.starts_with('/')
+                || sub_path.split('/').any(|part| part == "..")
+            {
+                errors.push(SpecValidationError::new(
+                    "spec.extraVolumeMounts[].subPath",
+                    format!("subPath '{sub_path}' must be a relative path without '..'"),
+                    "Use a path relative to the volume root, e.g. stellar-core.cfg.",
+                ));
+            }
+        }
+    }
+}
+
+fn validate_stellar_core_endpoint(
+    prefix: &str,
+    url: &str,
+    core_ref: Option<&StellarNodeRef>,
+    errors: &mut Vec<SpecValidationError>,
+) {
+    match core_ref {
+        Some(r) if r.name.trim().is_empty() => errors.push(SpecValidationError::new(
+            format!("{prefix}.stellarCoreRef.name"),
+            "stellarCoreRef.name must not be empty",
+            "Set stellarCoreRef.name to the name of a validator StellarNode.",
+        )),
+        Some(_) => {}
+        None if url.trim().is_empty() => errors.push(SpecValidationError::new(
+            format!("{prefix}.stellarCoreUrl"),
+            "either stellarCoreUrl or stellarCoreRef must be set",
+            "Set stellarCoreUrl to a Stellar Core endpoint or stellarCoreRef to a sibling validator StellarNode.",
+        )),
+        None => {}
+    }
+}
+
+fn validate_archive_publication(
+    publish: &HistoryArchivePublishConfig,
+    errors: &mut Vec<SpecValidationError>,
+) {
+    const FIELD: &str = "spec.validatorConfig.archivePublicationCredentials";
+
+    if publish.s3_bucket.is_some() && publish.azure_blob.is_some() {
+        errors.push(SpecValidationError::new(
+            FIELD,
+            "s3Bucket and azureBlob are mutually exclusive",
+            "Configure either s3Bucket or azureBlob for history archive publication, not both.",
+        ));
+    }
+    if let Some(ref azure) = publish.azure_blob {
+        for (name, value) in [
+            ("accountName", &azure.account_name),
+            ("containerName", &azure.container_name),
+            ("sasTokenSecret", &azure.sas_token_secret),
+        ] {
+            if value.trim().is_empty() {
+                errors.push(SpecValidationError::new(
+                    format!("{FIELD}.azureBlob.{name}"),
+                    format!("azureBlob.{name} must not be empty"),
+                    format!("Set {FIELD}.azureBlob.{name}."),
+                ));
+            }
+        }
+    }
+}
+
+fn validate_history_gc(
+    gc: &HistoryGcConfig,
+    publish: Option<&HistoryArchivePublishConfig>,
+    errors: &mut Vec<SpecValidationError>,
+) {
+    const FIELD: &str = "spec.validatorConfig.historyGCPolicy";
+
+    if !gc.enabled {
+        return;
+    }
+    if publish.and_then(|p| p.archive_url()).is_none() {
+        errors.push(SpecValidationError::new(
+            FIELD,
+            "historyGCPolicy requires a published history archive",
+            "Set spec.validatorConfig.archivePublicationCredentials with s3Bucket or azureBlob, or disable historyGCPolicy.",
+        ));
+    }
+    if gc.retention_count == 0 {
+        errors.push(SpecValidationError::new(
+            format!("{FIELD}.retentionCount"),
+            "retentionCount must be at least 1",
+            "Set retentionCount to the number of checkpoints to keep.",
+        ));
+    }
+    if let Err(e) = gc.schedule() {
+        errors.push(SpecValidationError::new(
+            format!("{FIELD}.gcSchedule"),
+            format!("invalid gcSchedule '{}': {e}", gc.gc_schedule),
+            "Use a five-field cron expression, e.g. \"0 3 * * *\" for daily at 03:00 UTC.",
+        ));
+    }
+    if gc.image.trim().is_empty() {
+        errors.push(SpecValidationError::new(
+            format!("{FIELD}.image"),
+            "image must not be empty",
+            "Set image to a container image providing stellar-archivist, or remove it to use the default.",
+        ));
+    }
+}
+
+fn validate_autoscaling(cfg: &AutoscalingConfig, errors: &mut Vec<SpecValidationError>) {
+    if cfg.min_replicas < 1 {
+        errors.push(SpecValidationError::new(
+            "spec.autoscaling.minReplicas",
+            "autoscaling.minReplicas must be at least 1",
+            "Set spec.autoscaling.minReplicas to 1 or greater.",
+        ));
+    }
+    if cfg.max_replicas < cfg.min_replicas {
+        errors.push(SpecValidationError::new(
+            "spec.autoscaling.maxReplicas",
+            "autoscaling.maxReplicas must be >= minReplicas",
+            "Set spec.autoscaling.maxReplicas to be greater than or equal to minReplicas.",
+        ));
+    }
+    match (&cfg.ingest_lag_metric, cfg.max_ingest_lag_seconds) {
+        (Some(metric), _) if metric.trim().is_empty() => {
+            errors.push(SpecValidationError::new(
+                "spec.autoscaling.ingestLagMetric",
+                "ingestLagMetric must not be empty",
+                "Set spec.autoscaling.ingestLagMetric to the Prometheus metric reporting Stellar Core ingest lag, or remove it.",
+            ));
+        }
+        (Some(_), None) | (None, Some(_)) => {
+            errors.push(SpecValidationError::new(
+                "spec.autoscaling.maxIngestLagSeconds",
+                "ingestLagMetric and maxIngestLagSeconds must be set together",
+                "Set both spec.autoscaling.ingestLagMetric and spec.autoscaling.maxIngestLagSeconds, or neither.",
+            ));
+        }
+        (Some(_), Some(0)) => {
+            errors.push(SpecValidationError::new(
+                "spec.autoscaling.maxIngestLagSeconds",
+                "maxIngestLagSeconds must be greater than 0",
+                "Set spec.autoscaling.maxIngestLagSeconds to 1 or greater.",
+            ));
+        }
+        _ => {}
+    }
+    for (i, cron) in cfg.cron_overrides.iter().enumerate() {
+        validate_cron_override(
+            &format!("spec.autoscaling.cronOverrides[{i}]"),
+            cron,
+            errors,
+        );
+    }
+    validate_weekend_scaling(cfg, errors);
+    validate_active_connections(cfg, errors);
+}
+
+fn validate_weekend_scaling(cfg: &AutoscalingConfig, errors: &mut Vec<SpecValidationError>) {
+    if cfg.weekend_time_zone().is_err() {
+        errors.push(SpecValidationError::new(
+            "spec.autoscaling.weekendTimezone",
+            format!(
+                "unknown timezone '{}'",
+                cfg.weekend_timezone.as_deref().unwrap_or_default()
+            ),
+            "Set spec.autoscaling.weekendTimezone to an IANA time zone name, e.g. \"UTC\" or \"Europe/Berlin\".",
+        ));
+    }
+    if cfg
+        .weekend_min_replicas
+        .is_some_and(|min| i64::from(min) > i64::from(cfg.max_replicas))
+    {
+        errors.push(SpecValidationError::new(
+            "spec.autoscaling.weekendMinReplicas",
+            "autoscaling.weekendMinReplicas must be <= maxReplicas",
+            "Lower spec.autoscaling.weekendMinReplicas to at most spec.autoscaling.maxReplicas, or 0 to suspend the node over weekends.",
+        ));
+    }
+}
+
+fn validate_active_connections(cfg: &AutoscalingConfig, errors: &mut Vec<SpecValidationError>) {
+    if cfg.max_connections_per_replica == Some(0) {
+        errors.push(SpecValidationError::new(
+            "spec.autoscaling.maxConnectionsPerReplica",
+            "maxConnectionsPerReplica must be greater than 0",
+            "Set spec.autoscaling.maxConnectionsPerReplica to Horizon's per-replica connection limit.",
+        ));
+    }
+    let Some(target) = cfg.target_active_connections else {
+        return;
+    };
+    if target < 1 {
+        errors.push(SpecValidationError::new(
+            "spec.autoscaling.targetActiveConnections",
+            "targetActiveConnections must be at least 1",
+            "Set spec.autoscaling.targetActiveConnections to the average connections per replica to scale at.",
+        ));
+    }
+    match cfg.max_connections_per_replica {
+        None => errors.push(SpecValidationError::new(
+            "spec.autoscaling.maxConnectionsPerReplica",
+            "targetActiveConnections requires maxConnectionsPerReplica",
+            "Set spec.autoscaling.maxConnectionsPerReplica to Horizon's per-replica connection limit.",
+        )),
+        Some(max) if target > i64::from(max) => errors.push(SpecValidationError::new(
+            "spec.autoscaling.targetActiveConnections",
+            format!("targetActiveConnections ({target}) must not exceed maxConnectionsPerReplica ({max})"),
+            "Lower spec.autoscaling.targetActiveConnections below maxConnectionsPerReplica so scaling starts before connections are rejected.",
+        )),
+        Some(_) => {}
+    }
+    if cfg
+        .active_connections_metric
+        .as_ref()
+        .is_some_and(|m| m.trim().is_empty())
+    {
+        errors.push(SpecValidationError::new(
+            "spec.autoscaling.activeConnectionsMetric",
+            "activeConnectionsMetric must not be empty",
+            "Set spec.autoscaling.activeConnectionsMetric to the external metric reporting active connections, or remove it to use nginx_active_connections.",
+        ));
+    }
+}
+
+fn validate_monitoring(cfg: &MonitoringConfig, errors: &mut Vec<SpecValidationError>) {
+    let mut parse = |field: &str, value: Option<&str>| {
+        let value = value?;
+        let duration = parse_prometheus_duration(value.trim()).filter(|d| !d.is_zero());
+        if duration.is_none() {
+            errors.push(SpecValidationError::new(
+                format!("spec.monitoring.{field}"),
+                format!("'{value}' is not a valid, non-zero Prometheus duration"),
+                "Use a duration such as \"30s\", \"2m\" or \"1m30s\".",
+            ));
+        }
+        duration
+    };
+    let interval = parse("scrapeInterval", cfg.scrape_interval.as_deref());
+    let timeout = parse("scrapeTimeout", cfg.scrape_timeout.as_deref());
+
+    if let (Some(interval), Some(timeout)) = (interval, timeout) {
+        if timeout >= interval {
+            errors.push(SpecValidationError::new(
+                "spec.monitoring.scrapeTimeout",
+                "scrapeTimeout must be shorter than scrapeInterval",
+                "Lower spec.monitoring.scrapeTimeout or raise spec.monitoring.scrapeInterval.",
+            ));
+        }
+    }
+}
+
+fn validate_cron_override(
+    field: &str,
+    cfg: &CronScalingOverride,
+    errors: &mut Vec<SpecValidationError>,
+) {
+    if let Err(e) = cfg.schedule() {
+        errors.push(SpecValidationError::new(
+            format!("{field}.cronExpression"),
+            format!("invalid cronExpression '{}': {e}", cfg.cron_expression),
+            "Use a five-field cron expression, e.g. \"0 14 * * 3\" for Wednesdays at 14:00.",
+        ));
+    }
+    if cfg.time_zone().is_err() {
+        errors.push(SpecValidationError::new(
+            format!("{field}.timezone"),
+            format!("unknown timezone '{}'", cfg.timezone),
+            "Set timezone to an IANA time zone name, e.g. \"UTC\" or \"America/New_York\".",
+        ));
+    }
+    if cfg.min_replicas < 1 || cfg.max_replicas < cfg.min_replicas {
+        errors.push(SpecValidationError::new(
+            format!("{field}.maxReplicas"),
+            "override minReplicas must be at least 1 and maxReplicas >= minReplicas",
+            "Set minReplicas to 1 or greater and maxReplicas to at least minReplicas.",
+        ));
+    }
+    if cfg.duration_minutes == 0 {
+        errors.push(SpecValidationError::new(
+            format!("{field}.durationMinutes"),
+            "durationMinutes must be greater than 0",
+            "Set durationMinutes to how long the override window should stay open.",
+        ));
+    }
+}
+
+fn validate_custom_resource_metrics(
+    metrics: &[CustomResourceMetricSpec],
+    errors: &mut Vec<SpecValidationError>,
+) {
+    for (i, metric) in metrics.iter().enumerate() {
+        let field = format!("spec.autoscaling.customResourceMetrics[{i}]");
+        let (kind, object_name) = metric.kind_and_name();
+        if metric.group.trim().is_empty() {
+            errors.push(SpecValidationError::new(
+                format!("{field}.group"),
+                "group must not be empty",
+                "Set the API group and version of the resource, e.g. \"queues.example.com/v1\".",
+            ));
+        }
+        if kind.trim().is_empty() || object_name.is_some_and(|n| n.trim().is_empty()) {
+            errors.push(SpecValidationError::new(
+                format!("{field}.resource"),
+                "resource must be a kind, optionally followed by /<name>",
+                "Set resource to the resource kind (e.g. \"IngestQueue\") or \"<kind>/<name>\".",
+            ));
+        }
+        if metric.name.trim().is_empty() {
+            errors.push(SpecValidationError::new(
+                format!("{field}.name"),
+                "name must not be empty",
+                "Set name to the metric exposed for the resource.",
+            ));
+        }
+        match metric.target_type {
+            TargetType::Value | TargetType::AverageValue => {
+                if !is_valid_quantity(&metric.target_value) {
+                    errors.push(SpecValidationError::new(
+                        format!("{field}.targetValue"),
+                        format!(
+                            "targetValue '{}' is not a valid Kubernetes quantity",
+                            metric.target_value
+                        ),
+                        "Use a quantity such as \"100\", \"500m\" or \"1Ki\" for Value and AverageValue targets.",
+                    ));
+                }
+            }
+            TargetType::Utilization => {
+                if !metric
+                    .target_value
+                    .trim()
+                    .parse::<i32>()
+                    .is_ok_and(|p| p > 0)
+                {
+                    errors.push(SpecValidationError::new(
+                        format!("{field}.targetValue"),
+                        format!(
+                            "targetValue '{}' is not a valid utilization percentage",
+                            metric.target_value
+                        ),
+                        "Use a positive integer percentage (e.g. \"80\") for Utilization targets.",
+                    ));
+                }
+            }
+        }
+    }
+}
+
+/// Check that a string is a valid Kubernetes resource quantity
+///
+/// Accepts a signed decimal number followed by an optional binary SI
+/// (`Ki`..`Ei`), decimal SI (`n`, `u`, `m`, `k`, `M`..`E`) or exponent suffix.
+fn is_valid_quantity(value: &str) -> bool {
+    let s = value.strip_prefix(['+', '-']).unwrap_or(value);
+    let number_end = s
+        .find(|c: char| !(c.is_ascii_digit() || c == '.'))
+        .unwrap_or(s.len());
+    let (number, suffix) = s.split_at(number_end);
+
+    let digits = number.replace('.', "");
+    if digits.is_empty() || number.matches('.').count() > 1 {
+        return false;
+    }
+
+    match suffix {
+        "" | "Ki" | "Mi" | "Gi" | "Ti" | "Pi" | "Ei" | "n" | "u" | "m" | "k" | "M" | "G" | "T"
+        | "P" | "E" => true,
+        exp => {
+            let Some(rest) = exp.strip_prefix(['e', 'E']) else {
+                return false;
+            };
+            let rest = rest.strip_prefix(['+', '-']).unwrap_or(rest);
+            !rest.is_empty() && rest.chars().all(|c| c.is_ascii_digit())
+        }
+    }
+}
+
+fn validate_alert_rules(rules: &[AlertRule], errors: &mut Vec<SpecValidationError>) {
+    for (i, rule) in rules.iter().enumerate() {
+        let field = format!("spec.prometheusRules.extraRules[{i}]");
+        if rule.alert.trim().is_empty() {
+            errors.push(SpecValidationError::new(
+                format!("{field}.alert"),
+                "alert name must not be empty",
+                "Give the rule an alert name, e.g. \"HorizonSlowIngestion\".",
+            ));
+        }
+        if rule.expr.trim().is_empty() {
+            errors.push(SpecValidationError::new(
+                format!("{field}.expr"),
+                "expr must not be empty",
+                "Set expr to a PromQL expression that returns results when the alert should fire.",
+            ));
+        }
+        if let Some(ref duration) = rule.for_duration {
+            if parse_prometheus_duration(duration).is_none() {
+                errors.push(SpecValidationError::new(
+                    format!("{field}.for"),
+                    format!("'{duration}' is not a valid Prometheus duration"),
+                    "Use a duration such as \"30s\", \"5m\" or \"1h30m\".",
+                ));
+            }
+        }
+    }
+}
+
+/// Parse a Prometheus duration such as "5m" or "1h30m"
+fn parse_prometheus_duration(value: &str) -> Option<Duration> {
+    // "ms" must be tried before "m"
+    const UNITS: [(&str, u64); 7] = [
+        ("ms", 1),
+        ("s", 1_000),
+        ("m", 60_000),
+        ("h", 3_600_000),
+        ("d", 86_400_000),
+        ("w", 604_800_000),
+        ("y", 31_536_000_000),
+    ];
+    if value.is_empty() {
+        return None;
+    }
+    let mut rest = value;
+    let mut millis: u64 = 0;
+    while !rest.is_empty() {
+        let digits = rest
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(rest.len());
+        if digits == 0 {
+            return None;
+        }
+        let amount: u64 = rest[..digits].parse().ok()?;
+        rest = &rest[digits..];
+        let (unit, unit_millis) = UNITS.iter().find(|(u, _)| rest.starts_with(*u))?;
+        millis = millis.checked_add(amount.checked_mul(*unit_millis)?)?;
+        rest = &rest[unit.len()..];
+    }
+    Some(Duration::from_millis(millis))
+}
+
+fn validate_hooks(hooks: &ReconcileHooksConfig, errors: &mut Vec<SpecValidationError>) {
+    let urls = [
+        ("preApplyUrl", hooks.pre_apply_url.as_deref()),
+        ("postApplyUrl", hooks.post_apply_url.as_deref()),
+    ];
+    for (field, url) in urls {
+        let Some(url) = url else { continue };
+        if !(url.starts_with("http://") || url.starts_with("https://")) {
+            errors.push(SpecValidationError::new(
+                format!("spec.hooks.{field}"),
+                format!("{field} must be an http:// or https:// URL"),
+                format!("Set spec.hooks.{field} to the full URL of the hook endpoint, e.g. https://automation.example.com/stellar."),
+            ));
+        }
+    }
+    if hooks.timeout_seconds == 0 || hooks.timeout_seconds > 30 {
+        errors.push(SpecValidationError::new(
+            "spec.hooks.timeoutSeconds",
+            "timeoutSeconds must be between 1 and 30",
+            "Set spec.hooks.timeoutSeconds to a value between 1 and 30; hooks run inline with reconciliation.",
+        ));
+    }
+}
+
+fn validate_network_policy(cfg: &NetworkPolicyConfig, errors: &mut Vec<SpecValidationError>) {
+    for (i, cidr) in cfg.allow_from_external_cidr.iter().enumerate() {
+        if !is_valid_cidr(cidr) {
+            errors.push(SpecValidationError::new(
+                format!("spec.networkPolicy.allowFromExternalCidr[{i}]"),
+                format!("{cidr} is not a valid CIDR block"),
+                "Use the form <ip>/<prefix>, e.g. 203.0.113.0/24 or 2001:db8::/32.",
+            ));
+        }
+    }
+    for (i, ns) in cfg.allow_from_namespaces.iter().enumerate() {
+        if ns.trim().is_empty() {
+            errors.push(SpecValidationError::new(
+                format!("spec.networkPolicy.allowFromNamespaces[{i}]"),
+                "namespace name must not be empty",
+                "List the names of the namespaces whose pods need access.",
+            ));
+        }
+    }
+}
+
+/// Whether `cidr` is an IPv4 or IPv6 address with a valid prefix length
+fn is_valid_cidr(cidr: &str) -> bool {
+    let Some((ip, prefix)) = cidr.split_once('/') else {
+        return false;
+    };
+    let Ok(prefix) = prefix.parse::<u8>() else {
+        return false;
+    };
+    match ip.parse::<std::net::IpAddr>() {
+        Ok(std::net::IpAddr::V4(_)) => prefix <= 32,
+        Ok(std::net::IpAddr::V6(_)) => prefix <= 128,
+        Err(_) => false,
+    }
+}
+
+fn validate_grafana_dashboard(cfg: &GrafanaDashboardConfig, errors: &mut Vec<SpecValidationError>) {
+    if cfg.namespace.trim().is_empty() {
+        errors.push(SpecValidationError::new(
+            "spec.grafanaDashboard.namespace",
+            "grafanaDashboard.namespace must not be empty",
+            "Set spec.grafanaDashboard.namespace to the namespace Grafana's dashboard sidecar watches.",
+        ));
+    }
+    if cfg.folder.trim().is_empty() {
+        errors.push(SpecValidationError::new(
+            "spec.grafanaDashboard.folder",
+            "grafanaDashboard.folder must not be empty",
+            "Set spec.grafanaDashboard.folder to the Grafana folder the dashboard should appear in.",
+        ));
+    }
+}
+
+fn validate_precheck_hook(cfg: &PrecheckHookConfig, errors: &mut Vec<SpecValidationError>) {
+    if cfg.image.trim().is_empty() {
+        errors.push(SpecValidationError::new(
+            "spec.precheckHook.image",
+            "precheckHook.image must not be empty",
+            "Set spec.precheckHook.image to the image that runs the check.",
+        ));
+    }
+    if cfg.command.iter().all(|c| c.trim().is_empty()) {
+        errors.push(SpecValidationError::new(
+            "spec.precheckHook.command",
+            "precheckHook.command must not be empty",
+            "Set spec.precheckHook.command to the check to run, e.g. [\"/bin/check-schema\"].",
+        ));
+    }
+    if cfg.timeout_seconds == 0 || cfg.timeout_seconds > MAX_PRECHECK_TIMEOUT_SECONDS {
+        errors.push(SpecValidationError::new(
+            "spec.precheckHook.timeoutSeconds",
+            format!(
+                "precheckHook.timeoutSeconds must be between 1 and {MAX_PRECHECK_TIMEOUT_SECONDS}, got {}",
+                cfg.timeout_seconds
+            ),
+            format!("Set spec.precheckHook.timeoutSeconds to a value between 1 and {MAX_PRECHECK_TIMEOUT_SECONDS}."),
+        ));
+    }
+}
+
+fn validate_container_security_context(
+    cfg: &ContainerSecurityContextConfig,
+    dev_mode: bool,
+    errors: &mut Vec<SpecValidationError>,
+) {
+    if dev_mode {
+        return;
+    }
+    for cap in cfg.capabilities_add.iter().filter(|c| c.is_privileged()) {
+        errors.push(SpecValidationError::new(
+            "spec.containerSecurityContext.capabilitiesAdd",
+            format!("capability {cap} is not allowed outside dev mode"),
+            format!("Remove {cap} from spec.containerSecurityContext.capabilitiesAdd, or set spec.devMode: true for non-production clusters."),
+        ));
+    }
+    if cfg.capabilities_add.contains(&LinuxCapability::All) {
+        errors.push(SpecValidationError::new(
+            "spec.containerSecurityContext.capabilitiesAdd",
+            "capability ALL is not allowed outside dev mode",
+            "List the individual capabilities the node needs in spec.containerSecurityContext.capabilitiesAdd instead of ALL.",
+        ));
+    }
+}
+
+fn validate_lifecycle_hook(
+    field: &str,
+    hook: &LifecycleHookSpec,
+    errors: &mut Vec<SpecValidationError>,
+) {
+    match (&hook.exec, &hook.http_get) {
+        (Some(_), Some(_)) | (None, None) => errors.push(SpecValidationError::new(
+            field,
+            "exactly one of exec or httpGet must be set",
+            format!("Configure either {field}.exec or {field}.httpGet, not both."),
+        )),
+        (Some(exec), None) => {
+            if exec.command.iter().all(|arg| arg.trim().is_empty()) {
+                errors.push(SpecValidationError::new(
+                    format!("{field}.exec.command"),
+                    "exec.command must not be empty",
+                    format!("Set {field}.exec.command to the command to run, e.g. [\"/bin/sh\", \"-c\", \"...\"]."),
+                ));
+            }
+        }
+        (None, Some(http)) => {
+            if !http.path.starts_with('/') {
+                errors.push(SpecValidationError::new(
+                    format!("{field}.httpGet.path"),
+                    format!("httpGet.path '{}' must start with '/'", http.path),
+                    format!(
+                        "Set {field}.httpGet.path to an absolute request path such as /register."
+                    ),
+                ));
+            }
+            if http.port == 0 {
+                errors.push(SpecValidationError::new(
+                    format!("{field}.httpGet.port"),
+                    "httpGet.port must be between 1 and 65535",
+                    format!("Set {field}.httpGet.port to the container port serving the hook."),
+                ));
+            }
+            if let Some(ref scheme) = http.scheme {
+                if !matches!(scheme.as_str(), "HTTP" | "HTTPS") {
+                    errors.push(SpecValidationError::new(
+                        format!("{field}.httpGet.scheme"),
+                        format!("invalid scheme '{scheme}'"),
+                        format!("Set {field}.httpGet.scheme to HTTP or HTTPS, or remove it."),
+                    ));
+                }
+            }
+        }
+    }
+}
+
+fn validate_vault(vault: &VaultConfig, errors: &mut Vec<SpecValidationError>) {
+    if vault.role.trim().is_empty() {
+        errors.push(SpecValidationError::new(
+            "spec.vault.role",
+            "vault.role must not be empty",
+            "Set spec.vault.role to the Vault Kubernetes auth role bound to this node's service account.",
+        ));
+    }
+    if vault.secrets.is_empty() {
+        errors.push(SpecValidationError::new(
+            "spec.vault.secrets",
+            "vault.secrets must list at least one secret",
+            "Add the Vault secret paths to render under spec.vault.secrets.",
+        ));
+    }
+    let mut names = std::collections::BTreeSet::new();
+    for (i, secret) in vault.secrets.iter().enumerate() {
+        let field = format!("spec.vault.secrets[{i}]");
+        let valid_name = !secret.name.is_empty()
+            && secret
+                .name
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_' || c == '.');
+        if !valid_name {
+            errors.push(SpecValidationError::new(
+                format!("{field}.name"),
+                "secret name must be non-empty and contain only letters, digits, '-', '_' or '.'",
+                "Use a simple file name such as validator-seed; it becomes /vault/secrets/<name>.",
+            ));
+        } else if !names.insert(secret.name.as_str()) {
+            errors.push(SpecValidationError::new(
+                format!("{field}.name"),
+                format!("secret name {} is used more than once", secret.name),
+                "Give each Vault secret a unique name.",
+            ));
+        }
+        if secret.path.trim().is_empty() {
+            errors.push(SpecValidationError::new(
+                format!("{field}.path"),
+                "secret path must not be empty",
+                "Set path to the Vault secret path, e.g. secret/data/stellar/validator.",
+            ));
+        }
+    }
+}
+
+fn validate_horizon_history(cfg: &HorizonConfig, errors: &mut Vec<SpecValidationError>) {
+    if cfg.checkpoint_frequency == Some(0) {
+        errors.push(SpecValidationError::new(
+            "spec.horizonConfig.checkpointFrequency",
+            "checkpointFrequency must be a positive integer",
+            "Set spec.horizonConfig.checkpointFrequency to a value greater than 0 (the network default is 64), or remove it.",
+        ));
+    }
+    if cfg.history_retention_count == Some(0) {
+        errors.push(SpecValidationError::new(
+            "spec.horizonConfig.historyRetentionCount",
+            "historyRetentionCount must be a positive integer",
+            "Set spec.horizonConfig.historyRetentionCount to the number of ledgers to keep, or remove it to retain full history.",
+        ));
+    }
+    if cfg.db_wait_timeout_seconds == Some(0) {
+        errors.push(SpecValidationError::new(
+            "spec.horizonConfig.dbWaitTimeoutSeconds",
+            "dbWaitTimeoutSeconds must be a positive integer",
+            "Set spec.horizonConfig.dbWaitTimeoutSeconds to how long to wait for the database, or remove it to start Horizon without waiting.",
+        ));
+    }
+    if cfg.database_ready_timeout_seconds == Some(0) {
+        errors.push(SpecValidationError::new(
+            "spec.horizonConfig.databaseReadyTimeoutSeconds",
+            "databaseReadyTimeoutSeconds must be a positive integer",
+            "Set spec.horizonConfig.databaseReadyTimeoutSeconds to how long the database Secret may take to appear, or remove it to skip the readiness gate.",
+        ));
+    }
+}
+
+fn validate_soroban_tx_queue(cfg: &SorobanConfig, errors: &mut Vec<SpecValidationError>) {
+    if cfg.tx_queue_size == Some(0) {
+        errors.push(SpecValidationError::new(
+            "spec.sorobanConfig.txQueueSize",
+            "txQueueSize must be a positive integer",
+            "Set spec.sorobanConfig.txQueueSize to a value greater than 0, or remove it to use the Soroban RPC default.",
+        ));
+    }
+    if cfg.tx_concurrency == Some(0) {
+        errors.push(SpecValidationError::new(
+            "spec.sorobanConfig.txConcurrency",
+            "txConcurrency must be a positive integer",
+            "Set spec.sorobanConfig.txConcurrency to a value greater than 0, or remove it to use the Soroban RPC default.",
+        ));
+    }
+}
+
+fn validate_soroban_contract_cache(cfg: &SorobanConfig, errors: &mut Vec<SpecValidationError>) {
+    if cfg
+        .contract_code_cache_size
+        .is_some_and(|size| size < MIN_CONTRACT_CODE_CACHE_SIZE)
+    {
+        errors.push(SpecValidationError::new(
+            "spec.sorobanConfig.contractCodeCacheSize",
+            format!(
+                "contractCodeCacheSize must be at least {MIN_CONTRACT_CODE_CACHE_SIZE}; Soroban RPC ignores smaller caches"
+            ),
+            format!(
+                "Set spec.sorobanConfig.contractCodeCacheSize to {MIN_CONTRACT_CODE_CACHE_SIZE} or more entries, or remove it to use the Soroban RPC default."
+            ),
+        ));
+    }
+    if cfg
+        .contract_code_cache_ttl_seconds
+        .is_some_and(|ttl| ttl < MIN_CONTRACT_CODE_CACHE_TTL_SECONDS)
+    {
+        errors.push(SpecValidationError::new(
+            "spec.sorobanConfig.contractCodeCacheTtlSeconds",
+            format!(
+                "contractCodeCacheTtlSeconds must be at least {MIN_CONTRACT_CODE_CACHE_TTL_SECONDS}"
+            ),
+            format!(
+                "Set spec.sorobanConfig.contractCodeCacheTtlSeconds to {MIN_CONTRACT_CODE_CACHE_TTL_SECONDS} or more, or remove it to use the Soroban RPC default."
+            ),
+        ));
+    }
+    if cfg.contract_instance_cache_size == Some(0) {
+        errors.push(SpecValidationError::new(
+            "spec.sorobanConfig.contractInstanceCacheSize",
+            "contractInstanceCacheSize must be a positive integer",
+            "Set spec.sorobanConfig.contractInstanceCacheSize to a value greater than 0, or remove it to use the Soroban RPC default.",
+        ));
+    }
+}
+
+fn validate_soroban_persistence(cfg: &SorobanConfig, errors: &mut Vec<SpecValidationError>) {
+    let secret_set = cfg
+        .database_secret_ref
+        .as_ref()
+        .is_some_and(|s| !s.trim().is_empty());
+    if cfg.persistence_backend == PersistenceBackend::PostgreSQL && !secret_set {
+        errors.push(SpecValidationError::new(
+            "spec.sorobanConfig.databaseSecretRef",
+            "databaseSecretRef is required when persistenceBackend is PostgreSQL",
+            "Set spec.sorobanConfig.databaseSecretRef to a Secret with a DATABASE_URL key, or use persistenceBackend SQLite.",
+        ));
+    }
+}
+
+fn validate_soroban_debug_rpc(
+    cfg: &SorobanConfig,
+    network: &StellarNetwork,
+    allow_on_mainnet: bool,
+    errors: &mut Vec<SpecValidationError>,
+) {
+    if !cfg.debug_rpc {
+        return;
+    }
+    if *network == StellarNetwork::Mainnet && !allow_on_mainnet {
+        errors.push(SpecValidationError::new(
+            "spec.sorobanConfig.debugRpc",
+            "debugRpc is not allowed on Mainnet: logged payloads include users' transaction data",
+            "Disable spec.sorobanConfig.debugRpc, or set spec.allowDebugOnMainnet to true to accept the exposure.",
+        ));
+    }
+    if cfg.debug_rpc_max_body_log_bytes == Some(0) {
+        errors.push(SpecValidationError::new(
+            "spec.sorobanConfig.debugRpcMaxBodyLogBytes",
+            "debugRpcMaxBodyLogBytes must be greater than 0",
+            format!(
+                "Set spec.sorobanConfig.debugRpcMaxBodyLogBytes to 1 or more, or remove it to use the default of {DEFAULT_DEBUG_RPC_MAX_BODY_LOG_BYTES}."
+            ),
+        ));
+    }
+}
+
+fn validate_soroban_rpc_endpoint(cfg: &SorobanConfig, errors: &mut Vec<SpecValidationError>) {
+    if let Some(ref path) = cfg.rpc_endpoint {
+        if !path.starts_with('/') || (path.len() > 1 && path.ends_with('/')) {
+            errors.push(SpecValidationError::new(
+                "spec.sorobanConfig.rpcEndpoint",
+                format!("rpcEndpoint '{path}' must start with '/' and must not end with '/'"),
+                "Set spec.sorobanConfig.rpcEndpoint to a path such as /soroban/rpc, or / for the root.",
+            ));
+        }
+    }
+}
+
+fn validate_archival_storage(cfg: &ArchivalStorageConfig, errors: &mut Vec<SpecValidationError>) {
+    if cfg.backend == ArchivalBackend::Disk {
+        return;
+    }
+    let has_bucket = cfg
+        .bucket_name
+        .as_deref()
+        .is_some_and(|b| !b.trim().is_empty());
+    if !has_bucket {
+        errors.push(SpecValidationError::new(
+            "spec.sorobanConfig.archivalStorage.bucketName",
+            "bucketName is required when the archival backend is S3 or GCS",
+            "Set spec.sorobanConfig.archivalStorage.bucketName, or use backend: Disk.",
+        ));
+    }
+}
+
+fn validate_horizon_captive_core(
+    cfg: &HorizonConfig,
+    captive: &HorizonCaptiveCoreConfig,
+    errors: &mut Vec<SpecValidationError>,
+) {
+    if !cfg.stellar_core_url.trim().is_empty() || cfg.stellar_core_ref.is_some() {
+        errors.push(SpecValidationError::new(
+            "spec.horizonConfig.captiveCore",
+            "captiveCore cannot be combined with stellarCoreUrl or stellarCoreRef",
+            "Configure exactly one ingestion mode: remove stellarCoreUrl and stellarCoreRef to use captive core, or remove captiveCore to ingest from a remote Stellar Core.",
+        ));
+    }
+    if captive.config.trim().is_empty() {
+        errors.push(SpecValidationError::new(
+            "spec.horizonConfig.captiveCore.config",
+            "captiveCore.config must not be empty",
+            "Provide the captive core configuration (TOML) in spec.horizonConfig.captiveCore.config.",
+        ));
+    }
+    if !captive.binary_path.starts_with('/') {
+        errors.push(SpecValidationError::new(
+            "spec.horizonConfig.captiveCore.binaryPath",
+            "captiveCore.binaryPath must be an absolute path",
+            "Set spec.horizonConfig.captiveCore.binaryPath to the stellar-core binary in the Horizon image, e.g. /usr/bin/stellar-core.",
+        ));
+    }
+}
+
+fn validate_horizon_tls(cfg: &HorizonTlsConfig, errors: &mut Vec<SpecValidationError>) {
+    if cfg.cert_secret_ref.trim().is_empty() {
+        errors.push(SpecValidationError::new(
+            "spec.horizonConfig.tls.certSecretRef",
+            "certSecretRef must not be empty",
+            "Set spec.horizonConfig.tls.certSecretRef to the name of a kubernetes.io/tls Secret.",
+        ));
+    }
+    if cfg.port == 0 {
+        errors.push(SpecValidationError::new(
+            "spec.horizonConfig.tls.port",
+            "port must be between 1 and 65535",
+            "Set spec.horizonConfig.tls.port to a valid port, or remove it to use 443.",
+        ));
+    }
+    if cfg
+        .client_ca_secret_ref
+        .as_ref()
+        .is_some_and(|s| s.trim().is_empty())
+    {
+        errors.push(SpecValidationError::new(
+            "spec.horizonConfig.tls.clientCaSecretRef",
+            "clientCaSecretRef must not be empty",
+            "Set spec.horizonConfig.tls.clientCaSecretRef to a Secret containing ca.crt, or remove it to disable client certificate verification.",
+        ));
+    }
+}
+
+fn validate_database_ssl(
+    cfg: &DatabaseSslConfig,
+    network: &StellarNetwork,
+    errors: &mut Vec<SpecValidationError>,
+) {
+    if cfg.mode == PgSslMode::Disable && *network == StellarNetwork::Mainnet {
+        errors.push(SpecValidationError::new(
+            "spec.horizonConfig.databaseSSL.mode",
+            "insecure database connections are not allowed on Mainnet",
+            "Set spec.horizonConfig.databaseSSL.mode to Require, VerifyCa or VerifyFull.",
+        ));
+    }
+    let empty = |s: &Option<String>| s.as_ref().is_some_and(|s| s.trim().is_empty());
+    let ca_missing = cfg.mode.verifies_server() && cfg.ca_cert_secret_ref.is_none();
+    if ca_missing || empty(&cfg.ca_cert_secret_ref) {
+        errors.push(SpecValidationError::new(
+            "spec.horizonConfig.databaseSSL.caCertSecretRef",
+            format!("caCertSecretRef is required for mode {}", cfg.mode),
+            "Set spec.horizonConfig.databaseSSL.caCertSecretRef to a Secret containing ca.crt.",
+        ));
+    }
+    if cfg.client_cert_secret_ref.is_some() != cfg.client_key_secret_ref.is_some()
+        || empty(&cfg.client_cert_secret_ref)
+        || empty(&cfg.client_key_secret_ref)
+    {
+        errors.push(SpecValidationError::new(
+            "spec.horizonConfig.databaseSSL.clientCertSecretRef",
+            "clientCertSecretRef and clientKeySecretRef must be set together",
+            "Set both spec.horizonConfig.databaseSSL.clientCertSecretRef and clientKeySecretRef, or neither.",
+        ));
+    }
+}
+
+/// Whether `origin` is "*" or `http(s)://host[:port]`, where the host may start with "*."
+fn is_valid_cors_origin(origin: &str) -> bool {
+    if origin == "*" {
+        return true;
+    }
+    let Some(authority) = origin
+        .strip_prefix("https://")
+        .or_else(|| origin.strip_prefix("http://"))
+    else {
+        return false;
+    };
+    let (host, port) = match authority.rsplit_once(':') {
+        Some((host, port)) => (host, Some(port)),
+        None => (authority, None),
+    };
+    let host = host.strip_prefix("*.").unwrap_or(host);
+    let host_ok = !host.is_empty()
+        && !host.starts_with('.')
+        && !host.ends_with('.')
+        && host
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '.' || c == '-');
+    let port_ok = match port {
+        Some(port) => port.parse::<u16>().is_ok_and(|p| p > 0),
+        None => true,
+    };
+    host_ok && port_ok
+}
+
+fn validate_cors_allowed_origins(origins: &[String], errors: &mut Vec<SpecValidationError>) {
+    for (i, origin) in origins.iter().enumerate() {
+        if !is_valid_cors_origin(origin) {
+            errors.push(SpecValidationError::new(
+                format!("spec.horizonConfig.corsAllowedOrigins[{i}]"),
+                format!("'{origin}' is not a valid origin"),
+                "Use \"*\" or a scheme and host without a path, e.g. https://app.example.com or http://localhost:3000.",
+            ));
+        }
+    }
+    if origins.len() > 1 && origins.iter().any(|o| o == "*") {
+        errors.push(SpecValidationError::new(
+            "spec.horizonConfig.corsAllowedOrigins",
+            "\"*\" cannot be combined with other origins",
+            "Use either \"*\" alone or a list of specific origins.",
+        ));
+    }
+}
+
+/// Response headers browsers never expose to scripts, even when listed
+const FORBIDDEN_CORS_EXPOSE_HEADERS: &[&str] = &["set-cookie", "set-cookie2", "authorization"];
+
+/// Whether `name` is an HTTP header name (an RFC 7230 token)
+fn is_valid_header_name(name: &str) -> bool {
+    !name.is_empty()
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || "!#$%&'*+-.^_`|~".contains(c))
+}
+
+fn validate_cors_headers(cfg: &HorizonConfig, errors: &mut Vec<SpecValidationError>) {
+    for (i, header) in cfg.cors_allowed_headers.iter().enumerate() {
+        if !is_valid_header_name(header) {
+            errors.push(SpecValidationError::new(
+                format!("spec.horizonConfig.corsAllowedHeaders[{i}]"),
+                format!("'{header}' is not a valid header name"),
+                "Use \"*\" or header names such as X-Client-Name.",
+            ));
+        }
+    }
+    for (i, header) in cfg.cors_expose_headers.iter().enumerate() {
+        let field = format!("spec.horizonConfig.corsExposeHeaders[{i}]");
+        if !is_valid_header_name(header) {
+            errors.push(SpecValidationError::new(
+                field,
+                format!("'{header}' is not a valid header name"),
+                "Use header names such as X-Ratelimit-Remaining.",
+            ));
+        } else if FORBIDDEN_CORS_EXPOSE_HEADERS.contains(&header.to_ascii_lowercase().as_str()) {
+            errors.push(SpecValidationError::new(
+                field,
+                format!("{header} is a forbidden response header; browsers never expose it"),
+                format!("Remove {header} from spec.horizonConfig.corsExposeHeaders."),
+            ));
+        }
+    }
+    if cfg.cors_max_age_seconds == Some(0) {
+        errors.push(SpecValidationError::new(
+            "spec.horizonConfig.corsMaxAgeSeconds",
+            "corsMaxAgeSeconds must be greater than 0",
+            "Set corsMaxAgeSeconds to a positive number of seconds, or remove it to use Horizon's default.",
+        ));
+    }
+}
+
+fn validate_transaction_filtering(
+    cfg: &TransactionFilteringConfig,
+    network: &StellarNetwork,
+    errors: &mut Vec<SpecValidationError>,
+) {
+    const FIELD: &str = "spec.horizonConfig.transactionFiltering.filterServiceUrl";
+
+    if !cfg.enabled {
+        return;
+    }
+    let url = cfg.filter_service_url.trim();
+    let host = url
+        .strip_prefix("https://")
+        .or_else(|| url.strip_prefix("http://"))
+        .and_then(|rest| rest.split(['/', '?', '#']).next())
+        .filter(|host| !host.is_empty());
+    if host.is_none() {
+        errors.push(SpecValidationError::new(
+            FIELD,
+            "filterServiceUrl must be an http:// or https:// URL with a host when transactionFiltering is enabled",
+            "Set filterServiceUrl to the filter service endpoint, e.g. https://compliance.example.com/v1/filter.",
+        ));
+    } else if *network == StellarNetwork::Mainnet && !url.starts_with("https://") {
+        errors.push(SpecValidationError::new(
+            FIELD,
+            "filterServiceUrl must use HTTPS on Mainnet",
+            "Serve the filter service over TLS and use an https:// URL.",
+        ));
+    }
+}
+
+fn validate_offers_filtering(cfg: &OffersFilteringConfig, errors: &mut Vec<SpecValidationError>) {
+    const FIELD: &str = "spec.horizonConfig.offersFiltering";
+
+    if !cfg.enabled {
+        return;
+    }
+    if cfg
+        .filter_rules_configmap_ref
+        .as_deref()
+        .is_none_or(|r| r.trim().is_empty())
+    {
+        errors.push(SpecValidationError::new(
+            format!("{FIELD}.filterRulesConfigmapRef"),
+            "filterRulesConfigmapRef is required when offersFiltering is enabled",
+            "Set filterRulesConfigmapRef to a ConfigMap with the rules under the filter-rules.json key.",
+        ));
+    }
+    if cfg.update_interval_seconds == Some(0) {
+        errors.push(SpecValidationError::new(
+            format!("{FIELD}.updateIntervalSeconds"),
+            "updateIntervalSeconds must be greater than 0",
+            "Set updateIntervalSeconds to 1 or more, or remove it to use Horizon's default.",
+        ));
+    }
+}
+
+fn validate_horizon_order_book(cfg: &HorizonConfig, errors: &mut Vec<SpecValidationError>) {
+    if let Some(depth) = cfg.order_book_cache_depth {
+        if depth == 0 || depth > MAX_ORDER_BOOK_CACHE_DEPTH {
             errors.push(SpecValidationError::new(
-                "spec.minAvailable / spec.maxUnavailable",
-                "Cannot specify both minAvailable and maxUnavailable in PDB configuration",
-                "Set either spec.minAvailable or spec.maxUnavailable in the spec, but not both at the same time.",
+                "spec.horizonConfig.orderBookCacheDepth",
+                format!(
+                    "orderBookCacheDepth {depth} is out of range (Horizon supports 1-{MAX_ORDER_BOOK_CACHE_DEPTH})"
+                ),
+                format!(
+                    "Set spec.horizonConfig.orderBookCacheDepth between 1 and {MAX_ORDER_BOOK_CACHE_DEPTH}, or remove it to use the default of {DEFAULT_ORDER_BOOK_CACHE_DEPTH}."
+                ),
             ));
         }
+    }
+}
 
-        // 3. Node Type Specific Logic
-        match self.node_type {
-            NodeType::Validator => {
-                // Validator config required
-                if self.validator_config.is_none() {
-                    errors.push(SpecValidationError::new(
-                        "spec.validatorConfig",
-                        "validatorConfig is required for Validator nodes",
-                        "Add a spec.validatorConfig section with the required validator settings when nodeType is Validator.",
-                    ));
-                } else if let Some(vc) = &self.validator_config {
-                    if vc.enable_history_archive && vc.history_archive_urls.is_empty() {
-                        errors.push(SpecValidationError::new(
-                            "spec.validatorConfig.historyArchiveUrls",
-                            "historyArchiveUrls must not be empty when enableHistoryArchive is true",
-                            "Provide at least one valid history archive URL in spec.validatorConfig.historyArchiveUrls when enableHistoryArchive is true.",
-                        ));
-                    }
-                }
-
-                // Exactly 1 replica required
-                if self.replicas != 1 {
-                    errors.push(SpecValidationError::new(
-                        "spec.replicas",
-                        "Validator nodes must have exactly 1 replica",
-                        "Set spec.replicas to 1 for Validator nodes.",
-                    ));
-                }
-                if self.min_available.is_some() || self.max_unavailable.is_some() {
-                    errors.push(SpecValidationError::new(
-                        "spec.minAvailable / spec.maxUnavailable",
-                        "PDB configuration is not supported for Validator nodes (replicas must be 1)",
-                        "Remove PodDisruptionBudget fields (minAvailable/maxUnavailable) for Validator nodes; they must always have exactly 1 replica.",
-                    ));
-                }
-                if self.autoscaling.is_some() {
-                    errors.push(SpecValidationError::new(
-                        "spec.autoscaling",
-                        "autoscaling is not supported for Validator nodes",
-                        "Remove spec.autoscaling when nodeType is Validator; autoscaling is only supported for Horizon and SorobanRpc.",
-                    ));
-                }
-
-                // Ingress not supported
-                if self.ingress.is_some() {
-                    errors.push(SpecValidationError::new(
-                        "spec.ingress",
-                        "ingress is not supported for Validator nodes",
-                        "Remove spec.ingress for Validator nodes; expose Validator nodes using peer discovery or other supported mechanisms.",
-                    ));
-                }
-                if matches!(self.strategy, RolloutStrategy::Canary(_)) {
-                    errors.push(SpecValidationError::new(
-                        "spec.strategy",
-                        "Canary rollout is not supported for Validator nodes",
-                        "Use a non-canary rollout strategy (e.g., RollingUpdate) for Validator nodes.",
-
-                // Autoscaling not supported
-                if self.autoscaling.is_some() {
-                    errors.push(SpecValidationError::new(
-                        "spec.autoscaling",
-                        "autoscaling is not supported for Validator nodes",
-                        "Remove spec.autoscaling when nodeType is Validator; autoscaling is only supported for Horizon and SorobanRpc.",
-                    ));
-                }
+fn validate_min_stellar_core_version(cfg: &HorizonConfig, errors: &mut Vec<SpecValidationError>) {
+    let Some(ref version) = cfg.min_stellar_core_version else {
+        return;
+    };
+    if parse_image_version(version).is_none() {
+        errors.push(SpecValidationError::new(
+            "spec.horizonConfig.minStellarCoreVersion",
+            format!("'{version}' is not a semantic version"),
+            "Set spec.horizonConfig.minStellarCoreVersion to a version such as 21.0.0.",
+        ));
+    }
+    if cfg.captive_core.is_some() {
+        errors.push(SpecValidationError::new(
+            "spec.horizonConfig.minStellarCoreVersion",
+            "minStellarCoreVersion cannot be combined with captiveCore",
+            "Remove minStellarCoreVersion; captive core runs the stellar-core binary shipped in the Horizon image.",
+        ));
+    }
+}
 
-                // History archive validation
-                if let Some(ref validator_config) = self.validator_config {
-                    if validator_config.enable_history_archive
-                        && validator_config.history_archive_urls.is_empty()
-                    {
-                        errors.push(SpecValidationError::new(
-                            "spec.validatorConfig.historyArchiveUrls",
-                            "historyArchiveUrls must not be empty when enableHistoryArchive is true",
-                            "Provide at least one valid history archive URL in spec.validatorConfig.historyArchiveUrls when enableHistoryArchive is true.",
-                        ));
-                    }
-                }
+fn validate_session_affinity(cfg: &ServiceConfig, errors: &mut Vec<SpecValidationError>) {
+    let Some(timeout) = cfg.session_affinity_timeout_seconds else {
+        return;
+    };
+    let local_traffic = cfg.external_traffic_policy == Some(ExternalTrafficPolicy::Local);
+    if cfg.session_affinity != SessionAffinity::ClientIp && !local_traffic {
+        errors.push(SpecValidationError::new(
+            "spec.service.sessionAffinityTimeoutSeconds",
+            "sessionAffinityTimeoutSeconds requires sessionAffinity ClientIP",
+            "Set spec.service.sessionAffinity to ClientIP, or remove spec.service.sessionAffinityTimeoutSeconds.",
+        ));
+    }
+    if !(1..=MAX_SESSION_AFFINITY_TIMEOUT_SECONDS).contains(&timeout) {
+        errors.push(SpecValidationError::new(
+            "spec.service.sessionAffinityTimeoutSeconds",
+            format!(
+                "sessionAffinityTimeoutSeconds {timeout} is out of range (1-{MAX_SESSION_AFFINITY_TIMEOUT_SECONDS})"
+            ),
+            format!(
+                "Set spec.service.sessionAffinityTimeoutSeconds between 1 and {MAX_SESSION_AFFINITY_TIMEOUT_SECONDS}, or remove it to use the Kubernetes default of 10800."
+            ),
+        ));
+    }
+}
 
-                // Canary strategy not supported
-                if matches!(self.strategy, RolloutStrategy::Canary(_)) {
-                    errors.push(SpecValidationError::new(
-                        "spec.strategy",
-                        "canary rollout strategy is not supported for Validator nodes",
-                        "Use RollingUpdate strategy for Validator nodes; canary is only supported for Horizon and SorobanRpc.",
-                    ));
-                }
-            }
-            NodeType::Horizon => {
-                // Horizon config required
-                if self.horizon_config.is_none() {
-                    errors.push(SpecValidationError::new(
-                        "spec.horizonConfig",
-                        "horizonConfig is required for Horizon nodes",
-                        "Add a spec.horizonConfig section with the required Horizon settings when nodeType is Horizon.",
-                    ));
-                }
-                if let Some(ref autoscaling) = self.autoscaling {
-                    if autoscaling.min_replicas < 1 {
-                        errors.push(SpecValidationError::new(
-                            "spec.autoscaling.minReplicas",
-                            "autoscaling.minReplicas must be at least 1",
-                            "Set spec.autoscaling.minReplicas to 1 or greater.",
-                        ));
-                    }
-                    if autoscaling.max_replicas < autoscaling.min_replicas {
-                        errors.push(SpecValidationError::new(
-                            "spec.autoscaling.maxReplicas",
-                            "autoscaling.maxReplicas must be >= minReplicas",
-                            "Set spec.autoscaling.maxReplicas to be greater than or equal to minReplicas.",
-                        ));
-                    }
-                }
-                if let Some(ingress) = &self.ingress {
-                    validate_ingress(ingress, &mut errors);
-                }
-            }
-            NodeType::SorobanRpc => {
-                // Soroban config required
-                if self.soroban_config.is_none() {
-                    errors.push(SpecValidationError::new(
-                        "spec.sorobanConfig",
-                        "sorobanConfig is required for SorobanRpc nodes",
-                        "Add a spec.sorobanConfig section with the required Soroban RPC settings when nodeType is SorobanRpc.",
-                    ));
-                }
-                if let Some(ref autoscaling) = self.autoscaling {
-                    if autoscaling.min_replicas < 1 {
-                        errors.push(SpecValidationError::new(
-                            "spec.autoscaling.minReplicas",
-                            "autoscaling.minReplicas must be at least 1",
-                            "Set spec.autoscaling.minReplicas to 1 or greater.",
-                        ));
-                    }
-                    if autoscaling.max_replicas < autoscaling.min_replicas {
-                        errors.push(SpecValidationError::new(
-                            "spec.autoscaling.maxReplicas",
-                            "autoscaling.maxReplicas must be >= minReplicas",
-                            "Set spec.autoscaling.maxReplicas to be greater than or equal to minReplicas.",
-                        ));
-                    }
-                }
-                if let Some(ingress) = &self.ingress {
-                    validate_ingress(ingress, &mut errors);
-                }
-            }
-        }
+fn validate_external_traffic_policy(cfg: &ServiceConfig, errors: &mut Vec<SpecValidationError>) {
+    if cfg.external_traffic_policy.is_some() && cfg.service_type == ServiceType::ClusterIp {
+        errors.push(SpecValidationError::new(
+            "spec.service.externalTrafficPolicy",
+            "externalTrafficPolicy requires a NodePort or LoadBalancer service",
+            "Set spec.service.type to NodePort or LoadBalancer, or remove spec.service.externalTrafficPolicy.",
+        ));
+    }
+    let Some(port) = cfg.health_check_node_port else {
+        return;
+    };
+    let local_load_balancer = cfg.service_type == ServiceType::LoadBalancer
+        && cfg.external_traffic_policy == Some(ExternalTrafficPolicy::Local);
+    if !local_load_balancer {
+        errors.push(SpecValidationError::new(
+            "spec.service.healthCheckNodePort",
+            "healthCheckNodePort requires type LoadBalancer with externalTrafficPolicy Local",
+            "Set spec.service.type to LoadBalancer and spec.service.externalTrafficPolicy to Local, or remove spec.service.healthCheckNodePort.",
+        ));
+    }
+    if !(1..=65535).contains(&port) {
+        errors.push(SpecValidationError::new(
+            "spec.service.healthCheckNodePort",
+            format!("healthCheckNodePort {port} is not a valid port"),
+            "Set spec.service.healthCheckNodePort to a port in the cluster's node port range (30000-32767 by default).",
+        ));
+    }
+}
 
-        // Validate optional features if present
-        if let Some(ref lb) = self.load_balancer {
-            validate_load_balancer(lb, &mut errors);
-        }
-        if let Some(ref gd) = self.global_discovery {
-            validate_global_discovery(gd, &mut errors);
+fn validate_image_override(cfg: &ImageOverrideConfig, errors: &mut Vec<SpecValidationError>) {
+    if let Some(ref policy) = cfg.pull_policy {
+        if !matches!(policy.as_str(), "Always" | "IfNotPresent" | "Never") {
+            errors.push(SpecValidationError::new(
+                "spec.imageOverride.pullPolicy",
+                format!("invalid pullPolicy '{policy}'"),
+                "Set spec.imageOverride.pullPolicy to one of Always, IfNotPresent or Never.",
+            ));
         }
-        if let Some(ref cc) = self.cross_cluster {
-            validate_cross_cluster(cc, &mut errors);
+    }
+    if let Some(ref digest) = cfg.digest {
+        if !digest.contains(':') {
+            errors.push(SpecValidationError::new(
+                "spec.imageOverride.digest",
+                format!("invalid digest '{digest}'"),
+                "Use the form <algorithm>:<hex>, e.g. sha256:0123abcd...",
+            ));
         }
+    }
+}
 
-        if errors.is_empty() {
-            Ok(())
-        } else {
-            Err(errors)
-        }
+/// Parse an image tag such as "v21", "21.1" or "v21.0.0-rc1" as a semver version
+pub(crate) fn parse_image_version(tag: &str) -> Option<semver::Version> {
+    let trimmed = tag.trim().trim_start_matches('v');
+    if let Ok(version) = semver::Version::parse(trimmed) {
+        return Some(version);
+    }
+    let (core, pre) = match trimmed.split_once('-') {
+        Some((core, pre)) => (core, Some(pre)),
+        None => (trimmed, None),
+    };
+    let mut parts = core.split('.').map(|p| p.parse::<u64>().ok());
+    let major = parts.next()??;
+    let minor = parts.next().unwrap_or(Some(0))?;
+    let patch = parts.next().unwrap_or(Some(0))?;
+    if parts.next().is_some() {
+        return None;
+    }
+    let mut version = semver::Version::new(major, minor, patch);
+    if let Some(pre) = pre {
+        version.pre = semver::Prerelease::new(pre).ok()?;
     }
+    Some(version)
+}
 
-    pub fn container_image(&self) -> String {
-        let name = match self.node_type {
-            NodeType::Validator => "stellar-core",
-            _ => "horizon",
-        };
-        format!("stellar/{}:{}", name, self.version)
+fn validate_image_version_constraint(
+    constraint: &str,
+    version: &str,
+    image_override: Option<&ImageOverrideConfig>,
+    errors: &mut Vec<SpecValidationError>,
+) {
+    let req = match semver::VersionReq::parse(constraint) {
+        Ok(req) => req,
+        Err(e) => {
+            errors.push(SpecValidationError::new(
+                "spec.imageVersionConstraint",
+                format!("invalid semver range '{constraint}': {e}"),
+                "Use a semver range such as \">=21.0.0, <23.0.0\".",
+            ));
+            return;
+        }
+    };
+
+    let mut candidates = vec![("spec.version", version)];
+    if let Some(tag) = image_override.and_then(|o| o.tag.as_deref()) {
+        candidates.push(("spec.imageOverride.tag", tag));
     }
 
-    pub fn should_delete_pvc(&self) -> bool {
-        self.storage.retention_policy == RetentionPolicy::Delete
+    for (field, value) in candidates {
+        match parse_image_version(value) {
+            Some(parsed) if req.matches(&parsed) => {}
+            Some(_) => errors.push(SpecValidationError::new(
+                field,
+                format!("'{value}' does not satisfy imageVersionConstraint '{constraint}'"),
+                "Choose a version within spec.imageVersionConstraint or relax the constraint.",
+            )),
+            None => errors.push(SpecValidationError::new(
+                field,
+                format!("'{value}' is not a semantic version and cannot be checked against imageVersionConstraint"),
+                "Use a semver-style version (e.g. v21.0.0) or remove spec.imageVersionConstraint.",
+            )),
+        }
     }
 }
+
 #[allow(dead_code)]
 fn validate_ingress(ingress: &IngressConfig, errors: &mut Vec<SpecValidationError>) {
     if ingress.hosts.is_empty() {
@@ -716,6 +2950,7 @@ pub struct StellarNodeStatus {
         since = "0.2.0",
         note = "Use conditions array instead. Phase is now derived from Ready/Progressing/Degraded conditions."
     )]
+    #[serde(default)]
     pub phase: String,
 
     /// Human-readable message about current state
@@ -774,6 +3009,125 @@ pub struct StellarNodeStatus {
     /// Version of the database schema after last successful migration
     #[serde(skip_serializing_if = "Option::is_none")]
     pub last_migrated_version: Option<String>,
+
+    /// Sum of instance weights across the nodes currently hosting replicas
+    /// (see `spec.autoscaling.instanceWeightAnnotations`); sizes the HPA's
+    /// scale-up step
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub effective_capacity_units: Option<u32>,
+
+    /// Minimum replicas of the managed HPA (cleared when autoscaling is disabled)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hpa_min_replicas: Option<i32>,
+
+    /// Maximum replicas of the managed HPA (cleared when autoscaling is disabled)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hpa_max_replicas: Option<i32>,
+
+    /// maxReplicas the cluster's free memory allows, when
+    /// `spec.autoscaling.clusterCapacityAware` is set
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cluster_capacity_limited_max_replicas: Option<i32>,
+
+    /// Percentage of the data PVC in use, as reported by the kubelet
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub storage_used_percent: Option<f64>,
+
+    /// Outcome of the most recent `spec.precheckHook` run
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_precheck_result: Option<PrecheckResult>,
+
+    /// Current protocol version of the network, as last read from Horizon
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub network_protocol_version: Option<u32>,
+
+    /// Stellar Core `build` string, as last read from the `/info` endpoint
+    /// Horizon ingests from
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stellar_core_version: Option<String>,
+
+    /// Reconciles that failed in a row when `nodeFailurePolicy.alarmAndStop`
+    /// stopped the node; reset by the next successful reconcile
+    #[serde(default)]
+    pub consecutive_failure_count: u32,
+
+    /// Whether the node is suspended for the weekend by
+    /// `spec.autoscaling.disableOnWeekend`
+    #[serde(default)]
+    pub weekend_suspension_active: bool,
+
+    /// Progress of a validator through `spec.validatorConfig.catchupBeforeValidate`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub validator_mode: Option<ValidatorMode>,
+
+    /// When a validator listed in `spec.validatorConfig.quorumSliceWatcher`
+    /// was first seen missing or disagreeing (RFC 3339), cleared once all agree
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub quorum_slice_degraded_since: Option<String>,
+
+    /// Node selector found by `spec.autoDetectNodeSelector`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub detected_node_selector: Option<BTreeMap<String, String>>,
+
+    /// Termination time of the most recent OOM kill reported as an event
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_oom_killed_at: Option<String>,
+
+    /// `spec.autoscaling.cronOverrides` window currently applied to the HPA
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub active_scaling_override: Option<ActiveScalingOverride>,
+
+    /// Validator public key (G...) derived from the seed in `seedSecretRef`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub public_key: Option<String>,
+}
+
+/// Cron scaling override currently applied to the HPA
+#[derive(Clone, Debug, Default, Deserialize, Serialize, JsonSchema, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct ActiveScalingOverride {
+    /// Schedule of the override
+    pub cron_expression: String,
+    /// HPA minReplicas while the window is open
+    pub min_replicas: i32,
+    /// HPA maxReplicas while the window is open
+    pub max_replicas: i32,
+    /// When the window closes and the configured bounds are restored (RFC 3339)
+    pub ends_at: String,
+}
+
+/// Whether a validator gated by `catchupBeforeValidate` is validating yet
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, JsonSchema, PartialEq, Eq)]
+pub enum ValidatorMode {
+    /// Running non-validating until Stellar Core reports it is synced
+    CatchingUp,
+    /// Caught up once and running as a validator
+    Validating,
+}
+
+impl std::fmt::Display for ValidatorMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ValidatorMode::CatchingUp => write!(f, "CatchingUp"),
+            ValidatorMode::Validating => write!(f, "Validating"),
+        }
+    }
+}
+
+/// Outcome of a precheck hook Job
+#[derive(Clone, Debug, Default, Deserialize, Serialize, JsonSchema, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct PrecheckResult {
+    /// Spec generation the check ran for
+    pub generation: i64,
+    /// Exit code of the check container (unset if it timed out before exiting)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub exit_code: Option<i32>,
+    /// Whether the check passed
+    pub passed: bool,
+    /// Human-readable summary
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
 }
 
 /// BGP advertisement status information
@@ -959,6 +3313,27 @@ mod tests {
                 kms_config: None,
                 vl_source: None,
                 hsm_config: None,
+                flood_tx_period_ms: None,
+                max_batch_write_count: None,
+                history_cache: None,
+                catchup_before_validate: false,
+                quorum_slice_watcher: None,
+                catchup_workers: None,
+                scp_ballot_timeout_ms: None,
+                scp_ledger_target_duration_ms: None,
+                scp_max_time_slip_seconds: None,
+                protocol_version_url: None,
+                minimum_protocol_version: None,
+                quorum_group: None,
+                quorum_validators: vec![],
+                archive_publication_credentials: None,
+                history_gc: None,
+                stellar_toml: None,
+                max_tx_set_size: None,
+                base_fee: None,
+                standby: false,
+                bucket_list_db: None,
+                stellar_beat_reporting: None,
             }),
             horizon_config: None,
             soroban_config: None,
@@ -967,6 +3342,7 @@ mod tests {
             max_unavailable: None,
             suspended: false,
             alerting: false,
+            prometheus_rules: None,
             database: None,
             managed_database: None,
             autoscaling: None,
@@ -981,10 +3357,32 @@ mod tests {
             network_policy: None,
             dr_config: None,
             topology_spread_constraints: None,
-            load_balancer: None,
-            global_discovery: None,
             cross_cluster: None,
             cve_handling: None,
+            image_override: None,
+            image_version_constraint: None,
+            extra_volume_mounts: vec![],
+            service: None,
+            hooks: None,
+            vault: None,
+            lifecycle: None,
+            container_security_context: None,
+            dev_mode: false,
+            allow_mainnet_parameter_override: false,
+            allow_debug_on_mainnet: false,
+            grafana_dashboard: None,
+            precheck_hook: None,
+            auto_detect_node_selector: false,
+            termination_message_policy: None,
+            termination_message_path: None,
+            emit_oom_event_on_kill: true,
+            mesh: None,
+            config_template_mode: ConfigTemplateMode::Static,
+            depends_on: vec![],
+            min_ready_replicas: None,
+            monitoring: None,
+            requeue_jitter_percent: None,
+            failure_policy: None,
             resource_meta: None,
         };
 
@@ -1008,6 +3406,26 @@ mod tests {
                 ingest_workers: 1,
                 enable_experimental_ingestion: false,
                 auto_migration: false,
+                min_stellar_core_version: None,
+                transaction_filtering: None,
+                database_ready_timeout_seconds: None,
+                db_wait_timeout_seconds: None,
+                offers_filtering: None,
+                cors_allowed_origins: vec![],
+                cors_allowed_headers: vec!["*".to_string()],
+                cors_expose_headers: vec![],
+                cors_max_age_seconds: None,
+                database_ssl: None,
+                captive_core: None,
+                order_book_cache_depth: None,
+                enable_order_book_streaming: false,
+                order_book_stale_tolerance_ledgers: None,
+                sse_max_connections: None,
+                tls: None,
+                checkpoint_frequency: None,
+                history_retention_count: None,
+                stellar_core_ref: None,
+                experimental_ingestion: None,
             }),
             soroban_config: None,
             replicas: 3,
@@ -1015,6 +3433,7 @@ mod tests {
             max_unavailable: None,
             suspended: false,
             alerting: false,
+            prometheus_rules: None,
             database: None,
             managed_database: None,
             autoscaling: None,
@@ -1031,6 +3450,30 @@ mod tests {
             topology_spread_constraints: None,
             cross_cluster: None,
             cve_handling: None,
+            image_override: None,
+            image_version_constraint: None,
+            extra_volume_mounts: vec![],
+            service: None,
+            hooks: None,
+            vault: None,
+            lifecycle: None,
+            container_security_context: None,
+            dev_mode: false,
+            allow_mainnet_parameter_override: false,
+            allow_debug_on_mainnet: false,
+            grafana_dashboard: None,
+            precheck_hook: None,
+            auto_detect_node_selector: false,
+            termination_message_policy: None,
+            termination_message_path: None,
+            emit_oom_event_on_kill: true,
+            mesh: None,
+            config_template_mode: ConfigTemplateMode::Static,
+            depends_on: vec![],
+            min_ready_replicas: None,
+            monitoring: None,
+            requeue_jitter_percent: None,
+            failure_policy: None,
             resource_meta: None,
         };
 
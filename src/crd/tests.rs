@@ -6,9 +6,21 @@
 #[cfg(test)]
 mod stellar_node_spec_validation {
     use crate::crd::{
-        AutoscalingConfig, HorizonConfig, IngressConfig, IngressHost, IngressPath, NodeType,
-        ResourceRequirements, ResourceSpec, SorobanConfig, SpecValidationError, StellarNetwork,
-        StellarNodeSpec, StorageConfig, ValidatorConfig,
+        AlertRule, ArchivalBackend, ArchivalStorageConfig, AutoscalingConfig, AzureBlobConfig,
+        BucketListDbConfig, ConfigTemplateMode, ContainerSecurityContextConfig,
+        CronScalingOverride, CustomResourceMetricSpec, DatabaseSslConfig, ExecHookSpec,
+        ExperimentalIngestionConfig, ExtraVolumeMount, FailurePolicy, GcImplementation,
+        GrafanaDashboardConfig, HistoryArchivePublishConfig, HistoryCacheConfig,
+        HistoryCacheVolumeType, HistoryGcConfig, HorizonCaptiveCoreConfig, HorizonConfig,
+        HorizonTlsConfig, HttpGetHookSpec, ImageOverrideConfig, IngressConfig, IngressHost,
+        IngressPath, LifecycleConfig, LifecycleHookSpec, LinuxCapability, MonitoringConfig,
+        NetworkPolicyConfig, NodeFailurePolicy, NodeType, OffersFilteringConfig,
+        PersistenceBackend, PgSslMode, PrecheckHookConfig, PrometheusRulesConfig,
+        QuorumSliceWatcherConfig, QuorumValidatorSpec, ReconcileHooksConfig, ResourceRequirements,
+        ResourceSpec, ServiceConfig, SessionAffinity, SorobanConfig, SorobanFeeConfig,
+        SpecValidationError, StellarBeatConfig, StellarNetwork, StellarNodeRef, StellarNodeSpec,
+        StellarTomlConfig, StorageConfig, TargetType, TerminationMessagePolicy,
+        TransactionFilteringConfig, ValidatorConfig, VaultConfig, VaultSecretSpec,
     };
 
     /// Helper to create a minimal valid StellarNodeSpec for a Validator
@@ -30,6 +42,27 @@ mod stellar_node_spec_validation {
                 kms_config: None,
                 vl_source: None,
                 hsm_config: None,
+                flood_tx_period_ms: None,
+                max_batch_write_count: None,
+                history_cache: None,
+                catchup_before_validate: false,
+                quorum_slice_watcher: None,
+                catchup_workers: None,
+                scp_ballot_timeout_ms: None,
+                scp_ledger_target_duration_ms: None,
+                scp_max_time_slip_seconds: None,
+                protocol_version_url: None,
+                minimum_protocol_version: None,
+                quorum_group: None,
+                quorum_validators: vec![],
+                archive_publication_credentials: None,
+                history_gc: None,
+                stellar_toml: None,
+                max_tx_set_size: None,
+                base_fee: None,
+                standby: false,
+                bucket_list_db: None,
+                stellar_beat_reporting: None,
             }),
             horizon_config: None,
             soroban_config: None,
@@ -38,6 +71,7 @@ mod stellar_node_spec_validation {
             max_unavailable: None,
             suspended: false,
             alerting: false,
+            prometheus_rules: None,
             database: None,
             managed_database: None,
             autoscaling: None,
@@ -51,6 +85,30 @@ mod stellar_node_spec_validation {
             dr_config: None,
             topology_spread_constraints: None,
             cve_handling: None,
+            image_override: None,
+            image_version_constraint: None,
+            extra_volume_mounts: vec![],
+            service: None,
+            hooks: None,
+            vault: None,
+            lifecycle: None,
+            container_security_context: None,
+            dev_mode: false,
+            allow_mainnet_parameter_override: false,
+            allow_debug_on_mainnet: false,
+            grafana_dashboard: None,
+            precheck_hook: None,
+            auto_detect_node_selector: false,
+            termination_message_policy: None,
+            termination_message_path: None,
+            emit_oom_event_on_kill: true,
+            mesh: None,
+            config_template_mode: ConfigTemplateMode::Static,
+            depends_on: vec![],
+            min_ready_replicas: None,
+            monitoring: None,
+            requeue_jitter_percent: None,
+            failure_policy: None,
             resource_meta: None,
         }
     }
@@ -72,6 +130,26 @@ mod stellar_node_spec_validation {
                 ingest_workers: 1,
                 enable_experimental_ingestion: false,
                 auto_migration: false,
+                min_stellar_core_version: None,
+                transaction_filtering: None,
+                database_ready_timeout_seconds: None,
+                db_wait_timeout_seconds: None,
+                offers_filtering: None,
+                cors_allowed_origins: vec![],
+                cors_allowed_headers: vec!["*".to_string()],
+                cors_expose_headers: vec![],
+                cors_max_age_seconds: None,
+                database_ssl: None,
+                captive_core: None,
+                order_book_cache_depth: None,
+                enable_order_book_streaming: false,
+                order_book_stale_tolerance_ledgers: None,
+                sse_max_connections: None,
+                tls: None,
+                checkpoint_frequency: None,
+                history_retention_count: None,
+                stellar_core_ref: None,
+                experimental_ingestion: None,
             }),
             soroban_config: None,
             replicas: 2,
@@ -79,6 +157,7 @@ mod stellar_node_spec_validation {
             max_unavailable: None,
             suspended: false,
             alerting: false,
+            prometheus_rules: None,
             database: None,
             managed_database: None,
             autoscaling: None,
@@ -92,6 +171,30 @@ mod stellar_node_spec_validation {
             dr_config: None,
             topology_spread_constraints: None,
             cve_handling: None,
+            image_override: None,
+            image_version_constraint: None,
+            extra_volume_mounts: vec![],
+            service: None,
+            hooks: None,
+            vault: None,
+            lifecycle: None,
+            container_security_context: None,
+            dev_mode: false,
+            allow_mainnet_parameter_override: false,
+            allow_debug_on_mainnet: false,
+            grafana_dashboard: None,
+            precheck_hook: None,
+            auto_detect_node_selector: false,
+            termination_message_policy: None,
+            termination_message_path: None,
+            emit_oom_event_on_kill: true,
+            mesh: None,
+            config_template_mode: ConfigTemplateMode::Static,
+            depends_on: vec![],
+            min_ready_replicas: None,
+            monitoring: None,
+            requeue_jitter_percent: None,
+            failure_policy: None,
             resource_meta: None,
         }
     }
@@ -114,12 +217,28 @@ mod stellar_node_spec_validation {
                 captive_core_structured_config: None,
                 enable_preflight: true,
                 max_events_per_request: 10000,
+                contract_code_cache_size: None,
+                contract_code_cache_ttl_seconds: None,
+                contract_instance_cache_size: None,
+                debug_rpc_max_body_log_bytes: None,
+                debug_rpc: false,
+                persistence_backend: Default::default(),
+                database_secret_ref: None,
+                run_migration_at_startup: false,
+                archival_storage: None,
+                tx_concurrency: None,
+                rpc_endpoint: None,
+                friendly_rpc_url: None,
+                tx_queue_size: None,
+                fee_config: None,
+                stellar_core_ref: None,
             }),
             replicas: 2,
             min_available: None,
             max_unavailable: None,
             suspended: false,
             alerting: false,
+            prometheus_rules: None,
             database: None,
             managed_database: None,
             autoscaling: None,
@@ -133,6 +252,30 @@ mod stellar_node_spec_validation {
             dr_config: None,
             topology_spread_constraints: None,
             cve_handling: None,
+            image_override: None,
+            image_version_constraint: None,
+            extra_volume_mounts: vec![],
+            service: None,
+            hooks: None,
+            vault: None,
+            lifecycle: None,
+            container_security_context: None,
+            dev_mode: false,
+            allow_mainnet_parameter_override: false,
+            allow_debug_on_mainnet: false,
+            grafana_dashboard: None,
+            precheck_hook: None,
+            auto_detect_node_selector: false,
+            termination_message_policy: None,
+            termination_message_path: None,
+            emit_oom_event_on_kill: true,
+            mesh: None,
+            config_template_mode: ConfigTemplateMode::Static,
+            depends_on: vec![],
+            min_ready_replicas: None,
+            monitoring: None,
+            requeue_jitter_percent: None,
+            failure_policy: None,
             resource_meta: None,
         }
     }
@@ -147,6 +290,7 @@ mod stellar_node_spec_validation {
                 cpu: "2".to_string(),
                 memory: "4Gi".to_string(),
             },
+            claims: vec![],
         }
     }
 
@@ -156,6 +300,8 @@ mod stellar_node_spec_validation {
             size: "100Gi".to_string(),
             retention_policy: Default::default(),
             annotations: None,
+            pvc_labels: None,
+            resize_grace_period_seconds: None,
         }
     }
 
@@ -229,6 +375,21 @@ mod stellar_node_spec_validation {
             target_cpu_utilization_percentage: Some(80),
             custom_metrics: vec![],
             behavior: None,
+            disable_on_weekend: false,
+            weekend_min_replicas: None,
+            weekend_timezone: None,
+            cluster_capacity_aware: false,
+            active_connections_metric: None,
+            max_connections_per_replica: None,
+            target_active_connections: None,
+            cron_overrides: vec![],
+            post_deploy_cooldown_seconds: None,
+            ingest_lag_metric: None,
+            max_ingest_lag_seconds: None,
+            enabled: true,
+            instance_weight_annotations: Default::default(),
+            instance_weight_label: "node.kubernetes.io/instance-type".to_string(),
+            custom_resource_metrics: vec![],
         });
 
         let result = spec.validate();
@@ -332,6 +493,791 @@ mod stellar_node_spec_validation {
         }));
     }
 
+    #[test]
+    fn test_horizon_filtering_without_rules_fails() {
+        let mut spec = valid_horizon_spec();
+        spec.horizon_config.as_mut().unwrap().experimental_ingestion =
+            Some(ExperimentalIngestionConfig {
+                enable_filtering: true,
+                ..Default::default()
+            });
+
+        let errors = spec.validate().unwrap_err();
+        assert!(errors
+            .iter()
+            .any(|e| { e.field == "spec.horizonConfig.experimentalIngestion.filterRulesFile" }));
+    }
+
+    #[test]
+    fn test_horizon_filtering_with_rules_passes() {
+        let mut spec = valid_horizon_spec();
+        spec.horizon_config.as_mut().unwrap().experimental_ingestion =
+            Some(ExperimentalIngestionConfig {
+                enable_filtering: true,
+                filter_rules_file: Some(r#"{"assets": []}"#.to_string()),
+                enable_soroban_events: true,
+                ..Default::default()
+            });
+
+        assert!(spec.validate().is_ok());
+    }
+
+    #[test]
+    fn test_validator_quorum_validators_validation() {
+        let key = "GCGB2S2KGYARPVIA37HYZXVRM2YZUEXA6S33ZU5BUDC6THSB62LZSTYH";
+        let quorum_validator = |public_key: &str, name: &str| QuorumValidatorSpec {
+            public_key: public_key.to_string(),
+            name: name.to_string(),
+            home_domain: "stellar.org".to_string(),
+            weight: None,
+        };
+
+        let mut spec = valid_validator_spec();
+        spec.validator_config.as_mut().unwrap().quorum_validators =
+            vec![quorum_validator(key, "sdf_1")];
+        assert!(spec.validate().is_ok());
+
+        spec.validator_config.as_mut().unwrap().quorum_validators = vec![
+            quorum_validator(key, "sdf_1"),
+            quorum_validator(key, "sdf_1_again"),
+            quorum_validator("not-a-key", ""),
+        ];
+        let errors = spec.validate().unwrap_err();
+        assert!(errors
+            .iter()
+            .any(|e| e.field == "spec.validatorConfig.quorumValidators[1].publicKey"));
+        assert!(errors
+            .iter()
+            .any(|e| e.field == "spec.validatorConfig.quorumValidators[2].publicKey"));
+        assert!(errors
+            .iter()
+            .any(|e| e.field == "spec.validatorConfig.quorumValidators[2].name"));
+    }
+
+    #[test]
+    fn test_validator_stellar_toml_validation() {
+        let mut spec = valid_validator_spec();
+        spec.validator_config.as_mut().unwrap().stellar_toml = Some(StellarTomlConfig {
+            organization_name: "Example Org".to_string(),
+            organization_url: "https://example.org".to_string(),
+            node_alias: "example-1".to_string(),
+            public_key: "GCGB2S2KGYARPVIA37HYZXVRM2YZUEXA6S33ZU5BUDC6THSB62LZSTYH".to_string(),
+            history_url: "https://history.example.org".to_string(),
+            documentation: None,
+            cert_manager_issuer: None,
+            cert_manager_cluster_issuer: Some("letsencrypt-prod".to_string()),
+        });
+        assert!(spec.validate().is_ok());
+
+        let stellar_toml = spec
+            .validator_config
+            .as_mut()
+            .unwrap()
+            .stellar_toml
+            .as_mut()
+            .unwrap();
+        stellar_toml.organization_url = "example.org".to_string();
+        stellar_toml.public_key = "not-a-key".to_string();
+        stellar_toml.cert_manager_cluster_issuer = None;
+
+        let errors = spec.validate().unwrap_err();
+        for field in [
+            "spec.validatorConfig.stellarToml.organizationUrl",
+            "spec.validatorConfig.stellarToml.publicKey",
+            "spec.validatorConfig.stellarToml",
+        ] {
+            assert!(errors.iter().any(|e| e.field == field), "missing {field}");
+        }
+    }
+
+    #[test]
+    fn test_validator_network_parameters_reject_zero() {
+        let mut spec = valid_validator_spec();
+        let vc = spec.validator_config.as_mut().unwrap();
+        vc.max_tx_set_size = Some(0);
+        vc.base_fee = Some(0);
+        let errors = spec.validate().unwrap_err();
+        assert!(errors
+            .iter()
+            .any(|e| e.field == "spec.validatorConfig.maxTxSetSize"));
+        assert!(errors
+            .iter()
+            .any(|e| e.field == "spec.validatorConfig.baseFee"));
+
+        let vc = spec.validator_config.as_mut().unwrap();
+        vc.max_tx_set_size = Some(1000);
+        vc.base_fee = Some(100);
+        assert!(spec.validate().is_ok());
+        assert!(spec.validation_warnings().is_empty());
+    }
+
+    #[test]
+    fn test_validator_low_base_fee_and_mainnet_warnings() {
+        let mut spec = valid_validator_spec();
+        spec.validator_config.as_mut().unwrap().base_fee = Some(50);
+        assert!(spec.validate().is_ok());
+        let warnings = spec.validation_warnings();
+        assert!(warnings
+            .iter()
+            .any(|w| w.field == "spec.validatorConfig.baseFee"));
+
+        spec.validator_config.as_mut().unwrap().base_fee = Some(100);
+        spec.network = StellarNetwork::Mainnet;
+        let warnings = spec.validation_warnings();
+        assert!(warnings
+            .iter()
+            .any(|w| w.field == "spec.allowMainnetParameterOverride"));
+        assert!(!spec.network_parameters_allowed());
+
+        spec.allow_mainnet_parameter_override = true;
+        assert!(spec.network_parameters_allowed());
+        assert!(spec.validation_warnings().is_empty());
+    }
+
+    #[test]
+    fn test_validator_flood_settings_validation() {
+        let mut spec = valid_validator_spec();
+        let vc = spec.validator_config.as_mut().unwrap();
+        vc.flood_tx_period_ms = Some(5);
+        vc.max_batch_write_count = Some(0);
+        let errors = spec.validate().unwrap_err();
+        assert!(errors
+            .iter()
+            .any(|e| e.field == "spec.validatorConfig.floodTxPeriodMs"));
+        assert!(errors
+            .iter()
+            .any(|e| e.field == "spec.validatorConfig.maxBatchWriteCount"));
+
+        let vc = spec.validator_config.as_mut().unwrap();
+        vc.flood_tx_period_ms = Some(100);
+        vc.max_batch_write_count = Some(4096);
+        assert!(spec.validate().is_ok());
+    }
+
+    #[test]
+    fn test_validator_scp_timing_validation() {
+        let mut spec = valid_validator_spec();
+        let vc = spec.validator_config.as_mut().unwrap();
+        vc.scp_ballot_timeout_ms = Some(50);
+        let errors = spec.validate().unwrap_err();
+        assert!(errors
+            .iter()
+            .any(|e| e.field == "spec.validatorConfig.scpBallotTimeoutMs"));
+
+        let vc = spec.validator_config.as_mut().unwrap();
+        vc.scp_ballot_timeout_ms = Some(1000);
+        vc.scp_ledger_target_duration_ms = Some(1500);
+        assert!(spec.validate().is_ok());
+        assert!(spec
+            .validation_warnings()
+            .iter()
+            .any(|w| w.field == "spec.validatorConfig.scpLedgerTargetDurationMs"));
+
+        spec.validator_config
+            .as_mut()
+            .unwrap()
+            .scp_ledger_target_duration_ms = Some(5000);
+        assert!(spec.validation_warnings().is_empty());
+    }
+
+    #[test]
+    fn test_validator_catchup_workers_range() {
+        let mut spec = valid_validator_spec();
+        for (workers, valid) in [(0, false), (1, true), (64, true), (65, false)] {
+            spec.validator_config.as_mut().unwrap().catchup_workers = Some(workers);
+            let result = spec.validate();
+            assert_eq!(result.is_ok(), valid, "catchupWorkers {workers}");
+            if let Err(errors) = result {
+                assert!(errors
+                    .iter()
+                    .any(|e| e.field == "spec.validatorConfig.catchupWorkers"));
+            }
+        }
+    }
+
+    #[test]
+    fn test_validator_bucket_list_db_ranges() {
+        let mut spec = valid_validator_spec();
+        spec.validator_config.as_mut().unwrap().bucket_list_db = Some(BucketListDbConfig {
+            index_page_size_exponent: Some(25),
+            index_cutoff_mb: Some(20_000),
+            persist_index: None,
+        });
+        let errors = spec.validate().unwrap_err();
+        assert!(errors
+            .iter()
+            .any(|e| e.field == "spec.validatorConfig.bucketListDb.indexPageSizeExponent"));
+        assert!(errors
+            .iter()
+            .any(|e| e.field == "spec.validatorConfig.bucketListDb.indexCutoffMb"));
+
+        spec.validator_config.as_mut().unwrap().bucket_list_db = Some(BucketListDbConfig {
+            index_page_size_exponent: Some(0),
+            index_cutoff_mb: Some(100),
+            persist_index: Some(false),
+        });
+        assert!(spec.validate().is_ok());
+    }
+
+    #[test]
+    fn test_stellar_toml_home_domain() {
+        let config = |url: &str| StellarTomlConfig {
+            organization_url: url.to_string(),
+            ..Default::default()
+        };
+        assert_eq!(
+            config("https://example.org/about").home_domain(),
+            Some("example.org")
+        );
+        assert_eq!(
+            config("https://example.org:8443").home_domain(),
+            Some("example.org")
+        );
+        assert_eq!(config("example.org").home_domain(), None);
+    }
+
+    #[test]
+    fn test_vault_config_validation() {
+        let mut spec = valid_horizon_spec();
+        spec.vault = Some(VaultConfig {
+            role: "stellar-horizon".to_string(),
+            secrets: vec![VaultSecretSpec {
+                name: "db-url".to_string(),
+                path: "secret/data/stellar/horizon-db".to_string(),
+                env_var: Some("DATABASE_URL_FILE".to_string()),
+                ..Default::default()
+            }],
+            ..Default::default()
+        });
+        assert!(spec.validate().is_ok());
+
+        spec.vault = Some(VaultConfig {
+            role: String::new(),
+            secrets: vec![VaultSecretSpec {
+                name: "../seed".to_string(),
+                path: String::new(),
+                ..Default::default()
+            }],
+            ..Default::default()
+        });
+        let errors = spec.validate().unwrap_err();
+        for field in [
+            "spec.vault.role",
+            "spec.vault.secrets[0].name",
+            "spec.vault.secrets[0].path",
+        ] {
+            assert!(errors.iter().any(|e| e.field == field), "missing {field}");
+        }
+    }
+
+    #[test]
+    fn test_horizon_zero_history_settings_fail() {
+        let mut spec = valid_horizon_spec();
+        let horizon = spec.horizon_config.as_mut().unwrap();
+        horizon.checkpoint_frequency = Some(0);
+        horizon.history_retention_count = Some(0);
+
+        let errors = spec.validate().unwrap_err();
+        assert!(errors
+            .iter()
+            .any(|e| e.field == "spec.horizonConfig.checkpointFrequency"));
+        assert!(errors
+            .iter()
+            .any(|e| e.field == "spec.horizonConfig.historyRetentionCount"));
+
+        let horizon = spec.horizon_config.as_mut().unwrap();
+        horizon.checkpoint_frequency = Some(64);
+        horizon.history_retention_count = Some(17280);
+        assert!(spec.validate().is_ok());
+    }
+
+    #[test]
+    fn test_horizon_zero_db_wait_timeout_fails() {
+        let mut spec = valid_horizon_spec();
+        spec.horizon_config
+            .as_mut()
+            .unwrap()
+            .db_wait_timeout_seconds = Some(0);
+
+        let errors = spec.validate().unwrap_err();
+        assert!(errors
+            .iter()
+            .any(|e| e.field == "spec.horizonConfig.dbWaitTimeoutSeconds"));
+
+        spec.horizon_config
+            .as_mut()
+            .unwrap()
+            .db_wait_timeout_seconds = Some(120);
+        assert!(spec.validate().is_ok());
+    }
+
+    #[test]
+    fn test_horizon_zero_database_ready_timeout_fails() {
+        let mut spec = valid_horizon_spec();
+        let horizon = spec.horizon_config.as_mut().unwrap();
+        horizon.database_ready_timeout_seconds = Some(0);
+
+        let errors = spec.validate().unwrap_err();
+        assert!(errors
+            .iter()
+            .any(|e| e.field == "spec.horizonConfig.databaseReadyTimeoutSeconds"));
+
+        let horizon = spec.horizon_config.as_mut().unwrap();
+        horizon.database_ready_timeout_seconds = Some(600);
+        assert!(spec.validate().is_ok());
+    }
+
+    #[test]
+    fn test_horizon_order_book_depth_limit() {
+        let mut spec = valid_horizon_spec();
+        spec.horizon_config.as_mut().unwrap().order_book_cache_depth = Some(201);
+        let errors = spec.validate().unwrap_err();
+        assert!(errors
+            .iter()
+            .any(|e| e.field == "spec.horizonConfig.orderBookCacheDepth"));
+
+        spec.horizon_config.as_mut().unwrap().order_book_cache_depth = Some(200);
+        assert!(spec.validate().is_ok());
+    }
+
+    #[test]
+    fn test_horizon_min_stellar_core_version_validation() {
+        let mut spec = valid_horizon_spec();
+        spec.horizon_config
+            .as_mut()
+            .unwrap()
+            .min_stellar_core_version = Some("latest".to_string());
+        let errors = spec.validate().unwrap_err();
+        assert!(errors
+            .iter()
+            .any(|e| e.field == "spec.horizonConfig.minStellarCoreVersion"));
+
+        spec.horizon_config
+            .as_mut()
+            .unwrap()
+            .min_stellar_core_version = Some("21.0.0".to_string());
+        assert!(spec.validate().is_ok());
+    }
+
+    #[test]
+    fn test_horizon_transaction_filtering_validation() {
+        let mut spec = valid_horizon_spec();
+        spec.horizon_config.as_mut().unwrap().transaction_filtering =
+            Some(TransactionFilteringConfig {
+                enabled: true,
+                filter_service_url: String::new(),
+                filter_cache_ttl_seconds: 300,
+                filter_on_ingest: false,
+            });
+        let errors = spec.validate().unwrap_err();
+        assert!(errors
+            .iter()
+            .any(|e| e.field == "spec.horizonConfig.transactionFiltering.filterServiceUrl"));
+
+        let filtering = spec
+            .horizon_config
+            .as_mut()
+            .unwrap()
+            .transaction_filtering
+            .as_mut()
+            .unwrap();
+        filtering.filter_service_url = "http://compliance.internal/v1/filter".to_string();
+        assert!(spec.validate().is_ok());
+
+        // Mainnet requires HTTPS
+        spec.network = StellarNetwork::Mainnet;
+        let errors = spec.validate().unwrap_err();
+        assert!(errors.iter().any(|e| e.message.contains("HTTPS")));
+
+        let filtering = spec
+            .horizon_config
+            .as_mut()
+            .unwrap()
+            .transaction_filtering
+            .as_mut()
+            .unwrap();
+        filtering.filter_service_url = "https://compliance.example.com/v1/filter".to_string();
+        assert!(spec.validate().is_ok());
+    }
+
+    #[test]
+    fn test_session_affinity_timeout_validation() {
+        let mut spec = valid_horizon_spec();
+        spec.service = Some(ServiceConfig {
+            session_affinity: SessionAffinity::ClientIp,
+            session_affinity_timeout_seconds: Some(3600),
+            ..Default::default()
+        });
+        assert!(spec.validate().is_ok());
+
+        spec.service
+            .as_mut()
+            .unwrap()
+            .session_affinity_timeout_seconds = Some(86401);
+        let errors = spec.validate().unwrap_err();
+        assert!(errors
+            .iter()
+            .any(|e| e.field == "spec.service.sessionAffinityTimeoutSeconds"
+                && e.message.contains("out of range")));
+
+        spec.service = Some(ServiceConfig {
+            session_affinity_timeout_seconds: Some(60),
+            ..Default::default()
+        });
+        let errors = spec.validate().unwrap_err();
+        assert!(errors
+            .iter()
+            .any(|e| e.message.contains("requires sessionAffinity ClientIP")));
+    }
+
+    #[test]
+    fn test_horizon_tls_validation() {
+        let mut spec = valid_horizon_spec();
+        spec.horizon_config.as_mut().unwrap().tls = Some(HorizonTlsConfig {
+            cert_secret_ref: "horizon-cert".to_string(),
+            port: 443,
+            client_ca_secret_ref: None,
+        });
+        assert!(spec.validate().is_ok());
+
+        let tls = spec.horizon_config.as_mut().unwrap().tls.as_mut().unwrap();
+        tls.cert_secret_ref = " ".to_string();
+        tls.port = 0;
+        let errors = spec.validate().unwrap_err();
+        assert!(errors
+            .iter()
+            .any(|e| e.field == "spec.horizonConfig.tls.certSecretRef"));
+        assert!(errors
+            .iter()
+            .any(|e| e.field == "spec.horizonConfig.tls.port"));
+    }
+
+    #[test]
+    fn test_horizon_tls_port_defaults_to_443() {
+        let tls: HorizonTlsConfig =
+            serde_json::from_value(serde_json::json!({ "certSecretRef": "horizon-cert" })).unwrap();
+        assert_eq!(tls.port, 443);
+    }
+
+    #[test]
+    fn test_horizon_cors_origin_validation() {
+        let mut spec = valid_horizon_spec();
+        spec.horizon_config.as_mut().unwrap().cors_allowed_origins = vec![
+            "https://app.example.com".to_string(),
+            "http://localhost:3000".to_string(),
+            "https://*.example.org".to_string(),
+        ];
+        assert!(spec.validate().is_ok());
+
+        spec.horizon_config.as_mut().unwrap().cors_allowed_origins = vec![
+            "app.example.com".to_string(),
+            "https://example.com/path".to_string(),
+            "*".to_string(),
+        ];
+        let errors = spec.validate().unwrap_err();
+        let fields: Vec<&str> = errors.iter().map(|e| e.field.as_str()).collect();
+        assert!(fields.contains(&"spec.horizonConfig.corsAllowedOrigins[0]"));
+        assert!(fields.contains(&"spec.horizonConfig.corsAllowedOrigins[1]"));
+        assert!(fields.contains(&"spec.horizonConfig.corsAllowedOrigins"));
+    }
+
+    #[test]
+    fn test_node_failure_policy_validation() {
+        let mut spec = valid_horizon_spec();
+        spec.failure_policy = Some(NodeFailurePolicy::ExponentialBackoff {
+            initial: 10,
+            max: 600,
+        });
+        assert!(spec.validate().is_ok());
+
+        for (policy, field) in [
+            (
+                NodeFailurePolicy::Requeue(0),
+                "spec.nodeFailurePolicy.requeue",
+            ),
+            (
+                NodeFailurePolicy::ExponentialBackoff {
+                    initial: 60,
+                    max: 30,
+                },
+                "spec.nodeFailurePolicy.exponentialBackoff",
+            ),
+            (
+                NodeFailurePolicy::AlarmAndStop { max_failures: 0 },
+                "spec.nodeFailurePolicy.alarmAndStop.maxFailures",
+            ),
+        ] {
+            spec.failure_policy = Some(policy);
+            let errors = spec.validate().unwrap_err();
+            assert!(errors.iter().any(|e| e.field == field), "{field}");
+        }
+    }
+
+    #[test]
+    fn test_resource_claims_validation() {
+        let mut spec = valid_horizon_spec();
+        spec.resources.claims = vec!["nvme-scratch".to_string()];
+        assert!(spec.validate().is_ok());
+
+        spec.resources.claims = vec![
+            "nvme-scratch".to_string(),
+            "nvme-scratch".to_string(),
+            "NVMe_Scratch".to_string(),
+        ];
+        let errors = spec.validate().unwrap_err();
+        let fields: Vec<&str> = errors.iter().map(|e| e.field.as_str()).collect();
+        assert!(fields.contains(&"spec.resources.claims[1]"));
+        assert!(fields.contains(&"spec.resources.claims[2]"));
+        assert!(!fields.contains(&"spec.resources.claims[0]"));
+    }
+
+    #[test]
+    fn test_horizon_cors_header_validation() {
+        let mut spec = valid_horizon_spec();
+        let horizon = spec.horizon_config.as_mut().unwrap();
+        horizon.cors_allowed_headers =
+            vec!["Content-Type".to_string(), "X-Client-Name".to_string()];
+        horizon.cors_expose_headers = vec!["X-Ratelimit-Remaining".to_string()];
+        horizon.cors_max_age_seconds = Some(600);
+        assert!(spec.validate().is_ok());
+
+        let horizon = spec.horizon_config.as_mut().unwrap();
+        horizon.cors_allowed_headers = vec!["X Client".to_string()];
+        horizon.cors_expose_headers = vec!["Set-Cookie".to_string(), "authorization".to_string()];
+        horizon.cors_max_age_seconds = Some(0);
+        let errors = spec.validate().unwrap_err();
+        let fields: Vec<&str> = errors.iter().map(|e| e.field.as_str()).collect();
+        assert!(fields.contains(&"spec.horizonConfig.corsAllowedHeaders[0]"));
+        assert!(fields.contains(&"spec.horizonConfig.corsExposeHeaders[0]"));
+        assert!(fields.contains(&"spec.horizonConfig.corsExposeHeaders[1]"));
+        assert!(fields.contains(&"spec.horizonConfig.corsMaxAgeSeconds"));
+    }
+
+    #[test]
+    fn test_horizon_offers_filtering_validation() {
+        let mut spec = valid_horizon_spec();
+        spec.horizon_config.as_mut().unwrap().offers_filtering = Some(OffersFilteringConfig {
+            enabled: true,
+            filter_rules_configmap_ref: Some("offer-filters".to_string()),
+            update_interval_seconds: Some(60),
+        });
+        assert!(spec.validate().is_ok());
+        assert_eq!(
+            spec.horizon_filter_rules_config_map(),
+            Some("offer-filters")
+        );
+
+        spec.horizon_config.as_mut().unwrap().offers_filtering = Some(OffersFilteringConfig {
+            enabled: true,
+            filter_rules_configmap_ref: None,
+            update_interval_seconds: Some(0),
+        });
+        let errors = spec.validate().unwrap_err();
+        let fields: Vec<&str> = errors.iter().map(|e| e.field.as_str()).collect();
+        assert!(fields.contains(&"spec.horizonConfig.offersFiltering.filterRulesConfigmapRef"));
+        assert!(fields.contains(&"spec.horizonConfig.offersFiltering.updateIntervalSeconds"));
+    }
+
+    #[test]
+    fn test_soroban_postgresql_requires_database_secret() {
+        let mut spec = valid_soroban_spec();
+        let soroban = spec.soroban_config.as_mut().unwrap();
+        soroban.persistence_backend = PersistenceBackend::PostgreSQL;
+        let errors = spec.validate().unwrap_err();
+        assert!(errors
+            .iter()
+            .any(|e| e.field == "spec.sorobanConfig.databaseSecretRef"));
+
+        spec.soroban_config.as_mut().unwrap().database_secret_ref = Some("soroban-db".to_string());
+        assert!(spec.validate().is_ok());
+    }
+
+    #[test]
+    fn test_soroban_debug_rpc_rejected_on_mainnet() {
+        let mut spec = valid_soroban_spec();
+        spec.soroban_config.as_mut().unwrap().debug_rpc = true;
+        assert!(spec.validate().is_ok());
+
+        spec.network = StellarNetwork::Mainnet;
+        let errors = spec.validate().unwrap_err();
+        assert!(errors
+            .iter()
+            .any(|e| e.field == "spec.sorobanConfig.debugRpc"));
+
+        spec.allow_debug_on_mainnet = true;
+        assert!(spec.validate().is_ok());
+        assert!(spec.soroban_debug_rpc());
+    }
+
+    #[test]
+    fn test_soroban_debug_rpc_zero_body_bytes_fails() {
+        let mut spec = valid_soroban_spec();
+        let soroban = spec.soroban_config.as_mut().unwrap();
+        soroban.debug_rpc = true;
+        soroban.debug_rpc_max_body_log_bytes = Some(0);
+        let errors = spec.validate().unwrap_err();
+        assert!(errors
+            .iter()
+            .any(|e| e.field == "spec.sorobanConfig.debugRpcMaxBodyLogBytes"));
+    }
+
+    #[test]
+    fn test_depends_on_validation() {
+        let mut spec = valid_horizon_spec();
+        spec.depends_on = vec![StellarNodeRef {
+            name: "core".to_string(),
+            namespace: None,
+        }];
+        assert!(spec.validate().is_ok());
+
+        spec.depends_on[0].name = " ".to_string();
+        let errors = spec.validate().unwrap_err();
+        assert!(errors.iter().any(|e| e.field == "spec.dependsOn[0].name"));
+    }
+
+    #[test]
+    fn test_horizon_database_ssl_validation() {
+        let mut spec = valid_horizon_spec();
+        spec.horizon_config.as_mut().unwrap().database_ssl = Some(DatabaseSslConfig {
+            mode: PgSslMode::VerifyFull,
+            ca_cert_secret_ref: Some("pg-ca".to_string()),
+            ..Default::default()
+        });
+        assert!(spec.validate().is_ok());
+
+        let ssl = spec
+            .horizon_config
+            .as_mut()
+            .unwrap()
+            .database_ssl
+            .as_mut()
+            .unwrap();
+        ssl.ca_cert_secret_ref = None;
+        ssl.client_cert_secret_ref = Some("pg-client".to_string());
+        let errors = spec.validate().unwrap_err();
+        assert!(errors
+            .iter()
+            .any(|e| e.field == "spec.horizonConfig.databaseSSL.caCertSecretRef"));
+        assert!(errors
+            .iter()
+            .any(|e| e.field == "spec.horizonConfig.databaseSSL.clientCertSecretRef"));
+
+        spec.horizon_config.as_mut().unwrap().database_ssl = Some(DatabaseSslConfig {
+            mode: PgSslMode::Disable,
+            ..Default::default()
+        });
+        assert!(spec.validate().is_ok());
+        spec.network = StellarNetwork::Mainnet;
+        let errors = spec.validate().unwrap_err();
+        assert!(errors
+            .iter()
+            .any(|e| e.field == "spec.horizonConfig.databaseSSL.mode"
+                && e.message.contains("not allowed on Mainnet")));
+    }
+
+    #[test]
+    fn test_pg_ssl_mode_ordering() {
+        let ssl: DatabaseSslConfig =
+            serde_json::from_value(serde_json::json!({ "mode": "VerifyCa" })).unwrap();
+        assert!(ssl.mode.verifies_server());
+        assert_eq!(ssl.mode.as_libpq(), "verify-ca");
+        assert!(!PgSslMode::Require.verifies_server());
+        assert_eq!(DatabaseSslConfig::default().mode, PgSslMode::Prefer);
+    }
+
+    #[test]
+    fn test_horizon_order_book_streaming_warns_without_sse_limit() {
+        let mut spec = valid_horizon_spec();
+        spec.horizon_config
+            .as_mut()
+            .unwrap()
+            .enable_order_book_streaming = true;
+        assert!(spec.validate().is_ok());
+
+        let warnings = spec.validation_warnings();
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].field, "spec.horizonConfig.sseMaxConnections");
+
+        spec.horizon_config.as_mut().unwrap().sse_max_connections = Some(500);
+        assert!(spec.validation_warnings().is_empty());
+    }
+
+    #[test]
+    fn test_horizon_stellar_core_ref_resolves_to_fqdn() {
+        let mut spec = valid_horizon_spec();
+        let horizon = spec.horizon_config.as_mut().unwrap();
+        horizon.stellar_core_url = String::new();
+        horizon.stellar_core_ref = Some(StellarNodeRef {
+            name: "validator-1".to_string(),
+            namespace: Some("stellar-core".to_string()),
+        });
+
+        assert!(spec.validate().is_ok());
+        assert_eq!(
+            spec.resolved_stellar_core_url("horizon").as_deref(),
+            Some("http://validator-1.stellar-core.svc.cluster.local:11626")
+        );
+    }
+
+    #[test]
+    fn test_soroban_stellar_core_ref_defaults_to_own_namespace() {
+        let mut spec = valid_soroban_spec();
+        spec.soroban_config.as_mut().unwrap().stellar_core_ref = Some(StellarNodeRef {
+            name: "validator-1".to_string(),
+            namespace: None,
+        });
+
+        assert_eq!(
+            spec.resolved_stellar_core_url("stellar").as_deref(),
+            Some("http://validator-1.stellar.svc.cluster.local:11626")
+        );
+    }
+
+    #[test]
+    fn test_horizon_missing_core_url_and_ref_fails() {
+        let mut spec = valid_horizon_spec();
+        spec.horizon_config.as_mut().unwrap().stellar_core_url = String::new();
+
+        let errors = spec.validate().unwrap_err();
+        assert!(errors
+            .iter()
+            .any(|e| e.field == "spec.horizonConfig.stellarCoreUrl"));
+    }
+
+    #[test]
+    fn test_horizon_captive_core_excludes_core_url() {
+        let mut spec = valid_horizon_spec();
+        let horizon = spec.horizon_config.as_mut().unwrap();
+        horizon.captive_core = Some(HorizonCaptiveCoreConfig {
+            config: "PEER_PORT=11725".to_string(),
+            binary_path: "/usr/bin/stellar-core".to_string(),
+        });
+        let errors = spec.validate().unwrap_err();
+        assert!(errors
+            .iter()
+            .any(|e| e.field == "spec.horizonConfig.captiveCore"));
+
+        spec.horizon_config.as_mut().unwrap().stellar_core_url = String::new();
+        assert!(spec.validate().is_ok());
+
+        let captive = spec
+            .horizon_config
+            .as_mut()
+            .unwrap()
+            .captive_core
+            .as_mut()
+            .unwrap();
+        captive.config = " ".to_string();
+        captive.binary_path = "stellar-core".to_string();
+        let errors = spec.validate().unwrap_err();
+        assert!(errors
+            .iter()
+            .any(|e| e.field == "spec.horizonConfig.captiveCore.config"));
+        assert!(errors
+            .iter()
+            .any(|e| e.field == "spec.horizonConfig.captiveCore.binaryPath"));
+    }
+
     #[test]
     fn test_horizon_with_multiple_replicas_passes() {
         let mut spec = valid_horizon_spec();
@@ -339,6 +1285,31 @@ mod stellar_node_spec_validation {
         assert!(spec.validate().is_ok());
     }
 
+    #[test]
+    fn test_min_ready_replicas_defaults_to_one_and_caps_at_replicas() {
+        let mut spec = valid_horizon_spec();
+        spec.replicas = 5;
+        assert_eq!(spec.min_ready_replicas(), 1);
+
+        spec.min_ready_replicas = Some(3);
+        assert!(spec.validate().is_ok());
+        assert_eq!(spec.min_ready_replicas(), 3);
+
+        spec.replicas = 2;
+        assert_eq!(spec.min_ready_replicas(), 2);
+
+        spec.replicas = 0;
+        assert_eq!(spec.min_ready_replicas(), 0);
+    }
+
+    #[test]
+    fn test_min_ready_replicas_zero_fails() {
+        let mut spec = valid_horizon_spec();
+        spec.min_ready_replicas = Some(0);
+        let errors = spec.validate().unwrap_err();
+        assert!(errors.iter().any(|e| e.field == "spec.minReadyReplicas"));
+    }
+
     #[test]
     fn test_horizon_valid_autoscaling_passes() {
         let mut spec = valid_horizon_spec();
@@ -348,9 +1319,179 @@ mod stellar_node_spec_validation {
             target_cpu_utilization_percentage: Some(80),
             custom_metrics: vec![],
             behavior: None,
+            disable_on_weekend: false,
+            weekend_min_replicas: None,
+            weekend_timezone: None,
+            cluster_capacity_aware: false,
+            active_connections_metric: None,
+            max_connections_per_replica: None,
+            target_active_connections: None,
+            cron_overrides: vec![],
+            post_deploy_cooldown_seconds: None,
+            ingest_lag_metric: None,
+            max_ingest_lag_seconds: None,
+            enabled: true,
+            instance_weight_annotations: Default::default(),
+            instance_weight_label: "node.kubernetes.io/instance-type".to_string(),
+            custom_resource_metrics: vec![],
+        });
+
+        assert!(spec.validate().is_ok());
+    }
+
+    #[test]
+    fn test_horizon_disabled_autoscaling_is_not_enabled() {
+        let mut spec = valid_horizon_spec();
+        spec.autoscaling = Some(AutoscalingConfig {
+            min_replicas: 2,
+            max_replicas: 10,
+            target_cpu_utilization_percentage: Some(80),
+            custom_metrics: vec![],
+            behavior: None,
+            disable_on_weekend: false,
+            weekend_min_replicas: None,
+            weekend_timezone: None,
+            cluster_capacity_aware: false,
+            active_connections_metric: None,
+            max_connections_per_replica: None,
+            target_active_connections: None,
+            cron_overrides: vec![],
+            post_deploy_cooldown_seconds: None,
+            ingest_lag_metric: None,
+            max_ingest_lag_seconds: None,
+            enabled: false,
+            instance_weight_annotations: Default::default(),
+            instance_weight_label: "node.kubernetes.io/instance-type".to_string(),
+            custom_resource_metrics: vec![],
         });
 
         assert!(spec.validate().is_ok());
+        assert!(!spec.autoscaling_enabled());
+
+        spec.autoscaling.as_mut().unwrap().enabled = true;
+        assert!(spec.autoscaling_enabled());
+    }
+
+    #[test]
+    fn test_removed_optional_config_disables_owned_resources() {
+        let mut spec = valid_horizon_spec();
+        spec.autoscaling = Some(AutoscalingConfig {
+            min_replicas: 2,
+            max_replicas: 10,
+            target_cpu_utilization_percentage: Some(80),
+            custom_metrics: vec![],
+            behavior: None,
+            disable_on_weekend: false,
+            weekend_min_replicas: None,
+            weekend_timezone: None,
+            cluster_capacity_aware: false,
+            active_connections_metric: None,
+            max_connections_per_replica: None,
+            target_active_connections: None,
+            cron_overrides: vec![],
+            post_deploy_cooldown_seconds: None,
+            ingest_lag_metric: None,
+            max_ingest_lag_seconds: None,
+            enabled: true,
+            instance_weight_annotations: Default::default(),
+            instance_weight_label: "node.kubernetes.io/instance-type".to_string(),
+            custom_resource_metrics: vec![],
+        });
+        spec.ingress = Some(IngressConfig {
+            class_name: Some("nginx".to_string()),
+            hosts: vec![IngressHost {
+                host: "horizon.example.com".to_string(),
+                paths: vec![IngressPath {
+                    path: "/".to_string(),
+                    path_type: Some("Prefix".to_string()),
+                }],
+            }],
+            tls_secret_name: None,
+            cert_manager_issuer: None,
+            cert_manager_cluster_issuer: None,
+            annotations: None,
+        });
+        spec.network_policy = Some(NetworkPolicyConfig {
+            enabled: true,
+            ..Default::default()
+        });
+        assert!(spec.autoscaling_enabled());
+        assert!(spec.ingress_enabled());
+        assert!(spec.network_policy_enabled());
+
+        // Dropping the config marks the HPA, Ingress and NetworkPolicy as stale
+        spec.autoscaling = None;
+        spec.ingress = None;
+        spec.network_policy = None;
+        assert!(!spec.autoscaling_enabled());
+        assert!(!spec.ingress_enabled());
+        assert!(!spec.network_policy_enabled());
+    }
+
+    fn horizon_with_resource_metric(
+        target_type: TargetType,
+        target_value: &str,
+    ) -> StellarNodeSpec {
+        let mut spec = valid_horizon_spec();
+        spec.autoscaling = Some(AutoscalingConfig {
+            min_replicas: 2,
+            max_replicas: 10,
+            target_cpu_utilization_percentage: None,
+            custom_metrics: vec![],
+            behavior: None,
+            disable_on_weekend: false,
+            weekend_min_replicas: None,
+            weekend_timezone: None,
+            cluster_capacity_aware: false,
+            active_connections_metric: None,
+            max_connections_per_replica: None,
+            target_active_connections: None,
+            cron_overrides: vec![],
+            post_deploy_cooldown_seconds: None,
+            ingest_lag_metric: None,
+            max_ingest_lag_seconds: None,
+            enabled: true,
+            instance_weight_annotations: Default::default(),
+            instance_weight_label: "node.kubernetes.io/instance-type".to_string(),
+            custom_resource_metrics: vec![CustomResourceMetricSpec {
+                group: "queues.example.com/v1".to_string(),
+                resource: "IngestQueue".to_string(),
+                name: "queue_depth".to_string(),
+                namespace_selector: None,
+                target_type,
+                target_value: target_value.to_string(),
+            }],
+        });
+        spec
+    }
+
+    #[test]
+    fn test_custom_resource_metric_valid_quantities_pass() {
+        for value in ["100", "500m", "1.5Gi", "2k", "1e3"] {
+            let spec = horizon_with_resource_metric(TargetType::AverageValue, value);
+            assert!(spec.validate().is_ok(), "{value} should be accepted");
+        }
+        let spec = horizon_with_resource_metric(TargetType::Utilization, "80");
+        assert!(spec.validate().is_ok());
+    }
+
+    #[test]
+    fn test_custom_resource_metric_invalid_quantity_fails() {
+        for value in ["", "abc", "1.2.3", "10Kb"] {
+            let spec = horizon_with_resource_metric(TargetType::Value, value);
+            let errors = spec.validate().unwrap_err();
+            assert!(errors
+                .iter()
+                .any(|e| e.field == "spec.autoscaling.customResourceMetrics[0].targetValue"));
+        }
+    }
+
+    #[test]
+    fn test_custom_resource_metric_utilization_requires_integer() {
+        for value in ["80%", "0", "1.5", "500m"] {
+            let spec = horizon_with_resource_metric(TargetType::Utilization, value);
+            assert!(spec.validate().is_err(), "{value} should be rejected");
+        }
     }
 
     #[test]
@@ -362,6 +1503,21 @@ mod stellar_node_spec_validation {
             target_cpu_utilization_percentage: Some(80),
             custom_metrics: vec![],
             behavior: None,
+            disable_on_weekend: false,
+            weekend_min_replicas: None,
+            weekend_timezone: None,
+            cluster_capacity_aware: false,
+            active_connections_metric: None,
+            max_connections_per_replica: None,
+            target_active_connections: None,
+            cron_overrides: vec![],
+            post_deploy_cooldown_seconds: None,
+            ingest_lag_metric: None,
+            max_ingest_lag_seconds: None,
+            enabled: true,
+            instance_weight_annotations: Default::default(),
+            instance_weight_label: "node.kubernetes.io/instance-type".to_string(),
+            custom_resource_metrics: vec![],
         });
 
         let result = spec.validate();
@@ -385,6 +1541,21 @@ mod stellar_node_spec_validation {
             target_cpu_utilization_percentage: Some(80),
             custom_metrics: vec![],
             behavior: None,
+            disable_on_weekend: false,
+            weekend_min_replicas: None,
+            weekend_timezone: None,
+            cluster_capacity_aware: false,
+            active_connections_metric: None,
+            max_connections_per_replica: None,
+            target_active_connections: None,
+            cron_overrides: vec![],
+            post_deploy_cooldown_seconds: None,
+            ingest_lag_metric: None,
+            max_ingest_lag_seconds: None,
+            enabled: true,
+            instance_weight_annotations: Default::default(),
+            instance_weight_label: "node.kubernetes.io/instance-type".to_string(),
+            custom_resource_metrics: vec![],
         });
 
         let result = spec.validate();
@@ -399,6 +1570,191 @@ mod stellar_node_spec_validation {
         }));
     }
 
+    #[test]
+    fn test_horizon_ingest_lag_feedback_loop_validation() {
+        let mut spec = valid_horizon_spec();
+        spec.autoscaling = Some(AutoscalingConfig {
+            min_replicas: 2,
+            max_replicas: 6,
+            target_cpu_utilization_percentage: Some(70),
+            custom_metrics: vec![],
+            behavior: None,
+            disable_on_weekend: false,
+            weekend_min_replicas: None,
+            weekend_timezone: None,
+            cluster_capacity_aware: false,
+            active_connections_metric: None,
+            max_connections_per_replica: None,
+            target_active_connections: None,
+            cron_overrides: vec![],
+            post_deploy_cooldown_seconds: None,
+            ingest_lag_metric: Some("stellar_core_ingest_lag_seconds".to_string()),
+            max_ingest_lag_seconds: Some(30),
+            enabled: true,
+            instance_weight_annotations: Default::default(),
+            instance_weight_label: "node.kubernetes.io/instance-type".to_string(),
+            custom_resource_metrics: vec![],
+        });
+        assert!(spec.validate().is_ok());
+
+        spec.autoscaling.as_mut().unwrap().max_ingest_lag_seconds = None;
+        let errors = spec.validate().unwrap_err();
+        assert!(errors
+            .iter()
+            .any(|e| e.field == "spec.autoscaling.maxIngestLagSeconds"
+                && e.message.contains("set together")));
+
+        spec.autoscaling.as_mut().unwrap().max_ingest_lag_seconds = Some(0);
+        let errors = spec.validate().unwrap_err();
+        assert!(errors
+            .iter()
+            .any(|e| e.field == "spec.autoscaling.maxIngestLagSeconds"
+                && e.message.contains("greater than 0")));
+    }
+
+    #[test]
+    fn test_horizon_active_connections_validation() {
+        let mut spec = valid_horizon_spec();
+        spec.autoscaling = Some(
+            serde_json::from_value(serde_json::json!({
+                "minReplicas": 2,
+                "maxReplicas": 10,
+                "targetActiveConnections": 800,
+                "maxConnectionsPerReplica": 1000,
+            }))
+            .unwrap(),
+        );
+        assert!(spec.validate().is_ok());
+
+        spec.autoscaling.as_mut().unwrap().target_active_connections = Some(1200);
+        let errors = spec.validate().unwrap_err();
+        assert!(errors
+            .iter()
+            .any(|e| e.field == "spec.autoscaling.targetActiveConnections"
+                && e.message.contains("must not exceed")));
+
+        let autoscaling = spec.autoscaling.as_mut().unwrap();
+        autoscaling.target_active_connections = Some(800);
+        autoscaling.max_connections_per_replica = None;
+        let errors = spec.validate().unwrap_err();
+        assert!(errors
+            .iter()
+            .any(|e| e.field == "spec.autoscaling.maxConnectionsPerReplica"));
+    }
+
+    #[test]
+    fn test_horizon_cron_override_validation() {
+        let mut spec = valid_horizon_spec();
+        spec.autoscaling = Some(AutoscalingConfig {
+            min_replicas: 2,
+            max_replicas: 6,
+            target_cpu_utilization_percentage: Some(70),
+            custom_metrics: vec![],
+            behavior: None,
+            disable_on_weekend: false,
+            weekend_min_replicas: None,
+            weekend_timezone: None,
+            cluster_capacity_aware: false,
+            active_connections_metric: None,
+            max_connections_per_replica: None,
+            target_active_connections: None,
+            cron_overrides: vec![CronScalingOverride {
+                cron_expression: "0 14 * * 3".to_string(),
+                min_replicas: 6,
+                max_replicas: 20,
+                duration_minutes: 120,
+                timezone: "America/New_York".to_string(),
+            }],
+            post_deploy_cooldown_seconds: None,
+            ingest_lag_metric: None,
+            max_ingest_lag_seconds: None,
+            enabled: true,
+            instance_weight_annotations: Default::default(),
+            instance_weight_label: "node.kubernetes.io/instance-type".to_string(),
+            custom_resource_metrics: vec![],
+        });
+        assert!(spec.validate().is_ok());
+
+        let cron = &mut spec.autoscaling.as_mut().unwrap().cron_overrides[0];
+        cron.cron_expression = "every wednesday".to_string();
+        cron.timezone = "Mars/Olympus".to_string();
+        cron.max_replicas = 4;
+        cron.duration_minutes = 0;
+        let errors = spec.validate().unwrap_err();
+        for field in [
+            "cronExpression",
+            "timezone",
+            "maxReplicas",
+            "durationMinutes",
+        ] {
+            let field = format!("spec.autoscaling.cronOverrides[0].{field}");
+            assert!(errors.iter().any(|e| e.field == field), "missing {field}");
+        }
+    }
+
+    #[test]
+    fn test_cron_override_uses_standard_day_of_week() {
+        use chrono::{DateTime, Datelike, Utc, Weekday};
+
+        let weekdays = |expression: &str| -> Vec<Weekday> {
+            let cron = CronScalingOverride {
+                cron_expression: expression.to_string(),
+                min_replicas: 6,
+                max_replicas: 20,
+                duration_minutes: 120,
+                timezone: "UTC".to_string(),
+            };
+            // 2024-01-01 is a Monday
+            let monday: DateTime<Utc> = "2024-01-01T00:00:00Z".parse().unwrap();
+            cron.schedule()
+                .unwrap()
+                .after(&monday)
+                .take_while(|fire| *fire < monday + chrono::Duration::days(7))
+                .map(|fire| fire.weekday())
+                .collect()
+        };
+
+        assert_eq!(weekdays("0 14 * * 3"), [Weekday::Wed]);
+        assert_eq!(weekdays("0 14 * * 0"), [Weekday::Sun]);
+        assert_eq!(weekdays("0 14 * * 7"), [Weekday::Sun]);
+        assert_eq!(
+            weekdays("0 14 * * 5-7"),
+            [Weekday::Fri, Weekday::Sat, Weekday::Sun]
+        );
+        assert_eq!(
+            weekdays("0 14 * * 1,3/2"),
+            [Weekday::Mon, Weekday::Wed, Weekday::Fri, Weekday::Sun]
+        );
+        assert_eq!(weekdays("0 14 * * MON"), [Weekday::Mon]);
+    }
+
+    #[test]
+    fn test_horizon_weekend_scaling_validation() {
+        let mut spec = valid_horizon_spec();
+        spec.autoscaling = Some(
+            serde_json::from_value(serde_json::json!({
+                "minReplicas": 2,
+                "maxReplicas": 6,
+                "disableOnWeekend": true,
+                "weekendMinReplicas": 1,
+                "weekendTimezone": "Europe/Berlin",
+            }))
+            .unwrap(),
+        );
+        assert!(spec.validate().is_ok());
+
+        let autoscaling = spec.autoscaling.as_mut().unwrap();
+        autoscaling.weekend_min_replicas = Some(8);
+        autoscaling.weekend_timezone = Some("Mars/Olympus".to_string());
+        let errors = spec.validate().unwrap_err();
+        assert!(errors
+            .iter()
+            .any(|e| e.field == "spec.autoscaling.weekendMinReplicas"));
+        assert!(errors
+            .iter()
+            .any(|e| e.field == "spec.autoscaling.weekendTimezone"));
+    }
+
     #[test]
     fn test_horizon_valid_ingress_passes() {
         let mut spec = valid_horizon_spec();
@@ -625,6 +1981,21 @@ mod stellar_node_spec_validation {
             target_cpu_utilization_percentage: Some(70),
             custom_metrics: vec!["rpc_requests_per_second".to_string()],
             behavior: None,
+            disable_on_weekend: false,
+            weekend_min_replicas: None,
+            weekend_timezone: None,
+            cluster_capacity_aware: false,
+            active_connections_metric: None,
+            max_connections_per_replica: None,
+            target_active_connections: None,
+            cron_overrides: vec![],
+            post_deploy_cooldown_seconds: None,
+            ingest_lag_metric: None,
+            max_ingest_lag_seconds: None,
+            enabled: true,
+            instance_weight_annotations: Default::default(),
+            instance_weight_label: "node.kubernetes.io/instance-type".to_string(),
+            custom_resource_metrics: vec![],
         });
 
         assert!(spec.validate().is_ok());
@@ -639,6 +2010,21 @@ mod stellar_node_spec_validation {
             target_cpu_utilization_percentage: None,
             custom_metrics: vec![],
             behavior: None,
+            disable_on_weekend: false,
+            weekend_min_replicas: None,
+            weekend_timezone: None,
+            cluster_capacity_aware: false,
+            active_connections_metric: None,
+            max_connections_per_replica: None,
+            target_active_connections: None,
+            cron_overrides: vec![],
+            post_deploy_cooldown_seconds: None,
+            ingest_lag_metric: None,
+            max_ingest_lag_seconds: None,
+            enabled: true,
+            instance_weight_annotations: Default::default(),
+            instance_weight_label: "node.kubernetes.io/instance-type".to_string(),
+            custom_resource_metrics: vec![],
         });
 
         let result = spec.validate();
@@ -662,6 +2048,21 @@ mod stellar_node_spec_validation {
             target_cpu_utilization_percentage: Some(80),
             custom_metrics: vec![],
             behavior: None,
+            disable_on_weekend: false,
+            weekend_min_replicas: None,
+            weekend_timezone: None,
+            cluster_capacity_aware: false,
+            active_connections_metric: None,
+            max_connections_per_replica: None,
+            target_active_connections: None,
+            cron_overrides: vec![],
+            post_deploy_cooldown_seconds: None,
+            ingest_lag_metric: None,
+            max_ingest_lag_seconds: None,
+            enabled: true,
+            instance_weight_annotations: Default::default(),
+            instance_weight_label: "node.kubernetes.io/instance-type".to_string(),
+            custom_resource_metrics: vec![],
         });
 
         let result = spec.validate();
@@ -677,95 +2078,479 @@ mod stellar_node_spec_validation {
     }
 
     #[test]
-    fn test_soroban_valid_ingress_passes() {
-        let mut spec = valid_soroban_spec();
-        spec.ingress = Some(IngressConfig {
-            class_name: Some("nginx".to_string()),
-            hosts: vec![IngressHost {
-                host: "soroban.example.com".to_string(),
-                paths: vec![IngressPath {
-                    path: "/".to_string(),
-                    path_type: Some("Prefix".to_string()),
-                }],
-            }],
-            tls_secret_name: Some("soroban-tls".to_string()),
-            cert_manager_issuer: None,
-            cert_manager_cluster_issuer: Some("letsencrypt-prod".to_string()),
-            annotations: None,
+    fn test_soroban_valid_ingress_passes() {
+        let mut spec = valid_soroban_spec();
+        spec.ingress = Some(IngressConfig {
+            class_name: Some("nginx".to_string()),
+            hosts: vec![IngressHost {
+                host: "soroban.example.com".to_string(),
+                paths: vec![IngressPath {
+                    path: "/".to_string(),
+                    path_type: Some("Prefix".to_string()),
+                }],
+            }],
+            tls_secret_name: Some("soroban-tls".to_string()),
+            cert_manager_issuer: None,
+            cert_manager_cluster_issuer: Some("letsencrypt-prod".to_string()),
+            annotations: None,
+        });
+
+        assert!(spec.validate().is_ok());
+    }
+
+    // =========================================================================
+    // Network Variant Tests
+    // =========================================================================
+
+    #[test]
+    fn test_validator_mainnet_passes() {
+        let mut spec = valid_validator_spec();
+        spec.network = StellarNetwork::Mainnet;
+        assert!(spec.validate().is_ok());
+    }
+
+    #[test]
+    fn test_validator_futurenet_passes() {
+        let mut spec = valid_validator_spec();
+        spec.network = StellarNetwork::Futurenet;
+        assert!(spec.validate().is_ok());
+    }
+
+    #[test]
+    fn test_validator_custom_network_passes() {
+        let mut spec = valid_validator_spec();
+        spec.network = StellarNetwork::Custom("My Private Network".to_string());
+        assert!(spec.validate().is_ok());
+    }
+
+    // =========================================================================
+    // Edge Cases and Boundary Tests
+    // =========================================================================
+
+    #[test]
+    fn test_validator_exactly_one_replica_passes() {
+        let spec = valid_validator_spec();
+        assert_eq!(spec.replicas, 1);
+        assert!(spec.validate().is_ok());
+    }
+
+    #[test]
+    fn test_horizon_autoscaling_equal_min_max_passes() {
+        let mut spec = valid_horizon_spec();
+        spec.autoscaling = Some(AutoscalingConfig {
+            min_replicas: 3,
+            max_replicas: 3,
+            target_cpu_utilization_percentage: Some(80),
+            custom_metrics: vec![],
+            behavior: None,
+            disable_on_weekend: false,
+            weekend_min_replicas: None,
+            weekend_timezone: None,
+            cluster_capacity_aware: false,
+            active_connections_metric: None,
+            max_connections_per_replica: None,
+            target_active_connections: None,
+            cron_overrides: vec![],
+            post_deploy_cooldown_seconds: None,
+            ingest_lag_metric: None,
+            max_ingest_lag_seconds: None,
+            enabled: true,
+            instance_weight_annotations: Default::default(),
+            instance_weight_label: "node.kubernetes.io/instance-type".to_string(),
+            custom_resource_metrics: vec![],
+        });
+
+        assert!(spec.validate().is_ok());
+    }
+
+    #[test]
+    fn test_suspended_validator_passes() {
+        let mut spec = valid_validator_spec();
+        spec.suspended = true;
+        assert!(spec.validate().is_ok());
+    }
+
+    #[test]
+    fn test_validator_with_alerting_passes() {
+        let mut spec = valid_validator_spec();
+        spec.alerting = true;
+        assert!(spec.validate().is_ok());
+    }
+
+    #[test]
+    fn test_validator_in_maintenance_mode_passes() {
+        let mut spec = valid_validator_spec();
+        spec.maintenance_mode = true;
+        assert!(spec.validate().is_ok());
+    }
+
+    #[test]
+    fn test_validator_history_cache_validation() {
+        let mut spec = valid_validator_spec();
+        let cache = |size: &str| {
+            Some(HistoryCacheConfig {
+                volume_type: HistoryCacheVolumeType::PersistentVolumeClaim,
+                size: size.to_string(),
+                storage_class: Some("fast-ssd".to_string()),
+            })
+        };
+        spec.validator_config.as_mut().unwrap().history_cache = cache("20Gi");
+        assert!(spec.validate().is_ok());
+
+        for size in ["", "lots", "0Gi", "-5Gi"] {
+            spec.validator_config.as_mut().unwrap().history_cache = cache(size);
+            let errors = spec.validate().unwrap_err();
+            assert!(
+                errors
+                    .iter()
+                    .any(|e| e.field == "spec.validatorConfig.historyCache.size"),
+                "{size:?} accepted"
+            );
+        }
+
+        spec.validator_config.as_mut().unwrap().history_cache = Some(HistoryCacheConfig {
+            volume_type: HistoryCacheVolumeType::EmptyDir,
+            size: "10Gi".to_string(),
+            storage_class: Some("fast-ssd".to_string()),
+        });
+        let errors = spec.validate().unwrap_err();
+        assert!(errors
+            .iter()
+            .any(|e| e.field == "spec.validatorConfig.historyCache.storageClass"));
+    }
+
+    #[test]
+    fn test_validator_quorum_slice_watcher_validation() {
+        let mut spec = valid_validator_spec();
+        spec.validator_config.as_mut().unwrap().quorum_slice_watcher =
+            Some(QuorumSliceWatcherConfig {
+                enabled: true,
+                check_interval_seconds: 60,
+                alert_on_missing_validators: vec![format!("G{}", "A".repeat(55))],
+            });
+        assert!(spec.validate().is_ok());
+
+        spec.validator_config.as_mut().unwrap().quorum_slice_watcher =
+            Some(QuorumSliceWatcherConfig {
+                enabled: true,
+                check_interval_seconds: 0,
+                alert_on_missing_validators: vec!["sdf_1".to_string()],
+            });
+        let errors = spec.validate().unwrap_err();
+        assert!(errors
+            .iter()
+            .any(|e| e.field == "spec.validatorConfig.quorumSliceWatcher.checkIntervalSeconds"));
+        assert!(errors
+            .iter()
+            .any(|e| e.field
+                == "spec.validatorConfig.quorumSliceWatcher.alertOnMissingValidators[0]"));
+
+        spec.validator_config.as_mut().unwrap().quorum_slice_watcher =
+            Some(QuorumSliceWatcherConfig {
+                enabled: true,
+                check_interval_seconds: 60,
+                alert_on_missing_validators: vec![],
+            });
+        let errors = spec.validate().unwrap_err();
+        assert!(
+            errors
+                .iter()
+                .any(|e| e.field
+                    == "spec.validatorConfig.quorumSliceWatcher.alertOnMissingValidators")
+        );
+    }
+
+    #[test]
+    fn test_validator_stellar_beat_reporting_passes() {
+        let mut spec = valid_validator_spec();
+        spec.validator_config
+            .as_mut()
+            .unwrap()
+            .stellar_beat_reporting = Some(StellarBeatConfig {
+            home_domain: "validator.example.com".to_string(),
+            public_key: format!("G{}", "A".repeat(55)),
+            api_url: "https://api.stellarbeat.io".to_string(),
+            image: "stellar/stellarbeat-reporter:latest".to_string(),
+            poll_interval_secs: 300,
+        });
+        assert!(spec.validate().is_ok());
+    }
+
+    #[test]
+    fn test_validator_stellar_beat_invalid_public_key_fails() {
+        let mut spec = valid_validator_spec();
+        spec.validator_config
+            .as_mut()
+            .unwrap()
+            .stellar_beat_reporting = Some(StellarBeatConfig {
+            home_domain: "validator.example.com".to_string(),
+            public_key: "SBADKEY".to_string(),
+            api_url: "https://api.stellarbeat.io".to_string(),
+            image: "stellar/stellarbeat-reporter:latest".to_string(),
+            poll_interval_secs: 300,
+        });
+        let errors = spec.validate().unwrap_err();
+        assert!(errors
+            .iter()
+            .any(|e| e.field == "spec.validatorConfig.stellarBeatReporting.publicKey"));
+    }
+
+    #[test]
+    fn test_validator_archive_publication_s3_and_azure_fails() {
+        let mut spec = valid_validator_spec();
+        spec.validator_config
+            .as_mut()
+            .unwrap()
+            .archive_publication_credentials = Some(HistoryArchivePublishConfig {
+            s3_bucket: Some("stellar-history".to_string()),
+            azure_blob: Some(AzureBlobConfig {
+                account_name: "stellarhistory".to_string(),
+                container_name: "archive".to_string(),
+                sas_token_secret: "azure-sas".to_string(),
+                endpoint_suffix: None,
+            }),
+        });
+
+        let errors = spec.validate().unwrap_err();
+        assert!(errors
+            .iter()
+            .any(|e| e.message == "s3Bucket and azureBlob are mutually exclusive"));
+    }
+
+    #[test]
+    fn test_validator_archive_publication_azure_passes() {
+        let mut spec = valid_validator_spec();
+        spec.validator_config
+            .as_mut()
+            .unwrap()
+            .archive_publication_credentials = Some(HistoryArchivePublishConfig {
+            s3_bucket: None,
+            azure_blob: Some(AzureBlobConfig {
+                account_name: "stellarhistory".to_string(),
+                container_name: "archive".to_string(),
+                sas_token_secret: "azure-sas".to_string(),
+                endpoint_suffix: Some("core.usgovcloudapi.net".to_string()),
+            }),
+        });
+
+        assert!(spec.validate().is_ok());
+    }
+
+    fn history_gc(schedule: &str) -> HistoryGcConfig {
+        HistoryGcConfig {
+            enabled: true,
+            retention_count: 1000,
+            gc_schedule: schedule.to_string(),
+            dry_run_first: true,
+            gc_implementation: GcImplementation::CronJob,
+            image: "stellar/stellar-archivist:latest".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_validator_history_gc_passes() {
+        let mut spec = valid_validator_spec();
+        let validator = spec.validator_config.as_mut().unwrap();
+        validator.archive_publication_credentials = Some(HistoryArchivePublishConfig {
+            s3_bucket: Some("stellar-history".to_string()),
+            azure_blob: None,
+        });
+        validator.history_gc = Some(history_gc("0 3 * * *"));
+
+        assert!(spec.validate().is_ok());
+    }
+
+    #[test]
+    fn test_validator_history_gc_schedule_uses_cronjob_day_of_week() {
+        use chrono::{DateTime, Datelike, Utc, Weekday};
+
+        let mut spec = valid_validator_spec();
+        let validator = spec.validator_config.as_mut().unwrap();
+        validator.archive_publication_credentials = Some(HistoryArchivePublishConfig {
+            s3_bucket: Some("stellar-history".to_string()),
+            azure_blob: None,
         });
 
-        assert!(spec.validate().is_ok());
+        // 2024-01-01 is a Monday
+        let monday: DateTime<Utc> = "2024-01-01T00:00:00Z".parse().unwrap();
+        for (schedule, weekday) in [
+            ("0 3 * * 0", Weekday::Sun),
+            ("0 3 * * 7", Weekday::Sun),
+            ("0 3 * * 6", Weekday::Sat),
+            ("0 3 * * 2", Weekday::Tue),
+        ] {
+            let gc = history_gc(schedule);
+            let next = gc.schedule().unwrap().after(&monday).next().unwrap();
+            assert_eq!(next.weekday(), weekday, "{schedule}");
+
+            spec.validator_config.as_mut().unwrap().history_gc = Some(gc);
+            assert!(spec.validate().is_ok(), "{schedule}");
+        }
     }
 
-    // =========================================================================
-    // Network Variant Tests
-    // =========================================================================
+    #[test]
+    fn test_validator_history_gc_requires_archive_and_valid_schedule() {
+        let mut spec = valid_validator_spec();
+        let mut gc = history_gc("0 0 3 * * *");
+        gc.retention_count = 0;
+        spec.validator_config.as_mut().unwrap().history_gc = Some(gc);
+
+        let errors = spec.validate().unwrap_err();
+        for field in ["", ".retentionCount", ".gcSchedule"] {
+            let field = format!("spec.validatorConfig.historyGCPolicy{field}");
+            assert!(errors.iter().any(|e| e.field == field), "missing {field}");
+        }
+    }
 
     #[test]
-    fn test_validator_mainnet_passes() {
+    fn test_validator_history_gc_disabled_is_not_validated() {
         let mut spec = valid_validator_spec();
-        spec.network = StellarNetwork::Mainnet;
+        let mut gc = history_gc("not a schedule");
+        gc.enabled = false;
+        spec.validator_config.as_mut().unwrap().history_gc = Some(gc);
+
         assert!(spec.validate().is_ok());
     }
 
     #[test]
-    fn test_validator_futurenet_passes() {
+    fn test_extra_volume_mount_with_sub_path_passes() {
         let mut spec = valid_validator_spec();
-        spec.network = StellarNetwork::Futurenet;
+        spec.extra_volume_mounts = vec![ExtraVolumeMount {
+            name: "config".to_string(),
+            mount_path: "/etc/stellar/stellar-core.cfg".to_string(),
+            sub_path: Some("stellar-core.cfg".to_string()),
+            read_only: true,
+        }];
         assert!(spec.validate().is_ok());
     }
 
     #[test]
-    fn test_validator_custom_network_passes() {
+    fn test_extra_volume_mount_invalid_paths_fail() {
         let mut spec = valid_validator_spec();
-        spec.network = StellarNetwork::Custom("My Private Network".to_string());
-        assert!(spec.validate().is_ok());
+        spec.extra_volume_mounts = vec![
+            ExtraVolumeMount {
+                name: "config".to_string(),
+                mount_path: "relative/path".to_string(),
+                sub_path: None,
+                read_only: true,
+            },
+            ExtraVolumeMount {
+                name: "data".to_string(),
+                mount_path: "/mnt/data".to_string(),
+                sub_path: Some("../escape".to_string()),
+                read_only: false,
+            },
+        ];
+        let errors = spec.validate().unwrap_err();
+        assert!(errors
+            .iter()
+            .any(|e| e.field == "spec.extraVolumeMounts[].mountPath"));
+        assert!(errors
+            .iter()
+            .any(|e| e.field == "spec.extraVolumeMounts[].subPath"));
     }
 
-    // =========================================================================
-    // Edge Cases and Boundary Tests
-    // =========================================================================
+    #[test]
+    fn test_extra_volume_mount_duplicate_mount_path_fails() {
+        let mut spec = valid_validator_spec();
+        let mount = ExtraVolumeMount {
+            name: "config".to_string(),
+            mount_path: "/etc/stellar/stellar-core.cfg".to_string(),
+            sub_path: Some("stellar-core.cfg".to_string()),
+            read_only: true,
+        };
+        spec.extra_volume_mounts = vec![mount.clone(), mount];
+        let errors = spec.validate().unwrap_err();
+        assert!(errors
+            .iter()
+            .any(|e| e.message.contains("used more than once")));
+    }
+
+    // -------------------------------------------------------------------------
+    // Image override tests
+    // -------------------------------------------------------------------------
 
     #[test]
-    fn test_validator_exactly_one_replica_passes() {
+    fn test_container_image_defaults_to_version() {
         let spec = valid_validator_spec();
-        assert_eq!(spec.replicas, 1);
-        assert!(spec.validate().is_ok());
+        assert_eq!(spec.container_image(), "stellar/stellar-core:v21.0.0");
     }
 
     #[test]
-    fn test_horizon_autoscaling_equal_min_max_passes() {
+    fn test_container_image_digest_takes_priority_over_tag() {
         let mut spec = valid_horizon_spec();
-        spec.autoscaling = Some(AutoscalingConfig {
-            min_replicas: 3,
-            max_replicas: 3,
-            target_cpu_utilization_percentage: Some(80),
-            custom_metrics: vec![],
-            behavior: None,
+        spec.image_override = Some(ImageOverrideConfig {
+            repository: Some("registry.example.com/horizon".to_string()),
+            tag: Some("v22.0.0".to_string()),
+            digest: Some("sha256:abcdef".to_string()),
+            pull_policy: None,
         });
+        assert_eq!(
+            spec.container_image(),
+            "registry.example.com/horizon@sha256:abcdef"
+        );
+    }
 
-        assert!(spec.validate().is_ok());
+    #[test]
+    fn test_container_image_tag_overrides_version() {
+        let mut spec = valid_horizon_spec();
+        spec.image_override = Some(ImageOverrideConfig {
+            tag: Some("v22.1.0".to_string()),
+            ..Default::default()
+        });
+        assert_eq!(spec.container_image(), "stellar/horizon:v22.1.0");
     }
 
     #[test]
-    fn test_suspended_validator_passes() {
+    fn test_image_override_invalid_pull_policy_fails() {
+        let mut spec = valid_horizon_spec();
+        spec.image_override = Some(ImageOverrideConfig {
+            pull_policy: Some("Sometimes".to_string()),
+            ..Default::default()
+        });
+        let errors = spec.validate().unwrap_err();
+        assert!(errors
+            .iter()
+            .any(|e| e.field == "spec.imageOverride.pullPolicy"));
+    }
+
+    #[test]
+    fn test_image_version_constraint_satisfied_passes() {
         let mut spec = valid_validator_spec();
-        spec.suspended = true;
+        spec.image_version_constraint = Some(">=21.0.0, <23.0.0".to_string());
         assert!(spec.validate().is_ok());
     }
 
     #[test]
-    fn test_validator_with_alerting_passes() {
+    fn test_image_version_constraint_rejects_old_version() {
         let mut spec = valid_validator_spec();
-        spec.alerting = true;
-        assert!(spec.validate().is_ok());
+        spec.version = "v20.4.0".to_string();
+        spec.image_version_constraint = Some(">=21.0.0".to_string());
+        let errors = spec.validate().unwrap_err();
+        assert!(errors.iter().any(|e| e.field == "spec.version"));
     }
 
     #[test]
-    fn test_validator_in_maintenance_mode_passes() {
+    fn test_image_version_constraint_rejects_tag_outside_range() {
+        let mut spec = valid_horizon_spec();
+        spec.image_version_constraint = Some(">=21.0.0, <22.0.0".to_string());
+        spec.image_override = Some(ImageOverrideConfig {
+            tag: Some("v22".to_string()),
+            ..Default::default()
+        });
+        let errors = spec.validate().unwrap_err();
+        assert!(errors.iter().any(|e| e.field == "spec.imageOverride.tag"));
+        assert!(!errors.iter().any(|e| e.field == "spec.version"));
+    }
+
+    #[test]
+    fn test_image_version_constraint_invalid_range_fails() {
         let mut spec = valid_validator_spec();
-        spec.maintenance_mode = true;
-        assert!(spec.validate().is_ok());
+        spec.image_version_constraint = Some("not a range".to_string());
+        let errors = spec.validate().unwrap_err();
+        assert!(errors
+            .iter()
+            .any(|e| e.field == "spec.imageVersionConstraint"));
     }
 
     #[test]
@@ -900,6 +2685,515 @@ mod stellar_node_spec_validation {
         assert!(spec.validate().is_ok());
     }
 
+    fn soroban_with_fees(network: StellarNetwork, fees: SorobanFeeConfig) -> StellarNodeSpec {
+        let mut spec = valid_soroban_spec();
+        spec.network = network;
+        spec.soroban_config.as_mut().unwrap().fee_config = Some(fees);
+        spec
+    }
+
+    #[test]
+    fn test_soroban_fee_config_allowed_on_test_networks() {
+        let fees = SorobanFeeConfig {
+            fee_per_instruction: Some(25),
+            fee_per_write_byte: Some(1000),
+            ..Default::default()
+        };
+        for network in [StellarNetwork::Testnet, StellarNetwork::Futurenet] {
+            let spec = soroban_with_fees(network, fees.clone());
+            assert!(spec.validate().is_ok());
+            assert!(spec.validation_warnings().is_empty());
+        }
+    }
+
+    #[test]
+    fn test_soroban_tx_queue_settings_must_be_positive() {
+        let mut spec = valid_soroban_spec();
+        let soroban = spec.soroban_config.as_mut().unwrap();
+        soroban.tx_queue_size = Some(0);
+        soroban.tx_concurrency = Some(0);
+
+        let errors = spec.validate().unwrap_err();
+        assert!(errors
+            .iter()
+            .any(|e| e.field == "spec.sorobanConfig.txQueueSize"));
+        assert!(errors
+            .iter()
+            .any(|e| e.field == "spec.sorobanConfig.txConcurrency"));
+
+        let soroban = spec.soroban_config.as_mut().unwrap();
+        soroban.tx_queue_size = Some(1000);
+        soroban.tx_concurrency = Some(8);
+        assert!(spec.validate().is_ok());
+    }
+
+    #[test]
+    fn test_soroban_contract_cache_minimums() {
+        let mut spec = valid_soroban_spec();
+        let soroban = spec.soroban_config.as_mut().unwrap();
+        soroban.contract_code_cache_size = Some(99);
+        soroban.contract_code_cache_ttl_seconds = Some(59);
+        soroban.contract_instance_cache_size = Some(0);
+
+        let errors = spec.validate().unwrap_err();
+        for field in [
+            "contractCodeCacheSize",
+            "contractCodeCacheTtlSeconds",
+            "contractInstanceCacheSize",
+        ] {
+            let field = format!("spec.sorobanConfig.{field}");
+            assert!(errors.iter().any(|e| e.field == field), "missing {field}");
+        }
+
+        let soroban = spec.soroban_config.as_mut().unwrap();
+        soroban.contract_code_cache_size = Some(100);
+        soroban.contract_code_cache_ttl_seconds = Some(60);
+        soroban.contract_instance_cache_size = Some(500);
+        assert!(spec.validate().is_ok());
+    }
+
+    #[test]
+    fn test_soroban_rpc_endpoint_validation() {
+        for valid in ["/", "/soroban/rpc"] {
+            let mut spec = valid_soroban_spec();
+            spec.soroban_config.as_mut().unwrap().rpc_endpoint = Some(valid.to_string());
+            assert!(spec.validate().is_ok(), "{valid}");
+        }
+        for invalid in ["soroban/rpc", "/soroban/rpc/", ""] {
+            let mut spec = valid_soroban_spec();
+            spec.soroban_config.as_mut().unwrap().rpc_endpoint = Some(invalid.to_string());
+            let errors = spec.validate().unwrap_err();
+            assert!(
+                errors
+                    .iter()
+                    .any(|e| e.field == "spec.sorobanConfig.rpcEndpoint"),
+                "{invalid}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_soroban_advertised_endpoint() {
+        let mut spec = valid_soroban_spec();
+        assert_eq!(
+            spec.advertised_endpoint("rpc", "stellar").as_deref(),
+            Some("http://rpc.stellar.svc.cluster.local:8000/")
+        );
+
+        let soroban = spec.soroban_config.as_mut().unwrap();
+        soroban.rpc_endpoint = Some("/soroban/rpc".to_string());
+        assert_eq!(
+            spec.advertised_endpoint("rpc", "stellar").as_deref(),
+            Some("http://rpc.stellar.svc.cluster.local:8000/soroban/rpc")
+        );
+
+        spec.soroban_config.as_mut().unwrap().friendly_rpc_url =
+            Some("https://example.org/soroban/rpc".to_string());
+        assert_eq!(
+            spec.advertised_endpoint("rpc", "stellar").as_deref(),
+            Some("https://example.org/soroban/rpc")
+        );
+
+        assert_eq!(
+            valid_horizon_spec().advertised_endpoint("h", "stellar"),
+            None
+        );
+    }
+
+    #[test]
+    fn test_soroban_fee_config_rejected_on_mainnet() {
+        let spec = soroban_with_fees(StellarNetwork::Mainnet, SorobanFeeConfig::default());
+        let errors = spec.validate().unwrap_err();
+        assert!(errors
+            .iter()
+            .any(|e| e.field == "spec.sorobanConfig.feeConfig"));
+    }
+
+    #[test]
+    fn test_soroban_zero_fee_warns_but_validates() {
+        let spec = soroban_with_fees(
+            StellarNetwork::Testnet,
+            SorobanFeeConfig {
+                fee_per_read_entry: Some(0),
+                fee_per_write_entry: Some(10),
+                ..Default::default()
+            },
+        );
+        assert!(spec.validate().is_ok());
+
+        let warnings = spec.validation_warnings();
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(
+            warnings[0].field,
+            "spec.sorobanConfig.feeConfig.feePerReadEntry"
+        );
+    }
+
+    #[test]
+    fn test_grafana_dashboard_requires_namespace_and_folder() {
+        let mut spec = valid_horizon_spec();
+        spec.grafana_dashboard = Some(GrafanaDashboardConfig::default());
+        let errors = spec.validate().unwrap_err();
+        assert!(errors
+            .iter()
+            .any(|e| e.field == "spec.grafanaDashboard.namespace"));
+        assert!(errors
+            .iter()
+            .any(|e| e.field == "spec.grafanaDashboard.folder"));
+
+        spec.grafana_dashboard = Some(GrafanaDashboardConfig {
+            namespace: "monitoring".to_string(),
+            folder: "Stellar".to_string(),
+            ..Default::default()
+        });
+        assert!(spec.validate().is_ok());
+    }
+
+    #[test]
+    fn test_validator_quorum_group_must_be_label_value() {
+        let mut spec = valid_validator_spec();
+        for group in ["", "-sdf", "sdf group", &"a".repeat(64)] {
+            spec.validator_config.as_mut().unwrap().quorum_group = Some(group.to_string());
+            let errors = spec.validate().unwrap_err();
+            assert!(errors
+                .iter()
+                .any(|e| e.field == "spec.validatorConfig.quorumGroup"));
+        }
+
+        spec.validator_config.as_mut().unwrap().quorum_group = Some("sdf.tier-1".to_string());
+        assert!(spec.validate().is_ok());
+    }
+
+    #[test]
+    fn test_minimum_protocol_version_needs_url_on_custom_network() {
+        let mut spec = valid_validator_spec();
+        spec.validator_config
+            .as_mut()
+            .unwrap()
+            .minimum_protocol_version = Some(21);
+        assert!(spec.validate().is_ok());
+
+        spec.network = StellarNetwork::Custom("Private Network ; 2024".to_string());
+        let errors = spec.validate().unwrap_err();
+        assert!(errors
+            .iter()
+            .any(|e| e.field == "spec.validatorConfig.protocolVersionUrl"));
+
+        spec.validator_config.as_mut().unwrap().protocol_version_url =
+            Some("horizon.internal".to_string());
+        assert!(spec.validate().is_err());
+
+        spec.validator_config.as_mut().unwrap().protocol_version_url =
+            Some("https://horizon.internal".to_string());
+        assert!(spec.validate().is_ok());
+    }
+
+    #[test]
+    fn test_soroban_cloud_archival_requires_bucket() {
+        let mut spec = valid_soroban_spec();
+        spec.soroban_config.as_mut().unwrap().archival_storage = Some(ArchivalStorageConfig {
+            backend: ArchivalBackend::S3,
+            ..Default::default()
+        });
+        let errors = spec.validate().unwrap_err();
+        assert!(errors
+            .iter()
+            .any(|e| e.field == "spec.sorobanConfig.archivalStorage.bucketName"));
+
+        spec.soroban_config.as_mut().unwrap().archival_storage = Some(ArchivalStorageConfig {
+            backend: ArchivalBackend::Disk,
+            ..Default::default()
+        });
+        assert!(spec.validate().is_ok());
+    }
+
+    #[test]
+    fn test_termination_message_path_must_be_absolute() {
+        let mut spec = valid_horizon_spec();
+        spec.termination_message_policy = Some(TerminationMessagePolicy::FallbackToLogsOnError);
+        spec.termination_message_path = Some("/dev/termination-log".to_string());
+        assert!(spec.validate().is_ok());
+
+        spec.termination_message_path = Some("termination-log".to_string());
+        let errors = spec.validate().unwrap_err();
+        assert!(errors
+            .iter()
+            .any(|e| e.field == "spec.terminationMessagePath"));
+    }
+
+    #[test]
+    fn test_emit_oom_event_on_kill_defaults_to_true() {
+        let spec: StellarNodeSpec = serde_json::from_value(serde_json::json!({
+            "nodeType": "Validator",
+            "network": "Testnet",
+            "version": "v21.0.0",
+            "validatorConfig": { "seedSecretRef": "validator-seed" },
+        }))
+        .unwrap();
+        assert!(spec.emit_oom_event_on_kill);
+    }
+
+    #[test]
+    fn test_config_template_syntax_error_rejected() {
+        let mut spec = valid_horizon_spec();
+        spec.config_template_mode = ConfigTemplateMode::GoTemplate;
+        assert!(spec.validate().is_ok());
+
+        spec.horizon_config.as_mut().unwrap().stellar_core_url =
+            "http://{{ .node.name -core:11626".to_string();
+        let errors = spec.validate().unwrap_err();
+        assert!(errors.iter().any(|e| e.field == "spec.configTemplateMode"));
+
+        spec.config_template_mode = ConfigTemplateMode::Static;
+        assert!(spec.validate().is_ok());
+    }
+
+    #[test]
+    fn test_precheck_hook_validation() {
+        let mut spec = valid_horizon_spec();
+        spec.precheck_hook = Some(PrecheckHookConfig {
+            image: " ".to_string(),
+            command: vec![],
+            timeout_seconds: 0,
+            failure_policy: FailurePolicy::Abort,
+        });
+        let errors = spec.validate().unwrap_err();
+        assert!(errors.iter().any(|e| e.field == "spec.precheckHook.image"));
+        assert!(errors
+            .iter()
+            .any(|e| e.field == "spec.precheckHook.command"));
+        assert!(errors
+            .iter()
+            .any(|e| e.field == "spec.precheckHook.timeoutSeconds"));
+
+        spec.precheck_hook = Some(PrecheckHookConfig {
+            image: "example/schema-check:1.0".to_string(),
+            command: vec!["/bin/check".to_string()],
+            timeout_seconds: 300,
+            failure_policy: FailurePolicy::Warn,
+        });
+        assert!(spec.validate().is_ok());
+    }
+
+    #[test]
+    fn test_precheck_hook_defaults() {
+        let hook: PrecheckHookConfig = serde_json::from_value(serde_json::json!({
+            "image": "example/schema-check:1.0",
+            "command": ["/bin/check"],
+        }))
+        .unwrap();
+        assert_eq!(hook.timeout_seconds, 300);
+        assert_eq!(hook.failure_policy, FailurePolicy::Abort);
+    }
+
+    #[test]
+    fn test_privileged_capabilities_require_dev_mode() {
+        let mut spec = valid_horizon_spec();
+        spec.container_security_context = Some(ContainerSecurityContextConfig {
+            capabilities_add: vec![LinuxCapability::SysAdmin, LinuxCapability::NetAdmin],
+            capabilities_drop: vec![LinuxCapability::All],
+        });
+        let errors = spec.validate().unwrap_err();
+        assert_eq!(
+            errors
+                .iter()
+                .filter(|e| e.field == "spec.containerSecurityContext.capabilitiesAdd")
+                .count(),
+            2
+        );
+
+        spec.dev_mode = true;
+        assert!(spec.validate().is_ok());
+    }
+
+    #[test]
+    fn test_unprivileged_capabilities_allowed() {
+        let mut spec = valid_horizon_spec();
+        spec.container_security_context = Some(ContainerSecurityContextConfig {
+            capabilities_add: vec![LinuxCapability::NetBindService],
+            capabilities_drop: vec![LinuxCapability::All],
+        });
+        assert!(spec.validate().is_ok());
+    }
+
+    #[test]
+    fn test_linux_capability_serializes_as_kernel_name() {
+        assert_eq!(
+            serde_json::to_value(LinuxCapability::NetBindService).unwrap(),
+            serde_json::json!("NET_BIND_SERVICE")
+        );
+        let cap: LinuxCapability = serde_json::from_value(serde_json::json!("ALL")).unwrap();
+        assert_eq!(cap, LinuxCapability::All);
+    }
+
+    #[test]
+    fn test_post_start_hook_requires_exactly_one_action() {
+        let mut spec = valid_horizon_spec();
+        spec.lifecycle = Some(LifecycleConfig {
+            post_start: Some(LifecycleHookSpec::default()),
+        });
+        let errors = spec.validate().unwrap_err();
+        assert!(errors.iter().any(|e| e.field == "spec.lifecycle.postStart"));
+
+        spec.lifecycle = Some(LifecycleConfig {
+            post_start: Some(LifecycleHookSpec {
+                exec: Some(ExecHookSpec {
+                    command: vec!["/bin/sh".to_string(), "-c".to_string(), "true".to_string()],
+                }),
+                http_get: None,
+            }),
+        });
+        assert!(spec.validate().is_ok());
+    }
+
+    #[test]
+    fn test_post_start_hook_validates_action_fields() {
+        let mut spec = valid_horizon_spec();
+        spec.lifecycle = Some(LifecycleConfig {
+            post_start: Some(LifecycleHookSpec {
+                exec: None,
+                http_get: Some(HttpGetHookSpec {
+                    path: "register".to_string(),
+                    port: 0,
+                    host: None,
+                    scheme: Some("ftp".to_string()),
+                }),
+            }),
+        });
+        let errors = spec.validate().unwrap_err();
+        for field in [
+            "spec.lifecycle.postStart.httpGet.path",
+            "spec.lifecycle.postStart.httpGet.port",
+            "spec.lifecycle.postStart.httpGet.scheme",
+        ] {
+            assert!(errors.iter().any(|e| e.field == field), "missing {field}");
+        }
+
+        spec.lifecycle = Some(LifecycleConfig {
+            post_start: Some(LifecycleHookSpec {
+                exec: Some(ExecHookSpec { command: vec![] }),
+                http_get: None,
+            }),
+        });
+        let errors = spec.validate().unwrap_err();
+        assert!(errors
+            .iter()
+            .any(|e| e.field == "spec.lifecycle.postStart.exec.command"));
+    }
+
+    #[test]
+    fn test_hooks_require_http_urls_and_bounded_timeout() {
+        let mut spec = valid_soroban_spec();
+        spec.hooks = Some(ReconcileHooksConfig {
+            pre_apply_url: Some("https://automation.example.com/pre".to_string()),
+            post_apply_url: Some("http://cmdb.internal/post".to_string()),
+            timeout_seconds: 5,
+        });
+        assert!(spec.validate().is_ok());
+
+        spec.hooks = Some(ReconcileHooksConfig {
+            pre_apply_url: Some("automation.example.com/pre".to_string()),
+            post_apply_url: None,
+            timeout_seconds: 60,
+        });
+        let errors = spec.validate().unwrap_err();
+        let fields: Vec<_> = errors.iter().map(|e| e.field.as_str()).collect();
+        assert!(fields.contains(&"spec.hooks.preApplyUrl"));
+        assert!(fields.contains(&"spec.hooks.timeoutSeconds"));
+    }
+
+    #[test]
+    fn test_prometheus_rules_supersede_alerting() {
+        let mut spec = valid_horizon_spec();
+        spec.alerting = true;
+        assert!(spec.alerting_config_map_enabled());
+        assert!(!spec.prometheus_rule_enabled());
+        assert!(spec.validation_warnings().is_empty());
+
+        spec.prometheus_rules = Some(PrometheusRulesConfig::default());
+        assert!(spec.prometheus_rule_enabled());
+        assert!(!spec.alerting_config_map_enabled());
+        assert!(spec
+            .validation_warnings()
+            .iter()
+            .any(|w| w.field == "spec.alerting"));
+    }
+
+    #[test]
+    fn test_extra_alert_rules_validation() {
+        let mut spec = valid_horizon_spec();
+        spec.prometheus_rules = Some(PrometheusRulesConfig {
+            enable_default_rules: true,
+            extra_rules: vec![AlertRule {
+                alert: "HorizonSlowIngestion".to_string(),
+                expr: "stellar_node_ingestion_lag > 50".to_string(),
+                for_duration: Some("1h30m".to_string()),
+                ..Default::default()
+            }],
+        });
+        assert!(spec.validate().is_ok());
+
+        spec.prometheus_rules = Some(PrometheusRulesConfig {
+            enable_default_rules: false,
+            extra_rules: vec![AlertRule {
+                alert: String::new(),
+                expr: " ".to_string(),
+                for_duration: Some("5 minutes".to_string()),
+                ..Default::default()
+            }],
+        });
+        let errors = spec.validate().unwrap_err();
+        let fields: Vec<_> = errors.iter().map(|e| e.field.as_str()).collect();
+        assert!(fields.contains(&"spec.prometheusRules.extraRules[0].alert"));
+        assert!(fields.contains(&"spec.prometheusRules.extraRules[0].expr"));
+        assert!(fields.contains(&"spec.prometheusRules.extraRules[0].for"));
+    }
+
+    #[test]
+    fn test_monitoring_scrape_settings_validation() {
+        let mut spec = valid_validator_spec();
+        spec.monitoring = Some(MonitoringConfig {
+            enabled: true,
+            scrape_interval: Some("2m".to_string()),
+            scrape_timeout: Some("45s".to_string()),
+        });
+        assert!(spec.validate().is_ok());
+
+        spec.monitoring = Some(MonitoringConfig {
+            enabled: true,
+            scrape_interval: Some("30s".to_string()),
+            scrape_timeout: Some("1m".to_string()),
+        });
+        let errors = spec.validate().unwrap_err();
+        assert!(errors
+            .iter()
+            .any(|e| e.field == "spec.monitoring.scrapeTimeout"
+                && e.message.contains("shorter than scrapeInterval")));
+
+        spec.monitoring = Some(MonitoringConfig {
+            enabled: true,
+            scrape_interval: Some("two minutes".to_string()),
+            scrape_timeout: Some("0s".to_string()),
+        });
+        let errors = spec.validate().unwrap_err();
+        let fields: Vec<_> = errors.iter().map(|e| e.field.as_str()).collect();
+        assert!(fields.contains(&"spec.monitoring.scrapeInterval"));
+        assert!(fields.contains(&"spec.monitoring.scrapeTimeout"));
+    }
+
+    #[test]
+    fn test_requeue_jitter_percent_validation() {
+        let mut spec = valid_horizon_spec();
+        spec.requeue_jitter_percent = Some(50);
+        assert!(spec.validate().is_ok());
+
+        spec.requeue_jitter_percent = Some(75);
+        let errors = spec.validate().unwrap_err();
+        assert!(errors
+            .iter()
+            .any(|e| e.field == "spec.requeueJitterPercent"));
+    }
+
     #[test]
     fn test_soroban_config_serialization_roundtrip() {
         use crate::crd::{CaptiveCoreConfig, SorobanConfig};
@@ -921,6 +3215,21 @@ mod stellar_node_spec_validation {
             }),
             enable_preflight: true,
             max_events_per_request: 10000,
+            contract_code_cache_size: None,
+            contract_code_cache_ttl_seconds: None,
+            contract_instance_cache_size: None,
+            debug_rpc_max_body_log_bytes: None,
+            debug_rpc: false,
+            persistence_backend: Default::default(),
+            database_secret_ref: None,
+            run_migration_at_startup: false,
+            archival_storage: None,
+            tx_concurrency: None,
+            rpc_endpoint: None,
+            friendly_rpc_url: None,
+            tx_queue_size: None,
+            fee_config: None,
+            stellar_core_ref: None,
         };
 
         // Test JSON serialization
@@ -943,3 +3252,61 @@ mod stellar_node_spec_validation {
         assert!(deserialized_yaml.captive_core_structured_config.is_some());
     }
 }
+
+#[cfg(test)]
+mod crd_versions {
+    use crate::crd::{stellar_node_crd, SERVED_VERSIONS, STORAGE_VERSION};
+
+    #[test]
+    fn test_all_versions_served_with_one_storage_version() {
+        let crd = stellar_node_crd();
+        let versions: Vec<_> = crd
+            .spec
+            .versions
+            .iter()
+            .map(|v| (v.name.as_str(), v.served, v.storage))
+            .collect();
+        assert_eq!(
+            versions,
+            vec![("v1alpha1", true, true), ("v1beta1", true, false)]
+        );
+        assert_eq!(
+            crd.spec.conversion.map(|c| c.strategy).as_deref(),
+            Some("None")
+        );
+        assert!(crd
+            .spec
+            .versions
+            .iter()
+            .all(|v| v.schema == crd.spec.versions[0].schema));
+    }
+
+    #[test]
+    fn test_manifests_match_served_versions() {
+        for manifest in [
+            include_str!("../../config/crd/stellarnode-crd.yaml"),
+            include_str!("../../charts/stellar-operator/templates/crd.yaml"),
+        ] {
+            // Skip Helm template lines; the versions list is plain YAML
+            let manifest: String = manifest
+                .lines()
+                .filter(|line| !line.contains("{{"))
+                .collect::<Vec<_>>()
+                .join("\n");
+            let crd: serde_yaml::Value = serde_yaml::from_str(&manifest).unwrap();
+            let versions = crd["spec"]["versions"].as_sequence().unwrap();
+            let names: Vec<_> = versions
+                .iter()
+                .map(|v| v["name"].as_str().unwrap())
+                .collect();
+            assert_eq!(names, SERVED_VERSIONS);
+            let stored: Vec<_> = versions
+                .iter()
+                .filter(|v| v["storage"].as_bool() == Some(true))
+                .map(|v| v["name"].as_str().unwrap())
+                .collect();
+            assert_eq!(stored, vec![STORAGE_VERSION]);
+            assert_eq!(crd["spec"]["conversion"]["strategy"].as_str(), Some("None"));
+        }
+    }
+}
@@ -15,6 +15,7 @@
 
 use std::collections::BTreeMap;
 
+use chrono::{DateTime, Utc};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
@@ -64,6 +65,26 @@ impl std::fmt::Display for HistoryMode {
     }
 }
 
+/// Schema for enums mixing unit variants with data-carrying ones
+///
+/// serde writes those as either a string (`"Mainnet"`) or a single-key object
+/// (`{"Custom": "..."}`), which a structural CRD schema cannot express; kube
+/// panics building the derived one. The field accepts any value and serde
+/// checks the shape.
+pub(crate) fn string_or_object_schema(
+    _: &mut schemars::gen::SchemaGenerator,
+) -> schemars::schema::Schema {
+    schemars::schema::SchemaObject {
+        extensions: [(
+            "x-kubernetes-preserve-unknown-fields".to_string(),
+            serde_json::Value::Bool(true),
+        )]
+        .into(),
+        ..Default::default()
+    }
+    .into()
+}
+
 /// Target Stellar network
 ///
 /// Specifies which Stellar network the node connects to.
@@ -101,6 +122,16 @@ impl StellarNetwork {
             StellarNetwork::Custom(passphrase) => passphrase,
         }
     }
+
+    /// SDF's public Horizon for this network (`None` for custom networks)
+    pub fn public_horizon_url(&self) -> Option<&'static str> {
+        match self {
+            StellarNetwork::Mainnet => Some("https://horizon.stellar.org"),
+            StellarNetwork::Testnet => Some("https://horizon-testnet.stellar.org"),
+            StellarNetwork::Futurenet => Some("https://horizon-futurenet.stellar.org"),
+            StellarNetwork::Custom(_) => None,
+        }
+    }
 }
 
 /// Kubernetes-style resource requirements
@@ -120,6 +151,7 @@ impl StellarNetwork {
 /// let resources = ResourceRequirements {
 ///     requests: Default::default(),
 ///     limits: Default::default(),
+///     claims: vec![],
 /// };
 /// ```
 #[derive(Clone, Debug, Deserialize, Serialize, JsonSchema)]
@@ -129,6 +161,10 @@ pub struct ResourceRequirements {
     pub requests: ResourceSpec,
     /// Maximum resources allowed
     pub limits: ResourceSpec,
+    /// ResourceClaims (dynamic resource allocation) in the node's namespace to
+    /// attach to the main container, e.g. a dedicated NVMe device
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub claims: Vec<String>,
 }
 
 impl Default for ResourceRequirements {
@@ -142,6 +178,7 @@ impl Default for ResourceRequirements {
                 cpu: "2".to_string(),
                 memory: "4Gi".to_string(),
             },
+            claims: Vec::new(),
         }
     }
 }
@@ -179,6 +216,8 @@ impl Default for ResourceSpec {
 ///     size: "500Gi".to_string(),
 ///     retention_policy: RetentionPolicy::Delete,
 ///     annotations: None,
+///     pvc_labels: None,
+///     resize_grace_period_seconds: None,
 /// };
 /// ```
 #[derive(Clone, Debug, Deserialize, Serialize, JsonSchema, PartialEq, Eq)]
@@ -195,6 +234,14 @@ pub struct StorageConfig {
     /// Useful for storage-class specific parameters (e.g., volumeBindingMode)
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub annotations: Option<BTreeMap<String, String>>,
+    /// Extra labels for the PersistentVolumeClaim only (e.g. `backup-policy: daily`
+    /// for snapshot tooling); other resources do not get them
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pvc_labels: Option<BTreeMap<String, String>>,
+    /// How long to wait for an online PVC expansion to finish before emitting a
+    /// `PVCResizeTimeout` warning (default: 0, wait indefinitely without a timeout)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub resize_grace_period_seconds: Option<u32>,
 }
 
 impl Default for StorageConfig {
@@ -204,6 +251,8 @@ impl Default for StorageConfig {
             size: "100Gi".to_string(),
             retention_policy: RetentionPolicy::default(),
             annotations: None,
+            pvc_labels: None,
+            resize_grace_period_seconds: None,
         }
     }
 }
@@ -250,6 +299,27 @@ pub enum RetentionPolicy {
 ///     kms_config: None,
 ///     vl_source: None,
 ///     hsm_config: None,
+///     flood_tx_period_ms: None,
+///     max_batch_write_count: None,
+///     history_cache: None,
+///     catchup_before_validate: false,
+///     quorum_slice_watcher: None,
+///     catchup_workers: None,
+///     scp_ballot_timeout_ms: None,
+///     scp_ledger_target_duration_ms: None,
+///     scp_max_time_slip_seconds: None,
+///     protocol_version_url: None,
+///     minimum_protocol_version: None,
+///     quorum_group: None,
+///     quorum_validators: vec![],
+///     archive_publication_credentials: None,
+///     history_gc: None,
+///     stellar_toml: None,
+///     max_tx_set_size: None,
+///     base_fee: None,
+///     standby: false,
+///     bucket_list_db: None,
+///     stellar_beat_reporting: None,
 /// };
 /// ```
 #[derive(Clone, Debug, Deserialize, Serialize, JsonSchema)]
@@ -258,8 +328,13 @@ pub struct ValidatorConfig {
     /// Secret name containing the validator seed (key: STELLAR_CORE_SEED)
     pub seed_secret_ref: String,
     /// Quorum set configuration as TOML string
+    ///
+    /// Used only when `quorumValidators` is empty.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub quorum_set: Option<String>,
+    /// Validators to trust; the operator generates the quorum set TOML from this list
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub quorum_validators: Vec<QuorumValidatorSpec>,
     /// Enable history archive for this validator
     #[serde(default)]
     pub enable_history_archive: bool,
@@ -281,6 +356,523 @@ pub struct ValidatorConfig {
     /// Cloud HSM configuration for secure key loading (optional)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub hsm_config: Option<HsmConfig>,
+    /// StellarBeat uptime reporting (optional)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stellar_beat_reporting: Option<StellarBeatConfig>,
+    /// Destination and credentials for publishing the history archive (optional)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub archive_publication_credentials: Option<HistoryArchivePublishConfig>,
+    /// Garbage collection of old checkpoints in the published history archive (optional)
+    ///
+    /// Requires `archivePublicationCredentials`; the archive URL and
+    /// credentials are taken from there.
+    #[serde(rename = "historyGCPolicy", skip_serializing_if = "Option::is_none")]
+    pub history_gc: Option<HistoryGcConfig>,
+    /// Publish a `.well-known/stellar.toml` for this validator's home domain (optional)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stellar_toml: Option<StellarTomlConfig>,
+    /// Transaction set size this validator votes for at the next protocol upgrade
+    /// (`MAX_TRANSACTIONS_PER_LEDGER`, default: 500)
+    ///
+    /// Not emitted on Mainnet unless `spec.allowMainnetParameterOverride` is set.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_tx_set_size: Option<u32>,
+    /// Base fee in stroops this validator votes for at the next protocol upgrade
+    /// (`BASE_FEE`, default: 100)
+    ///
+    /// Not emitted on Mainnet unless `spec.allowMainnetParameterOverride` is set.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub base_fee: Option<u64>,
+    /// Initial SCP ballot prepare timeout in milliseconds
+    /// (`SCP_BALLOT_PREPARE_TIMER_MS`, default: 1000)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scp_ballot_timeout_ms: Option<u32>,
+    /// Ledger close time SCP aims for, in milliseconds
+    /// (`SCP_LEDGER_TARGET_DURATION_MS`, default: 5000)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scp_ledger_target_duration_ms: Option<u32>,
+    /// How far a proposed close time may drift from the local clock, in seconds
+    /// (`MAXIMUM_LEDGER_CLOSETIME_DRIFT`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scp_max_time_slip_seconds: Option<u32>,
+    /// Parallel history archive downloads during catchup
+    /// (`MAX_CONCURRENT_SUBPROCESSES`, default: 16)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub catchup_workers: Option<u32>,
+    /// How often queued transactions are flooded to peers, in milliseconds
+    /// (`FLOOD_TX_PERIOD_MS`, default: 200)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub flood_tx_period_ms: Option<u32>,
+    /// Entries Stellar Core writes to the database per batch
+    /// (`MAX_BATCH_WRITE_COUNT`, default: 1024)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_batch_write_count: Option<u32>,
+    /// Run as a warm standby: stay in sync with the network without validating
+    ///
+    /// Promote with `POST /api/v1/nodes/<namespace>/<name>/promote`, which
+    /// clears this flag and restarts the node as a validator.
+    #[serde(default)]
+    pub standby: bool,
+    /// Keep a new validator non-validating until Stellar Core reports it is
+    /// synced, then restart it as a validator (`status.validatorMode`)
+    ///
+    /// Prevents a fresh validator from voting before it has caught up.
+    #[serde(default)]
+    pub catchup_before_validate: bool,
+    /// Stellar Core BucketListDB tuning (optional)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bucket_list_db: Option<BucketListDbConfig>,
+    /// Quorum this validator belongs to (optional)
+    ///
+    /// Validators sharing a group are never scheduled onto the same Kubernetes
+    /// node, in any namespace, so one node failure cannot take out several
+    /// members of the same quorum.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub quorum_group: Option<String>,
+    /// Minimum network protocol version the validator is deployed for (optional)
+    ///
+    /// Set it to the protocol the image was released for. Once the network's
+    /// current protocol version is higher, the validator is scaled to 0 and
+    /// `ProtocolVersionOutdated` is set until this is raised along with the
+    /// image.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub minimum_protocol_version: Option<u32>,
+    /// Horizon URL the network's current protocol version is read from
+    /// (default: SDF's public Horizon for the network)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub protocol_version_url: Option<String>,
+    /// Alert when validators of the quorum slice disagree or go missing (optional)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub quorum_slice_watcher: Option<QuorumSliceWatcherConfig>,
+    /// Local cache for files downloaded from history archives (optional)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub history_cache: Option<HistoryCacheConfig>,
+}
+
+/// Volume Stellar Core downloads history archive files into during catchup
+///
+/// Mounted at `/opt/stellar/history-cache` and set as Stellar Core's
+/// `TMP_DIR_PATH`. A PersistentVolumeClaim keeps the downloads across pod
+/// restarts, so an interrupted catchup does not fetch them again.
+///
+/// # Example
+///
+/// ```yaml
+/// historyCache:
+///   volumeType: PersistentVolumeClaim
+///   size: 20Gi
+///   storageClass: fast-ssd
+/// ```
+#[derive(Clone, Debug, Deserialize, Serialize, JsonSchema, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct HistoryCacheConfig {
+    /// Backing volume (default: EmptyDir)
+    #[serde(default)]
+    pub volume_type: HistoryCacheVolumeType,
+    /// Cache size, e.g. "20Gi": the EmptyDir size limit or the PVC request
+    pub size: String,
+    /// Storage class of the PVC (default: `spec.storage.storageClass`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub storage_class: Option<String>,
+}
+
+/// Volume backing the history archive cache
+#[derive(Clone, Debug, Default, Deserialize, Serialize, JsonSchema, PartialEq, Eq)]
+pub enum HistoryCacheVolumeType {
+    /// Scratch space removed with the pod
+    #[default]
+    EmptyDir,
+    /// Operator-managed PVC named `<node>-history-cache`
+    PersistentVolumeClaim,
+}
+
+/// BucketListDB index tuning for Stellar Core
+///
+/// Trades memory for lookup speed on validators with large ledger state.
+/// Unset fields use Stellar Core's defaults.
+///
+/// # Example
+///
+/// ```yaml
+/// bucketListDb:
+///   indexPageSizeExponent: 16
+///   indexCutoffMb: 50
+///   persistIndex: true
+/// ```
+#[derive(Clone, Debug, Default, Deserialize, Serialize, JsonSchema, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct BucketListDbConfig {
+    /// Page size of the bucket index as a power of two
+    /// (`BUCKETLIST_DB_INDEX_PAGE_SIZE_EXPONENT`, default: 14, max: 24)
+    ///
+    /// Larger pages use less memory but slow down lookups; 0 indexes every entry.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub index_page_size_exponent: Option<u32>,
+    /// Buckets smaller than this many MB get a full index instead of a page index
+    /// (`BUCKETLIST_DB_INDEX_CUTOFF`, default: 20, max: 10240)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub index_cutoff_mb: Option<u32>,
+    /// Persist indexes to disk so restarts skip rebuilding them
+    /// (`BUCKETLIST_DB_PERSIST_INDEX`, default: true)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub persist_index: Option<bool>,
+}
+
+/// Stellar Core default `BUCKETLIST_DB_INDEX_PAGE_SIZE_EXPONENT`
+pub const DEFAULT_BUCKETLIST_DB_PAGE_SIZE_EXPONENT: u32 = 14;
+
+/// Largest accepted `BUCKETLIST_DB_INDEX_PAGE_SIZE_EXPONENT` (16 MiB pages)
+pub const MAX_BUCKETLIST_DB_PAGE_SIZE_EXPONENT: u32 = 24;
+
+/// Stellar Core default `BUCKETLIST_DB_INDEX_CUTOFF`, in MB
+pub const DEFAULT_BUCKETLIST_DB_INDEX_CUTOFF_MB: u32 = 20;
+
+/// Largest accepted `BUCKETLIST_DB_INDEX_CUTOFF`, in MB
+pub const MAX_BUCKETLIST_DB_INDEX_CUTOFF_MB: u32 = 10240;
+
+/// Stellar network minimum base fee, in stroops
+pub const MIN_NETWORK_BASE_FEE: u64 = 100;
+
+/// Largest accepted `catchupWorkers`
+pub const MAX_CATCHUP_WORKERS: u32 = 64;
+
+/// Smallest accepted `contractCodeCacheSize`; Soroban RPC ignores smaller
+/// values with a startup warning and uses its internal minimum
+pub const MIN_CONTRACT_CODE_CACHE_SIZE: u32 = 100;
+
+/// Smallest accepted `contractCodeCacheTtlSeconds`
+pub const MIN_CONTRACT_CODE_CACHE_TTL_SECONDS: u32 = 60;
+
+/// Smallest accepted `scpBallotTimeoutMs`; shorter timers flood the network
+/// with SCP messages
+pub const MIN_SCP_BALLOT_TIMEOUT_MS: u32 = 100;
+
+/// Accepted `floodTxPeriodMs` range; shorter periods flood peers with tiny
+/// batches, longer ones delay transactions past the next ledger
+pub const MIN_FLOOD_TX_PERIOD_MS: u32 = 10;
+pub const MAX_FLOOD_TX_PERIOD_MS: u32 = 5000;
+
+/// Largest accepted `maxBatchWriteCount`
+pub const MAX_BATCH_WRITE_COUNT_LIMIT: u32 = 65536;
+
+/// `scpLedgerTargetDurationMs` below which consensus rounds are likely to be
+/// missed on high-latency networks
+pub const MIN_RECOMMENDED_SCP_LEDGER_TARGET_DURATION_MS: u32 = 2000;
+
+/// SEP-1 `stellar.toml` published for a validator
+///
+/// The operator renders the file into a `<node>-stellar-toml` ConfigMap and
+/// serves it over HTTPS at `/.well-known/stellar.toml` from a small Nginx
+/// Deployment, using a cert-manager certificate for the `organizationUrl` host.
+///
+/// # Example
+///
+/// ```yaml
+/// stellarToml:
+///   organizationName: Example Org
+///   organizationUrl: https://example.org
+///   nodeAlias: example-1
+///   publicKey: GCGB2S2KGYARPVIA37HYZXVRM2YZUEXA6S33ZU5BUDC6THSB62LZSTYH
+///   historyUrl: https://history.example.org
+///   certManagerClusterIssuer: letsencrypt-prod
+/// ```
+#[derive(Clone, Debug, Default, Deserialize, Serialize, JsonSchema, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct StellarTomlConfig {
+    /// Organization name (`ORG_NAME`)
+    pub organization_name: String,
+    /// Organization website (`ORG_URL`); its host is the home domain
+    pub organization_url: String,
+    /// Validator alias (`ALIAS`)
+    pub node_alias: String,
+    /// Validator public key (`PUBLIC_KEY`)
+    pub public_key: String,
+    /// Public history archive URL (`HISTORY`)
+    pub history_url: String,
+    /// Organization description (`ORG_DESCRIPTION`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub documentation: Option<String>,
+    /// cert-manager issuer name (namespaced)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cert_manager_issuer: Option<String>,
+    /// cert-manager cluster issuer name
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cert_manager_cluster_issuer: Option<String>,
+}
+
+impl StellarTomlConfig {
+    /// Home domain served by the certificate, taken from `organizationUrl`
+    pub fn home_domain(&self) -> Option<&str> {
+        let rest = self
+            .organization_url
+            .strip_prefix("https://")
+            .or_else(|| self.organization_url.strip_prefix("http://"))?;
+        let host = rest.split(['/', ':', '?', '#']).next()?;
+        (!host.is_empty()).then_some(host)
+    }
+}
+
+/// History archive publication target for validators
+///
+/// Exactly one of `s3Bucket` or `azureBlob` may be set.
+#[derive(Clone, Debug, Default, Deserialize, Serialize, JsonSchema, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct HistoryArchivePublishConfig {
+    /// S3 bucket to publish the archive to
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub s3_bucket: Option<String>,
+    /// Azure Blob Storage container to publish the archive to
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub azure_blob: Option<AzureBlobConfig>,
+}
+
+impl HistoryArchivePublishConfig {
+    /// URL `stellar-archivist` reads the published archive from
+    pub fn archive_url(&self) -> Option<String> {
+        match (&self.s3_bucket, &self.azure_blob) {
+            (Some(bucket), _) => Some(format!("s3://{bucket}")),
+            (None, Some(azure)) => Some(azure.container_url()),
+            (None, None) => None,
+        }
+    }
+}
+
+/// How history archive garbage collection is run
+#[derive(Clone, Copy, Debug, Default, Deserialize, Serialize, JsonSchema, PartialEq, Eq)]
+pub enum GcImplementation {
+    /// Sidecar next to Stellar Core running GC on the schedule
+    #[default]
+    Sidecar,
+    /// Kubernetes CronJob `<name>-history-gc`
+    CronJob,
+}
+
+/// Garbage collection of old history archive checkpoints
+///
+/// Runs `stellar-archivist gc` against the archive configured in
+/// `archivePublicationCredentials`, keeping the newest `retentionCount`
+/// checkpoints.
+///
+/// # Example
+///
+/// ```yaml
+/// historyGCPolicy:
+///   enabled: true
+///   retentionCount: 1000
+///   gcSchedule: "0 3 * * *"
+///   dryRunFirst: true
+///   gcImplementation: CronJob
+/// ```
+#[derive(Clone, Debug, Deserialize, Serialize, JsonSchema, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct HistoryGcConfig {
+    /// Run garbage collection
+    #[serde(default)]
+    pub enabled: bool,
+    /// Number of most recent archive checkpoints to keep
+    pub retention_count: u32,
+    /// When GC runs (five-field cron expression, UTC)
+    pub gc_schedule: String,
+    /// Log what a run would delete before deleting it
+    #[serde(default)]
+    pub dry_run_first: bool,
+    /// Run GC in a sidecar or in a CronJob (default: Sidecar)
+    #[serde(default)]
+    pub gc_implementation: GcImplementation,
+    /// Image providing `stellar-archivist` and, for the sidecar, `supercronic`
+    #[serde(default = "default_history_gc_image")]
+    pub image: String,
+}
+
+fn default_history_gc_image() -> String {
+    "stellar/stellar-archivist:latest".to_string()
+}
+
+impl HistoryGcConfig {
+    /// Parsed `gcSchedule`; only the five-field format accepted by CronJobs
+    pub fn schedule(&self) -> Result<cron::Schedule, String> {
+        if self.gc_schedule.split_whitespace().count() != 5 {
+            return Err("expected five fields".to_string());
+        }
+        parse_cron_schedule(&self.gc_schedule)
+    }
+}
+
+/// Parse a cron expression
+///
+/// Five-field expressions use standard cron semantics and get a leading
+/// seconds field; their day-of-week (0-7, 0 and 7 = Sunday) is renumbered
+/// into the `cron` crate's 1-7 with 1 = Sunday. Six- and seven-field
+/// expressions are handed to the crate as written.
+fn parse_cron_schedule(expression: &str) -> Result<cron::Schedule, String> {
+    let fields: Vec<&str> = expression.split_whitespace().collect();
+    let expression = match fields.as_slice() {
+        [minute, hour, day_of_month, month, day_of_week] => format!(
+            "0 {minute} {hour} {day_of_month} {month} {}",
+            crate_day_of_week(day_of_week)?
+        ),
+        _ => expression.trim().to_string(),
+    };
+    expression
+        .parse()
+        .map_err(|e: cron::error::Error| e.to_string())
+}
+
+/// Renumber a standard day-of-week field for the `cron` crate
+///
+/// Numeric lists, ranges and steps are expanded into an explicit list of
+/// days; fields using day names already mean the same thing to the crate
+/// and are returned unchanged.
+fn crate_day_of_week(field: &str) -> Result<String, String> {
+    if field == "*" || field == "?" || field.chars().any(|c| c.is_ascii_alphabetic()) {
+        return Ok(field.to_string());
+    }
+
+    let number = |value: &str| -> Result<u32, String> {
+        value
+            .parse()
+            .map_err(|_| format!("invalid day of week '{value}'"))
+    };
+    let mut days = std::collections::BTreeSet::new();
+    for part in field.split(',') {
+        let (range, step) = match part.split_once('/') {
+            Some((range, step)) => (range, Some(number(step)?)),
+            None => (part, None),
+        };
+        let (first, last) = match range.split_once('-') {
+            _ if range == "*" => (0, 7),
+            Some((first, last)) => (number(first)?, number(last)?),
+            // `3/2` runs from 3 to the end of the week
+            None if step.is_some() => (number(range)?, 7),
+            None => (number(range)?, number(range)?),
+        };
+        if last > 7 || first > last || step == Some(0) {
+            return Err(format!("invalid day of week '{part}'"));
+        }
+        let step = step.unwrap_or(1) as usize;
+        days.extend((first..=last).step_by(step).map(|day| day % 7));
+    }
+    Ok(days
+        .iter()
+        .map(|day| (day + 1).to_string())
+        .collect::<Vec<_>>()
+        .join(","))
+}
+
+/// Azure Blob Storage target for history archive publication
+#[derive(Clone, Debug, Deserialize, Serialize, JsonSchema, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct AzureBlobConfig {
+    /// Storage account name
+    pub account_name: String,
+    /// Blob container name
+    pub container_name: String,
+    /// Secret holding the SAS token (key: AZURE_STORAGE_SAS_TOKEN)
+    pub sas_token_secret: String,
+    /// Endpoint suffix for sovereign clouds (default: "core.windows.net")
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub endpoint_suffix: Option<String>,
+}
+
+impl AzureBlobConfig {
+    /// Base URL of the blob container
+    pub fn container_url(&self) -> String {
+        format!(
+            "https://{}.blob.{}/{}",
+            self.account_name,
+            self.endpoint_suffix
+                .as_deref()
+                .unwrap_or("core.windows.net"),
+            self.container_name
+        )
+    }
+}
+
+/// Configuration for reporting validator uptime to StellarBeat
+///
+/// When set, a sidecar periodically posts the validator's public key and
+/// current ledger sequence to the StellarBeat API.
+#[derive(Clone, Debug, Deserialize, Serialize, JsonSchema, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct StellarBeatConfig {
+    /// Home domain of the validator (as published in stellar.toml)
+    pub home_domain: String,
+    /// Public key (G...) of the validator
+    pub public_key: String,
+    /// StellarBeat API endpoint (default: "https://api.stellarbeat.io")
+    #[serde(default = "default_stellar_beat_api_url")]
+    pub api_url: String,
+    /// Sidecar image that performs the reporting
+    #[serde(default = "default_stellar_beat_image")]
+    pub image: String,
+    /// Interval in seconds between reports (default: 300)
+    #[serde(default = "default_stellar_beat_poll_interval")]
+    pub poll_interval_secs: u64,
+}
+
+fn default_stellar_beat_api_url() -> String {
+    "https://api.stellarbeat.io".to_string()
+}
+
+fn default_stellar_beat_image() -> String {
+    "stellar/stellarbeat-reporter:latest".to_string()
+}
+
+fn default_stellar_beat_poll_interval() -> u64 {
+    300
+}
+
+/// Watch for fellow validators dropping out of this validator's quorum slice
+///
+/// The operator reads Stellar Core's `/quorum` endpoint and sets
+/// `QuorumSliceAlert=True` once any listed validator has been missing or
+/// disagreeing for longer than `checkIntervalSeconds`.
+///
+/// # Example
+///
+/// ```yaml
+/// quorumSliceWatcher:
+///   enabled: true
+///   checkIntervalSeconds: 120
+///   alertOnMissingValidators:
+///     - GCGB2S2KGYARPVIA37HYZXVRM2YZUEXA6S33ZU5BUDC6THSB62LZSTYH
+/// ```
+#[derive(Clone, Debug, Deserialize, Serialize, JsonSchema, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct QuorumSliceWatcherConfig {
+    /// Enable the watcher
+    #[serde(default)]
+    pub enabled: bool,
+    /// Seconds a listed validator may be missing or disagreeing before the
+    /// alert fires (default: 60)
+    #[serde(default = "default_quorum_slice_check_interval")]
+    pub check_interval_seconds: u32,
+    /// Public keys (G...) of the validators to alert on
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub alert_on_missing_validators: Vec<String>,
+}
+
+fn default_quorum_slice_check_interval() -> u32 {
+    DEFAULT_QUORUM_SLICE_CHECK_INTERVAL_SECONDS
+}
+
+/// Default `quorumSliceWatcher.checkIntervalSeconds`
+pub const DEFAULT_QUORUM_SLICE_CHECK_INTERVAL_SECONDS: u32 = 60;
+
+/// A validator included in the generated quorum set
+#[derive(Clone, Debug, Deserialize, Serialize, JsonSchema, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct QuorumValidatorSpec {
+    /// Public key (G...) of the validator
+    pub public_key: String,
+    /// Short alias shown in stellar-core logs (e.g. "sdf_1")
+    pub name: String,
+    /// Home domain of the organization running the validator
+    pub home_domain: String,
+    /// Relative weight (default: 1); validators are listed heaviest first and
+    /// a weight of 0 excludes the validator from the quorum set
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub weight: Option<u32>,
 }
 
 /// Configuration for Hardware Security Module (HSM) integration
@@ -366,68 +958,814 @@ pub struct KmsConfig {
     pub fetcher_image: Option<String>,
 }
 
-/// Horizon API server configuration
-///
-/// Configuration for Horizon nodes that provide a REST API to query the Stellar ledger.
-/// Horizon ingests data from Stellar Core and indexes it for fast queries.
+/// Linux capability names accepted in `containerSecurityContext`
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum LinuxCapability {
+    /// Every capability (only meaningful in `capabilitiesDrop`)
+    All,
+    AuditWrite,
+    Chown,
+    DacOverride,
+    Fowner,
+    Fsetid,
+    IpcLock,
+    Kill,
+    Mknod,
+    NetAdmin,
+    NetBindService,
+    NetRaw,
+    Setfcap,
+    Setgid,
+    Setpcap,
+    Setuid,
+    SysAdmin,
+    SysChroot,
+    SysPtrace,
+    SysResource,
+    SysTime,
+}
+
+impl LinuxCapability {
+    /// Capability name as Kubernetes expects it (e.g. "NET_BIND_SERVICE")
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            LinuxCapability::All => "ALL",
+            LinuxCapability::AuditWrite => "AUDIT_WRITE",
+            LinuxCapability::Chown => "CHOWN",
+            LinuxCapability::DacOverride => "DAC_OVERRIDE",
+            LinuxCapability::Fowner => "FOWNER",
+            LinuxCapability::Fsetid => "FSETID",
+            LinuxCapability::IpcLock => "IPC_LOCK",
+            LinuxCapability::Kill => "KILL",
+            LinuxCapability::Mknod => "MKNOD",
+            LinuxCapability::NetAdmin => "NET_ADMIN",
+            LinuxCapability::NetBindService => "NET_BIND_SERVICE",
+            LinuxCapability::NetRaw => "NET_RAW",
+            LinuxCapability::Setfcap => "SETFCAP",
+            LinuxCapability::Setgid => "SETGID",
+            LinuxCapability::Setpcap => "SETPCAP",
+            LinuxCapability::Setuid => "SETUID",
+            LinuxCapability::SysAdmin => "SYS_ADMIN",
+            LinuxCapability::SysChroot => "SYS_CHROOT",
+            LinuxCapability::SysPtrace => "SYS_PTRACE",
+            LinuxCapability::SysResource => "SYS_RESOURCE",
+            LinuxCapability::SysTime => "SYS_TIME",
+        }
+    }
+
+    /// Capabilities that grant near-root control of the node or its network
+    pub fn is_privileged(&self) -> bool {
+        matches!(self, LinuxCapability::SysAdmin | LinuxCapability::NetAdmin)
+    }
+}
+
+impl std::fmt::Display for LinuxCapability {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Capabilities applied to the main container's `securityContext`
 ///
-/// # Examples
+/// When `containerSecurityContext` is omitted the operator drops `ALL`
+/// capabilities.
 ///
-/// ```rust,no_run
-/// use stellar_k8s::crd::HorizonConfig;
+/// # Example
 ///
-/// let config = HorizonConfig {
-///     database_secret_ref: "horizon-db-secret".to_string(),
-///     enable_ingest: true,
-///     stellar_core_url: "http://core.default:11626".to_string(),
-///     ingest_workers: 4,
-///     enable_experimental_ingestion: false,
-///     auto_migration: true,
-/// };
+/// ```yaml
+/// containerSecurityContext:
+///   capabilitiesDrop: [ALL]
+///   capabilitiesAdd: [NET_BIND_SERVICE]
 /// ```
-#[derive(Clone, Debug, Deserialize, Serialize, JsonSchema)]
+#[derive(Clone, Debug, Default, Deserialize, Serialize, JsonSchema, PartialEq, Eq)]
 #[serde(rename_all = "camelCase")]
-pub struct HorizonConfig {
-    /// Secret reference for database credentials
-    pub database_secret_ref: String,
-    /// Enable real-time ingestion from Stellar Core
-    #[serde(default = "default_true")]
-    pub enable_ingest: bool,
-    /// Stellar Core URL to ingest from
-    pub stellar_core_url: String,
-    /// Number of parallel ingestion workers
-    #[serde(default = "default_ingest_workers")]
-    pub ingest_workers: u32,
-    /// Enable experimental features
-    #[serde(default)]
-    pub enable_experimental_ingestion: bool,
-    /// Automatically run database migrations on startup or upgrade
-    #[serde(default = "default_true")]
-    pub auto_migration: bool,
+pub struct ContainerSecurityContextConfig {
+    /// Capabilities added to the container
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub capabilities_add: Vec<LinuxCapability>,
+    /// Capabilities dropped from the container
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub capabilities_drop: Vec<LinuxCapability>,
 }
 
-fn default_true() -> bool {
-    true
+/// How the kubelet fills in a container's termination message
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub enum TerminationMessagePolicy {
+    /// Read the message from `terminationMessagePath` only
+    #[default]
+    File,
+    /// Use the tail of the container log when the message file is empty and the container failed
+    FallbackToLogsOnError,
 }
 
-fn default_ingest_workers() -> u32 {
-    1
+impl std::fmt::Display for TerminationMessagePolicy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TerminationMessagePolicy::File => write!(f, "File"),
+            TerminationMessagePolicy::FallbackToLogsOnError => write!(f, "FallbackToLogsOnError"),
+        }
+    }
 }
 
-/// Captive Core configuration for Soroban RPC
-///
-/// Structured configuration for Captive Core, which is used by Soroban RPC nodes
-/// to stream ledger data from the Stellar network.
-///
-/// This provides a type-safe alternative to raw TOML strings, ensuring configuration
-/// correctness at compile time and runtime.
+/// How config values are rendered before the ConfigMap is applied
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub enum ConfigTemplateMode {
+    /// Values are used as written
+    #[default]
+    Static,
+    /// Values are Go templates rendered with `.node.name`, `.node.namespace`
+    /// and `.node.spec` (e.g. `http://{{ .node.name }}-core:11626`)
+    GoTemplate,
+}
+
+impl std::fmt::Display for ConfigTemplateMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ConfigTemplateMode::Static => write!(f, "Static"),
+            ConfigTemplateMode::GoTemplate => write!(f, "GoTemplate"),
+        }
+    }
+}
+
+/// Default file the termination message is read from
+pub const DEFAULT_TERMINATION_MESSAGE_PATH: &str = "/dev/termination-log";
+
+/// Grafana dashboard provisioning
 ///
-/// # Examples
+/// The operator writes a dashboard for the node's type into a ConfigMap labelled
+/// `grafana_dashboard: "1"` in the Grafana namespace, where Grafana's dashboard
+/// sidecar picks it up.
 ///
-/// ```rust,no_run
-/// use stellar_k8s::crd::CaptiveCoreConfig;
+/// # Example
 ///
-/// let config = CaptiveCoreConfig {
+/// ```yaml
+/// grafanaDashboard:
+///   namespace: monitoring
+///   folder: Stellar
+///   dashboardLabels:
+///     release: kube-prometheus-stack
+/// ```
+#[derive(Clone, Debug, Default, Deserialize, Serialize, JsonSchema, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct GrafanaDashboardConfig {
+    /// Namespace Grafana (and its dashboard sidecar) runs in
+    pub namespace: String,
+    /// Grafana folder for the dashboard (`grafana_folder` annotation)
+    pub folder: String,
+    /// Extra labels the sidecar selects dashboards by
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub dashboard_labels: BTreeMap<String, String>,
+}
+
+/// Pre-upgrade check run as a one-shot Job before changes that restart pods
+///
+/// # Example
+///
+/// ```yaml
+/// precheckHook:
+///   image: registry.example.org/stellar/schema-check:1.2
+///   command: ["/bin/check-schema", "--target", "v22"]
+///   timeoutSeconds: 300
+///   failurePolicy: Abort
+/// ```
+#[derive(Clone, Debug, Deserialize, Serialize, JsonSchema, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct PrecheckHookConfig {
+    /// Image running the check
+    pub image: String,
+    /// Command (and arguments) of the check; a non-zero exit code fails it
+    pub command: Vec<String>,
+    /// How long to wait for the check to finish (default: 300)
+    #[serde(default = "default_precheck_timeout_seconds")]
+    pub timeout_seconds: u32,
+    /// What to do when the check fails or times out
+    #[serde(default)]
+    pub failure_policy: FailurePolicy,
+}
+
+fn default_precheck_timeout_seconds() -> u32 {
+    300
+}
+
+/// Longest precheck hook timeout accepted (one hour)
+pub const MAX_PRECHECK_TIMEOUT_SECONDS: u32 = 3600;
+
+/// Reaction to a failed precheck hook
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub enum FailurePolicy {
+    /// Keep the current pods and do not roll out the change
+    #[default]
+    Abort,
+    /// Emit a Warning event and roll out the change anyway
+    Warn,
+}
+
+impl std::fmt::Display for FailurePolicy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FailurePolicy::Abort => write!(f, "Abort"),
+            FailurePolicy::Warn => write!(f, "Warn"),
+        }
+    }
+}
+
+/// How the operator retries a node whose reconciles keep failing
+///
+/// Without a policy, retriable errors are retried after 15s and others after 60s.
+///
+/// # Example
+///
+/// ```yaml
+/// nodeFailurePolicy:
+///   exponentialBackoff:
+///     initial: 10
+///     max: 600
+/// ```
+#[derive(Clone, Debug, Deserialize, Serialize, JsonSchema, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum NodeFailurePolicy {
+    /// Retry after a fixed number of seconds
+    Requeue(u64),
+    /// Double the retry interval after each consecutive failure, in seconds
+    ExponentialBackoff { initial: u64, max: u64 },
+    /// Stop retrying after `maxFailures` consecutive failures and set the
+    /// `ReconcileStopped` condition; any spec change resumes reconciling
+    #[serde(rename_all = "camelCase")]
+    AlarmAndStop { max_failures: u32 },
+}
+
+/// Lifecycle hooks for the main container
+///
+/// # Example
+///
+/// ```yaml
+/// lifecycle:
+///   postStart:
+///     exec:
+///       command: ["/bin/sh", "-c", "cp /config/seed.cfg /data/seed.cfg"]
+/// ```
+#[derive(Clone, Debug, Default, Deserialize, Serialize, JsonSchema, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct LifecycleConfig {
+    /// Hook run right after the container starts
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub post_start: Option<LifecycleHookSpec>,
+}
+
+/// A lifecycle hook; exactly one of `exec` or `httpGet` must be set
+#[derive(Clone, Debug, Default, Deserialize, Serialize, JsonSchema, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct LifecycleHookSpec {
+    /// Run a command inside the container
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub exec: Option<ExecHookSpec>,
+    /// Send an HTTP GET request to the container
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub http_get: Option<HttpGetHookSpec>,
+}
+
+/// Command executed by a lifecycle hook
+#[derive(Clone, Debug, Default, Deserialize, Serialize, JsonSchema, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct ExecHookSpec {
+    /// Command and arguments (not run in a shell unless one is invoked explicitly)
+    pub command: Vec<String>,
+}
+
+/// HTTP GET request made by a lifecycle hook
+#[derive(Clone, Debug, Default, Deserialize, Serialize, JsonSchema, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct HttpGetHookSpec {
+    /// Request path (e.g. "/register")
+    pub path: String,
+    /// Container port to call
+    pub port: u16,
+    /// Host to connect to (defaults to the pod IP)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub host: Option<String>,
+    /// HTTP or HTTPS (default: HTTP)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scheme: Option<String>,
+}
+
+/// HashiCorp Vault Agent Injector integration
+///
+/// Adds the `vault.hashicorp.com/*` annotations to the pod template so the Vault
+/// Agent Injector renders each secret to `/vault/secrets/<name>`. Secrets with an
+/// `envVar` get that variable set to the rendered file path on the main container.
+///
+/// # Example
+///
+/// ```yaml
+/// vault:
+///   role: stellar-validator
+///   secrets:
+///     - name: validator-seed
+///       path: secret/data/stellar/validator
+///       template: '{{ with secret "secret/data/stellar/validator" }}{{ .Data.data.seed }}{{ end }}'
+///       envVar: STELLAR_CORE_SEED_PATH
+/// ```
+#[derive(Clone, Debug, Default, Deserialize, Serialize, JsonSchema, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct VaultConfig {
+    /// Vault Kubernetes auth role used by the agent
+    pub role: String,
+    /// Secrets rendered by the agent
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub secrets: Vec<VaultSecretSpec>,
+    /// Only render secrets in an init container, without a long-running agent sidecar
+    #[serde(default)]
+    pub pre_populate_only: bool,
+    /// Additional `vault.hashicorp.com/*` annotations passed through to the pod template
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub annotations: BTreeMap<String, String>,
+}
+
+/// A secret rendered by the Vault agent
+#[derive(Clone, Debug, Default, Deserialize, Serialize, JsonSchema, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct VaultSecretSpec {
+    /// File name under `/vault/secrets/`
+    pub name: String,
+    /// Vault secret path (e.g. "secret/data/stellar/validator")
+    pub path: String,
+    /// Consul template used to render the file (optional)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub template: Option<String>,
+    /// Environment variable on the main container set to the rendered file path
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub env_var: Option<String>,
+}
+
+impl VaultSecretSpec {
+    /// Path the agent renders this secret to
+    pub fn file_path(&self) -> String {
+        format!("/vault/secrets/{}", self.name)
+    }
+}
+
+/// Service mesh sidecar injection
+///
+/// Sets the mesh's injection annotation on the pod template. When `inject` is
+/// unset, Validators opt out (so the mesh does not intercept peer traffic) and
+/// Horizon and Soroban RPC opt in.
+///
+/// # Example
+///
+/// ```yaml
+/// mesh:
+///   provider: Linkerd
+///   inject: true
+/// ```
+#[derive(Clone, Debug, Deserialize, Serialize, JsonSchema, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct MeshConfig {
+    /// Service mesh injecting the sidecar
+    pub provider: MeshProvider,
+    /// Inject the sidecar into the node's pods (default: false for Validators, true otherwise)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub inject: Option<bool>,
+}
+
+/// Supported service meshes
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub enum MeshProvider {
+    Istio,
+    Linkerd,
+}
+
+impl MeshProvider {
+    /// Pod annotation controlling sidecar injection
+    pub fn injection_annotation(&self) -> &'static str {
+        match self {
+            MeshProvider::Istio => "sidecar.istio.io/inject",
+            MeshProvider::Linkerd => "linkerd.io/inject",
+        }
+    }
+
+    /// Value of the injection annotation
+    pub fn injection_value(&self, inject: bool) -> &'static str {
+        match (self, inject) {
+            (MeshProvider::Istio, true) => "true",
+            (MeshProvider::Istio, false) => "false",
+            (MeshProvider::Linkerd, true) => "enabled",
+            (MeshProvider::Linkerd, false) => "disabled",
+        }
+    }
+}
+
+/// Horizon API server configuration
+///
+/// Configuration for Horizon nodes that provide a REST API to query the Stellar ledger.
+/// Horizon ingests data from Stellar Core and indexes it for fast queries.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use stellar_k8s::crd::HorizonConfig;
+///
+/// let config = HorizonConfig {
+///     database_secret_ref: "horizon-db-secret".to_string(),
+///     enable_ingest: true,
+///     stellar_core_url: "http://core.default:11626".to_string(),
+///     ingest_workers: 4,
+///     enable_experimental_ingestion: false,
+///     auto_migration: true,
+///     min_stellar_core_version: None,
+///     transaction_filtering: None,
+///     database_ready_timeout_seconds: None,
+///     db_wait_timeout_seconds: None,
+///     offers_filtering: None,
+///     cors_allowed_origins: vec![],
+///     cors_allowed_headers: vec!["*".to_string()],
+///     cors_expose_headers: vec![],
+///     cors_max_age_seconds: None,
+///     database_ssl: None,
+///     captive_core: None,
+///     order_book_cache_depth: None,
+///     enable_order_book_streaming: false,
+///     order_book_stale_tolerance_ledgers: None,
+///     sse_max_connections: None,
+///     tls: None,
+///     checkpoint_frequency: None,
+///     history_retention_count: None,
+///     stellar_core_ref: None,
+///     experimental_ingestion: None,
+/// };
+/// ```
+#[derive(Clone, Debug, Deserialize, Serialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct HorizonConfig {
+    /// Secret reference for database credentials
+    pub database_secret_ref: String,
+    /// Enable real-time ingestion from Stellar Core
+    #[serde(default = "default_true")]
+    pub enable_ingest: bool,
+    /// Stellar Core URL to ingest from
+    /// May be left empty when `stellarCoreRef` is set
+    #[serde(default)]
+    pub stellar_core_url: String,
+    /// Reference to a sibling validator StellarNode; resolved to its
+    /// cluster-internal FQDN and takes precedence over `stellarCoreUrl`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stellar_core_ref: Option<StellarNodeRef>,
+    /// Minimum Stellar Core version this Horizon release requires (e.g. "21.0.0")
+    ///
+    /// Compared against the `build` reported by the Stellar Core `/info`
+    /// endpoint; while Core is older, Horizon is scaled to 0 and the
+    /// `CoreVersionIncompatible` condition is set.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_stellar_core_version: Option<String>,
+    /// Number of parallel ingestion workers
+    #[serde(default = "default_ingest_workers")]
+    pub ingest_workers: u32,
+    /// Enable experimental features
+    #[serde(default)]
+    pub enable_experimental_ingestion: bool,
+    /// Automatically run database migrations on startup or upgrade
+    #[serde(default = "default_true")]
+    pub auto_migration: bool,
+    /// Fine-grained toggles for the Protocol 21 experimental ingestion pipeline
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub experimental_ingestion: Option<ExperimentalIngestionConfig>,
+    /// Ledgers between ingestion checkpoints (`CHECKPOINT_FREQUENCY`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub checkpoint_frequency: Option<u32>,
+    /// Number of latest ledgers of history Horizon retains (`HISTORY_RETENTION_COUNT`)
+    ///
+    /// Directly bounds the size of the Horizon database.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub history_retention_count: Option<u32>,
+    /// Order book levels cached per trading pair (`ORDER_BOOK_DEPTH`, default: 20, max: 200)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub order_book_cache_depth: Option<u32>,
+    /// Stream order book updates to clients over SSE (`ENABLE_ORDER_BOOK_STREAMING`)
+    #[serde(default)]
+    pub enable_order_book_streaming: bool,
+    /// Ledgers the cached order book may lag before it is treated as stale
+    /// (`ORDER_BOOK_STALE_TOLERANCE_LEDGERS`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub order_book_stale_tolerance_ledgers: Option<u32>,
+    /// Maximum concurrent SSE streaming connections (`SSE_MAX_CONNECTIONS`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sse_max_connections: Option<u32>,
+    /// Serve HTTPS directly from the Horizon container, without a reverse proxy
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tls: Option<HorizonTlsConfig>,
+    /// Run Stellar Core as a Horizon subprocess (captive core) instead of
+    /// ingesting from `stellarCoreUrl` / `stellarCoreRef`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub captive_core: Option<HorizonCaptiveCoreConfig>,
+    /// TLS settings for the connection to PostgreSQL
+    #[serde(rename = "databaseSSL", skip_serializing_if = "Option::is_none")]
+    pub database_ssl: Option<DatabaseSslConfig>,
+    /// Origins allowed to call Horizon from a browser (`CORS_ALLOWED_ORIGINS`),
+    /// e.g. "https://app.example.com", or "*" for any origin
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub cors_allowed_origins: Vec<String>,
+    /// Request headers browsers may send (`CORS_ALLOWED_HEADERS`, default: ["*"])
+    #[serde(default = "default_cors_allowed_headers")]
+    pub cors_allowed_headers: Vec<String>,
+    /// Response headers exposed to browser scripts (`CORS_EXPOSE_HEADERS`)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub cors_expose_headers: Vec<String>,
+    /// How long browsers may cache preflight responses, in seconds (`CORS_MAX_AGE`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cors_max_age_seconds: Option<u32>,
+    /// Restrict which DEX offers Horizon serves (optional)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub offers_filtering: Option<OffersFilteringConfig>,
+    /// Exclude transactions, e.g. from sanctioned accounts, as decided by an
+    /// external filter service (optional)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub transaction_filtering: Option<TransactionFilteringConfig>,
+    /// Wait up to this many seconds for PostgreSQL to accept connections
+    /// before Horizon starts (optional)
+    ///
+    /// An init container retries with exponential backoff, so Horizon does
+    /// not crash-loop while a co-deployed database is still starting.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub db_wait_timeout_seconds: Option<u32>,
+    /// Hold the node until its database Secret exists and holds a valid
+    /// connection string, flagging the wait after this many seconds (optional)
+    ///
+    /// The operator sets the `WaitingForDatabase` condition and requeues
+    /// instead of creating the workload, e.g. while a provisioner produces the
+    /// Secret. The wait continues past the timeout with reason
+    /// `DatabaseReadyTimeout`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub database_ready_timeout_seconds: Option<u32>,
+}
+
+fn default_cors_allowed_headers() -> Vec<String> {
+    vec!["*".to_string()]
+}
+
+/// Filtering of the DEX offers Horizon serves
+///
+/// The rules are read from the `filter-rules.json` key of a ConfigMap,
+/// mounted at `/etc/horizon/filter-rules.json` (`FILTER_RULES_FILE`).
+/// Edits to the ConfigMap trigger a reconcile of the referencing nodes.
+///
+/// # Example
+///
+/// ```yaml
+/// offersFiltering:
+///   enabled: true
+///   filterRulesConfigmapRef: horizon-offer-filters
+///   updateIntervalSeconds: 60
+/// ```
+#[derive(Clone, Debug, Default, Deserialize, Serialize, JsonSchema, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct OffersFilteringConfig {
+    /// Apply the filter rules
+    #[serde(default)]
+    pub enabled: bool,
+    /// ConfigMap holding the filter rules JSON (key: filter-rules.json)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub filter_rules_configmap_ref: Option<String>,
+    /// How often Horizon re-reads the rules file, in seconds
+    /// (`FILTER_RULES_UPDATE_INTERVAL_SECONDS`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub update_interval_seconds: Option<u32>,
+}
+
+/// Compliance filtering of the transactions Horizon serves
+///
+/// Horizon asks the filter service at `filterServiceUrl` which transactions to
+/// exclude and caches each decision for `filterCacheTtlSeconds`.
+///
+/// # Example
+///
+/// ```yaml
+/// transactionFiltering:
+///   enabled: true
+///   filterServiceUrl: https://compliance.example.com/v1/filter
+///   filterCacheTtlSeconds: 300
+///   filterOnIngest: true
+/// ```
+#[derive(Clone, Debug, Deserialize, Serialize, JsonSchema, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct TransactionFilteringConfig {
+    /// Apply transaction filtering
+    #[serde(default)]
+    pub enabled: bool,
+    /// Filter service endpoint (`TRANSACTION_FILTER_SERVICE_URL`); must be HTTPS on Mainnet
+    #[serde(default)]
+    pub filter_service_url: String,
+    /// How long filter decisions are cached, in seconds (`TRANSACTION_FILTER_CACHE_TTL`)
+    #[serde(default = "default_filter_cache_ttl_seconds")]
+    pub filter_cache_ttl_seconds: u32,
+    /// Also drop filtered transactions during ingestion, not only from API
+    /// responses (`FILTER_TRANSACTIONS_ON_INGEST`)
+    #[serde(default)]
+    pub filter_on_ingest: bool,
+}
+
+/// Default cache lifetime of transaction filter decisions, in seconds
+pub const DEFAULT_FILTER_CACHE_TTL_SECONDS: u32 = 300;
+
+fn default_filter_cache_ttl_seconds() -> u32 {
+    DEFAULT_FILTER_CACHE_TTL_SECONDS
+}
+
+/// Captive Core ingestion for Horizon
+///
+/// `config` is written to the ConfigMap as `captive-core.cfg` and passed to
+/// Horizon via `CAPTIVE_CORE_CONFIG_PATH`; Horizon runs the stellar-core binary
+/// at `binaryPath` itself.
+#[derive(Clone, Debug, Deserialize, Serialize, JsonSchema, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct HorizonCaptiveCoreConfig {
+    /// Captive Core configuration (TOML)
+    pub config: String,
+    /// Path of the stellar-core binary inside the Horizon image
+    #[serde(default = "default_captive_core_binary_path")]
+    pub binary_path: String,
+}
+
+/// Default stellar-core binary path for Horizon captive core
+pub const DEFAULT_CAPTIVE_CORE_BINARY_PATH: &str = "/usr/bin/stellar-core";
+
+fn default_captive_core_binary_path() -> String {
+    DEFAULT_CAPTIVE_CORE_BINARY_PATH.to_string()
+}
+
+/// Container-level TLS for Horizon
+///
+/// The certificate Secret is mounted at `/etc/tls` and passed to Horizon via
+/// `TLS_CERT_FILE` / `TLS_KEY_FILE`; Horizon and its Service then listen on
+/// `port` instead of 8000.
+#[derive(Clone, Debug, Deserialize, Serialize, JsonSchema, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct HorizonTlsConfig {
+    /// Name of a `kubernetes.io/tls` Secret holding `tls.crt` and `tls.key`
+    pub cert_secret_ref: String,
+    /// HTTPS port (default: 443)
+    #[serde(default = "default_horizon_tls_port")]
+    pub port: u16,
+    /// Secret holding `ca.crt` used to verify client certificates (enables mTLS)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub client_ca_secret_ref: Option<String>,
+}
+
+/// PostgreSQL `sslmode`, in increasing order of strictness
+#[derive(
+    Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize, JsonSchema,
+)]
+pub enum PgSslMode {
+    /// Never use TLS
+    Disable,
+    /// Use TLS only if the server requires it
+    Allow,
+    /// Use TLS if the server supports it
+    #[default]
+    Prefer,
+    /// Always use TLS, without verifying the server certificate
+    Require,
+    /// Always use TLS and verify the server certificate against the CA
+    VerifyCa,
+    /// As `VerifyCa`, and also check the server host name
+    VerifyFull,
+}
+
+impl PgSslMode {
+    /// Value for libpq's `PGSSLMODE`
+    pub fn as_libpq(&self) -> &'static str {
+        match self {
+            PgSslMode::Disable => "disable",
+            PgSslMode::Allow => "allow",
+            PgSslMode::Prefer => "prefer",
+            PgSslMode::Require => "require",
+            PgSslMode::VerifyCa => "verify-ca",
+            PgSslMode::VerifyFull => "verify-full",
+        }
+    }
+
+    /// Whether the server certificate is verified (requires a CA certificate)
+    pub fn verifies_server(&self) -> bool {
+        *self >= PgSslMode::VerifyCa
+    }
+}
+
+impl std::fmt::Display for PgSslMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PgSslMode::Disable => write!(f, "Disable"),
+            PgSslMode::Allow => write!(f, "Allow"),
+            PgSslMode::Prefer => write!(f, "Prefer"),
+            PgSslMode::Require => write!(f, "Require"),
+            PgSslMode::VerifyCa => write!(f, "VerifyCa"),
+            PgSslMode::VerifyFull => write!(f, "VerifyFull"),
+        }
+    }
+}
+
+/// TLS for Horizon's PostgreSQL connection
+///
+/// `mode` is passed as `PGSSLMODE`. From `VerifyCa` on, `ca.crt` of
+/// `caCertSecretRef` is mounted at `/etc/ssl/pg-ca.crt` and passed as
+/// `PGSSLROOTCERT`. A client certificate (`tls.crt` of `clientCertSecretRef`)
+/// and key (`tls.key` of `clientKeySecretRef`) are mounted next to it and
+/// passed as `PGSSLCERT` / `PGSSLKEY`.
+#[derive(Clone, Debug, Default, Deserialize, Serialize, JsonSchema, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct DatabaseSslConfig {
+    /// SSL mode (default: Prefer)
+    #[serde(default)]
+    pub mode: PgSslMode,
+    /// Secret holding `ca.crt`; required for `VerifyCa` and `VerifyFull`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ca_cert_secret_ref: Option<String>,
+    /// Secret holding the client certificate as `tls.crt`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub client_cert_secret_ref: Option<String>,
+    /// Secret holding the client private key as `tls.key`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub client_key_secret_ref: Option<String>,
+}
+
+/// Default HTTPS port for container-level Horizon TLS
+pub const DEFAULT_HORIZON_TLS_PORT: u16 = 443;
+
+fn default_horizon_tls_port() -> u16 {
+    DEFAULT_HORIZON_TLS_PORT
+}
+
+/// Default Horizon order book cache depth
+pub const DEFAULT_ORDER_BOOK_CACHE_DEPTH: u32 = 20;
+
+/// Largest order book cache depth Horizon accepts
+pub const MAX_ORDER_BOOK_CACHE_DEPTH: u32 = 200;
+
+/// Experimental ingestion settings for Horizon (Protocol 21)
+#[derive(Clone, Debug, Default, Deserialize, Serialize, JsonSchema, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct ExperimentalIngestionConfig {
+    /// Enable ingestion filtering (requires `filterRulesFile`)
+    #[serde(default)]
+    pub enable_filtering: bool,
+    /// Ingestion filter rules (JSON), written to the ConfigMap as `ingestion-filter-rules.json`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub filter_rules_file: Option<String>,
+    /// Ingest claimable balances
+    #[serde(default)]
+    pub enable_claimable_balances: bool,
+    /// Ingest liquidity pools
+    #[serde(default)]
+    pub enable_liquidity_pools: bool,
+    /// Ingest Soroban contract events
+    #[serde(default)]
+    pub enable_soroban_events: bool,
+}
+
+fn default_true() -> bool {
+    true
+}
+
+fn default_ingest_workers() -> u32 {
+    1
+}
+
+/// Reference to another StellarNode in the cluster
+#[derive(Clone, Debug, Deserialize, Serialize, JsonSchema, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct StellarNodeRef {
+    /// Name of the referenced StellarNode
+    pub name: String,
+    /// Namespace of the referenced StellarNode (defaults to the referencing node's namespace)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub namespace: Option<String>,
+}
+
+impl StellarNodeRef {
+    /// Cluster-internal FQDN of the referenced node's Service
+    pub fn service_fqdn(&self, default_namespace: &str) -> String {
+        let namespace = self.namespace.as_deref().unwrap_or(default_namespace);
+        format!("{}.{}.svc.cluster.local", self.name, namespace)
+    }
+
+    /// Stellar Core HTTP endpoint of the referenced validator
+    pub fn core_http_url(&self, default_namespace: &str) -> String {
+        format!("http://{}:11626", self.service_fqdn(default_namespace))
+    }
+}
+
+/// Captive Core configuration for Soroban RPC
+///
+/// Structured configuration for Captive Core, which is used by Soroban RPC nodes
+/// to stream ledger data from the Stellar network.
+///
+/// This provides a type-safe alternative to raw TOML strings, ensuring configuration
+/// correctness at compile time and runtime.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use stellar_k8s::crd::CaptiveCoreConfig;
+///
+/// let config = CaptiveCoreConfig {
 ///     network_passphrase: None, // Will use network default
 ///     history_archive_urls: vec![
 ///         "https://history.stellar.org/prd/core-live/core_live_001".to_string(),
@@ -500,39 +1838,274 @@ pub struct CaptiveCoreConfig {
 ///     }),
 ///     enable_preflight: true,
 ///     max_events_per_request: 10000,
+///     contract_code_cache_size: None,
+///     contract_code_cache_ttl_seconds: None,
+///     contract_instance_cache_size: None,
+///     debug_rpc_max_body_log_bytes: None,
+///     debug_rpc: false,
+///     persistence_backend: Default::default(),
+///     database_secret_ref: None,
+///     run_migration_at_startup: false,
+///     archival_storage: None,
+///     tx_concurrency: None,
+///     rpc_endpoint: None,
+///     friendly_rpc_url: None,
+///     tx_queue_size: None,
+///     fee_config: None,
+///     stellar_core_ref: None,
 /// };
 /// ```
 #[derive(Clone, Debug, Deserialize, Serialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
-pub struct SorobanConfig {
-    /// Stellar Core endpoint URL
-    pub stellar_core_url: String,
+pub struct SorobanConfig {
+    /// Stellar Core endpoint URL
+    /// May be left empty when `stellarCoreRef` is set
+    #[serde(default)]
+    pub stellar_core_url: String,
+
+    /// Reference to a sibling validator StellarNode; resolved to its
+    /// cluster-internal FQDN and takes precedence over `stellarCoreUrl`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stellar_core_ref: Option<StellarNodeRef>,
+
+    /// Captive Core configuration (TOML format)
+    ///
+    /// **DEPRECATED**: Use `captive_core_structured_config` instead.
+    /// This field is maintained for backward compatibility only.
+    #[deprecated(
+        since = "0.2.0",
+        note = "Use captive_core_structured_config for type-safe configuration"
+    )]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub captive_core_config: Option<String>,
+
+    /// Structured Captive Core configuration
+    ///
+    /// This is the recommended way to configure Captive Core.
+    /// If both this and `captive_core_config` are provided, this field takes precedence.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub captive_core_structured_config: Option<CaptiveCoreConfig>,
+
+    /// Enable transaction simulation preflight
+    #[serde(default = "default_true")]
+    pub enable_preflight: bool,
+
+    /// Maximum number of events to return per request
+    #[serde(default = "default_max_events")]
+    pub max_events_per_request: u32,
+
+    /// Custom resource fee schedule used for local simulation
+    /// Only allowed on Testnet and Futurenet
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fee_config: Option<SorobanFeeConfig>,
+
+    /// Transactions buffered for submission before new ones are rejected (`TX_QUEUE_SIZE`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tx_queue_size: Option<u32>,
+
+    /// Transaction submissions processed concurrently (`TX_CONCURRENCY`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tx_concurrency: Option<u32>,
+
+    /// Compiled contract code entries kept in memory (`CONTRACT_CODE_CACHE_SIZE`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub contract_code_cache_size: Option<u32>,
+
+    /// Seconds an unused contract code entry stays cached (`CONTRACT_CODE_CACHE_TTL`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub contract_code_cache_ttl_seconds: Option<u32>,
+
+    /// Contract instance entries kept in memory (`CONTRACT_INSTANCE_CACHE_SIZE`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub contract_instance_cache_size: Option<u32>,
+
+    /// Path Soroban RPC serves JSON-RPC on, for proxies that route a sub-path
+    /// such as "/soroban/rpc" (`RPC_ENDPOINT`, default: "/")
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rpc_endpoint: Option<String>,
+
+    /// Externally advertised RPC URL, including any sub-path
+    /// (e.g. "https://example.org/soroban/rpc")
+    ///
+    /// Reported in `status.endpoint` instead of the internal Service URL.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub friendly_rpc_url: Option<String>,
+
+    /// Soroban state archival storage (Protocol 21+)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub archival_storage: Option<ArchivalStorageConfig>,
+
+    /// Event and transaction store (default: SQLite on the data volume)
+    #[serde(default)]
+    pub persistence_backend: PersistenceBackend,
+
+    /// Secret holding the PostgreSQL connection string under `DATABASE_URL`;
+    /// required when `persistenceBackend` is PostgreSQL
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub database_secret_ref: Option<String>,
+
+    /// Run the database migration in an init container before Soroban RPC
+    /// starts (PostgreSQL only)
+    #[serde(default)]
+    pub run_migration_at_startup: bool,
+
+    /// Log full JSON-RPC request and response payloads (`RPC_DEBUG_LOG`)
+    ///
+    /// Payloads include users' transactions, so this is rejected on Mainnet
+    /// unless `spec.allowDebugOnMainnet` is set.
+    #[serde(default)]
+    pub debug_rpc: bool,
+
+    /// Bytes of each payload logged when `debugRpc` is on
+    /// (`RPC_DEBUG_MAX_BODY_BYTES`, default: 4096)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub debug_rpc_max_body_log_bytes: Option<u32>,
+}
+
+impl SorobanConfig {
+    /// Effective JSON-RPC path
+    pub fn rpc_path(&self) -> &str {
+        self.rpc_endpoint.as_deref().unwrap_or("/")
+    }
+}
+
+/// Soroban RPC event and transaction store
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub enum PersistenceBackend {
+    /// SQLite database at `/data/soroban.db` (`DATABASE_PATH`)
+    #[default]
+    SQLite,
+    /// PostgreSQL from `databaseSecretRef` (`DATABASE_URL`)
+    PostgreSQL,
+}
+
+impl std::fmt::Display for PersistenceBackend {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PersistenceBackend::SQLite => write!(f, "SQLite"),
+            PersistenceBackend::PostgreSQL => write!(f, "PostgreSQL"),
+        }
+    }
+}
+
+/// Storage backend for archived Soroban state
+///
+/// # Example
+///
+/// ```yaml
+/// archivalStorage:
+///   backend: S3
+///   bucketName: soroban-archive
+///   credentialsSecretRef: soroban-archive-credentials
+///   restorationMaxBytesPerLedger: 1048576
+/// ```
+#[derive(Clone, Debug, Default, Deserialize, Serialize, JsonSchema, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct ArchivalStorageConfig {
+    /// Where archived state is kept
+    #[serde(default)]
+    pub backend: ArchivalBackend,
+    /// Bucket holding archived state (required for S3 and GCS)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bucket_name: Option<String>,
+    /// Secret with the bucket credentials
+    ///
+    /// S3 reads the `AWS_ACCESS_KEY_ID` and `AWS_SECRET_ACCESS_KEY` keys; GCS
+    /// mounts the `credentials.json` key as the service account key.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub credentials_secret_ref: Option<String>,
+    /// Most bytes of archived state restored per ledger
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub restoration_max_bytes_per_ledger: Option<u64>,
+    /// Ledger entries scanned per ledger for eviction
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub eviction_scan_size: Option<u32>,
+}
+
+/// Soroban state archival backend
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub enum ArchivalBackend {
+    /// Archived state stays on the node's data volume
+    #[default]
+    Disk,
+    /// Amazon S3 (or an S3-compatible store)
+    S3,
+    /// Google Cloud Storage
+    #[serde(rename = "GCS")]
+    Gcs,
+}
+
+impl std::fmt::Display for ArchivalBackend {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ArchivalBackend::Disk => write!(f, "disk"),
+            ArchivalBackend::S3 => write!(f, "s3"),
+            ArchivalBackend::Gcs => write!(f, "gcs"),
+        }
+    }
+}
+
+/// Soroban resource fee schedule for transaction simulation
+///
+/// Unset fields keep the network's fee schedule.
+#[derive(Clone, Debug, Default, Deserialize, Serialize, JsonSchema, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct SorobanFeeConfig {
+    /// Fee per instruction increment (10,000 instructions)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fee_per_instruction: Option<u64>,
 
-    /// Captive Core configuration (TOML format)
-    ///
-    /// **DEPRECATED**: Use `captive_core_structured_config` instead.
-    /// This field is maintained for backward compatibility only.
-    #[deprecated(
-        since = "0.2.0",
-        note = "Use captive_core_structured_config for type-safe configuration"
-    )]
+    /// Fee per ledger entry read
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub captive_core_config: Option<String>,
+    pub fee_per_read_entry: Option<u64>,
 
-    /// Structured Captive Core configuration
-    ///
-    /// This is the recommended way to configure Captive Core.
-    /// If both this and `captive_core_config` are provided, this field takes precedence.
+    /// Fee per ledger entry written
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub captive_core_structured_config: Option<CaptiveCoreConfig>,
+    pub fee_per_write_entry: Option<u64>,
 
-    /// Enable transaction simulation preflight
-    #[serde(default = "default_true")]
-    pub enable_preflight: bool,
+    /// Fee per byte read from the ledger
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fee_per_read_byte: Option<u64>,
 
-    /// Maximum number of events to return per request
-    #[serde(default = "default_max_events")]
-    pub max_events_per_request: u32,
+    /// Fee per byte written to the ledger
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fee_per_write_byte: Option<u64>,
+}
+
+impl SorobanFeeConfig {
+    /// Configured fees as (camelCase field, environment variable, value)
+    pub fn entries(&self) -> Vec<(&'static str, &'static str, u64)> {
+        [
+            (
+                "feePerInstruction",
+                "FEE_PER_INSTRUCTION_INCREMENT",
+                self.fee_per_instruction,
+            ),
+            (
+                "feePerReadEntry",
+                "FEE_PER_READ_ENTRY",
+                self.fee_per_read_entry,
+            ),
+            (
+                "feePerWriteEntry",
+                "FEE_PER_WRITE_ENTRY",
+                self.fee_per_write_entry,
+            ),
+            (
+                "feePerReadByte",
+                "FEE_PER_READ_BYTE",
+                self.fee_per_read_byte,
+            ),
+            (
+                "feePerWriteByte",
+                "FEE_PER_WRITE_BYTE",
+                self.fee_per_write_byte,
+            ),
+        ]
+        .into_iter()
+        .filter_map(|(field, env, value)| value.map(|v| (field, env, v)))
+        .collect()
+    }
 }
 
 /// External database configuration for managed Postgres databases
@@ -576,6 +2149,184 @@ pub struct SecretKeyRef {
     pub key: String,
 }
 
+/// Configuration for the Service fronting the node
+#[derive(Clone, Debug, Default, Deserialize, Serialize, JsonSchema, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ServiceConfig {
+    /// Enable topology-aware routing so in-cluster clients prefer endpoints in
+    /// their own zone, reducing cross-AZ traffic. Only applies to Horizon and
+    /// SorobanRpc, which run multiple interchangeable replicas.
+    #[serde(default)]
+    pub topology_aware: bool,
+
+    /// Route all requests from a client IP to the same pod (default: None)
+    #[serde(default)]
+    pub session_affinity: SessionAffinity,
+
+    /// How long a ClientIP affinity sticks, in seconds (1-86400, default: 10800)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub session_affinity_timeout_seconds: Option<i32>,
+
+    /// Service type (default: ClusterIP)
+    #[serde(default, rename = "type")]
+    pub service_type: ServiceType,
+
+    /// Route external traffic through any node (`Cluster`) or only to pods on
+    /// the receiving node, preserving the client source IP (`Local`).
+    /// Requires a NodePort or LoadBalancer service; `Local` also implies
+    /// `sessionAffinity: ClientIP` unless set otherwise.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub external_traffic_policy: Option<ExternalTrafficPolicy>,
+
+    /// Node port the load balancer health-checks nodes on with the `Local`
+    /// policy (LoadBalancer only; allocated by Kubernetes when unset)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub health_check_node_port: Option<i32>,
+}
+
+/// Kubernetes Service type for the node's Service
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub enum ServiceType {
+    #[default]
+    #[serde(rename = "ClusterIP")]
+    ClusterIp,
+    NodePort,
+    LoadBalancer,
+}
+
+impl std::fmt::Display for ServiceType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ServiceType::ClusterIp => write!(f, "ClusterIP"),
+            ServiceType::NodePort => write!(f, "NodePort"),
+            ServiceType::LoadBalancer => write!(f, "LoadBalancer"),
+        }
+    }
+}
+
+/// Longest ClientIP session affinity timeout Kubernetes accepts (one day)
+pub const MAX_SESSION_AFFINITY_TIMEOUT_SECONDS: i32 = 86400;
+
+/// Service session affinity mode
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub enum SessionAffinity {
+    /// Requests are balanced across pods
+    #[default]
+    None,
+    /// Requests from the same client IP go to the same pod
+    #[serde(rename = "ClientIP")]
+    ClientIp,
+}
+
+impl std::fmt::Display for SessionAffinity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SessionAffinity::None => write!(f, "None"),
+            SessionAffinity::ClientIp => write!(f, "ClientIP"),
+        }
+    }
+}
+
+/// Alerting rules rendered into the node's `PrometheusRule`
+///
+/// # Example
+///
+/// ```yaml
+/// prometheusRules:
+///   enableDefaultRules: true
+///   extraRules:
+///     - alert: HorizonSlowIngestion
+///       expr: stellar_node_ingestion_lag{name="horizon-1"} > 50
+///       for: 15m
+///       labels:
+///         severity: warning
+/// ```
+#[derive(Clone, Debug, Default, Deserialize, Serialize, JsonSchema, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct PrometheusRulesConfig {
+    /// Include the built-in Stellar alerts for this node type
+    #[serde(default = "default_true")]
+    pub enable_default_rules: bool,
+
+    /// User-defined alerts appended after the built-in ones
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub extra_rules: Vec<AlertRule>,
+}
+
+/// Prometheus Operator `ServiceMonitor` generated for the node
+///
+/// # Example
+///
+/// ```yaml
+/// monitoring:
+///   scrapeInterval: 2m
+///   scrapeTimeout: 30s
+/// ```
+#[derive(Clone, Debug, Deserialize, Serialize, JsonSchema, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct MonitoringConfig {
+    /// Generate the `ServiceMonitor` (default: true)
+    #[serde(default = "default_true")]
+    pub enabled: bool,
+
+    /// How often Prometheus scrapes the node (e.g. "30s", "2m")
+    /// Defaults to the Prometheus global scrape interval
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scrape_interval: Option<String>,
+
+    /// Scrape timeout, shorter than `scrapeInterval` (e.g. "10s")
+    /// Defaults to the Prometheus global scrape timeout
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scrape_timeout: Option<String>,
+}
+
+/// A single Prometheus alerting rule
+#[derive(Clone, Debug, Default, Deserialize, Serialize, JsonSchema, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct AlertRule {
+    /// Alert name (e.g. "HorizonSlowIngestion")
+    pub alert: String,
+
+    /// PromQL expression that fires the alert when it returns results
+    pub expr: String,
+
+    /// How long the expression must be true before firing (e.g. "5m")
+    #[serde(rename = "for", skip_serializing_if = "Option::is_none")]
+    pub for_duration: Option<String>,
+
+    /// Extra labels attached to the alert (e.g. severity)
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub labels: BTreeMap<String, String>,
+
+    /// Annotations such as summary and description
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub annotations: BTreeMap<String, String>,
+}
+
+/// HTTP hooks called around resource application
+///
+/// The operator POSTs a small JSON payload to each configured URL. Failures and
+/// timeouts are logged and never block reconciliation.
+#[derive(Clone, Debug, Default, Deserialize, Serialize, JsonSchema, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ReconcileHooksConfig {
+    /// URL called before the operator applies resources
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pre_apply_url: Option<String>,
+
+    /// URL called after resources have been applied and status updated
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub post_apply_url: Option<String>,
+
+    /// Request timeout in seconds (default: 5, max: 30)
+    #[serde(default = "default_hook_timeout_seconds")]
+    pub timeout_seconds: u32,
+}
+
+fn default_hook_timeout_seconds() -> u32 {
+    5
+}
+
 /// Ingress configuration for exposing Horizon or Soroban RPC over HTTPS
 ///
 /// Configures Kubernetes Ingress for external HTTP/HTTPS access to Horizon or Soroban RPC nodes.
@@ -671,6 +2422,9 @@ fn default_path_type() -> Option<String> {
     Some("Prefix".to_string())
 }
 
+/// Default `RPC_DEBUG_MAX_BODY_BYTES` for `sorobanConfig.debugRpc`
+pub const DEFAULT_DEBUG_RPC_MAX_BODY_LOG_BYTES: u32 = 4096;
+
 fn default_max_events() -> u32 {
     10000
 }
@@ -692,11 +2446,31 @@ fn default_max_events() -> u32 {
 ///     target_cpu_utilization_percentage: Some(70),
 ///     custom_metrics: vec![],
 ///     behavior: None,
+///     disable_on_weekend: false,
+///     weekend_min_replicas: None,
+///     weekend_timezone: None,
+///     cluster_capacity_aware: false,
+///     active_connections_metric: None,
+///     max_connections_per_replica: None,
+///     target_active_connections: None,
+///     cron_overrides: vec![],
+///     post_deploy_cooldown_seconds: None,
+///     ingest_lag_metric: None,
+///     max_ingest_lag_seconds: None,
+///     enabled: true,
+///     instance_weight_annotations: Default::default(),
+///     instance_weight_label: "node.kubernetes.io/instance-type".to_string(),
+///     custom_resource_metrics: vec![],
 /// };
 /// ```
 #[derive(Clone, Debug, Deserialize, Serialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct AutoscalingConfig {
+    /// Whether autoscaling is active (default: true)
+    /// Setting this to false removes any previously created HPA
+    #[serde(default = "default_autoscaling_enabled")]
+    pub enabled: bool,
+
     /// Minimum number of replicas
     pub min_replicas: i32,
 
@@ -716,6 +2490,276 @@ pub struct AutoscalingConfig {
     /// Behavior configuration for scale up/down
     #[serde(skip_serializing_if = "Option::is_none")]
     pub behavior: Option<ScalingBehavior>,
+
+    /// Relative capacity weights for heterogeneous node pools
+    ///
+    /// Keys are values of the `instanceWeightLabel` node label (e.g. "c6i.4xlarge"),
+    /// values are relative weights. Replicas prefer the heaviest pools when
+    /// scheduled, and each HPA scale-up period adds about one heaviest-pool
+    /// replica's worth of `status.effectiveCapacityUnits`.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub instance_weight_annotations: BTreeMap<String, u32>,
+
+    /// Node label whose value is looked up in `instanceWeightAnnotations`
+    /// (default: "node.kubernetes.io/instance-type")
+    #[serde(default = "default_instance_weight_label")]
+    pub instance_weight_label: String,
+
+    /// Metrics exposed by custom API resources (e.g. a queue CRD) to scale on
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub custom_resource_metrics: Vec<CustomResourceMetricSpec>,
+
+    /// Seconds to freeze scaling after a new version is deployed (default: 120)
+    ///
+    /// While the rolling restart warms up, the HPA's minReplicas is raised to
+    /// maxReplicas so it cannot scale in prematurely. 0 disables the freeze.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub post_deploy_cooldown_seconds: Option<u32>,
+
+    /// Metric reporting how far Stellar Core is behind on ingestion, in seconds
+    /// (e.g. "stellar_core_ingest_lag_seconds")
+    ///
+    /// Read through the custom metrics API, so it requires Prometheus Adapter.
+    /// While the highest value across the node's pods exceeds
+    /// `maxIngestLagSeconds`, the HPA's minReplicas is dropped to 1 to take
+    /// load off the catching-up Stellar Core backend, and the
+    /// `IngestLagThrottling` condition is set.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ingest_lag_metric: Option<String>,
+
+    /// Ingest lag, in seconds, above which Horizon scaling is throttled
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_ingest_lag_seconds: Option<u32>,
+
+    /// Scheduled windows that replace `minReplicas` / `maxReplicas` on the HPA,
+    /// e.g. to pre-scale ahead of a known traffic peak. The first listed window
+    /// containing the current time applies.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub cron_overrides: Vec<CronScalingOverride>,
+
+    /// Average active connections per replica to scale on (Horizon)
+    ///
+    /// Requires `maxConnectionsPerReplica`; keep it below that limit so new
+    /// replicas come up before Horizon starts rejecting requests.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub target_active_connections: Option<i64>,
+
+    /// Horizon's per-replica connection limit, rendered as `CONNECTION_LIMIT`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_connections_per_replica: Option<u32>,
+
+    /// External metric reporting active connections
+    /// (default: "nginx_active_connections"; e.g. "horizon_active_connections"
+    /// with a custom exporter)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub active_connections_metric: Option<String>,
+
+    /// Cap the HPA's maxReplicas at what the cluster's schedulable nodes can fit
+    ///
+    /// The cap is free allocatable memory (after all pods' requests) divided by
+    /// `resources.requests.memory`, plus the replicas already running. It never
+    /// drops below minReplicas and is reported in
+    /// `status.clusterCapacityLimitedMaxReplicas`. It is measured when the HPA
+    /// is created and whenever the spec changes.
+    #[serde(default)]
+    pub cluster_capacity_aware: bool,
+
+    /// Scale down over weekends, from Friday 18:00 to Monday 09:00 in
+    /// `weekendTimezone`, e.g. to save costs on test environments
+    ///
+    /// During the window the HPA's minReplicas is lowered to
+    /// `weekendMinReplicas`; with 0 the node is suspended as if
+    /// `spec.suspended` were set. `status.weekendSuspensionActive` reports it.
+    #[serde(default)]
+    pub disable_on_weekend: bool,
+
+    /// HPA minReplicas over the weekend (default: 0, suspending the node)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub weekend_min_replicas: Option<u32>,
+
+    /// IANA time zone the weekend window is evaluated in (default: UTC)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub weekend_timezone: Option<String>,
+}
+
+/// Upper bound for `spec.requeueJitterPercent` and `--requeue-jitter-percent`
+pub const MAX_REQUEUE_JITTER_PERCENT: u8 = 50;
+
+/// Default post-deploy autoscaling freeze, in seconds
+pub const DEFAULT_POST_DEPLOY_COOLDOWN_SECONDS: u32 = 120;
+
+/// Default external metric for connection-count-based scaling
+pub const DEFAULT_ACTIVE_CONNECTIONS_METRIC: &str = "nginx_active_connections";
+
+impl AutoscalingConfig {
+    /// Effective post-deploy cooldown in seconds
+    pub fn post_deploy_cooldown(&self) -> u32 {
+        self.post_deploy_cooldown_seconds
+            .unwrap_or(DEFAULT_POST_DEPLOY_COOLDOWN_SECONDS)
+    }
+
+    /// Effective external metric for `targetActiveConnections`
+    pub fn active_connections_metric(&self) -> &str {
+        self.active_connections_metric
+            .as_deref()
+            .unwrap_or(DEFAULT_ACTIVE_CONNECTIONS_METRIC)
+    }
+
+    /// Time zone the weekend window is evaluated in
+    pub fn weekend_time_zone(&self) -> Result<chrono_tz::Tz, String> {
+        self.weekend_timezone
+            .as_deref()
+            .unwrap_or("UTC")
+            .parse()
+            .map_err(|e| format!("{e}"))
+    }
+
+    /// Metric name and threshold of the ingest lag feedback loop, when configured
+    pub fn ingest_lag_threshold(&self) -> Option<(&str, u32)> {
+        match (&self.ingest_lag_metric, self.max_ingest_lag_seconds) {
+            (Some(metric), Some(max)) => Some((metric.as_str(), max)),
+            _ => None,
+        }
+    }
+}
+
+fn default_autoscaling_enabled() -> bool {
+    true
+}
+
+/// Scheduled override of the HPA replica range
+///
+/// Each time `cronExpression` fires, the HPA's bounds are set to
+/// `minReplicas`/`maxReplicas` for `durationMinutes`; the configured bounds are
+/// restored once the window closes.
+///
+/// # Example
+///
+/// ```yaml
+/// cronOverrides:
+///   - cronExpression: "0 14 * * 3"
+///     minReplicas: 10
+///     maxReplicas: 30
+///     durationMinutes: 120
+///     timezone: America/New_York
+/// ```
+#[derive(Clone, Debug, Deserialize, Serialize, JsonSchema, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct CronScalingOverride {
+    /// When the window opens (minute hour day-of-month month day-of-week)
+    pub cron_expression: String,
+    /// HPA minReplicas during the window
+    pub min_replicas: i32,
+    /// HPA maxReplicas during the window
+    pub max_replicas: i32,
+    /// How long the window stays open
+    pub duration_minutes: u32,
+    /// IANA time zone `cronExpression` is evaluated in (default: UTC)
+    #[serde(default = "default_cron_timezone")]
+    pub timezone: String,
+}
+
+fn default_cron_timezone() -> String {
+    "UTC".to_string()
+}
+
+impl CronScalingOverride {
+    /// Parsed schedule; five-field expressions use standard cron semantics
+    pub fn schedule(&self) -> Result<cron::Schedule, String> {
+        parse_cron_schedule(&self.cron_expression)
+    }
+
+    /// Time zone the schedule is evaluated in
+    pub fn time_zone(&self) -> Result<chrono_tz::Tz, String> {
+        self.timezone.parse().map_err(|e| format!("{e}"))
+    }
+
+    /// End of the window containing `now`, if the override is active
+    pub fn window_end(&self, now: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        let schedule = self.schedule().ok()?;
+        let time_zone = self.time_zone().ok()?;
+        let duration = chrono::Duration::minutes(i64::from(self.duration_minutes));
+
+        // Only the first start after `now - duration` can open a window that
+        // is still open at `now`
+        let earliest = (now - duration).with_timezone(&time_zone);
+        let start = schedule.after(&earliest).next()?.with_timezone(&Utc);
+        (start <= now).then_some(start + duration)
+    }
+}
+
+fn default_instance_weight_label() -> String {
+    "node.kubernetes.io/instance-type".to_string()
+}
+
+/// A metric exposed by a custom API resource, used as an HPA scaling signal
+///
+/// Without a `namespaceSelector` the metric is read from a single object in the
+/// node's namespace (HPA `Object` metric). With a `namespaceSelector` it is read
+/// from the external metrics API (HPA `External` metric) and the selector labels
+/// are used to pick the metric series.
+///
+/// # Example
+///
+/// ```yaml
+/// customResourceMetrics:
+///   - group: queues.example.com/v1
+///     resource: IngestQueue/horizon-ingest
+///     name: queue_depth
+///     targetType: AverageValue
+///     targetValue: "500"
+/// ```
+#[derive(Clone, Debug, Deserialize, Serialize, JsonSchema, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct CustomResourceMetricSpec {
+    /// API group and version of the resource (e.g. "queues.example.com/v1")
+    pub group: String,
+
+    /// Kind of the resource, optionally followed by `/<name>`
+    /// The object name defaults to the StellarNode name
+    pub resource: String,
+
+    /// Name of the metric
+    pub name: String,
+
+    /// Labels selecting the metric series; switches to an `External` metric
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub namespace_selector: Option<BTreeMap<String, String>>,
+
+    /// How `targetValue` is compared against the metric
+    pub target_type: TargetType,
+
+    /// Target value: a Kubernetes quantity for `Value`/`AverageValue`,
+    /// an integer percentage for `Utilization`
+    pub target_value: String,
+}
+
+impl CustomResourceMetricSpec {
+    /// Split `resource` into kind and optional object name
+    pub fn kind_and_name(&self) -> (&str, Option<&str>) {
+        match self.resource.split_once('/') {
+            Some((kind, name)) => (kind, Some(name)),
+            None => (self.resource.as_str(), None),
+        }
+    }
+}
+
+/// HPA metric target type
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, JsonSchema, PartialEq, Eq)]
+pub enum TargetType {
+    Value,
+    AverageValue,
+    Utilization,
+}
+
+impl std::fmt::Display for TargetType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TargetType::Value => write!(f, "Value"),
+            TargetType::AverageValue => write!(f, "AverageValue"),
+            TargetType::Utilization => write!(f, "Utilization"),
+        }
+    }
 }
 
 /// Scaling behavior configuration for HPA
@@ -845,6 +2889,8 @@ impl Condition {
 ///
 /// When enabled, creates a default deny-all ingress policy with explicit allow rules
 /// for peer-to-peer traffic (Validators), API access (Horizon/Soroban), and metrics.
+/// Without any namespace, pod selector or CIDR allow rules, API access is limited
+/// to pods in the node's own namespace.
 #[derive(Clone, Debug, Deserialize, Serialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct NetworkPolicyConfig {
@@ -871,6 +2917,18 @@ pub struct NetworkPolicyConfig {
     /// Namespace where Prometheus/monitoring stack runs (default: "monitoring")
     #[serde(default = "default_monitoring_namespace")]
     pub metrics_namespace: String,
+
+    /// Namespaces whose pods may reach the node's API ports (e.g. ["my-app"])
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub allow_from_namespaces: Vec<String>,
+
+    /// External CIDR blocks that may reach the node's API ports (e.g. ["203.0.113.0/24"])
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub allow_from_external_cidr: Vec<String>,
+
+    /// Deny all ingress, ignoring every allow rule (default: false)
+    #[serde(default)]
+    pub deny_all_ingress: bool,
 }
 
 fn default_monitoring_namespace() -> String {
@@ -886,6 +2944,9 @@ impl Default for NetworkPolicyConfig {
             allow_cidrs: Vec::new(),
             allow_metrics_scrape: true,
             metrics_namespace: default_monitoring_namespace(),
+            allow_from_namespaces: Vec::new(),
+            allow_from_external_cidr: Vec::new(),
+            deny_all_ingress: false,
         }
     }
 }
@@ -1779,6 +3840,71 @@ impl Default for CVEHandlingConfig {
     }
 }
 
+// ============================================================================
+// Container Image Override Configuration
+// ============================================================================
+
+/// Per-node container image override
+///
+/// Lets operators pin the image of a node independently of the default
+/// `stellar/<component>:<version>` mapping. When resolving the image,
+/// `digest` takes priority over `tag`, which takes priority over `spec.version`.
+#[derive(Clone, Debug, Default, Deserialize, Serialize, JsonSchema, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct ImageOverrideConfig {
+    /// Image repository (e.g. "registry.example.com/stellar/stellar-core")
+    /// Defaults to "stellar/stellar-core" for validators and "stellar/horizon" otherwise
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub repository: Option<String>,
+
+    /// Image tag, used instead of `spec.version` when set
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tag: Option<String>,
+
+    /// Image digest (e.g. "sha256:abc..."), takes priority over `tag`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub digest: Option<String>,
+
+    /// Image pull policy (Always, IfNotPresent, Never)
+    ///
+    /// Defaults to `Always` for channel tags such as `latest` or `stable` and
+    /// `IfNotPresent` for version tags and digests.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pull_policy: Option<String>,
+}
+
+// ============================================================================
+// Extra Volume Mounts
+// ============================================================================
+
+/// Additional mount of an operator-managed volume into the main container
+///
+/// Use `subPath` to mount a single file or subdirectory instead of the whole
+/// volume, e.g. mounting only the `stellar-core.cfg` key of the `config`
+/// volume at `/etc/stellar/stellar-core.cfg`. Note that Kubernetes does not
+/// propagate ConfigMap updates to subPath mounts; the pod must be restarted.
+#[derive(Clone, Debug, Deserialize, Serialize, JsonSchema, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct ExtraVolumeMount {
+    /// Name of the pod volume to mount (e.g. "config", "data")
+    pub name: String,
+
+    /// Absolute path inside the container
+    pub mount_path: String,
+
+    /// Path within the volume to mount instead of its root
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sub_path: Option<String>,
+
+    /// Mount read-only (default: true)
+    #[serde(default = "default_extra_mount_read_only")]
+    pub read_only: bool,
+}
+
+fn default_extra_mount_read_only() -> bool {
+    true
+}
+
 // ============================================================================
 // CloudNativePG Managed Database Configuration
 // ============================================================================
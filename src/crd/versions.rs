@@ -0,0 +1,42 @@
+//! Served and stored API versions of the StellarNode CRD
+//!
+//! `#[derive(CustomResource)]` generates a single version, so the full CRD is
+//! assembled here: every entry of [`SERVED_VERSIONS`] shares the derived
+//! schema and [`STORAGE_VERSION`] is the one persisted in etcd. The schemas are
+//! identical, so conversion uses the `None` strategy (the API server only
+//! rewrites `apiVersion`). A version with a diverging schema needs its own
+//! derived type and a conversion webhook. `make crdgen` prints the result.
+
+use k8s_openapi::apiextensions_apiserver::pkg::apis::apiextensions::v1::{
+    CustomResourceConversion, CustomResourceDefinition,
+};
+use kube::CustomResourceExt;
+
+use super::StellarNode;
+
+/// Version persisted in etcd
+pub const STORAGE_VERSION: &str = "v1alpha1";
+
+/// Versions clients may request, oldest first
+pub const SERVED_VERSIONS: &[&str] = &["v1alpha1", "v1beta1"];
+
+/// The StellarNode CRD with all served versions
+pub fn stellar_node_crd() -> CustomResourceDefinition {
+    let mut crd = StellarNode::crd();
+    let derived = crd.spec.versions.remove(0);
+    crd.spec.versions = SERVED_VERSIONS
+        .iter()
+        .map(|version| {
+            let mut entry = derived.clone();
+            entry.name = version.to_string();
+            entry.served = true;
+            entry.storage = *version == STORAGE_VERSION;
+            entry
+        })
+        .collect();
+    crd.spec.conversion = Some(CustomResourceConversion {
+        strategy: "None".to_string(),
+        webhook: None,
+    });
+    crd
+}
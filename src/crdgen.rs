@@ -0,0 +1,12 @@
+//! crdgen: print the StellarNode CRD, with every served version, as YAML
+//!
+//! `cargo run --bin crdgen > stellarnode-crd.yaml`
+
+use stellar_k8s::crd::stellar_node_crd;
+
+fn main() {
+    print!(
+        "{}",
+        serde_yaml::to_string(&stellar_node_crd()).expect("CRD serializes to YAML")
+    );
+}
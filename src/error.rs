@@ -75,6 +75,14 @@ pub enum Error {
     /// I/O error
     #[error("I/O error: {0}")]
     IoError(#[from] std::io::Error),
+
+    /// Kubernetes API call exceeded its deadline
+    #[error("Timeout: {0}")]
+    Timeout(String),
+
+    /// A templated config value failed to parse or render
+    #[error("Template syntax error: {0}")]
+    TemplateSyntaxError(String),
 }
 
 /// Result type alias for operator operations
@@ -85,7 +93,10 @@ impl Error {
     pub fn is_retriable(&self) -> bool {
         matches!(
             self,
-            Error::KubeError(_) | Error::FinalizerError(_) | Error::RemediationError(_)
+            Error::KubeError(_)
+                | Error::FinalizerError(_)
+                | Error::RemediationError(_)
+                | Error::Timeout(_)
         )
     }
 
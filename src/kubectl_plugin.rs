@@ -11,7 +11,7 @@ use clap::{Parser, Subcommand};
 use k8s_openapi::api::core::v1::Pod;
 use kube::{api::Api, Client, ResourceExt};
 
-use stellar_k8s::controller::check_node_health;
+use stellar_k8s::controller::{build_health_http_client, check_node_health};
 use stellar_k8s::crd::StellarNode;
 use stellar_k8s::error::{Error, Result};
 
@@ -385,11 +385,13 @@ async fn status(
         return Ok(());
     }
 
+    let http_client = build_health_http_client(None)?;
+
     match output {
         "json" => {
             let mut results = Vec::new();
             for node in nodes {
-                let health_result = check_node_health(client, &node, None).await?;
+                let health_result = check_node_health(client, &node, &http_client, false).await?;
                 results.push(serde_json::json!({
                     "name": node.name_any(),
                     "namespace": node.namespace().unwrap_or_else(|| "default".to_string()),
@@ -411,7 +413,7 @@ async fn status(
         "yaml" => {
             let mut results = Vec::new();
             for node in nodes {
-                let health_result = check_node_health(client, &node, None).await?;
+                let health_result = check_node_health(client, &node, &http_client, false).await?;
                 results.push(serde_json::json!({
                     "name": node.name_any(),
                     "namespace": node.namespace().unwrap_or_else(|| "default".to_string()),
@@ -450,7 +452,7 @@ async fn status(
             }
 
             for node in nodes {
-                let health_result = check_node_health(client, &node, None).await?;
+                let health_result = check_node_health(client, &node, &http_client, false).await?;
                 let name = node.name_any();
                 let node_type = format!("{:?}", node.spec.node_type);
                 let phase = get_node_phase(&node);
@@ -488,7 +490,9 @@ mod tests {
     use super::*;
     use kube::api::ObjectMeta;
     use stellar_k8s::controller::conditions::{CONDITION_STATUS_TRUE, CONDITION_TYPE_READY};
-    use stellar_k8s::crd::{Condition, NodeType, StellarNodeSpec, StellarNodeStatus};
+    use stellar_k8s::crd::{
+        Condition, ConfigTemplateMode, NodeType, StellarNodeSpec, StellarNodeStatus,
+    };
 
     #[allow(deprecated)]
     fn create_test_node(name: &str, namespace: &str, node_type: NodeType) -> StellarNode {
@@ -526,6 +530,7 @@ mod tests {
                 max_unavailable: None,
                 suspended: false,
                 alerting: false,
+                prometheus_rules: None,
                 database: None,
                 managed_database: None,
                 autoscaling: None,
@@ -539,6 +544,30 @@ mod tests {
                 dr_config: None,
                 topology_spread_constraints: None,
                 cve_handling: None,
+                image_override: None,
+                image_version_constraint: None,
+                extra_volume_mounts: vec![],
+                service: None,
+                hooks: None,
+                vault: None,
+                lifecycle: None,
+                container_security_context: None,
+                dev_mode: false,
+                allow_mainnet_parameter_override: false,
+                allow_debug_on_mainnet: false,
+                grafana_dashboard: None,
+                precheck_hook: None,
+                auto_detect_node_selector: false,
+                termination_message_policy: None,
+                termination_message_path: None,
+                emit_oom_event_on_kill: true,
+                mesh: None,
+                config_template_mode: ConfigTemplateMode::Static,
+                depends_on: vec![],
+                min_ready_replicas: None,
+                monitoring: None,
+                requeue_jitter_percent: None,
+                failure_policy: None,
                 resource_meta: None,
             },
             status: Some(StellarNodeStatus {
@@ -557,6 +586,22 @@ mod tests {
                 canary_ready_replicas: 0,
                 canary_version: None,
                 last_migrated_version: None,
+                weekend_suspension_active: false,
+                validator_mode: None,
+                consecutive_failure_count: 0,
+                quorum_slice_degraded_since: None,
+                cluster_capacity_limited_max_replicas: None,
+                hpa_max_replicas: None,
+                hpa_min_replicas: None,
+                effective_capacity_units: None,
+                storage_used_percent: None,
+                last_precheck_result: None,
+                detected_node_selector: None,
+                network_protocol_version: None,
+                last_oom_killed_at: None,
+                active_scaling_override: None,
+                public_key: None,
+                stellar_core_version: None,
             }),
         }
     }
@@ -1,8 +1,10 @@
 use clap::{Parser, Subcommand};
+use std::sync::atomic::AtomicBool;
 use std::sync::Arc;
+use stellar_k8s::telemetry::LogFormat;
 use stellar_k8s::{controller, crd::StellarNode, Error};
 use tracing::{info, Level};
-use tracing_subscriber::{fmt, prelude::*, EnvFilter};
+use tracing_subscriber::{prelude::*, EnvFilter};
 
 #[derive(Parser, Debug)]
 #[command(author, version, about, long_about = None)]
@@ -42,6 +44,75 @@ struct RunArgs {
     /// Custom scheduler name (used when --scheduler is set)
     #[arg(long, env = "SCHEDULER_NAME", default_value = "stellar-scheduler")]
     scheduler_name: String,
+
+    /// Maximum per-node jitter applied to reconcile requeue intervals, in percent (0-50)
+    #[arg(
+        long,
+        env = "REQUEUE_JITTER_PERCENT",
+        default_value_t = 10,
+        value_parser = clap::value_parser!(u8).range(0..=50)
+    )]
+    requeue_jitter_percent: u8,
+
+    /// Deadline for a single Kubernetes API call made while reconciling, in seconds
+    #[arg(
+        long,
+        env = "API_CALL_TIMEOUT_SECONDS",
+        default_value_t = controller::DEFAULT_API_CALL_TIMEOUT_SECONDS,
+        value_parser = clap::value_parser!(u64).range(1..)
+    )]
+    api_call_timeout_seconds: u64,
+
+    /// Most entries kept in each StellarNode's status.conditions; the oldest
+    /// beyond it are dropped, except Ready, Progressing and Degraded
+    #[arg(
+        long,
+        env = "MAX_CONDITIONS",
+        default_value_t = controller::conditions::DEFAULT_MAX_CONDITIONS as u32,
+        value_parser = clap::value_parser!(u32).range(3..)
+    )]
+    max_conditions: u32,
+
+    /// Webhook notified when a node keeps failing to reconcile (optional)
+    #[arg(long, env = "FAILURE_WEBHOOK_URL")]
+    failure_webhook_url: Option<String>,
+
+    /// Consecutive reconcile failures of a node before the webhook is notified
+    #[arg(
+        long,
+        env = "FAILURE_NOTIFICATION_THRESHOLD",
+        default_value_t = controller::DEFAULT_FAILURE_NOTIFICATION_THRESHOLD,
+        value_parser = clap::value_parser!(u32).range(1..)
+    )]
+    failure_notification_threshold: u32,
+
+    /// Minimum time between two failure notifications for the same node, in seconds
+    #[arg(
+        long,
+        env = "FAILURE_NOTIFICATION_INTERVAL_SECONDS",
+        default_value_t = controller::DEFAULT_FAILURE_NOTIFICATION_INTERVAL_SECONDS
+    )]
+    failure_notification_interval_seconds: u64,
+
+    /// Maximum concurrent reconciles of Validator nodes (0 = unlimited)
+    #[arg(long, env = "VALIDATOR_RECONCILE_CONCURRENCY", default_value_t = 0)]
+    validator_reconcile_concurrency: usize,
+
+    /// Maximum concurrent reconciles of Horizon nodes (0 = unlimited)
+    #[arg(long, env = "HORIZON_RECONCILE_CONCURRENCY", default_value_t = 0)]
+    horizon_reconcile_concurrency: usize,
+
+    /// Maximum concurrent reconciles of Soroban RPC nodes (0 = unlimited)
+    #[arg(long, env = "SOROBAN_RECONCILE_CONCURRENCY", default_value_t = 0)]
+    soroban_reconcile_concurrency: usize,
+
+    /// Check that a node's image exists in its registry before rolling it out
+    #[arg(long, env = "VERIFY_IMAGES")]
+    verify_images: bool,
+
+    /// Format of the operator's own logs (text or json)
+    #[arg(long, env = "LOG_FORMAT", default_value_t = LogFormat::Text)]
+    log_format: LogFormat,
 }
 
 #[derive(Parser, Debug)]
@@ -94,7 +165,7 @@ async fn run_operator(args: RunArgs) -> Result<(), Error> {
         .with_default_directive(Level::INFO.into())
         .from_env_lossy();
 
-    let fmt_layer = fmt::layer().with_target(true);
+    let fmt_layer = stellar_k8s::telemetry::fmt_layer(args.log_format, std::io::stdout);
 
     // Register the subscriber with both stdout logging and OpenTelemetry tracing
     let registry = tracing_subscriber::registry()
@@ -107,7 +178,10 @@ async fn run_operator(args: RunArgs) -> Result<(), Error> {
     if otel_enabled {
         let otel_layer = stellar_k8s::telemetry::init_telemetry(&registry);
         registry.with(otel_layer).init();
-        info!("OpenTelemetry tracing initialized");
+        info!(
+            "OpenTelemetry tracing initialized with sampler {:?}",
+            stellar_k8s::telemetry::TracesSamplerConfig::effective()
+        );
     } else {
         registry.init();
         info!("OpenTelemetry tracing disabled (OTEL_EXPORTER_OTLP_ENDPOINT not set)");
@@ -193,19 +267,32 @@ async fn run_operator(args: RunArgs) -> Result<(), Error> {
     };
     // Leader election configuration
     let _namespace = std::env::var("POD_NAMESPACE").unwrap_or_else(|_| "default".to_string());
-    let hostname = std::env::var("HOSTNAME").unwrap_or_else(|_| {
-        hostname::get()
-            .ok()
-            .and_then(|h| h.into_string().ok())
-            .unwrap_or_else(|| "unknown-host".to_string())
-    });
+    let hostname = std::env::var("STELLAR_OPERATOR_LEADER_ID")
+        .or_else(|_| std::env::var("HOSTNAME"))
+        .unwrap_or_else(|_| {
+            hostname::get()
+                .ok()
+                .and_then(|h| h.into_string().ok())
+                .unwrap_or_else(|| "unknown-host".to_string())
+        });
 
     info!("Leader election using holder ID: {}", hostname);
 
+    // Label all exported metrics with this instance before the registry is first used
+    #[cfg(feature = "metrics")]
+    controller::metrics::set_instance_id(&hostname);
+
     // TODO: Re-enable leader election once kube-leader-election version is aligned
     // let lease_name = "stellar-operator-leader";
     // let lock = LeaseLock::new(...);
 
+    controller::set_api_call_timeout(std::time::Duration::from_secs(
+        args.api_call_timeout_seconds,
+    ));
+
+    // Probe optional add-ons (e.g. metrics-server) once; dependent features degrade without them
+    let capabilities = controller::detect_capabilities(&client).await;
+
     // Create shared controller state
     let state = Arc::new(controller::ControllerState {
         client: client.clone(),
@@ -213,6 +300,24 @@ async fn run_operator(args: RunArgs) -> Result<(), Error> {
         operator_namespace: args.namespace.clone(),
         mtls_config: mtls_config.clone(),
         dry_run: args.dry_run,
+        requeue_jitter_percent: args.requeue_jitter_percent,
+        instance_id: hostname.clone(),
+        // Leader election is disabled, so every instance actively reconciles
+        is_leader: AtomicBool::new(true),
+        http_client: controller::build_health_http_client(mtls_config.as_ref())?,
+        capabilities,
+        failure_notifier: controller::FailureNotifier::new(
+            args.failure_webhook_url.clone(),
+            args.failure_notification_threshold,
+            std::time::Duration::from_secs(args.failure_notification_interval_seconds),
+        ),
+        reconcile_limiter: controller::ReconcileLimiter::new(
+            args.validator_reconcile_concurrency,
+            args.horizon_reconcile_concurrency,
+            args.soroban_reconcile_concurrency,
+        ),
+        image_verifier: args.verify_images.then(controller::ImageVerifier::new),
+        max_conditions: args.max_conditions as usize,
     });
 
     // Start the peer discovery manager
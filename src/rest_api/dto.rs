@@ -5,6 +5,7 @@
 use serde::{Deserialize, Serialize};
 
 use crate::crd::{NodeType, StellarNetwork, StellarNodeStatus};
+use crate::telemetry::TracesSamplerConfig;
 
 /// Response for listing nodes
 #[derive(Debug, Serialize)]
@@ -24,6 +25,9 @@ pub struct NodeSummary {
     pub phase: String,
     pub replicas: i32,
     pub ready_replicas: i32,
+    /// Validator public key, once derived from the seed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub public_key: Option<String>,
 }
 
 /// Response for a single node
@@ -39,6 +43,26 @@ pub struct NodeDetailResponse {
     pub created_at: Option<String>,
 }
 
+/// CPU and memory usage of a node's pods
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NodeUsageResponse {
+    pub name: String,
+    pub namespace: String,
+    pub cpu_millicores: u64,
+    pub memory_bytes: u64,
+    pub pods: Vec<PodUsage>,
+}
+
+/// Usage of a single pod, summed over its containers
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PodUsage {
+    pub name: String,
+    pub cpu_millicores: u64,
+    pub memory_bytes: u64,
+}
+
 /// Request to create a node (simplified)
 /// Reserved for future API endpoints
 #[allow(dead_code)]
@@ -59,6 +83,27 @@ pub struct HealthResponse {
     pub version: String,
 }
 
+/// Operator build information response
+#[derive(Debug, Serialize)]
+pub struct VersionResponse {
+    pub version: String,
+    pub git_sha: String,
+    pub build_date: String,
+    pub rust_version: String,
+}
+
+/// Operator instance information response
+#[derive(Debug, Serialize)]
+pub struct OperatorInfoResponse {
+    pub instance_id: String,
+    pub is_leader: bool,
+    pub version: String,
+    /// Whether OpenTelemetry trace export is enabled
+    pub tracing_enabled: bool,
+    /// Effective trace sampler configuration
+    pub traces_sampler: TracesSamplerConfig,
+}
+
 /// Error response
 #[derive(Debug, Serialize)]
 pub struct ErrorResponse {
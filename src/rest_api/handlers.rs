@@ -1,5 +1,6 @@
 //! HTTP handlers for the REST API
 
+use std::sync::atomic::Ordering;
 use std::sync::Arc;
 
 use axum::{
@@ -7,14 +8,21 @@ use axum::{
     http::StatusCode,
     Json,
 };
-use kube::{api::Api, ResourceExt};
-use tracing::{error, instrument};
+use kube::{
+    api::{Api, Patch, PatchParams},
+    ResourceExt,
+};
+use serde_json::json;
+use tracing::{error, info, instrument};
 
-use crate::controller::ControllerState;
-use crate::crd::StellarNode;
+use crate::controller::{set_reconciliation_paused, ControllerState};
+use crate::crd::{NodeType, StellarNode};
+use crate::error::Error;
+use crate::telemetry::TracesSamplerConfig;
 
 use super::dto::{
     ErrorResponse, HealthResponse, NodeDetailResponse, NodeListResponse, NodeSummary,
+    OperatorInfoResponse, VersionResponse,
 };
 
 /// Health check endpoint
@@ -26,6 +34,31 @@ pub async fn health() -> Json<HealthResponse> {
     })
 }
 
+/// Operator build information endpoint
+#[instrument]
+pub async fn version() -> Json<VersionResponse> {
+    Json(VersionResponse {
+        version: env!("CARGO_PKG_VERSION").to_string(),
+        git_sha: env!("GIT_SHA").to_string(),
+        build_date: env!("BUILD_DATE").to_string(),
+        rust_version: env!("RUST_VERSION").to_string(),
+    })
+}
+
+/// Information about the operator instance serving the request
+#[instrument(skip(state))]
+pub async fn operator_info(
+    State(state): State<Arc<ControllerState>>,
+) -> Json<OperatorInfoResponse> {
+    Json(OperatorInfoResponse {
+        instance_id: state.instance_id.clone(),
+        is_leader: state.is_leader.load(Ordering::Relaxed),
+        version: env!("CARGO_PKG_VERSION").to_string(),
+        tracing_enabled: std::env::var("OTEL_EXPORTER_OTLP_ENDPOINT").is_ok(),
+        traces_sampler: TracesSamplerConfig::effective(),
+    })
+}
+
 /// List all StellarNodes
 #[instrument(skip(state))]
 #[allow(deprecated)]
@@ -51,6 +84,7 @@ pub async fn list_nodes(
                         .unwrap_or_else(|| "Unknown".to_string()),
                     replicas: n.spec.replicas,
                     ready_replicas: n.status.as_ref().map(|s| s.ready_replicas).unwrap_or(0),
+                    public_key: n.status.as_ref().and_then(|s| s.public_key.clone()),
                 })
                 .collect();
 
@@ -67,6 +101,98 @@ pub async fn list_nodes(
     }
 }
 
+/// Refuse a mutating request because the API is served without mTLS
+#[instrument]
+pub async fn mtls_required() -> (StatusCode, Json<ErrorResponse>) {
+    (
+        StatusCode::FORBIDDEN,
+        Json(ErrorResponse::new(
+            "mtls_required",
+            "This endpoint is only available when the operator serves the API with mTLS",
+        )),
+    )
+}
+
+/// Promote a warm-standby validator to a validating node
+///
+/// Clears `spec.validatorConfig.standby`; the reconciler then regenerates the
+/// config and rolls the pods.
+#[instrument(skip(state), fields(name = %name, namespace = %namespace))]
+pub async fn promote_node(
+    State(state): State<Arc<ControllerState>>,
+    Path((namespace, name)): Path<(String, String)>,
+) -> Result<Json<NodeDetailResponse>, (StatusCode, Json<ErrorResponse>)> {
+    let api: Api<StellarNode> = Api::namespaced(state.client.clone(), &namespace);
+
+    let node = match api.get(&name).await {
+        Ok(node) => node,
+        Err(kube::Error::Api(e)) if e.code == 404 => {
+            return Err((
+                StatusCode::NOT_FOUND,
+                Json(ErrorResponse::new(
+                    "not_found",
+                    &format!("Node {namespace}/{name} not found"),
+                )),
+            ))
+        }
+        Err(e) => {
+            error!("Failed to get node {}/{}: {:?}", namespace, name, e);
+            return Err((
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse::new("get_failed", &e.to_string())),
+            ));
+        }
+    };
+
+    if node.spec.node_type != NodeType::Validator {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            Json(ErrorResponse::new(
+                "not_a_validator",
+                &format!("Node {namespace}/{name} is not a Validator"),
+            )),
+        ));
+    }
+    if !node
+        .spec
+        .validator_config
+        .as_ref()
+        .is_some_and(|c| c.standby)
+    {
+        return Err((
+            StatusCode::CONFLICT,
+            Json(ErrorResponse::new(
+                "not_standby",
+                &format!("Node {namespace}/{name} is not a standby validator"),
+            )),
+        ));
+    }
+
+    let patch = json!({
+        "spec": {
+            "validatorConfig": {
+                "standby": false
+            }
+        }
+    });
+    match api
+        .patch(&name, &PatchParams::default(), &Patch::Merge(&patch))
+        .await
+    {
+        Ok(node) => {
+            info!("Promoted standby validator {}/{}", namespace, name);
+            Ok(Json(node_detail(node)))
+        }
+        Err(e) => {
+            error!("Failed to promote node {}/{}: {:?}", namespace, name, e);
+            Err((
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse::new("promote_failed", &e.to_string())),
+            ))
+        }
+    }
+}
+
 /// Get a specific StellarNode
 #[instrument(skip(state), fields(name = %name, namespace = %namespace))]
 pub async fn get_node(
@@ -104,3 +230,61 @@ pub async fn get_node(
         }
     }
 }
+
+fn node_detail(node: StellarNode) -> NodeDetailResponse {
+    NodeDetailResponse {
+        name: node.name_any(),
+        namespace: node.namespace().unwrap_or_default(),
+        node_type: node.spec.node_type,
+        network: node.spec.network,
+        version: node.spec.version,
+        status: node.status.unwrap_or_default(),
+        created_at: node.metadata.creation_timestamp.map(|t| t.0.to_rfc3339()),
+    }
+}
+
+async fn set_paused(
+    state: &ControllerState,
+    namespace: &str,
+    name: &str,
+    paused: bool,
+) -> Result<Json<NodeDetailResponse>, (StatusCode, Json<ErrorResponse>)> {
+    match set_reconciliation_paused(&state.client, namespace, name, paused).await {
+        Ok(node) => Ok(Json(node_detail(node))),
+        Err(Error::KubeError(kube::Error::Api(e))) if e.code == 404 => Err((
+            StatusCode::NOT_FOUND,
+            Json(ErrorResponse::new(
+                "not_found",
+                &format!("Node {namespace}/{name} not found"),
+            )),
+        )),
+        Err(e) => {
+            error!(
+                "Failed to set reconciliation pause of {}/{}: {:?}",
+                namespace, name, e
+            );
+            Err((
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse::new("pause_failed", &e.to_string())),
+            ))
+        }
+    }
+}
+
+/// Pause reconciliation of a node by setting `stellar.org/pause-reconciliation`
+#[instrument(skip(state), fields(name = %name, namespace = %namespace))]
+pub async fn pause_node(
+    State(state): State<Arc<ControllerState>>,
+    Path((namespace, name)): Path<(String, String)>,
+) -> Result<Json<NodeDetailResponse>, (StatusCode, Json<ErrorResponse>)> {
+    set_paused(&state, &namespace, &name, true).await
+}
+
+/// Resume reconciliation of a node by removing `stellar.org/pause-reconciliation`
+#[instrument(skip(state), fields(name = %name, namespace = %namespace))]
+pub async fn resume_node(
+    State(state): State<Arc<ControllerState>>,
+    Path((namespace, name)): Path<(String, String)>,
+) -> Result<Json<NodeDetailResponse>, (StatusCode, Json<ErrorResponse>)> {
+    set_paused(&state, &namespace, &name, false).await
+}
@@ -3,7 +3,10 @@
 use std::net::SocketAddr;
 use std::sync::Arc;
 
-use axum::{routing::get, Router};
+use axum::{
+    routing::{get, post},
+    Router,
+};
 use axum_server::tls_rustls::RustlsConfig;
 use rustls::server::WebPkiClientVerifier;
 use rustls::RootCertStore;
@@ -17,6 +20,7 @@ use crate::{Error, MtlsConfig, Result};
 
 use super::custom_metrics;
 use super::handlers;
+use super::usage;
 
 /// Metrics endpoint handler
 async fn metrics_handler() -> String {
@@ -26,6 +30,41 @@ async fn metrics_handler() -> String {
     buffer
 }
 
+/// Routes that change nodes
+///
+/// Only a verified client certificate authenticates callers, so without mTLS
+/// these answer 403 instead of letting anyone who reaches the port act.
+fn admin_routes(mtls_enabled: bool) -> Router<Arc<ControllerState>> {
+    if !mtls_enabled {
+        return Router::new()
+            .route(
+                "/api/v1/nodes/:namespace/:name/promote",
+                post(handlers::mtls_required),
+            )
+            .route(
+                "/api/v1/nodes/:namespace/:name/pause",
+                post(handlers::mtls_required),
+            )
+            .route(
+                "/api/v1/nodes/:namespace/:name/resume",
+                post(handlers::mtls_required),
+            );
+    }
+    Router::new()
+        .route(
+            "/api/v1/nodes/:namespace/:name/promote",
+            post(handlers::promote_node),
+        )
+        .route(
+            "/api/v1/nodes/:namespace/:name/pause",
+            post(handlers::pause_node),
+        )
+        .route(
+            "/api/v1/nodes/:namespace/:name/resume",
+            post(handlers::resume_node),
+        )
+}
+
 /// Run the REST API server
 pub async fn run_server(
     state: Arc<ControllerState>,
@@ -33,11 +72,18 @@ pub async fn run_server(
 ) -> Result<()> {
     let app = Router::new()
         .route("/health", get(handlers::health))
+        .route("/version", get(handlers::version))
         .route("/metrics", get(metrics_handler))
+        .route("/api/v1/operator/info", get(handlers::operator_info))
         .route("/api/v1/nodes", get(handlers::list_nodes))
         .route("/api/v1/nodes/:namespace/:name", get(handlers::get_node))
+        .route(
+            "/api/v1/nodes/:namespace/:name/usage",
+            get(usage::get_node_usage),
+        )
         .route("/apis/custom.metrics.k8s.io/v1beta2/namespaces/:namespace/pods/:name/:metric", get(custom_metrics::get_pod_metric))
         .route("/apis/custom.metrics.k8s.io/v1beta2/namespaces/:namespace/stellarnodes.stellar.org/:name/:metric", get(custom_metrics::get_stellar_node_metric))
+        .merge(admin_routes(mtls_config.is_some()))
         .layer(TraceLayer::new_for_http())
         .with_state(state);
 
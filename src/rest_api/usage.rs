@@ -0,0 +1,180 @@
+//! Resource usage of a node's pods, read from metrics-server
+//!
+//! Without metrics-server (see [`crate::controller::ClusterCapabilities`]) the
+//! endpoint answers `503 metrics_unavailable` instead of failing with a 500.
+
+use std::sync::Arc;
+
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    Json,
+};
+use kube::api::ListParams;
+use serde_json::Value;
+use tracing::{error, instrument};
+
+use crate::controller::ControllerState;
+
+use super::dto::{ErrorResponse, NodeUsageResponse, PodUsage};
+
+/// Parse a CPU quantity (e.g. "250m", "1", "12345678n") into millicores
+pub fn parse_cpu_millicores(quantity: &str) -> Option<u64> {
+    let (number, scale) = match quantity.char_indices().last()? {
+        (i, 'n') => (&quantity[..i], 1e-6),
+        (i, 'u') => (&quantity[..i], 1e-3),
+        (i, 'm') => (&quantity[..i], 1.0),
+        _ => (quantity, 1e3),
+    };
+    let value: f64 = number.parse().ok()?;
+    (value >= 0.0).then(|| (value * scale).round() as u64)
+}
+
+/// Parse a memory quantity (e.g. "512Mi", "1G", "1048576") into bytes
+pub fn parse_memory_bytes(quantity: &str) -> Option<u64> {
+    const SUFFIXES: [(&str, f64); 10] = [
+        ("Ki", 1024.0),
+        ("Mi", 1024.0 * 1024.0),
+        ("Gi", 1024.0 * 1024.0 * 1024.0),
+        ("Ti", 1024.0 * 1024.0 * 1024.0 * 1024.0),
+        ("k", 1e3),
+        ("K", 1e3),
+        ("M", 1e6),
+        ("G", 1e9),
+        ("T", 1e12),
+        ("m", 1e-3),
+    ];
+    let (number, scale) = SUFFIXES
+        .iter()
+        .find_map(|(suffix, scale)| quantity.strip_suffix(suffix).map(|n| (n, *scale)))
+        .unwrap_or((quantity, 1.0));
+    let value: f64 = number.parse().ok()?;
+    (value >= 0.0).then(|| (value * scale).round() as u64)
+}
+
+/// Sum container usage per pod in a `PodMetricsList`
+pub fn pod_usage(list: &Value) -> Vec<PodUsage> {
+    let Some(items) = list.get("items").and_then(Value::as_array) else {
+        return Vec::new();
+    };
+
+    items
+        .iter()
+        .filter_map(|item| {
+            let name = item.pointer("/metadata/name")?.as_str()?.to_string();
+            let containers = item.get("containers")?.as_array()?;
+            let usage = |resource: &str, parse: fn(&str) -> Option<u64>| -> u64 {
+                containers
+                    .iter()
+                    .filter_map(|c| c.pointer(&format!("/usage/{resource}"))?.as_str())
+                    .filter_map(parse)
+                    .sum()
+            };
+            Some(PodUsage {
+                cpu_millicores: usage("cpu", parse_cpu_millicores),
+                memory_bytes: usage("memory", parse_memory_bytes),
+                name,
+            })
+        })
+        .collect()
+}
+
+fn metrics_unavailable() -> (StatusCode, Json<ErrorResponse>) {
+    (
+        StatusCode::SERVICE_UNAVAILABLE,
+        Json(ErrorResponse::new(
+            "metrics_unavailable",
+            "metrics-server is not available in this cluster; install it to report resource usage",
+        )),
+    )
+}
+
+/// CPU and memory usage of a StellarNode's pods
+#[instrument(skip(state), fields(name = %name, namespace = %namespace))]
+pub async fn get_node_usage(
+    State(state): State<Arc<ControllerState>>,
+    Path((namespace, name)): Path<(String, String)>,
+) -> Result<Json<NodeUsageResponse>, (StatusCode, Json<ErrorResponse>)> {
+    if !state.capabilities.metrics_server {
+        return Err(metrics_unavailable());
+    }
+
+    let lp = ListParams::default().labels(&format!("app.kubernetes.io/instance={name}"));
+    let request = kube::core::Request::new(format!(
+        "/apis/metrics.k8s.io/v1beta1/namespaces/{namespace}/pods"
+    ))
+    .list(&lp)
+    .map_err(|e| {
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ErrorResponse::new("usage_failed", &e.to_string())),
+        )
+    })?;
+
+    match state.client.request::<Value>(request).await {
+        Ok(list) => {
+            let pods = pod_usage(&list);
+            Ok(Json(NodeUsageResponse {
+                name,
+                namespace,
+                cpu_millicores: pods.iter().map(|p| p.cpu_millicores).sum(),
+                memory_bytes: pods.iter().map(|p| p.memory_bytes).sum(),
+                pods,
+            }))
+        }
+        // metrics-server was removed or is not ready since startup
+        Err(kube::Error::Api(e)) if e.code == 404 || e.code == 503 => Err(metrics_unavailable()),
+        Err(e) => {
+            error!("Failed to read usage for {}/{}: {:?}", namespace, name, e);
+            Err((
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse::new("usage_failed", &e.to_string())),
+            ))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_parse_cpu_millicores() {
+        assert_eq!(parse_cpu_millicores("250m"), Some(250));
+        assert_eq!(parse_cpu_millicores("2"), Some(2000));
+        assert_eq!(parse_cpu_millicores("12345678n"), Some(12));
+        assert_eq!(parse_cpu_millicores("1500u"), Some(2));
+        assert_eq!(parse_cpu_millicores("abc"), None);
+        assert_eq!(parse_cpu_millicores(""), None);
+    }
+
+    #[test]
+    fn test_parse_memory_bytes() {
+        assert_eq!(parse_memory_bytes("1Ki"), Some(1024));
+        assert_eq!(parse_memory_bytes("512Mi"), Some(512 * 1024 * 1024));
+        assert_eq!(parse_memory_bytes("1G"), Some(1_000_000_000));
+        assert_eq!(parse_memory_bytes("1048576"), Some(1_048_576));
+        assert_eq!(parse_memory_bytes("lots"), None);
+    }
+
+    #[test]
+    fn test_pod_usage_sums_containers() {
+        let list = json!({
+            "kind": "PodMetricsList",
+            "items": [{
+                "metadata": { "name": "horizon-1-abc" },
+                "containers": [
+                    { "name": "stellar-node", "usage": { "cpu": "250m", "memory": "512Mi" } },
+                    { "name": "sidecar", "usage": { "cpu": "5000000n", "memory": "16Mi" } }
+                ]
+            }]
+        });
+        let pods = pod_usage(&list);
+        assert_eq!(pods.len(), 1);
+        assert_eq!(pods[0].name, "horizon-1-abc");
+        assert_eq!(pods[0].cpu_millicores, 255);
+        assert_eq!(pods[0].memory_bytes, 528 * 1024 * 1024);
+        assert!(pod_usage(&json!({ "items": [] })).is_empty());
+    }
+}
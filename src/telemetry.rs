@@ -10,9 +10,218 @@ use opentelemetry_sdk::propagation::TraceContextPropagator;
 use opentelemetry_sdk::resource::Resource;
 use opentelemetry_sdk::runtime;
 use opentelemetry_sdk::trace::{Config, Sampler, SpanProcessor};
+use serde::Serialize;
 use std::env;
+use std::fmt;
+use std::str::FromStr;
+use tracing_subscriber::fmt::MakeWriter;
 use tracing_subscriber::{registry::LookupSpan, Layer};
 
+/// Environment variable overriding the service name traces are reported under
+pub const SERVICE_NAME_ENV: &str = "OTEL_SERVICE_NAME";
+
+/// Environment variable holding extra resource attributes, as
+/// `key1=value1,key2=value2` (e.g. `k8s.cluster.name=prod-eu,deployment.environment=production`)
+pub const RESOURCE_ATTRIBUTES_ENV: &str = "OTEL_RESOURCE_ATTRIBUTES";
+
+/// Service name used when none is configured
+pub const DEFAULT_SERVICE_NAME: &str = "stellar-operator";
+
+/// Build the resource attributes traces are attributed to
+///
+/// `service.name` is taken from `service_name`, then from a `service.name`
+/// entry in `attributes`, then [`DEFAULT_SERVICE_NAME`]. Malformed entries
+/// (without `=` or with an empty key) are skipped.
+pub fn resource_attributes(service_name: Option<&str>, attributes: Option<&str>) -> Vec<KeyValue> {
+    let mut extra: Vec<(String, String)> = attributes
+        .unwrap_or_default()
+        .split(',')
+        .filter_map(|pair| pair.split_once('='))
+        .map(|(k, v)| (k.trim().to_string(), v.trim().to_string()))
+        .filter(|(k, _)| !k.is_empty())
+        .collect();
+
+    let attribute_service_name = extra
+        .iter()
+        .position(|(k, _)| k == "service.name")
+        .map(|i| extra.remove(i).1);
+    let service_name = service_name
+        .map(str::trim)
+        .filter(|n| !n.is_empty())
+        .map(str::to_string)
+        .or(attribute_service_name)
+        .unwrap_or_else(|| DEFAULT_SERVICE_NAME.to_string());
+
+    let mut resource = vec![
+        KeyValue::new("service.name", service_name),
+        KeyValue::new("service.version", env!("CARGO_PKG_VERSION")),
+    ];
+    resource.extend(
+        extra
+            .into_iter()
+            .filter(|(k, _)| k != "service.version")
+            .map(|(k, v)| KeyValue::new(k, v)),
+    );
+    resource
+}
+
+/// Resource of the operator's tracer, configured from the environment
+pub fn operator_resource() -> Resource {
+    let service_name = env::var(SERVICE_NAME_ENV).ok();
+    let attributes = env::var(RESOURCE_ATTRIBUTES_ENV).ok();
+    Resource::new(resource_attributes(
+        service_name.as_deref(),
+        attributes.as_deref(),
+    ))
+}
+
+/// Output format of the operator's own logs
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum LogFormat {
+    /// Human-readable lines
+    #[default]
+    Text,
+    /// One JSON object per line, for structured log ingestion (Loki, ELK)
+    Json,
+}
+
+impl FromStr for LogFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "text" => Ok(LogFormat::Text),
+            "json" => Ok(LogFormat::Json),
+            other => Err(format!(
+                "unknown log format '{other}', expected 'text' or 'json'"
+            )),
+        }
+    }
+}
+
+impl fmt::Display for LogFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LogFormat::Text => write!(f, "text"),
+            LogFormat::Json => write!(f, "json"),
+        }
+    }
+}
+
+/// Build the stdout logging layer for the given format
+pub fn fmt_layer<S, W>(format: LogFormat, writer: W) -> Box<dyn Layer<S> + Send + Sync>
+where
+    S: tracing::Subscriber + for<'a> LookupSpan<'a> + Send + Sync,
+    W: for<'w> MakeWriter<'w> + Send + Sync + 'static,
+{
+    let layer = tracing_subscriber::fmt::layer()
+        .with_target(true)
+        .with_writer(writer);
+    match format {
+        LogFormat::Text => layer.boxed(),
+        LogFormat::Json => layer.json().boxed(),
+    }
+}
+
+/// Environment variable selecting the trace sampler
+pub const TRACES_SAMPLER_ENV: &str = "OTEL_TRACES_SAMPLER";
+
+/// Environment variable holding the sampler argument (ratio between 0.0 and 1.0)
+pub const TRACES_SAMPLER_ARG_ENV: &str = "OTEL_TRACES_SAMPLER_ARG";
+
+/// Sampling strategy applied to operator traces
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+pub enum SamplerType {
+    AlwaysOn,
+    AlwaysOff,
+    TraceIdRatioBased,
+    ParentBased,
+}
+
+impl SamplerType {
+    /// Parse a sampler name, accepting both the OpenTelemetry environment
+    /// names (`always_on`, `traceidratio`, `parentbased_traceidratio`, ...)
+    /// and the variant names used in this enum
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "always_on" | "alwayson" => Some(SamplerType::AlwaysOn),
+            "always_off" | "alwaysoff" => Some(SamplerType::AlwaysOff),
+            "traceidratio" | "traceidratiobased" => Some(SamplerType::TraceIdRatioBased),
+            "parentbased" | "parentbased_always_on" | "parentbased_traceidratio" => {
+                Some(SamplerType::ParentBased)
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Trace sampler configuration of the operator
+///
+/// Read from `OTEL_TRACES_SAMPLER` and `OTEL_TRACES_SAMPLER_ARG` rather than
+/// from the StellarNode CRD, since sampling applies to the operator process.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TracesSamplerConfig {
+    #[serde(rename = "type")]
+    pub type_: SamplerType,
+    /// Sampling ratio between 0.0 and 1.0 for `TraceIdRatioBased`, and for
+    /// the root sampler of `ParentBased`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub arg: Option<f64>,
+}
+
+impl Default for TracesSamplerConfig {
+    fn default() -> Self {
+        Self {
+            type_: SamplerType::AlwaysOn,
+            arg: None,
+        }
+    }
+}
+
+impl TracesSamplerConfig {
+    /// Build a config from raw sampler and argument values
+    ///
+    /// Returns `None` when the sampler name is missing or unknown. The
+    /// argument is clamped to 0.0–1.0 and ignored when not a number.
+    pub fn parse(sampler: Option<&str>, arg: Option<&str>) -> Option<Self> {
+        let type_ = SamplerType::parse(sampler?)?;
+        let arg = arg
+            .and_then(|a| a.trim().parse::<f64>().ok())
+            .filter(|a| a.is_finite())
+            .map(|a| a.clamp(0.0, 1.0));
+        Some(Self { type_, arg })
+    }
+
+    /// Read the sampler configuration from the environment
+    pub fn from_env() -> Option<Self> {
+        let sampler = env::var(TRACES_SAMPLER_ENV).ok();
+        let arg = env::var(TRACES_SAMPLER_ARG_ENV).ok();
+        Self::parse(sampler.as_deref(), arg.as_deref())
+    }
+
+    /// Effective sampler configuration, falling back to `AlwaysOn`
+    pub fn effective() -> Self {
+        Self::from_env().unwrap_or_default()
+    }
+
+    /// Build the OpenTelemetry sampler for this configuration
+    pub fn to_sampler(&self) -> Sampler {
+        match self.type_ {
+            SamplerType::AlwaysOn => Sampler::AlwaysOn,
+            SamplerType::AlwaysOff => Sampler::AlwaysOff,
+            SamplerType::TraceIdRatioBased => Sampler::TraceIdRatioBased(self.arg.unwrap_or(1.0)),
+            SamplerType::ParentBased => {
+                let root = match self.arg {
+                    Some(ratio) => Sampler::TraceIdRatioBased(ratio),
+                    None => Sampler::AlwaysOn,
+                };
+                Sampler::ParentBased(Box::new(root))
+            }
+        }
+    }
+}
+
 /// A span processor that scrubs sensitive information from span attributes
 #[derive(Debug)]
 struct ScrubbingProcessor {
@@ -84,10 +293,7 @@ where
     let otlp_endpoint = env::var("OTEL_EXPORTER_OTLP_ENDPOINT")
         .unwrap_or_else(|_| "http://localhost:4317".to_string());
 
-    let resource = Resource::new(vec![
-        KeyValue::new("service.name", "stellar-operator"),
-        KeyValue::new("service.version", env!("CARGO_PKG_VERSION")),
-    ]);
+    let resource = operator_resource();
 
     // Configure OTLP exporter
     // Note: We use grpc as default but it can be changed to http/protobuf if needed
@@ -110,7 +316,7 @@ where
         .with_config(
             Config::default()
                 .with_resource(resource)
-                .with_sampler(Sampler::AlwaysOn),
+                .with_sampler(TracesSamplerConfig::effective().to_sampler()),
         )
         .with_span_processor(scrubbing_processor)
         .build();
@@ -166,6 +372,181 @@ mod tests {
         }
     }
 
+    #[derive(Clone, Default)]
+    struct BufferWriter(Arc<Mutex<Vec<u8>>>);
+
+    impl std::io::Write for BufferWriter {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.0.lock().unwrap().extend_from_slice(buf);
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    impl<'a> MakeWriter<'a> for BufferWriter {
+        type Writer = BufferWriter;
+
+        fn make_writer(&'a self) -> Self::Writer {
+            self.clone()
+        }
+    }
+
+    fn log_line(format: LogFormat) -> String {
+        use tracing_subscriber::prelude::*;
+
+        let writer = BufferWriter::default();
+        let subscriber = tracing_subscriber::registry().with(fmt_layer(format, writer.clone()));
+        tracing::subscriber::with_default(subscriber, || {
+            tracing::info!(node = "validator-1", "reconciled");
+        });
+        let buf = writer.0.lock().unwrap().clone();
+        String::from_utf8(buf).unwrap()
+    }
+
+    fn attribute(resource: &[KeyValue], key: &str) -> Option<String> {
+        resource
+            .iter()
+            .find(|kv| kv.key.as_str() == key)
+            .map(|kv| kv.value.to_string())
+    }
+
+    #[test]
+    fn test_resource_defaults_service_name() {
+        let resource = resource_attributes(None, None);
+        assert_eq!(
+            attribute(&resource, "service.name").as_deref(),
+            Some(DEFAULT_SERVICE_NAME)
+        );
+        assert_eq!(
+            attribute(&resource, "service.version").as_deref(),
+            Some(env!("CARGO_PKG_VERSION"))
+        );
+        assert_eq!(resource.len(), 2);
+    }
+
+    #[test]
+    fn test_resource_carries_configured_name_and_attributes() {
+        let resource = resource_attributes(
+            Some("stellar-operator-eu"),
+            Some("k8s.cluster.name=prod-eu, deployment.environment=production,malformed,=x"),
+        );
+        assert_eq!(
+            attribute(&resource, "service.name").as_deref(),
+            Some("stellar-operator-eu")
+        );
+        assert_eq!(
+            attribute(&resource, "k8s.cluster.name").as_deref(),
+            Some("prod-eu")
+        );
+        assert_eq!(
+            attribute(&resource, "deployment.environment").as_deref(),
+            Some("production")
+        );
+        assert_eq!(resource.len(), 4);
+    }
+
+    #[test]
+    fn test_resource_service_name_from_attributes() {
+        let resource = resource_attributes(None, Some("service.name=from-attrs"));
+        assert_eq!(
+            attribute(&resource, "service.name").as_deref(),
+            Some("from-attrs")
+        );
+
+        let resource = resource_attributes(Some("from-env"), Some("service.name=from-attrs"));
+        assert_eq!(
+            attribute(&resource, "service.name").as_deref(),
+            Some("from-env")
+        );
+        assert_eq!(resource.len(), 2);
+    }
+
+    #[test]
+    fn test_log_format_parses() {
+        assert_eq!("text".parse::<LogFormat>(), Ok(LogFormat::Text));
+        assert_eq!("JSON".parse::<LogFormat>(), Ok(LogFormat::Json));
+        assert!("logfmt".parse::<LogFormat>().is_err());
+        assert_eq!(LogFormat::default(), LogFormat::Text);
+    }
+
+    #[test]
+    fn test_json_log_format_produces_json() {
+        let line = log_line(LogFormat::Json);
+        let json: serde_json::Value = serde_json::from_str(line.trim()).unwrap();
+        assert_eq!(json["level"], "INFO");
+        assert_eq!(json["fields"]["message"], "reconciled");
+        assert_eq!(json["fields"]["node"], "validator-1");
+    }
+
+    #[test]
+    fn test_text_log_format_is_not_json() {
+        let line = log_line(LogFormat::Text);
+        assert!(line.contains("reconciled"));
+        assert!(serde_json::from_str::<serde_json::Value>(line.trim()).is_err());
+    }
+
+    #[test]
+    fn test_sampler_config_parses_otel_names() {
+        let config = TracesSamplerConfig::parse(Some("traceidratio"), Some("0.25")).unwrap();
+        assert_eq!(config.type_, SamplerType::TraceIdRatioBased);
+        assert_eq!(config.arg, Some(0.25));
+
+        let config = TracesSamplerConfig::parse(Some("parentbased_always_on"), None).unwrap();
+        assert_eq!(config.type_, SamplerType::ParentBased);
+        assert_eq!(config.arg, None);
+
+        let config = TracesSamplerConfig::parse(Some("AlwaysOff"), None).unwrap();
+        assert_eq!(config.type_, SamplerType::AlwaysOff);
+    }
+
+    #[test]
+    fn test_sampler_config_rejects_unknown_sampler() {
+        assert_eq!(
+            TracesSamplerConfig::parse(Some("jaeger_remote"), None),
+            None
+        );
+        assert_eq!(TracesSamplerConfig::parse(None, Some("0.5")), None);
+    }
+
+    #[test]
+    fn test_sampler_arg_is_clamped() {
+        let config = TracesSamplerConfig::parse(Some("traceidratio"), Some("1.5")).unwrap();
+        assert_eq!(config.arg, Some(1.0));
+
+        let config = TracesSamplerConfig::parse(Some("traceidratio"), Some("-0.1")).unwrap();
+        assert_eq!(config.arg, Some(0.0));
+
+        let config = TracesSamplerConfig::parse(Some("traceidratio"), Some("abc")).unwrap();
+        assert_eq!(config.arg, None);
+    }
+
+    #[test]
+    fn test_sampler_config_builds_sampler() {
+        let config = TracesSamplerConfig {
+            type_: SamplerType::ParentBased,
+            arg: Some(0.1),
+        };
+        assert!(matches!(config.to_sampler(), Sampler::ParentBased(_)));
+        assert!(matches!(
+            TracesSamplerConfig::default().to_sampler(),
+            Sampler::AlwaysOn
+        ));
+    }
+
+    #[test]
+    fn test_sampler_config_serializes_type() {
+        let config = TracesSamplerConfig {
+            type_: SamplerType::TraceIdRatioBased,
+            arg: Some(0.5),
+        };
+        let json = serde_json::to_value(&config).unwrap();
+        assert_eq!(json["type"], "TraceIdRatioBased");
+        assert_eq!(json["arg"], 0.5);
+    }
+
     #[test]
     fn test_scrubbing_processor() {
         let mock_inner = MockProcessor::new();
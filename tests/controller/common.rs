@@ -0,0 +1,51 @@
+//! StellarNode fixtures shared by the controller integration tests
+//!
+//! The spec builders return JSON so a test can set the fields it cares about
+//! (`spec["horizonConfig"]["enableIngest"] = json!(true)`) before passing the
+//! spec to `node`.
+
+#![allow(dead_code)]
+
+use serde_json::{json, Value};
+use stellar_k8s::crd::{StellarNode, StellarNodeSpec};
+
+/// Build a minimal StellarNode named `test-node` in `stellar` from a JSON spec
+pub fn node(spec: Value) -> StellarNode {
+    let spec: StellarNodeSpec = serde_json::from_value(spec).expect("valid spec");
+    let mut node = StellarNode::new("test-node", spec);
+    node.metadata.namespace = Some("stellar".to_string());
+    node
+}
+
+/// Spec of a Testnet validator
+pub fn validator() -> Value {
+    json!({
+        "nodeType": "Validator",
+        "network": "Testnet",
+        "version": "v21.0.0",
+        "validatorConfig": { "seedSecretRef": "validator-seed" },
+    })
+}
+
+/// Spec of a Testnet Horizon
+pub fn horizon() -> Value {
+    json!({
+        "nodeType": "Horizon",
+        "network": "Testnet",
+        "version": "v2.31.0",
+        "horizonConfig": {
+            "databaseSecretRef": "horizon-db",
+            "stellarCoreUrl": "http://core:11626",
+        },
+    })
+}
+
+/// Spec of a Testnet Soroban RPC node
+pub fn soroban() -> Value {
+    json!({
+        "nodeType": "SorobanRpc",
+        "network": "Testnet",
+        "version": "v21.0.0",
+        "sorobanConfig": { "stellarCoreUrl": "http://core:11626" },
+    })
+}
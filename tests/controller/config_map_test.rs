@@ -0,0 +1,633 @@
+//! Tests for `build_config_map` across all node types and networks
+
+mod common;
+
+use common::node;
+use serde_json::{json, Value};
+use stellar_k8s::controller::build_config_map;
+use stellar_k8s::crd::{StellarNetwork, StellarNode, StellarNodeStatus, ValidatorMode};
+
+/// `spec` with the top-level fields of `extra` replaced
+fn with(mut spec: Value, extra: Value) -> Value {
+    for (k, v) in extra.as_object().expect("object") {
+        spec[k] = v.clone();
+    }
+    spec
+}
+
+fn validator(quorum_set: Option<&str>) -> StellarNode {
+    let mut spec = common::validator();
+    spec["validatorConfig"]["quorumSet"] = json!(quorum_set);
+    node(spec)
+}
+
+fn horizon() -> StellarNode {
+    let mut spec = common::horizon();
+    spec["horizonConfig"]["enableIngest"] = json!(true);
+    node(spec)
+}
+
+fn soroban(captive_core_config: Option<&str>) -> StellarNode {
+    let mut spec = common::soroban();
+    spec["sorobanConfig"]["captiveCoreConfig"] = json!(captive_core_config);
+    node(spec)
+}
+
+fn data(node: &StellarNode) -> std::collections::BTreeMap<String, String> {
+    build_config_map(node, None, false).data.unwrap_or_default()
+}
+
+#[test]
+fn test_network_passphrase_present_for_all_node_types() {
+    for node in [validator(None), horizon(), soroban(None)] {
+        let data = data(&node);
+        assert_eq!(
+            data.get("NETWORK_PASSPHRASE").map(String::as_str),
+            Some(node.spec.network.passphrase())
+        );
+    }
+}
+
+#[test]
+fn test_network_passphrase_matches_every_network() {
+    let networks = [
+        StellarNetwork::Mainnet,
+        StellarNetwork::Testnet,
+        StellarNetwork::Futurenet,
+        StellarNetwork::Custom("Private Test Network ; 2024".to_string()),
+    ];
+
+    for network in networks {
+        let mut spec = common::horizon();
+        spec["network"] = json!(network);
+        let data = data(&node(spec));
+        assert_eq!(
+            data.get("NETWORK_PASSPHRASE").map(String::as_str),
+            Some(network.passphrase()),
+            "passphrase mismatch for {network:?}"
+        );
+    }
+}
+
+#[test]
+fn test_validator_emits_core_cfg_with_quorum_set() {
+    let data = data(&validator(Some("[QUORUM_SET]\nTHRESHOLD_PERCENT=67")));
+    let cfg = data
+        .get("stellar-core.cfg")
+        .expect("stellar-core.cfg present");
+    assert!(cfg.contains("[QUORUM_SET]"));
+}
+
+#[test]
+fn test_validator_without_quorum_set_has_no_quorum_in_core_cfg() {
+    let data = data(&validator(None));
+    // History mode settings are always rendered, but no quorum set is included
+    assert!(!data
+        .get("stellar-core.cfg")
+        .is_some_and(|cfg| cfg.contains("QUORUM_SET")));
+    assert!(!data.contains_key("STELLAR_CORE_URL"));
+}
+
+#[test]
+fn test_validator_quorum_validators_replace_raw_quorum_set() {
+    let node = node(with(
+        common::validator(),
+        json!({
+            "validatorConfig": {
+                "seedSecretRef": "validator-seed",
+                "quorumSet": "[QUORUM_SET]\nTHRESHOLD_PERCENT=51\nVALIDATORS=[]",
+                "quorumValidators": [{
+                    "publicKey": "GCGB2S2KGYARPVIA37HYZXVRM2YZUEXA6S33ZU5BUDC6THSB62LZSTYH",
+                    "name": "sdf_1",
+                    "homeDomain": "stellar.org",
+                }],
+            }
+        }),
+    ));
+
+    let cfg = data(&node)
+        .remove("stellar-core.cfg")
+        .expect("stellar-core.cfg present");
+    assert!(cfg.contains("THRESHOLD_PERCENT=67"));
+    assert!(!cfg.contains("THRESHOLD_PERCENT=51"));
+    assert!(cfg.contains("\"GCGB2S2KGYARPVIA37HYZXVRM2YZUEXA6S33ZU5BUDC6THSB62LZSTYH sdf_1\""));
+}
+
+#[test]
+fn test_validator_emits_azure_history_section() {
+    let node = node(with(
+        common::validator(),
+        json!({
+            "validatorConfig": {
+                "seedSecretRef": "validator-seed",
+                "archivePublicationCredentials": {
+                    "azureBlob": {
+                        "accountName": "stellarhistory",
+                        "containerName": "archive",
+                        "sasTokenSecret": "azure-sas",
+                    }
+                }
+            }
+        }),
+    ));
+    let data = data(&node);
+    let cfg = data
+        .get("stellar-core.cfg")
+        .expect("stellar-core.cfg present");
+    assert!(cfg.contains("[HISTORY.azure]"));
+    assert!(cfg.contains("https://stellarhistory.blob.core.windows.net/archive/{0}"));
+}
+
+fn validator_with_parameters(network: StellarNetwork, allow_override: bool) -> StellarNode {
+    node(with(
+        common::validator(),
+        json!({
+            "network": network,
+            "allowMainnetParameterOverride": allow_override,
+            "validatorConfig": {
+                "seedSecretRef": "validator-seed",
+                "quorumSet": "[QUORUM_SET]\nTHRESHOLD_PERCENT=67\n",
+                "maxTxSetSize": 1000,
+                "baseFee": 200,
+            }
+        }),
+    ))
+}
+
+#[test]
+fn test_validator_emits_network_parameters_before_sections() {
+    let data = data(&validator_with_parameters(StellarNetwork::Testnet, false));
+    let cfg = data
+        .get("stellar-core.cfg")
+        .expect("stellar-core.cfg present");
+    assert!(cfg.starts_with("MAX_TRANSACTIONS_PER_LEDGER=1000\nBASE_FEE=200\n"));
+    assert!(cfg.contains("[QUORUM_SET]"));
+}
+
+#[test]
+fn test_validator_network_parameters_gated_on_mainnet() {
+    let gated = data(&validator_with_parameters(StellarNetwork::Mainnet, false));
+    let cfg = gated.get("stellar-core.cfg").cloned().unwrap_or_default();
+    assert!(!cfg.contains("MAX_TRANSACTIONS_PER_LEDGER"));
+    assert!(!cfg.contains("BASE_FEE"));
+
+    let allowed = data(&validator_with_parameters(StellarNetwork::Mainnet, true));
+    let cfg = allowed
+        .get("stellar-core.cfg")
+        .expect("stellar-core.cfg present");
+    assert!(cfg.contains("MAX_TRANSACTIONS_PER_LEDGER=1000\n"));
+    assert!(cfg.contains("BASE_FEE=200\n"));
+}
+
+#[test]
+fn test_validator_emits_scp_timing_catchup_workers_and_flood_settings() {
+    let tuned = node(with(
+        common::validator(),
+        json!({
+            "network": StellarNetwork::Mainnet,
+            "validatorConfig": {
+                "seedSecretRef": "validator-seed",
+                "scpBallotTimeoutMs": 1500,
+                "scpLedgerTargetDurationMs": 6000,
+                "scpMaxTimeSlipSeconds": 30,
+                "catchupWorkers": 32,
+                "floodTxPeriodMs": 100,
+                "maxBatchWriteCount": 4096,
+            }
+        }),
+    ));
+    let tuned = data(&tuned);
+    let cfg = tuned
+        .get("stellar-core.cfg")
+        .expect("stellar-core.cfg present");
+    assert!(cfg.contains("SCP_BALLOT_PREPARE_TIMER_MS=1500\n"));
+    assert!(cfg.contains("SCP_LEDGER_TARGET_DURATION_MS=6000\n"));
+    assert!(cfg.contains("MAXIMUM_LEDGER_CLOSETIME_DRIFT=30\n"));
+    assert!(cfg.contains("MAX_CONCURRENT_SUBPROCESSES=32\n"));
+    assert!(cfg.contains("FLOOD_TX_PERIOD_MS=100\n"));
+    assert!(cfg.contains("MAX_BATCH_WRITE_COUNT=4096\n"));
+
+    let defaults = data(&validator(Some("[QUORUM_SET]\n")));
+    let cfg = defaults
+        .get("stellar-core.cfg")
+        .cloned()
+        .unwrap_or_default();
+    assert!(!cfg.contains("SCP_BALLOT_PREPARE_TIMER_MS"));
+    assert!(!cfg.contains("MAX_CONCURRENT_SUBPROCESSES"));
+    assert!(!cfg.contains("FLOOD_TX_PERIOD_MS"));
+    assert!(!cfg.contains("MAX_BATCH_WRITE_COUNT"));
+}
+
+#[test]
+fn test_validator_emits_bucket_list_db_settings() {
+    let tuned = node(with(
+        common::validator(),
+        json!({
+            "validatorConfig": {
+                "seedSecretRef": "validator-seed",
+                "bucketListDb": { "indexPageSizeExponent": 16 },
+            }
+        }),
+    ));
+    let data = data(&tuned);
+    let cfg = data
+        .get("stellar-core.cfg")
+        .expect("stellar-core.cfg present");
+    assert!(cfg.contains("BUCKETLIST_DB_INDEX_PAGE_SIZE_EXPONENT=16\n"));
+    assert!(cfg.contains("BUCKETLIST_DB_INDEX_CUTOFF=20\n"));
+    assert!(cfg.contains("BUCKETLIST_DB_PERSIST_INDEX=true\n"));
+}
+
+#[test]
+fn test_validator_bucket_list_db_omitted_by_default() {
+    let data = data(&validator(Some("[QUORUM_SET]\nTHRESHOLD_PERCENT=67\n")));
+    let cfg = data
+        .get("stellar-core.cfg")
+        .expect("stellar-core.cfg present");
+    assert!(!cfg.contains("BUCKETLIST_DB"));
+}
+
+#[test]
+fn test_standby_validator_does_not_validate() {
+    let standby = node(with(
+        common::validator(),
+        json!({
+            "validatorConfig": {
+                "seedSecretRef": "validator-seed",
+                "quorumSet": "[QUORUM_SET]\nTHRESHOLD_PERCENT=67\n",
+                "standby": true,
+            }
+        }),
+    ));
+    let data = data(&standby);
+    let cfg = data
+        .get("stellar-core.cfg")
+        .expect("stellar-core.cfg present");
+    assert!(cfg.starts_with("NODE_IS_VALIDATOR=false\n"));
+}
+
+#[test]
+fn test_catchup_gate_validates_only_after_sync() {
+    let mut fresh = node(with(
+        common::validator(),
+        json!({
+            "validatorConfig": {
+                "seedSecretRef": "validator-seed",
+                "quorumSet": "[QUORUM_SET]\nTHRESHOLD_PERCENT=67\n",
+                "catchupBeforeValidate": true,
+            }
+        }),
+    ));
+    let cfg = data(&fresh)["stellar-core.cfg"].clone();
+    assert!(cfg.starts_with("NODE_IS_VALIDATOR=false\n"));
+
+    fresh.status = Some(StellarNodeStatus {
+        validator_mode: Some(ValidatorMode::Validating),
+        ..Default::default()
+    });
+    let cfg = data(&fresh)["stellar-core.cfg"].clone();
+    assert!(!cfg.contains("NODE_IS_VALIDATOR"));
+}
+
+#[test]
+fn test_active_validator_has_no_standby_override() {
+    let data = data(&validator(Some("[QUORUM_SET]\nTHRESHOLD_PERCENT=67\n")));
+    let cfg = data
+        .get("stellar-core.cfg")
+        .expect("stellar-core.cfg present");
+    assert!(!cfg.contains("NODE_IS_VALIDATOR"));
+}
+
+#[test]
+fn test_horizon_emits_core_url_and_ingest() {
+    let data = data(&horizon());
+    assert_eq!(
+        data.get("STELLAR_CORE_URL").map(String::as_str),
+        Some("http://core:11626")
+    );
+    assert_eq!(data.get("INGEST").map(String::as_str), Some("true"));
+    assert!(!data.contains_key("stellar-core.cfg"));
+}
+
+#[test]
+fn test_horizon_captive_core_mode() {
+    let captive = node(with(
+        common::horizon(),
+        json!({
+            "horizonConfig": {
+                "databaseSecretRef": "horizon-db",
+                "captiveCore": { "config": "PEER_PORT=11725\n" },
+            }
+        }),
+    ));
+    let settings = data(&captive);
+    assert_eq!(
+        settings.get("captive-core.cfg").map(String::as_str),
+        Some("PEER_PORT=11725\n")
+    );
+    assert_eq!(
+        settings.get("CAPTIVE_CORE_CONFIG_PATH").map(String::as_str),
+        Some("/config/captive-core.cfg")
+    );
+    assert_eq!(
+        settings.get("STELLAR_CORE_BINARY_PATH").map(String::as_str),
+        Some("/usr/bin/stellar-core")
+    );
+    assert!(!settings.contains_key("STELLAR_CORE_URL"));
+}
+
+#[test]
+fn test_horizon_emits_order_book_settings() {
+    let defaults = data(&horizon());
+    assert_eq!(
+        defaults.get("ORDER_BOOK_DEPTH").map(String::as_str),
+        Some("20")
+    );
+    assert_eq!(
+        defaults
+            .get("ENABLE_ORDER_BOOK_STREAMING")
+            .map(String::as_str),
+        Some("false")
+    );
+    assert!(!defaults.contains_key("ORDER_BOOK_STALE_TOLERANCE_LEDGERS"));
+
+    let streaming = node(with(
+        common::horizon(),
+        json!({
+            "horizonConfig": {
+                "databaseSecretRef": "horizon-db",
+                "stellarCoreUrl": "http://core:11626",
+                "orderBookCacheDepth": 50,
+                "enableOrderBookStreaming": true,
+                "orderBookStaleToleranceLedgers": 3,
+                "sseMaxConnections": 1000,
+            }
+        }),
+    ));
+    let streaming = data(&streaming);
+    assert_eq!(
+        streaming.get("ORDER_BOOK_DEPTH").map(String::as_str),
+        Some("50")
+    );
+    assert_eq!(
+        streaming
+            .get("ENABLE_ORDER_BOOK_STREAMING")
+            .map(String::as_str),
+        Some("true")
+    );
+    assert_eq!(
+        streaming
+            .get("ORDER_BOOK_STALE_TOLERANCE_LEDGERS")
+            .map(String::as_str),
+        Some("3")
+    );
+    assert_eq!(
+        streaming.get("SSE_MAX_CONNECTIONS").map(String::as_str),
+        Some("1000")
+    );
+}
+
+#[test]
+fn test_soroban_emits_tx_queue_settings() {
+    assert!(!data(&soroban(None)).contains_key("TX_QUEUE_SIZE"));
+
+    let node = node(with(
+        common::soroban(),
+        json!({
+            "sorobanConfig": {
+                "stellarCoreUrl": "http://core:11626",
+                "txQueueSize": 5000,
+                "txConcurrency": 16,
+            }
+        }),
+    ));
+    let data = data(&node);
+    assert_eq!(data.get("TX_QUEUE_SIZE").map(String::as_str), Some("5000"));
+    assert_eq!(data.get("TX_CONCURRENCY").map(String::as_str), Some("16"));
+}
+
+#[test]
+fn test_soroban_emits_contract_cache_settings() {
+    assert!(!data(&soroban(None)).contains_key("CONTRACT_CODE_CACHE_SIZE"));
+
+    let node = node(with(
+        common::soroban(),
+        json!({
+            "sorobanConfig": {
+                "stellarCoreUrl": "http://core:11626",
+                "contractCodeCacheSize": 2000,
+                "contractCodeCacheTtlSeconds": 600,
+                "contractInstanceCacheSize": 5000,
+            }
+        }),
+    ));
+    let data = data(&node);
+    assert_eq!(
+        data.get("CONTRACT_CODE_CACHE_SIZE").map(String::as_str),
+        Some("2000")
+    );
+    assert_eq!(
+        data.get("CONTRACT_CODE_CACHE_TTL").map(String::as_str),
+        Some("600")
+    );
+    assert_eq!(
+        data.get("CONTRACT_INSTANCE_CACHE_SIZE").map(String::as_str),
+        Some("5000")
+    );
+}
+
+#[test]
+fn test_soroban_emits_debug_rpc_settings() {
+    assert!(!data(&soroban(None)).contains_key("RPC_DEBUG_LOG"));
+
+    let debug = |extra: Value| {
+        let mut spec = common::soroban();
+        spec["sorobanConfig"]["debugRpc"] = json!(true);
+        for (k, v) in extra.as_object().expect("object") {
+            spec["sorobanConfig"][k] = v.clone();
+        }
+        data(&node(spec))
+    };
+
+    let data = debug(json!({}));
+    assert_eq!(data.get("RPC_DEBUG_LOG").map(String::as_str), Some("true"));
+    assert_eq!(
+        data.get("RPC_DEBUG_MAX_BODY_BYTES").map(String::as_str),
+        Some("4096")
+    );
+
+    let data = debug(json!({ "debugRpcMaxBodyLogBytes": 65536 }));
+    assert_eq!(
+        data.get("RPC_DEBUG_MAX_BODY_BYTES").map(String::as_str),
+        Some("65536")
+    );
+}
+
+#[test]
+fn test_soroban_emits_rpc_endpoint() {
+    assert_eq!(
+        data(&soroban(None)).get("RPC_ENDPOINT").map(String::as_str),
+        Some("/")
+    );
+
+    let node = node(with(
+        common::soroban(),
+        json!({
+            "sorobanConfig": {
+                "stellarCoreUrl": "http://core:11626",
+                "rpcEndpoint": "/soroban/rpc",
+            }
+        }),
+    ));
+    assert_eq!(
+        data(&node).get("RPC_ENDPOINT").map(String::as_str),
+        Some("/soroban/rpc")
+    );
+}
+
+#[test]
+fn test_soroban_emits_core_url_without_captive_core() {
+    let data = data(&soroban(None));
+    assert_eq!(
+        data.get("STELLAR_CORE_URL").map(String::as_str),
+        Some("http://core:11626")
+    );
+    assert!(!data.contains_key("captive-core.cfg"));
+}
+
+#[test]
+fn test_soroban_emits_captive_core_cfg_when_configured() {
+    let data = data(&soroban(Some("NETWORK_PASSPHRASE=\"Test\"")));
+    assert_eq!(
+        data.get("captive-core.cfg").map(String::as_str),
+        Some("NETWORK_PASSPHRASE=\"Test\"")
+    );
+}
+
+#[test]
+fn test_soroban_emits_fee_config() {
+    let node = node(with(
+        common::soroban(),
+        json!({
+            "sorobanConfig": {
+                "stellarCoreUrl": "http://core:11626",
+                "feeConfig": {
+                    "feePerInstruction": 25,
+                    "feePerWriteByte": 1000,
+                }
+            }
+        }),
+    ));
+    let data = data(&node);
+    assert_eq!(
+        data.get("FEE_PER_INSTRUCTION_INCREMENT")
+            .map(String::as_str),
+        Some("25")
+    );
+    assert_eq!(
+        data.get("FEE_PER_WRITE_BYTE").map(String::as_str),
+        Some("1000")
+    );
+    assert!(!data.contains_key("FEE_PER_READ_ENTRY"));
+}
+
+#[test]
+fn test_soroban_archival_storage_settings() {
+    let archival = node(with(
+        common::soroban(),
+        json!({
+            "sorobanConfig": {
+                "stellarCoreUrl": "http://core:11626",
+                "archivalStorage": {
+                    "backend": "GCS",
+                    "bucketName": "soroban-archive",
+                    "restorationMaxBytesPerLedger": 1048576,
+                    "evictionScanSize": 100000,
+                },
+            }
+        }),
+    ));
+    let settings = data(&archival);
+    assert_eq!(
+        settings.get("ARCHIVAL_BACKEND").map(String::as_str),
+        Some("gcs")
+    );
+    assert_eq!(
+        settings.get("ARCHIVAL_BUCKET").map(String::as_str),
+        Some("soroban-archive")
+    );
+    assert_eq!(
+        settings
+            .get("ARCHIVAL_RESTORATION_MAX_BYTES_PER_LEDGER")
+            .map(String::as_str),
+        Some("1048576")
+    );
+    assert_eq!(
+        settings
+            .get("ARCHIVAL_EVICTION_SCAN_SIZE")
+            .map(String::as_str),
+        Some("100000")
+    );
+
+    assert!(!data(&soroban(None))
+        .keys()
+        .any(|k| k.starts_with("ARCHIVAL_")));
+}
+
+#[test]
+fn test_horizon_connection_limit_from_autoscaling() {
+    let node = node(with(
+        common::horizon(),
+        json!({
+            "horizonConfig": {
+                "databaseSecretRef": "horizon-db",
+                "stellarCoreUrl": "http://core:11626",
+            },
+            "autoscaling": {
+                "minReplicas": 2,
+                "maxReplicas": 10,
+                "targetActiveConnections": 800,
+                "maxConnectionsPerReplica": 1000,
+            }
+        }),
+    ));
+    assert_eq!(
+        data(&node).get("CONNECTION_LIMIT").map(String::as_str),
+        Some("1000")
+    );
+    assert!(!data(&horizon()).contains_key("CONNECTION_LIMIT"));
+}
+
+#[test]
+fn test_horizon_emits_cors_headers() {
+    let defaults = data(&horizon());
+    assert_eq!(
+        defaults.get("CORS_ALLOWED_HEADERS").map(String::as_str),
+        Some("*")
+    );
+    assert!(!defaults.contains_key("CORS_EXPOSE_HEADERS"));
+    assert!(!defaults.contains_key("CORS_MAX_AGE"));
+
+    let node = node(with(
+        common::horizon(),
+        json!({
+            "horizonConfig": {
+                "databaseSecretRef": "horizon-db",
+                "stellarCoreUrl": "http://core:11626",
+                "corsAllowedHeaders": ["Content-Type", "X-Client-Name"],
+                "corsExposeHeaders": ["X-Ratelimit-Remaining", "Latest-Ledger"],
+                "corsMaxAgeSeconds": 600,
+            }
+        }),
+    ));
+    let data = data(&node);
+    assert_eq!(
+        data.get("CORS_ALLOWED_HEADERS").map(String::as_str),
+        Some("Content-Type,X-Client-Name")
+    );
+    assert_eq!(
+        data.get("CORS_EXPOSE_HEADERS").map(String::as_str),
+        Some("X-Ratelimit-Remaining,Latest-Ledger")
+    );
+    assert_eq!(data.get("CORS_MAX_AGE").map(String::as_str), Some("600"));
+}
@@ -0,0 +1,882 @@
+//! Tests for `build_deployment` pod template and container environment
+
+use k8s_openapi::api::core::v1::Capabilities;
+use k8s_openapi::apimachinery::pkg::util::intstr::IntOrString;
+use serde_json::json;
+use stellar_k8s::controller::{
+    build_deployment, CATCHUP_ANNOTATION, QUORUM_GROUP_LABEL, STANDBY_ANNOTATION,
+};
+use stellar_k8s::crd::{
+    StellarNode, StellarNodeSpec, StellarNodeStatus, TerminationMessagePolicy, ValidatorMode,
+};
+
+/// Build a minimal StellarNode from a JSON spec
+fn node(spec: serde_json::Value) -> StellarNode {
+    let spec: StellarNodeSpec = serde_json::from_value(spec).expect("valid spec");
+    let mut node = StellarNode::new("test-node", spec);
+    node.metadata.namespace = Some("stellar".to_string());
+    node
+}
+
+/// Value of env var `name` on the main container, if set
+fn env_value(node: &StellarNode, name: &str) -> Option<String> {
+    let deployment = build_deployment(node, false);
+    let pod_spec = deployment.spec?.template.spec?;
+    pod_spec.containers[0]
+        .env
+        .as_ref()?
+        .iter()
+        .find(|e| e.name == name)
+        .and_then(|e| e.value.clone())
+}
+
+fn horizon(extra: serde_json::Value) -> StellarNode {
+    let mut horizon_config = json!({
+        "databaseSecretRef": "horizon-db",
+        "stellarCoreUrl": "http://core:11626",
+    });
+    for (k, v) in extra.as_object().expect("object") {
+        horizon_config[k] = v.clone();
+    }
+    node(json!({
+        "nodeType": "Horizon",
+        "network": "Testnet",
+        "version": "v2.31.0",
+        "horizonConfig": horizon_config,
+    }))
+}
+
+#[test]
+fn test_horizon_history_settings_rendered_as_env() {
+    let node = horizon(json!({ "checkpointFrequency": 64, "historyRetentionCount": 17280 }));
+
+    assert_eq!(
+        env_value(&node, "CHECKPOINT_FREQUENCY").as_deref(),
+        Some("64")
+    );
+    assert_eq!(
+        env_value(&node, "HISTORY_RETENTION_COUNT").as_deref(),
+        Some("17280")
+    );
+}
+
+#[test]
+fn test_horizon_cors_origins_rendered_as_env() {
+    let node = horizon(json!({
+        "corsAllowedOrigins": ["https://app.example.com", "http://localhost:3000"],
+    }));
+    assert_eq!(
+        env_value(&node, "CORS_ALLOWED_ORIGINS").as_deref(),
+        Some("https://app.example.com,http://localhost:3000")
+    );
+    assert_eq!(env_value(&horizon(json!({})), "CORS_ALLOWED_ORIGINS"), None);
+}
+
+#[test]
+fn test_horizon_history_settings_omitted_by_default() {
+    let node = horizon(json!({}));
+
+    assert_eq!(env_value(&node, "CHECKPOINT_FREQUENCY"), None);
+    assert_eq!(env_value(&node, "HISTORY_RETENTION_COUNT"), None);
+}
+
+#[test]
+fn test_horizon_db_wait_init_container_runs_first() {
+    let mut node = horizon(json!({ "dbWaitTimeoutSeconds": 90, "autoMigration": true }));
+    node.spec.database = Some(
+        serde_json::from_value(json!({
+            "secretKeyRef": { "name": "horizon-db", "key": "DATABASE_URL" },
+        }))
+        .expect("valid database config"),
+    );
+    let pod_spec = build_deployment(&node, false)
+        .spec
+        .unwrap()
+        .template
+        .spec
+        .unwrap();
+    let init = pod_spec.init_containers.expect("init containers");
+
+    assert_eq!(init[0].name, "wait-for-db");
+    assert!(init.len() > 1, "migration still runs after the wait");
+    let env = init[0].env.as_ref().unwrap();
+    assert!(env.iter().any(|e| e.name == "DATABASE_URL"));
+    assert!(env
+        .iter()
+        .any(|e| e.name == "DB_WAIT_TIMEOUT_SECONDS" && e.value.as_deref() == Some("90")));
+}
+
+#[test]
+fn test_horizon_no_db_wait_by_default() {
+    let pod_spec = build_deployment(&horizon(json!({})), false)
+        .spec
+        .unwrap()
+        .template
+        .spec
+        .unwrap();
+    assert!(!pod_spec
+        .init_containers
+        .unwrap_or_default()
+        .iter()
+        .any(|c| c.name == "wait-for-db"));
+}
+
+#[test]
+fn test_vault_injection_annotations_and_env() {
+    let mut node = horizon(json!({}));
+    node.spec.vault = serde_json::from_value(json!({
+        "role": "stellar-horizon",
+        "secrets": [{
+            "name": "horizon-db",
+            "path": "secret/data/stellar/horizon-db",
+            "template": "{{ with secret \"secret/data/stellar/horizon-db\" }}{{ .Data.data.url }}{{ end }}",
+            "envVar": "DATABASE_URL_FILE",
+        }],
+    }))
+    .expect("valid vault config");
+
+    let annotations = build_deployment(&node, false)
+        .spec
+        .and_then(|s| s.template.metadata)
+        .and_then(|m| m.annotations)
+        .expect("pod template annotations");
+    assert_eq!(annotations["vault.hashicorp.com/agent-inject"], "true");
+    assert_eq!(annotations["vault.hashicorp.com/role"], "stellar-horizon");
+    assert_eq!(
+        annotations["vault.hashicorp.com/agent-inject-secret-horizon-db"],
+        "secret/data/stellar/horizon-db"
+    );
+    assert!(annotations.contains_key("vault.hashicorp.com/agent-inject-template-horizon-db"));
+
+    assert_eq!(
+        env_value(&node, "DATABASE_URL_FILE").as_deref(),
+        Some("/vault/secrets/horizon-db")
+    );
+}
+
+#[test]
+fn test_no_vault_annotations_by_default() {
+    let annotations = build_deployment(&horizon(json!({})), false)
+        .spec
+        .and_then(|s| s.template.metadata)
+        .and_then(|m| m.annotations)
+        .unwrap_or_default();
+    assert!(!annotations
+        .keys()
+        .any(|k| k.starts_with("vault.hashicorp.com/")));
+}
+
+#[test]
+fn test_post_start_hook_wired_into_container() {
+    let mut node = horizon(json!({}));
+    node.spec.lifecycle = serde_json::from_value(json!({
+        "postStart": { "exec": { "command": ["/bin/sh", "-c", "touch /data/ready"] } },
+    }))
+    .expect("valid lifecycle config");
+
+    let container = build_deployment(&node, false)
+        .spec
+        .and_then(|s| s.template.spec)
+        .map(|s| s.containers[0].clone())
+        .expect("main container");
+    let post_start = container
+        .lifecycle
+        .and_then(|l| l.post_start)
+        .expect("postStart hook");
+    assert_eq!(
+        post_start.exec.and_then(|e| e.command),
+        Some(vec![
+            "/bin/sh".to_string(),
+            "-c".to_string(),
+            "touch /data/ready".to_string()
+        ])
+    );
+    assert!(post_start.http_get.is_none());
+}
+
+#[test]
+fn test_post_start_http_get_hook() {
+    let mut node = horizon(json!({}));
+    node.spec.lifecycle = serde_json::from_value(json!({
+        "postStart": { "httpGet": { "path": "/register", "port": 8000 } },
+    }))
+    .expect("valid lifecycle config");
+
+    let http_get = build_deployment(&node, false)
+        .spec
+        .and_then(|s| s.template.spec)
+        .and_then(|s| s.containers[0].lifecycle.clone())
+        .and_then(|l| l.post_start)
+        .and_then(|h| h.http_get)
+        .expect("httpGet hook");
+    assert_eq!(http_get.path.as_deref(), Some("/register"));
+    assert_eq!(http_get.port, IntOrString::Int(8000));
+}
+
+/// `securityContext.capabilities` of the main container
+fn capabilities(node: &StellarNode) -> Capabilities {
+    build_deployment(node, false)
+        .spec
+        .and_then(|s| s.template.spec)
+        .and_then(|s| s.containers[0].security_context.clone())
+        .and_then(|s| s.capabilities)
+        .expect("capabilities")
+}
+
+#[test]
+fn test_capabilities_drop_all_by_default() {
+    let caps = capabilities(&horizon(json!({})));
+    assert_eq!(caps.drop, Some(vec!["ALL".to_string()]));
+    assert_eq!(caps.add, None);
+}
+
+#[test]
+fn test_capabilities_from_container_security_context() {
+    let mut node = horizon(json!({}));
+    node.spec.container_security_context = serde_json::from_value(json!({
+        "capabilitiesAdd": ["NET_BIND_SERVICE"],
+        "capabilitiesDrop": ["ALL"],
+    }))
+    .expect("valid security context");
+
+    let caps = capabilities(&node);
+    assert_eq!(caps.add, Some(vec!["NET_BIND_SERVICE".to_string()]));
+    assert_eq!(caps.drop, Some(vec!["ALL".to_string()]));
+}
+
+/// Pull policy of the main container
+fn pull_policy(node: &StellarNode) -> Option<String> {
+    build_deployment(node, false)
+        .spec?
+        .template
+        .spec?
+        .containers[0]
+        .image_pull_policy
+        .clone()
+}
+
+#[test]
+fn test_pull_policy_if_not_present_for_pinned_versions() {
+    let node = horizon(json!({}));
+    assert_eq!(pull_policy(&node).as_deref(), Some("IfNotPresent"));
+
+    let mut node = horizon(json!({}));
+    node.spec.image_override = serde_json::from_value(json!({
+        "repository": "registry.example.com:5000/stellar/horizon",
+        "digest": "sha256:0123abcd",
+    }))
+    .expect("valid image override");
+    assert_eq!(pull_policy(&node).as_deref(), Some("IfNotPresent"));
+}
+
+#[test]
+fn test_pull_policy_always_for_mutable_tags() {
+    for tag in ["latest", "stable", "edge"] {
+        let mut node = horizon(json!({}));
+        node.spec.image_override =
+            serde_json::from_value(json!({ "tag": tag })).expect("valid image override");
+        assert_eq!(pull_policy(&node).as_deref(), Some("Always"), "tag {tag}");
+    }
+}
+
+#[test]
+fn test_explicit_pull_policy_wins() {
+    let mut node = horizon(json!({}));
+    node.spec.image_override = serde_json::from_value(json!({
+        "tag": "latest",
+        "pullPolicy": "IfNotPresent",
+    }))
+    .expect("valid image override");
+    assert_eq!(pull_policy(&node).as_deref(), Some("IfNotPresent"));
+}
+
+#[test]
+fn test_horizon_tls_mounts_secret_and_sets_env() {
+    let node = horizon(json!({
+        "tls": { "certSecretRef": "horizon-cert", "clientCaSecretRef": "client-ca" },
+    }));
+    assert_eq!(
+        env_value(&node, "TLS_CERT_FILE").as_deref(),
+        Some("/etc/tls/tls.crt")
+    );
+    assert_eq!(
+        env_value(&node, "TLS_KEY_FILE").as_deref(),
+        Some("/etc/tls/tls.key")
+    );
+    assert_eq!(
+        env_value(&node, "CA_CERT_FILE").as_deref(),
+        Some("/etc/tls-client-ca/ca.crt")
+    );
+    assert_eq!(env_value(&node, "PORT").as_deref(), Some("443"));
+
+    let pod_spec = build_deployment(&node, false)
+        .spec
+        .and_then(|s| s.template.spec)
+        .expect("pod spec");
+    let volume = pod_spec
+        .volumes
+        .as_ref()
+        .and_then(|v| v.iter().find(|v| v.name == "horizon-tls"))
+        .expect("TLS volume");
+    assert_eq!(
+        volume
+            .secret
+            .as_ref()
+            .and_then(|s| s.secret_name.as_deref()),
+        Some("horizon-cert")
+    );
+
+    let container = &pod_spec.containers[0];
+    let mount = container
+        .volume_mounts
+        .as_ref()
+        .and_then(|m| m.iter().find(|m| m.name == "horizon-tls"))
+        .expect("TLS mount");
+    assert_eq!(mount.mount_path, "/etc/tls");
+    assert_eq!(container.ports.as_ref().unwrap()[0].container_port, 443);
+}
+
+#[test]
+fn test_horizon_offers_filtering_mounts_rules() {
+    let node = horizon(json!({
+        "offersFiltering": {
+            "enabled": true,
+            "filterRulesConfigmapRef": "offer-filters",
+            "updateIntervalSeconds": 30,
+        },
+    }));
+    assert_eq!(
+        env_value(&node, "FILTER_RULES_FILE").as_deref(),
+        Some("/etc/horizon/filter-rules.json")
+    );
+    assert_eq!(
+        env_value(&node, "FILTER_RULES_UPDATE_INTERVAL_SECONDS").as_deref(),
+        Some("30")
+    );
+
+    let pod_spec = build_deployment(&node, false)
+        .spec
+        .and_then(|s| s.template.spec)
+        .expect("pod spec");
+    let config_map = pod_spec
+        .volumes
+        .as_ref()
+        .and_then(|v| v.iter().find(|v| v.name == "horizon-filter-rules"))
+        .and_then(|v| v.config_map.as_ref())
+        .expect("filter rules volume");
+    assert_eq!(config_map.name.as_deref(), Some("offer-filters"));
+    assert_eq!(
+        config_map.items.as_ref().unwrap()[0].key,
+        "filter-rules.json"
+    );
+
+    let mount = pod_spec.containers[0]
+        .volume_mounts
+        .as_ref()
+        .and_then(|m| m.iter().find(|m| m.name == "horizon-filter-rules"))
+        .expect("filter rules mount");
+    assert_eq!(mount.mount_path, "/etc/horizon");
+    assert_eq!(mount.sub_path, None);
+}
+
+#[test]
+fn test_horizon_offers_filtering_disabled() {
+    let node = horizon(json!({
+        "offersFiltering": { "enabled": false, "filterRulesConfigmapRef": "offer-filters" },
+    }));
+    assert_eq!(env_value(&node, "FILTER_RULES_FILE"), None);
+}
+
+#[test]
+fn test_horizon_database_ssl_mounts_ca_and_sets_env() {
+    let node = horizon(json!({
+        "databaseSSL": { "mode": "VerifyFull", "caCertSecretRef": "pg-ca" },
+    }));
+    assert_eq!(
+        env_value(&node, "PGSSLMODE").as_deref(),
+        Some("verify-full")
+    );
+    assert_eq!(
+        env_value(&node, "PGSSLROOTCERT").as_deref(),
+        Some("/etc/ssl/pg-ca.crt")
+    );
+    assert_eq!(env_value(&node, "PGSSLCERT"), None);
+
+    let pod_spec = build_deployment(&node, false)
+        .spec
+        .and_then(|s| s.template.spec)
+        .expect("pod spec");
+    let volume = pod_spec
+        .volumes
+        .as_ref()
+        .and_then(|v| v.iter().find(|v| v.name == "pg-ssl-ca"))
+        .expect("CA volume");
+    assert_eq!(
+        volume
+            .secret
+            .as_ref()
+            .and_then(|s| s.secret_name.as_deref()),
+        Some("pg-ca")
+    );
+    let mount = pod_spec.containers[0]
+        .volume_mounts
+        .as_ref()
+        .and_then(|m| m.iter().find(|m| m.name == "pg-ssl-ca"))
+        .expect("CA mount");
+    assert_eq!(mount.mount_path, "/etc/ssl/pg-ca.crt");
+    assert_eq!(mount.sub_path.as_deref(), Some("ca.crt"));
+}
+
+#[test]
+fn test_horizon_database_ssl_require_skips_ca() {
+    let node = horizon(json!({
+        "databaseSSL": { "mode": "Require", "caCertSecretRef": "pg-ca" },
+    }));
+    assert_eq!(env_value(&node, "PGSSLMODE").as_deref(), Some("require"));
+    assert_eq!(env_value(&node, "PGSSLROOTCERT"), None);
+}
+
+#[test]
+fn test_horizon_without_tls_has_no_tls_volume() {
+    let node = horizon(json!({}));
+    assert_eq!(env_value(&node, "TLS_CERT_FILE"), None);
+
+    let pod_spec = build_deployment(&node, false)
+        .spec
+        .and_then(|s| s.template.spec)
+        .expect("pod spec");
+    assert!(!pod_spec
+        .volumes
+        .unwrap_or_default()
+        .iter()
+        .any(|v| v.name == "horizon-tls"));
+    assert_eq!(
+        pod_spec.containers[0].ports.as_ref().unwrap()[0].container_port,
+        8000
+    );
+}
+
+fn validator(standby: bool) -> StellarNode {
+    node(json!({
+        "nodeType": "Validator",
+        "network": "Testnet",
+        "version": "v21.0.0",
+        "validatorConfig": {
+            "seedSecretRef": "validator-seed",
+            "standby": standby,
+        },
+    }))
+}
+
+/// Pod template annotations of the node's workload
+fn pod_annotations(node: &StellarNode) -> std::collections::BTreeMap<String, String> {
+    build_deployment(node, false)
+        .spec
+        .and_then(|s| s.template.metadata)
+        .and_then(|m| m.annotations)
+        .unwrap_or_default()
+}
+
+#[test]
+fn test_standby_validator_marks_pod_template() {
+    let standby = pod_annotations(&validator(true));
+    assert_eq!(
+        standby.get(STANDBY_ANNOTATION).map(String::as_str),
+        Some("true")
+    );
+
+    // Promotion drops the annotation, changing the template and rolling the pods
+    assert!(!pod_annotations(&validator(false)).contains_key(STANDBY_ANNOTATION));
+}
+
+#[test]
+fn test_catching_up_validator_marks_pod_template() {
+    let mut fresh = node(json!({
+        "nodeType": "Validator",
+        "network": "Testnet",
+        "version": "v21.0.0",
+        "validatorConfig": {
+            "seedSecretRef": "validator-seed",
+            "catchupBeforeValidate": true,
+        },
+    }));
+    assert_eq!(
+        pod_annotations(&fresh)
+            .get(CATCHUP_ANNOTATION)
+            .map(String::as_str),
+        Some("true")
+    );
+
+    // Once synced the annotation is dropped, rolling the pods into validating mode
+    fresh.status = Some(StellarNodeStatus {
+        validator_mode: Some(ValidatorMode::Validating),
+        ..Default::default()
+    });
+    assert!(!pod_annotations(&fresh).contains_key(CATCHUP_ANNOTATION));
+    assert!(!pod_annotations(&validator(false)).contains_key(CATCHUP_ANNOTATION));
+}
+
+#[test]
+fn test_soroban_liveness_probe_uses_rpc_endpoint() {
+    let node = node(json!({
+        "nodeType": "SorobanRpc",
+        "network": "Testnet",
+        "version": "v21.0.0",
+        "sorobanConfig": {
+            "stellarCoreUrl": "http://core:11626",
+            "rpcEndpoint": "/soroban/rpc",
+        },
+    }));
+    let probe = build_deployment(&node, false)
+        .spec
+        .and_then(|s| s.template.spec)
+        .and_then(|s| s.containers[0].liveness_probe.clone())
+        .expect("liveness probe");
+    let http_get = probe.http_get.expect("httpGet probe");
+    assert_eq!(http_get.path.as_deref(), Some("/soroban/rpc"));
+    assert_eq!(http_get.port, IntOrString::Int(8000));
+}
+
+#[test]
+fn test_detected_node_selector_applied_when_auto_detect_enabled() {
+    let mut node = horizon(json!({}));
+    node.status = Some(StellarNodeStatus {
+        detected_node_selector: Some(
+            [("stellar.org/node-type".to_string(), "rpc".to_string())].into(),
+        ),
+        ..Default::default()
+    });
+
+    let selector = |node: &StellarNode| {
+        build_deployment(node, false)
+            .spec
+            .and_then(|s| s.template.spec)
+            .and_then(|s| s.node_selector)
+    };
+    assert_eq!(selector(&node), None);
+
+    node.spec.auto_detect_node_selector = true;
+    let detected = selector(&node).expect("node selector set");
+    assert_eq!(
+        detected.get("stellar.org/node-type").map(String::as_str),
+        Some("rpc")
+    );
+}
+
+#[test]
+fn test_quorum_group_validators_repel_each_other() {
+    let member = |name: &str| {
+        let mut node = validator(false);
+        node.metadata.name = Some(name.to_string());
+        node.spec.validator_config.as_mut().unwrap().quorum_group = Some("sdf".to_string());
+        build_deployment(&node, false).spec.expect("spec").template
+    };
+    let first = member("validator-a");
+    let second = member("validator-b");
+
+    let second_labels = second.metadata.and_then(|m| m.labels).unwrap_or_default();
+    assert_eq!(
+        second_labels.get(QUORUM_GROUP_LABEL).map(String::as_str),
+        Some("sdf")
+    );
+
+    let terms = first
+        .spec
+        .and_then(|s| s.affinity)
+        .and_then(|a| a.pod_anti_affinity)
+        .and_then(|a| a.required_during_scheduling_ignored_during_execution)
+        .expect("required pod anti-affinity");
+    assert_eq!(terms.len(), 1);
+    assert_eq!(terms[0].topology_key, "kubernetes.io/hostname");
+    let match_labels = terms[0]
+        .label_selector
+        .as_ref()
+        .and_then(|s| s.match_labels.clone())
+        .expect("match labels");
+    assert!(match_labels
+        .iter()
+        .all(|(k, v)| second_labels.get(k) == Some(v)));
+}
+
+#[test]
+fn test_no_anti_affinity_without_quorum_group() {
+    let template = build_deployment(&validator(false), false)
+        .spec
+        .expect("spec")
+        .template;
+    assert!(template.spec.and_then(|s| s.affinity).is_none());
+}
+
+#[test]
+fn test_soroban_s3_archival_credentials_from_secret() {
+    let node = node(json!({
+        "nodeType": "SorobanRpc",
+        "network": "Testnet",
+        "version": "v21.0.0",
+        "sorobanConfig": {
+            "stellarCoreUrl": "http://core:11626",
+            "archivalStorage": {
+                "backend": "S3",
+                "bucketName": "soroban-archive",
+                "credentialsSecretRef": "archive-creds",
+            },
+        },
+    }));
+    let container = build_deployment(&node, false)
+        .spec
+        .and_then(|s| s.template.spec)
+        .map(|s| s.containers[0].clone())
+        .expect("container");
+    for key in ["AWS_ACCESS_KEY_ID", "AWS_SECRET_ACCESS_KEY"] {
+        let secret_ref = container
+            .env
+            .as_ref()
+            .and_then(|env| env.iter().find(|e| e.name == key))
+            .and_then(|e| e.value_from.as_ref())
+            .and_then(|v| v.secret_key_ref.as_ref())
+            .expect("secret-backed env var");
+        assert_eq!(secret_ref.name.as_deref(), Some("archive-creds"));
+        assert_eq!(secret_ref.key, key);
+    }
+}
+
+#[test]
+fn test_termination_message_policy_on_main_container() {
+    let container = |node: &StellarNode| {
+        build_deployment(node, false)
+            .spec
+            .and_then(|s| s.template.spec)
+            .map(|s| s.containers[0].clone())
+            .expect("container")
+    };
+
+    let default = container(&horizon(json!({})));
+    assert_eq!(default.termination_message_policy, None);
+    assert_eq!(default.termination_message_path, None);
+
+    let mut fallback = horizon(json!({}));
+    fallback.spec.termination_message_policy =
+        Some(TerminationMessagePolicy::FallbackToLogsOnError);
+    let main = container(&fallback);
+    assert_eq!(
+        main.termination_message_policy.as_deref(),
+        Some("FallbackToLogsOnError")
+    );
+    assert_eq!(
+        main.termination_message_path.as_deref(),
+        Some("/dev/termination-log")
+    );
+
+    fallback.spec.termination_message_path = Some("/var/log/stellar/exit".to_string());
+    assert_eq!(
+        container(&fallback).termination_message_path.as_deref(),
+        Some("/var/log/stellar/exit")
+    );
+}
+
+#[test]
+fn test_mesh_injection_annotations() {
+    let mut rpc = horizon(json!({}));
+    rpc.spec.mesh = serde_json::from_value(json!({ "provider": "Istio" })).ok();
+    assert_eq!(
+        pod_annotations(&rpc)
+            .get("sidecar.istio.io/inject")
+            .map(String::as_str),
+        Some("true")
+    );
+
+    let mut core = validator(false);
+    core.spec.mesh = serde_json::from_value(json!({ "provider": "Linkerd" })).ok();
+    assert_eq!(
+        pod_annotations(&core)
+            .get("linkerd.io/inject")
+            .map(String::as_str),
+        Some("disabled")
+    );
+
+    core.spec.mesh = serde_json::from_value(json!({ "provider": "Linkerd", "inject": true })).ok();
+    assert_eq!(
+        pod_annotations(&core)
+            .get("linkerd.io/inject")
+            .map(String::as_str),
+        Some("enabled")
+    );
+
+    assert!(!pod_annotations(&horizon(json!({}))).contains_key("sidecar.istio.io/inject"));
+}
+
+fn soroban(extra: serde_json::Value) -> StellarNode {
+    let mut soroban_config = json!({ "stellarCoreUrl": "http://core:11626" });
+    for (k, v) in extra.as_object().expect("object") {
+        soroban_config[k] = v.clone();
+    }
+    node(json!({
+        "nodeType": "SorobanRpc",
+        "network": "Testnet",
+        "version": "v21.0.0",
+        "sorobanConfig": soroban_config,
+    }))
+}
+
+#[test]
+fn test_soroban_sqlite_backend_sets_database_path() {
+    let node = soroban(json!({}));
+    assert_eq!(
+        env_value(&node, "DATABASE_PATH").as_deref(),
+        Some("/data/soroban.db")
+    );
+    let init_containers = build_deployment(&node, false)
+        .spec
+        .and_then(|s| s.template.spec)
+        .and_then(|s| s.init_containers)
+        .unwrap_or_default();
+    assert!(init_containers.is_empty());
+}
+
+#[test]
+fn test_soroban_postgresql_backend_uses_secret_and_migrates() {
+    let node = soroban(json!({
+        "persistenceBackend": "PostgreSQL",
+        "databaseSecretRef": "soroban-db",
+        "runMigrationAtStartup": true,
+    }));
+    let pod_spec = build_deployment(&node, false)
+        .spec
+        .and_then(|s| s.template.spec)
+        .expect("pod spec");
+    let env = pod_spec.containers[0].env.clone().unwrap_or_default();
+    assert!(!env.iter().any(|e| e.name == "DATABASE_PATH"));
+    let secret_ref = env
+        .iter()
+        .find(|e| e.name == "DATABASE_URL")
+        .and_then(|e| e.value_from.as_ref())
+        .and_then(|v| v.secret_key_ref.as_ref())
+        .expect("DATABASE_URL from secret");
+    assert_eq!(secret_ref.name.as_deref(), Some("soroban-db"));
+    assert_eq!(secret_ref.key, "DATABASE_URL");
+
+    let init_containers = pod_spec.init_containers.unwrap_or_default();
+    assert_eq!(init_containers.len(), 1);
+    assert_eq!(init_containers[0].name, "soroban-db-migration");
+}
+
+#[test]
+fn test_resource_claims_attached_to_container_and_pod() {
+    let node = node(json!({
+        "nodeType": "Horizon",
+        "network": "Testnet",
+        "version": "v2.31.0",
+        "horizonConfig": {
+            "databaseSecretRef": "horizon-db",
+            "stellarCoreUrl": "http://core:11626",
+        },
+        "resources": {
+            "requests": { "cpu": "500m", "memory": "1Gi" },
+            "limits": { "cpu": "2", "memory": "4Gi" },
+            "claims": ["nvme-scratch"],
+        },
+    }));
+    let pod_spec = build_deployment(&node, false)
+        .spec
+        .unwrap()
+        .template
+        .spec
+        .unwrap();
+
+    let claims = pod_spec.containers[0]
+        .resources
+        .as_ref()
+        .and_then(|r| r.claims.clone())
+        .unwrap();
+    assert_eq!(claims.len(), 1);
+    assert_eq!(claims[0].name, "nvme-scratch");
+
+    let pod_claims = pod_spec.resource_claims.unwrap();
+    assert_eq!(pod_claims[0].name, "nvme-scratch");
+    assert_eq!(
+        pod_claims[0]
+            .source
+            .as_ref()
+            .and_then(|s| s.resource_claim_name.as_deref()),
+        Some("nvme-scratch")
+    );
+
+    let without = build_deployment(&horizon(json!({})), false)
+        .spec
+        .unwrap()
+        .template
+        .spec
+        .unwrap();
+    assert!(without.resource_claims.is_none());
+    assert!(without.containers[0]
+        .resources
+        .as_ref()
+        .is_some_and(|r| r.claims.is_none()));
+}
+
+#[test]
+fn test_horizon_scaled_to_zero_while_core_too_old() {
+    let mut node = horizon(json!({ "minStellarCoreVersion": "21.0.0" }));
+    node.spec.replicas = 2;
+    node.status = Some(StellarNodeStatus {
+        stellar_core_version: Some("stellar-core 20.2.0 (a1b2c3d4)".to_string()),
+        ..Default::default()
+    });
+    let replicas = |node: &StellarNode| build_deployment(node, false).spec.unwrap().replicas;
+    assert_eq!(replicas(&node), Some(0));
+
+    node.status.as_mut().unwrap().stellar_core_version =
+        Some("stellar-core 21.1.0 (e5f6a7b8)".to_string());
+    assert_eq!(replicas(&node), Some(2));
+}
+
+#[test]
+fn test_weekend_suspension_scales_to_zero_without_suspending_spec() {
+    let mut node = horizon(json!({}));
+    node.spec.replicas = 3;
+    node.status = Some(StellarNodeStatus {
+        weekend_suspension_active: true,
+        ..Default::default()
+    });
+    let replicas = |node: &StellarNode| build_deployment(node, false).spec.unwrap().replicas;
+    assert_eq!(replicas(&node), Some(0));
+    assert!(!node.spec.suspended);
+
+    node.status.as_mut().unwrap().weekend_suspension_active = false;
+    assert_eq!(replicas(&node), Some(3));
+}
+
+#[test]
+fn test_horizon_transaction_filtering_rendered_as_env() {
+    let node = horizon(json!({
+        "transactionFiltering": {
+            "enabled": true,
+            "filterServiceUrl": "https://compliance.example.com/v1/filter",
+            "filterOnIngest": true,
+        },
+    }));
+    assert_eq!(
+        env_value(&node, "TRANSACTION_FILTER_SERVICE_URL").as_deref(),
+        Some("https://compliance.example.com/v1/filter")
+    );
+    assert_eq!(
+        env_value(&node, "TRANSACTION_FILTER_CACHE_TTL").as_deref(),
+        Some("300")
+    );
+    assert_eq!(
+        env_value(&node, "FILTER_TRANSACTIONS_ON_INGEST").as_deref(),
+        Some("true")
+    );
+}
+
+#[test]
+fn test_horizon_transaction_filtering_disabled_omits_env() {
+    let node = horizon(json!({
+        "transactionFiltering": {
+            "enabled": false,
+            "filterServiceUrl": "https://compliance.example.com/v1/filter",
+        },
+    }));
+    assert_eq!(env_value(&node, "TRANSACTION_FILTER_SERVICE_URL"), None);
+    assert_eq!(env_value(&node, "FILTER_TRANSACTIONS_ON_INGEST"), None);
+}
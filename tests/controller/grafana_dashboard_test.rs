@@ -0,0 +1,81 @@
+//! Tests for `build_grafana_dashboard` and the embedded per-node-type dashboards
+
+mod common;
+
+use common::{horizon, node, soroban, validator};
+use serde_json::{json, Value};
+use stellar_k8s::controller::{
+    build_grafana_dashboard, grafana_dashboard_json, GRAFANA_DASHBOARD_LABEL,
+    GRAFANA_FOLDER_ANNOTATION,
+};
+use stellar_k8s::crd::{GrafanaDashboardConfig, StellarNode};
+
+/// Build a node from `spec` with a Grafana dashboard in `grafana_namespace`
+fn with_dashboard(mut spec: Value, grafana_namespace: &str) -> StellarNode {
+    spec["grafanaDashboard"] = json!({
+        "namespace": grafana_namespace,
+        "folder": "Stellar",
+        "dashboardLabels": { "release": "kube-prometheus-stack" },
+    });
+    node(spec)
+}
+
+fn config(node: &StellarNode) -> &GrafanaDashboardConfig {
+    node.spec
+        .grafana_dashboard
+        .as_ref()
+        .expect("grafanaDashboard")
+}
+
+#[test]
+fn test_dashboard_config_map_is_discoverable_by_sidecar() {
+    let node = with_dashboard(validator(), "monitoring");
+    let cm = build_grafana_dashboard(&node, config(&node));
+
+    assert_eq!(cm.metadata.namespace.as_deref(), Some("monitoring"));
+    let labels = cm.metadata.labels.expect("labels");
+    assert_eq!(labels[GRAFANA_DASHBOARD_LABEL], "1");
+    assert_eq!(labels["release"], "kube-prometheus-stack");
+    assert_eq!(
+        cm.metadata.annotations.expect("annotations")[GRAFANA_FOLDER_ANNOTATION],
+        "Stellar"
+    );
+    // Owner references cannot cross namespaces
+    assert!(cm.metadata.owner_references.is_none());
+    assert_eq!(cm.data.expect("data").len(), 1);
+}
+
+#[test]
+fn test_same_namespace_dashboard_is_owned_by_node() {
+    let node = with_dashboard(horizon(), "stellar");
+    let cm = build_grafana_dashboard(&node, config(&node));
+    assert_eq!(cm.metadata.owner_references.map(|o| o.len()), Some(1));
+}
+
+#[test]
+fn test_dashboard_per_node_type_scoped_to_node() {
+    for (spec, title) in [
+        (validator(), "Stellar Validator"),
+        (horizon(), "Stellar Horizon"),
+        (soroban(), "Stellar Soroban RPC"),
+    ] {
+        let node_type = spec["nodeType"].clone();
+        let dashboard: Value =
+            serde_json::from_str(&grafana_dashboard_json(&with_dashboard(spec, "monitoring")))
+                .expect("valid dashboard JSON");
+
+        assert_eq!(
+            dashboard["title"],
+            format!("{title} (stellar/test-node)"),
+            "{node_type}"
+        );
+        assert_eq!(dashboard["uid"], "stellar-stellar-test-node");
+        let variables = dashboard["templating"]["list"].as_array().unwrap();
+        assert!(variables
+            .iter()
+            .any(|v| v["name"] == "instance" && v["query"] == "test-node"));
+        assert!(variables
+            .iter()
+            .any(|v| v["name"] == "namespace" && v["query"] == "stellar"));
+    }
+}
@@ -0,0 +1,121 @@
+//! Tests for the history archive GC sidecar and CronJob
+
+mod common;
+
+use common::node;
+use k8s_openapi::api::core::v1::Container;
+use serde_json::json;
+use stellar_k8s::controller::{build_deployment, build_history_gc_cron_job};
+use stellar_k8s::crd::StellarNode;
+
+/// Build a validator publishing to Azure Blob with the given `historyGCPolicy`
+fn validator(history_gc: serde_json::Value) -> StellarNode {
+    let mut spec = common::validator();
+    spec["validatorConfig"]["archivePublicationCredentials"] = json!({
+        "azureBlob": {
+            "accountName": "stellarhistory",
+            "containerName": "archive",
+            "sasTokenSecret": "azure-sas",
+        },
+    });
+    spec["validatorConfig"]["historyGCPolicy"] = history_gc;
+    node(spec)
+}
+
+fn gc_sidecar(node: &StellarNode) -> Option<Container> {
+    let pod_spec = build_deployment(node, false).spec?.template.spec?;
+    pod_spec
+        .containers
+        .into_iter()
+        .find(|c| c.name == "history-gc")
+}
+
+fn env_value(container: &Container, name: &str) -> Option<String> {
+    container
+        .env
+        .as_ref()?
+        .iter()
+        .find(|e| e.name == name)
+        .and_then(|e| e.value.clone())
+}
+
+#[test]
+fn test_history_gc_sidecar_by_default() {
+    let node = validator(json!({
+        "enabled": true,
+        "retentionCount": 1000,
+        "gcSchedule": "0 3 * * *",
+        "dryRunFirst": true,
+    }));
+
+    let sidecar = gc_sidecar(&node).expect("history-gc sidecar");
+    assert_eq!(
+        env_value(&sidecar, "HISTORY_ARCHIVE_URL").as_deref(),
+        Some("https://stellarhistory.blob.core.windows.net/archive")
+    );
+    assert_eq!(
+        env_value(&sidecar, "HISTORY_GC_RETENTION_COUNT").as_deref(),
+        Some("1000")
+    );
+    assert_eq!(
+        env_value(&sidecar, "HISTORY_GC_SCHEDULE").as_deref(),
+        Some("0 3 * * *")
+    );
+    assert_eq!(
+        env_value(&sidecar, "HISTORY_GC_DRY_RUN_FIRST").as_deref(),
+        Some("true")
+    );
+    let sas = sidecar
+        .env
+        .as_ref()
+        .unwrap()
+        .iter()
+        .find(|e| e.name == "AZURE_STORAGE_SAS_TOKEN")
+        .and_then(|e| e.value_from.as_ref()?.secret_key_ref.as_ref())
+        .expect("SAS token from secret");
+    assert_eq!(sas.name.as_deref(), Some("azure-sas"));
+
+    assert!(build_history_gc_cron_job(&node).is_none());
+}
+
+#[test]
+fn test_history_gc_cron_job_implementation() {
+    let node = validator(json!({
+        "enabled": true,
+        "retentionCount": 500,
+        "gcSchedule": "30 2 * * 0",
+        "gcImplementation": "CronJob",
+    }));
+
+    assert!(gc_sidecar(&node).is_none());
+
+    let cron_job = build_history_gc_cron_job(&node).expect("CronJob");
+    assert_eq!(
+        cron_job.metadata.name.as_deref(),
+        Some("test-node-history-gc")
+    );
+    let spec = cron_job.spec.unwrap();
+    assert_eq!(spec.schedule, "30 2 * * 0");
+    assert_eq!(spec.concurrency_policy.as_deref(), Some("Forbid"));
+    let pod_spec = spec.job_template.spec.unwrap().template.spec.unwrap();
+    let container = &pod_spec.containers[0];
+    assert!(container.command.as_ref().unwrap()[2].contains("stellar-archivist gc"));
+    assert_eq!(
+        env_value(container, "HISTORY_GC_RETENTION_COUNT").as_deref(),
+        Some("500")
+    );
+    assert_eq!(env_value(container, "HISTORY_GC_SCHEDULE"), None);
+}
+
+#[test]
+fn test_history_gc_disabled() {
+    let node = validator(json!({
+        "enabled": false,
+        "retentionCount": 500,
+        "gcSchedule": "30 2 * * 0",
+        "gcImplementation": "CronJob",
+    }));
+
+    assert!(gc_sidecar(&node).is_none());
+    assert!(build_history_gc_cron_job(&node).is_none());
+}
@@ -0,0 +1,95 @@
+//! Tests for `build_network_policy` ingress rules
+
+mod common;
+
+use common::node;
+use k8s_openapi::api::networking::v1::NetworkPolicyIngressRule;
+use serde_json::json;
+use stellar_k8s::controller::build_network_policy;
+use stellar_k8s::crd::{NetworkPolicyConfig, StellarNode};
+
+/// Build a Horizon node with the given `networkPolicy` block
+fn horizon(network_policy: serde_json::Value) -> StellarNode {
+    let mut spec = common::horizon();
+    spec["networkPolicy"] = network_policy;
+    node(spec)
+}
+
+fn ingress(node: &StellarNode) -> Option<Vec<NetworkPolicyIngressRule>> {
+    let config: &NetworkPolicyConfig = node.spec.network_policy.as_ref().unwrap();
+    build_network_policy(node, config).spec.unwrap().ingress
+}
+
+/// Namespace names selected by any ingress rule
+fn allowed_namespaces(rules: &[NetworkPolicyIngressRule]) -> Vec<String> {
+    rules
+        .iter()
+        .flat_map(|r| r.from.iter().flatten())
+        .filter_map(|peer| peer.namespace_selector.as_ref()?.match_labels.as_ref())
+        .filter_map(|labels| labels.get("kubernetes.io/metadata.name").cloned())
+        .collect()
+}
+
+#[test]
+fn test_allow_from_namespaces_and_cidrs() {
+    let node = horizon(json!({
+        "enabled": true,
+        "allowMetricsScrape": false,
+        "allowFromNamespaces": ["my-app"],
+        "allowFromExternalCidr": ["203.0.113.0/24"],
+    }));
+    let rules = ingress(&node).expect("ingress rules");
+
+    assert_eq!(allowed_namespaces(&rules), vec!["my-app".to_string()]);
+    let cidrs: Vec<&str> = rules
+        .iter()
+        .flat_map(|r| r.from.iter().flatten())
+        .filter_map(|peer| peer.ip_block.as_ref().map(|b| b.cidr.as_str()))
+        .collect();
+    assert_eq!(cidrs, vec!["203.0.113.0/24"]);
+    // Explicit rules replace the same-namespace default
+    assert!(!rules
+        .iter()
+        .flat_map(|r| r.from.iter().flatten())
+        .any(|peer| peer.pod_selector.is_some()));
+}
+
+#[test]
+fn test_same_namespace_only_by_default() {
+    let node = horizon(json!({ "enabled": true, "allowMetricsScrape": false }));
+    let rules = ingress(&node).expect("ingress rules");
+
+    assert_eq!(rules.len(), 1);
+    let peers = rules[0].from.as_ref().unwrap();
+    assert_eq!(peers.len(), 1);
+    assert_eq!(peers[0].pod_selector, Some(Default::default()));
+    assert!(peers[0].namespace_selector.is_none());
+}
+
+#[test]
+fn test_deny_all_ingress_overrides_allow_rules() {
+    let node = horizon(json!({
+        "enabled": true,
+        "denyAllIngress": true,
+        "allowFromNamespaces": ["my-app"],
+        "allowFromExternalCidr": ["0.0.0.0/0"],
+    }));
+
+    assert_eq!(ingress(&node), None);
+}
+
+#[test]
+fn test_invalid_external_cidr_rejected() {
+    let node = horizon(json!({
+        "enabled": true,
+        "allowFromExternalCidr": ["10.0.0.0/33", "not-a-cidr"],
+    }));
+    let errors = node.spec.validate().unwrap_err();
+
+    assert!(errors
+        .iter()
+        .any(|e| e.field == "spec.networkPolicy.allowFromExternalCidr[0]"));
+    assert!(errors
+        .iter()
+        .any(|e| e.field == "spec.networkPolicy.allowFromExternalCidr[1]"));
+}
@@ -0,0 +1,76 @@
+//! Tests for `build_prometheus_rule` default and user-defined alerts
+
+mod common;
+
+use common::{horizon, node, soroban, validator};
+use serde_json::json;
+use stellar_k8s::controller::build_prometheus_rule;
+use stellar_k8s::crd::StellarNode;
+
+fn with_rules(mut spec: serde_json::Value, rules: serde_json::Value) -> serde_json::Value {
+    spec["prometheusRules"] = rules;
+    spec
+}
+
+fn alert_names(node: &StellarNode) -> Vec<String> {
+    let rule = build_prometheus_rule(node);
+    rule.data["spec"]["groups"][0]["rules"]
+        .as_array()
+        .expect("rules array")
+        .iter()
+        .map(|r| r["alert"].as_str().unwrap_or_default().to_string())
+        .collect()
+}
+
+#[test]
+fn test_default_rules_per_node_type() {
+    let defaults = json!({ "enableDefaultRules": true });
+
+    let names = alert_names(&node(with_rules(validator(), defaults.clone())));
+    assert!(names.contains(&"StellarValidatorBehind".to_string()));
+
+    let names = alert_names(&node(with_rules(horizon(), defaults.clone())));
+    assert!(names.contains(&"HorizonHighLatency".to_string()));
+    assert!(!names.contains(&"StellarValidatorBehind".to_string()));
+
+    let names = alert_names(&node(with_rules(soroban(), defaults)));
+    assert!(names.contains(&"SorobanRpcDown".to_string()));
+}
+
+#[test]
+fn test_extra_rules_are_appended() {
+    let node = node(with_rules(
+        horizon(),
+        json!({
+            "enableDefaultRules": true,
+            "extraRules": [{
+                "alert": "HorizonSlowIngestion",
+                "expr": "stellar_node_ingestion_lag{name=\"test-node\"} > 50",
+                "for": "15m",
+                "labels": { "severity": "warning" },
+            }],
+        }),
+    ));
+
+    let rule = build_prometheus_rule(&node);
+    let rules = rule.data["spec"]["groups"][0]["rules"].as_array().unwrap();
+    let extra = rules.last().unwrap();
+    assert_eq!(extra["alert"], "HorizonSlowIngestion");
+    assert_eq!(extra["for"], "15m");
+    assert_eq!(extra["labels"]["severity"], "warning");
+    assert!(rules.len() > 1);
+}
+
+#[test]
+fn test_default_rules_can_be_disabled() {
+    let node = node(with_rules(
+        soroban(),
+        json!({
+            "enableDefaultRules": false,
+            "extraRules": [{ "alert": "Custom", "expr": "vector(1)" }],
+        }),
+    ));
+
+    assert_eq!(alert_names(&node), vec!["Custom".to_string()]);
+    assert!(node.spec.prometheus_rule_enabled());
+}
@@ -0,0 +1,137 @@
+//! Tests for `build_pvc` labels and annotations and the history cache volume
+
+mod common;
+
+use common::node;
+use serde_json::json;
+use stellar_k8s::controller::{
+    build_config_map, build_deployment, build_history_cache_pvc, build_pvc,
+    HISTORY_CACHE_MOUNT_PATH,
+};
+use stellar_k8s::crd::StellarNode;
+
+/// Build a minimal Horizon StellarNode with the given storage config
+fn horizon(storage: serde_json::Value) -> StellarNode {
+    let mut spec = common::horizon();
+    spec["storage"] = storage;
+    node(spec)
+}
+
+/// Build a validator with the given `validatorConfig.historyCache`
+fn validator(history_cache: serde_json::Value) -> StellarNode {
+    let mut spec = common::validator();
+    spec["storage"] = json!({ "storageClass": "standard", "size": "100Gi" });
+    spec["validatorConfig"]["historyCache"] = history_cache;
+    node(spec)
+}
+
+#[test]
+fn test_pvc_labels_only_on_pvc() {
+    let node = horizon(json!({
+        "storageClass": "standard",
+        "size": "100Gi",
+        "pvcLabels": { "backup-policy": "daily" },
+    }));
+
+    let pvc_labels = build_pvc(&node).metadata.labels.expect("PVC labels");
+    assert_eq!(
+        pvc_labels.get("backup-policy").map(String::as_str),
+        Some("daily")
+    );
+    assert_eq!(
+        pvc_labels
+            .get("app.kubernetes.io/instance")
+            .map(String::as_str),
+        Some("test-node")
+    );
+
+    let deployment = build_deployment(&node, false);
+    assert!(!deployment
+        .metadata
+        .labels
+        .unwrap_or_default()
+        .contains_key("backup-policy"));
+    let template_labels = deployment
+        .spec
+        .and_then(|s| s.template.metadata)
+        .and_then(|m| m.labels)
+        .unwrap_or_default();
+    assert!(!template_labels.contains_key("backup-policy"));
+}
+
+#[test]
+fn test_pvc_labels_do_not_override_standard_labels() {
+    let node = horizon(json!({
+        "storageClass": "standard",
+        "size": "100Gi",
+        "pvcLabels": { "app.kubernetes.io/instance": "other" },
+    }));
+    let labels = build_pvc(&node).metadata.labels.expect("PVC labels");
+    assert_eq!(
+        labels.get("app.kubernetes.io/instance").map(String::as_str),
+        Some("test-node")
+    );
+}
+
+#[test]
+fn test_history_cache_pvc_is_mounted() {
+    let node = validator(json!({
+        "volumeType": "PersistentVolumeClaim",
+        "size": "20Gi",
+        "storageClass": "fast-ssd",
+    }));
+    let pvc = build_history_cache_pvc(&node).expect("history cache PVC");
+    assert_eq!(
+        pvc.metadata.name.as_deref(),
+        Some("test-node-history-cache")
+    );
+    let spec = pvc.spec.expect("PVC spec");
+    assert_eq!(spec.storage_class_name.as_deref(), Some("fast-ssd"));
+    assert_eq!(
+        spec.resources.and_then(|r| r.requests).unwrap()["storage"].0,
+        "20Gi"
+    );
+
+    let pod_spec = build_deployment(&node, false)
+        .spec
+        .and_then(|s| s.template.spec)
+        .expect("pod spec");
+    let volume = pod_spec
+        .volumes
+        .unwrap_or_default()
+        .into_iter()
+        .find(|v| v.name == "history-cache")
+        .expect("history cache volume");
+    assert_eq!(
+        volume.persistent_volume_claim.map(|p| p.claim_name),
+        Some("test-node-history-cache".to_string())
+    );
+    assert!(pod_spec.containers[0]
+        .volume_mounts
+        .as_ref()
+        .unwrap()
+        .iter()
+        .any(|m| m.name == "history-cache" && m.mount_path == HISTORY_CACHE_MOUNT_PATH));
+
+    let cfg = build_config_map(&node, None, false).data.unwrap()["stellar-core.cfg"].clone();
+    assert!(cfg.contains(&format!("TMP_DIR_PATH=\"{HISTORY_CACHE_MOUNT_PATH}\"")));
+}
+
+#[test]
+fn test_history_cache_empty_dir_has_size_limit() {
+    let node = validator(json!({ "size": "10Gi" }));
+    assert!(build_history_cache_pvc(&node).is_none());
+
+    let volume = build_deployment(&node, false)
+        .spec
+        .and_then(|s| s.template.spec)
+        .and_then(|s| s.volumes)
+        .unwrap_or_default()
+        .into_iter()
+        .find(|v| v.name == "history-cache")
+        .expect("history cache volume");
+    assert_eq!(
+        volume.empty_dir.and_then(|e| e.size_limit).map(|q| q.0),
+        Some("10Gi".to_string())
+    );
+}
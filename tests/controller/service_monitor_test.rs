@@ -0,0 +1,52 @@
+//! Tests for `build_service_monitor` selector and scrape settings
+
+mod common;
+
+use common::node;
+use serde_json::json;
+use stellar_k8s::controller::build_service_monitor;
+use stellar_k8s::crd::StellarNode;
+
+/// Build a minimal Validator with the given `monitoring` config
+fn validator(monitoring: serde_json::Value) -> StellarNode {
+    let mut spec = common::validator();
+    spec["monitoring"] = monitoring;
+    node(spec)
+}
+
+#[test]
+fn test_service_monitor_carries_custom_interval() {
+    let node = validator(json!({ "scrapeInterval": "2m", "scrapeTimeout": "45s" }));
+    let monitor = build_service_monitor(&node);
+
+    assert_eq!(
+        monitor.metadata.name.as_deref(),
+        Some("test-node-service-monitor")
+    );
+    let endpoint = &monitor.data["spec"]["endpoints"][0];
+    assert_eq!(endpoint["port"], "http");
+    assert_eq!(endpoint["path"], "/metrics");
+    assert_eq!(endpoint["interval"], "2m");
+    assert_eq!(endpoint["scrapeTimeout"], "45s");
+}
+
+#[test]
+fn test_service_monitor_defaults_leave_interval_to_prometheus() {
+    let monitor = build_service_monitor(&validator(json!({})));
+    let endpoint = &monitor.data["spec"]["endpoints"][0];
+
+    assert!(endpoint.get("interval").is_none());
+    assert!(endpoint.get("scrapeTimeout").is_none());
+}
+
+#[test]
+fn test_service_monitor_selects_node_service() {
+    let monitor = build_service_monitor(&validator(json!({})));
+    let spec = &monitor.data["spec"];
+
+    assert_eq!(
+        spec["selector"]["matchLabels"]["app.kubernetes.io/instance"],
+        "test-node"
+    );
+    assert_eq!(spec["namespaceSelector"]["matchNames"][0], "stellar");
+}
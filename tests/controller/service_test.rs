@@ -0,0 +1,145 @@
+//! Tests for `build_service` topology-aware routing, ports, session affinity and traffic policy
+
+mod common;
+
+use common::node;
+use serde_json::json;
+use stellar_k8s::controller::{build_service, TOPOLOGY_MODE_ANNOTATION};
+use stellar_k8s::crd::StellarNode;
+
+fn horizon(topology_aware: Option<bool>) -> StellarNode {
+    let mut spec = common::horizon();
+    spec["replicas"] = json!(3);
+    if let Some(enabled) = topology_aware {
+        spec["service"] = json!({ "topologyAware": enabled });
+    }
+    node(spec)
+}
+
+fn topology_mode(node: &StellarNode) -> Option<String> {
+    build_service(node, false)
+        .metadata
+        .annotations
+        .and_then(|a| a.get(TOPOLOGY_MODE_ANNOTATION).cloned())
+}
+
+#[test]
+fn test_topology_aware_horizon_sets_topology_mode() {
+    assert_eq!(topology_mode(&horizon(Some(true))).as_deref(), Some("Auto"));
+}
+
+#[test]
+fn test_topology_routing_off_by_default() {
+    assert_eq!(topology_mode(&horizon(None)), None);
+    assert_eq!(topology_mode(&horizon(Some(false))), None);
+}
+
+#[test]
+fn test_validator_never_gets_topology_mode() {
+    let mut spec = common::validator();
+    spec["service"] = json!({ "topologyAware": true });
+    let validator = node(spec);
+
+    assert_eq!(topology_mode(&validator), None);
+    let errors = validator.spec.validate().unwrap_err();
+    assert!(errors
+        .iter()
+        .any(|e| e.field == "spec.service.topologyAware"));
+}
+
+#[test]
+fn test_horizon_tls_uses_configured_port() {
+    let mut node = horizon(None);
+    node.spec.horizon_config.as_mut().unwrap().tls = serde_json::from_value(json!({
+        "certSecretRef": "horizon-cert",
+        "port": 8443,
+    }))
+    .expect("valid TLS config");
+
+    let ports = build_service(&node, false).spec.unwrap().ports.unwrap();
+    assert_eq!(ports.len(), 1);
+    assert_eq!(ports[0].port, 8443);
+    assert_eq!(ports[0].name.as_deref(), Some("https"));
+
+    let ports = build_service(&horizon(None), false)
+        .spec
+        .unwrap()
+        .ports
+        .unwrap();
+    assert_eq!(ports[0].port, 8000);
+}
+
+#[test]
+fn test_client_ip_session_affinity() {
+    let mut node = horizon(None);
+    node.spec.service = serde_json::from_value(json!({
+        "sessionAffinity": "ClientIP",
+        "sessionAffinityTimeoutSeconds": 3600,
+    }))
+    .expect("valid service config");
+
+    let spec = build_service(&node, false).spec.unwrap();
+    assert_eq!(spec.session_affinity.as_deref(), Some("ClientIP"));
+    assert_eq!(
+        spec.session_affinity_config
+            .and_then(|c| c.client_ip)
+            .and_then(|c| c.timeout_seconds),
+        Some(3600)
+    );
+}
+
+#[test]
+fn test_no_session_affinity_by_default() {
+    let spec = build_service(&horizon(None), false).spec.unwrap();
+    assert_eq!(spec.session_affinity.as_deref(), Some("None"));
+    assert!(spec.session_affinity_config.is_none());
+}
+
+#[test]
+fn test_local_traffic_policy_on_load_balancer() {
+    let mut node = horizon(None);
+    node.spec.service = serde_json::from_value(json!({
+        "type": "LoadBalancer",
+        "externalTrafficPolicy": "Local",
+        "healthCheckNodePort": 32000,
+    }))
+    .expect("valid service config");
+    assert!(node.spec.validate().is_ok());
+    assert!(node
+        .spec
+        .validation_warnings()
+        .iter()
+        .any(|w| w.field == "spec.service.externalTrafficPolicy"));
+
+    let spec = build_service(&node, false).spec.unwrap();
+    assert_eq!(spec.type_.as_deref(), Some("LoadBalancer"));
+    assert_eq!(spec.external_traffic_policy.as_deref(), Some("Local"));
+    assert_eq!(spec.health_check_node_port, Some(32000));
+    assert_eq!(spec.session_affinity.as_deref(), Some("ClientIP"));
+}
+
+#[test]
+fn test_external_traffic_policy_requires_external_service() {
+    let mut node = horizon(None);
+    node.spec.service = serde_json::from_value(json!({ "externalTrafficPolicy": "Local" }))
+        .expect("valid service config");
+    let errors = node.spec.validate().unwrap_err();
+    assert!(errors
+        .iter()
+        .any(|e| e.field == "spec.service.externalTrafficPolicy"));
+
+    let spec = build_service(&node, false).spec.unwrap();
+    assert_eq!(spec.type_.as_deref(), Some("ClusterIP"));
+    assert_eq!(spec.external_traffic_policy, None);
+
+    node.spec.service = serde_json::from_value(json!({
+        "type": "NodePort",
+        "externalTrafficPolicy": "Cluster",
+        "healthCheckNodePort": 32000,
+    }))
+    .expect("valid service config");
+    let errors = node.spec.validate().unwrap_err();
+    assert!(errors
+        .iter()
+        .any(|e| e.field == "spec.service.healthCheckNodePort"));
+}
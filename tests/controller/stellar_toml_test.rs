@@ -0,0 +1,91 @@
+//! Tests for the stellar.toml ConfigMap, Nginx Deployment, Service and Certificate
+
+mod common;
+
+use common::node;
+use serde_json::json;
+use stellar_k8s::controller::{
+    build_stellar_toml_certificate, build_stellar_toml_config_map, build_stellar_toml_deployment,
+    build_stellar_toml_service,
+};
+use stellar_k8s::crd::{StellarNode, StellarTomlConfig};
+
+const PUBLIC_KEY: &str = "GCGB2S2KGYARPVIA37HYZXVRM2YZUEXA6S33ZU5BUDC6THSB62LZSTYH";
+
+/// Build a Validator publishing a stellar.toml
+fn validator() -> StellarNode {
+    let mut spec = common::validator();
+    spec["validatorConfig"]["stellarToml"] = json!({
+        "organizationName": "Example Org",
+        "organizationUrl": "https://example.org",
+        "nodeAlias": "example-1",
+        "publicKey": PUBLIC_KEY,
+        "historyUrl": "https://history.example.org",
+        "certManagerClusterIssuer": "letsencrypt-prod",
+    });
+    node(spec)
+}
+
+fn config(node: &StellarNode) -> &StellarTomlConfig {
+    node.spec
+        .validator_config
+        .as_ref()
+        .and_then(|v| v.stellar_toml.as_ref())
+        .expect("stellarToml configured")
+}
+
+#[test]
+fn test_config_map_holds_stellar_toml_and_nginx_config() {
+    let node = validator();
+    let cm = build_stellar_toml_config_map(&node, config(&node));
+
+    assert_eq!(cm.metadata.name.as_deref(), Some("test-node-stellar-toml"));
+    let data = cm.data.expect("data");
+    assert!(data["stellar.toml"].contains(&format!("PUBLIC_KEY=\"{PUBLIC_KEY}\"")));
+    assert!(data["stellar.toml"].contains("ALIAS=\"example-1\""));
+    assert!(data["default.conf"].contains("location = /.well-known/stellar.toml"));
+}
+
+#[test]
+fn test_deployment_serves_https_with_tls_secret() {
+    let deployment = build_stellar_toml_deployment(&validator());
+    let pod_spec = deployment
+        .spec
+        .and_then(|s| s.template.spec)
+        .expect("pod spec");
+
+    let container = &pod_spec.containers[0];
+    assert_eq!(container.ports.as_ref().unwrap()[0].container_port, 443);
+
+    let tls_secret = pod_spec
+        .volumes
+        .unwrap_or_default()
+        .into_iter()
+        .find_map(|v| v.secret)
+        .and_then(|s| s.secret_name);
+    assert_eq!(tls_secret.as_deref(), Some("test-node-stellar-toml-tls"));
+}
+
+#[test]
+fn test_server_pods_are_not_selected_as_node_pods() {
+    let service = build_stellar_toml_service(&validator());
+    let selector = service.spec.and_then(|s| s.selector).expect("selector");
+    assert!(!selector.contains_key("app.kubernetes.io/instance"));
+    assert_eq!(selector["stellar.org/stellar-toml-for"], "test-node");
+}
+
+#[test]
+fn test_certificate_covers_home_domain() {
+    let node = validator();
+    let certificate = build_stellar_toml_certificate(&node, config(&node));
+
+    assert_eq!(certificate.data["spec"]["dnsNames"], json!(["example.org"]));
+    assert_eq!(
+        certificate.data["spec"]["secretName"],
+        "test-node-stellar-toml-tls"
+    );
+    assert_eq!(
+        certificate.data["spec"]["issuerRef"],
+        json!({ "name": "letsencrypt-prod", "kind": "ClusterIssuer" })
+    );
+}